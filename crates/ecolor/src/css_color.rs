@@ -0,0 +1,168 @@
+//! Parse colors from CSS color strings at runtime.
+//!
+//! Supports the most common formats of <https://drafts.csswg.org/css-color-4/>,
+//! i.e. what you typically copy from a web page or a design tool.
+
+use crate::{Color32, HexColor};
+
+impl Color32 {
+    /// Parses a color from a CSS color string.
+    ///
+    /// Supports:
+    /// * hex colors, with or without the leading `#`: `#f00`, `ff0000`, `#ff000080`
+    /// * `rgb(…)` and `rgba(…)`, with comma- or space-separated components,
+    ///   given as 0-255 or as percentages: `rgb(255, 0, 0)`, `rgb(100% 0% 0% / 50%)`
+    /// * the basic named colors, like `red`, `orange` and `transparent`
+    ///
+    /// Surrounding whitespace and a trailing `;` are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ecolor::Color32;
+    /// assert_eq!(Color32::from_css("#ff0000"), Some(Color32::RED));
+    /// assert_eq!(Color32::from_css("rgb(0, 255, 0)"), Some(Color32::GREEN));
+    /// assert_eq!(Color32::from_css("blue;"), Some(Color32::BLUE));
+    /// assert_eq!(Color32::from_css("rgba(0, 0, 0, 0)"), Some(Color32::TRANSPARENT));
+    /// ```
+    pub fn from_css(css: &str) -> Option<Self> {
+        let css = css
+            .trim()
+            .trim_end_matches(';')
+            .trim_end()
+            .to_ascii_lowercase();
+
+        if let Some(hex) = css.strip_prefix('#') {
+            return HexColor::from_str_without_hash(hex).ok().map(|h| h.color());
+        }
+
+        if let Some(args) = css
+            .strip_prefix("rgba(")
+            .or_else(|| css.strip_prefix("rgb("))
+        {
+            return parse_rgb_args(args.strip_suffix(')')?);
+        }
+
+        named_color(&css).or_else(|| {
+            HexColor::from_str_without_hash(&css)
+                .ok()
+                .map(|h| h.color())
+        })
+    }
+}
+
+/// Parses the arguments of `rgb(…)`/`rgba(…)`, e.g. `255, 0, 0, 0.5` or `255 0 0 / 50%`.
+fn parse_rgb_args(args: &str) -> Option<Color32> {
+    let (rgb, alpha) = match args.split_once('/') {
+        Some((rgb, alpha)) => (rgb, Some(alpha.trim())),
+        None => (args, None),
+    };
+
+    let mut components: Vec<&str> = rgb
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let alpha = match (alpha, components.len()) {
+        (alpha, 3) => alpha,
+        (None, 4) => components.pop(),
+        _ => return None,
+    };
+
+    let [r, g, b] = [components[0], components[1], components[2]].map(parse_channel);
+    let a = alpha.map_or(Some(255), parse_alpha)?;
+    Some(Color32::from_rgba_unmultiplied(r?, g?, b?, a))
+}
+
+/// `128` or `50%`
+fn parse_channel(s: &str) -> Option<u8> {
+    let value = if let Some(percent) = s.strip_suffix('%') {
+        percent.parse::<f32>().ok()? / 100.0 * 255.0
+    } else {
+        s.parse::<f32>().ok()?
+    };
+    Some(value.round().clamp(0.0, 255.0) as u8)
+}
+
+/// `0.5` or `50%`
+fn parse_alpha(s: &str) -> Option<u8> {
+    let value = if let Some(percent) = s.strip_suffix('%') {
+        percent.parse::<f32>().ok()? / 100.0
+    } else {
+        s.parse::<f32>().ok()?
+    };
+    Some((value.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// The basic CSS named colors.
+fn named_color(name: &str) -> Option<Color32> {
+    let [r, g, b] = match name {
+        "transparent" => return Some(Color32::TRANSPARENT),
+        "black" => [0, 0, 0],
+        "silver" => [192, 192, 192],
+        "gray" | "grey" => [128, 128, 128],
+        "white" => [255, 255, 255],
+        "maroon" => [128, 0, 0],
+        "red" => [255, 0, 0],
+        "purple" => [128, 0, 128],
+        "fuchsia" | "magenta" => [255, 0, 255],
+        "green" => [0, 128, 0],
+        "lime" => [0, 255, 0],
+        "olive" => [128, 128, 0],
+        "yellow" => [255, 255, 0],
+        "navy" => [0, 0, 128],
+        "blue" => [0, 0, 255],
+        "teal" => [0, 128, 128],
+        "aqua" | "cyan" => [0, 255, 255],
+        "orange" => [255, 165, 0],
+        _ => return None,
+    };
+    Some(Color32::from_rgb(r, g, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_color_formats() {
+        let half_red = Color32::from_rgba_unmultiplied(255, 0, 0, 128);
+        let cases = [
+            ("#f00", Color32::RED),
+            ("#FF0000", Color32::RED),
+            ("ff0000", Color32::RED),
+            ("#ff000080", half_red),
+            ("  #ff0000 ;", Color32::RED),
+            ("rgb(255, 0, 0)", Color32::RED),
+            ("RGB(255,0,0)", Color32::RED),
+            ("rgb(255 0 0)", Color32::RED),
+            ("rgb(100% 0% 0%)", Color32::RED),
+            ("rgba(255, 0, 0, 0.5)", half_red),
+            ("rgba(255, 0, 0, 50%)", half_red),
+            ("rgb(255 0 0 / 0.5)", half_red),
+            ("rgb(255 0 0 / 50%)", half_red),
+            ("red", Color32::RED),
+            ("Lime", Color32::GREEN),
+            ("green", Color32::from_rgb(0, 128, 0)),
+            ("transparent", Color32::TRANSPARENT),
+        ];
+        for (css, color) in cases {
+            assert_eq!(Color32::from_css(css), Some(color), "{css}");
+        }
+    }
+
+    #[test]
+    fn css_color_invalid() {
+        for css in [
+            "",
+            "#",
+            "#ff00000",
+            "not a color",
+            "rgb(255, 0)",
+            "rgb(255, 0, 0",
+            "rgb(a, b, c)",
+            "rgb(255, 0, 0, 0.5, 1)",
+            "rgb(255 0 0 0 / 1)",
+        ] {
+            assert_eq!(Color32::from_css(css), None, "{css}");
+        }
+    }
+}
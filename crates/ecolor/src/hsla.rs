@@ -0,0 +1,102 @@
+use crate::{
+    Color32, Hsva, Rgba, gamma_from_linear, hsv_from_rgb, linear_from_gamma, rgb_from_hsv,
+};
+
+/// Hue, saturation, lightness, alpha. All in the range [0, 1].
+/// No premultiplied alpha.
+///
+/// Like the `hsl()` of CSS, this is computed from the gamma-space (sRGB) color.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsla {
+    /// hue 0-1
+    pub h: f32,
+
+    /// saturation 0-1
+    pub s: f32,
+
+    /// lightness 0-1
+    pub l: f32,
+
+    /// alpha 0-1. A negative value signifies an additive color (and alpha is ignored).
+    pub a: f32,
+}
+
+impl Hsla {
+    #[inline]
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Hsva> for Hsla {
+    fn from(hsva: Hsva) -> Self {
+        let [r, g, b, a] = hsva.to_rgba_unmultiplied();
+        let (h, s, v) = hsv_from_rgb([
+            gamma_from_linear(r),
+            gamma_from_linear(g),
+            gamma_from_linear(b),
+        ]);
+
+        let l = v * (1.0 - s / 2.0);
+        let s = if l <= 0.0 || 1.0 <= l {
+            0.0
+        } else {
+            (v - l) / l.min(1.0 - l)
+        };
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Hsla> for Hsva {
+    fn from(hsla: Hsla) -> Self {
+        let Hsla { h, s, l, a } = hsla;
+        let v = l + s * l.min(1.0 - l);
+        let s = if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+
+        let [r, g, b] = rgb_from_hsv((h, s, v));
+        Self::from_rgba_unmultiplied(
+            linear_from_gamma(r),
+            linear_from_gamma(g),
+            linear_from_gamma(b),
+            a,
+        )
+    }
+}
+
+impl From<Hsla> for Rgba {
+    fn from(hsla: Hsla) -> Self {
+        Hsva::from(hsla).into()
+    }
+}
+
+impl From<Hsla> for Color32 {
+    fn from(hsla: Hsla) -> Self {
+        Hsva::from(hsla).into()
+    }
+}
+
+impl From<Rgba> for Hsla {
+    fn from(rgba: Rgba) -> Self {
+        Hsva::from(rgba).into()
+    }
+}
+
+impl From<Color32> for Hsla {
+    fn from(srgba: Color32) -> Self {
+        Hsva::from(srgba).into()
+    }
+}
+
+#[test]
+fn test_hsla_roundtrip() {
+    for color in [
+        Color32::RED,
+        Color32::from_rgb(12, 200, 99),
+        Color32::from_rgb(250, 250, 240),
+        Color32::from_rgba_unmultiplied(40, 60, 200, 128),
+    ] {
+        assert_eq!(Color32::from(Hsla::from(color)), color);
+    }
+    assert!((Hsla::from(Color32::RED).l - 0.5).abs() < 1e-3);
+}
@@ -33,6 +33,12 @@ pub use hsva_gamma::*;
 mod hsva;
 pub use hsva::*;
 
+mod hsla;
+pub use hsla::*;
+
+mod oklab;
+pub use oklab::*;
+
 #[cfg(feature = "color-hex")]
 mod hex_color_macro;
 #[cfg(feature = "color-hex")]
@@ -45,6 +51,8 @@ pub use rgba::*;
 mod hex_color_runtime;
 pub use hex_color_runtime::*;
 
+mod css_color;
+
 // ----------------------------------------------------------------------------
 // Color conversion:
 
@@ -0,0 +1,208 @@
+use crate::{Color32, Hsva, Rgba};
+
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) color space,
+/// a perceptually uniform take on CIELAB.
+///
+/// No premultiplied alpha.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklab {
+    /// perceived lightness 0-1
+    pub l: f32,
+
+    /// green (negative) to red (positive), roughly -0.4 to 0.4
+    pub a: f32,
+
+    /// blue (negative) to yellow (positive), roughly -0.4 to 0.4
+    pub b: f32,
+
+    /// alpha 0-1. A negative value signifies an additive color (and alpha is ignored).
+    pub alpha: f32,
+}
+
+/// The polar form of [`Oklab`]: lightness, chroma, hue.
+///
+/// No premultiplied alpha.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklch {
+    /// perceived lightness 0-1
+    pub l: f32,
+
+    /// chroma, 0 for grays and up to roughly 0.37 for the most saturated sRGB colors
+    pub c: f32,
+
+    /// hue 0-1 (the fraction of a full turn, like [`Hsva::h`])
+    pub h: f32,
+
+    /// alpha 0-1. A negative value signifies an additive color (and alpha is ignored).
+    pub alpha: f32,
+}
+
+impl Oklab {
+    #[inline]
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// From linear RGB, without premultiplied alpha.
+    pub fn from_rgb([r, g, b]: [f32; 3]) -> Self {
+        #![allow(clippy::many_single_char_names)]
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: 1.0,
+        }
+    }
+
+    /// To linear RGB, without premultiplied alpha.
+    ///
+    /// Colors outside of the sRGB gamut are clamped.
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+        .map(|c| c.clamp(0.0, 1.0))
+    }
+}
+
+impl Oklch {
+    #[inline]
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let Oklab { l, a, b, alpha } = lab;
+        let h = b.atan2(a) / std::f32::consts::TAU;
+        Self {
+            l,
+            c: a.hypot(b),
+            h: (h + 1.0).fract(),
+            alpha,
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let Oklch { l, c, h, alpha } = lch;
+        let (sin, cos) = (h * std::f32::consts::TAU).sin_cos();
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha,
+        }
+    }
+}
+
+impl From<Hsva> for Oklab {
+    fn from(hsva: Hsva) -> Self {
+        let [r, g, b, alpha] = hsva.to_rgba_unmultiplied();
+        Self {
+            alpha,
+            ..Self::from_rgb([r, g, b])
+        }
+    }
+}
+
+impl From<Oklab> for Hsva {
+    fn from(lab: Oklab) -> Self {
+        let [r, g, b] = lab.to_rgb();
+        Self::from_rgba_unmultiplied(r, g, b, lab.alpha)
+    }
+}
+
+impl From<Hsva> for Oklch {
+    fn from(hsva: Hsva) -> Self {
+        Oklab::from(hsva).into()
+    }
+}
+
+impl From<Oklch> for Hsva {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
+
+impl From<Oklab> for Rgba {
+    fn from(lab: Oklab) -> Self {
+        Hsva::from(lab).into()
+    }
+}
+
+impl From<Oklab> for Color32 {
+    fn from(lab: Oklab) -> Self {
+        Hsva::from(lab).into()
+    }
+}
+
+impl From<Oklch> for Rgba {
+    fn from(lch: Oklch) -> Self {
+        Hsva::from(lch).into()
+    }
+}
+
+impl From<Oklch> for Color32 {
+    fn from(lch: Oklch) -> Self {
+        Hsva::from(lch).into()
+    }
+}
+
+impl From<Rgba> for Oklab {
+    fn from(rgba: Rgba) -> Self {
+        Hsva::from(rgba).into()
+    }
+}
+
+impl From<Color32> for Oklab {
+    fn from(srgba: Color32) -> Self {
+        Hsva::from(srgba).into()
+    }
+}
+
+impl From<Rgba> for Oklch {
+    fn from(rgba: Rgba) -> Self {
+        Hsva::from(rgba).into()
+    }
+}
+
+impl From<Color32> for Oklch {
+    fn from(srgba: Color32) -> Self {
+        Hsva::from(srgba).into()
+    }
+}
+
+#[test]
+fn test_oklab() {
+    let white = Oklab::from(Color32::WHITE);
+    assert!((white.l - 1.0).abs() < 1e-3);
+    assert!(white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+
+    for color in [
+        Color32::RED,
+        Color32::from_rgb(12, 200, 99),
+        Color32::from_rgba_unmultiplied(40, 60, 200, 128),
+    ] {
+        assert_eq!(Color32::from(Oklab::from(color)), color);
+        assert_eq!(Color32::from(Oklch::from(color)), color);
+    }
+}
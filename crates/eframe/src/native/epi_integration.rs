@@ -156,6 +156,10 @@ pub struct EpiIntegration {
     last_auto_save: Instant,
     pub beginning: Instant,
     is_first_frame: bool,
+
+    /// When the root viewport was last painted, relative to [`Self::beginning`].
+    last_frame_painted: Option<f64>,
+
     pub egui_ctx: egui::Context,
     pending_full_output: egui::FullOutput,
 
@@ -222,6 +226,7 @@ impl EpiIntegration {
             app_icon_setter,
             beginning: Instant::now(),
             is_first_frame: true,
+            last_frame_painted: None,
         }
     }
 
@@ -265,7 +270,10 @@ impl EpiIntegration {
         viewport_ui_cb: Option<&DeferredViewportUiCallback>,
         mut raw_input: egui::RawInput,
     ) -> egui::FullOutput {
-        raw_input.time = Some(self.beginning.elapsed().as_secs_f64());
+        raw_input.set_time(self.beginning.elapsed().as_secs_f64());
+        if viewport_ui_cb.is_none() {
+            raw_input.last_frame_painted = self.last_frame_painted.take();
+        }
 
         let close_requested = raw_input.viewport().close_requested();
 
@@ -305,6 +313,7 @@ impl EpiIntegration {
 
     pub fn post_rendering(&mut self, window: &winit::window::Window) {
         profiling::function_scope!();
+        self.last_frame_painted = Some(self.beginning.elapsed().as_secs_f64());
         if std::mem::take(&mut self.is_first_frame) {
             // We keep hidden until we've painted something. See https://github.com/emilk/egui/pull/2279
             window.set_visible(true);
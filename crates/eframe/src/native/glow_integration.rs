@@ -551,7 +551,7 @@ impl GlowWinitRunning<'_> {
 
             self.integration.pre_update();
 
-            raw_input.time = Some(self.integration.beginning.elapsed().as_secs_f64());
+            raw_input.set_time(self.integration.beginning.elapsed().as_secs_f64());
             raw_input.viewports = glutin
                 .viewports
                 .iter()
@@ -625,6 +625,7 @@ impl GlowWinitRunning<'_> {
             textures_delta,
             shapes,
             pixels_per_point,
+            dirty_rect: _, // We always repaint everything
             viewport_output,
         } = full_output;
 
@@ -663,12 +664,17 @@ impl GlowWinitRunning<'_> {
             painter.clear(screen_size_in_pixels, clear_color);
         }
 
-        painter.paint_and_update_textures(
-            screen_size_in_pixels,
-            pixels_per_point,
-            &clipped_primitives,
-            &textures_delta,
-        );
+        {
+            let _scope = integration
+                .egui_ctx
+                .profile_scope("texture upload and paint");
+            painter.paint_and_update_textures(
+                screen_size_in_pixels,
+                pixels_per_point,
+                &clipped_primitives,
+                &textures_delta,
+            );
+        }
 
         {
             for action in viewport.actions_requested.drain() {
@@ -691,15 +697,7 @@ impl GlowWinitRunning<'_> {
                         egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                     }
                     ActionRequested::Paste => {
-                        if let Some(contents) = egui_winit.clipboard_text() {
-                            let contents = contents.replace("\r\n", "\n");
-                            if !contents.is_empty() {
-                                egui_winit
-                                    .egui_input_mut()
-                                    .events
-                                    .push(egui::Event::Paste(contents));
-                            }
-                        }
+                        egui_winit.paste();
                     }
                 }
             }
@@ -1457,7 +1455,7 @@ fn render_immediate_viewport(
             .iter()
             .map(|(id, viewport)| (*id, viewport.info.clone()))
             .collect();
-        raw_input.time = Some(beginning.elapsed().as_secs_f64());
+        raw_input.set_time(beginning.elapsed().as_secs_f64());
         raw_input
     };
 
@@ -1470,6 +1468,7 @@ fn render_immediate_viewport(
         textures_delta,
         shapes,
         pixels_per_point,
+        dirty_rect: _, // We always repaint everything
         viewport_output,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
@@ -603,7 +603,7 @@ impl WgpuWinitRunning<'_> {
 
             integration.pre_update();
 
-            raw_input.time = Some(integration.beginning.elapsed().as_secs_f64());
+            raw_input.set_time(integration.beginning.elapsed().as_secs_f64());
             raw_input.viewports = viewports
                 .iter()
                 .map(|(id, viewport)| (*id, viewport.info.clone()))
@@ -637,6 +637,7 @@ impl WgpuWinitRunning<'_> {
             textures_delta,
             shapes,
             pixels_per_point,
+            dirty_rect: _, // We always repaint everything
             viewport_output,
         } = full_output;
 
@@ -670,14 +671,17 @@ impl WgpuWinitRunning<'_> {
                 true
             }
         });
-        let vsync_secs = painter.paint_and_update_textures(
-            viewport_id,
-            pixels_per_point,
-            app.clear_color(&egui_ctx.style().visuals),
-            &clipped_primitives,
-            &textures_delta,
-            screenshot_commands,
-        );
+        let vsync_secs = {
+            let _scope = egui_ctx.profile_scope("texture upload and paint");
+            painter.paint_and_update_textures(
+                viewport_id,
+                pixels_per_point,
+                app.clear_color(&egui_ctx.style().visuals),
+                &clipped_primitives,
+                &textures_delta,
+                screenshot_commands,
+            )
+        };
 
         for action in viewport.actions_requested.drain() {
             match action {
@@ -691,15 +695,7 @@ impl WgpuWinitRunning<'_> {
                     egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                 }
                 ActionRequested::Paste => {
-                    if let Some(contents) = egui_winit.clipboard_text() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            egui_winit
-                                .egui_input_mut()
-                                .events
-                                .push(egui::Event::Paste(contents));
-                        }
-                    }
+                    egui_winit.paste();
                 }
             }
         }
@@ -964,7 +960,7 @@ fn render_immediate_viewport(
             .iter()
             .map(|(id, viewport)| (*id, viewport.info.clone()))
             .collect();
-        input.time = Some(beginning.elapsed().as_secs_f64());
+        input.set_time(beginning.elapsed().as_secs_f64());
         input
     };
 
@@ -979,6 +975,7 @@ fn render_immediate_viewport(
         textures_delta,
         shapes,
         pixels_per_point,
+        dirty_rect: _, // We always repaint everything
         viewport_output,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
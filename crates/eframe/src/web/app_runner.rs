@@ -16,6 +16,9 @@ pub struct AppRunner {
     last_save_time: f64,
     pub(crate) text_agent: TextAgent,
 
+    /// What the app wants to happen with whatever is being dragged over it from another app.
+    pub(crate) drop_effect: Option<egui::DropEffect>,
+
     // If not empty, the painter should capture n frames from now.
     // zero means capture the exact next frame.
     screenshot_commands_with_frame_delay: Vec<(UserData, usize)>,
@@ -114,6 +117,7 @@ impl AppRunner {
             needs_repaint,
             last_save_time: now_sec(),
             text_agent,
+            drop_effect: None,
             screenshot_commands_with_frame_delay: vec![],
             textures_delta: Default::default(),
             clipped_primitives: None,
@@ -128,6 +132,8 @@ impl AppRunner {
             .or_default()
             .native_pixels_per_point = Some(super::native_pixels_per_point());
         runner.input.raw.system_theme = super::system_theme();
+        runner.input.raw.reduced_motion = super::system_reduced_motion();
+        runner.input.raw.high_contrast = super::system_high_contrast();
 
         Ok(runner)
     }
@@ -215,6 +221,8 @@ impl AppRunner {
 
         let canvas_size = super::canvas_size_in_points(self.canvas(), self.egui_ctx());
         let mut raw_input = self.input.new_frame(canvas_size);
+        raw_input.virtual_keyboard_rect =
+            super::virtual_keyboard_rect(self.canvas(), self.egui_ctx());
 
         if super::DEBUG_RESIZE {
             log::info!(
@@ -238,6 +246,7 @@ impl AppRunner {
             textures_delta,
             shapes,
             pixels_per_point,
+            dirty_rect: _, // We always repaint everything
             viewport_output,
         } = full_output;
 
@@ -303,7 +312,7 @@ impl AppRunner {
         self.frame.info.cpu_usage = Some(cpu_usage_seconds);
     }
 
-    fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
+    fn handle_platform_output(&mut self, platform_output: egui::PlatformOutput) {
         #![allow(deprecated)]
 
         #[cfg(feature = "web_screen_reader")]
@@ -317,14 +326,18 @@ impl AppRunner {
             open_url,
             copied_text,
             events: _,                    // already handled
+            announcements: _,             // already handled by `events_description`
             mutable_text_under_cursor: _, // TODO(#4569): https://github.com/emilk/egui/issues/4569
             ime,
             #[cfg(feature = "accesskit")]
                 accesskit_update: _, // not currently implemented
             num_completed_passes: _,    // handled by `Context::run`
             request_discard_reasons: _, // handled by `Context::run`
+            drop_effect,
         } = platform_output;
 
+        self.drop_effect = drop_effect;
+
         for command in commands {
             match command {
                 egui::OutputCommand::CopyText(text) => {
@@ -333,6 +346,18 @@ impl AppRunner {
                 egui::OutputCommand::CopyImage(image) => {
                     super::set_clipboard_image(&image);
                 }
+                egui::OutputCommand::CopyHtml { html, alt_text } => {
+                    super::set_clipboard_html(&html, &alt_text);
+                }
+                egui::OutputCommand::CopyFiles(paths) => {
+                    log::debug!(
+                        "Copying file lists is not supported on the web; copying the paths as text"
+                    );
+                    let text = egui::ClipboardContent::Files(paths)
+                        .to_text()
+                        .unwrap_or_default();
+                    super::set_clipboard_text(&text);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     super::open_url(&open_url.url, open_url.new_tab);
                 }
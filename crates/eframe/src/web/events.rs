@@ -104,6 +104,7 @@ pub(crate) fn install_event_handlers(runner_ref: &WebRunner) -> Result<(), JsVal
     install_drag_and_drop(runner_ref, &canvas)?;
     install_window_events(runner_ref, &window)?;
     install_color_scheme_change_event(runner_ref, &window)?;
+    install_accessibility_preference_change_events(runner_ref, &window)?;
     Ok(())
 }
 
@@ -321,7 +322,7 @@ fn install_copy_cut_paste(runner_ref: &WebRunner, target: &EventTarget) -> Resul
                 let mut should_stop_propagation = true;
                 let mut should_prevent_default = true;
                 if !text.is_empty() {
-                    let egui_event = egui::Event::Paste(text);
+                    let egui_event = egui::Event::Paste(text.clone());
                     should_stop_propagation =
                         (runner.web_options.should_stop_propagation)(&egui_event);
                     should_prevent_default =
@@ -330,6 +331,17 @@ fn install_copy_cut_paste(runner_ref: &WebRunner, target: &EventTarget) -> Resul
                     runner.needs_repaint.repaint_asap();
                 }
 
+                if let Ok(html) = data.get_data("text/html") {
+                    if !html.is_empty() {
+                        runner.input.raw.events.push(egui::Event::PasteContent(
+                            egui::ClipboardContent::Html {
+                                html,
+                                alt_text: text,
+                            },
+                        ));
+                    }
+                }
+
                 // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
                 if should_stop_propagation {
                     event.stop_propagation();
@@ -487,6 +499,27 @@ fn install_color_scheme_change_event(
     Ok(())
 }
 
+fn install_accessibility_preference_change_events(
+    runner_ref: &WebRunner,
+    window: &web_sys::Window,
+) -> Result<(), JsValue> {
+    for query in [super::REDUCED_MOTION_QUERY, super::HIGH_CONTRAST_QUERY] {
+        if let Some(media_query_list) = window.match_media(query)? {
+            runner_ref.add_event_listener::<web_sys::MediaQueryListEvent>(
+                &media_query_list,
+                "change",
+                |_event, runner| {
+                    runner.input.raw.reduced_motion = super::system_reduced_motion();
+                    runner.input.raw.high_contrast = super::system_high_contrast();
+                    runner.needs_repaint.repaint_asap();
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
 fn prevent_default_and_stop_propagation(
     runner_ref: &WebRunner,
     target: &EventTarget,
@@ -871,6 +904,14 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
                     .push(egui::HoveredFile::default());
             }
 
+            // Let the app know where the files are hovered, so widgets can react to them:
+            let pos = pos_from_mouse_event(runner.canvas(), &event, runner.egui_ctx());
+            runner.input.raw.events.push(egui::Event::PointerMoved(pos));
+
+            if let Some(drop_effect) = runner.drop_effect {
+                data_transfer.set_drop_effect(drop_effect.as_str());
+            }
+
             runner.needs_repaint.repaint_asap();
             event.stop_propagation();
             event.prevent_default();
@@ -893,6 +934,23 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
                 runner.input.raw.hovered_files.clear();
                 runner.needs_repaint.repaint_asap();
 
+                let pos = pos_from_mouse_event(runner.canvas(), &event, runner.egui_ctx());
+                runner.input.raw.events.push(egui::Event::PointerMoved(pos));
+
+                let num_files = data_transfer.files().map_or(0, |files| files.length());
+                if num_files == 0 {
+                    // Not files, but maybe some text dragged from another app:
+                    if let Ok(text) = data_transfer.get_data("text/plain") {
+                        if !text.is_empty() {
+                            runner.input.raw.dropped_files.push(egui::DroppedFile {
+                                mime: "text/plain".to_owned(),
+                                text: Some(text),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+
                 if let Some(files) = data_transfer.files() {
                     for i in 0..files.length() {
                         if let Some(file) = files.get(i) {
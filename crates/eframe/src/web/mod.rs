@@ -139,6 +139,30 @@ fn prefers_color_scheme(
     window.match_media(format!("(prefers-color-scheme: {theme})").as_str())
 }
 
+/// Media query matching when the user asked for less motion.
+const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+
+/// Media query matching when the user asked for more contrast, or forced their own colors.
+const HIGH_CONTRAST_QUERY: &str = "(prefers-contrast: more), (forced-colors: active)";
+
+/// Ask the browser whether the user prefers reduced motion.
+///
+/// `None` means unknown.
+pub fn system_reduced_motion() -> Option<bool> {
+    media_query_matches(REDUCED_MOTION_QUERY)
+}
+
+/// Ask the browser whether the user prefers high contrast.
+///
+/// `None` means unknown.
+pub fn system_high_contrast() -> Option<bool> {
+    media_query_matches(HIGH_CONTRAST_QUERY)
+}
+
+fn media_query_matches(query: &str) -> Option<bool> {
+    Some(web_sys::window()?.match_media(query).ok()??.matches())
+}
+
 /// Returns the canvas in client coordinates.
 fn canvas_content_rect(canvas: &web_sys::HtmlCanvasElement) -> egui::Rect {
     let bounding_rect = canvas.get_bounding_client_rect();
@@ -166,6 +190,36 @@ fn canvas_content_rect(canvas: &web_sys::HtmlCanvasElement) -> egui::Rect {
     rect
 }
 
+/// The part of the canvas hidden behind the on-screen keyboard, in egui points.
+///
+/// Mobile browsers shrink the visual viewport when the keyboard is shown,
+/// so the part of the canvas below the visual viewport is what the keyboard covers.
+fn virtual_keyboard_rect(
+    canvas: &web_sys::HtmlCanvasElement,
+    ctx: &egui::Context,
+) -> Option<egui::Rect> {
+    let visual_viewport = web_sys::window()?.visual_viewport()?;
+    if 1.0 < visual_viewport.scale() {
+        // Pinch-zoomed in, so the visual viewport is small for another reason.
+        return None;
+    }
+    let visible_bottom = (visual_viewport.offset_top() + visual_viewport.height()) as f32;
+
+    let canvas_rect = canvas_content_rect(canvas);
+    if canvas_rect.bottom() <= visible_bottom + 1.0 {
+        return None;
+    }
+
+    // CSS pixels to egui points:
+    let points_per_css_pixel = 1.0 / ctx.zoom_factor();
+    Some(
+        egui::Rect::from_min_max(
+            egui::pos2(0.0, (visible_bottom - canvas_rect.top()).max(0.0)),
+            egui::pos2(canvas_rect.width(), canvas_rect.height()),
+        ) * points_per_css_pixel,
+    )
+}
+
 fn canvas_size_in_points(canvas: &web_sys::HtmlCanvasElement, ctx: &egui::Context) -> egui::Vec2 {
     // ctx.pixels_per_point can be outdated
 
@@ -210,6 +264,42 @@ fn set_clipboard_text(s: &str) {
     }
 }
 
+/// Set the clipboard to rich text, with a plain text fallback.
+fn set_clipboard_html(html: &str, alt_text: &str) {
+    if let Some(window) = web_sys::window() {
+        if !window.is_secure_context() {
+            log::error!(
+                "Clipboard is not available because we are not in a secure context. \
+                See https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts"
+            );
+            return;
+        }
+
+        let item = match create_clipboard_item(&[
+            ("text/html", html.as_bytes()),
+            ("text/plain", alt_text.as_bytes()),
+        ]) {
+            Ok(item) => item,
+            Err(err) => {
+                log::error!("Failed to copy html: {}", string_from_js_value(&err));
+                return;
+            }
+        };
+        let items = js_sys::Array::of1(&item);
+        let promise = window.navigator().clipboard().write(&items);
+        let future = wasm_bindgen_futures::JsFuture::from(promise);
+        let future = async move {
+            if let Err(err) = future.await {
+                log::error!(
+                    "Copy/cut html action failed: {}",
+                    string_from_js_value(&err)
+                );
+            }
+        };
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
+
 /// Set the clipboard image.
 fn set_clipboard_image(image: &egui::ColorImage) {
     if let Some(window) = web_sys::window() {
@@ -230,9 +320,7 @@ fn set_clipboard_image(image: &egui::ColorImage) {
             }
         };
 
-        let mime = "image/png";
-
-        let item = match create_clipboard_item(mime, &png_bytes) {
+        let item = match create_clipboard_item(&[("image/png", &png_bytes)]) {
             Ok(item) => item,
             Err(err) => {
                 log::error!("Failed to copy image: {}", string_from_js_value(&err));
@@ -276,23 +364,26 @@ fn to_png_bytes(image: &image::RgbaImage) -> Result<Vec<u8>, String> {
     Ok(png_bytes)
 }
 
-fn create_clipboard_item(mime: &str, bytes: &[u8]) -> Result<web_sys::ClipboardItem, JsValue> {
-    let array = js_sys::Uint8Array::from(bytes);
-    let blob_parts = js_sys::Array::new();
-    blob_parts.push(&array);
+/// A clipboard item with the given `(mime, bytes)` representations.
+fn create_clipboard_item(entries: &[(&str, &[u8])]) -> Result<web_sys::ClipboardItem, JsValue> {
+    let items = js_sys::Object::new();
 
-    let options = web_sys::BlobPropertyBag::new();
-    options.set_type(mime);
+    for (mime, bytes) in entries {
+        let array = js_sys::Uint8Array::from(*bytes);
+        let blob_parts = js_sys::Array::new();
+        blob_parts.push(&array);
 
-    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)?;
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(mime);
 
-    let items = js_sys::Object::new();
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)?;
 
-    // SAFETY: I hope so
-    #[expect(unsafe_code, unused_unsafe)] // Weird false positive
-    unsafe {
-        js_sys::Reflect::set(&items, &JsValue::from_str(mime), &blob)?
-    };
+        // SAFETY: I hope so
+        #[expect(unsafe_code, unused_unsafe)] // Weird false positive
+        unsafe {
+            js_sys::Reflect::set(&items, &JsValue::from_str(mime), &blob)?
+        };
+    }
 
     let clipboard_item = web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&items)?;
 
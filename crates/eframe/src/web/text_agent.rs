@@ -100,8 +100,12 @@ impl TextAgent {
             move |event: web_sys::CompositionEvent, runner: &mut AppRunner| {
                 let Some(text) = event.data() else { return };
                 input.set_value("");
+                // Like egui-winit: an empty commit cancels the composition,
+                // and the IME is disabled until the next composition starts.
                 let event = egui::Event::Ime(egui::ImeEvent::Commit(text));
                 runner.input.raw.events.push(event);
+                let event = egui::Event::Ime(egui::ImeEvent::Disabled);
+                runner.input.raw.events.push(event);
                 runner.needs_repaint.repaint_asap();
             }
         };
@@ -145,14 +149,13 @@ impl TextAgent {
 
         let style = self.input.style();
 
-        // This is where the IME input will point to:
-        style.set_property(
-            "left",
-            &format!("{}px", cursor_rect.center().x * zoom_factor),
-        )?;
+        // This is where the IME input will point to.
+        // Cover the text cursor, so the candidate window is shown below the line being edited:
+        style.set_property("left", &format!("{}px", cursor_rect.min.x * zoom_factor))?;
+        style.set_property("top", &format!("{}px", cursor_rect.min.y * zoom_factor))?;
         style.set_property(
-            "top",
-            &format!("{}px", cursor_rect.center().y * zoom_factor),
+            "height",
+            &format!("{}px", cursor_rect.height().max(1.0) * zoom_factor),
         )?;
 
         Ok(())
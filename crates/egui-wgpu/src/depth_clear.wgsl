@@ -0,0 +1,21 @@
+// Clears the depth buffer inside the scissor rectangle,
+// before a paint callback that asked for it with `PaintCallback::depth_buffer`.
+
+var<private> positions: array<vec2f, 3> = array<vec2f, 3>(
+    vec2f(-1.0, -3.0),
+    vec2f(-1.0, 1.0),
+    vec2f(3.0, 1.0)
+);
+
+// meant to be called with 3 vertex indices: 0, 1, 2
+// draws one large triangle over the clip space, on the far plane (depth 1.0)
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4f {
+    return vec4f(positions[vertex_index], 1.0, 1.0);
+}
+
+// The color target is written with an empty write mask, so this is discarded.
+@fragment
+fn fs_main() -> @location(0) vec4f {
+    return vec4f(0.0);
+}
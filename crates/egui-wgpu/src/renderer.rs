@@ -3,7 +3,7 @@
 use std::{borrow::Cow, num::NonZeroU64, ops::Range};
 
 use ahash::HashMap;
-use epaint::{PaintCallbackInfo, Primitive, Vertex, emath::NumExt as _};
+use epaint::{Mesh, PaintCallbackInfo, Primitive, TextureId, Vertex, emath::NumExt as _};
 
 use wgpu::util::DeviceExt as _;
 
@@ -34,10 +34,7 @@ impl Callback {
         rect: epaint::emath::Rect,
         callback: impl CallbackTrait + 'static,
     ) -> epaint::PaintCallback {
-        epaint::PaintCallback {
-            rect,
-            callback: std::sync::Arc::new(Self(Box::new(callback))),
-        }
+        epaint::PaintCallback::new(rect, std::sync::Arc::new(Self(Box::new(callback))))
     }
 }
 
@@ -112,6 +109,12 @@ pub trait CallbackTrait: Send + Sync {
     ///
     /// It is given access to the [`wgpu::RenderPass`] so that it can issue draw commands
     /// into the same [`wgpu::RenderPass`] that is used for all other egui elements.
+    ///
+    /// If the renderer was created with a depth format, the render pass has a depth attachment,
+    /// which is cleared at the start of the pass.
+    /// egui only writes to it to clear it inside the clip rectangle of callbacks
+    /// with [`epaint::PaintCallback::depth_buffer`] set.
+    /// This is reported in [`PaintCallbackInfo::has_depth_buffer`].
     fn paint(
         &self,
         info: PaintCallbackInfo,
@@ -179,9 +182,17 @@ pub struct Texture {
 pub struct Renderer {
     pipeline: wgpu::RenderPipeline,
 
+    /// Clears the depth buffer for callbacks with [`epaint::PaintCallback::depth_buffer`].
+    ///
+    /// `None` if there is no depth buffer.
+    depth_clear_pipeline: Option<wgpu::RenderPipeline>,
+
     index_buffer: SlicedBuffer,
     vertex_buffer: SlicedBuffer,
 
+    /// The texture of each mesh in [`Self::index_buffer`] and [`Self::vertex_buffer`].
+    mesh_textures: Vec<TextureId>,
+
     uniform_buffer: wgpu::Buffer,
     previous_uniform_buffer_content: UniformBuffer,
     uniform_bind_group: wgpu::BindGroup,
@@ -196,6 +207,10 @@ pub struct Renderer {
 
     dithering: bool,
 
+    /// Does the render pass have a depth attachment?
+    has_depth_buffer: bool,
+    msaa_samples: u32,
+
     /// Storage for resources shared with all invocations of [`CallbackTrait`]'s methods.
     ///
     /// See also [`CallbackTrait`].
@@ -373,6 +388,46 @@ impl Renderer {
         )
         };
 
+        let depth_clear_pipeline = output_depth_format.map(|format| {
+            profiling::scope!("create_depth_clear_pipeline");
+            let module = device.create_shader_module(wgpu::include_wgsl!("depth_clear.wgsl"));
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("egui_depth_clear_pipeline"),
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &module,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Always,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    alpha_to_coverage_enabled: false,
+                    count: msaa_samples,
+                    mask: !0,
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &module,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: output_color_format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::empty(),
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                multiview: None,
+                cache: None,
+            })
+        });
+
         const VERTEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<Vertex>() * 1024) as _;
         const INDEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
@@ -380,6 +435,7 @@ impl Renderer {
 
         Self {
             pipeline,
+            depth_clear_pipeline,
             vertex_buffer: SlicedBuffer {
                 buffer: create_vertex_buffer(device, VERTEX_BUFFER_START_CAPACITY),
                 slices: Vec::with_capacity(64),
@@ -390,6 +446,7 @@ impl Renderer {
                 slices: Vec::with_capacity(64),
                 capacity: INDEX_BUFFER_START_CAPACITY,
             },
+            mesh_textures: Vec::with_capacity(64),
             uniform_buffer,
             // Buffers on wgpu are zero initialized, so this is indeed its current state!
             previous_uniform_buffer_content: UniformBuffer {
@@ -403,6 +460,8 @@ impl Renderer {
             next_user_texture_id: 0,
             samplers: HashMap::default(),
             dithering,
+            has_depth_buffer: output_depth_format.is_some(),
+            msaa_samples,
             callback_resources: CallbackResources::default(),
         }
     }
@@ -431,6 +490,7 @@ impl Renderer {
 
         let mut index_buffer_slices = self.index_buffer.slices.iter();
         let mut vertex_buffer_slices = self.vertex_buffer.slices.iter();
+        let mut mesh_textures = self.mesh_textures.iter();
 
         for epaint::ClippedPrimitive {
             clip_rect,
@@ -456,10 +516,15 @@ impl Renderer {
 
                 if rect.width == 0 || rect.height == 0 {
                     // Skip rendering zero-sized clip areas.
-                    if let Primitive::Mesh(_) = primitive {
+                    if let Primitive::Mesh(_)
+                    | Primitive::Instanced(_)
+                    | Primitive::SdfRect(_)
+                    | Primitive::BackdropBlur(_) = primitive
+                    {
                         // If this is a mesh, we need to advance the index and vertex buffer iterators:
                         index_buffer_slices.next().unwrap();
                         vertex_buffer_slices.next().unwrap();
+                        mesh_textures.next().unwrap();
                     }
                     continue;
                 }
@@ -468,11 +533,15 @@ impl Renderer {
             }
 
             match primitive {
-                Primitive::Mesh(mesh) => {
+                Primitive::Mesh(_)
+                | Primitive::Instanced(_)
+                | Primitive::SdfRect(_)
+                | Primitive::BackdropBlur(_) => {
+                    let texture_id = mesh_textures.next().unwrap();
                     let index_buffer_slice = index_buffer_slices.next().unwrap();
                     let vertex_buffer_slice = vertex_buffer_slices.next().unwrap();
 
-                    if let Some(Texture { bind_group, .. }) = self.textures.get(&mesh.texture_id) {
+                    if let Some(Texture { bind_group, .. }) = self.textures.get(texture_id) {
                         render_pass.set_bind_group(1, bind_group, &[]);
                         render_pass.set_index_buffer(
                             self.index_buffer.buffer.slice(
@@ -486,9 +555,11 @@ impl Renderer {
                                 vertex_buffer_slice.start as u64..vertex_buffer_slice.end as u64,
                             ),
                         );
-                        render_pass.draw_indexed(0..mesh.indices.len() as u32, 0, 0..1);
+                        let num_indices = (index_buffer_slice.end - index_buffer_slice.start)
+                            / std::mem::size_of::<u32>();
+                        render_pass.draw_indexed(0..num_indices as u32, 0, 0..1);
                     } else {
-                        log::warn!("Missing texture: {:?}", mesh.texture_id);
+                        log::warn!("Missing texture: {texture_id:?}");
                     }
                 }
                 Primitive::Callback(callback) => {
@@ -502,6 +573,9 @@ impl Renderer {
                         clip_rect: *clip_rect,
                         pixels_per_point,
                         screen_size_px: size_in_pixels,
+                        transform: callback.transform,
+                        has_depth_buffer: self.has_depth_buffer,
+                        msaa_samples: self.msaa_samples,
                     };
 
                     let viewport_px = info.viewport_in_pixels();
@@ -510,6 +584,14 @@ impl Renderer {
 
                         needs_reset = true;
 
+                        if callback.depth_buffer {
+                            if let Some(depth_clear_pipeline) = &self.depth_clear_pipeline {
+                                // The scissor rect is set, so this only clears inside the clip rect:
+                                render_pass.set_pipeline(depth_clear_pipeline);
+                                render_pass.draw(0..3, 0..1);
+                            }
+                        }
+
                         // We're setting a default viewport for the render pass as a
                         // courtesy for the user, so that they don't have to think about
                         // it in the simple case where they just want to fill the whole
@@ -555,17 +637,17 @@ impl Renderer {
             depth_or_array_layers: 1,
         };
 
-        let data_color32 = match &image_delta.image {
+        let image = match &image_delta.image {
             epaint::ImageData::Color(image) => {
                 assert_eq!(
                     width as usize * height as usize,
                     image.pixels.len(),
                     "Mismatch between texture size and texel count"
                 );
-                Cow::Borrowed(&image.pixels)
+                image
             }
         };
-        let data_bytes: &[u8] = bytemuck::cast_slice(data_color32.as_slice());
+        let data_bytes: &[u8] = bytemuck::cast_slice(image.pixels.as_slice());
 
         let queue_write_data_to_texture = |texture, origin| {
             profiling::scope!("write_texture");
@@ -623,10 +705,15 @@ impl Renderer {
             // allocate a new texture
             let texture = {
                 profiling::scope!("create_texture");
+                let mip_level_count = if image_delta.options.mipmap_mode.is_some() {
+                    size.max_mips(wgpu::TextureDimension::D2)
+                } else {
+                    1
+                };
                 device.create_texture(&wgpu::TextureDescriptor {
                     label,
                     size,
-                    mip_level_count: 1,
+                    mip_level_count,
                     sample_count: 1,
                     dimension: wgpu::TextureDimension::D2,
                     format: wgpu::TextureFormat::Rgba8Unorm,
@@ -662,6 +749,9 @@ impl Renderer {
         });
 
         queue_write_data_to_texture(&texture, origin);
+        if 1 < texture.mip_level_count() {
+            write_mipmaps(queue, &texture, origin, image);
+        }
         self.textures.insert(
             id,
             Texture {
@@ -859,14 +949,23 @@ impl Renderer {
             self.previous_uniform_buffer_content = uniform_buffer_content;
         }
 
-        // Determine how many vertices & indices need to be rendered, and gather prepare callbacks
+        // Gather the meshes to render, and the prepare callbacks.
+        // We have no instanced, SDF, or blur draw path, so those fall back to plain meshes,
+        // which are built once here and then uploaded.
         let mut callbacks = Vec::new();
-        let (vertex_count, index_count) = {
-            profiling::scope!("count_vertices_indices");
-            paint_jobs.iter().fold((0, 0), |acc, clipped_primitive| {
-                match &clipped_primitive.primitive {
-                    Primitive::Mesh(mesh) => {
-                        (acc.0 + mesh.vertices.len(), acc.1 + mesh.indices.len())
+        let meshes: Vec<Cow<'_, Mesh>> = {
+            profiling::scope!("gather_meshes");
+            let pixels_per_point = screen_descriptor.pixels_per_point;
+            paint_jobs
+                .iter()
+                .filter_map(|clipped_primitive| match &clipped_primitive.primitive {
+                    Primitive::Mesh(mesh) => Some(Cow::Borrowed(mesh)),
+                    Primitive::Instanced(instanced) => Some(Cow::Owned(instanced.to_mesh())),
+                    Primitive::SdfRect(sdf_rect) => {
+                        Some(Cow::Owned(sdf_rect.to_mesh(pixels_per_point)))
+                    }
+                    Primitive::BackdropBlur(blur) => {
+                        Some(Cow::Owned(blur.to_fallback_mesh(pixels_per_point)))
                     }
                     Primitive::Callback(callback) => {
                         if let Some(c) = callback.callback.downcast_ref::<Callback>() {
@@ -874,11 +973,17 @@ impl Renderer {
                         } else {
                             log::warn!("Unknown paint callback: expected `egui_wgpu::Callback`");
                         };
-                        acc
+                        None
                     }
-                }
-            })
+                })
+                .collect()
         };
+        let vertex_count: usize = meshes.iter().map(|mesh| mesh.vertices.len()).sum();
+        let index_count: usize = meshes.iter().map(|mesh| mesh.indices.len()).sum();
+
+        self.mesh_textures.clear();
+        self.mesh_textures
+            .extend(meshes.iter().map(|mesh| mesh.texture_id));
 
         if index_count > 0 {
             profiling::scope!("indices", index_count.to_string().as_str());
@@ -908,18 +1013,13 @@ impl Renderer {
             };
 
             let mut index_offset = 0;
-            for epaint::ClippedPrimitive { primitive, .. } in paint_jobs {
-                match primitive {
-                    Primitive::Mesh(mesh) => {
-                        let size = mesh.indices.len() * std::mem::size_of::<u32>();
-                        let slice = index_offset..(size + index_offset);
-                        index_buffer_staging[slice.clone()]
-                            .copy_from_slice(bytemuck::cast_slice(&mesh.indices));
-                        self.index_buffer.slices.push(slice);
-                        index_offset += size;
-                    }
-                    Primitive::Callback(_) => {}
-                }
+            for mesh in &meshes {
+                let size = mesh.indices.len() * std::mem::size_of::<u32>();
+                let slice = index_offset..(size + index_offset);
+                index_buffer_staging[slice.clone()]
+                    .copy_from_slice(bytemuck::cast_slice(&mesh.indices));
+                self.index_buffer.slices.push(slice);
+                index_offset += size;
             }
         }
         if vertex_count > 0 {
@@ -951,18 +1051,13 @@ impl Renderer {
             };
 
             let mut vertex_offset = 0;
-            for epaint::ClippedPrimitive { primitive, .. } in paint_jobs {
-                match primitive {
-                    Primitive::Mesh(mesh) => {
-                        let size = mesh.vertices.len() * std::mem::size_of::<Vertex>();
-                        let slice = vertex_offset..(size + vertex_offset);
-                        vertex_buffer_staging[slice.clone()]
-                            .copy_from_slice(bytemuck::cast_slice(&mesh.vertices));
-                        self.vertex_buffer.slices.push(slice);
-                        vertex_offset += size;
-                    }
-                    Primitive::Callback(_) => {}
-                }
+            for mesh in &meshes {
+                let size = mesh.vertices.len() * std::mem::size_of::<Vertex>();
+                let slice = vertex_offset..(size + vertex_offset);
+                vertex_buffer_staging[slice.clone()]
+                    .copy_from_slice(bytemuck::cast_slice(&mesh.vertices));
+                self.vertex_buffer.slices.push(slice);
+                vertex_offset += size;
             }
         }
 
@@ -995,6 +1090,59 @@ impl Renderer {
     }
 }
 
+/// Fill in the mip levels of the region of `texture` that was just updated with `image`,
+/// by downscaling it on the CPU.
+///
+/// The edges of regions that don't start at even coordinates are approximate.
+fn write_mipmaps(
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    origin: wgpu::Origin3d,
+    image: &epaint::ColorImage,
+) {
+    profiling::function_scope!();
+
+    let mut level_image = image.downscale_half();
+    let [mut x, mut y] = [origin.x, origin.y];
+    for mip_level in 1..texture.mip_level_count() {
+        x /= 2;
+        y /= 2;
+        let extent = texture
+            .size()
+            .mip_level_size(mip_level, texture.dimension());
+        let width = (level_image.width() as u32).min(extent.width.saturating_sub(x));
+        let height = (level_image.height() as u32).min(extent.height.saturating_sub(y));
+        if width == 0 || height == 0 {
+            break;
+        }
+        if [width as usize, height as usize] != level_image.size {
+            level_image = level_image.region_by_pixels([0, 0], [width as usize, height as usize]);
+        }
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level,
+                origin: wgpu::Origin3d { x, y, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(level_image.pixels.as_slice()),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * width),
+                rows_per_image: Some(height),
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        level_image = level_image.downscale_half();
+    }
+}
+
 fn create_sampler(
     options: epaint::textures::TextureOptions,
     device: &wgpu::Device,
@@ -1012,12 +1160,17 @@ fn create_sampler(
         epaint::textures::TextureWrapMode::Repeat => wgpu::AddressMode::Repeat,
         epaint::textures::TextureWrapMode::MirroredRepeat => wgpu::AddressMode::MirrorRepeat,
     };
+    let mipmap_filter = match options.mipmap_mode {
+        None | Some(epaint::textures::TextureFilter::Nearest) => wgpu::FilterMode::Nearest,
+        Some(epaint::textures::TextureFilter::Linear) => wgpu::FilterMode::Linear,
+    };
     device.create_sampler(&wgpu::SamplerDescriptor {
         label: Some(&format!(
-            "egui sampler (mag: {mag_filter:?}, min {min_filter:?})"
+            "egui sampler (mag: {mag_filter:?}, min {min_filter:?}, mipmap {mipmap_filter:?})"
         )),
         mag_filter,
         min_filter,
+        mipmap_filter,
         address_mode_u: address_mode,
         address_mode_v: address_mode,
         ..Default::default()
@@ -72,6 +72,7 @@ impl Clipboard {
         if let Some(clipboard) = &mut self.arboard {
             return match clipboard.get_text() {
                 Ok(text) => Some(text),
+                Err(arboard::Error::ContentNotAvailable) => None, // e.g. an image
                 Err(err) => {
                     log::error!("arboard paste error: {err}");
                     None
@@ -109,6 +110,49 @@ impl Clipboard {
         self.clipboard = text;
     }
 
+    /// Fetches an image from the clipboard, if there is one.
+    pub fn get_image(&mut self) -> Option<egui::ColorImage> {
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get_image() {
+                Ok(image) => Some(egui::ColorImage::from_rgba_unmultiplied(
+                    [image.width, image.height],
+                    &image.bytes,
+                )),
+                Err(arboard::Error::ContentNotAvailable) => None,
+                Err(err) => {
+                    // Not necessarily a problem, e.g. if the clipboard has some other content:
+                    log::debug!("arboard image paste error: {err}");
+                    None
+                }
+            };
+        }
+
+        None
+    }
+
+    pub fn set_html(&mut self, html: String, alt_text: String) {
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        if let Some(clipboard) = &mut self.arboard {
+            if let Err(err) = clipboard.set_html(html, Some(alt_text)) {
+                log::error!("arboard copy/cut error: {err}");
+            }
+            return;
+        }
+
+        // Fall back to plain text:
+        _ = html;
+        self.set_text(alt_text);
+    }
+
+    pub fn set_files(&mut self, paths: &[std::path::PathBuf]) {
+        log::debug!("Copying file lists is not supported by egui-winit; copying the paths as text");
+        let text = egui::ClipboardContent::Files(paths.to_vec())
+            .to_text()
+            .unwrap_or_default();
+        self.set_text(text);
+    }
+
     pub fn set_image(&mut self, image: &egui::ColorImage) {
         #[cfg(all(feature = "arboard", not(target_os = "android")))]
         if let Some(clipboard) = &mut self.arboard {
@@ -190,6 +190,26 @@ impl State {
         self.clipboard.get()
     }
 
+    /// Pastes what is on the clipboard as an [`egui::Event::Paste`] event,
+    /// or as an [`egui::Event::PasteContent`] image if there is no text on the clipboard.
+    pub fn paste(&mut self) {
+        if let Some(contents) = self.clipboard.get() {
+            let contents = contents.replace("\r\n", "\n");
+            if !contents.is_empty() {
+                self.egui_input.events.push(egui::Event::Paste(contents));
+                return;
+            }
+        }
+        // Only look for an image if there is no text, since decoding it can be slow:
+        if let Some(image) = self.clipboard.get_image() {
+            self.egui_input
+                .events
+                .push(egui::Event::PasteContent(egui::ClipboardContent::Image(
+                    image,
+                )));
+        }
+    }
+
     /// Places the text onto the clipboard.
     pub fn set_clipboard_text(&mut self, text: String) {
         self.clipboard.set_text(text);
@@ -269,6 +289,21 @@ impl State {
     ) -> EventResponse {
         profiling::function_scope!(short_window_event_description(event));
 
+        let response = self.on_window_event_impl(window, event);
+
+        // Timestamp the new events, so egui can measure the input latency:
+        let now = self.start_time.elapsed().as_secs_f64();
+        let num_events = self.egui_input.events.len();
+        self.egui_input.event_times.resize(num_events, now);
+
+        response
+    }
+
+    fn on_window_event_impl(
+        &mut self,
+        window: &Window,
+        event: &winit::event::WindowEvent,
+    ) -> EventResponse {
         #[cfg(feature = "accesskit")]
         if let Some(accesskit) = self.accesskit.as_mut() {
             accesskit.process_event(window, event);
@@ -772,12 +807,7 @@ impl State {
                     self.egui_input.events.push(egui::Event::Copy);
                     return;
                 } else if is_paste_command(self.egui_input.modifiers, active_key) {
-                    if let Some(contents) = self.clipboard.get() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            self.egui_input.events.push(egui::Event::Paste(contents));
-                        }
-                    }
+                    self.paste();
                     return;
                 }
             }
@@ -837,12 +867,14 @@ impl State {
             open_url,
             copied_text,
             events: _,                    // handled elsewhere
+            announcements: _,             // exposed as AccessKit live regions
             mutable_text_under_cursor: _, // only used in eframe web
             ime,
             #[cfg(feature = "accesskit")]
             accesskit_update,
             num_completed_passes: _,    // `egui::Context::run` handles this
             request_discard_reasons: _, // `egui::Context::run` handles this
+            drop_effect: _,             // winit has no way to set the drop effect
         } = platform_output;
 
         for command in commands {
@@ -853,6 +885,12 @@ impl State {
                 egui::OutputCommand::CopyImage(image) => {
                     self.clipboard.set_image(&image);
                 }
+                egui::OutputCommand::CopyHtml { html, alt_text } => {
+                    self.clipboard.set_html(html, alt_text);
+                }
+                egui::OutputCommand::CopyFiles(paths) => {
+                    self.clipboard.set_files(&paths);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     open_url_in_browser(&open_url.url);
                 }
@@ -878,7 +916,8 @@ impl State {
 
         if let Some(ime) = ime {
             let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
-            let ime_rect_px = pixels_per_point * ime.rect;
+            // Place the candidate window next to the text cursor, not the whole `TextEdit`:
+            let ime_rect_px = pixels_per_point * ime.cursor_rect;
             if self.ime_rect_px != Some(ime_rect_px)
                 || self.egui_ctx.input(|i| !i.events.is_empty())
             {
@@ -0,0 +1,248 @@
+//! Describing custom widgets to assistive technologies, see [`AccessibilityNode`].
+
+use crate::WidgetType;
+
+/// Something an assistive technology can ask a widget to do.
+///
+/// Declare the supported actions with [`AccessibilityNode::action`],
+/// and check for requests with [`crate::Response::accessibility_action_requested`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessibilityAction {
+    /// Activate the widget, like a mouse click would.
+    Click,
+
+    /// Give the widget keyboard focus.
+    Focus,
+
+    /// Increase the value of e.g. a slider by one step.
+    Increment,
+
+    /// Decrease the value of e.g. a slider by one step.
+    Decrement,
+
+    /// Set the value directly.
+    SetValue,
+
+    /// Expand e.g. a menu or a tree node.
+    Expand,
+
+    /// Collapse e.g. a menu or a tree node.
+    Collapse,
+
+    /// Scroll so that the widget becomes visible.
+    ScrollIntoView,
+}
+
+impl AccessibilityAction {
+    /// The corresponding [`accesskit::Action`].
+    #[cfg(feature = "accesskit")]
+    pub fn accesskit_action(self) -> accesskit::Action {
+        use accesskit::Action;
+
+        match self {
+            Self::Click => Action::Click,
+            Self::Focus => Action::Focus,
+            Self::Increment => Action::Increment,
+            Self::Decrement => Action::Decrement,
+            Self::SetValue => Action::SetValue,
+            Self::Expand => Action::Expand,
+            Self::Collapse => Action::Collapse,
+            Self::ScrollIntoView => Action::ScrollIntoView,
+        }
+    }
+}
+
+/// Builder for the accessibility information of a widget.
+///
+/// You get one from [`crate::Response::accessibility`].
+/// Everything set here overrides what the widget itself reported with [`crate::Response::widget_info`].
+///
+/// When egui is compiled without the `accesskit` feature all methods are no-ops,
+/// so code using this works the same with and without it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let (rating, max_rating) = (3, 5);
+/// let response = ui.label("★".repeat(rating));
+/// response.accessibility(|node| {
+///     node.role(egui::WidgetType::Slider)
+///         .label("Rating")
+///         .value(format!("{rating} of {max_rating} stars"))
+///         .numeric_value(rating as f64)
+///         .numeric_range(0.0..=max_rating as f64)
+///         .numeric_step(1.0)
+///         .action(egui::AccessibilityAction::Increment)
+///         .action(egui::AccessibilityAction::Decrement);
+/// });
+/// # });
+/// ```
+pub struct AccessibilityNode<'a> {
+    #[cfg(feature = "accesskit")]
+    node: &'a mut accesskit::Node,
+
+    #[cfg(not(feature = "accesskit"))]
+    _node: std::marker::PhantomData<&'a mut ()>,
+}
+
+impl<'a> AccessibilityNode<'a> {
+    #[cfg(feature = "accesskit")]
+    pub(crate) fn new(node: &'a mut accesskit::Node) -> Self {
+        Self { node }
+    }
+
+    /// The underlying [`accesskit::Node`], for anything not covered by this builder.
+    #[cfg(feature = "accesskit")]
+    pub fn accesskit_node(&mut self) -> &mut accesskit::Node {
+        self.node
+    }
+
+    /// What kind of widget this is.
+    #[inline]
+    pub fn role(&mut self, role: WidgetType) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_role(role.accesskit_role());
+        #[cfg(not(feature = "accesskit"))]
+        let _ = role;
+        self
+    }
+
+    /// The text a screen reader reads as the name of the widget.
+    #[inline]
+    pub fn label(&mut self, label: impl Into<String>) -> &mut Self {
+        let label = label.into();
+        #[cfg(feature = "accesskit")]
+        self.node.set_label(label);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = label;
+        self
+    }
+
+    /// The current value as text, e.g. `"50%"` for a slider.
+    #[inline]
+    pub fn value(&mut self, value: impl Into<String>) -> &mut Self {
+        let value = value.into();
+        #[cfg(feature = "accesskit")]
+        self.node.set_value(value);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = value;
+        self
+    }
+
+    /// The current value, for widgets with a numeric value.
+    #[inline]
+    pub fn numeric_value(&mut self, value: f64) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_numeric_value(value);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = value;
+        self
+    }
+
+    /// The smallest and largest allowed numeric value.
+    #[inline]
+    pub fn numeric_range(&mut self, range: std::ops::RangeInclusive<f64>) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        {
+            self.node.set_min_numeric_value(*range.start());
+            self.node.set_max_numeric_value(*range.end());
+        }
+        #[cfg(not(feature = "accesskit"))]
+        let _ = range;
+        self
+    }
+
+    /// How much [`AccessibilityAction::Increment`] and [`AccessibilityAction::Decrement`] change the numeric value.
+    #[inline]
+    pub fn numeric_step(&mut self, step: f64) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_numeric_value_step(step);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = step;
+        self
+    }
+
+    /// Whether e.g. a menu or a tree node is expanded.
+    #[inline]
+    pub fn expanded(&mut self, expanded: bool) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_expanded(expanded);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = expanded;
+        self
+    }
+
+    /// Whether e.g. a list item or a table row is selected.
+    #[inline]
+    pub fn selected(&mut self, selected: bool) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_selected(selected);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = selected;
+        self
+    }
+
+    /// The position of this item among its siblings, e.g. "item 2 of 5" in a menu.
+    ///
+    /// `position` starts at 1.
+    #[inline]
+    pub fn position_in_set(&mut self, position: usize, size: usize) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        {
+            self.node.set_position_in_set(position);
+            self.node.set_size_of_set(size);
+        }
+        #[cfg(not(feature = "accesskit"))]
+        let _ = (position, size);
+        self
+    }
+
+    /// The row of this cell or row in its table, starting at 0.
+    #[inline]
+    pub fn row_index(&mut self, row_index: usize) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_row_index(row_index);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = row_index;
+        self
+    }
+
+    /// The column of this cell in its table, starting at 0.
+    #[inline]
+    pub fn column_index(&mut self, column_index: usize) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_column_index(column_index);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = column_index;
+        self
+    }
+
+    /// The number of rows in this table.
+    #[inline]
+    pub fn row_count(&mut self, row_count: usize) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_row_count(row_count);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = row_count;
+        self
+    }
+
+    /// The number of columns in this table.
+    #[inline]
+    pub fn column_count(&mut self, column_count: usize) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.set_column_count(column_count);
+        #[cfg(not(feature = "accesskit"))]
+        let _ = column_count;
+        self
+    }
+
+    /// Declare that the widget supports this action.
+    #[inline]
+    pub fn action(&mut self, action: AccessibilityAction) -> &mut Self {
+        #[cfg(feature = "accesskit")]
+        self.node.add_action(action.accesskit_action());
+        #[cfg(not(feature = "accesskit"))]
+        let _ = action;
+        self
+    }
+}
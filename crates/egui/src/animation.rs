@@ -0,0 +1,227 @@
+//! Tweens and keyframed animations of values.
+//!
+//! See [`crate::Context::animate_tween`] and [`crate::Context::animate_keyframes`].
+//! For easing functions, see [`emath::easing`].
+
+use crate::{Color32, Pos2, Rect, Rgba, Vec2, emath};
+
+/// A value that can be animated by interpolating between two values.
+pub trait Animatable: Clone + PartialEq + Send + Sync + 'static {
+    /// Interpolate from `from` (at `t = 0`) to `to` (at `t = 1`).
+    ///
+    /// `t` can be outside `[0, 1]` for easing functions that overshoot,
+    /// like [`emath::easing::spring`].
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self;
+}
+
+impl Animatable for f32 {
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self {
+        emath::lerp(*from..=*to, t)
+    }
+}
+
+impl Animatable for Vec2 {
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self {
+        *from + (*to - *from) * t
+    }
+}
+
+impl Animatable for Pos2 {
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self {
+        *from + (*to - *from) * t
+    }
+}
+
+impl Animatable for Rect {
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self {
+        Self::from_min_max(from.min.lerp(to.min, t), from.max.lerp(to.max, t))
+    }
+}
+
+impl Animatable for Color32 {
+    /// Interpolates in gamma space. Colors don't overshoot.
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self {
+        from.lerp_to_gamma(*to, t.clamp(0.0, 1.0))
+    }
+}
+
+impl Animatable for Rgba {
+    /// Interpolates in linear space. Colors don't overshoot.
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        *from * (1.0 - t) + *to * t
+    }
+}
+
+/// How to animate from one value to another: how long, after what delay, and along what easing curve.
+///
+/// ```
+/// # use egui::animation::Tween;
+/// let tween = Tween::new(0.3).with_easing(egui::emath::easing::cubic_out);
+/// assert_eq!(tween.progress(0.0), 0.0);
+/// assert_eq!(tween.progress(0.3), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Tween {
+    /// How long the animation takes, in seconds, not counting [`Self::delay`].
+    pub duration: f32,
+
+    /// How long to wait before starting, in seconds.
+    pub delay: f32,
+
+    /// Maps linear progress in `[0, 1]` to eased progress, see [`emath::easing`].
+    pub easing: fn(f32) -> f32,
+}
+
+impl Tween {
+    /// A linear animation taking `duration` seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            delay: 0.0,
+            easing: emath::easing::linear,
+        }
+    }
+
+    /// Use e.g. [`emath::easing::cubic_out`] for a responsive start and a slow end,
+    /// or [`emath::easing::spring`] for a bit of bounce.
+    #[inline]
+    pub fn with_easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Wait this many seconds before starting.
+    #[inline]
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// The delay plus the duration, in seconds.
+    pub fn total_duration(&self) -> f32 {
+        self.delay + self.duration
+    }
+
+    /// The eased progress, `elapsed` seconds after the tween was started.
+    ///
+    /// This is `0` before and during the delay, and `1` once done.
+    pub fn progress(&self, elapsed: f32) -> f32 {
+        let time = elapsed - self.delay;
+        if time.is_nan() || time <= 0.0 {
+            0.0
+        } else if self.duration <= time {
+            1.0
+        } else {
+            (self.easing)(time / self.duration)
+        }
+    }
+}
+
+/// A chain of tweens from one value to the next, e.g. to pulse, shake, or fade in and out.
+///
+/// ```
+/// # use egui::animation::{Keyframes, Tween};
+/// let pulse = Keyframes::new(1.0_f32)
+///     .then(1.2, Tween::new(0.1))
+///     .then(1.0, Tween::new(0.3).with_easing(egui::emath::easing::spring));
+/// assert_eq!(pulse.duration(), 0.4);
+/// assert_eq!(pulse.value_at(0.1), 1.2);
+/// ```
+///
+/// See [`crate::Context::animate_keyframes`].
+#[derive(Clone, Debug)]
+pub struct Keyframes<T> {
+    start: T,
+    steps: Vec<(Tween, T)>,
+    looping: bool,
+}
+
+impl<T: Animatable> Keyframes<T> {
+    /// Start at this value.
+    pub fn new(start: T) -> Self {
+        Self {
+            start,
+            steps: vec![],
+            looping: false,
+        }
+    }
+
+    /// After the previous keyframes, animate to `value` using `tween`.
+    #[inline]
+    pub fn then(mut self, value: T, tween: Tween) -> Self {
+        self.steps.push((tween, value));
+        self
+    }
+
+    /// Start over once done, forever.
+    ///
+    /// For a seamless loop, end with the value you started with.
+    #[inline]
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Does this start over once done?
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// The total duration of all the steps, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.steps
+            .iter()
+            .map(|(tween, _)| tween.total_duration())
+            .sum()
+    }
+
+    /// The value this many seconds after the start.
+    pub fn value_at(&self, time: f32) -> T {
+        let duration = self.duration();
+        let mut time = if self.looping && 0.0 < duration {
+            time.rem_euclid(duration)
+        } else {
+            time
+        };
+
+        let mut from = &self.start;
+        for (tween, to) in &self.steps {
+            if time < tween.total_duration() {
+                return T::lerp(from, to, tween.progress(time));
+            }
+            time -= tween.total_duration();
+            from = to;
+        }
+        from.clone()
+    }
+}
+
+/// How contents appear and disappear, see [`crate::Ui::show_animated_if`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Transition {
+    /// Fade in and out.
+    Fade,
+
+    /// Slide down into place when appearing, and back up when disappearing.
+    Slide,
+
+    /// Fade in while sliding down into place, e.g. for rows and panels.
+    #[default]
+    FadeSlide,
+
+    /// Fade in while growing from slightly smaller, e.g. for popups.
+    FadeScale,
+
+    /// Grow and shrink in height, like a [`crate::CollapsingHeader`],
+    /// moving whatever comes after it.
+    Collapse,
+}
+
+impl Transition {
+    /// Does this change the opacity?
+    pub fn fades(self) -> bool {
+        matches!(self, Self::Fade | Self::FadeSlide | Self::FadeScale)
+    }
+}
@@ -1,12 +1,25 @@
+use std::sync::Arc;
+
 use crate::{
-    Id, IdMap, InputState,
-    emath::{NumExt as _, remap_clamp},
+    Context, Id, IdMap, InputState,
+    animation::{Animatable, Keyframes, Tween},
+    emath::NumExt as _,
+    util::IdTypeMap,
 };
 
+/// A callback for [`crate::Context::on_animation_done`].
+pub(crate) type AnimationDoneCallback = Arc<dyn Fn(&Context) + Send + Sync>;
+
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
-    values: IdMap<ValueAnim>,
+
+    /// [`TweenAnim`]s of different types.
+    tweens: IdTypeMap,
+
+    keyframes: IdMap<KeyframesAnim>,
+
+    pub done_callbacks: IdMap<AnimationDoneCallback>,
 }
 
 #[derive(Clone, Debug)]
@@ -16,13 +29,36 @@ struct BoolAnim {
 }
 
 #[derive(Clone, Debug)]
-struct ValueAnim {
-    from_value: f32,
+struct TweenAnim<T> {
+    from_value: T,
 
-    to_value: f32,
+    to_value: T,
 
-    /// when did `value` last toggle?
+    /// when did `to_value` last change?
     toggle_time: f64,
+
+    tween: Tween,
+
+    done: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct KeyframesAnim {
+    start_time: f64,
+    done: bool,
+}
+
+/// What happened to an animation this frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AnimationState {
+    /// Not moving.
+    Idle,
+
+    /// Still moving, so we need to repaint.
+    Running,
+
+    /// Reached its end, so we should call the [`AnimationManager::done_callbacks`].
+    JustFinished,
 }
 
 impl AnimationManager {
@@ -65,46 +101,190 @@ impl AnimationManager {
         }
     }
 
-    pub fn animate_value(
+    /// See [`crate::Context::animate_tween`] for documentation
+    pub fn animate_tween<T: Animatable>(
         &mut self,
         input: &InputState,
-        animation_time: f32,
         id: Id,
-        value: f32,
-    ) -> f32 {
-        match self.values.get_mut(&id) {
-            None => {
-                self.values.insert(
-                    id,
-                    ValueAnim {
-                        from_value: value,
-                        to_value: value,
-                        toggle_time: -f64::INFINITY, // long time ago
-                    },
-                );
-                value
-            }
-            Some(anim) => {
-                let time_since_toggle = (input.time - anim.toggle_time) as f32;
-                // On the frame we toggle we don't want to return the old value,
-                // so we extrapolate forwards by half a frame:
-                let time_since_toggle = time_since_toggle + input.predicted_dt / 2.0;
-                let current_value = remap_clamp(
-                    time_since_toggle,
-                    0.0..=animation_time,
-                    anim.from_value..=anim.to_value,
-                );
-                if anim.to_value != value {
-                    anim.from_value = current_value; //start new animation from current position of playing animation
-                    anim.to_value = value;
-                    anim.toggle_time = input.time;
-                }
-                if animation_time == 0.0 {
-                    anim.from_value = value;
-                    anim.to_value = value;
-                }
-                current_value
+        value: T,
+        tween: Tween,
+    ) -> (T, AnimationState) {
+        let anim = self.tweens.get_temp_mut_or_insert_with(id, || TweenAnim {
+            from_value: value.clone(),
+            to_value: value.clone(),
+            toggle_time: -f64::INFINITY, // long time ago
+            tween,
+            done: true,
+        });
+
+        let time_since_toggle = (input.time - anim.toggle_time) as f32;
+        // On the frame we toggle we don't want to return the old value,
+        // so we extrapolate forwards by half a frame:
+        let current_value = T::lerp(
+            &anim.from_value,
+            &anim.to_value,
+            anim.tween
+                .progress(time_since_toggle + input.predicted_dt / 2.0),
+        );
+
+        if anim.to_value != value {
+            anim.from_value = current_value.clone(); //start new animation from current position of playing animation
+            anim.to_value = value.clone();
+            anim.toggle_time = input.time;
+            anim.tween = tween;
+            anim.done = false;
+        }
+        if tween.total_duration() <= 0.0 {
+            anim.from_value = value.clone();
+            anim.to_value = value;
+            anim.toggle_time = -f64::INFINITY;
+        }
+
+        let state = if anim.done {
+            AnimationState::Idle
+        } else if current_value != anim.to_value
+            || ((input.time - anim.toggle_time) as f32) < anim.tween.total_duration()
+        {
+            AnimationState::Running
+        } else {
+            anim.done = true;
+            AnimationState::JustFinished
+        };
+
+        (current_value, state)
+    }
+
+    /// See [`crate::Context::animate_keyframes`] for documentation
+    pub fn animate_keyframes<T: Animatable>(
+        &mut self,
+        input: &InputState,
+        id: Id,
+        keyframes: &Keyframes<T>,
+        reduced_motion: bool,
+    ) -> (T, AnimationState) {
+        let anim = self.keyframes.entry(id).or_insert(KeyframesAnim {
+            start_time: input.time,
+            done: false,
+        });
+
+        if reduced_motion {
+            // Jump straight to the end, and don't play looping animations at all:
+            if keyframes.is_looping() {
+                return (keyframes.value_at(0.0), AnimationState::Idle);
             }
+            let value = keyframes.value_at(f32::INFINITY);
+            let state = if anim.done {
+                AnimationState::Idle
+            } else {
+                anim.done = true;
+                AnimationState::JustFinished
+            };
+            return (value, state);
         }
+
+        let time = (input.time - anim.start_time) as f32;
+        let value = keyframes.value_at(time);
+
+        let state = if anim.done {
+            AnimationState::Idle
+        } else if keyframes.is_looping() || time < keyframes.duration() {
+            AnimationState::Running
+        } else {
+            anim.done = true;
+            AnimationState::JustFinished
+        };
+
+        (value, state)
+    }
+
+    /// Forget everything about the animations with this id.
+    pub fn reset(&mut self, id: Id) {
+        self.bools.remove(&id);
+        self.keyframes.remove(&id);
+        self.tweens.retain(|entry| entry.id != Some(id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::{Keyframes, RawInput, Tween};
+
+    use super::*;
+
+    #[test]
+    fn tween_and_keyframes() {
+        let ctx = Context::default();
+        let id = Id::new("tween");
+        let done = Arc::new(AtomicUsize::new(0));
+        let keyframes = Keyframes::new(0.0_f32).then(1.0, Tween::new(1.0));
+
+        let run = |time: f64, target: f32| {
+            let mut values = (0.0, 0.0);
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                },
+                |ctx| {
+                    let done = done.clone();
+                    ctx.on_animation_done(id, move |_| {
+                        done.fetch_add(1, Ordering::Relaxed);
+                    });
+                    values = (
+                        ctx.animate_tween(id, target, Tween::new(1.0)),
+                        ctx.animate_keyframes(Id::new("keyframes"), &keyframes),
+                    );
+                },
+            );
+            values
+        };
+
+        assert_eq!(run(0.0, 0.0), (0.0, 0.0), "Starts at the target");
+        assert_eq!(run(1.0, 10.0).0, 0.0, "Starts moving");
+
+        let (tween, keyframe) = run(1.5, 10.0);
+        assert!((5.0..5.2).contains(&tween), "Half-way: {tween}");
+        assert_eq!(keyframe, 1.0, "Keyframes are done");
+        assert_eq!(done.load(Ordering::Relaxed), 0);
+
+        assert_eq!(run(2.5, 10.0).0, 10.0);
+        assert_eq!(done.load(Ordering::Relaxed), 1, "Called when done");
+        assert_eq!(run(3.0, 10.0).0, 10.0);
+        assert_eq!(done.load(Ordering::Relaxed), 1, "Only called once");
+    }
+
+    #[test]
+    fn reduced_motion_from_system() {
+        let ctx = Context::default();
+        let keyframes = Keyframes::new(0.0_f32).then(1.0, Tween::new(1.0));
+
+        let run = |time: f64, open: bool| {
+            let mut values = (0.0, 0.0, 0.0);
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    reduced_motion: Some(true),
+                    ..Default::default()
+                },
+                |ctx| {
+                    values = (
+                        ctx.animate_bool(Id::new("bool"), open),
+                        ctx.animate_tween(
+                            Id::new("tween"),
+                            if open { 10.0 } else { 0.0 },
+                            Tween::new(1.0),
+                        ),
+                        ctx.animate_keyframes(Id::new("keyframes"), &keyframes),
+                    );
+                },
+            );
+            values
+        };
+
+        assert_eq!(run(0.0, false), (0.0, 0.0, 1.0));
+        assert!(ctx.style().reduced_motion, "Follows the system preference");
+        assert_eq!(run(0.1, true), (1.0, 10.0, 1.0), "Jumps to the target");
     }
 }
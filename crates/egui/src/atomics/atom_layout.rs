@@ -136,6 +136,7 @@ impl<'a> AtomLayout<'a> {
     }
 
     /// [`AtomLayout::allocate`] and [`AllocatedAtomLayout::paint`] in one go.
+    #[track_caller]
     pub fn show(self, ui: &mut Ui) -> AtomLayoutResponse {
         self.allocate(ui).paint(ui)
     }
@@ -143,6 +144,7 @@ impl<'a> AtomLayout<'a> {
     /// Calculate sizes, create [`Galley`]s and allocate a [`Response`].
     ///
     /// Use the returned [`AllocatedAtomLayout`] for painting.
+    #[track_caller]
     pub fn allocate(self, ui: &mut Ui) -> AllocatedAtomLayout<'a> {
         let Self {
             id,
@@ -386,7 +388,13 @@ impl<'atom> AllocatedAtomLayout<'atom> {
             align2.align_size_within_rect(desired_size, inner_rect)
         };
 
-        let mut cursor = aligned_rect.left();
+        // In right-to-left mode the first atom goes on the right:
+        let rtl = ui.layout_direction().is_rtl();
+        let mut cursor = if rtl {
+            aligned_rect.right()
+        } else {
+            aligned_rect.left()
+        };
 
         let mut response = AtomLayoutResponse::empty(response);
 
@@ -396,10 +404,19 @@ impl<'atom> AllocatedAtomLayout<'atom> {
             // https://github.com/emilk/egui/pull/5830#discussion_r2079627864
             let growth = if sized.is_grow() { grow_width } else { 0.0 };
 
-            let frame = aligned_rect
-                .with_min_x(cursor)
-                .with_max_x(cursor + size.x + growth);
-            cursor = frame.right() + gap;
+            let frame = if rtl {
+                let frame = aligned_rect
+                    .with_max_x(cursor)
+                    .with_min_x(cursor - size.x - growth);
+                cursor = frame.left() - gap;
+                frame
+            } else {
+                let frame = aligned_rect
+                    .with_min_x(cursor)
+                    .with_max_x(cursor + size.x + growth);
+                cursor = frame.right() + gap;
+                frame
+            };
 
             let align = Align2::CENTER_CENTER;
             let rect = align.align_size_within_rect(size, frame);
@@ -459,6 +476,7 @@ impl AtomLayoutResponse {
 }
 
 impl Widget for AtomLayout<'_> {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         self.show(ui).response
     }
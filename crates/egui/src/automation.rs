@@ -0,0 +1,600 @@
+//! Drive an egui app without a windowing backend, e.g. for integration tests.
+//!
+//! See [`Harness`].
+
+use crate::{
+    CentralPanel, Context, Event, FullOutput, Id, Key, KeyboardShortcut, LayerId, Modifiers,
+    PointerButton, Pos2, RawInput, Rect, Ui, Vec2, ViewportId, WidgetInfo, WidgetType,
+};
+
+/// A widget found by a [`Harness`] query, as it was at the end of the last frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetNode {
+    pub id: Id,
+
+    pub layer_id: LayerId,
+
+    /// Where the widget is on the screen, in points.
+    pub rect: Rect,
+
+    /// Where the widget reacts to the pointer, on the screen, in points.
+    pub interact_rect: Rect,
+
+    /// What the widget reported about itself with [`crate::Response::widget_info`],
+    /// e.g. its label, whether it is selected and its value.
+    ///
+    /// Just the [`WidgetType::Other`] and whether it is enabled, for widgets that don't report anything.
+    pub info: WidgetInfo,
+}
+
+impl WidgetNode {
+    /// The label of the widget, if it has one.
+    pub fn label(&self) -> Option<&str> {
+        self.info.label.as_deref()
+    }
+
+    /// Is the widget enabled?
+    pub fn is_enabled(&self) -> bool {
+        self.info.enabled
+    }
+
+    /// Is the checkbox, radio button or selectable label selected?
+    pub fn is_selected(&self) -> Option<bool> {
+        self.info.selected
+    }
+
+    /// The text of a [`crate::TextEdit`].
+    pub fn text(&self) -> Option<&str> {
+        self.info.current_text_value.as_deref()
+    }
+
+    /// The value of a slider, drag value, etc.
+    pub fn value(&self) -> Option<f64> {
+        self.info.value
+    }
+}
+
+/// Builds a [`Harness`].
+#[derive(Clone, Debug)]
+pub struct HarnessBuilder {
+    size: Vec2,
+    pixels_per_point: f32,
+    step_dt: f32,
+    max_steps: usize,
+}
+
+impl Default for HarnessBuilder {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(800.0, 600.0),
+            pixels_per_point: 1.0,
+            step_dt: 1.0 / 60.0,
+            max_steps: 4,
+        }
+    }
+}
+
+impl HarnessBuilder {
+    /// The size of the screen, in points. Default: `800x600`.
+    #[inline]
+    pub fn with_size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Default: `1.0`.
+    #[inline]
+    pub fn with_pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// How much time passes each frame, in seconds. Default: `1/60`.
+    #[inline]
+    pub fn with_step_dt(mut self, step_dt: f32) -> Self {
+        self.step_dt = step_dt;
+        self
+    }
+
+    /// The most frames [`Harness::run`] runs while the app keeps asking for a repaint. Default: `4`.
+    #[inline]
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Build a [`Harness`] for an app that adds its own panels and windows, and run it until it settles.
+    pub fn build<'a>(self, mut app: impl FnMut(&Context) + 'a) -> Harness<'a> {
+        self.build_state(move |ctx, _: &mut ()| app(ctx), ())
+    }
+
+    /// Build a [`Harness`] for ui code that is shown in a [`CentralPanel`], and run it until it settles.
+    pub fn build_ui<'a>(self, mut app: impl FnMut(&mut Ui) + 'a) -> Harness<'a> {
+        self.build_ui_state(move |ui, _: &mut ()| app(ui), ())
+    }
+
+    /// Like [`Self::build`], with some state the app can change, and the test can check.
+    pub fn build_state<'a, State>(
+        self,
+        app: impl FnMut(&Context, &mut State) + 'a,
+        state: State,
+    ) -> Harness<'a, State> {
+        let Self {
+            size,
+            pixels_per_point,
+            step_dt,
+            max_steps,
+        } = self;
+
+        let ctx = Context::default();
+        ctx.options_mut(|options| options.record_widget_info = true);
+
+        let mut harness = Harness {
+            ctx,
+            app: Box::new(app),
+            state,
+            input: RawInput::default(),
+            output: FullOutput::default(),
+            screen_rect: Rect::from_min_size(Pos2::ZERO, size),
+            pixels_per_point,
+            time: 0.0,
+            step_dt,
+            max_steps,
+        };
+        harness.run();
+        harness
+    }
+
+    /// Like [`Self::build_ui`], with some state the ui can change, and the test can check.
+    pub fn build_ui_state<'a, State>(
+        self,
+        mut app: impl FnMut(&mut Ui, &mut State) + 'a,
+        state: State,
+    ) -> Harness<'a, State> {
+        self.build_state(
+            move |ctx, state| {
+                CentralPanel::default().show(ctx, |ui| app(ui, state));
+            },
+            state,
+        )
+    }
+}
+
+/// Runs an egui app frame by frame without a windowing backend,
+/// finds its widgets, and acts like a user would.
+///
+/// Widgets are found by their label, [`Id`] or [`WidgetType`] (or accesskit role),
+/// as they were at the end of the last frame.
+/// The actions, like [`Self::click`] and [`Self::type_text`], run frames until the app has settled,
+/// so their effect can be checked right away.
+///
+/// This needs no extra dependencies, so apps can write integration tests with it directly.
+/// For screenshot tests, see the `egui_kittest` crate.
+///
+/// ```
+/// use egui::automation::Harness;
+///
+/// let mut harness = Harness::new_ui_state(
+///     |ui, checked: &mut bool| {
+///         ui.checkbox(checked, "Check me");
+///     },
+///     false,
+/// );
+///
+/// let checkbox = harness.get_by_label("Check me");
+/// harness.click(&checkbox);
+/// assert!(*harness.state());
+/// assert_eq!(harness.get_by_label("Check me").is_selected(), Some(true));
+/// ```
+pub struct Harness<'a, State = ()> {
+    ctx: Context,
+    app: Box<dyn FnMut(&Context, &mut State) + 'a>,
+    state: State,
+    input: RawInput,
+    output: FullOutput,
+    screen_rect: Rect,
+    pixels_per_point: f32,
+    time: f64,
+    step_dt: f32,
+    max_steps: usize,
+}
+
+impl<'a> Harness<'a> {
+    /// See [`HarnessBuilder`].
+    pub fn builder() -> HarnessBuilder {
+        HarnessBuilder::default()
+    }
+
+    /// See [`HarnessBuilder::build`].
+    pub fn new(app: impl FnMut(&Context) + 'a) -> Self {
+        Self::builder().build(app)
+    }
+
+    /// See [`HarnessBuilder::build_ui`].
+    pub fn new_ui(app: impl FnMut(&mut Ui) + 'a) -> Self {
+        Self::builder().build_ui(app)
+    }
+}
+
+impl<'a, State> Harness<'a, State> {
+    /// See [`HarnessBuilder::build_state`].
+    pub fn new_state(app: impl FnMut(&Context, &mut State) + 'a, state: State) -> Self {
+        Harness::builder().build_state(app, state)
+    }
+
+    /// See [`HarnessBuilder::build_ui_state`].
+    pub fn new_ui_state(app: impl FnMut(&mut Ui, &mut State) + 'a, state: State) -> Self {
+        Harness::builder().build_ui_state(app, state)
+    }
+
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// The output of the last frame.
+    pub fn output(&self) -> &FullOutput {
+        &self.output
+    }
+
+    /// The input of the next frame, to add any events the actions of the harness don't cover.
+    pub fn input_mut(&mut self) -> &mut RawInput {
+        &mut self.input
+    }
+
+    // ------------------------------------------------------------------------
+    // Running:
+
+    /// Run a single frame, with the input added since the last one.
+    pub fn step(&mut self) {
+        self.time += self.step_dt as f64;
+
+        let mut input = self.input.take();
+        input.time = Some(self.time);
+        input.predicted_dt = self.step_dt;
+        input.screen_rect = Some(self.screen_rect);
+        input
+            .viewports
+            .entry(ViewportId::ROOT)
+            .or_default()
+            .native_pixels_per_point = Some(self.pixels_per_point);
+
+        let Self { app, state, .. } = self;
+        self.output = self.ctx.run(input, |ctx| app(ctx, state));
+    }
+
+    /// Run frames until the app doesn't ask for another one right away,
+    /// but at most [`HarnessBuilder::with_max_steps`] frames.
+    ///
+    /// Returns how many frames were run.
+    pub fn run(&mut self) -> usize {
+        for steps in 1..=self.max_steps {
+            self.step();
+            if !self.wants_repaint() {
+                return steps;
+            }
+        }
+        self.max_steps
+    }
+
+    /// Run frames until `condition` is true, but at most `max_steps` frames.
+    ///
+    /// Returns whether `condition` became true, e.g. for waiting for an animation to end.
+    pub fn run_until(
+        &mut self,
+        max_steps: usize,
+        mut condition: impl FnMut(&Self) -> bool,
+    ) -> bool {
+        for _ in 0..max_steps {
+            if condition(self) {
+                return true;
+            }
+            self.step();
+        }
+        condition(self)
+    }
+
+    fn wants_repaint(&self) -> bool {
+        self.output
+            .viewport_output
+            .get(&ViewportId::ROOT)
+            .is_some_and(|viewport| viewport.repaint_delay.is_zero())
+    }
+
+    // ------------------------------------------------------------------------
+    // Queries:
+
+    /// All widgets of the last frame, back to front.
+    pub fn widgets(&self) -> Vec<WidgetNode> {
+        let ctx = &self.ctx;
+        let widgets = ctx.pass_state(|pass| pass.widgets.clone());
+        let mut layers: Vec<LayerId> = widgets.layer_ids().collect();
+        ctx.memory(|mem| layers.sort_by(|&a, &b| mem.areas().compare_order(a, b)));
+
+        layers
+            .into_iter()
+            .flat_map(|layer_id| {
+                let to_global = ctx.layer_transform_to_global(layer_id).unwrap_or_default();
+                widgets
+                    .get_layer(layer_id)
+                    .map(|w| {
+                        let mut info = widgets
+                            .info(w.id)
+                            .cloned()
+                            .unwrap_or_else(|| WidgetInfo::new(WidgetType::Other));
+                        info.enabled &= w.enabled;
+                        WidgetNode {
+                            id: w.id,
+                            layer_id,
+                            rect: to_global * w.rect,
+                            interact_rect: to_global * w.interact_rect,
+                            info,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// All widgets of the last frame for which `predicate` is true, back to front.
+    pub fn query_all(&self, mut predicate: impl FnMut(&WidgetNode) -> bool) -> Vec<WidgetNode> {
+        self.widgets()
+            .into_iter()
+            .filter(|node| predicate(node))
+            .collect()
+    }
+
+    /// The widget for which `predicate` is true, if there is one.
+    ///
+    /// Panics if there are several, with `description` in the message.
+    fn query_one(
+        &self,
+        description: &str,
+        predicate: impl FnMut(&WidgetNode) -> bool,
+    ) -> Option<WidgetNode> {
+        let mut nodes = self.query_all(predicate);
+        assert!(
+            nodes.len() <= 1,
+            "Found {} widgets with {description}: {nodes:#?}",
+            nodes.len()
+        );
+        nodes.pop()
+    }
+
+    fn get_one(&self, description: &str, predicate: impl FnMut(&WidgetNode) -> bool) -> WidgetNode {
+        self.query_one(description, predicate)
+            .unwrap_or_else(|| panic!("Found no widget with {description}"))
+    }
+
+    /// The widget with exactly this label, if there is one.
+    ///
+    /// Panics if there are several.
+    pub fn query_by_label(&self, label: &str) -> Option<WidgetNode> {
+        self.query_one(&format!("label {label:?}"), |node| {
+            node.label() == Some(label)
+        })
+    }
+
+    /// The widget with exactly this label.
+    ///
+    /// Panics if there is none, or several.
+    pub fn get_by_label(&self, label: &str) -> WidgetNode {
+        self.get_one(&format!("label {label:?}"), |node| {
+            node.label() == Some(label)
+        })
+    }
+
+    /// The widget with this [`Id`], if there is one.
+    pub fn query_by_id(&self, id: Id) -> Option<WidgetNode> {
+        self.query_one(&format!("id {id:?}"), |node| node.id == id)
+    }
+
+    /// The widget with this [`Id`].
+    ///
+    /// Panics if there is none.
+    pub fn get_by_id(&self, id: Id) -> WidgetNode {
+        self.get_one(&format!("id {id:?}"), |node| node.id == id)
+    }
+
+    /// The widget of this type, if there is one.
+    ///
+    /// Panics if there are several.
+    pub fn query_by_type(&self, typ: WidgetType) -> Option<WidgetNode> {
+        self.query_one(&format!("type {typ:?}"), |node| node.info.typ == typ)
+    }
+
+    /// The widget of this type.
+    ///
+    /// Panics if there is none, or several.
+    pub fn get_by_type(&self, typ: WidgetType) -> WidgetNode {
+        self.get_one(&format!("type {typ:?}"), |node| node.info.typ == typ)
+    }
+
+    /// The widget with this accesskit role, if there is one.
+    ///
+    /// Panics if there are several.
+    #[cfg(feature = "accesskit")]
+    pub fn query_by_role(&self, role: accesskit::Role) -> Option<WidgetNode> {
+        self.query_one(&format!("role {role:?}"), |node| {
+            node.info.typ.accesskit_role() == role
+        })
+    }
+
+    /// The widget with this accesskit role.
+    ///
+    /// Panics if there is none, or several.
+    #[cfg(feature = "accesskit")]
+    pub fn get_by_role(&self, role: accesskit::Role) -> WidgetNode {
+        self.get_one(&format!("role {role:?}"), |node| {
+            node.info.typ.accesskit_role() == role
+        })
+    }
+
+    /// Has this widget keyboard focus?
+    pub fn is_focused(&self, node: &WidgetNode) -> bool {
+        self.ctx.memory(|mem| mem.has_focus(node.id))
+    }
+
+    // ------------------------------------------------------------------------
+    // Actions:
+
+    fn push_event(&mut self, event: Event) {
+        self.input.events.push(event);
+    }
+
+    fn pointer_button(&mut self, pos: Pos2, button: PointerButton, pressed: bool) {
+        let modifiers = self.input.modifiers;
+        self.push_event(Event::PointerButton {
+            pos,
+            button,
+            pressed,
+            modifiers,
+        });
+    }
+
+    /// Move the pointer over the widget, and run until the app has settled.
+    pub fn hover(&mut self, node: &WidgetNode) {
+        self.push_event(Event::PointerMoved(node.interact_rect.center()));
+        self.run();
+    }
+
+    /// Click the widget with the primary button, and run until the app has settled.
+    pub fn click(&mut self, node: &WidgetNode) {
+        self.click_by(node, PointerButton::Primary);
+    }
+
+    /// Click the widget with the given button, and run until the app has settled.
+    pub fn click_by(&mut self, node: &WidgetNode, button: PointerButton) {
+        let pos = node.interact_rect.center();
+        self.push_event(Event::PointerMoved(pos));
+        self.pointer_button(pos, button, true);
+        self.pointer_button(pos, button, false);
+        self.run();
+    }
+
+    /// Drag the widget with the primary button by `delta`, and run until the app has settled.
+    ///
+    /// This takes several frames, for the drag to start, move, and stop.
+    pub fn drag(&mut self, node: &WidgetNode, delta: Vec2) {
+        let start = node.interact_rect.center();
+        self.push_event(Event::PointerMoved(start));
+        self.pointer_button(start, PointerButton::Primary, true);
+        self.step();
+
+        let num_moves = 4;
+        for i in 1..=num_moves {
+            let pos = start + delta * (i as f32 / num_moves as f32);
+            self.push_event(Event::PointerMoved(pos));
+            self.step();
+        }
+
+        self.pointer_button(start + delta, PointerButton::Primary, false);
+        self.run();
+    }
+
+    /// Give the widget keyboard focus, and run until the app has settled.
+    pub fn focus(&mut self, node: &WidgetNode) {
+        self.ctx.memory_mut(|mem| mem.request_focus(node.id));
+        self.run();
+    }
+
+    /// Type text into the focused widget, and run until the app has settled.
+    pub fn type_text(&mut self, text: &str) {
+        self.push_event(Event::Text(text.to_owned()));
+        self.run();
+    }
+
+    /// Press and release a key, and run until the app has settled.
+    pub fn key_press(&mut self, key: Key) {
+        self.key_combo(&KeyboardShortcut::new(Modifiers::NONE, key));
+    }
+
+    /// Press and release a key while holding down the modifiers, e.g. `Cmd+S`,
+    /// and run until the app has settled.
+    pub fn key_combo(&mut self, shortcut: &KeyboardShortcut) {
+        let KeyboardShortcut {
+            modifiers,
+            logical_key: key,
+        } = *shortcut;
+        let previous_modifiers = self.input.modifiers;
+        self.input.modifiers = modifiers;
+        for pressed in [true, false] {
+            self.push_event(Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers,
+            });
+        }
+        self.step();
+        self.input.modifiers = previous_modifiers;
+        self.run();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Harness;
+    use crate::{Key, KeyboardShortcut, Modifiers, Slider, TextEdit, WidgetType, vec2};
+
+    #[test]
+    fn test_click_type_and_drag() {
+        #[derive(Default)]
+        struct State {
+            clicks: usize,
+            name: String,
+            volume: f32,
+            saved: bool,
+        }
+
+        let mut harness = Harness::new_ui_state(
+            |ui, state: &mut State| {
+                if ui.button("Click me").clicked() {
+                    state.clicks += 1;
+                }
+                ui.add(TextEdit::singleline(&mut state.name));
+                ui.add(Slider::new(&mut state.volume, 0.0..=100.0).text("Volume"));
+                if ui.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::S)) {
+                    state.saved = true;
+                }
+            },
+            State::default(),
+        );
+
+        let button = harness.get_by_label("Click me");
+        assert_eq!(button.info.typ, WidgetType::Button);
+        harness.click(&button);
+        harness.click(&button);
+        assert_eq!(harness.state().clicks, 2);
+        #[cfg(feature = "accesskit")]
+        assert_eq!(harness.get_by_role(accesskit::Role::Button).id, button.id);
+
+        let text_edit = harness.get_by_type(WidgetType::TextEdit);
+        harness.click(&text_edit);
+        assert!(harness.is_focused(&text_edit));
+        harness.type_text("egui");
+        assert_eq!(harness.state().name, "egui");
+        assert_eq!(
+            harness.get_by_type(WidgetType::TextEdit).text(),
+            Some("egui")
+        );
+
+        let slider = harness.get_by_type(WidgetType::Slider);
+        harness.drag(&slider, vec2(1000.0, 0.0));
+        assert_eq!(harness.state().volume, 100.0);
+        assert_eq!(harness.get_by_type(WidgetType::Slider).value(), Some(100.0));
+
+        harness.key_combo(&KeyboardShortcut::new(Modifiers::COMMAND, Key::S));
+        assert!(harness.state().saved);
+
+        assert_eq!(harness.query_by_label("Nothing"), None);
+    }
+}
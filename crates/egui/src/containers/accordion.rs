@@ -0,0 +1,134 @@
+//! A group of collapsing sections of which only one is open at a time, see [`Accordion`].
+
+use crate::{CollapsingHeader, CollapsingResponse, Id, InnerResponse, Ui, WidgetText};
+
+/// A group of [`CollapsingHeader`]s of which at most one is open:
+/// opening one section closes the others.
+///
+/// Which section is open is persisted in [`crate::Memory`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Accordion::new("settings")
+///     .default_open(Some(0))
+///     .show(ui, |accordion| {
+///         accordion.section("General", |ui| {
+///             ui.label("General settings");
+///         });
+///         accordion.section("Appearance", |ui| {
+///             ui.label("Appearance settings");
+///         });
+///         accordion.section("Advanced", |ui| {
+///             ui.label("Advanced settings");
+///         });
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct Accordion {
+    id_salt: Id,
+    default_open: Option<usize>,
+}
+
+impl Accordion {
+    /// Create a new [`Accordion`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            default_open: None,
+        }
+    }
+
+    /// The index of the section that is open at first.
+    ///
+    /// Default: `None` (all closed).
+    #[inline]
+    pub fn default_open(mut self, index: Option<usize>) -> Self {
+        self.default_open = index;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut AccordionUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            default_open,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let open = ui
+            .data_mut(|d| d.get_persisted::<Option<usize>>(id))
+            .unwrap_or(default_open);
+
+        ui.vertical(|ui| {
+            let mut accordion = AccordionUi {
+                ui,
+                id,
+                open,
+                new_open: open,
+                num_sections: 0,
+            };
+            let inner = add_contents(&mut accordion);
+
+            let AccordionUi { ui, new_open, .. } = accordion;
+            if new_open != open {
+                // The sections above the clicked one have already been shown,
+                // so show all of them again with the right one open:
+                ui.ctx().request_discard("Accordion section opened");
+            }
+            ui.data_mut(|d| d.insert_persisted(id, new_open));
+            inner
+        })
+    }
+}
+
+/// Adds sections to an [`Accordion`], see [`Accordion::show`].
+pub struct AccordionUi<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+
+    /// The open section, at the start of the frame.
+    open: Option<usize>,
+
+    /// The open section, after the user interactions of this frame.
+    new_open: Option<usize>,
+
+    num_sections: usize,
+}
+
+impl AccordionUi<'_> {
+    /// The index of the open section, if any.
+    pub fn open_index(&self) -> Option<usize> {
+        self.new_open
+    }
+
+    /// Open the section with this index (and close the others), or close all with `None`.
+    pub fn set_open_index(&mut self, index: Option<usize>) {
+        self.new_open = index;
+    }
+
+    /// Add a section, with a clickable header that opens or closes it.
+    pub fn section<R>(
+        &mut self,
+        heading: impl Into<WidgetText>,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let index = self.num_sections;
+        self.num_sections += 1;
+
+        let is_open = self.open == Some(index);
+        let response = CollapsingHeader::new(heading)
+            .id_salt(self.id.with(index))
+            .open(Some(is_open))
+            .show(self.ui, add_body);
+
+        if response.header_response.clicked() {
+            self.new_open = if is_open { None } else { Some(index) };
+        }
+        response
+    }
+}
@@ -0,0 +1,101 @@
+//! Animate items to their new place when the layout changes, see [`AnimatedLayout`].
+
+use crate::{Id, InnerResponse, Tween, Ui, UiBuilder};
+
+/// Slides items to their new position when it changes, e.g. because items were
+/// inserted, removed, reordered or filtered out, instead of jumping there.
+///
+/// Each item is added with [`AnimatedLayoutUi::item`], with a key that identifies it
+/// across frames (e.g. its index in your data, *before* sorting or filtering).
+/// The items are laid out as usual in the [`Ui`] of the [`AnimatedLayout`],
+/// only what is painted moves from the previous position to the new one over
+/// [`crate::style::Style::animation_time`].
+///
+/// Only the painting is animated: while moving, the items react to the pointer at their new position.
+/// When an item changes size, it grows or shrinks smoothly, and so the items after it move smoothly too.
+///
+/// This is a shortcut for giving each item a [`UiBuilder::layout_transition`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut names = vec!["Charlie".to_owned(), "Alice".to_owned(), "Bob".to_owned()];
+/// if ui.button("Sort").clicked() {
+///     names.sort();
+/// }
+/// egui::AnimatedLayout::new("names").show(ui, |layout| {
+///     for name in &names {
+///         layout.item(name, |ui| {
+///             ui.label(name);
+///         });
+///     }
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct AnimatedLayout {
+    id_salt: Id,
+    animation_time: Option<f32>,
+}
+
+impl AnimatedLayout {
+    /// Create a new [`AnimatedLayout`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            animation_time: None,
+        }
+    }
+
+    /// How long it takes an item to get to its new position, in seconds.
+    ///
+    /// Default: [`crate::style::Style::animation_time`].
+    #[inline]
+    pub fn animation_time(mut self, animation_time: f32) -> Self {
+        self.animation_time = Some(animation_time);
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut AnimatedLayoutUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            animation_time,
+        } = self;
+        let animation_time = animation_time.unwrap_or_else(|| ui.style().animation_time);
+
+        ui.push_id(id_salt, |ui| {
+            add_contents(&mut AnimatedLayoutUi { ui, animation_time })
+        })
+    }
+}
+
+/// Adds items to an [`AnimatedLayout`], see [`AnimatedLayout::show`].
+pub struct AnimatedLayoutUi<'a> {
+    /// The [`Ui`] the items are added to, to add anything that shouldn't be animated.
+    pub ui: &'a mut Ui,
+
+    animation_time: f32,
+}
+
+impl AnimatedLayoutUi<'_> {
+    /// Add an item, which slides to its new position when it changes.
+    ///
+    /// The `key` must be unique within the [`AnimatedLayout`], and stay the same across frames.
+    /// It is also used as the [`Id`] salt of the contents, so their state follows the item.
+    pub fn item<R>(
+        &mut self,
+        key: impl std::hash::Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.ui.scope_builder(
+            UiBuilder::new()
+                .id_salt(key)
+                .layout_transition(Tween::new(self.animation_time)),
+            add_contents,
+        )
+    }
+}
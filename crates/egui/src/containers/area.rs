@@ -400,6 +400,14 @@ pub(crate) struct Prepared {
 
     fade_in: bool,
     layout: Layout,
+
+    /// Can we move the area to keep its focused widget above an on-screen keyboard?
+    ///
+    /// Not if the position is set by [`Area::anchor`] or [`Area::current_pos`] every frame.
+    avoid_keyboard: bool,
+
+    /// Times the layout of the area for the frame profiler, until dropped in [`Self::end`].
+    _profile_scope: Option<crate::profiler::ProfileScopeGuard>,
 }
 
 impl Area {
@@ -526,7 +534,18 @@ impl Area {
             );
 
             if movable && move_response.dragged() {
-                if let Some(pivot_pos) = &mut state.pivot_pos {
+                if let Some(grid) = layout_grid(ctx) {
+                    // Keep track of where the area would be without snapping,
+                    // so that it moves on once the pointer has moved far enough:
+                    let free_id = interact_id.with("unsnapped");
+                    let free_pos = ctx
+                        .data(|d| d.get_temp::<Pos2>(free_id))
+                        .filter(|_| !move_response.drag_started())
+                        .unwrap_or_else(|| state.left_top_pos())
+                        + move_response.drag_delta();
+                    ctx.data_mut(|d| d.insert_temp(free_id, free_pos));
+                    state.set_left_top_pos(snap_to_grid(free_pos, grid));
+                } else if let Some(pivot_pos) = &mut state.pivot_pos {
                     *pivot_pos += move_response.drag_delta();
                 }
             }
@@ -554,7 +573,15 @@ impl Area {
         move_response.rect = state.rect();
         move_response.interact_rect = state.rect();
 
+        let _profile_scope = ctx.is_profiling().then(|| {
+            let kind = info
+                .kind
+                .map_or_else(|| "Area".to_owned(), |kind| format!("{kind:?}"));
+            ctx.profile_scope(format!("{kind} {:?}", layer_id.id))
+        });
+
         Prepared {
+            _profile_scope,
             info: Some(info),
             layer_id,
             state,
@@ -565,6 +592,7 @@ impl Area {
             sizing_pass,
             fade_in,
             layout,
+            avoid_keyboard: anchor.is_none() && new_pos.is_none(),
         }
     }
 }
@@ -606,7 +634,7 @@ impl Prepared {
         let mut ui = Ui::new(ctx.clone(), self.layer_id.id, ui_builder);
         ui.set_clip_rect(self.constrain_rect); // Don't paint outside our bounds
 
-        if self.fade_in {
+        if self.fade_in && !ctx.style().reduced_motion {
             if let Some(last_became_visible_at) = self.state.last_became_visible_at {
                 let age =
                     ctx.input(|i| (i.time - last_became_visible_at) as f32 + i.predicted_dt / 2.0);
@@ -630,6 +658,10 @@ impl Prepared {
         self.move_response.id
     }
 
+    pub(crate) fn move_response(&self) -> &Response {
+        &self.move_response
+    }
+
     #[expect(clippy::needless_pass_by_value)] // intentional to swallow up `content_ui`.
     pub(crate) fn end(self, ctx: &Context, content_ui: Ui) -> Response {
         let Self {
@@ -638,11 +670,16 @@ impl Prepared {
             mut state,
             move_response: mut response,
             sizing_pass,
+            avoid_keyboard,
             ..
         } = self;
 
         state.size = Some(content_ui.min_size());
 
+        if avoid_keyboard && !sizing_pass {
+            move_focused_widget_above_keyboard(ctx, layer_id, &mut state);
+        }
+
         // Make sure we report back the correct size.
         // Very important after the initial sizing pass, when the initial estimate of the size is way off.
         let final_rect = state.rect();
@@ -666,6 +703,47 @@ impl Prepared {
     }
 }
 
+/// If the focused widget is in this area and hidden behind the on-screen keyboard,
+/// move the area up (but not past the top of the screen) so the widget can be seen.
+fn move_focused_widget_above_keyboard(ctx: &Context, layer_id: LayerId, state: &mut AreaState) {
+    let Some(keyboard_rect) = ctx.input(|i| i.virtual_keyboard_rect) else {
+        return;
+    };
+    let Some(widget) = ctx
+        .memory(|mem| mem.focused())
+        .and_then(|id| ctx.read_response(id))
+    else {
+        return;
+    };
+    if widget.layer_id != layer_id || !widget.interact_rect.is_positive() {
+        return;
+    }
+
+    let to_global = ctx.layer_transform_to_global(layer_id).unwrap_or_default();
+    let widget_rect = to_global * widget.interact_rect;
+    let overlap = widget_rect.bottom() + ctx.style().spacing.item_spacing.y - keyboard_rect.top();
+    let room_above = (to_global * state.rect()).top() - ctx.screen_rect().top();
+    let shift = overlap.min(room_above) / to_global.scaling;
+    if 0.5 <= shift {
+        if let Some(pivot_pos) = &mut state.pivot_pos {
+            pivot_pos.y -= shift;
+            ctx.request_repaint();
+        }
+    }
+}
+
+/// The spacing of [`crate::style::Interaction::layout_grid`],
+/// unless the user is holding down alt to place things freely.
+pub(crate) fn layout_grid(ctx: &Context) -> Option<f32> {
+    let grid = ctx.style().interaction.layout_grid?;
+    (0.0 < grid && !ctx.input(|i| i.modifiers.alt)).then_some(grid)
+}
+
+/// The closest point on a grid with the given spacing.
+pub(crate) fn snap_to_grid(pos: Pos2, grid: f32) -> Pos2 {
+    (pos / grid).round() * grid
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input(|i| i.pointer.any_pressed());
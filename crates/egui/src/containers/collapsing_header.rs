@@ -49,6 +49,22 @@ impl CollapsingState {
         self.id
     }
 
+    /// Is the collapsing region with this id open?
+    ///
+    /// `None` if it has not been shown yet.
+    /// For a [`CollapsingHeader`], the id is that of [`CollapsingResponse::header_response`].
+    pub fn is_open_by_id(ctx: &Context, id: Id) -> Option<bool> {
+        Self::load(ctx, id).map(|state| state.is_open())
+    }
+
+    /// Open or close the collapsing region with this id, e.g. to open a [`CollapsingHeader`]
+    /// that has not been shown yet.
+    pub fn set_open_by_id(ctx: &Context, id: Id, open: bool) {
+        let mut state = Self::load_with_default_open(ctx, id, open);
+        state.set_open(open);
+        state.store(ctx);
+    }
+
     pub fn load_with_default_open(ctx: &Context, id: Id, default_open: bool) -> Self {
         Self::load(ctx, id).unwrap_or(Self {
             id,
@@ -157,6 +173,11 @@ impl CollapsingState {
         ui: &mut Ui,
         add_header: impl FnOnce(&mut Ui) -> HeaderRet,
     ) -> HeaderResponse<'_, HeaderRet> {
+        if let Some(open) = requested_open(ui) {
+            if open != self.is_open() {
+                self.toggle(ui);
+            }
+        }
         let header_response = ui.horizontal(|ui| {
             let prev_item_spacing = ui.spacing_mut().item_spacing;
             ui.spacing_mut().item_spacing.x = 0.0; // the toggler button uses the full indent width
@@ -347,6 +368,55 @@ impl<HeaderRet> HeaderResponse<'_, HeaderRet> {
 
 // ----------------------------------------------------------------------------
 
+/// A request to open or close all collapsing headers in a [`Ui`], see [`Ui::set_all_collapsing_open`].
+#[derive(Clone, Copy, Debug)]
+struct OpenAllRequest {
+    /// Only headers in the [`Ui`] with this id, or anywhere if `None`.
+    scope: Option<Id>,
+    open: bool,
+    pass_nr: u64,
+}
+
+fn open_all_requests_id() -> Id {
+    Id::new("egui::collapsing_header::OpenAllRequest")
+}
+
+/// Open or close all [`CollapsingHeader`]s in the [`Ui`] with the `scope` id,
+/// or everywhere if `None`.
+///
+/// The request lasts for this pass and the next,
+/// so that it also reaches the headers that have already been shown this pass.
+pub(crate) fn request_all_open(ctx: &Context, scope: Option<Id>, open: bool) {
+    let pass_nr = ctx.cumulative_pass_nr();
+    ctx.data_mut(|d| {
+        let requests = d.get_temp_mut_or_default::<Vec<OpenAllRequest>>(open_all_requests_id());
+        requests.retain(|request| pass_nr <= request.pass_nr + 1);
+        requests.push(OpenAllRequest {
+            scope,
+            open,
+            pass_nr,
+        });
+    });
+    ctx.request_repaint();
+}
+
+/// Should the collapsing header shown in this `ui` be opened or closed,
+/// because of [`request_all_open`]?
+fn requested_open(ui: &Ui) -> Option<bool> {
+    let requests = ui.data(|d| d.get_temp::<Vec<OpenAllRequest>>(open_all_requests_id()))?;
+    let pass_nr = ui.ctx().cumulative_pass_nr();
+    requests
+        .iter()
+        .rev()
+        .filter(|request| pass_nr <= request.pass_nr + 1)
+        .find(|request| {
+            request
+                .scope
+                .is_none_or(|scope| ui.stack().iter().any(|frame| frame.id == scope))
+        })
+        .map(|request| request.open)
+}
+
 /// Paint the arrow icon that indicated if the region is open or not
 pub fn paint_default_icon(ui: &mut Ui, openness: f32, response: &Response) {
     let visuals = ui.style().interact(response);
@@ -563,7 +633,7 @@ impl CollapsingHeader {
         );
 
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
-        if let Some(open) = open {
+        if let Some(open) = open.or_else(|| requested_open(ui)) {
             if open != state.is_open() {
                 state.toggle(ui);
                 header_response.mark_changed();
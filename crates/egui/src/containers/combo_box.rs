@@ -339,7 +339,8 @@ fn combo_box_dyn<'c, R>(
     let close_behavior = close_behavior.unwrap_or(PopupCloseBehavior::CloseOnClick);
 
     let margin = ui.spacing().button_padding;
-    let button_response = button_frame(ui, button_id, is_popup_open, Sense::click(), |ui| {
+    let sense = ui.edit_sense(Sense::click());
+    let button_response = button_frame(ui, button_id, is_popup_open, sense, |ui| {
         let icon_spacing = ui.spacing().icon_spacing;
         let icon_size = Vec2::splat(ui.spacing().icon_width);
 
@@ -364,11 +365,17 @@ fn combo_box_dyn<'c, R>(
 
         let (_, rect) = ui.allocate_space(Vec2::new(actual_width, actual_height));
         let button_rect = ui.min_rect().expand2(ui.spacing().button_padding);
-        let response = ui.interact(button_rect, button_id, Sense::click());
+        let response = ui.interact(button_rect, button_id, ui.edit_sense(Sense::click()));
         // response.active |= is_popup_open;
 
         if ui.is_rect_visible(rect) {
-            let icon_rect = Align2::RIGHT_CENTER.align_size_within_rect(icon_size, rect);
+            // The icon goes at the end of the line, i.e. on the left in right-to-left mode:
+            let (icon_align, text_align) = if ui.layout_direction().is_rtl() {
+                (Align2::LEFT_CENTER, Align2::RIGHT_CENTER)
+            } else {
+                (Align2::RIGHT_CENTER, Align2::LEFT_CENTER)
+            };
+            let icon_rect = icon_align.align_size_within_rect(icon_size, rect);
             let visuals = if is_popup_open {
                 &ui.visuals().widgets.open
             } else {
@@ -386,7 +393,7 @@ fn combo_box_dyn<'c, R>(
                 paint_default_icon(ui.painter(), icon_rect.expand(visuals.expansion), visuals);
             }
 
-            let text_rect = Align2::LEFT_CENTER.align_size_within_rect(galley.size(), rect);
+            let text_rect = text_align.align_size_within_rect(galley.size(), rect);
             ui.painter()
                 .galley(text_rect.min, galley, visuals.text_color());
         }
@@ -0,0 +1,305 @@
+//! Ready-made modal dialogs: confirm, prompt, and alert.
+//!
+//! See [`Dialogs`].
+
+use std::{collections::VecDeque, marker::PhantomData, sync::Arc};
+
+use crate::{
+    Align, Button, Context, Id, Key, Layout, Modal, Modifiers, Response, RichText, Sides, TextEdit,
+};
+
+/// A handle to a dialog queued with [`Dialogs`].
+///
+/// Use [`Self::poll`] every frame to check if the user has answered the dialog.
+#[must_use = "You should call .poll() to get the result of the dialog"]
+pub struct DialogHandle<T> {
+    id: Id,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for DialogHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DialogHandle<T> {}
+
+impl<T> std::fmt::Debug for DialogHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DialogHandle")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> DialogHandle<T> {
+    /// The unique id of this dialog.
+    #[inline]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the result of the dialog, once the user has answered it.
+    ///
+    /// The result is only returned once.
+    /// Returns `None` while the dialog is still waiting in the queue or is being shown.
+    pub fn poll(&self, ctx: &Context) -> Option<T> {
+        ctx.data_mut(|d| d.remove_temp::<Answer<T>>(self.id))
+            .and_then(|answer| answer.0)
+    }
+
+    /// Is the dialog still waiting for an answer (either shown or queued)?
+    pub fn is_pending(&self, ctx: &Context) -> bool {
+        ctx.data(|d| {
+            d.get_temp::<DialogsState>(Id::NULL)
+                .is_some_and(|state| state.queue.iter().any(|dialog| dialog.id == self.id))
+        })
+    }
+}
+
+/// Wrapper so that the answer of a dialog can't collide with other data of the same type.
+#[derive(Clone)]
+struct Answer<T>(Option<T>);
+
+impl<T> Default for Answer<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+#[derive(Clone)]
+enum DialogKind {
+    Alert,
+    Confirm,
+    Prompt { text: String },
+}
+
+#[derive(Clone)]
+struct QueuedDialog {
+    id: Id,
+    title: String,
+    message: String,
+    kind: DialogKind,
+
+    /// Has the dialog been shown at least one frame?
+    shown: bool,
+}
+
+#[derive(Clone, Default)]
+struct DialogsState {
+    /// The first dialog is the one currently shown.
+    queue: VecDeque<QueuedDialog>,
+    next_id: u64,
+}
+
+/// Ready-made modal dialogs, accessed with [`Context::dialogs`].
+///
+/// Dialogs are queued, and shown one at a time, in the order they were opened.
+/// Each dialog is shown as a [`Modal`], blocking input to the rest of the UI,
+/// with keyboard focus kept inside the dialog.
+///
+/// Pressing `Enter` accepts the dialog, and pressing `Escape` (or clicking outside it) cancels it.
+///
+/// Opening a dialog returns a [`DialogHandle`], which you poll for the result:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut pending_delete = None;
+/// if ui.button("Delete").clicked() {
+///     pending_delete = Some(ui.ctx().dialogs().confirm("Delete file", "Are you sure?"));
+/// }
+/// if let Some(handle) = pending_delete {
+///     if let Some(confirmed) = handle.poll(ui.ctx()) {
+///         if confirmed {
+///             // delete the file
+///         }
+///         pending_delete = None;
+///     }
+/// }
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct Dialogs {
+    ctx: Context,
+}
+
+impl Dialogs {
+    pub(crate) fn new(ctx: Context) -> Self {
+        Self { ctx }
+    }
+
+    pub(crate) fn register(ctx: &Context) {
+        ctx.on_end_pass("dialogs_end_pass", Arc::new(Self::end_pass));
+    }
+
+    /// Show a message with an "OK" button.
+    pub fn alert(&self, title: impl Into<String>, message: impl Into<String>) -> DialogHandle<()> {
+        self.push(title.into(), message.into(), DialogKind::Alert)
+    }
+
+    /// Ask the user a yes/no question.
+    ///
+    /// The result is `true` if the user accepted, and `false` if they cancelled.
+    pub fn confirm(
+        &self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> DialogHandle<bool> {
+        self.push(title.into(), message.into(), DialogKind::Confirm)
+    }
+
+    /// Ask the user to enter a line of text, starting with `default_text`.
+    ///
+    /// The result is `None` if the user cancelled.
+    pub fn prompt(
+        &self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        default_text: impl Into<String>,
+    ) -> DialogHandle<Option<String>> {
+        let kind = DialogKind::Prompt {
+            text: default_text.into(),
+        };
+        self.push(title.into(), message.into(), kind)
+    }
+
+    /// Is any dialog currently shown or queued?
+    pub fn is_any_open(&self) -> bool {
+        self.ctx.data(|d| {
+            d.get_temp::<DialogsState>(Id::NULL)
+                .is_some_and(|state| !state.queue.is_empty())
+        })
+    }
+
+    fn push<T>(&self, title: String, message: String, kind: DialogKind) -> DialogHandle<T> {
+        let id = self.ctx.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<DialogsState>(Id::NULL);
+            let id = Id::new("egui_dialog").with(state.next_id);
+            state.next_id += 1;
+            state.queue.push_back(QueuedDialog {
+                id,
+                title,
+                message,
+                kind,
+                shown: false,
+            });
+            id
+        });
+        self.ctx.request_repaint();
+        DialogHandle {
+            id,
+            _marker: PhantomData,
+        }
+    }
+
+    fn end_pass(ctx: &Context) {
+        let Some(mut dialog) = ctx.data(|d| {
+            d.get_temp::<DialogsState>(Id::NULL)
+                .and_then(|state| state.queue.front().cloned())
+        }) else {
+            return;
+        };
+
+        let done = show_dialog(ctx, &mut dialog);
+
+        ctx.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<DialogsState>(Id::NULL);
+            if done {
+                state.queue.pop_front();
+            } else if let Some(front) = state.queue.front_mut() {
+                *front = dialog;
+            }
+        });
+
+        if done {
+            // Show the next dialog in the queue, if any:
+            ctx.request_repaint();
+        }
+    }
+}
+
+/// Returns `true` when the dialog has been answered, and the answer has been stored.
+fn show_dialog(ctx: &Context, dialog: &mut QueuedDialog) -> bool {
+    let QueuedDialog {
+        id,
+        title,
+        message,
+        kind,
+        shown,
+    } = dialog;
+    let id = *id;
+    let first_frame = !*shown;
+    *shown = true;
+
+    // `Some(true)` = accepted, `Some(false)` = cancelled.
+    let mut answer: Option<bool> = None;
+
+    let modal = Modal::new(id.with("modal")).show(ctx, |ui| {
+        ui.set_max_width(320.0);
+        ui.heading(title.as_str());
+        ui.add_space(4.0);
+        ui.label(message.as_str());
+        ui.add_space(8.0);
+
+        let mut initial_focus: Option<Response> = None;
+
+        if let DialogKind::Prompt { text } = kind {
+            let response = ui.add(TextEdit::singleline(text).desired_width(f32::INFINITY));
+            if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                answer = Some(true);
+            }
+            ui.add_space(8.0);
+            initial_focus = Some(response);
+        }
+
+        Sides::new().show(
+            ui,
+            |_ui| {},
+            |ui| {
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let ok = ui.add(Button::new(RichText::new("OK").strong()));
+                    if ok.clicked() {
+                        answer = Some(true);
+                    }
+                    if !matches!(kind, DialogKind::Alert) && ui.button("Cancel").clicked() {
+                        answer = Some(false);
+                    }
+                    initial_focus.get_or_insert(ok);
+                });
+            },
+        );
+
+        // Trap focus: keep it on one of the widgets of the dialog.
+        if let Some(initial_focus) = initial_focus {
+            let has_focus = ui.memory(|mem| mem.focused().is_some());
+            if first_frame || !has_focus {
+                initial_focus.request_focus();
+            }
+        }
+    });
+
+    if answer.is_none() {
+        let enter_pressed = modal.is_top_modal
+            && ctx.memory(|mem| mem.focused().is_none())
+            && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Enter));
+        if enter_pressed {
+            answer = Some(true);
+        } else if modal.should_close() {
+            answer = Some(false);
+        }
+    }
+
+    let Some(accepted) = answer else {
+        return false;
+    };
+
+    ctx.data_mut(|d| match kind {
+        DialogKind::Alert => d.insert_temp(id, Answer(Some(()))),
+        DialogKind::Confirm => d.insert_temp(id, Answer(Some(accepted))),
+        DialogKind::Prompt { text } => {
+            d.insert_temp(id, Answer(Some(accepted.then(|| std::mem::take(text)))));
+        }
+    });
+    true
+}
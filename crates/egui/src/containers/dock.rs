@@ -0,0 +1,825 @@
+//! Dockable tabs: tab groups that can be split, and tabs that can be dragged between them.
+//!
+//! See [`Dock`] and [`DockTree`].
+
+use std::hash::Hash;
+
+use emath::GuiRounding as _;
+
+use super::tab_bar::{Tab, tab_ui_button, tab_width};
+
+use crate::{
+    Align2, CursorIcon, Id, LayerId, NumExt as _, Order, Pos2, Rect, Response, Sense, Stroke,
+    StrokeKind, TextStyle, Ui, UiBuilder, Vec2, WidgetText, pos2, vec2,
+};
+
+/// The index of a node in a [`DockTree`].
+///
+/// The tree is a binary tree stored in a flat list:
+/// the children of node `i` are at `2i + 1` (left/top) and `2i + 2` (right/bottom).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NodeIndex(pub usize);
+
+impl NodeIndex {
+    pub const ROOT: Self = Self(0);
+
+    /// The left (or top) child.
+    #[inline]
+    pub fn left(self) -> Self {
+        Self(2 * self.0 + 1)
+    }
+
+    /// The right (or bottom) child.
+    #[inline]
+    pub fn right(self) -> Self {
+        Self(2 * self.0 + 2)
+    }
+
+    #[inline]
+    pub fn parent(self) -> Option<Self> {
+        (0 < self.0).then(|| Self((self.0 - 1) / 2))
+    }
+
+    /// The other child of our parent.
+    #[inline]
+    pub fn sibling(self) -> Option<Self> {
+        let parent = self.parent()?;
+        Some(if parent.left() == self {
+            parent.right()
+        } else {
+            parent.left()
+        })
+    }
+}
+
+/// Where to put new tabs when splitting a node of a [`DockTree`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// A node in a [`DockTree`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockNode<Tab> {
+    /// No node here.
+    Empty,
+
+    /// A group of tabs, of which the `active` one is shown.
+    Leaf { tabs: Vec<Tab>, active: usize },
+
+    /// The two children are shown side by side (if `horizontal`) or on top of each other.
+    ///
+    /// `fraction` is the share of the space given to the first (left/top) child.
+    Split { horizontal: bool, fraction: f32 },
+}
+
+impl<Tab> Default for DockNode<Tab> {
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+
+/// A node and all its descendants, taken out of a [`DockTree`].
+struct Subtree<Tab> {
+    node: DockNode<Tab>,
+    children: Option<Box<[Subtree<Tab>; 2]>>,
+}
+
+/// The layout of a [`Dock`]: a binary tree of splits, with groups of tabs at the leaves.
+///
+/// This is owned by your app, and can be serialized to persist the layout.
+///
+/// ```
+/// use egui::containers::dock::{DockSide, DockTree, NodeIndex};
+///
+/// let mut tree = DockTree::new(vec!["Scene", "Game"]);
+/// let inspector = tree.split(NodeIndex::ROOT, DockSide::Right, 0.75, vec!["Inspector"]);
+/// tree.split(inspector, DockSide::Bottom, 0.5, vec!["Assets", "Console"]);
+/// assert_eq!(tree.tabs().count(), 5);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DockTree<Tab> {
+    nodes: Vec<DockNode<Tab>>,
+
+    /// The tab group the user last interacted with.
+    focused: Option<NodeIndex>,
+}
+
+impl<Tab> DockTree<Tab> {
+    /// A single group with the given tabs.
+    pub fn new(tabs: Vec<Tab>) -> Self {
+        Self {
+            nodes: vec![DockNode::Leaf { tabs, active: 0 }],
+            focused: None,
+        }
+    }
+
+    /// Are there no tabs at all?
+    pub fn is_empty(&self) -> bool {
+        self.tabs().next().is_none()
+    }
+
+    /// The node at the given index, if any.
+    pub fn node(&self, index: NodeIndex) -> Option<&DockNode<Tab>> {
+        self.nodes
+            .get(index.0)
+            .filter(|node| !matches!(node, DockNode::Empty))
+    }
+
+    /// All non-empty nodes.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeIndex, &DockNode<Tab>)> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !matches!(node, DockNode::Empty))
+            .map(|(i, node)| (NodeIndex(i), node))
+    }
+
+    /// All tabs, in no particular order.
+    pub fn tabs(&self) -> impl Iterator<Item = &Tab> + '_ {
+        self.nodes.iter().flat_map(|node| match node {
+            DockNode::Leaf { tabs, .. } => tabs.as_slice(),
+            _ => &[],
+        })
+    }
+
+    /// All tabs, in no particular order.
+    pub fn tabs_mut(&mut self) -> impl Iterator<Item = &mut Tab> + '_ {
+        self.nodes.iter_mut().flat_map(|node| match node {
+            DockNode::Leaf { tabs, .. } => tabs.as_mut_slice(),
+            _ => &mut [],
+        })
+    }
+
+    /// Find the first tab matching the predicate.
+    ///
+    /// Returns the tab group and the index of the tab in it.
+    pub fn find_tab(&self, predicate: impl Fn(&Tab) -> bool) -> Option<(NodeIndex, usize)> {
+        self.nodes().find_map(|(index, node)| match node {
+            DockNode::Leaf { tabs, .. } => Some((index, tabs.iter().position(&predicate)?)),
+            _ => None,
+        })
+    }
+
+    /// The tab group the user last interacted with, if it still exists.
+    pub fn focused_leaf(&self) -> Option<NodeIndex> {
+        self.focused
+            .filter(|&index| matches!(self.node(index), Some(DockNode::Leaf { .. })))
+    }
+
+    /// Make the given tab the one shown in its group, and focus the group.
+    pub fn set_active_tab(&mut self, (index, tab_index): (NodeIndex, usize)) {
+        if let Some(DockNode::Leaf { tabs, active }) = self.nodes.get_mut(index.0) {
+            if tab_index < tabs.len() {
+                *active = tab_index;
+                self.focused = Some(index);
+            }
+        }
+    }
+
+    /// Add a tab to the focused tab group (or the first one), and make it active.
+    pub fn push_tab(&mut self, tab: Tab) {
+        let leaf = self.focused_leaf().or_else(|| {
+            self.nodes()
+                .find(|(_, node)| matches!(node, DockNode::Leaf { .. }))
+                .map(|(index, _)| index)
+        });
+        if let Some(DockNode::Leaf { tabs, active }) = leaf.and_then(|i| self.nodes.get_mut(i.0)) {
+            tabs.push(tab);
+            *active = tabs.len() - 1;
+        } else {
+            *self = Self::new(vec![tab]);
+        }
+    }
+
+    /// Split the given node, putting a new group with the given tabs on the given side of it.
+    ///
+    /// `fraction` is the share of the space the existing node keeps.
+    ///
+    /// Returns the index of the new tab group.
+    pub fn split(
+        &mut self,
+        index: NodeIndex,
+        side: DockSide,
+        fraction: f32,
+        tabs: Vec<Tab>,
+    ) -> NodeIndex {
+        let existing = self.take_subtree(index);
+        let (horizontal, new_first) = match side {
+            DockSide::Left => (true, true),
+            DockSide::Right => (true, false),
+            DockSide::Top => (false, true),
+            DockSide::Bottom => (false, false),
+        };
+        let fraction = fraction.clamp(0.0, 1.0);
+        self.set(
+            index,
+            DockNode::Split {
+                horizontal,
+                fraction: if new_first { 1.0 - fraction } else { fraction },
+            },
+        );
+
+        let (existing_index, new_index) = if new_first {
+            (index.right(), index.left())
+        } else {
+            (index.left(), index.right())
+        };
+        self.put_subtree(existing_index, existing);
+        self.set(new_index, DockNode::Leaf { tabs, active: 0 });
+        new_index
+    }
+
+    /// Remove a tab.
+    ///
+    /// If its group becomes empty, the group is removed, and its sibling takes its place.
+    pub fn remove_tab(&mut self, (index, tab_index): (NodeIndex, usize)) -> Option<Tab> {
+        let DockNode::Leaf { tabs, active } = self.nodes.get_mut(index.0)? else {
+            return None;
+        };
+        if tabs.len() <= tab_index {
+            return None;
+        }
+        let tab = tabs.remove(tab_index);
+        if tab_index < *active || tabs.len() <= *active {
+            *active = active.saturating_sub(1);
+        }
+        if tabs.is_empty() {
+            self.remove_leaf(index);
+        }
+        Some(tab)
+    }
+
+    /// Remove an empty leaf, replacing its parent with its sibling.
+    fn remove_leaf(&mut self, index: NodeIndex) {
+        let (Some(parent), Some(sibling)) = (index.parent(), index.sibling()) else {
+            return; // Keep the root, so there is something to drop tabs onto.
+        };
+        self.set(index, DockNode::Empty);
+        let sibling = self.take_subtree(sibling);
+        self.put_subtree(parent, sibling);
+        if self.focused == Some(index) {
+            self.focused = None;
+        }
+    }
+
+    /// Move a tab to a new place.
+    fn move_tab(&mut self, source: (NodeIndex, usize), target: NodeIndex, side: Option<DockSide>) {
+        let (mut source_index, tab_index) = source;
+        let Some(DockNode::Leaf { tabs, active }) = self.nodes.get_mut(source_index.0) else {
+            return;
+        };
+        if tab_index >= tabs.len() || (side.is_none() && source_index == target) {
+            return;
+        }
+
+        // Take the tab out, but leave the (possibly empty) group in place for now,
+        // so that the index of the target doesn't change:
+        let tab = tabs.remove(tab_index);
+        if tab_index < *active || tabs.len() <= *active {
+            *active = active.saturating_sub(1);
+        }
+
+        let new_leaf = if let Some(side) = side {
+            let new_leaf = self.split(target, side, 0.5, vec![tab]);
+            if source_index == target {
+                source_index = new_leaf.sibling().unwrap_or(source_index);
+            }
+            new_leaf
+        } else {
+            if let Some(DockNode::Leaf { tabs, active }) = self.nodes.get_mut(target.0) {
+                tabs.push(tab);
+                *active = tabs.len() - 1;
+            }
+            target
+        };
+        self.focused = Some(new_leaf);
+
+        let source_is_empty =
+            matches!(&self.nodes[source_index.0], DockNode::Leaf { tabs, .. } if tabs.is_empty());
+        if source_is_empty {
+            let (sibling, parent) = (source_index.sibling(), source_index.parent());
+            self.remove_leaf(source_index);
+            if let (Some(sibling), Some(parent)) = (sibling, parent) {
+                // The new group may have moved up, together with its ancestors:
+                if let Some(moved) = relocate(new_leaf, sibling, parent) {
+                    self.focused = Some(moved);
+                }
+            }
+        }
+    }
+
+    fn set(&mut self, index: NodeIndex, node: DockNode<Tab>) {
+        if self.nodes.len() <= index.0 {
+            self.nodes.resize_with(index.0 + 1, Default::default);
+        }
+        self.nodes[index.0] = node;
+    }
+
+    fn take_subtree(&mut self, index: NodeIndex) -> Subtree<Tab> {
+        let node = self
+            .nodes
+            .get_mut(index.0)
+            .map(std::mem::take)
+            .unwrap_or_default();
+        let children = matches!(node, DockNode::Split { .. }).then(|| {
+            Box::new([
+                self.take_subtree(index.left()),
+                self.take_subtree(index.right()),
+            ])
+        });
+        Subtree { node, children }
+    }
+
+    fn put_subtree(&mut self, index: NodeIndex, subtree: Subtree<Tab>) {
+        self.set(index, subtree.node);
+        if let Some(children) = subtree.children {
+            let [left, right] = *children;
+            self.put_subtree(index.left(), left);
+            self.put_subtree(index.right(), right);
+        }
+    }
+
+    /// The rectangles of all non-empty nodes, and the separator of each split.
+    fn layout(&self, rect: Rect, gap: f32) -> Vec<(NodeIndex, Rect)> {
+        let mut rects = vec![];
+        let mut stack = vec![(NodeIndex::ROOT, rect)];
+        while let Some((index, rect)) = stack.pop() {
+            match self.node(index) {
+                None | Some(DockNode::Empty) => {}
+                Some(DockNode::Leaf { .. }) => rects.push((index, rect)),
+                Some(DockNode::Split {
+                    horizontal,
+                    fraction,
+                }) => {
+                    rects.push((index, rect));
+                    let [first, second] = split_rect(rect, *horizontal, *fraction, gap);
+                    stack.push((index.left(), first));
+                    stack.push((index.right(), second));
+                }
+            }
+        }
+        rects
+    }
+}
+
+/// If `index` is in the subtree rooted at `from`, where does it end up if that subtree is moved to `to`?
+fn relocate(index: NodeIndex, from: NodeIndex, to: NodeIndex) -> Option<NodeIndex> {
+    let mut path = vec![];
+    let mut i = index;
+    while i != from {
+        let parent = i.parent()?;
+        path.push(parent.right() == i);
+        i = parent;
+    }
+    Some(path.into_iter().rev().fold(
+        to,
+        |node, right| {
+            if right { node.right() } else { node.left() }
+        },
+    ))
+}
+
+fn split_rect(rect: Rect, horizontal: bool, fraction: f32, gap: f32) -> [Rect; 2] {
+    if horizontal {
+        let width = (rect.width() - gap).at_least(0.0);
+        let x = rect.min.x + width * fraction;
+        [
+            Rect::from_min_max(rect.min, pos2(x, rect.max.y)),
+            Rect::from_min_max(pos2(x + gap, rect.min.y), rect.max),
+        ]
+    } else {
+        let height = (rect.height() - gap).at_least(0.0);
+        let y = rect.min.y + height * fraction;
+        [
+            Rect::from_min_max(rect.min, pos2(rect.max.x, y)),
+            Rect::from_min_max(pos2(rect.min.x, y + gap), rect.max),
+        ]
+    }
+}
+
+/// Shows the tabs of a [`Dock`].
+pub trait TabViewer {
+    type Tab;
+
+    /// The title of the tab, shown in the tab bar.
+    fn title(&mut self, tab: &mut Self::Tab) -> WidgetText;
+
+    /// The contents of the tab.
+    fn ui(&mut self, ui: &mut Ui, tab: &mut Self::Tab);
+
+    /// Can this tab be closed by the user?
+    fn closeable(&mut self, _tab: &mut Self::Tab) -> bool {
+        true
+    }
+
+    /// The user clicked the close button of the tab.
+    ///
+    /// Return `false` to keep the tab open.
+    fn on_close(&mut self, _tab: &mut Self::Tab) -> bool {
+        true
+    }
+}
+
+/// Where a dragged tab is dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DropTarget {
+    leaf: NodeIndex,
+
+    /// `None` means "add to the tab group".
+    side: Option<DockSide>,
+
+    /// What to highlight.
+    rect: Rect,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct DockDragState {
+    /// The tab being dragged.
+    source: Option<(NodeIndex, usize)>,
+}
+
+/// Tab groups that the user can rearrange by dragging tabs between them,
+/// and split horizontally or vertically by dropping tabs on their edges.
+///
+/// The layout is stored in a [`DockTree`] owned by you,
+/// and the tabs are shown using your implementation of [`TabViewer`].
+///
+/// The dock fills all available space of the [`Ui`].
+///
+/// ```
+/// use egui::containers::dock::{Dock, DockTree, TabViewer};
+///
+/// struct MyTabs;
+///
+/// impl TabViewer for MyTabs {
+///     type Tab = String;
+///
+///     fn title(&mut self, tab: &mut String) -> egui::WidgetText {
+///         tab.as_str().into()
+///     }
+///
+///     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut String) {
+///         ui.label(format!("This is {tab}"));
+///     }
+/// }
+///
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tree = DockTree::new(vec!["Files".to_owned(), "Search".to_owned()]);
+/// Dock::new("my_dock").show(ui, &mut tree, &mut MyTabs);
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Dock {
+    id_salt: Id,
+    show_close_buttons: bool,
+    min_size: f32,
+}
+
+impl Dock {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            show_close_buttons: true,
+            min_size: 32.0,
+        }
+    }
+
+    /// Show close buttons on the tabs (if [`TabViewer::closeable`]).
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_close_buttons(mut self, show_close_buttons: bool) -> Self {
+        self.show_close_buttons = show_close_buttons;
+        self
+    }
+
+    /// The smallest width or height a split can be resized to.
+    ///
+    /// Default: `32.0`.
+    #[inline]
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size.at_least(0.0);
+        self
+    }
+
+    pub fn show<V: TabViewer>(
+        self,
+        ui: &mut Ui,
+        tree: &mut DockTree<V::Tab>,
+        viewer: &mut V,
+    ) -> Response {
+        let Self {
+            id_salt,
+            show_close_buttons,
+            min_size,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let rect = ui.available_rect_before_wrap();
+        ui.ctx().check_for_id_clash(id, rect, "Dock");
+
+        let gap = ui.spacing().item_spacing.x;
+        let layout = tree.layout(rect, gap);
+
+        let mut drag_state = ui
+            .data(|d| d.get_temp::<DockDragState>(id))
+            .unwrap_or_default();
+        let mut clicked_tab = None;
+        let mut close_tab = None;
+        let mut leaf_rects = vec![];
+
+        for &(index, node_rect) in &layout {
+            match tree.nodes.get_mut(index.0) {
+                Some(DockNode::Leaf { tabs, active }) => {
+                    leaf_rects.push((index, node_rect));
+                    *active = (*active).at_most(tabs.len().saturating_sub(1));
+
+                    let tab_bar_height = ui.spacing().interact_size.y + 4.0;
+                    let (bar_rect, content_rect) = node_rect.split_top_bottom_at_y(
+                        (node_rect.min.y + tab_bar_height).at_most(node_rect.max.y),
+                    );
+
+                    let mut tab_ui = ui.new_child(
+                        UiBuilder::new()
+                            .id_salt(id.with(("tab_bar", index)))
+                            .max_rect(bar_rect),
+                    );
+                    tab_ui.set_clip_rect(bar_rect.intersect(ui.clip_rect()));
+                    tab_ui
+                        .painter()
+                        .rect_filled(bar_rect, 0.0, ui.visuals().faint_bg_color);
+
+                    let mut x = bar_rect.min.x;
+                    for (i, tab) in tabs.iter_mut().enumerate() {
+                        let description = Tab::new(viewer.title(tab))
+                            .closable(show_close_buttons && viewer.closeable(tab));
+                        let tab_rect = Rect::from_min_size(
+                            pos2(x, bar_rect.min.y),
+                            vec2(tab_width(&tab_ui, &description), bar_rect.height()),
+                        );
+                        let tab_response = tab_ui_button(
+                            &tab_ui,
+                            id.with(("tab", index, i)),
+                            &description,
+                            i == *active,
+                            tab_rect,
+                        );
+                        x = tab_response.tab.rect.max.x + 1.0;
+
+                        if tab_response.close_clicked {
+                            close_tab = Some((index, i));
+                        } else if tab_response.tab.clicked() {
+                            clicked_tab = Some((index, i));
+                        }
+                        if tab_response.tab.drag_started() {
+                            drag_state.source = Some((index, i));
+                        }
+                    }
+
+                    let content_rect = content_rect.round_ui();
+                    ui.painter().rect_stroke(
+                        content_rect,
+                        0.0,
+                        ui.visuals().widgets.noninteractive.bg_stroke,
+                        StrokeKind::Inside,
+                    );
+                    if let Some(tab) = tabs.get_mut(*active) {
+                        let mut content_ui = ui.new_child(
+                            UiBuilder::new()
+                                .id_salt(id.with(("content", index)))
+                                .max_rect(content_rect.shrink(4.0)),
+                        );
+                        content_ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                        viewer.ui(&mut content_ui, tab);
+                    }
+                }
+                Some(DockNode::Split {
+                    horizontal,
+                    fraction,
+                }) => {
+                    separator_ui(
+                        ui,
+                        id.with(("separator", index)),
+                        node_rect,
+                        *horizontal,
+                        fraction,
+                        gap,
+                        min_size,
+                    );
+                }
+                Some(DockNode::Empty) | None => {}
+            }
+        }
+
+        if let Some(tab) = clicked_tab {
+            tree.set_active_tab(tab);
+        }
+        if let Some((index, i)) = close_tab {
+            let close = match tree.nodes.get_mut(index.0) {
+                Some(DockNode::Leaf { tabs, .. }) => {
+                    tabs.get_mut(i).is_some_and(|tab| viewer.on_close(tab))
+                }
+                _ => false,
+            };
+            if close {
+                tree.remove_tab((index, i));
+            }
+        }
+
+        if let Some(source) = drag_state.source {
+            let (pointer, released) =
+                ui.input(|i| (i.pointer.interact_pos(), !i.pointer.primary_down()));
+            let target = pointer.and_then(|pointer| drop_target(&leaf_rects, pointer));
+
+            if released {
+                if let Some(target) = target {
+                    tree.move_tab(source, target.leaf, target.side);
+                }
+                drag_state.source = None;
+            } else {
+                ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                let title = match tree.nodes.get_mut(source.0.0) {
+                    Some(DockNode::Leaf { tabs, .. }) => {
+                        tabs.get_mut(source.1).map(|tab| viewer.title(tab))
+                    }
+                    _ => None,
+                };
+                paint_drag_overlay(ui, id, target, pointer, title);
+            }
+        }
+        ui.data_mut(|d| d.insert_temp(id, drag_state));
+
+        ui.advance_cursor_after_rect(rect);
+        ui.interact(rect, id, Sense::hover())
+    }
+}
+
+fn separator_ui(
+    ui: &Ui,
+    id: Id,
+    rect: Rect,
+    horizontal: bool,
+    fraction: &mut f32,
+    gap: f32,
+    min_size: f32,
+) {
+    let [first, _] = split_rect(rect, horizontal, *fraction, gap);
+    let grab_radius = ui.style().interaction.resize_grab_radius_side;
+    let separator_rect = if horizontal {
+        let x = first.max.x + 0.5 * gap;
+        Rect::from_x_y_ranges(x - grab_radius..=x + grab_radius, rect.y_range())
+    } else {
+        let y = first.max.y + 0.5 * gap;
+        Rect::from_x_y_ranges(rect.x_range(), y - grab_radius..=y + grab_radius)
+    };
+    let response = ui.interact(separator_rect, id, Sense::drag());
+
+    if let (true, Some(pointer)) = (response.dragged(), response.interact_pointer_pos()) {
+        let (pointer, min, len) = if horizontal {
+            (pointer.x, rect.min.x, rect.width() - gap)
+        } else {
+            (pointer.y, rect.min.y, rect.height() - gap)
+        };
+        if 0.0 < len {
+            let min_fraction = (min_size / len).at_most(0.5);
+            *fraction = ((pointer - min - 0.5 * gap) / len).clamp(min_fraction, 1.0 - min_fraction);
+        }
+    }
+
+    if response.hovered() || response.dragged() {
+        ui.ctx().set_cursor_icon(if horizontal {
+            CursorIcon::ResizeHorizontal
+        } else {
+            CursorIcon::ResizeVertical
+        });
+    }
+
+    let stroke = if response.dragged() {
+        ui.style().visuals.widgets.active.fg_stroke
+    } else if response.hovered() {
+        ui.style().visuals.widgets.hovered.fg_stroke
+    } else {
+        Stroke::NONE
+    };
+    let center = separator_rect.center();
+    if horizontal {
+        ui.painter().vline(center.x, rect.y_range(), stroke);
+    } else {
+        ui.painter().hline(rect.x_range(), center.y, stroke);
+    }
+}
+
+/// Which tab group is the pointer over, and which part of it?
+///
+/// The outer quarter on each side splits the group, and the middle adds to it.
+fn drop_target(leaf_rects: &[(NodeIndex, Rect)], pointer: Pos2) -> Option<DropTarget> {
+    let &(leaf, rect) = leaf_rects.iter().find(|(_, rect)| rect.contains(pointer))?;
+    let rel = (pointer - rect.min) / rect.size().max(Vec2::splat(1.0));
+
+    let (side, rect) = if rel.x < 0.25 {
+        (
+            Some(DockSide::Left),
+            rect.split_left_right_at_fraction(0.5).0,
+        )
+    } else if 0.75 < rel.x {
+        (
+            Some(DockSide::Right),
+            rect.split_left_right_at_fraction(0.5).1,
+        )
+    } else if rel.y < 0.25 {
+        (
+            Some(DockSide::Top),
+            rect.split_top_bottom_at_fraction(0.5).0,
+        )
+    } else if 0.75 < rel.y {
+        (
+            Some(DockSide::Bottom),
+            rect.split_top_bottom_at_fraction(0.5).1,
+        )
+    } else {
+        (None, rect)
+    };
+    Some(DropTarget { leaf, side, rect })
+}
+
+fn paint_drag_overlay(
+    ui: &Ui,
+    id: Id,
+    target: Option<DropTarget>,
+    pointer: Option<Pos2>,
+    title: Option<WidgetText>,
+) {
+    let painter = ui
+        .painter()
+        .clone()
+        .with_layer_id(LayerId::new(Order::Foreground, id.with("drop_overlay")));
+    let selection = ui.visuals().selection;
+
+    if let Some(target) = target {
+        painter.rect(
+            target.rect.shrink(2.0),
+            ui.visuals().widgets.inactive.corner_radius,
+            selection.bg_fill.gamma_multiply(0.4),
+            selection.stroke,
+            StrokeKind::Inside,
+        );
+    }
+
+    if let (Some(pointer), Some(title)) = (pointer, title) {
+        painter.text(
+            pointer + vec2(12.0, 12.0),
+            Align2::LEFT_TOP,
+            title.text(),
+            TextStyle::Button.resolve(ui.style()),
+            ui.visuals().strong_text_color(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf_tabs(tree: &DockTree<&'static str>, index: NodeIndex) -> Vec<&'static str> {
+        match tree.node(index) {
+            Some(DockNode::Leaf { tabs, .. }) => tabs.clone(),
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_move_and_collapse() {
+        let mut tree = DockTree::new(vec!["a", "b"]);
+        let right = tree.split(NodeIndex::ROOT, DockSide::Right, 0.5, vec!["c"]);
+        assert_eq!(right, NodeIndex::ROOT.right());
+        assert_eq!(leaf_tabs(&tree, NodeIndex::ROOT.left()), ["a", "b"]);
+
+        // Split a group by dragging one of its own tabs to its bottom edge:
+        tree.move_tab(
+            (NodeIndex::ROOT.left(), 0),
+            NodeIndex::ROOT.left(),
+            Some(DockSide::Bottom),
+        );
+        assert_eq!(leaf_tabs(&tree, NodeIndex::ROOT.left().left()), ["b"]);
+        assert_eq!(leaf_tabs(&tree, NodeIndex::ROOT.left().right()), ["a"]);
+
+        // Moving the last tab out of a group removes the group:
+        tree.move_tab((right, 0), NodeIndex::ROOT.left().left(), None);
+        assert_eq!(leaf_tabs(&tree, NodeIndex::ROOT.left()), ["b", "c"]);
+        assert_eq!(leaf_tabs(&tree, NodeIndex::ROOT.right()), ["a"]);
+        assert_eq!(tree.focused_leaf(), Some(NodeIndex::ROOT.left()));
+
+        assert_eq!(tree.remove_tab((NodeIndex::ROOT.right(), 0)), Some("a"));
+        assert_eq!(leaf_tabs(&tree, NodeIndex::ROOT), ["b", "c"]);
+        assert_eq!(tree.tabs().count(), 2);
+    }
+}
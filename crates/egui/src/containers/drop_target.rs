@@ -0,0 +1,210 @@
+//! A drop target that declares which drag-and-drop payloads it accepts, see [`DropTarget`].
+
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{CursorIcon, DragAndDrop, Frame, Pos2, Response, Ui};
+
+type PreviewFn<'a> = Box<dyn FnOnce(&mut Ui, Pos2) + 'a>;
+
+/// Something you can drop drag-and-drop payloads onto.
+///
+/// The drop target declares the payload types it accepts with [`Self::accept`].
+/// While the user is dragging something:
+/// * if the payload is accepted, the target is highlighted, and more so when hovered,
+/// * if the payload is not accepted, the target is grayed out and shows a [`CursorIcon::NotAllowed`] when hovered.
+///
+/// Use [`Self::preview`] to show where the payload would end up, e.g. an insert marker in a list.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # #[derive(Clone, Copy)] struct Fruit(usize);
+/// # #[derive(Clone, Copy)] struct Vegetable(usize);
+/// # let mut basket: Vec<String> = vec![];
+/// let response = egui::DropTarget::new()
+///     .accept::<Fruit>()
+///     .accept::<Vegetable>()
+///     .show(ui, |ui| {
+///         for item in &basket {
+///             ui.label(item);
+///         }
+///     });
+///
+/// if let Some(fruit) = response.dropped::<Fruit>() {
+///     basket.push(format!("Fruit {}", fruit.0));
+/// } else if let Some(vegetable) = response.dropped::<Vegetable>() {
+///     basket.push(format!("Vegetable {}", vegetable.0));
+/// }
+/// # });
+/// ```
+///
+/// See also [`Ui::dnd_drop_zone`] and [`crate::Response::dnd_set_drag_payload`].
+#[doc(alias = "drag and drop")]
+#[must_use = "You should call .show()"]
+pub struct DropTarget<'a> {
+    accepts: Vec<TypeId>,
+    frame: Option<Frame>,
+    preview: Option<PreviewFn<'a>>,
+}
+
+/// The result of [`DropTarget::show`].
+#[derive(Debug)]
+pub struct DropTargetResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the whole drop target, including its frame.
+    pub response: Response,
+
+    /// Is something being dragged that this target accepts?
+    pub accepts: bool,
+
+    /// Is something that this target accepts being held over it?
+    pub hovered: bool,
+}
+
+impl Default for DropTarget<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> DropTarget<'a> {
+    /// A drop target that accepts nothing. Add payload types with [`Self::accept`].
+    pub fn new() -> Self {
+        Self {
+            accepts: vec![],
+            frame: None,
+            preview: None,
+        }
+    }
+
+    /// Accept payloads of this type, as set by [`crate::Response::dnd_set_drag_payload`].
+    #[inline]
+    pub fn accept<Payload: Any + Send + Sync>(mut self) -> Self {
+        self.accepts.push(TypeId::of::<Payload>());
+        self
+    }
+
+    /// The frame around the contents.
+    ///
+    /// Its margins and rounding are used, but the fill and stroke are set from [`crate::Visuals::widgets`]
+    /// depending on what is being dragged.
+    ///
+    /// Defaults to [`Frame::group`] with some inner margin.
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Paint a preview of the drop, while an accepted payload is held over the target.
+    ///
+    /// The callback is called after the contents were added, with the [`Ui`] of the contents
+    /// and the pointer position.
+    /// Use it for instance to paint an insert marker between the items of a list:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let items = vec!["a", "b", "c"];
+    /// let response = egui::DropTarget::new()
+    ///     .accept::<usize>()
+    ///     .preview(|ui, pointer| {
+    ///         let rect = ui.min_rect();
+    ///         let y = if pointer.y < rect.center().y { rect.top() } else { rect.bottom() };
+    ///         let stroke = ui.visuals().selection.stroke;
+    ///         ui.painter().hline(rect.x_range(), y, stroke);
+    ///     })
+    ///     .show(ui, |ui| {
+    ///         for item in &items {
+    ///             ui.label(*item);
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn preview(mut self, preview: impl FnOnce(&mut Ui, Pos2) + 'a) -> Self {
+        self.preview = Some(Box::new(preview));
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> DropTargetResponse<R> {
+        let Self {
+            accepts,
+            frame,
+            preview,
+        } = self;
+
+        let ctx = ui.ctx().clone();
+        let payload_type = DragAndDrop::payload_type_id(&ctx);
+        let is_anything_being_dragged = payload_type.is_some();
+        let accepts = payload_type.is_some_and(|payload_type| accepts.contains(&payload_type));
+
+        let frame = frame.unwrap_or_else(|| Frame::group(ui.style()).inner_margin(4.0));
+        let mut prepared = frame.begin(ui);
+        let inner = add_contents(&mut prepared.content_ui);
+        let response = prepared.allocate_space(ui);
+
+        // NOTE: we use `response.contains_pointer` here instead of `hovered`, because
+        // `hovered` is always false when another widget is being dragged.
+        let contains_pointer = response.contains_pointer();
+        let hovered = accepts && contains_pointer;
+
+        let visuals = ui.visuals();
+        let style = if hovered {
+            visuals.widgets.active
+        } else if accepts {
+            visuals.widgets.hovered
+        } else {
+            visuals.widgets.inactive
+        };
+
+        let mut fill = style.bg_fill;
+        let mut stroke = style.bg_stroke;
+
+        if is_anything_being_dragged && !accepts {
+            // When dragging something else, show that it can't be dropped here:
+            fill = visuals.disable(fill);
+            stroke.color = visuals.disable(stroke.color);
+            if contains_pointer {
+                ctx.set_cursor_icon(CursorIcon::NotAllowed);
+            }
+        }
+
+        prepared.frame.fill = fill;
+        prepared.frame.stroke = stroke;
+        prepared.paint(ui);
+
+        if hovered {
+            if let (Some(preview), Some(pointer)) = (preview, ctx.pointer_latest_pos()) {
+                preview(&mut prepared.content_ui, pointer);
+            }
+        }
+
+        DropTargetResponse {
+            inner,
+            response,
+            accepts,
+            hovered,
+        }
+    }
+}
+
+impl<R> DropTargetResponse<R> {
+    /// The payload dropped onto the target this frame, if it is of the given type.
+    ///
+    /// Call this for each of the accepted types.
+    pub fn dropped<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        if self.accepts {
+            self.response.dnd_release_payload::<Payload>()
+        } else {
+            None
+        }
+    }
+}
@@ -1,10 +1,14 @@
 //! Frame container
 
+use std::sync::Arc;
+
 use crate::{
     InnerResponse, Response, Sense, Style, Ui, UiBuilder, UiKind, UiStackInfo, epaint,
-    layers::ShapeIdx,
+    layers::ShapeIdx, load::SizedTexture,
+};
+use epaint::{
+    Brush, Color32, CornerRadius, Margin, MarginF32, NineSlice, Rect, Shadow, Shape, Stroke,
 };
-use epaint::{Color32, CornerRadius, Margin, MarginF32, Rect, Shadow, Shape, Stroke};
 
 /// A frame around some content, including margin, colors, etc.
 ///
@@ -106,6 +110,9 @@ pub struct Frame {
     /// The background fill color of the frame, within the [`Self::stroke`].
     ///
     /// Known as `background` in CSS.
+    ///
+    /// To fill the frame with a gradient, see [`Prepared::fill_brush`].
+    /// For a frosted-glass look, see [`Prepared::backdrop_blur`].
     #[doc(alias = "background")]
     pub fill: Color32,
 
@@ -375,6 +382,57 @@ pub struct Prepared {
     /// but the rest of the fields may be modified.
     pub frame: Frame,
 
+    /// If set, the frame is filled with this (e.g. a gradient) instead of [`Frame::fill`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut frame = egui::Frame::default().inner_margin(4.0).begin(ui);
+    /// frame.fill_brush = Some(egui::Brush::vertical_gradient(
+    ///     egui::Color32::DARK_BLUE,
+    ///     egui::Color32::BLACK,
+    /// ).into());
+    /// frame.content_ui.label("Inside the frame");
+    /// frame.end(ui);
+    /// # });
+    /// ```
+    pub fill_brush: Option<Arc<Brush>>,
+
+    /// If set, the frame is filled with this nine-patch texture instead of [`Frame::fill`],
+    /// e.g. for a skinned window or a speech bubble.
+    ///
+    /// The texture covers the whole widget rect, including the [`Frame::stroke`],
+    /// so you usually want no stroke.
+    /// See [`Frame::paint_with_nine_slice`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let texture = egui::load::SizedTexture::new(egui::TextureId::default(), [48.0, 48.0]);
+    /// let mut frame = egui::Frame::new().inner_margin(16).begin(ui);
+    /// frame.fill_nine_slice = Some((texture, egui::NineSlice::new(16.0)));
+    /// frame.content_ui.label("Inside the skinned frame");
+    /// frame.end(ui);
+    /// # });
+    /// ```
+    pub fill_nine_slice: Option<(SizedTexture, NineSlice)>,
+
+    /// Blur whatever is behind the frame by this many points, for a frosted-glass look.
+    ///
+    /// This only makes sense with a translucent [`Frame::fill`].
+    /// If the backend can't blur (see [`epaint::TessellationOptions::backdrop_blur`]),
+    /// the fill is painted twice instead, so that what is behind is muted more.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut frame = egui::Frame::window(ui.style())
+    ///     .fill(egui::Color32::from_black_alpha(128))
+    ///     .begin(ui);
+    /// frame.backdrop_blur = 16;
+    /// frame.content_ui.label("Frosted glass");
+    /// frame.end(ui);
+    /// # });
+    /// ```
+    pub backdrop_blur: u8,
+
     /// This is where we will insert the frame shape so it ends up behind the content.
     where_to_put_background: ShapeIdx,
 
@@ -407,6 +465,9 @@ impl Frame {
 
         Prepared {
             frame: self,
+            fill_brush: None,
+            fill_nine_slice: None,
+            backdrop_blur: 0,
             where_to_put_background,
             content_ui,
         }
@@ -431,6 +492,22 @@ impl Frame {
 
     /// Paint this frame as a shape.
     pub fn paint(&self, content_rect: Rect) -> Shape {
+        self.paint_with_brush(content_rect, None)
+    }
+
+    /// Like [`Self::paint`], but filled with `fill_brush` (if any) instead of [`Self::fill`].
+    pub fn paint_with_brush(&self, content_rect: Rect, fill_brush: Option<Arc<Brush>>) -> Shape {
+        self.paint_with_brush_and_blur(content_rect, fill_brush, 0)
+    }
+
+    /// Like [`Self::paint_with_brush`], but also blurs what is behind the frame,
+    /// see [`Prepared::backdrop_blur`].
+    fn paint_with_brush_and_blur(
+        &self,
+        content_rect: Rect,
+        fill_brush: Option<Arc<Brush>>,
+        backdrop_blur: u8,
+    ) -> Shape {
         let Self {
             inner_margin: _,
             fill,
@@ -442,19 +519,75 @@ impl Frame {
 
         let widget_rect = self.widget_rect(content_rect);
 
-        let frame_shape = Shape::Rect(epaint::RectShape::new(
+        let mut rect_shape = epaint::RectShape::new(
             widget_rect,
             corner_radius,
             fill,
             stroke,
             epaint::StrokeKind::Inside,
-        ));
+        );
+        if let Some(fill_brush) = fill_brush {
+            // The brush is multiplied with the fill color:
+            rect_shape.fill = Color32::WHITE;
+            rect_shape.brush = Some(fill_brush);
+        }
+        let frame_shape = Shape::Rect(rect_shape);
 
-        if shadow == Default::default() {
+        if shadow == Default::default() && backdrop_blur == 0 {
             frame_shape
         } else {
-            let shadow = shadow.as_shape(widget_rect, corner_radius);
-            Shape::Vec(vec![Shape::from(shadow), frame_shape])
+            let mut shapes = Vec::with_capacity(3);
+            if shadow != Default::default() {
+                shapes.push(shadow.as_shape(widget_rect, corner_radius).into());
+            }
+            if 0 < backdrop_blur {
+                shapes.push(
+                    epaint::BackdropBlurShape::new(
+                        widget_rect,
+                        corner_radius,
+                        backdrop_blur.into(),
+                        fill,
+                    )
+                    .into(),
+                );
+            }
+            shapes.push(frame_shape);
+            Shape::Vec(shapes)
+        }
+    }
+}
+
+impl Frame {
+    /// Like [`Self::paint`], but filled with a nine-patch texture instead of [`Self::fill`].
+    ///
+    /// The texture is stretched over the whole widget rect (see [`NineSlice`]),
+    /// and the stroke is painted on top of it.
+    pub fn paint_with_nine_slice(
+        &self,
+        content_rect: Rect,
+        texture: SizedTexture,
+        nine_slice: NineSlice,
+    ) -> Shape {
+        let background = Shape::mesh(nine_slice.mesh(
+            texture.id,
+            self.widget_rect(content_rect),
+            Rect::from_min_max(epaint::pos2(0.0, 0.0), epaint::pos2(1.0, 1.0)),
+            texture.size,
+            Color32::WHITE,
+        ));
+
+        let frame = Self {
+            fill: Color32::TRANSPARENT,
+            ..*self
+        };
+        match frame.paint(content_rect) {
+            Shape::Vec(mut shapes) => {
+                // Between the shadow and the stroke:
+                let index = shapes.len().saturating_sub(1);
+                shapes.insert(index, background);
+                Shape::Vec(shapes)
+            }
+            frame_shape => Shape::Vec(vec![background, frame_shape]),
         }
     }
 }
@@ -485,7 +618,16 @@ impl Prepared {
         let widget_rect = self.frame.widget_rect(content_rect);
 
         if ui.is_rect_visible(widget_rect) {
-            let shape = self.frame.paint(content_rect);
+            let shape = if let Some((texture, nine_slice)) = self.fill_nine_slice {
+                self.frame
+                    .paint_with_nine_slice(content_rect, texture, nine_slice)
+            } else {
+                self.frame.paint_with_brush_and_blur(
+                    content_rect,
+                    self.fill_brush.clone(),
+                    self.backdrop_blur,
+                )
+            };
             ui.painter().set(self.where_to_put_background, shape);
         }
     }
@@ -0,0 +1,193 @@
+//! A magnifying glass that follows the pointer, see [`Magnifier`].
+
+use emath::TSTransform;
+
+use crate::{
+    Color32, Id, InnerResponse, LayerId, Order, Pos2, Rect, Stroke, StrokeKind, Ui, Vec2,
+    epaint::ClippedShape, vec2,
+};
+
+/// Shows a zoomed-in view of the contents under the pointer, in a lens next to it.
+///
+/// The contents are painted as usual, and then painted again, scaled up, in a lens on top of everything else.
+/// Since the shapes are re-painted rather than sampled from the screen, the result stays sharp for shapes,
+/// while images and text are scaled up from their textures.
+///
+/// This is useful for color pickers and pixel-art tools.
+/// When zoomed in far enough, the physical pixel under the pointer is outlined.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Magnifier::new().zoom(8.0).show(ui, |ui| {
+///     ui.label("Hover me to see me up close");
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct Magnifier {
+    id_salt: Option<Id>,
+    zoom: f32,
+    size: Vec2,
+    enabled: bool,
+}
+
+impl Default for Magnifier {
+    fn default() -> Self {
+        Self {
+            id_salt: None,
+            zoom: 4.0,
+            size: Vec2::splat(128.0),
+            enabled: true,
+        }
+    }
+}
+
+impl Magnifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Must be set if multiple magnifiers are in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// How much to scale up the contents.
+    ///
+    /// This is independent of [`crate::Context::zoom_factor`]: a zoom of `4` always makes
+    /// each physical pixel four physical pixels wide.
+    ///
+    /// Default: `4.0`.
+    #[inline]
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom.max(1.0);
+        self
+    }
+
+    /// The size of the lens, in points.
+    ///
+    /// Default: `128x128`.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// If `false`, the contents are shown without a lens.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            zoom,
+            size,
+            enabled,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt.unwrap_or_else(|| Id::new("magnifier")));
+        let layer_id = ui.layer_id();
+        let ctx = ui.ctx().clone();
+
+        let num_shapes = || ctx.graphics(|g| g.get(layer_id).map_or(0, |list| list.next_idx().0));
+        let start = num_shapes();
+        let response = ui.scope(add_contents);
+        let end = num_shapes();
+
+        if !enabled || !response.response.contains_pointer() {
+            return response;
+        }
+        let Some(pointer) = ctx.pointer_hover_pos() else {
+            return response;
+        };
+
+        let shapes: Vec<ClippedShape> = ctx.graphics(|g| {
+            g.get(layer_id).map_or_else(Vec::new, |list| {
+                list.all_entries()
+                    .skip(start)
+                    .take(end - start)
+                    .cloned()
+                    .collect()
+            })
+        });
+
+        let lens_rect = lens_rect(ctx.screen_rect(), pointer, size);
+
+        // From the coordinates of the contents, via global coordinates, to the lens:
+        let to_global = ctx.layer_transform_to_global(layer_id).unwrap_or_default();
+        let to_lens = TSTransform::new(
+            lens_rect.center().to_vec2() - zoom * pointer.to_vec2(),
+            zoom,
+        );
+        let transform = to_lens * to_global;
+
+        let lens_layer = LayerId::new(Order::Tooltip, id.with("lens"));
+        let visuals = ui.visuals();
+        let painter = ctx.layer_painter(lens_layer).with_clip_rect(lens_rect);
+        painter.rect_filled(lens_rect, 0.0, visuals.extreme_bg_color);
+        ctx.graphics_mut(|g| {
+            let list = g.entry(lens_layer);
+            for ClippedShape {
+                clip_rect,
+                mut shape,
+            } in shapes
+            {
+                shape.transform(transform);
+                let clip_rect = transform.mul_rect(clip_rect).intersect(lens_rect);
+                if clip_rect.is_positive() {
+                    list.add(clip_rect, shape);
+                }
+            }
+        });
+
+        // Outline the physical pixel under the pointer, if it is big enough to see:
+        let pixel_size = zoom / ctx.pixels_per_point();
+        if 4.0 <= pixel_size {
+            let pixel_in_points = 1.0 / ctx.pixels_per_point();
+            let pixel_min = (pointer / pixel_in_points).floor() * pixel_in_points;
+            let pixel_rect = Rect::from_min_size(to_lens * pixel_min, Vec2::splat(pixel_size));
+            painter.rect_stroke(
+                pixel_rect,
+                0.0,
+                Stroke::new(1.0, Color32::WHITE),
+                StrokeKind::Outside,
+            );
+            painter.rect_stroke(
+                pixel_rect.expand(1.0),
+                0.0,
+                Stroke::new(1.0, Color32::BLACK),
+                StrokeKind::Outside,
+            );
+        }
+
+        painter.rect_stroke(
+            lens_rect,
+            visuals.window_corner_radius,
+            visuals.window_stroke,
+            StrokeKind::Outside,
+        );
+
+        response
+    }
+}
+
+/// Place the lens below and to the right of the pointer, flipping it to the other side if it doesn't fit.
+fn lens_rect(screen_rect: Rect, pointer: Pos2, size: Vec2) -> Rect {
+    let offset = vec2(16.0, 16.0);
+    let mut min = pointer + offset;
+    if screen_rect.max.x < min.x + size.x {
+        min.x = pointer.x - offset.x - size.x;
+    }
+    if screen_rect.max.y < min.y + size.y {
+        min.y = pointer.y - offset.y - size.y;
+    }
+    Rect::from_min_size(min, size)
+}
@@ -0,0 +1,177 @@
+//! Lay out cards of different heights in balanced columns, see [`Masonry`].
+
+use crate::{Id, InnerResponse, Layout, NumExt as _, Rect, Sense, Ui, UiBuilder, Vec2, pos2, vec2};
+
+/// Lays out cards of different heights in columns, like a photo gallery or a dashboard.
+///
+/// Every card is as wide as a column, and goes into the column that is currently the shortest,
+/// so the columns stay balanced.
+///
+/// The number of columns is either fixed with [`Self::columns`],
+/// or as many as fit when each is at least [`Self::min_card_width`] wide (the default).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Masonry::new("gallery")
+///     .min_card_width(150.0)
+///     .show(ui, |masonry| {
+///         for i in 0..10 {
+///             masonry.card(|ui| {
+///                 egui::Frame::group(ui.style()).show(ui, |ui| {
+///                     ui.set_width(ui.available_width());
+///                     ui.label(format!("Card {i}"));
+///                     ui.add_space(10.0 * (i % 3) as f32);
+///                 });
+///             });
+///         }
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct Masonry {
+    id_salt: Id,
+    num_columns: Option<usize>,
+    min_card_width: f32,
+    spacing: Option<Vec2>,
+}
+
+impl Masonry {
+    /// Create a new [`Masonry`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            num_columns: None,
+            min_card_width: 200.0,
+            spacing: None,
+        }
+    }
+
+    /// Use exactly this many columns, regardless of the available width.
+    #[inline]
+    pub fn columns(mut self, num_columns: usize) -> Self {
+        self.num_columns = Some(num_columns.at_least(1));
+        self
+    }
+
+    /// Use as many columns as fit, with each column at least this wide.
+    ///
+    /// Ignored if [`Self::columns`] is set.
+    ///
+    /// Default: `200.0`.
+    #[inline]
+    pub fn min_card_width(mut self, min_card_width: f32) -> Self {
+        self.min_card_width = min_card_width.at_least(1.0);
+        self
+    }
+
+    /// Set spacing between columns and cards.
+    /// Default: [`crate::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut MasonryUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            num_columns,
+            min_card_width,
+            spacing,
+        } = self;
+        let spacing = spacing.unwrap_or_else(|| ui.spacing().item_spacing);
+
+        let available_width = ui.available_width();
+        let num_columns = num_columns.unwrap_or_else(|| {
+            ((available_width + spacing.x) / (min_card_width + spacing.x)).floor() as usize
+        });
+        let num_columns = num_columns.at_least(1);
+        let column_width = ((available_width - spacing.x * (num_columns - 1) as f32)
+            / num_columns as f32)
+            .at_least(0.0);
+
+        let top_left = ui.cursor().min;
+        let mut child_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(id_salt)
+                .max_rect(Rect::from_min_size(top_left, vec2(available_width, 0.0))),
+        );
+        let mut masonry = MasonryUi {
+            ui: &mut child_ui,
+            top_left,
+            column_width,
+            spacing,
+            column_heights: vec![0.0; num_columns],
+            num_cards: 0,
+        };
+        let inner = add_contents(&mut masonry);
+
+        let height = masonry
+            .column_heights
+            .iter()
+            .map(|&height| (height - spacing.y).at_least(0.0))
+            .fold(0.0, f32::max);
+        let rect = Rect::from_min_size(top_left, vec2(available_width, height));
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Adds cards to a [`Masonry`], see [`Masonry::show`].
+pub struct MasonryUi<'a> {
+    ui: &'a mut Ui,
+    top_left: crate::Pos2,
+    column_width: f32,
+    spacing: Vec2,
+
+    /// How far down each column is filled, including the spacing after the last card.
+    column_heights: Vec<f32>,
+
+    num_cards: usize,
+}
+
+impl MasonryUi<'_> {
+    /// The number of columns.
+    pub fn num_columns(&self) -> usize {
+        self.column_heights.len()
+    }
+
+    /// The width of each card.
+    pub fn column_width(&self) -> f32 {
+        self.column_width
+    }
+
+    /// Add a card to the shortest column.
+    pub fn card<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        let (column, &column_height) = self
+            .column_heights
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .expect("There is at least one column");
+
+        let min = pos2(
+            self.top_left.x + column as f32 * (self.column_width + self.spacing.x),
+            self.top_left.y + column_height,
+        );
+        let mut card_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(("card", self.num_cards))
+                .max_rect(Rect::from_min_size(
+                    min,
+                    vec2(self.column_width, f32::INFINITY),
+                ))
+                .layout(Layout::top_down(crate::Align::Min)),
+        );
+        let inner = add_contents(&mut card_ui);
+
+        self.column_heights[column] += card_ui.min_rect().height() + self.spacing.y;
+        self.num_cards += 1;
+        inner
+    }
+}
@@ -7,11 +7,14 @@
 //! - [`Response::context_menu`]
 //!
 //! See [`MenuBar`] for an example.
+//!
+//! Menus can be used with the keyboard, see [`MenuBar`].
 
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, Popup,
-    PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
+    Button, Color32, Context, Event, FontSelection, Frame, Id, InnerResponse, IntoAtoms, Key,
+    LayerId, Layout, Modifiers, Popup, PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind,
+    UiStack, UiStackInfo, Widget as _, WidgetText,
 };
 use emath::{Align, RectAlign, Vec2, vec2};
 use epaint::Stroke;
@@ -73,6 +76,11 @@ pub struct MenuConfig {
     ///
     /// Default is [`menu_style`].
     pub style: StyleModifier,
+
+    /// Does a `&` in the text of a [`MenuButton`] or [`SubMenuButton`] mark its mnemonic?
+    ///
+    /// Default is `false`, so `&` is shown as is.
+    pub mnemonics: bool,
 }
 
 impl Default for MenuConfig {
@@ -81,6 +89,7 @@ impl Default for MenuConfig {
             close_behavior: PopupCloseBehavior::default(),
             bar: false,
             style: menu_style.into(),
+            mnemonics: false,
         }
     }
 }
@@ -109,6 +118,15 @@ impl MenuConfig {
         self
     }
 
+    /// Should a `&` in the text of a [`MenuButton`] or [`SubMenuButton`] mark its mnemonic?
+    ///
+    /// See [`MenuBar`] for details. Default is `false`.
+    #[inline]
+    pub fn mnemonics(mut self, mnemonics: bool) -> Self {
+        self.mnemonics = mnemonics;
+        self
+    }
+
     fn from_stack(stack: &UiStack) -> Self {
         stack
             .info
@@ -171,17 +189,410 @@ impl MenuState {
     }
 }
 
+/// Remove the mnemonic marker from the text of a [`MenuButton`] or [`SubMenuButton`],
+/// and underline the mnemonic.
+///
+/// `"&File"` becomes `"File"` with an underlined `F`, and [`Key::F`] is returned.
+/// Use `&&` for a literal `&`.
+fn parse_mnemonic(text: WidgetText, ui: &Ui) -> (WidgetText, Option<Key>) {
+    let has_marker = match &text {
+        WidgetText::Text(text) => text.contains('&'),
+        WidgetText::RichText(text) => text.text().contains('&'),
+        WidgetText::LayoutJob(job) => job.text.contains('&'),
+        WidgetText::Galley(_) => false,
+    };
+    if !has_marker {
+        return (text, None);
+    }
+
+    let job = text.into_layout_job(ui.style(), FontSelection::Default, ui.text_valign());
+    let mut new_job = (*job).clone();
+    new_job.text.clear();
+    new_job.sections.clear();
+
+    let mut mnemonic = None;
+    for section in &job.sections {
+        let mut leading_space = section.leading_space;
+        let mut plain = String::new();
+        let mut chars = job.text[section.byte_range.clone()].chars();
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                plain.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some(c) if c != '&' && mnemonic.is_none() => {
+                    mnemonic = Key::from_name(&c.to_uppercase().to_string());
+                    new_job.append(&plain, leading_space, section.format.clone());
+                    leading_space = 0.0;
+                    plain.clear();
+
+                    let mut format = section.format.clone();
+                    format.underline = Stroke::new(1.0, format.color);
+                    new_job.append(&c.to_string(), 0.0, format);
+                }
+                Some(c) => plain.push(c),
+                None => plain.push('&'),
+            }
+        }
+        new_job.append(&plain, leading_space, section.format.clone());
+    }
+
+    (new_job.into(), mnemonic)
+}
+
+/// Parse the mnemonic of a menu button, if enabled with [`MenuConfig::mnemonics`].
+fn button_mnemonic<'a>(button: Button<'a>, ui: &Ui, enabled: bool) -> (Button<'a>, Option<Key>) {
+    if !enabled {
+        return (button, None);
+    }
+    let mut mnemonic = None;
+    let button = button.map_texts(|text| {
+        let (text, key) = parse_mnemonic(text, ui);
+        mnemonic = mnemonic.or(key);
+        text
+    });
+    (button, mnemonic)
+}
+
+/// The layer of the widget with keyboard focus, if any.
+fn focused_layer(ctx: &Context) -> Option<LayerId> {
+    let focused = ctx.memory(|mem| mem.focused())?;
+    ctx.viewport(|viewport| {
+        viewport
+            .this_pass
+            .widgets
+            .get(focused)
+            .or_else(|| viewport.prev_pass.widgets.get(focused))
+            .map(|widget| widget.layer_id)
+    })
+}
+
+/// Should the left and right arrow keys move between menus?
+///
+/// Not if the focused widget uses them itself, e.g. a [`crate::TextEdit`].
+fn menu_arrows_allowed(ctx: &Context) -> bool {
+    !ctx.memory(|mem| mem.focused_event_filter().horizontal_arrows)
+}
+
+/// Give focus to the first item of the menu with this (popup) id, the next time it is shown.
+///
+/// Used when a menu is opened with the keyboard.
+pub(crate) fn request_focus_first_item(ctx: &Context, menu_id: Id) {
+    ctx.data_mut(|data| data.insert_temp(menu_id.with("focus_first_item"), true));
+}
+
+/// Keyboard handling at the end of the contents of a menu.
+///
+/// Focuses the first item if requested (see [`request_focus_first_item`]),
+/// and closes the menu if an item was activated with enter or space.
+pub(crate) fn menu_keyboard_ui(ui: &Ui, menu_id: Id, close_behavior: PopupCloseBehavior) {
+    let ctx = ui.ctx();
+
+    let item_activated = focused_layer(ctx) == Some(ui.layer_id())
+        && ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space))
+        // Opening a sub menu doesn't count:
+        && MenuState::from_ui(ui, |state, _| state.open_item.is_none());
+    if item_activated && close_behavior == PopupCloseBehavior::CloseOnClick {
+        ui.close();
+    }
+
+    // The items can't be focused during the sizing pass of a new menu, so we wait for the next one:
+    let focus_first_item = !ui.is_sizing_pass()
+        && ctx
+            .data_mut(|data| data.remove_temp::<bool>(menu_id.with("focus_first_item")))
+            .unwrap_or(false);
+    if focus_first_item {
+        // The popup area is clickable too, so we skip it (see `Area::show`):
+        let area_interact_id = ui.layer_id().id.with("move");
+        let first_item = ctx.viewport(|viewport| {
+            viewport
+                .this_pass
+                .widgets
+                .get_layer(ui.layer_id())
+                .find(|widget| {
+                    widget.id != area_interact_id && widget.enabled && widget.sense.is_focusable()
+                })
+                .map(|widget| widget.id)
+        });
+        if let Some(first_item) = first_item {
+            ctx.memory_mut(|mem| mem.request_focus(first_item));
+        }
+    }
+}
+
+/// Tell assistive technologies about the items of a menu, at the end of its contents.
+///
+/// Buttons, checkboxes and radio buttons become menu items, and report their position in the menu.
+#[cfg(feature = "accesskit")]
+fn menu_accessibility_ui(ui: &Ui) {
+    use accesskit::Role;
+
+    let ctx = ui.ctx();
+    let area_interact_id = ui.layer_id().id.with("move");
+    let items: Vec<Id> = ctx.viewport(|viewport| {
+        viewport
+            .this_pass
+            .widgets
+            .get_layer(ui.layer_id())
+            .filter(|widget| widget.id != area_interact_id && widget.sense.is_focusable())
+            .map(|widget| widget.id)
+            .collect()
+    });
+
+    let mut menu_items = Vec::with_capacity(items.len());
+    for id in items {
+        let is_menu_item = ctx.accesskit_node_builder(id, |node| {
+            let role = match node.role() {
+                Role::Button | Role::MenuItem => Role::MenuItem,
+                Role::CheckBox | Role::MenuItemCheckBox => Role::MenuItemCheckBox,
+                Role::RadioButton | Role::MenuItemRadio => Role::MenuItemRadio,
+                _ => return false,
+            };
+            node.set_role(role);
+            true
+        });
+        if is_menu_item == Some(true) {
+            menu_items.push(id);
+        }
+    }
+
+    let size = menu_items.len();
+    for (index, id) in menu_items.into_iter().enumerate() {
+        ctx.accesskit_node_builder(id, |node| {
+            node.set_position_in_set(index + 1);
+            node.set_size_of_set(size);
+        });
+    }
+}
+
+/// Tell assistive technologies that this button opens a menu, and whether it is open.
+fn menu_button_accessibility(response: &Response, open: bool) {
+    #[cfg(feature = "accesskit")]
+    response.ctx.accesskit_node_builder(response.id, |node| {
+        node.set_has_popup(accesskit::HasPopup::Menu);
+    });
+    response.accessibility(|node| {
+        node.expanded(open);
+    });
+}
+
+/// A [`MenuButton`] in a [`MenuBar`], see [`MenuBarState`].
+#[derive(Clone, Copy, Debug)]
+struct MenuBarButton {
+    id: Id,
+    popup_id: Id,
+    mnemonic: Option<Key>,
+}
+
+/// The keyboard navigation state of a [`MenuBar`].
+#[derive(Clone, Debug, Default)]
+struct MenuBarState {
+    /// The [`MenuButton`]s in the bar, in order.
+    buttons: Vec<MenuBarButton>,
+
+    /// Was alt down last pass?
+    alt_down: bool,
+
+    /// Was alt pressed on its own (without any other key or click) since it went down?
+    alt_alone: bool,
+}
+
+impl MenuBarState {
+    fn id(bar_id: Id) -> Id {
+        bar_id.with("menu_bar_state")
+    }
+
+    fn load(ctx: &Context, bar_id: Id) -> Self {
+        ctx.data_mut(|data| data.get_temp(Self::id(bar_id)))
+            .unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context, bar_id: Id) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(bar_id), self));
+    }
+
+    /// Called by [`MenuButton`]s in the bar.
+    fn add_button(ctx: &Context, bar_id: Id, button: MenuBarButton) {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Self>(Self::id(bar_id))
+                .buttons
+                .push(button);
+        });
+    }
+
+    /// Called before the contents of the bar.
+    ///
+    /// Returns the button of the menu that was open last pass.
+    fn begin(ctx: &Context, bar_id: Id) -> Option<MenuBarButton> {
+        let mut state = Self::load(ctx, bar_id);
+        let open = state
+            .buttons
+            .iter()
+            .find(|button| Popup::is_id_open(ctx, button.popup_id))
+            .copied();
+        state.buttons.clear();
+        state.store(ctx, bar_id);
+        open
+    }
+
+    /// Called after the contents of the bar.
+    fn keyboard_navigation(ui: &Ui, bar_id: Id, was_open: Option<MenuBarButton>) {
+        let ctx = ui.ctx();
+        let mut state = Self::load(ctx, bar_id);
+
+        let (alt_down, alt_only, other_input) = ui.input(|i| {
+            let other_input = i.events.iter().any(|event| {
+                matches!(
+                    event,
+                    Event::Key { .. } | Event::PointerButton { .. } | Event::Text(_)
+                )
+            });
+            (i.modifiers.alt, i.modifiers == Modifiers::ALT, other_input)
+        });
+        if alt_down {
+            if !state.alt_down {
+                state.alt_alone = true;
+            }
+            if !alt_only || other_input {
+                state.alt_alone = false;
+            }
+        }
+        let alt_tapped = state.alt_down && !alt_down && state.alt_alone && !other_input;
+        state.alt_down = alt_down;
+
+        let buttons = std::mem::take(&mut state.buttons);
+        Self::navigate(ui, &buttons, was_open, alt_tapped);
+        state.buttons = buttons;
+        state.store(ctx, bar_id);
+    }
+
+    fn navigate(
+        ui: &Ui,
+        buttons: &[MenuBarButton],
+        was_open: Option<MenuBarButton>,
+        alt_tapped: bool,
+    ) {
+        let ctx = ui.ctx();
+        let Some(first) = buttons.first() else {
+            return;
+        };
+        if !ui.is_enabled() {
+            return;
+        }
+
+        let focused = ctx.memory(|mem| mem.focused());
+        let focused_index = buttons.iter().position(|button| Some(button.id) == focused);
+        let open_index = buttons
+            .iter()
+            .position(|button| Popup::is_id_open(ctx, button.popup_id));
+
+        let open_menu = |button: &MenuBarButton| {
+            Popup::open_id(ctx, button.popup_id);
+            request_focus_first_item(ctx, button.popup_id);
+            ctx.memory_mut(|mem| {
+                mem.request_focus(button.id);
+                mem.cancel_focus_move();
+            });
+        };
+
+        // Escape closes the menu (see `Popup`), and puts the focus back on its button:
+        if let Some(was_open) = was_open {
+            if open_index.is_none() && ui.input(|i| i.key_pressed(Key::Escape)) {
+                ctx.memory_mut(|mem| mem.request_focus(was_open.id));
+                return;
+            }
+        }
+
+        // Pressing and releasing alt enters or leaves the menu bar:
+        if alt_tapped {
+            if focused_index.is_some() || open_index.is_some() {
+                if let Some(open_index) = open_index {
+                    Popup::close_id(ctx, buttons[open_index].popup_id);
+                }
+                if let Some(focused) = focused {
+                    ctx.memory_mut(|mem| mem.surrender_focus(focused));
+                }
+            } else {
+                ctx.memory_mut(|mem| mem.request_focus(first.id));
+            }
+            return;
+        }
+
+        // Alt + the mnemonic opens a menu:
+        let mnemonic_index = ui.input_mut(|i| {
+            buttons.iter().position(|button| {
+                button
+                    .mnemonic
+                    .is_some_and(|key| i.consume_key(Modifiers::ALT, key))
+            })
+        });
+        if let Some(index) = mnemonic_index {
+            open_menu(&buttons[index]);
+            return;
+        }
+
+        let Some(current) = open_index.or(focused_index) else {
+            return;
+        };
+
+        // Left and right move between the menus:
+        if menu_arrows_allowed(ctx) {
+            let num = buttons.len();
+            let next = ui.input_mut(|i| {
+                if i.consume_key(Modifiers::NONE, Key::ArrowRight) {
+                    Some((current + 1) % num)
+                } else if i.consume_key(Modifiers::NONE, Key::ArrowLeft) {
+                    Some((current + num - 1) % num)
+                } else {
+                    None
+                }
+            });
+            if let Some(next) = next {
+                if open_index.is_some() {
+                    open_menu(&buttons[next]);
+                } else {
+                    ctx.memory_mut(|mem| {
+                        mem.request_focus(buttons[next].id);
+                        mem.cancel_focus_move();
+                    });
+                }
+                return;
+            }
+        }
+
+        // Down opens the menu of the focused button:
+        if open_index.is_none() && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowDown))
+        {
+            open_menu(&buttons[current]);
+        }
+    }
+}
+
 /// Horizontal menu bar where you can add [`MenuButton`]s.
 ///
 /// The menu bar goes well in a [`crate::TopBottomPanel::top`],
 /// but can also be placed in a [`crate::Window`].
 /// In the latter case you may want to wrap it in [`Frame`].
 ///
+/// ### Keyboard navigation
+/// Like native menu bars, the menu bar can be used with the keyboard:
+/// - Pressing and releasing alt focuses the first menu button (and pressing it again leaves the bar).
+/// - Left and right move between the menus, down (or enter) opens the focused menu.
+/// - Up and down move between the items of a menu, right opens a submenu and left closes it.
+/// - Enter activates the focused item, escape closes the menu.
+///
+/// With [`MenuConfig::mnemonics`], put a `&` in front of a letter in the text of a
+/// [`MenuButton`] or [`SubMenuButton`] to make it the mnemonic of the menu: the letter is
+/// underlined, and pressing alt and the letter opens the menu (or just the letter, in an open menu).
+/// Use `&&` for a literal `&`.
+///
 /// ### Example:
 /// ```
+/// # use egui::containers::menu::MenuConfig;
 /// # egui::__run_test_ui(|ui| {
-/// egui::MenuBar::new().ui(ui, |ui| {
-///     ui.menu_button("File", |ui| {
+/// egui::MenuBar::new().config(MenuConfig::new().mnemonics(true)).ui(ui, |ui| {
+///     ui.menu_button("&File", |ui| {
 ///         if ui.button("Quit").clicked() {
 ///             ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
 ///         }
@@ -253,7 +664,11 @@ impl MenuBar {
                     let height = ui.spacing().interact_size.y;
                     ui.set_min_size(vec2(ui.available_width(), height));
 
-                    content(ui)
+                    let bar_id = ui.unique_id();
+                    let was_open = MenuBarState::begin(ui.ctx(), bar_id);
+                    let inner = content(ui);
+                    MenuBarState::keyboard_navigation(ui, bar_id, was_open);
+                    inner
                 },
             )
             .inner
@@ -293,21 +708,49 @@ impl<'a> MenuButton<'a> {
     }
 
     /// Show the menu button.
+    ///
+    /// With [`MenuConfig::mnemonics`], a `&` in the text marks the mnemonic, see [`MenuBar`].
     pub fn ui<R>(
         self,
         ui: &mut Ui,
         content: impl FnOnce(&mut Ui) -> R,
     ) -> (Response, Option<InnerResponse<R>>) {
-        let response = self.button.ui(ui);
         let mut config = self.config.unwrap_or_else(|| MenuConfig::find(ui));
+        let (button, mnemonic) = button_mnemonic(self.button, ui, config.mnemonics);
+        let response = button.ui(ui);
+        let popup_id = Popup::default_response_id(&response);
+
+        if config.bar {
+            let button = MenuBarButton {
+                id: response.id,
+                popup_id,
+                mnemonic,
+            };
+            MenuBarState::add_button(ui.ctx(), find_menu_root(ui).id, button);
+        }
         config.bar = false;
+
+        // Clicked with enter or space:
+        let clicked_with_keyboard = response.clicked() && !ui.input(|i| i.pointer.any_click());
+        if clicked_with_keyboard && !Popup::is_id_open(ui.ctx(), popup_id) {
+            request_focus_first_item(ui.ctx(), popup_id);
+        }
+
+        let close_behavior = config.close_behavior;
         let inner = Popup::menu(&response)
-            .close_behavior(config.close_behavior)
+            .close_behavior(close_behavior)
             .style(config.style.clone())
             .info(
                 UiStackInfo::new(UiKind::Menu).with_tag_value(MenuConfig::MENU_CONFIG_TAG, config),
             )
-            .show(content);
+            .show(|ui| {
+                let inner = content(ui);
+                menu_keyboard_ui(ui, popup_id, close_behavior);
+                #[cfg(feature = "accesskit")]
+                menu_accessibility_ui(ui);
+                inner
+            });
+        menu_button_accessibility(&response, inner.is_some());
         (response, inner)
     }
 }
@@ -347,24 +790,55 @@ impl<'a> SubMenuButton<'a> {
     }
 
     /// Show the submenu button.
+    ///
+    /// With [`MenuConfig::mnemonics`], a `&` in the text marks the mnemonic, see [`MenuBar`].
     pub fn ui<R>(
         self,
         ui: &mut Ui,
         content: impl FnOnce(&mut Ui) -> R,
     ) -> (Response, Option<InnerResponse<R>>) {
         let my_id = ui.next_auto_id();
-        let open = MenuState::from_ui(ui, |state, _| {
-            state.open_item == Some(SubMenu::id_from_widget_id(my_id))
+        let sub_menu_id = SubMenu::id_from_widget_id(my_id);
+        let (open, any_open) = MenuState::from_ui(ui, |state, _| {
+            (
+                state.open_item == Some(sub_menu_id),
+                state.open_item.is_some(),
+            )
         });
         let inactive = ui.style().visuals.widgets.inactive;
         // TODO(lucasmerlin) add `open` function to `Button`
         if open {
             ui.style_mut().visuals.widgets.inactive = ui.style().visuals.widgets.open;
         }
-        let response = self.button.ui(ui);
+        let mnemonics = self
+            .sub_menu
+            .config
+            .as_ref()
+            .map_or_else(|| MenuConfig::find(ui).mnemonics, |config| config.mnemonics);
+        let (button, mnemonic) = button_mnemonic(self.button, ui, mnemonics);
+        let response = button.ui(ui);
         ui.style_mut().visuals.widgets.inactive = inactive;
 
+        // Open the submenu with right, enter, space or the mnemonic:
+        if !open && ui.is_enabled() {
+            let focus_in_menu = focused_layer(ui.ctx()) == Some(ui.layer_id());
+            let arrows_allowed = response.has_focus() && menu_arrows_allowed(ui.ctx());
+            let open_with_keyboard = ui.input_mut(|i| {
+                (arrows_allowed && i.consume_key(Modifiers::NONE, Key::ArrowRight))
+                    || (response.clicked() && !i.pointer.any_click())
+                    || (focus_in_menu
+                        && !any_open
+                        && mnemonic.is_some_and(|key| i.consume_key(Modifiers::NONE, key)))
+            });
+            if open_with_keyboard {
+                MenuState::from_ui(ui, |state, _| state.open_item = Some(sub_menu_id));
+                request_focus_first_item(ui.ctx(), sub_menu_id);
+                ui.ctx().memory_mut(|mem| mem.cancel_focus_move());
+            }
+        }
+
         let popup_response = self.sub_menu.show(ui, &response, content);
+        menu_button_accessibility(&response, popup_response.is_some());
 
         (response, popup_response)
     }
@@ -481,7 +955,11 @@ impl SubMenu {
                 if button_response.clicked() || button_response.is_pointer_button_down_on() {
                     ui.ctx().move_to_top(ui.layer_id());
                 }
-                content(ui)
+                let inner = content(ui);
+                menu_keyboard_ui(ui, id, menu_config.close_behavior);
+                #[cfg(feature = "accesskit")]
+                menu_accessibility_ui(ui);
+                inner
             });
 
         if let Some(popup_response) = &popup_response {
@@ -545,6 +1023,19 @@ impl SubMenu {
                 set_open = Some(false);
             }
 
+            // Left closes the submenu, and puts the focus back on its button:
+            let close_with_keyboard = is_deepest_submenu
+                && focused_layer(ui.ctx()) == Some(popup_response.response.layer_id)
+                && menu_arrows_allowed(ui.ctx())
+                && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowLeft));
+            if close_with_keyboard {
+                set_open = Some(false);
+                ui.ctx().memory_mut(|mem| {
+                    mem.request_focus(button_response.id);
+                    mem.cancel_focus_move();
+                });
+            }
+
             if ui.will_parent_close() {
                 ui.data_mut(|data| data.remove_by_type::<MenuState>());
             }
@@ -0,0 +1,188 @@
+//! A structured way to build menus, see [`MenuBuilder`].
+
+use crate::{
+    Atom, AtomExt as _, Button, InnerResponse, Label, Response, Separator, Ui, Vec2, WidgetText,
+};
+
+use super::menu::SubMenuButton;
+
+/// An item in a menu, added with [`MenuBuilder::item`], [`MenuBuilder::checkable`]
+/// or [`MenuBuilder::submenu`].
+#[must_use = "You should add it to a menu with `MenuBuilder::item`"]
+pub struct MenuItem<'a> {
+    text: WidgetText,
+    icon: Option<Atom<'a>>,
+    shortcut_text: Option<Atom<'a>>,
+    disabled_reason: Option<WidgetText>,
+}
+
+impl<'a> MenuItem<'a> {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            icon: None,
+            shortcut_text: None,
+            disabled_reason: None,
+        }
+    }
+
+    /// An icon in front of the text, e.g. an emoji or an [`crate::Image`].
+    ///
+    /// Items without an icon leave the space empty, so the texts of all items line up.
+    #[inline]
+    pub fn icon(mut self, icon: impl Into<Atom<'a>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Text shown at the right side of the item, e.g. a keyboard shortcut like `"Ctrl+S"`.
+    ///
+    /// See also [`crate::Context::format_shortcut`].
+    #[inline]
+    pub fn shortcut_text(mut self, shortcut_text: impl Into<Atom<'a>>) -> Self {
+        self.shortcut_text = Some(shortcut_text.into());
+        self
+    }
+
+    /// Disable the item, and show why when it is hovered.
+    #[inline]
+    pub fn disabled(mut self, reason: impl Into<WidgetText>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+
+    /// Disable the item if `enabled` is `false`, and show why when it is hovered.
+    #[inline]
+    pub fn enabled_or(self, enabled: bool, reason: impl Into<WidgetText>) -> Self {
+        if enabled { self } else { self.disabled(reason) }
+    }
+
+    /// The button for the item, and the reason it is disabled (if it is).
+    fn into_button(self, ui: &Ui, checked: bool) -> (Button<'a>, Option<WidgetText>) {
+        let Self {
+            text,
+            icon,
+            shortcut_text,
+            disabled_reason,
+        } = self;
+
+        // A check mark or icon, or empty space so all items line up:
+        let icon_size = Vec2::splat(ui.spacing().icon_width);
+        let icon = if checked {
+            "✔".atom_size(icon_size)
+        } else if let Some(icon) = icon {
+            icon.atom_size(icon_size)
+        } else {
+            Atom::default().atom_size(icon_size)
+        };
+
+        let mut button = Button::new((icon, text));
+        if let Some(shortcut_text) = shortcut_text {
+            button = button.shortcut_text(shortcut_text);
+        }
+        (button, disabled_reason)
+    }
+}
+
+/// Build a menu out of [`MenuItem`]s, with icons, shortcuts, checkable items,
+/// labeled sections and submenus.
+///
+/// Use it in any menu via [`MenuBuilder::new`], or directly with
+/// [`Response::context_menu_builder`].
+///
+/// The contents of a submenu are only built while it is open,
+/// so even large menus are cheap to show.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut show_grid = true;
+/// use egui::MenuItem;
+///
+/// let response = ui.button("Right-click me!");
+/// response.context_menu_builder(|menu| {
+///     menu.section("Edit");
+///     if menu.item(MenuItem::new("Cut").icon("✂").shortcut_text("Ctrl+X")).clicked() {
+///         // …
+///     }
+///     menu.item(MenuItem::new("Paste").disabled("The clipboard is empty"));
+///
+///     menu.section("View");
+///     menu.checkable(&mut show_grid, MenuItem::new("Show grid"));
+///     menu.submenu(MenuItem::new("Zoom"), |menu| {
+///         for zoom in [50, 100, 200] {
+///             menu.item(MenuItem::new(format!("{zoom}%")));
+///         }
+///     });
+/// });
+/// # });
+/// ```
+pub struct MenuBuilder<'a> {
+    /// The [`Ui`] of the menu, to add anything else.
+    pub ui: &'a mut Ui,
+}
+
+impl<'a> MenuBuilder<'a> {
+    /// Build a menu in the given [`Ui`], e.g. the one passed to [`Response::context_menu`].
+    pub fn new(ui: &'a mut Ui) -> Self {
+        Self { ui }
+    }
+
+    /// A horizontal line between items.
+    pub fn separator(&mut self) {
+        self.ui.separator();
+    }
+
+    /// A separator with a label, to start a new group of items.
+    pub fn section(&mut self, label: impl Into<WidgetText>) {
+        self.ui.horizontal(|ui| {
+            ui.add(Label::new(label.into().small().weak()).selectable(false));
+            ui.add(Separator::default().horizontal());
+        });
+    }
+
+    /// Add an item. Check [`Response::clicked`] to see if it was picked.
+    pub fn item(&mut self, item: MenuItem<'_>) -> Response {
+        let (button, disabled_reason) = item.into_button(self.ui, false);
+        add_button(self.ui, button, disabled_reason)
+    }
+
+    /// Add an item with a check mark in front of it when `checked` is `true`.
+    ///
+    /// Clicking the item toggles `checked`.
+    pub fn checkable(&mut self, checked: &mut bool, item: MenuItem<'_>) -> Response {
+        let (button, disabled_reason) = item.into_button(self.ui, *checked);
+        let mut response = add_button(self.ui, button, disabled_reason);
+        if response.clicked() {
+            *checked = !*checked;
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Add an item that opens a submenu when hovered.
+    ///
+    /// `build` is only called while the submenu is open.
+    /// If the item is disabled, the submenu can't be opened.
+    pub fn submenu<R>(
+        &mut self,
+        item: MenuItem<'_>,
+        build: impl FnOnce(&mut MenuBuilder<'_>) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let (button, disabled_reason) = item.into_button(self.ui, false);
+        let button = button.right_text(SubMenuButton::RIGHT_ARROW);
+        if disabled_reason.is_some() {
+            return InnerResponse::new(None, add_button(self.ui, button, disabled_reason));
+        }
+        let (response, inner) =
+            SubMenuButton::from_button(button).ui(self.ui, |ui| build(&mut MenuBuilder::new(ui)));
+        InnerResponse::new(inner.map(|inner| inner.inner), response)
+    }
+}
+
+fn add_button(ui: &mut Ui, button: Button<'_>, disabled_reason: Option<WidgetText>) -> Response {
+    if let Some(reason) = disabled_reason {
+        ui.add_enabled(false, button).on_disabled_hover_text(reason)
+    } else {
+        ui.add(button)
+    }
+}
@@ -1,38 +1,68 @@
-//! Containers are pieces of the UI which wraps other pieces of UI. Examples: [`Window`], [`ScrollArea`], [`Resize`], [`SidePanel`], etc.
+//! Containers are pieces of the UI which wraps other pieces of UI. Examples: [`Window`], [`ScrollArea`], [`Resize`], [`SidePanel`], [`Split`], [`Dialogs`], [`Dock`], etc.
 //!
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
+mod accordion;
+mod animated_layout;
 pub(crate) mod area;
 mod close_tag;
 pub mod collapsing_header;
 mod combo_box;
+pub mod dialogs;
+pub mod dock;
+mod drop_target;
 pub mod frame;
+mod magnifier;
+mod masonry;
 pub mod menu;
+mod menu_builder;
 pub mod modal;
 pub mod old_popup;
 pub mod panel;
+mod pie_menu;
 mod popup;
 pub(crate) mod resize;
+mod rubber_band;
 mod scene;
 pub mod scroll_area;
 mod sides;
+pub mod split;
+mod sticky_section;
+mod tab_bar;
 mod tooltip;
 pub(crate) mod window;
+mod wizard;
+mod zoom_pan_area;
 
 pub use {
+    accordion::{Accordion, AccordionUi},
+    animated_layout::{AnimatedLayout, AnimatedLayoutUi},
     area::{Area, AreaState},
     close_tag::ClosableTag,
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
+    dialogs::{DialogHandle, Dialogs},
+    dock::{Dock, DockTree, TabViewer},
+    drop_target::{DropTarget, DropTargetResponse},
     frame::Frame,
+    magnifier::Magnifier,
+    masonry::{Masonry, MasonryUi},
+    menu_builder::{MenuBuilder, MenuItem},
     modal::{Modal, ModalResponse},
     old_popup::*,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
+    pie_menu::PieMenu,
     popup::*,
     resize::Resize,
+    rubber_band::{RubberBand, RubberBandResponse},
     scene::{DragPanButtons, Scene},
     scroll_area::ScrollArea,
     sides::Sides,
+    split::{Split, SplitState},
+    sticky_section::StickySection,
+    tab_bar::{Tab, TabBar, TabBarResponse},
     tooltip::*,
-    window::Window,
+    window::{Window, WindowShortcuts},
+    wizard::{Wizard, WizardResponse, WizardState, WizardUi},
+    zoom_pan_area::{ZoomPanArea, ZoomPanResponse, ZoomPanState},
 };
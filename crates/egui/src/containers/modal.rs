@@ -83,6 +83,7 @@ impl Modal {
 
         let is_top_modal = ctx.memory_mut(|mem| {
             mem.set_modal_layer(area.layer());
+            mem.restore_focus_when_closed(area.layer().id);
             mem.top_modal_layer() == Some(area.layer())
         });
         let any_popup_open = crate::Popup::is_any_open(ctx);
@@ -18,14 +18,63 @@
 use emath::GuiRounding as _;
 
 use crate::{
-    Align, Context, CursorIcon, Frame, Id, InnerResponse, LayerId, Layout, NumExt as _, Rangef,
-    Rect, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, lerp, vec2,
+    Color32, Context, CursorIcon, Frame, Id, InnerResponse, LayerId, Layout, LayoutDirection,
+    NumExt as _, Order, Rangef, Rect, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2,
+    lerp, vec2,
 };
 
 fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
     ctx.animate_bool_responsive(id, is_expanded)
 }
 
+/// A child [`Ui`] on a layer above `ui`, for panels in overlay mode.
+///
+/// It starts with a scrim that dims everything below it, and blocks it from being interacted with.
+fn overlay_ui(ui: &mut Ui, id: Id, how_expanded: f32) -> Ui {
+    let scrim_rect = ui.clip_rect();
+    let mut overlay_ui = ui.new_child(
+        UiBuilder::new()
+            .layer_id(LayerId::new(Order::Foreground, id.with("overlay")))
+            .max_rect(ui.available_rect_before_wrap()),
+    );
+    overlay_ui.set_clip_rect(scrim_rect);
+    overlay_ui.interact(scrim_rect, id.with("scrim"), Sense::CLICK | Sense::DRAG);
+    overlay_ui.painter().rect_filled(
+        scrim_rect,
+        0.0,
+        Color32::from_black_alpha(100).gamma_multiply(how_expanded),
+    );
+    overlay_ui
+}
+
+/// Paints the line showing where a panel edge will end up, on top of the other panels.
+fn resize_preview_painter(ui: &Ui, id: Id, clip_rect: Rect) -> crate::Painter {
+    ui.ctx()
+        .layer_painter(LayerId::new(Order::Foreground, id.with("resize_preview")))
+        .with_clip_rect(clip_rect)
+}
+
+/// The builder for the [`Ui`] of a panel.
+fn panel_ui_builder(
+    id: Id,
+    kind: UiKind,
+    panel_rect: Rect,
+    overlay: bool,
+    direction: LayoutDirection,
+) -> UiBuilder {
+    let builder = UiBuilder::new()
+        .id_salt(id)
+        .ui_stack_info(UiStackInfo::new(kind))
+        .max_rect(panel_rect)
+        .layout(Layout::top_down(direction.start()));
+    if overlay {
+        // Don't let clicks on the panel through to the scrim below it:
+        builder.sense(Sense::CLICK | Sense::DRAG)
+    } else {
+        builder
+    }
+}
+
 /// State regarding panels.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -51,6 +100,9 @@ impl PanelState {
 // ----------------------------------------------------------------------------
 
 /// [`Left`](Side::Left) or [`Right`](Side::Right)
+///
+/// In right-to-left mode (see [`crate::LayoutDirection`]) the sides are mirrored,
+/// so that [`Side::Left`] is where lines start, i.e. on the right.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Side {
     Left,
@@ -58,6 +110,14 @@ pub enum Side {
 }
 
 impl Side {
+    /// The side on screen, taking right-to-left mode into account.
+    fn on_screen(self, layout_direction: LayoutDirection) -> Self {
+        match layout_direction {
+            LayoutDirection::Ltr => self,
+            LayoutDirection::Rtl => self.opposite(),
+        }
+    }
+
     fn opposite(self) -> Self {
         match self {
             Self::Left => Self::Right,
@@ -111,9 +171,11 @@ pub struct SidePanel {
     id: Id,
     frame: Option<Frame>,
     resizable: bool,
+    resize_preview: bool,
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+    overlay: bool,
 }
 
 impl SidePanel {
@@ -134,9 +196,11 @@ impl SidePanel {
             id: id.into(),
             frame: None,
             resizable: true,
+            resize_preview: false,
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            overlay: false,
         }
     }
 
@@ -157,6 +221,19 @@ impl SidePanel {
         self
     }
 
+    /// While resizing, only show a line where the edge will end up,
+    /// and resize the panel when the drag is released.
+    ///
+    /// This avoids laying out the contents at every size along the way,
+    /// which is useful if they are expensive.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn resize_preview(mut self, resize_preview: bool) -> Self {
+        self.resize_preview = resize_preview;
+        self
+    }
+
     /// Show a separator line, even when not interacting with it?
     ///
     /// Default: `true`.
@@ -214,6 +291,20 @@ impl SidePanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Float the panel above the rest of the [`Ui`] or screen instead of taking up space,
+    /// like a drawer on mobile.
+    ///
+    /// Everything below the panel is dimmed by a scrim, and can't be interacted with.
+    /// Use [`crate::Response::clicked_elsewhere`] on the returned response to close the panel
+    /// when the user clicks on the scrim.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
 }
 
 impl SidePanel {
@@ -223,25 +314,42 @@ impl SidePanel {
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_inside_dyn(ui, Box::new(add_contents))
+        self.show_inside_dyn(ui, Box::new(add_contents), 1.0)
     }
 
     /// Show the panel inside a [`Ui`].
+    ///
+    /// `how_expanded` is less than one while the panel is sliding in or out.
     fn show_inside_dyn<'c, R>(
         self,
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        how_expanded: f32,
     ) -> InnerResponse<R> {
         let Self {
             side,
             id,
             frame,
             resizable,
+            resize_preview,
             show_separator_line,
             default_width,
             width_range,
+            overlay,
         } = self;
 
+        let side = side.on_screen(ui.layout_direction());
+        let is_animating = how_expanded < 1.0;
+        let resizable = resizable && !is_animating;
+
+        let mut overlay_ui;
+        let ui = if overlay {
+            overlay_ui = self::overlay_ui(ui, id, how_expanded);
+            &mut overlay_ui
+        } else {
+            ui
+        };
+
         let available_rect = ui.available_rect_before_wrap();
         let mut panel_rect = available_rect;
         let mut width = default_width;
@@ -257,36 +365,46 @@ impl SidePanel {
         let resize_id = id.with("__resize");
         let mut resize_hover = false;
         let mut is_resizing = false;
+        let mut preview_width = None;
         if resizable {
             // First we read the resize interaction results, to avoid frame latency in the resize:
             if let Some(resize_response) = ui.ctx().read_response(resize_id) {
                 resize_hover = resize_response.hovered();
                 is_resizing = resize_response.dragged();
 
-                if is_resizing {
+                if is_resizing || resize_preview && resize_response.drag_stopped() {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
-                        width = (pointer.x - side.side_x(panel_rect)).abs();
-                        width = clamp_to_range(width, width_range).at_most(available_rect.width());
-                        side.set_rect_width(&mut panel_rect, width);
+                        let new_width = (pointer.x - side.side_x(panel_rect)).abs();
+                        let new_width =
+                            clamp_to_range(new_width, width_range).at_most(available_rect.width());
+                        if resize_preview && is_resizing {
+                            preview_width = Some(new_width);
+                        } else {
+                            width = new_width;
+                            side.set_rect_width(&mut panel_rect, width);
+                        }
                     }
                 }
             }
         }
 
-        panel_rect = panel_rect.round_ui();
+        // Slide the panel out past its side while animating:
+        let slide = side.sign() * (1.0 - how_expanded) * width;
+        panel_rect = panel_rect.translate(vec2(slide, 0.0)).round_ui();
 
-        let mut panel_ui = ui.new_child(
-            UiBuilder::new()
-                .id_salt(id)
-                .ui_stack_info(UiStackInfo::new(match side {
-                    Side::Left => UiKind::LeftPanel,
-                    Side::Right => UiKind::RightPanel,
-                }))
-                .max_rect(panel_rect)
-                .layout(Layout::top_down(Align::Min)),
-        );
+        let kind = match side {
+            Side::Left => UiKind::LeftPanel,
+            Side::Right => UiKind::RightPanel,
+        };
+        let mut panel_ui = ui.new_child(panel_ui_builder(
+            id,
+            kind,
+            panel_rect,
+            overlay,
+            ui.layout_direction(),
+        ));
         panel_ui.expand_to_include_rect(panel_rect);
-        panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        panel_ui.set_clip_rect(panel_rect.intersect(available_rect)); // If we overflow, don't do so visibly (#4475)
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
@@ -309,7 +427,7 @@ impl SidePanel {
             }
             ui.set_cursor(cursor);
         }
-        ui.expand_to_include_rect(rect);
+        ui.expand_to_include_rect(rect.intersect(available_rect));
 
         if resizable {
             // Now we do the actual resize interaction, on top of all the contents.
@@ -324,15 +442,16 @@ impl SidePanel {
         }
 
         if resize_hover || is_resizing {
+            let width = preview_width.unwrap_or(width);
             let cursor_icon = if width <= width_range.min {
-                match self.side {
+                match side {
                     Side::Left => CursorIcon::ResizeEast,
                     Side::Right => CursorIcon::ResizeWest,
                 }
             } else if width < width_range.max {
                 CursorIcon::ResizeHorizontal
             } else {
-                match self.side {
+                match side {
                     Side::Left => CursorIcon::ResizeWest,
                     Side::Right => CursorIcon::ResizeEast,
                 }
@@ -340,7 +459,9 @@ impl SidePanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        if !is_animating {
+            PanelState { rect }.store(ui.ctx(), id);
+        }
 
         {
             let stroke = if is_resizing {
@@ -359,6 +480,17 @@ impl SidePanel {
             // Make sure the line is on the inside of the panel:
             let resize_x = resize_x + 0.5 * side.sign() * stroke.width;
             ui.painter().vline(resize_x, panel_rect.y_range(), stroke);
+
+            if let Some(preview_width) = preview_width {
+                let mut preview_rect = panel_rect;
+                side.set_rect_width(&mut preview_rect, preview_width);
+                let preview_x = side.opposite().side_x(preview_rect);
+                resize_preview_painter(ui, id, available_rect).vline(
+                    preview_x,
+                    panel_rect.y_range(),
+                    stroke,
+                );
+            }
         }
 
         inner_response
@@ -370,7 +502,7 @@ impl SidePanel {
         ctx: &Context,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_dyn(ctx, Box::new(add_contents))
+        self.show_dyn(ctx, Box::new(add_contents), 1.0)
     }
 
     /// Show the panel at the top level.
@@ -378,8 +510,10 @@ impl SidePanel {
         self,
         ctx: &Context,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        how_expanded: f32,
     ) -> InnerResponse<R> {
-        let side = self.side;
+        let side = self.side.on_screen(ctx.style().layout_direction);
+        let overlay = self.overlay;
         let available_rect = ctx.available_rect();
         let mut panel_ui = Ui::new(
             ctx.clone(),
@@ -390,9 +524,13 @@ impl SidePanel {
         );
         panel_ui.set_clip_rect(ctx.screen_rect());
 
-        let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents);
+        let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents, how_expanded);
         let rect = inner_response.response.rect;
 
+        if overlay {
+            return inner_response;
+        }
+
         match side {
             Side::Left => ctx.pass_state_mut(|state| {
                 state.allocate_left_panel(Rect::from_min_max(available_rect.min, rect.max));
@@ -405,7 +543,10 @@ impl SidePanel {
     }
 
     /// Show the panel if `is_expanded` is `true`,
-    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    /// otherwise don't show it, but with a nice animation of the panel sliding in and out.
+    ///
+    /// The contents are shown while the panel slides, so this returns `Some`
+    /// as soon as any part of the panel is visible, and `None` only when it is fully hidden.
     pub fn show_animated<R>(
         self,
         ctx: &Context,
@@ -416,29 +557,16 @@ impl SidePanel {
 
         if 0.0 == how_expanded {
             None
-        } else if how_expanded < 1.0 {
-            // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its width.
-            // Then we can actually paint it as it animates.
-            let expanded_width = PanelState::load(ctx, self.id)
-                .map_or(self.default_width, |state| state.rect.width());
-            let fake_width = how_expanded * expanded_width;
-            Self {
-                id: self.id.with("animating_panel"),
-                ..self
-            }
-            .resizable(false)
-            .exact_width(fake_width)
-            .show(ctx, |_ui| {});
-            None
         } else {
-            // Show the real panel:
-            Some(self.show(ctx, add_contents))
+            Some(self.show_dyn(ctx, Box::new(add_contents), how_expanded))
         }
     }
 
     /// Show the panel if `is_expanded` is `true`,
-    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    /// otherwise don't show it, but with a nice animation of the panel sliding in and out.
+    ///
+    /// The contents are shown while the panel slides, so this returns `Some`
+    /// as soon as any part of the panel is visible, and `None` only when it is fully hidden.
     pub fn show_animated_inside<R>(
         self,
         ui: &mut Ui,
@@ -449,24 +577,8 @@ impl SidePanel {
 
         if 0.0 == how_expanded {
             None
-        } else if how_expanded < 1.0 {
-            // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its width.
-            // Then we can actually paint it as it animates.
-            let expanded_width = PanelState::load(ui.ctx(), self.id)
-                .map_or(self.default_width, |state| state.rect.width());
-            let fake_width = how_expanded * expanded_width;
-            Self {
-                id: self.id.with("animating_panel"),
-                ..self
-            }
-            .resizable(false)
-            .exact_width(fake_width)
-            .show_inside(ui, |_ui| {});
-            None
         } else {
-            // Show the real panel:
-            Some(self.show_inside(ui, add_contents))
+            Some(self.show_inside_dyn(ui, Box::new(add_contents), how_expanded))
         }
     }
 
@@ -598,9 +710,11 @@ pub struct TopBottomPanel {
     id: Id,
     frame: Option<Frame>,
     resizable: bool,
+    resize_preview: bool,
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    overlay: bool,
 }
 
 impl TopBottomPanel {
@@ -621,9 +735,11 @@ impl TopBottomPanel {
             id: id.into(),
             frame: None,
             resizable: false,
+            resize_preview: false,
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            overlay: false,
         }
     }
 
@@ -644,6 +760,19 @@ impl TopBottomPanel {
         self
     }
 
+    /// While resizing, only show a line where the edge will end up,
+    /// and resize the panel when the drag is released.
+    ///
+    /// This avoids laying out the contents at every size along the way,
+    /// which is useful if they are expensive.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn resize_preview(mut self, resize_preview: bool) -> Self {
+        self.resize_preview = resize_preview;
+        self
+    }
+
     /// Show a separator line, even when not interacting with it?
     ///
     /// Default: `true`.
@@ -704,6 +833,20 @@ impl TopBottomPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Float the panel above the rest of the [`Ui`] or screen instead of taking up space,
+    /// like a bottom sheet on mobile.
+    ///
+    /// Everything below the panel is dimmed by a scrim, and can't be interacted with.
+    /// Use [`crate::Response::clicked_elsewhere`] on the returned response to close the panel
+    /// when the user clicks on the scrim.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
 }
 
 impl TopBottomPanel {
@@ -713,25 +856,41 @@ impl TopBottomPanel {
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_inside_dyn(ui, Box::new(add_contents))
+        self.show_inside_dyn(ui, Box::new(add_contents), 1.0)
     }
 
     /// Show the panel inside a [`Ui`].
+    ///
+    /// `how_expanded` is less than one while the panel is sliding in or out.
     fn show_inside_dyn<'c, R>(
         self,
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        how_expanded: f32,
     ) -> InnerResponse<R> {
         let Self {
             side,
             id,
             frame,
             resizable,
+            resize_preview,
             show_separator_line,
             default_height,
             height_range,
+            overlay,
         } = self;
 
+        let is_animating = how_expanded < 1.0;
+        let resizable = resizable && !is_animating;
+
+        let mut overlay_ui;
+        let ui = if overlay {
+            overlay_ui = self::overlay_ui(ui, id, how_expanded);
+            &mut overlay_ui
+        } else {
+            ui
+        };
+
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
 
         let available_rect = ui.available_rect_before_wrap();
@@ -753,37 +912,46 @@ impl TopBottomPanel {
         let resize_id = id.with("__resize");
         let mut resize_hover = false;
         let mut is_resizing = false;
+        let mut preview_height = None;
         if resizable {
             // First we read the resize interaction results, to avoid frame latency in the resize:
             if let Some(resize_response) = ui.ctx().read_response(resize_id) {
                 resize_hover = resize_response.hovered();
                 is_resizing = resize_response.dragged();
 
-                if is_resizing {
+                if is_resizing || resize_preview && resize_response.drag_stopped() {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
-                        height = (pointer.y - side.side_y(panel_rect)).abs();
-                        height =
-                            clamp_to_range(height, height_range).at_most(available_rect.height());
-                        side.set_rect_height(&mut panel_rect, height);
+                        let new_height = (pointer.y - side.side_y(panel_rect)).abs();
+                        let new_height = clamp_to_range(new_height, height_range)
+                            .at_most(available_rect.height());
+                        if resize_preview && is_resizing {
+                            preview_height = Some(new_height);
+                        } else {
+                            height = new_height;
+                            side.set_rect_height(&mut panel_rect, height);
+                        }
                     }
                 }
             }
         }
 
-        panel_rect = panel_rect.round_ui();
+        // Slide the panel out past its side while animating:
+        let slide = side.sign() * (1.0 - how_expanded) * height;
+        panel_rect = panel_rect.translate(vec2(0.0, slide)).round_ui();
 
-        let mut panel_ui = ui.new_child(
-            UiBuilder::new()
-                .id_salt(id)
-                .ui_stack_info(UiStackInfo::new(match side {
-                    TopBottomSide::Top => UiKind::TopPanel,
-                    TopBottomSide::Bottom => UiKind::BottomPanel,
-                }))
-                .max_rect(panel_rect)
-                .layout(Layout::top_down(Align::Min)),
-        );
+        let kind = match side {
+            TopBottomSide::Top => UiKind::TopPanel,
+            TopBottomSide::Bottom => UiKind::BottomPanel,
+        };
+        let mut panel_ui = ui.new_child(panel_ui_builder(
+            id,
+            kind,
+            panel_rect,
+            overlay,
+            ui.layout_direction(),
+        ));
         panel_ui.expand_to_include_rect(panel_rect);
-        panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        panel_ui.set_clip_rect(panel_rect.intersect(available_rect)); // If we overflow, don't do so visibly (#4475)
 
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
@@ -805,7 +973,7 @@ impl TopBottomPanel {
             }
             ui.set_cursor(cursor);
         }
-        ui.expand_to_include_rect(rect);
+        ui.expand_to_include_rect(rect.intersect(available_rect));
 
         if resizable {
             // Now we do the actual resize interaction, on top of all the contents.
@@ -821,6 +989,7 @@ impl TopBottomPanel {
         }
 
         if resize_hover || is_resizing {
+            let height = preview_height.unwrap_or(height);
             let cursor_icon = if height <= height_range.min {
                 match self.side {
                     TopBottomSide::Top => CursorIcon::ResizeSouth,
@@ -837,7 +1006,9 @@ impl TopBottomPanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        if !is_animating {
+            PanelState { rect }.store(ui.ctx(), id);
+        }
 
         {
             let stroke = if is_resizing {
@@ -856,6 +1027,17 @@ impl TopBottomPanel {
             // Make sure the line is on the inside of the panel:
             let resize_y = resize_y + 0.5 * side.sign() * stroke.width;
             ui.painter().hline(panel_rect.x_range(), resize_y, stroke);
+
+            if let Some(preview_height) = preview_height {
+                let mut preview_rect = panel_rect;
+                side.set_rect_height(&mut preview_rect, preview_height);
+                let preview_y = side.opposite().side_y(preview_rect);
+                resize_preview_painter(ui, id, available_rect).hline(
+                    panel_rect.x_range(),
+                    preview_y,
+                    stroke,
+                );
+            }
         }
 
         inner_response
@@ -867,7 +1049,7 @@ impl TopBottomPanel {
         ctx: &Context,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_dyn(ctx, Box::new(add_contents))
+        self.show_dyn(ctx, Box::new(add_contents), 1.0)
     }
 
     /// Show the panel at the top level.
@@ -875,9 +1057,11 @@ impl TopBottomPanel {
         self,
         ctx: &Context,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        how_expanded: f32,
     ) -> InnerResponse<R> {
         let available_rect = ctx.available_rect();
         let side = self.side;
+        let overlay = self.overlay;
 
         let mut panel_ui = Ui::new(
             ctx.clone(),
@@ -888,9 +1072,13 @@ impl TopBottomPanel {
         );
         panel_ui.set_clip_rect(ctx.screen_rect());
 
-        let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents);
+        let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents, how_expanded);
         let rect = inner_response.response.rect;
 
+        if overlay {
+            return inner_response;
+        }
+
         match side {
             TopBottomSide::Top => {
                 ctx.pass_state_mut(|state| {
@@ -908,7 +1096,10 @@ impl TopBottomPanel {
     }
 
     /// Show the panel if `is_expanded` is `true`,
-    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    /// otherwise don't show it, but with a nice animation of the panel sliding in and out.
+    ///
+    /// The contents are shown while the panel slides, so this returns `Some`
+    /// as soon as any part of the panel is visible, and `None` only when it is fully hidden.
     pub fn show_animated<R>(
         self,
         ctx: &Context,
@@ -919,31 +1110,16 @@ impl TopBottomPanel {
 
         if 0.0 == how_expanded {
             None
-        } else if how_expanded < 1.0 {
-            // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its height.
-            // Then we can actually paint it as it animates.
-            let expanded_height = PanelState::load(ctx, self.id)
-                .map(|state| state.rect.height())
-                .or(self.default_height)
-                .unwrap_or_else(|| ctx.style().spacing.interact_size.y);
-            let fake_height = how_expanded * expanded_height;
-            Self {
-                id: self.id.with("animating_panel"),
-                ..self
-            }
-            .resizable(false)
-            .exact_height(fake_height)
-            .show(ctx, |_ui| {});
-            None
         } else {
-            // Show the real panel:
-            Some(self.show(ctx, add_contents))
+            Some(self.show_dyn(ctx, Box::new(add_contents), how_expanded))
         }
     }
 
     /// Show the panel if `is_expanded` is `true`,
-    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    /// otherwise don't show it, but with a nice animation of the panel sliding in and out.
+    ///
+    /// The contents are shown while the panel slides, so this returns `Some`
+    /// as soon as any part of the panel is visible, and `None` only when it is fully hidden.
     pub fn show_animated_inside<R>(
         self,
         ui: &mut Ui,
@@ -954,26 +1130,8 @@ impl TopBottomPanel {
 
         if 0.0 == how_expanded {
             None
-        } else if how_expanded < 1.0 {
-            // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its height.
-            // Then we can actually paint it as it animates.
-            let expanded_height = PanelState::load(ui.ctx(), self.id)
-                .map(|state| state.rect.height())
-                .or(self.default_height)
-                .unwrap_or_else(|| ui.style().spacing.interact_size.y);
-            let fake_height = how_expanded * expanded_height;
-            Self {
-                id: self.id.with("animating_panel"),
-                ..self
-            }
-            .resizable(false)
-            .exact_height(fake_height)
-            .show_inside(ui, |_ui| {});
-            None
         } else {
-            // Show the real panel:
-            Some(self.show_inside(ui, add_contents))
+            Some(self.show_inside_dyn(ui, Box::new(add_contents), how_expanded))
         }
     }
 
@@ -1116,7 +1274,7 @@ impl CentralPanel {
             UiBuilder::new()
                 .ui_stack_info(UiStackInfo::new(UiKind::CentralPanel))
                 .max_rect(panel_rect)
-                .layout(Layout::top_down(Align::Min)),
+                .layout(Layout::top_down(ui.layout_direction().start())),
         );
         panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
 
@@ -0,0 +1,286 @@
+//! A radial context menu, see [`PieMenu`].
+
+use std::f32::consts::TAU;
+
+use crate::{
+    Area, Id, Key, Modifiers, Order, PointerButton, Pos2, Response, Sense, Shape, TextStyle,
+    TextWrapMode, Ui, Vec2, WidgetText,
+};
+
+#[derive(Clone, Copy)]
+struct PieMenuState {
+    /// Where the menu was opened.
+    center: Pos2,
+
+    highlighted: Option<usize>,
+
+    /// The button that opened the menu, while it is still held down.
+    /// Releasing it picks the highlighted slice.
+    held: Option<PointerButton>,
+}
+
+/// A radial context menu: a ring of slices around the pointer, picked by direction.
+///
+/// This is quick to use with a pen or on a touch screen, since only the direction matters:
+/// * Press the secondary mouse button (or long-press on a touch screen) on the response,
+///   move towards a slice, and release to pick it.
+/// * Or click to open the menu, and then click a slice.
+/// * With the keyboard, press Shift+F10 while the widget has focus, pick a slice with the
+///   arrow keys and press Enter. Escape closes the menu.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.label("Right-click for tools");
+/// if let Some(tool) = egui::PieMenu::new("tools")
+///     .item("✏", "Pen")
+///     .item("⬛", "Rectangle")
+///     .item("⭕", "Circle")
+///     .item("🗑", "Erase")
+///     .show(&response)
+/// {
+///     println!("Picked tool {tool}");
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct PieMenu {
+    id_salt: Id,
+    items: Vec<(WidgetText, WidgetText)>,
+    radius: f32,
+}
+
+impl PieMenu {
+    /// Create a new [`PieMenu`] with an identifier that is unique for the response it is shown for.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            items: Vec::new(),
+            radius: 80.0,
+        }
+    }
+
+    /// Add a slice, clockwise from the top.
+    ///
+    /// The `icon` is shown in the slice, so should be short (e.g. an emoji),
+    /// and the `label` is shown in the middle while the slice is highlighted.
+    #[inline]
+    pub fn item(mut self, icon: impl Into<WidgetText>, label: impl Into<WidgetText>) -> Self {
+        self.items.push((icon.into(), label.into()));
+        self
+    }
+
+    /// The outer radius of the menu.
+    ///
+    /// Default: `80.0`.
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Show the menu if it has been opened on `response`.
+    ///
+    /// Returns the index of the picked item, on the frame it is picked.
+    pub fn show(self, response: &Response) -> Option<usize> {
+        let Self {
+            id_salt,
+            items,
+            radius,
+        } = self;
+        if items.is_empty() {
+            return None;
+        }
+
+        let ctx = &response.ctx;
+        let id = response.id.with(id_salt);
+        let inner_radius = 0.3 * radius;
+        let num_items = items.len();
+
+        let mut state = ctx
+            .data(|d| d.get_temp::<PieMenuState>(id))
+            .or_else(|| open(response, radius))?;
+
+        let (pointer, pointer_moved, released) = ctx.input(|i| {
+            (
+                i.pointer.latest_pos(),
+                i.pointer.delta() != Vec2::ZERO,
+                state
+                    .held
+                    .is_some_and(|button| !i.pointer.button_down(button)),
+            )
+        });
+        if pointer_moved {
+            if let Some(pointer) = pointer {
+                state.highlighted = slice_at(pointer - state.center, inner_radius, num_items);
+            }
+        }
+
+        let (next, previous, confirm, cancel) = ctx.input_mut(|i| {
+            let mut key = |keys: &[Key]| keys.iter().any(|&k| i.consume_key(Modifiers::NONE, k));
+            (
+                key(&[Key::ArrowRight, Key::ArrowDown]),
+                key(&[Key::ArrowLeft, Key::ArrowUp]),
+                key(&[Key::Enter, Key::Space]),
+                key(&[Key::Escape]),
+            )
+        });
+        if next {
+            state.highlighted = Some(state.highlighted.map_or(0, |i| (i + 1) % num_items));
+        }
+        if previous {
+            state.highlighted = Some(
+                state
+                    .highlighted
+                    .map_or(num_items - 1, |i| (i + num_items - 1) % num_items),
+            );
+        }
+
+        let mut picked = confirm.then_some(state.highlighted).flatten();
+        let mut close = cancel || confirm;
+        if released {
+            // Picked by direction, or else it stays open to be clicked:
+            state.held = None;
+            if state.highlighted.is_some() {
+                picked = state.highlighted;
+                close = true;
+            }
+        }
+
+        let menu_response = Area::new(id)
+            .kind(crate::UiKind::Menu)
+            .order(Order::Foreground)
+            .fixed_pos(state.center - Vec2::splat(radius))
+            .constrain(false)
+            .show(ctx, |ui| {
+                let (_, menu_response) =
+                    ui.allocate_exact_size(Vec2::splat(2.0 * radius), Sense::click());
+                paint(ui, &items, &state, inner_radius, radius);
+                menu_response
+            })
+            .inner;
+
+        if state.held.is_none() && !released {
+            if menu_response.clicked() {
+                // Clicking the middle closes the menu without picking anything:
+                picked = state.highlighted;
+                close = true;
+            } else if menu_response.clicked_elsewhere() {
+                close = true;
+            }
+        }
+
+        if close {
+            ctx.data_mut(|d| d.remove::<PieMenuState>(id));
+            ctx.request_repaint();
+        } else {
+            ctx.data_mut(|d| d.insert_temp(id, state));
+        }
+        picked
+    }
+}
+
+/// Should the menu be opened this frame, and if so, where?
+fn open(response: &Response, radius: f32) -> Option<PieMenuState> {
+    let ctx = &response.ctx;
+
+    // Keep the whole menu on screen, if possible:
+    let screen = ctx.screen_rect().shrink(radius);
+    let keep_on_screen = |pos: Pos2| {
+        if screen.is_positive() {
+            pos.clamp(screen.min, screen.max)
+        } else {
+            pos
+        }
+    };
+
+    let secondary_pressed =
+        response.hovered() && ctx.input(|i| i.pointer.button_pressed(PointerButton::Secondary));
+    if secondary_pressed || response.long_touched() {
+        let held = if secondary_pressed {
+            PointerButton::Secondary
+        } else {
+            PointerButton::Primary
+        };
+        let center = ctx.input(|i| i.pointer.interact_pos())?;
+        return Some(PieMenuState {
+            center: keep_on_screen(center),
+            highlighted: None,
+            held: Some(held),
+        });
+    }
+
+    if response.has_focus() && ctx.input_mut(|i| i.consume_key(Modifiers::SHIFT, Key::F10)) {
+        return Some(PieMenuState {
+            center: keep_on_screen(response.rect.center()),
+            highlighted: Some(0),
+            held: None,
+        });
+    }
+
+    None
+}
+
+/// The slice in the direction of `offset` from the center, if outside of the middle.
+fn slice_at(offset: Vec2, inner_radius: f32, num_items: usize) -> Option<usize> {
+    if offset.length() < inner_radius {
+        return None;
+    }
+    // Clockwise from straight up, like the slices:
+    let angle = (offset.angle() + TAU / 4.0).rem_euclid(TAU);
+    let step = TAU / num_items as f32;
+    Some((angle / step).round() as usize % num_items)
+}
+
+fn paint(
+    ui: &Ui,
+    items: &[(WidgetText, WidgetText)],
+    state: &PieMenuState,
+    inner_radius: f32,
+    radius: f32,
+) {
+    let visuals = ui.visuals();
+    let painter = ui.painter();
+    let center = state.center;
+    let step = TAU / items.len() as f32;
+
+    painter.add_with_shadow(
+        Shape::circle_filled(center, radius, visuals.window_fill),
+        visuals.popup_shadow,
+    );
+
+    for (i, (icon, _)) in items.iter().enumerate() {
+        let highlighted = state.highlighted == Some(i);
+        let (fill, text_color) = if highlighted {
+            (visuals.selection.bg_fill, visuals.selection.stroke.color)
+        } else {
+            (visuals.window_fill, visuals.text_color())
+        };
+        let middle = -TAU / 4.0 + i as f32 * step;
+        painter.add(Shape::ring_sector(
+            center,
+            inner_radius..=radius,
+            middle - step / 2.0..=middle + step / 2.0,
+            fill,
+            visuals.window_stroke,
+        ));
+
+        let galley = icon.clone().into_galley(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Heading,
+        );
+        let pos = center + 0.5 * (inner_radius + radius) * Vec2::angled(middle);
+        painter.galley(pos - 0.5 * galley.size(), galley, text_color);
+    }
+
+    if let Some((_, label)) = state.highlighted.and_then(|i| items.get(i)) {
+        let galley = label.clone().into_galley(
+            ui,
+            Some(TextWrapMode::Truncate),
+            2.0 * inner_radius,
+            TextStyle::Small,
+        );
+        painter.galley(center - 0.5 * galley.size(), galley, visuals.text_color());
+    }
+}
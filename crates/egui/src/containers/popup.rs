@@ -6,15 +6,15 @@ use emath::{Align, Pos2, Rect, RectAlign, Vec2, vec2};
 
 use crate::{
     Area, AreaState, Context, Frame, Id, InnerResponse, Key, LayerId, Layout, Order, Response,
-    Sense, Ui, UiKind, UiStackInfo,
+    ScrollArea, Sense, Ui, UiKind, UiStackInfo,
     containers::menu::{MenuConfig, MenuState, menu_style},
     style::StyleModifier,
 };
 
 /// What should we anchor the popup to?
 ///
-/// The final position for the popup will be calculated based on [`RectAlign`]
-/// and can be customized with [`Popup::align`] and [`Popup::align_alternatives`].
+/// The final position for the popup will be calculated based on [`RectAlign`] and [`PositionStrategy`],
+/// and can be customized with [`Popup::align`], [`Popup::align_alternatives`] and [`Popup::position`].
 /// [`PopupAnchor`] is the parent rect of [`RectAlign`].
 ///
 /// For [`PopupAnchor::Pointer`], [`PopupAnchor::PointerFixed`] and [`PopupAnchor::Position`],
@@ -134,6 +134,212 @@ impl OpenKind<'_> {
     }
 }
 
+/// How a [`Popup`] is placed next to its [`PopupAnchor`], and what happens when it doesn't fit
+/// on the screen. Set with [`Popup::position`].
+///
+/// The popup is placed according to [`Self::align`] if it fits. Otherwise:
+/// 1. With [`Self::flip`], the other sides of the anchor are tried (see [`Popup::align_alternatives`]).
+///    If none of them fit either, the one where most of the popup is visible is used.
+/// 2. With [`Self::resize`], the popup shrinks to the room on its side of the anchor,
+///    and its contents can be scrolled.
+/// 3. With [`Self::shift`], the popup is moved to stay on the screen, even if that means
+///    covering the anchor.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Popup, PositionStrategy, RectAlign};
+///
+/// let response = ui.button("Long list");
+/// Popup::menu(&response)
+///     .position(PositionStrategy::new(RectAlign::BOTTOM_START).resize(true))
+///     .show(|ui| {
+///         for i in 0..100 {
+///             ui.label(format!("Item {i}"));
+///         }
+///     });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PositionStrategy {
+    align: RectAlign,
+    flip: bool,
+    shift: bool,
+    resize: bool,
+}
+
+impl Default for PositionStrategy {
+    fn default() -> Self {
+        Self::new(RectAlign::BOTTOM_START)
+    }
+}
+
+impl PositionStrategy {
+    /// Place the popup at `align` relative to its anchor, flipping and shifting it if needed.
+    pub fn new(align: RectAlign) -> Self {
+        Self {
+            align,
+            flip: true,
+            shift: true,
+            resize: false,
+        }
+    }
+
+    /// The preferred position relative to the [`PopupAnchor`].
+    #[inline]
+    pub fn align(mut self, align: RectAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Try other sides of the anchor if the popup doesn't fit at [`Self::align`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Move the popup so it stays on the screen.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Shrink the popup to the room next to the anchor, scrolling its contents.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn resize(mut self, resize: bool) -> Self {
+        self.resize = resize;
+        self
+    }
+
+    /// Where a popup of the given size goes.
+    fn place(
+        self,
+        alternatives: Option<&[RectAlign]>,
+        bounds: Rect,
+        anchor_rect: Rect,
+        gap: f32,
+        size: Vec2,
+    ) -> Placement {
+        let Self {
+            align,
+            flip,
+            shift,
+            resize,
+        } = self;
+
+        let candidates: Vec<RectAlign> = if flip {
+            once(align)
+                .chain(alternatives.map_or_else(
+                    || {
+                        align
+                            .symmetries()
+                            .into_iter()
+                            .chain(RectAlign::MENU_ALIGNS)
+                            .collect()
+                    },
+                    <[RectAlign]>::to_vec,
+                ))
+                .collect()
+        } else {
+            vec![align]
+        };
+        let visible_area = |align: &RectAlign| {
+            align
+                .align_rect(&anchor_rect, size, gap)
+                .intersect(bounds)
+                .area()
+                .max(0.0)
+        };
+        let fits =
+            |align: &RectAlign| bounds.contains_rect(align.align_rect(&anchor_rect, size, gap));
+        let preferred = align;
+        let align = candidates.iter().find(|align| fits(align)).copied();
+        let align = align.unwrap_or_else(|| {
+            // Nothing fits, so use the side with the most room. Ties go to the preferred side:
+            let flipped = if flip {
+                preferred.symmetries()
+            } else {
+                [preferred; 3]
+            };
+            once(preferred)
+                .chain(flipped)
+                .rev()
+                .max_by(|a, b| visible_area(a).total_cmp(&visible_area(b)))
+                .unwrap_or(preferred)
+        });
+
+        let mut max_size = None;
+        let mut size = size;
+        if resize {
+            let room = room_next_to(align, anchor_rect, gap, bounds);
+            size = size.min(room.size());
+            max_size = Some(room.size());
+        }
+
+        let mut rect = align.align_rect(&anchor_rect, size, gap);
+        if shift {
+            rect = rect.translate(shift_into(rect, bounds));
+        }
+
+        Placement {
+            align,
+            rect,
+            max_size,
+        }
+    }
+}
+
+/// The result of [`PositionStrategy::place`].
+struct Placement {
+    align: RectAlign,
+
+    /// Where the popup goes, with its expected size.
+    rect: Rect,
+
+    /// The popup may not be larger than this, if it is resized to fit.
+    max_size: Option<Vec2>,
+}
+
+/// The part of `bounds` on the side of the anchor that `align` puts the popup at.
+fn room_next_to(align: RectAlign, anchor_rect: Rect, gap: f32, bounds: Rect) -> Rect {
+    let anchor = align.anchor(&anchor_rect, gap);
+    let gap_vector = align.gap_vector();
+    let mut room = bounds;
+    for d in 0..2 {
+        // Only the axis along which the popup is next to the anchor is limited:
+        if gap_vector[d] == 0.0 {
+            continue;
+        }
+        match align.child().0[d] {
+            Align::Min => room.min[d] = anchor[d].clamp(bounds.min[d], bounds.max[d]),
+            Align::Max => room.max[d] = anchor[d].clamp(bounds.min[d], bounds.max[d]),
+            Align::Center => {}
+        }
+    }
+    room
+}
+
+/// How far to move `rect` to have it inside `bounds`, preferring to show its top left corner.
+fn shift_into(rect: Rect, bounds: Rect) -> Vec2 {
+    let mut offset = Vec2::ZERO;
+    for d in 0..2 {
+        if bounds.max[d] < rect.max[d] {
+            offset[d] = bounds.max[d] - rect.max[d];
+        }
+        if rect.min[d] + offset[d] < bounds.min[d] {
+            offset[d] = bounds.min[d] - rect.min[d];
+        }
+    }
+    offset
+}
+
 /// Is the popup a popup, tooltip or menu?
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PopupKind {
@@ -168,7 +374,7 @@ pub struct Popup<'a> {
     id: Id,
     ctx: Context,
     anchor: PopupAnchor,
-    rect_align: RectAlign,
+    position: PositionStrategy,
     alternative_aligns: Option<&'a [RectAlign]>,
     layer_id: LayerId,
     open_kind: OpenKind<'a>,
@@ -202,7 +408,7 @@ impl<'a> Popup<'a> {
             info: None,
             kind: PopupKind::Popup,
             layer_id,
-            rect_align: RectAlign::BOTTOM_START,
+            position: PositionStrategy::default(),
             alternative_aligns: None,
             gap: 0.0,
             widget_clicked_elsewhere: false,
@@ -239,17 +445,18 @@ impl<'a> Popup<'a> {
     }
 
     /// Show a popup when the widget was clicked.
-    /// Sets the layout to `Layout::top_down_justified(Align::Min)`.
+    /// Sets the layout to `Layout::top_down_justified`, starting at the side given by [`crate::Style::layout_direction`].
     pub fn menu(button_response: &Response) -> Self {
+        let start = button_response.ctx.style().layout_direction.start();
         Self::from_toggle_button_response(button_response)
             .kind(PopupKind::Menu)
-            .layout(Layout::top_down_justified(Align::Min))
+            .layout(Layout::top_down_justified(start))
             .style(menu_style)
             .gap(0.0)
     }
 
     /// Show a context menu when the widget was secondary clicked.
-    /// Sets the layout to `Layout::top_down_justified(Align::Min)`.
+    /// Sets the layout like [`Self::menu`].
     /// In contrast to [`Self::menu`], this will open at the pointer position.
     pub fn context_menu(response: &Response) -> Self {
         Self::menu(response)
@@ -284,7 +491,7 @@ impl<'a> Popup<'a> {
     /// See [`Self::align_alternatives`] for more on this.
     #[inline]
     pub fn align(mut self, position_align: RectAlign) -> Self {
-        self.rect_align = position_align;
+        self.position.align = position_align;
         self
     }
 
@@ -297,6 +504,16 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// How the popup is placed, and what happens if it doesn't fit on the screen.
+    ///
+    /// This replaces the alignment set with [`Self::align`].
+    /// Default: [`PositionStrategy::default`], which flips and shifts but doesn't resize the popup.
+    #[inline]
+    pub fn position(mut self, position: PositionStrategy) -> Self {
+        self.position = position;
+        self
+    }
+
     /// Force the popup to be open or closed.
     #[inline]
     pub fn open(mut self, open: bool) -> Self {
@@ -438,13 +655,8 @@ impl<'a> Popup<'a> {
     /// Returns `None` if the popup wasn't shown before or anchor is `PopupAnchor::Pointer` and
     /// there is no pointer.
     pub fn get_popup_rect(&self) -> Option<Rect> {
-        let size = self.get_expected_size();
-        if let Some(size) = size {
-            self.get_anchor_rect()
-                .map(|anchor| self.get_best_align().align_rect(&anchor, size, self.gap))
-        } else {
-            None
-        }
+        self.get_expected_size()?;
+        self.placement().map(|placement| placement.rect)
     }
 
     /// Get the id of the popup.
@@ -469,34 +681,23 @@ impl<'a> Popup<'a> {
 
     /// Calculate the best alignment for the popup, based on the last size and screen rect.
     pub fn get_best_align(&self) -> RectAlign {
+        self.placement()
+            .map_or(self.position.align, |placement| placement.align)
+    }
+
+    /// Where the popup goes according to its [`PositionStrategy`], based on the last size and screen rect.
+    fn placement(&self) -> Option<Placement> {
         let expected_popup_size = self
             .get_expected_size()
             .unwrap_or(vec2(self.width.unwrap_or(0.0), 0.0));
-
-        let Some(anchor_rect) = self.anchor.rect(self.id, &self.ctx) else {
-            return self.rect_align;
-        };
-
-        RectAlign::find_best_align(
-            #[expect(clippy::iter_on_empty_collections)]
-            once(self.rect_align).chain(
-                self.alternative_aligns
-                    // Need the empty slice so the iters have the same type so we can unwrap_or
-                    .map(|a| a.iter().copied().chain([].iter().copied()))
-                    .unwrap_or(
-                        self.rect_align
-                            .symmetries()
-                            .iter()
-                            .copied()
-                            .chain(RectAlign::MENU_ALIGNS.iter().copied()),
-                    ),
-            ),
+        let anchor_rect = self.anchor.rect(self.id, &self.ctx)?;
+        Some(self.position.place(
+            self.alternative_aligns,
             self.ctx.screen_rect(),
             anchor_rect,
             self.gap,
             expected_popup_size,
-        )
-        .unwrap_or_default()
+        ))
     }
 
     /// Show the popup.
@@ -534,20 +735,21 @@ impl<'a> Popup<'a> {
             return None;
         }
 
-        let best_align = self.get_best_align();
+        let placement = self.placement();
+        let shift = self.position.shift;
 
         let Popup {
             id,
             ctx,
-            anchor,
+            anchor: _,
             open_kind,
             close_behavior,
             kind,
             info,
             layer_id,
-            rect_align: _,
+            position: _,
             alternative_aligns: _,
-            gap,
+            gap: _,
             widget_clicked_elsewhere,
             width,
             sense,
@@ -564,16 +766,21 @@ impl<'a> Popup<'a> {
                     .open_popups
                     .insert(id)
             });
+            ctx.memory_mut(|mem| mem.restore_focus_when_closed(id));
         }
 
-        let anchor_rect = anchor.rect(id, &ctx)?;
-
-        let (pivot, anchor) = best_align.pivot_pos(&anchor_rect, gap);
+        let Placement {
+            align,
+            rect,
+            max_size,
+        } = placement?;
+        let pivot = align.child();
 
         let mut area = Area::new(id)
             .order(kind.order())
             .pivot(pivot)
-            .fixed_pos(anchor)
+            .fixed_pos(pivot.pos_in_rect(&rect))
+            .constrain(shift)
             .sense(sense)
             .layout(layout)
             .info(info.unwrap_or_else(|| {
@@ -592,7 +799,20 @@ impl<'a> Popup<'a> {
         let mut response = area.show(&ctx, |ui| {
             style.apply(ui.style_mut());
             let frame = frame.unwrap_or_else(|| Frame::popup(ui.style()));
-            frame.show(ui, content).inner
+            if let Some(max_size) = max_size {
+                let max_size = max_size - frame.total_margin().sum();
+                frame
+                    .show(ui, |ui| {
+                        ScrollArea::both()
+                            .max_width(max_size.x)
+                            .max_height(max_size.y)
+                            .show(ui, content)
+                            .inner
+                    })
+                    .inner
+            } else {
+                frame.show(ui, content).inner
+            }
         });
 
         let closed_by_click = match close_behavior {
@@ -693,3 +913,53 @@ impl Popup<'_> {
         ctx.memory(|mem| mem.popup_position(popup_id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use emath::pos2;
+
+    #[test]
+    fn test_position_strategy() {
+        let bounds = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let size = vec2(20.0, 30.0);
+        let place = |strategy: PositionStrategy, anchor: Rect, size| {
+            strategy.place(Some(&[]), bounds, anchor, 0.0, size)
+        };
+
+        // Fits below:
+        let anchor = Rect::from_min_size(pos2(10.0, 10.0), vec2(10.0, 10.0));
+        let placement = place(PositionStrategy::default(), anchor, size);
+        assert_eq!(placement.rect, Rect::from_min_size(pos2(10.0, 20.0), size));
+
+        // Flips to above the anchor when there is no room below:
+        let anchor = Rect::from_min_size(pos2(10.0, 80.0), vec2(10.0, 10.0));
+        let placement = place(PositionStrategy::default(), anchor, size);
+        assert_eq!(placement.rect, Rect::from_min_size(pos2(10.0, 50.0), size));
+
+        // Flips to end at the right side of the anchor:
+        let anchor = Rect::from_min_size(pos2(90.0, 10.0), vec2(5.0, 10.0));
+        let placement = place(PositionStrategy::default(), anchor, size);
+        assert_eq!(placement.rect, Rect::from_min_size(pos2(75.0, 20.0), size));
+
+        // Without flipping, it shifts to the left to stay on screen:
+        let strategy = PositionStrategy::default().flip(false);
+        let placement = place(strategy, anchor, size);
+        assert_eq!(placement.rect, Rect::from_min_size(pos2(80.0, 20.0), size));
+
+        // …unless told not to:
+        let placement = place(strategy.shift(false), anchor, size);
+        assert_eq!(placement.rect, Rect::from_min_size(pos2(90.0, 20.0), size));
+
+        // Too tall for either side, so it goes where there is the most room, and shrinks:
+        let tall = vec2(20.0, 200.0);
+        let anchor = Rect::from_min_size(pos2(10.0, 30.0), vec2(10.0, 10.0));
+        let strategy = PositionStrategy::default().resize(true);
+        let placement = strategy.place(None, bounds, anchor, 0.0, tall);
+        assert_eq!(
+            placement.rect,
+            Rect::from_min_max(pos2(10.0, 40.0), pos2(30.0, 100.0))
+        );
+        assert_eq!(placement.max_size, Some(vec2(100.0, 60.0)));
+    }
+}
@@ -27,6 +27,17 @@ impl State {
     pub fn store(self, ctx: &Context, id: Id) {
         ctx.data_mut(|d| d.insert_persisted(id, self));
     }
+
+    /// Make the [`Resize`] with the given id take on this size the next time it is shown.
+    pub fn request_size(ctx: &Context, id: Id, size: Vec2) {
+        let mut state = Self::load(ctx, id).unwrap_or(Self {
+            desired_size: size,
+            last_content_size: Vec2::ZERO,
+            requested_size: None,
+        });
+        state.requested_size = Some(size);
+        state.store(ctx, id);
+    }
 }
 
 /// A region that can be resized by dragging the bottom right corner.
@@ -0,0 +1,216 @@
+//! Marquee ("rubber band") selection, see [`RubberBand`].
+
+use crate::{
+    Id, IdSet, LayerId, Modifiers, Order, Pos2, Rect, Response, Sense, StrokeKind, Ui, UiBuilder,
+};
+
+#[derive(Clone, Default)]
+struct RubberBandState {
+    /// Where the drag started, in layer coordinates.
+    origin: Pos2,
+
+    /// The modifiers held when the drag started.
+    modifiers: Modifiers,
+
+    /// The selection before the drag started, set by [`RubberBandResponse::update_selection`].
+    selection_at_start: Option<IdSet>,
+}
+
+/// Select many widgets at once by dragging a rectangle over them, like in a file manager.
+///
+/// Dragging on empty space inside the contents (i.e. not on a widget that senses drags)
+/// draws a selection rectangle on top of the contents.
+/// Every frame of the drag, the interactive widgets of the contents that intersect the rectangle
+/// are reported in [`RubberBandResponse::hits`].
+///
+/// Use [`RubberBandResponse::update_selection`] to keep a set of selected [`Id`]s up to date.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut selection = egui::IdSet::default();
+/// let band = egui::RubberBand::new().show(ui, |ui| {
+///     for i in 0..10 {
+///         let selected = selection.contains(&ui.next_auto_id());
+///         ui.selectable_label(selected, format!("File {i}"));
+///     }
+/// });
+/// band.update_selection(&mut selection);
+/// # });
+/// ```
+///
+/// The reported ids are the widget ids, i.e. [`Response::id`].
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RubberBand {
+    id_salt: Option<Id>,
+}
+
+/// The result of [`RubberBand::show`].
+#[derive(Debug)]
+pub struct RubberBandResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the empty space of the contents.
+    pub response: Response,
+
+    /// The selection rectangle, while the user is dragging (including the frame the drag stops).
+    pub rect: Option<Rect>,
+
+    /// The interactive widgets intersecting [`Self::rect`], with their rectangles.
+    pub hits: Vec<(Id, Rect)>,
+
+    /// Did the user release the drag this frame?
+    pub finished: bool,
+
+    id: Id,
+}
+
+impl RubberBand {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Must be set if multiple rubber bands are in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> RubberBandResponse<R> {
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new("rubber_band")));
+        let layer_id = ui.layer_id();
+        let ctx = ui.ctx().clone();
+
+        let num_widgets_before = ctx.pass_state(|p| p.widgets.get_layer(layer_id).count());
+
+        let crate::InnerResponse { inner, response } = ui.scope_builder(
+            UiBuilder::new().id_salt(id).sense(Sense::click_and_drag()),
+            add_contents,
+        );
+
+        if response.drag_started() {
+            let origin = ctx.input(|i| i.pointer.press_origin()).map(|pos| {
+                ctx.layer_transform_from_global(layer_id)
+                    .map_or(pos, |from_global| from_global * pos)
+            });
+            if let Some(origin) = origin {
+                let state = RubberBandState {
+                    origin,
+                    modifiers: ctx.input(|i| i.modifiers),
+                    selection_at_start: None,
+                };
+                ctx.data_mut(|d| d.insert_temp(id, state));
+            }
+        }
+
+        let state = ctx.data(|d| d.get_temp::<RubberBandState>(id));
+        let finished = response.drag_stopped() && state.is_some();
+
+        let rect = state.as_ref().and_then(|state| {
+            if !response.dragged() && !finished {
+                return None;
+            }
+            let pos = response.interact_pointer_pos()?;
+            Some(Rect::from_two_pos(state.origin, pos))
+        });
+
+        let mut hits = vec![];
+        if let Some(rect) = rect {
+            ctx.pass_state(|p| {
+                let widgets = p.widgets.get_layer(layer_id);
+                for widget in widgets.skip(num_widgets_before) {
+                    if widget.id != response.id
+                        && widget.sense.interactive()
+                        && widget.rect.intersects(rect)
+                    {
+                        hits.push((widget.id, widget.rect));
+                    }
+                }
+            });
+
+            paint_band(ui, id, layer_id, rect);
+        }
+
+        if state.is_some() && !response.dragged() && !finished {
+            ctx.data_mut(|d| d.remove::<RubberBandState>(id));
+        }
+
+        RubberBandResponse {
+            inner,
+            response,
+            rect,
+            hits,
+            finished,
+            id,
+        }
+    }
+}
+
+fn paint_band(ui: &Ui, id: Id, layer_id: LayerId, rect: Rect) {
+    let overlay = LayerId::new(Order::Foreground, id);
+    if let Some(transform) = ui.ctx().layer_transform_to_global(layer_id) {
+        ui.ctx().set_transform_layer(overlay, transform);
+    }
+
+    let selection = ui.visuals().selection;
+    let painter = ui.painter().clone().with_layer_id(overlay);
+    painter.rect(
+        rect,
+        0.0,
+        selection.bg_fill.gamma_multiply(0.3),
+        selection.stroke,
+        StrokeKind::Inside,
+    );
+}
+
+impl<R> RubberBandResponse<R> {
+    /// The ids in [`Self::hits`].
+    pub fn hit_ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.hits.iter().map(|(id, _)| *id)
+    }
+
+    /// Update a set of selected widget ids, the way file managers do:
+    ///
+    /// * Dragging selects the widgets in the rectangle, and deselects everything else.
+    /// * Dragging while holding shift or the command key adds to the previous selection.
+    /// * Clicking on empty space (without modifiers) deselects everything.
+    ///
+    /// Call this every frame.
+    pub fn update_selection(&self, selection: &mut IdSet) {
+        let ctx = &self.response.ctx;
+
+        if self.rect.is_none() {
+            if self.response.clicked() && ctx.input(|i| i.modifiers.is_none()) {
+                selection.clear();
+            }
+            return;
+        }
+
+        let Some(mut state) = ctx.data(|d| d.get_temp::<RubberBandState>(self.id)) else {
+            return;
+        };
+
+        let extend = state.modifiers.shift || state.modifiers.command;
+        let selection_at_start = state
+            .selection_at_start
+            .get_or_insert_with(|| {
+                if extend {
+                    selection.clone()
+                } else {
+                    IdSet::default()
+                }
+            })
+            .clone();
+
+        *selection = selection_at_start;
+        selection.extend(self.hit_ids());
+
+        ctx.data_mut(|d| d.insert_temp(self.id, state));
+    }
+}
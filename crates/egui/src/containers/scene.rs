@@ -12,7 +12,7 @@ use crate::{
 /// The resulting visual scene bounds can be larger, due to letterboxing.
 ///
 /// Returns the transformation from `scene` to `global` coordinates.
-fn fit_to_rect_in_scene(
+pub(crate) fn fit_to_rect_in_scene(
     rect_in_global: Rect,
     rect_in_scene: Rect,
     zoom_range: Rangef,
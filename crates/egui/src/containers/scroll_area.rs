@@ -1,10 +1,13 @@
 #![allow(clippy::needless_range_loop)]
 
-use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
+use std::{
+    ops::{Add, AddAssign, BitOr, BitOrAssign},
+    sync::Arc,
+};
 
 use crate::{
-    Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind,
-    UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    Context, CursorIcon, Id, Key, Modifiers, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder,
+    UiKind, UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -37,6 +40,11 @@ pub struct State {
     #[cfg_attr(feature = "serde", serde(skip))]
     vel: Vec2,
 
+    /// How far the content was dragged past its start (positive) or end (negative),
+    /// see [`ScrollArea::overscroll`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pull: Vec2,
+
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top_left: [Option<f32>; 2],
 
@@ -47,6 +55,26 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// The content size when [`ScrollArea::on_reach_start`] was last called,
+    /// or `None` if we have since scrolled away from the start.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reached_start: [Option<f32>; 2],
+
+    /// The content size when [`ScrollArea::on_reach_end`] was last called,
+    /// or `None` if we have since scrolled away from the end.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reached_end: [Option<f32>; 2],
+
+    /// The content size when [`ScrollArea::on_reach_start`] was last called,
+    /// kept until the content changes size, so that we can keep the same content
+    /// in view when more is prepended.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    prepend_anchor: [Option<f32>; 2],
+
+    /// The [`ScrollArea::anchor_id`] widget, and where it was in the content last frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    anchor: Option<(Id, Vec2)>,
 }
 
 impl Default for State {
@@ -58,9 +86,14 @@ impl Default for State {
             content_is_too_large: Vec2b::FALSE,
             scroll_bar_interaction: Vec2b::FALSE,
             vel: Vec2::ZERO,
+            pull: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            reached_start: [None; 2],
+            reached_end: [None; 2],
+            prepend_anchor: [None; 2],
+            anchor: None,
         }
     }
 }
@@ -74,10 +107,26 @@ impl State {
         ctx.data_mut(|d| d.insert_persisted(id, self));
     }
 
+    /// Scroll by `delta` without anything in view moving, because the content moved by as much.
+    fn shift_offset(&mut self, d: usize, delta: f32) {
+        self.offset[d] += delta;
+        if let Some(animation) = &mut self.offset_target[d] {
+            animation.target_offset += delta;
+        }
+    }
+
     /// Get the current kinetic scrolling velocity.
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// How far the user has scrolled over the first `distance` points, per axis:
+    /// `0.0` when not scrolled, and `1.0` when scrolled `distance` or more.
+    ///
+    /// Useful for scroll-linked animations, see [`ScrollArea::scroll_progress`].
+    pub fn scroll_progress(&self, distance: f32) -> Vec2 {
+        (self.offset / distance.max(f32::EPSILON)).clamp(Vec2::ZERO, Vec2::splat(1.0))
+    }
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -98,6 +147,28 @@ pub struct ScrollAreaOutput<R> {
     pub inner_rect: Rect,
 }
 
+impl<R> ScrollAreaOutput<R> {
+    /// How far the user has scrolled through the content, per axis:
+    /// `0.0` at the start, and `1.0` at the end.
+    ///
+    /// Zero if the content fits, so there is nothing to scroll.
+    pub fn scroll_fraction(&self) -> Vec2 {
+        let max_offset = self.content_size - self.inner_rect.size();
+        Vec2::new(
+            fraction(self.state.offset.x, max_offset.x),
+            fraction(self.state.offset.y, max_offset.y),
+        )
+    }
+}
+
+fn fraction(offset: f32, max_offset: f32) -> f32 {
+    if 0.0 < max_offset {
+        (offset / max_offset).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -149,7 +220,7 @@ pub struct ScrollSource {
     pub drag: bool,
 
     /// Scroll the area by scrolling (or shift scrolling) the mouse wheel with
-    /// the mouse cursor over the [`ScrollArea`].
+    /// the mouse cursor over the [`ScrollArea`], and with `PageUp`, `PageDown`, `Home` and `End`.
     pub mouse_wheel: bool,
 }
 
@@ -271,6 +342,9 @@ impl AddAssign for ScrollSource {
 ///
 /// You can scroll to an element using [`crate::Response::scroll_to_me`], [`Ui::scroll_to_cursor`] and [`Ui::scroll_to_rect`].
 ///
+/// `PageUp`, `PageDown`, `Home` and `End` scroll the innermost scroll area containing the focused widget,
+/// or the hovered one if no widget has focus.
+///
 /// ## See also
 /// If you want to allow zooming, use [`crate::Scene`].
 #[derive(Clone, Debug)]
@@ -299,6 +373,55 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    kinetic_friction: f32,
+    overscroll: bool,
+    pull_to_refresh: Option<PullToRefresh>,
+
+    on_reach_start: Option<ReachCallback>,
+    on_reach_end: Option<ReachCallback>,
+    anchor_id: Option<Id>,
+}
+
+/// See [`ScrollArea::pull_to_refresh`].
+#[derive(Clone)]
+struct PullToRefresh {
+    refreshing: bool,
+    callback: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl std::fmt::Debug for PullToRefresh {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PullToRefresh")
+            .field("refreshing", &self.refreshing)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How far the content must be pulled down to refresh it, and the room for the spinner while refreshing.
+const PULL_TO_REFRESH_DISTANCE: f32 = 60.0;
+
+/// How far the content moves when dragged `pull` points past an end of a scroll area of the given `size`.
+///
+/// The further it is pulled, the more it resists.
+fn rubber_band(pull: f32, size: f32) -> f32 {
+    let size = size.max(1.0);
+    pull.signum() * size * (1.0 - 1.0 / (1.0 + 0.55 * pull.abs() / size))
+}
+
+/// See [`ScrollArea::on_reach_start`] and [`ScrollArea::on_reach_end`].
+#[derive(Clone)]
+struct ReachCallback {
+    threshold: f32,
+    callback: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl std::fmt::Debug for ReachCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReachCallback")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
 }
 
 impl ScrollArea {
@@ -346,6 +469,12 @@ impl ScrollArea {
             wheel_scroll_multiplier: Vec2::splat(1.0),
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            kinetic_friction: 1000.0,
+            overscroll: false,
+            pull_to_refresh: None,
+            on_reach_start: None,
+            on_reach_end: None,
+            anchor_id: None,
         }
     }
 
@@ -565,6 +694,62 @@ impl ScrollArea {
         self
     }
 
+    /// How quickly kinetic scrolling slows down after dragging the content and letting go,
+    /// in points per second squared.
+    ///
+    /// Use `f32::INFINITY` to stop right away. Default: `1000.0`.
+    #[inline]
+    pub fn kinetic_friction(mut self, friction: f32) -> Self {
+        self.kinetic_friction = friction;
+        self
+    }
+
+    /// Can the content be dragged past its ends, e.g. on a touch screen?
+    ///
+    /// If so, it springs back when let go, and kinetic scrolling bounces off the ends.
+    /// Default: `false`.
+    #[inline]
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// Let the user drag the content down past its start to refresh it, like in many mobile apps.
+    ///
+    /// When the content is pulled down far enough and let go, `callback` is called.
+    /// Pass `refreshing: true` while the refresh is in progress, to show a spinner above the content.
+    /// This turns on [`Self::overscroll`].
+    /// The area needs to be tall enough to pull it that far, so you may want to turn off [`Self::auto_shrink`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+    ///
+    /// let refreshing = Arc::new(AtomicBool::new(false));
+    /// egui::ScrollArea::vertical()
+    ///     .pull_to_refresh(refreshing.load(Ordering::Relaxed), {
+    ///         let refreshing = refreshing.clone();
+    ///         move || refreshing.store(true, Ordering::Relaxed)
+    ///     })
+    ///     .show(ui, |ui| {
+    ///         ui.label("Latest news");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn pull_to_refresh(
+        mut self,
+        refreshing: bool,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        self.overscroll = true;
+        self.pull_to_refresh = Some(PullToRefresh {
+            refreshing,
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
     /// For each axis, should the containing area shrink if the content is small?
     ///
     /// * If `true`, egui will add blank space outside the scroll area.
@@ -586,6 +771,100 @@ impl ScrollArea {
         self
     }
 
+    /// Keep the widget with this [`Id`] in the same place in view when the content
+    /// around it changes, e.g. when items are inserted or removed above it.
+    ///
+    /// Without this, the content in view jumps whenever something above it changes size.
+    /// Any widget with a stable [`Id`] can be the anchor. For a [`Ui`], use [`UiBuilder::id`].
+    /// Usually you want to anchor to the first item in view, or to the one the user interacts with.
+    ///
+    /// The anchor is not used while the [`ScrollArea`] is stuck to the end
+    /// (see [`Self::stick_to_bottom`]).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # struct Message { id: u64, text: String }
+    /// # let messages: Vec<Message> = vec![];
+    /// # let selected_message = 0;
+    /// egui::ScrollArea::vertical()
+    ///     .anchor_id(egui::Id::new(("message", selected_message)))
+    ///     .show(ui, |ui| {
+    ///         for message in &messages {
+    ///             let id = ("message", message.id);
+    ///             ui.scope_builder(egui::UiBuilder::new().id(id), |ui| {
+    ///                 ui.label(&message.text);
+    ///             });
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn anchor_id(mut self, anchor_id: impl Into<Id>) -> Self {
+        self.anchor_id = Some(anchor_id.into());
+        self
+    }
+
+    /// Call `callback` when the user scrolls to within `threshold` points of the start,
+    /// e.g. to load older messages in a chat.
+    ///
+    /// The callback is called once each time the start is reached,
+    /// and again if the content changes size while still there.
+    ///
+    /// Content added to the start afterwards (on any later frame) does not move what is in view:
+    /// the scroll offset is increased by however much the content grew.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+    ///
+    /// let load_older = Arc::new(AtomicBool::new(false));
+    /// egui::ScrollArea::vertical()
+    ///     .on_reach_start(100.0, {
+    ///         let load_older = load_older.clone();
+    ///         move || load_older.store(true, Ordering::Relaxed)
+    ///     })
+    ///     .show(ui, |ui| {
+    ///         ui.label("Oldest loaded message");
+    ///     });
+    /// if load_older.load(Ordering::Relaxed) {
+    ///     // Fetch older messages and prepend them next frame
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_reach_start(
+        mut self,
+        threshold: f32,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        self.on_reach_start = Some(ReachCallback {
+            threshold,
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
+    /// Call `callback` when the user scrolls to within `threshold` points of the end,
+    /// e.g. to lazily load more items of an infinite list.
+    ///
+    /// The callback is called once each time the end is reached,
+    /// and again if the content changes size while still there,
+    /// so it keeps getting called until there is enough content to fill the [`ScrollArea`].
+    ///
+    /// See also [`Self::on_reach_start`].
+    #[inline]
+    pub fn on_reach_end(
+        mut self,
+        threshold: f32,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        self.on_reach_end = Some(ReachCallback {
+            threshold,
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.direction_enabled[0] || self.direction_enabled[1]
@@ -614,6 +893,36 @@ impl ScrollArea {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// How far this scroll area was scrolled last pass, over the first `distance` points,
+    /// per axis: `0.0` when not scrolled, and `1.0` when scrolled `distance` or more.
+    ///
+    /// Use this for scroll-linked animations, e.g. to shrink a large header into a compact
+    /// toolbar as the user scrolls (together with [`crate::Style::lerp`]).
+    /// Call it with the same [`Ui`] the scroll area is shown in, before showing it:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let scroll_area = egui::ScrollArea::vertical().id_salt("feed");
+    /// let collapsed = scroll_area.scroll_progress(ui, 100.0).y;
+    ///
+    /// let large = ui.style().clone();
+    /// let mut compact = (*large).clone();
+    /// compact.text_styles.insert(egui::TextStyle::Heading, egui::FontId::proportional(14.0));
+    /// ui.set_style(large.lerp(&compact, collapsed));
+    /// ui.heading("My feed");
+    ///
+    /// scroll_area.show(ui, |ui| {
+    ///     for i in 0..100 {
+    ///         ui.label(format!("Post {i}"));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn scroll_progress(&self, ui: &Ui, distance: f32) -> Vec2 {
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new("scroll_area")));
+        State::load(ui.ctx(), id).map_or(Vec2::ZERO, |state| state.scroll_progress(distance))
+    }
 }
 
 struct Prepared {
@@ -660,6 +969,15 @@ struct Prepared {
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
     animated: bool,
+
+    overscroll: bool,
+
+    /// Is the content being dragged this pass?
+    is_dragging_content: bool,
+
+    on_reach_start: Option<ReachCallback>,
+    on_reach_end: Option<ReachCallback>,
+    anchor_id: Option<Id>,
 }
 
 impl ScrollArea {
@@ -680,6 +998,12 @@ impl ScrollArea {
             wheel_scroll_multiplier,
             stick_to_end,
             animated,
+            kinetic_friction,
+            overscroll,
+            pull_to_refresh,
+            on_reach_start,
+            on_reach_end,
+            anchor_id,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -744,7 +1068,24 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        // Content dragged past its ends, or making room for the pull-to-refresh spinner:
+        let mut pulled_by = if overscroll {
+            Vec2::new(
+                rubber_band(state.pull.x, inner_size.x),
+                rubber_band(state.pull.y, inner_size.y),
+            )
+        } else {
+            Vec2::ZERO
+        };
+        if pull_to_refresh
+            .as_ref()
+            .is_some_and(|refresh| refresh.refreshing)
+        {
+            pulled_by.y = pulled_by.y.max(PULL_TO_REFRESH_DISTANCE - state.offset.y);
+        }
+
+        let content_max_rect =
+            Rect::from_min_size(inner_rect.min - state.offset + pulled_by, content_max_size);
         let mut content_ui = ui.new_child(
             UiBuilder::new()
                 .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
@@ -772,9 +1113,12 @@ impl ScrollArea {
         let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
 
+        let mut is_dragging_content = false;
         if scroll_source.drag
             && ui.is_enabled()
-            && (state.content_is_too_large[0] || state.content_is_too_large[1])
+            && (state.content_is_too_large[0]
+                || state.content_is_too_large[1]
+                || (pull_to_refresh.is_some() && direction_enabled[1]))
         {
             // Drag contents to scroll (for touch screens mostly).
             // We must do this BEFORE adding content to the `ScrollArea`,
@@ -787,8 +1131,12 @@ impl ScrollArea {
                 .as_ref()
                 .is_some_and(|response| response.dragged())
             {
+                is_dragging_content = true;
                 for d in 0..2 {
                     if direction_enabled[d] {
+                        // Pick up where the content was pulled to, `Prepared::end` puts it back:
+                        state.offset[d] -= state.pull[d];
+                        state.pull[d] = 0.0;
                         ui.input(|input| {
                             state.offset[d] -= input.pointer.delta()[d];
                         });
@@ -804,13 +1152,18 @@ impl ScrollArea {
                 {
                     state.vel =
                         direction_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
+
+                    if let Some(refresh) = &pull_to_refresh {
+                        if !refresh.refreshing && PULL_TO_REFRESH_DISTANCE <= pulled_by.y {
+                            (refresh.callback)();
+                        }
+                    }
                 }
                 for d in 0..2 {
                     // Kinetic scrolling
                     let stop_speed = 20.0; // Pixels per second.
-                    let friction_coeff = 1000.0; // Pixels per second squared.
 
-                    let friction = friction_coeff * dt;
+                    let friction = kinetic_friction * dt;
                     if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
                         state.vel[d] = 0.0;
                     } else {
@@ -837,6 +1190,23 @@ impl ScrollArea {
             }
         }
 
+        // Content pulled past its ends springs back when let go:
+        if !is_dragging_content {
+            for d in 0..2 {
+                if state.pull[d] != 0.0 {
+                    state.pull[d] *= (-12.0 * dt).exp();
+                    if state.pull[d].abs() < 0.5 {
+                        state.pull[d] = 0.0;
+                    }
+                    ctx.request_repaint();
+                }
+            }
+        }
+
+        if let Some(refresh) = &pull_to_refresh {
+            paint_pull_to_refresh_spinner(ui, inner_rect, pulled_by.y, refresh.refreshing);
+        }
+
         // Scroll with an animation if we have a target offset (that hasn't been cleared by the code
         // above).
         for d in 0..2 {
@@ -889,6 +1259,11 @@ impl ScrollArea {
             stick_to_end,
             saved_scroll_target,
             animated,
+            overscroll,
+            is_dragging_content,
+            on_reach_start,
+            on_reach_end,
+            anchor_id,
         }
     }
 
@@ -952,6 +1327,83 @@ impl ScrollArea {
         })
     }
 
+    /// Efficiently show only the visible part of a large number of rows of different heights,
+    /// like a chat log.
+    ///
+    /// Unlike [`Self::show_rows`], you don't need to know the height of the rows up front.
+    /// Instead `estimate_row_height` gives a guess for each row (excluding item spacing),
+    /// and egui measures the rows as they become visible, remembering their real heights
+    /// so that the scroll bar becomes more accurate as you scroll around.
+    ///
+    /// The measurements are forgotten when the number of rows or the width of the [`ScrollArea`] changes.
+    ///
+    /// `add_row` is called for each visible row, with a [`Ui`] of its own.
+    /// Returns the range of rows that were shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let messages: Vec<String> = (0..1000).map(|i| "Hello! ".repeat(i % 7 + 1)).collect();
+    /// let row_height = ui.text_style_height(&egui::TextStyle::Body);
+    /// egui::ScrollArea::vertical().show_varying_rows(
+    ///     ui,
+    ///     messages.len(),
+    ///     |_row| row_height,
+    ///     |ui, row| {
+    ///         ui.label(&messages[row]);
+    ///     },
+    /// );
+    /// # });
+    /// ```
+    pub fn show_varying_rows(
+        self,
+        ui: &mut Ui,
+        total_rows: usize,
+        estimate_row_height: impl Fn(usize) -> f32,
+        mut add_row: impl FnMut(&mut Ui, usize),
+    ) -> ScrollAreaOutput<std::ops::Range<usize>> {
+        let spacing = ui.spacing().item_spacing.y;
+        self.show_viewport(ui, |ui, viewport| {
+            let id = ui.id().with("row_heights");
+            let max_rect = ui.max_rect();
+
+            let mut row_heights: RowHeights = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+            if row_heights.len() != total_rows || row_heights.width != max_rect.width() {
+                row_heights = RowHeights::new(
+                    (0..total_rows).map(|row| estimate_row_height(row) + spacing),
+                    max_rect.width(),
+                );
+            }
+
+            let first_row = row_heights.row_at(viewport.min.y);
+            let mut y = max_rect.top() + row_heights.offset(first_row);
+            let mut row = first_row;
+            let mut changed = false;
+            while row < total_rows && y < max_rect.top() + viewport.max.y {
+                let row_rect = Rect::from_min_max(
+                    pos2(max_rect.left(), y),
+                    pos2(max_rect.right(), f32::INFINITY),
+                );
+                let response =
+                    ui.scope_builder(UiBuilder::new().id_salt(row).max_rect(row_rect), |ui| {
+                        add_row(ui, row);
+                    });
+                let height = response.response.rect.height() + spacing;
+                changed |= row_heights.set(row, height);
+                y += height;
+                row += 1;
+            }
+
+            ui.expand_to_include_y(max_rect.top() + (row_heights.total() - spacing).at_least(0.0));
+            if changed {
+                // The rows may have moved around, and the scroll bar changed size:
+                ui.ctx().request_repaint();
+            }
+            ui.data_mut(|d| d.insert_temp(id, row_heights));
+
+            first_row..row
+        })
+    }
+
     /// This can be used to only paint the visible part of the contents.
     ///
     /// `add_contents` is given the viewport rectangle, which is the relative view of the content.
@@ -1004,6 +1456,11 @@ impl Prepared {
             stick_to_end,
             saved_scroll_target,
             animated,
+            overscroll,
+            is_dragging_content,
+            on_reach_start,
+            on_reach_end,
+            anchor_id,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -1012,6 +1469,10 @@ impl Prepared {
             .ctx()
             .pass_state_mut(|state| std::mem::take(&mut state.scroll_delta));
 
+        // Scroll targets for the ScrollAreas up the stack, e.g. so that
+        // a widget in a nested ScrollArea is revealed by all of them.
+        let mut parent_scroll_target: [Option<pass_state::ScrollTarget>; 2] = [None, None];
+
         for d in 0..2 {
             // PassState::scroll_delta is inverted from the way we apply the delta, so we need to negate it.
             let mut delta = -scroll_delta.0[d];
@@ -1031,7 +1492,7 @@ impl Prepared {
                         animation: animation_update,
                     } = target;
                     let min = content_ui.min_rect().min[d];
-                    let clip_rect = content_ui.clip_rect();
+                    let clip_rect = unoccluded_clip_rect(&content_ui, content_ui.clip_rect());
                     let visible_range = min..=min + clip_rect.size()[d];
                     let (start, end) = (range.min, range.max);
                     let clip_start = clip_rect.min[d];
@@ -1059,12 +1520,26 @@ impl Prepared {
 
                     delta += delta_update;
                     animation = animation_update;
+
+                    // Where the target ends up after we've scrolled:
+                    let moved = Rangef::new(start - delta_update, end - delta_update);
+                    let visible = Rangef::new(clip_start, clip_end);
+                    let range = if moved.intersects(visible) {
+                        moved.intersection(visible)
+                    } else {
+                        moved
+                    };
+                    parent_scroll_target[d] = Some(pass_state::ScrollTarget::new(
+                        range,
+                        align,
+                        animation_update,
+                    ));
                 };
 
                 if delta != 0.0 {
                     let target_offset = state.offset[d] + delta;
 
-                    if !animated {
+                    if !animated || ui.style().reduced_motion {
                         state.offset[d] = target_offset;
                     } else if let Some(animation) = &mut state.offset_target[d] {
                         // For instance: the user is continuously calling `ui.scroll_to_cursor`,
@@ -1082,15 +1557,21 @@ impl Prepared {
                     }
                     ui.ctx().request_repaint();
                 }
+            } else {
+                // We can't scroll this way, but maybe a ScrollArea up the stack can:
+                parent_scroll_target[d] = scroll_target;
             }
         }
 
         // Restore scroll target meant for ScrollAreas up the stack (if any)
+        let has_parent_scroll_area = ui.stack().contained_in(UiKind::ScrollArea);
         ui.ctx().pass_state_mut(|state| {
             for d in 0..2 {
                 if saved_scroll_target[d].is_some() {
                     state.scroll_target[d] = saved_scroll_target[d].clone();
-                };
+                } else if has_parent_scroll_area {
+                    state.scroll_target[d] = parent_scroll_target[d].take();
+                }
             }
         });
 
@@ -1157,6 +1638,71 @@ impl Prepared {
             }
         }
 
+        // `PageUp`/`PageDown`/`Home`/`End` scroll the innermost `ScrollArea` with the focused widget,
+        // or the hovered one if nothing has focus:
+        let keyboard_scroll_dim = [1, 0].into_iter().find(|&d| content_is_too_large[d]);
+        if let Some(d) = keyboard_scroll_dim {
+            let is_keyboard_target = match ui.memory(|mem| mem.focused()) {
+                Some(focused) => ui.ctx().viewport(|viewport| {
+                    viewport
+                        .this_pass
+                        .widgets
+                        .get(focused)
+                        .is_some_and(|widget| {
+                            widget.layer_id == ui.layer_id()
+                                && content_ui.min_rect().contains_rect(widget.rect)
+                        })
+                }),
+                None => is_hovering_outer_rect,
+            };
+            if scroll_source.mouse_wheel && ui.is_enabled() && is_keyboard_target {
+                // `Home` and `End` move the cursor of a `TextEdit`:
+                let is_editing_text = ui.ctx().output(|o| o.ime.is_some());
+                let page = inner_rect.size()[d] - ui.text_style_height(&crate::TextStyle::Body);
+                let offset = state.offset[d];
+                let new_offset = ui.input_mut(|input| {
+                    if input.consume_key(Modifiers::NONE, Key::PageUp) {
+                        Some(offset - page)
+                    } else if input.consume_key(Modifiers::NONE, Key::PageDown) {
+                        Some(offset + page)
+                    } else if !is_editing_text && input.consume_key(Modifiers::NONE, Key::Home) {
+                        Some(0.0)
+                    } else if !is_editing_text && input.consume_key(Modifiers::NONE, Key::End) {
+                        Some(max_offset[d])
+                    } else {
+                        None
+                    }
+                });
+                if let Some(new_offset) = new_offset {
+                    state.offset[d] = new_offset.clamp(0.0, max_offset[d]);
+                    state.scroll_stuck_to_end[d] = false;
+                    state.offset_target[d] = None;
+                    ui.ctx().request_repaint();
+                }
+            }
+        }
+
+        if overscroll {
+            // Dragging past the ends pulls the content along, and kinetic scrolling bounces off them:
+            for d in 0..2 {
+                let clamped = state.offset[d].clamp(0.0, max_offset[d].max(0.0));
+                let excess = clamped - state.offset[d];
+                if !direction_enabled[d] || excess == 0.0 {
+                    continue;
+                }
+                if is_dragging_content {
+                    state.pull[d] += excess;
+                    state.offset[d] = clamped;
+                } else if state.vel[d] != 0.0 {
+                    let bounce_time = 0.04; // How long the momentum carries the content past the end.
+                    state.pull[d] = (state.pull[d] + bounce_time * state.vel[d])
+                        .clamp(-inner_rect.size()[d], inner_rect.size()[d]);
+                    state.vel[d] = 0.0;
+                    state.offset[d] = clamped;
+                }
+            }
+        }
+
         let show_scroll_this_frame = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
             ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
@@ -1421,10 +1967,79 @@ impl Prepared {
             ui.ctx().request_repaint();
         }
 
+        // Where the anchor widget is in the content, which does not depend on the scroll offset:
+        let anchor_pos = anchor_id.and_then(|anchor_id| {
+            let rect = ui
+                .ctx()
+                .pass_state(|fs| fs.widgets.get(anchor_id).map(|widget| widget.rect))?;
+            Some((anchor_id, rect.min - content_ui.max_rect().min))
+        });
+        let mut anchored = Vec2b::FALSE;
+        if let (Some((anchor_id, pos)), Some((last_id, last_pos))) = (anchor_pos, state.anchor) {
+            for d in 0..2 {
+                let is_stuck = stick_to_end[d] && state.scroll_stuck_to_end[d];
+                if anchor_id == last_id && direction_enabled[d] && !is_stuck {
+                    anchored[d] = true;
+                    let moved = pos[d] - last_pos[d];
+                    if moved != 0.0 {
+                        state.shift_offset(d, moved);
+                        ui.ctx()
+                            .request_discard("ScrollArea anchor moved within the content");
+                    }
+                }
+            }
+        }
+        state.anchor = anchor_pos;
+
+        for d in 0..2 {
+            if let Some(anchor) = state.prepend_anchor[d] {
+                if content_size[d] != anchor {
+                    state.prepend_anchor[d] = None;
+                    if direction_enabled[d] && !anchored[d] && anchor < content_size[d] {
+                        // Content was prepended: keep showing what we were showing.
+                        state.shift_offset(d, content_size[d] - anchor);
+                        ui.ctx().request_discard("ScrollArea content was prepended");
+                    }
+                }
+            }
+        }
+
         let available_offset = content_size - inner_rect.size();
         state.offset = state.offset.min(available_offset);
         state.offset = state.offset.max(Vec2::ZERO);
 
+        for d in 0..2 {
+            if !direction_enabled[d] {
+                continue;
+            }
+            let mut appending = false;
+            if let Some(on_reach_end) = &on_reach_end {
+                if available_offset[d] - state.offset[d] <= on_reach_end.threshold {
+                    if state.reached_end[d] != Some(content_size[d]) {
+                        state.reached_end[d] = Some(content_size[d]);
+                        appending = true;
+                        (on_reach_end.callback)();
+                    }
+                } else {
+                    state.reached_end[d] = None;
+                }
+            }
+            if let Some(on_reach_start) = &on_reach_start {
+                if state.offset[d] <= on_reach_start.threshold {
+                    if state.reached_start[d] != Some(content_size[d]) {
+                        state.reached_start[d] = Some(content_size[d]);
+                        // If the content fits, or could grow at either end, there is nothing to keep in view:
+                        if 0.0 < available_offset[d] && !appending {
+                            state.prepend_anchor[d] = Some(content_size[d]);
+                        }
+                        (on_reach_start.callback)();
+                    }
+                } else {
+                    state.reached_start[d] = None;
+                }
+            }
+        }
+
         // Is scroll handle at end of content, or is there no scrollbar
         // yet (not enough content), but sticking is requested? If so, enter sticky mode.
         // Only has an effect if stick_to_end is enabled but we save in
@@ -1446,3 +2061,148 @@ impl Prepared {
         (content_size, state)
     }
 }
+
+/// The spinner above content that is pulled down to refresh, see [`ScrollArea::pull_to_refresh`].
+fn paint_pull_to_refresh_spinner(ui: &mut Ui, inner_rect: Rect, pulled_by: f32, refreshing: bool) {
+    if pulled_by <= 0.0 {
+        return;
+    }
+    let size = 0.5 * PULL_TO_REFRESH_DISTANCE;
+    let center = pos2(
+        inner_rect.center().x,
+        inner_rect.top() + 0.5 * pulled_by.min(PULL_TO_REFRESH_DISTANCE),
+    );
+    let rect = Rect::from_center_size(center, Vec2::splat(size));
+
+    let mut spinner_ui = ui.new_child(UiBuilder::new().max_rect(rect));
+    spinner_ui.set_clip_rect(inner_rect.intersect(ui.clip_rect()));
+    if !refreshing {
+        // Fade in while pulling:
+        spinner_ui.multiply_opacity((pulled_by / PULL_TO_REFRESH_DISTANCE).min(1.0));
+    }
+    crate::Spinner::new().size(size).paint_at(&spinner_ui, rect);
+}
+
+/// The part of `clip_rect` that isn't hidden behind an on-screen keyboard,
+/// so that scrolling to a widget reveals it above the keyboard.
+fn unoccluded_clip_rect(ui: &Ui, clip_rect: Rect) -> Rect {
+    let Some(unoccluded) =
+        ui.input(|i| i.virtual_keyboard_rect.map(|_| i.unoccluded_screen_rect()))
+    else {
+        return clip_rect;
+    };
+    let unoccluded = ui
+        .ctx()
+        .layer_transform_from_global(ui.layer_id())
+        .map_or(unoccluded, |transform| transform.mul_rect(unoccluded));
+    let visible = clip_rect.intersect(unoccluded);
+    if visible.is_positive() {
+        visible
+    } else {
+        clip_rect
+    }
+}
+
+/// The measured (or estimated) height of each row in [`ScrollArea::show_varying_rows`],
+/// in a Fenwick tree, so we can quickly find the row at any offset, and update single rows.
+#[derive(Clone, Default)]
+struct RowHeights {
+    heights: Vec<f32>,
+
+    /// `tree[i]` is the sum of the heights of the `i & (-i)` rows ending at row `i - 1`.
+    tree: Vec<f32>,
+
+    /// The width of the rows when they were measured.
+    width: f32,
+}
+
+impl RowHeights {
+    fn new(heights: impl Iterator<Item = f32>, width: f32) -> Self {
+        let heights: Vec<f32> = heights.collect();
+        let mut tree = Vec::with_capacity(heights.len() + 1);
+        tree.push(0.0);
+        tree.extend_from_slice(&heights);
+        for i in 1..tree.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent < tree.len() {
+                tree[parent] += tree[i];
+            }
+        }
+        Self {
+            heights,
+            tree,
+            width,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    fn total(&self) -> f32 {
+        self.offset(self.len())
+    }
+
+    /// The sum of the heights of the rows before `row`.
+    fn offset(&self, row: usize) -> f32 {
+        let mut sum = 0.0;
+        let mut i = row;
+        while 0 < i {
+            sum += self.tree[i];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// The row at the given offset from the top, clamped to the last row.
+    fn row_at(&self, offset: f32) -> usize {
+        let mut row = 0;
+        let mut remaining = offset;
+        let mut step = self.tree.len().next_power_of_two();
+        while 0 < step {
+            let next = row + step;
+            if next < self.tree.len() && self.tree[next] <= remaining {
+                row = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+        row.min(self.len().saturating_sub(1))
+    }
+
+    /// Returns `true` if the height changed.
+    fn set(&mut self, row: usize, height: f32) -> bool {
+        let delta = height - self.heights[row];
+        if delta.abs() < 0.1 {
+            return false;
+        }
+        self.heights[row] = height;
+        let mut i = row + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowHeights;
+
+    #[test]
+    fn row_heights() {
+        let mut heights = RowHeights::new([10.0, 20.0, 30.0, 40.0, 50.0].into_iter(), 100.0);
+        assert_eq!(heights.total(), 150.0);
+        assert_eq!(heights.offset(3), 60.0);
+        assert_eq!(heights.row_at(0.0), 0);
+        assert_eq!(heights.row_at(29.0), 1);
+        assert_eq!(heights.row_at(30.0), 2);
+        assert_eq!(heights.row_at(1000.0), 4);
+
+        assert!(heights.set(1, 5.0));
+        assert!(!heights.set(1, 5.0));
+        assert_eq!(heights.total(), 135.0);
+        assert_eq!(heights.row_at(15.0), 2);
+    }
+}
@@ -0,0 +1,394 @@
+//! A resizable split container: a region divided into several panes with draggable dividers.
+//!
+//! See [`Split`].
+
+use std::hash::Hash;
+
+use emath::GuiRounding as _;
+
+use crate::{
+    Context, CursorIcon, Id, InnerResponse, Layout, NumExt as _, Rangef, Rect, Sense, Stroke, Ui,
+    UiBuilder, pos2,
+};
+
+/// The persisted state of a [`Split`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SplitState {
+    /// The fraction of the available space given to each pane.
+    ///
+    /// Sums to one.
+    pub ratios: Vec<f32>,
+
+    /// Which panes are collapsed.
+    ///
+    /// A collapsed pane keeps its ratio, so that it gets the same size when expanded again.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub collapsed: Vec<bool>,
+}
+
+impl SplitState {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// Divide the available space of a [`Ui`] into several panes, separated by draggable dividers.
+///
+/// Unlike [`crate::SidePanel`], a [`Split`] is not tied to the edges of the screen or a window,
+/// and can be used anywhere inside a [`Ui`], including inside another [`Split`].
+///
+/// The split will fill all available space of the parent [`Ui`].
+/// The fraction of space given to each pane is persisted in [`crate::Memory`].
+///
+/// Double-clicking a divider collapses the pane before it (left or above),
+/// and double-clicking it again restores the pane.
+///
+/// For the common case of two panes, see also [`Ui::split_horizontal`] and [`Ui::split_vertical`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::containers::Split::horizontal("my_split")
+///     .min_size(50.0)
+///     .show(ui, 2, |panes| {
+///         panes[0].label("Left pane");
+///         panes[1].label("Right pane");
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Split {
+    id_salt: Id,
+    horizontal: bool,
+    size_range: Rangef,
+    default_ratios: Option<Vec<f32>>,
+    show_separator_line: bool,
+    collapsible: bool,
+}
+
+impl Split {
+    /// Panes are placed side by side, left-to-right, with vertical dividers between them.
+    pub fn horizontal(id_salt: impl Hash) -> Self {
+        Self::new(id_salt, true)
+    }
+
+    /// Panes are placed on top of each other, top-to-bottom, with horizontal dividers between them.
+    pub fn vertical(id_salt: impl Hash) -> Self {
+        Self::new(id_salt, false)
+    }
+
+    fn new(id_salt: impl Hash, horizontal: bool) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            horizontal,
+            size_range: Rangef::new(32.0, f32::INFINITY),
+            default_ratios: None,
+            show_separator_line: true,
+            collapsible: true,
+        }
+    }
+
+    /// The minimum width (for [`Self::horizontal`]) or height (for [`Self::vertical`]) of each pane.
+    ///
+    /// Default: `32.0`.
+    #[inline]
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        let min_size = min_size.at_least(0.0);
+        self.size_range = Rangef::new(min_size, self.size_range.max.at_least(min_size));
+        self
+    }
+
+    /// The maximum width (for [`Self::horizontal`]) or height (for [`Self::vertical`]) of each pane.
+    ///
+    /// If all panes are at their maximum, they still grow to fill the available space.
+    ///
+    /// Default: no maximum.
+    #[inline]
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.size_range = Rangef::new(self.size_range.min.at_most(max_size), max_size);
+        self
+    }
+
+    /// The fraction of the space each pane gets before the user has resized anything.
+    ///
+    /// The ratios are normalized, so `[1.0, 2.0]` gives the second pane twice the space of the first.
+    /// If the number of ratios doesn't match the number of panes, the space is divided evenly.
+    #[inline]
+    pub fn default_ratios(mut self, ratios: impl IntoIterator<Item = f32>) -> Self {
+        self.default_ratios = Some(ratios.into_iter().collect());
+        self
+    }
+
+    /// Show a separator line between the panes, even when not interacting with it?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_separator_line(mut self, show_separator_line: bool) -> Self {
+        self.show_separator_line = show_separator_line;
+        self
+    }
+
+    /// Can panes be collapsed by double-clicking a divider?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Show the split, with `num_panes` panes.
+    ///
+    /// The closure is given one child [`Ui`] per pane.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        num_panes: usize,
+        add_contents: impl FnOnce(&mut [Ui]) -> R,
+    ) -> InnerResponse<R> {
+        self.show_dyn(ui, num_panes, Box::new(add_contents))
+    }
+
+    fn show_dyn<'c, R>(
+        self,
+        ui: &mut Ui,
+        num_panes: usize,
+        add_contents: Box<dyn FnOnce(&mut [Ui]) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            horizontal,
+            size_range,
+            default_ratios,
+            show_separator_line,
+            collapsible,
+        } = self;
+
+        let num_panes = num_panes.at_least(1);
+        let id = ui.make_persistent_id(id_salt);
+        let rect = ui.available_rect_before_wrap();
+        ui.ctx().check_for_id_clash(id, rect, "Split");
+
+        let gap = if horizontal {
+            ui.spacing().item_spacing.x
+        } else {
+            ui.spacing().item_spacing.y
+        };
+        let (rect_min, rect_len) = if horizontal {
+            (rect.min.x, rect.width())
+        } else {
+            (rect.min.y, rect.height())
+        };
+        let available = (rect_len - gap * (num_panes - 1) as f32).at_least(0.0);
+
+        let mut state = SplitState::load(ui.ctx(), id)
+            .filter(|state| state.ratios.len() == num_panes)
+            .unwrap_or_else(|| SplitState {
+                ratios: default_ratios
+                    .filter(|ratios| ratios.len() == num_panes)
+                    .unwrap_or_else(|| vec![1.0; num_panes]),
+                collapsed: vec![],
+            });
+        state.collapsed.resize(num_panes, false);
+
+        // Read the divider interactions first, to avoid frame latency in the resize:
+        let divider_id = |i: usize| id.with(("divider", i));
+        let mut dragged_divider = None;
+        for i in 0..num_panes - 1 {
+            let Some(response) = ui.ctx().read_response(divider_id(i)) else {
+                continue;
+            };
+            if collapsible && response.double_clicked() {
+                state.collapsed[i] = !state.collapsed[i];
+            } else if response.dragged() {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    // Dragging the divider of a collapsed pane expands it again:
+                    state.collapsed[i] = false;
+                    state.collapsed[i + 1] = false;
+                    dragged_divider = Some((i, if horizontal { pointer.x } else { pointer.y }));
+                }
+            }
+        }
+
+        let mut sizes = resolve_sizes(&state.ratios, available, size_range, &state.collapsed);
+
+        if let Some((i, pointer)) = dragged_divider {
+            let start: f32 = sizes[..i].iter().sum();
+            let pair = sizes[i] + sizes[i + 1];
+            let boundary = pointer - rect_min - gap * (i as f32 + 0.5);
+            let min_first = size_range.min.at_least(pair - size_range.max);
+            let max_first = size_range.max.at_most(pair - size_range.min);
+            let first = if min_first <= max_first {
+                (boundary - start).clamp(min_first, max_first)
+            } else {
+                0.5 * pair
+            };
+            sizes[i] = first;
+            sizes[i + 1] = pair - first;
+        }
+
+        let mut pane_rects = Vec::with_capacity(num_panes);
+        let mut offset = rect_min;
+        for &size in &sizes {
+            let pane_rect = if horizontal {
+                Rect::from_min_max(pos2(offset, rect.min.y), pos2(offset + size, rect.max.y))
+            } else {
+                Rect::from_min_max(pos2(rect.min.x, offset), pos2(rect.max.x, offset + size))
+            };
+            pane_rects.push(pane_rect.round_ui());
+            offset += size + gap;
+        }
+
+        let mut panes: Vec<Ui> = pane_rects
+            .iter()
+            .enumerate()
+            .map(|(i, &pane_rect)| {
+                let mut pane_ui = ui.new_child(
+                    UiBuilder::new()
+                        .id_salt(id.with(i))
+                        .max_rect(pane_rect)
+                        .layout(Layout::top_down(ui.layout_direction().start())),
+                );
+                pane_ui.set_clip_rect(pane_rect.intersect(ui.clip_rect()));
+                pane_ui
+            })
+            .collect();
+
+        let inner = add_contents(&mut panes[..]);
+
+        // Now we do the actual divider interaction, on top of all the contents:
+        for i in 0..num_panes - 1 {
+            let (a, b) = (pane_rects[i], pane_rects[i + 1]);
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+            let divider_rect = if horizontal {
+                let x = 0.5 * (a.max.x + b.min.x);
+                Rect::from_x_y_ranges(x - grab_radius..=x + grab_radius, rect.y_range())
+            } else {
+                let y = 0.5 * (a.max.y + b.min.y);
+                Rect::from_x_y_ranges(rect.x_range(), y - grab_radius..=y + grab_radius)
+            };
+            let sense = if collapsible {
+                Sense::click_and_drag()
+            } else {
+                Sense::drag()
+            };
+            let response = ui.interact(divider_rect, divider_id(i), sense);
+
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(if horizontal {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                });
+            }
+
+            let stroke = if response.dragged() {
+                ui.style().visuals.widgets.active.fg_stroke
+            } else if response.hovered() {
+                ui.style().visuals.widgets.hovered.fg_stroke
+            } else if show_separator_line {
+                ui.style().visuals.widgets.noninteractive.bg_stroke
+            } else {
+                Stroke::NONE
+            };
+            let center = divider_rect.center();
+            if horizontal {
+                ui.painter().vline(center.x, rect.y_range(), stroke);
+            } else {
+                ui.painter().hline(rect.x_range(), center.y, stroke);
+            }
+        }
+
+        if 0.0 < available {
+            // Collapsed panes keep their ratio, and the open panes share what is left:
+            let collapsed_ratio: f32 = (0..num_panes)
+                .filter(|&i| state.collapsed[i])
+                .map(|i| state.ratios[i])
+                .sum();
+            let open_ratio = (1.0 - collapsed_ratio).at_least(0.0);
+            for (i, size) in sizes.iter().enumerate() {
+                if !state.collapsed[i] {
+                    state.ratios[i] = open_ratio * size / available;
+                }
+            }
+        }
+        state.store(ui.ctx(), id);
+
+        ui.advance_cursor_after_rect(rect);
+        let response = ui.interact(rect, id, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Turn ratios into sizes that sum to `available`, keeping each pane within `size_range` if possible.
+///
+/// Collapsed panes get no space at all.
+fn resolve_sizes(
+    ratios: &[f32],
+    available: f32,
+    size_range: Rangef,
+    collapsed: &[bool],
+) -> Vec<f32> {
+    let ratios: Vec<f32> = ratios
+        .iter()
+        .zip(collapsed)
+        .map(|(&ratio, &collapsed)| if collapsed { 0.0 } else { ratio.at_least(0.0) })
+        .collect();
+    let num_open = collapsed.iter().filter(|&&collapsed| !collapsed).count();
+    if num_open == 0 {
+        return vec![0.0; ratios.len()];
+    }
+
+    let total: f32 = ratios.iter().sum();
+    let mut sizes: Vec<f32> = ratios
+        .iter()
+        .zip(collapsed)
+        .map(|(&ratio, &collapsed)| {
+            if collapsed {
+                0.0
+            } else if 0.0 < total {
+                available * ratio / total
+            } else {
+                available / num_open as f32
+            }
+        })
+        .collect();
+
+    // Make sure the range can be satisfied:
+    let even = available / num_open as f32;
+    let (min_size, max_size) = (size_range.min.at_most(even), size_range.max.at_least(even));
+
+    // Clamp each pane, and give (or take) the difference to the panes that can take it:
+    for _ in 0..sizes.len() {
+        let mut excess = 0.0;
+        for (size, &collapsed) in sizes.iter_mut().zip(collapsed) {
+            if !collapsed {
+                let clamped = size.clamp(min_size, max_size);
+                excess += *size - clamped;
+                *size = clamped;
+            }
+        }
+        let flexible: Vec<usize> = (0..sizes.len())
+            .filter(|&i| {
+                !collapsed[i]
+                    && if 0.0 < excess {
+                        sizes[i] < max_size
+                    } else {
+                        min_size < sizes[i]
+                    }
+            })
+            .collect();
+        if excess.abs() < 0.01 || flexible.is_empty() {
+            break;
+        }
+        for &i in &flexible {
+            sizes[i] += excess / flexible.len() as f32;
+        }
+    }
+    sizes
+}
@@ -0,0 +1,178 @@
+//! Headers and footers that stay in view while their section scrolls, see [`StickySection`].
+
+use crate::{
+    Color32, CornerRadius, Id, InnerResponse, Rect, Shape, Ui, UiBuilder, UiKind, pos2, vec2,
+};
+
+/// A section with a header that sticks to the top of the visible area while the section
+/// scrolls underneath it, like the section headers of a list on a phone.
+///
+/// When the end of the section reaches the header, the header is pushed out with it,
+/// so a list of sections hands the top over from one header to the next.
+/// The same goes for an optional footer, which sticks to the bottom of the visible area.
+///
+/// This is meant to be used inside a [`crate::ScrollArea`], but works anywhere
+/// (the "visible area" being [`Ui::clip_rect`]).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::ScrollArea::vertical().show(ui, |ui| {
+///     for letter in ['A', 'B', 'C'] {
+///         egui::StickySection::new(letter)
+///             .header(|ui| {
+///                 ui.heading(letter.to_string());
+///             })
+///             .show(ui, |ui| {
+///                 for i in 0..20 {
+///                     ui.label(format!("{letter}{i}"));
+///                 }
+///             });
+///     }
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct StickySection<'a> {
+    id_salt: Id,
+    header: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
+    footer: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
+    fill: Option<Color32>,
+}
+
+impl<'a> StickySection<'a> {
+    /// Create a new [`StickySection`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            header: None,
+            footer: None,
+            fill: None,
+        }
+    }
+
+    /// The header, which sticks to the top of the visible area.
+    #[inline]
+    pub fn header(mut self, add_header: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.header = Some(Box::new(add_header));
+        self
+    }
+
+    /// The footer, which sticks to the bottom of the visible area.
+    #[inline]
+    pub fn footer(mut self, add_footer: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.footer = Some(Box::new(add_footer));
+        self
+    }
+
+    /// The background of the header and footer while they are stuck,
+    /// hiding the contents scrolling underneath.
+    ///
+    /// Default: the fill of the surrounding [`crate::Window`] or panel.
+    #[inline]
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            header,
+            footer,
+            fill,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let fill = fill.unwrap_or_else(|| {
+            if ui.stack().contained_in(UiKind::Window) {
+                ui.visuals().window_fill()
+            } else {
+                ui.visuals().panel_fill
+            }
+        });
+
+        // The header and footer are added last, so they are on top of the contents,
+        // so we reserve space for them using their sizes from the previous frame:
+        let known_heights: Option<[f32; 2]> = ui.data(|d| d.get_temp(id));
+        let [header_height, footer_height] = known_heights.unwrap_or_default();
+
+        let InnerResponse {
+            inner: (inner, header_rect, footer_rect),
+            response,
+        } = ui.scope(|ui| {
+            let width = ui.available_width();
+            let header_rect = header
+                .is_some()
+                .then(|| ui.allocate_space(vec2(width, header_height)).1);
+            let inner = add_contents(ui);
+            let footer_rect = footer
+                .is_some()
+                .then(|| ui.allocate_space(vec2(width, footer_height)).1);
+            (inner, header_rect, footer_rect)
+        });
+
+        let visible = ui.clip_rect();
+        let contents_bottom = footer_rect.map_or(response.rect.bottom(), |rect| {
+            rect.top() - ui.spacing().item_spacing.y
+        });
+
+        let mut new_heights = [header_height, footer_height];
+        let mut header_bottom = response.rect.top();
+        if let (Some(add_header), Some(natural)) = (header, header_rect) {
+            // Stick to the top, until pushed out by the end of the section:
+            let top = visible
+                .top()
+                .min(contents_bottom - header_height)
+                .max(natural.top());
+            new_heights[0] = sticky_ui(ui, id.with("header"), natural, top, fill, add_header);
+            header_bottom = top + header_height;
+        }
+        if let (Some(add_footer), Some(natural)) = (footer, footer_rect) {
+            // Stick to the bottom, but never above the header:
+            let top = (visible.bottom() - footer_height)
+                .max(header_bottom)
+                .min(natural.top());
+            new_heights[1] = sticky_ui(ui, id.with("footer"), natural, top, fill, add_footer);
+        }
+
+        if known_heights != Some(new_heights) {
+            ui.data_mut(|d| d.insert_temp(id, new_heights));
+            ui.ctx()
+                .request_discard("StickySection header or footer changed size");
+        }
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Show a header or footer at `top`, which is where it would naturally be or further down,
+/// and return its height.
+fn sticky_ui(
+    ui: &mut Ui,
+    id_salt: Id,
+    natural: Rect,
+    top: f32,
+    fill: Color32,
+    add_contents: Box<dyn FnOnce(&mut Ui) + '_>,
+) -> f32 {
+    let is_stuck = 0.5 < (top - natural.top()).abs();
+    let background = ui.painter().add(Shape::Noop);
+
+    let max_rect = Rect::from_min_size(
+        pos2(natural.left(), top),
+        vec2(natural.width(), f32::INFINITY),
+    );
+    let mut child_ui = ui.new_child(UiBuilder::new().id_salt(id_salt).max_rect(max_rect));
+    add_contents(&mut child_ui);
+    let rect = child_ui.min_rect();
+
+    if is_stuck {
+        let background_rect = Rect::from_x_y_ranges(natural.x_range(), rect.y_range());
+        ui.painter().set(
+            background,
+            Shape::rect_filled(background_rect, CornerRadius::ZERO, fill),
+        );
+    }
+
+    rect.height()
+}
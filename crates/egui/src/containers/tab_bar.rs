@@ -0,0 +1,467 @@
+//! A row of tabs, see [`TabBar`].
+
+use std::sync::Arc;
+
+use crate::{
+    Button, CursorIcon, Galley, Id, Key, Modifiers, Popup, Rect, Response, Sense, Stroke,
+    StrokeKind, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText, WidgetType,
+    pos2, vec2,
+};
+
+/// A tab in a [`TabBar`].
+#[derive(Clone, Debug)]
+pub struct Tab {
+    title: WidgetText,
+    closable: bool,
+    unsaved: bool,
+}
+
+impl Tab {
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: title.into(),
+            closable: true,
+            unsaved: false,
+        }
+    }
+
+    /// Show a close button on the tab.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Show a dot on the tab, to indicate that it has unsaved changes.
+    ///
+    /// The dot turns into the close button when the tab is hovered.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn unsaved(mut self, unsaved: bool) -> Self {
+        self.unsaved = unsaved;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct TabBarState {
+    /// The first tab that is shown, when they don't all fit.
+    first: usize,
+
+    /// The tab being dragged.
+    dragged: Option<usize>,
+}
+
+/// A row of tabs, of which one is active, e.g. for the open documents of an editor.
+///
+/// The tabs are a slice of your own type, which you describe with a [`Tab`] each.
+/// * Clicking a tab activates it, as does Ctrl+Tab (next) and Ctrl+Shift+Tab (previous).
+/// * Tabs can be reordered by dragging them. This reorders your slice.
+/// * Clicking the close button of a tab is reported in [`TabBarResponse::close_requested`],
+///   so you can e.g. ask to save the changes before removing it.
+/// * When the tabs don't all fit, the active one is scrolled into view,
+///   and a button at the end lists all the tabs.
+///
+/// The [`TabBar`] only shows the tabs, not their contents.
+/// To also split and rearrange groups of tabs, use [`crate::Dock`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// struct Document {
+///     name: String,
+///     unsaved: bool,
+/// }
+/// # let mut documents: Vec<Document> = vec![];
+/// # let mut active = 0;
+/// let response = egui::TabBar::new("documents").show(ui, &mut documents, &mut active, |doc| {
+///     egui::Tab::new(doc.name.as_str()).unsaved(doc.unsaved)
+/// });
+/// if let Some(index) = response.close_requested {
+///     documents.remove(index);
+/// }
+/// if let Some(doc) = documents.get_mut(active) {
+///     ui.label(format!("Editing {}", doc.name));
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct TabBar {
+    id_salt: Id,
+    reorderable: bool,
+    keyboard_shortcuts: bool,
+}
+
+impl TabBar {
+    /// Create a new [`TabBar`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            reorderable: true,
+            keyboard_shortcuts: true,
+        }
+    }
+
+    /// Can the tabs be reordered by dragging them?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Switch tabs with Ctrl+Tab and Ctrl+Shift+Tab.
+    ///
+    /// Turn this off if there is more than one [`TabBar`] on screen.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn keyboard_shortcuts(mut self, keyboard_shortcuts: bool) -> Self {
+        self.keyboard_shortcuts = keyboard_shortcuts;
+        self
+    }
+
+    /// Show the tabs, with `active` being the index of the active one.
+    ///
+    /// `tab` describes how each element of `tabs` is shown.
+    pub fn show<T>(
+        self,
+        ui: &mut Ui,
+        tabs: &mut [T],
+        active: &mut usize,
+        tab: impl FnMut(&T) -> Tab,
+    ) -> TabBarResponse {
+        let Self {
+            id_salt,
+            reorderable,
+            keyboard_shortcuts,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state = ui
+            .data(|d| d.get_temp::<TabBarState>(id))
+            .unwrap_or_default();
+        let num_tabs = tabs.len();
+        let previous_active = *active;
+        *active = (*active).min(num_tabs.saturating_sub(1));
+
+        let height = ui.spacing().interact_size.y + 4.0;
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+        ui.painter()
+            .rect_filled(rect, 0.0, ui.visuals().faint_bg_color);
+
+        if keyboard_shortcuts && 0 < num_tabs {
+            let (previous, next) = ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::CTRL | Modifiers::SHIFT, Key::Tab),
+                    i.consume_key(Modifiers::CTRL, Key::Tab),
+                )
+            });
+            if previous {
+                *active = (*active + num_tabs - 1) % num_tabs;
+            } else if next {
+                *active = (*active + 1) % num_tabs;
+            }
+        }
+
+        let descriptions: Vec<Tab> = tabs.iter().map(tab).collect();
+        let widths: Vec<f32> = descriptions.iter().map(|tab| tab_width(ui, tab)).collect();
+
+        let gap = 1.0;
+        let total_width = |range: std::ops::RangeInclusive<usize>| -> f32 {
+            widths[range].iter().map(|w| w + gap).sum()
+        };
+        let overflowing = 0 < num_tabs && rect.width() < total_width(0..=num_tabs - 1);
+        let (tabs_rect, overflow_rect) = if overflowing {
+            rect.split_left_right_at_x(rect.max.x - height)
+        } else {
+            (rect, Rect::NOTHING)
+        };
+
+        // Which tabs are shown, keeping the active one in view:
+        let visible = if overflowing {
+            let mut first = state.first.min(*active);
+            while first < *active && tabs_rect.width() < total_width(first..=*active) {
+                first += 1;
+            }
+            let mut last = *active;
+            while last + 1 < num_tabs && total_width(first..=last + 1) <= tabs_rect.width() {
+                last += 1;
+            }
+            // Don't leave empty space at the end:
+            while 0 < first && total_width(first - 1..=last) <= tabs_rect.width() {
+                first -= 1;
+            }
+            first..last + 1
+        } else {
+            0..num_tabs
+        };
+        state.first = visible.start;
+
+        let mut tab_ui = ui.new_child(UiBuilder::new().id_salt(id).max_rect(tabs_rect));
+        tab_ui.set_clip_rect(tabs_rect.intersect(ui.clip_rect()));
+
+        let mut close_requested = None;
+        let mut tab_rects = vec![];
+        let mut x = tabs_rect.min.x;
+        for i in visible.clone() {
+            let tab_rect = Rect::from_min_size(pos2(x, rect.min.y), vec2(widths[i], height));
+            x = tab_rect.max.x + gap;
+            let tab_response = tab_ui_button(
+                &tab_ui,
+                id.with(("tab", i)),
+                &descriptions[i],
+                i == *active,
+                tab_rect,
+            );
+
+            if tab_response.close_clicked {
+                close_requested = Some(i);
+            } else if tab_response.tab.clicked() {
+                *active = i;
+            }
+            if reorderable && tab_response.tab.drag_started() {
+                state.dragged = Some(i);
+            }
+            tab_rects.push((i, tab_rect));
+        }
+
+        if overflowing {
+            let button = ui
+                .put(overflow_rect.shrink(2.0), Button::new("⏷").frame(false))
+                .on_hover_text("All tabs");
+            Popup::menu(&button).show(|ui| {
+                for (i, tab) in descriptions.iter().enumerate() {
+                    if ui
+                        .selectable_label(i == *active, tab.title.clone())
+                        .clicked()
+                    {
+                        *active = i;
+                    }
+                }
+            });
+        }
+
+        let mut reordered = false;
+        if let Some(source) = state.dragged.filter(|&i| i < num_tabs) {
+            let (pointer, released) =
+                ui.input(|i| (i.pointer.interact_pos(), !i.pointer.primary_down()));
+            // The tab is moved to before the first tab whose center is right of the pointer:
+            let target = pointer.map(|pointer| {
+                tab_rects
+                    .iter()
+                    .find(|(_, rect)| pointer.x < rect.center().x)
+                    .map_or(visible.end, |&(i, _)| i)
+            });
+
+            if released {
+                if let Some(target) = target {
+                    let destination = if source < target { target - 1 } else { target };
+                    if destination != source {
+                        move_tab(tabs, source, destination);
+                        *active = moved_index(*active, source, destination);
+                        reordered = true;
+                    }
+                }
+                state.dragged = None;
+            } else {
+                ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                if let Some(target) = target {
+                    let x = tab_rects
+                        .iter()
+                        .find(|&&(i, _)| i == target)
+                        .map_or(x, |(_, rect)| rect.min.x);
+                    ui.painter().vline(
+                        x - 0.5 * gap,
+                        rect.y_range(),
+                        Stroke::new(2.0, ui.visuals().selection.bg_fill),
+                    );
+                }
+            }
+        } else {
+            state.dragged = None;
+        }
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        TabBarResponse {
+            response,
+            close_requested,
+            active_changed: *active != previous_active,
+            reordered,
+        }
+    }
+}
+
+/// Move the element at `source` to `destination`, shifting the ones in between.
+fn move_tab<T>(tabs: &mut [T], source: usize, destination: usize) {
+    if source < destination {
+        tabs[source..=destination].rotate_left(1);
+    } else {
+        tabs[destination..=source].rotate_right(1);
+    }
+}
+
+/// Where the element at `index` ends up after [`move_tab`].
+fn moved_index(index: usize, source: usize, destination: usize) -> usize {
+    if index == source {
+        destination
+    } else if source < index && index <= destination {
+        index - 1
+    } else if destination <= index && index < source {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// Returned by [`TabBar::show`].
+#[derive(Clone, Debug)]
+pub struct TabBarResponse {
+    /// The response of the whole bar.
+    pub response: Response,
+
+    /// The user clicked the close button of the tab with this index.
+    ///
+    /// It is up to you to remove it.
+    pub close_requested: Option<usize>,
+
+    /// The active tab changed this frame, by clicking or with the keyboard.
+    pub active_changed: bool,
+
+    /// The user dragged a tab to a new place this frame.
+    pub reordered: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Shared with `Dock`:
+
+pub(crate) struct TabResponse {
+    pub tab: Response,
+    pub close_clicked: bool,
+}
+
+fn tab_galley(ui: &Ui, tab: &Tab) -> Arc<Galley> {
+    tab.title.clone().into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    )
+}
+
+/// The width of a tab, with room for its close button or unsaved dot.
+pub(crate) fn tab_width(ui: &Ui, tab: &Tab) -> f32 {
+    let padding = ui.spacing().button_padding.x;
+    let button_width = if tab.closable || tab.unsaved {
+        ui.spacing().icon_width + padding
+    } else {
+        0.0
+    };
+    padding + tab_galley(ui, tab).size().x + padding + button_width
+}
+
+pub(crate) fn tab_ui_button(ui: &Ui, id: Id, tab: &Tab, active: bool, rect: Rect) -> TabResponse {
+    let Tab {
+        closable, unsaved, ..
+    } = *tab;
+    let galley = tab_galley(ui, tab);
+    let padding = ui.spacing().button_padding.x;
+    let close_size = ui.spacing().icon_width;
+
+    let tab = ui.interact(rect, id, Sense::click_and_drag());
+    tab.widget_info(|| {
+        WidgetInfo::selected(
+            WidgetType::SelectableLabel,
+            ui.is_enabled(),
+            active,
+            galley.text(),
+        )
+    });
+
+    let visuals = ui.style().interact_selectable(&tab, active);
+    let fill = if active {
+        ui.visuals().panel_fill
+    } else if tab.hovered() {
+        visuals.weak_bg_fill
+    } else {
+        ui.visuals().faint_bg_color
+    };
+    let corner_radius = ui.visuals().widgets.inactive.corner_radius;
+    let corner_radius = epaint::CornerRadius {
+        sw: 0,
+        se: 0,
+        ..corner_radius
+    };
+    ui.painter().rect(
+        rect,
+        corner_radius,
+        fill,
+        if active {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        } else {
+            Stroke::NONE
+        },
+        StrokeKind::Inside,
+    );
+    let close_rect = Rect::from_center_size(
+        pos2(rect.max.x - padding - 0.5 * close_size, rect.center().y),
+        Vec2::splat(close_size),
+    );
+    let mut close_clicked = false;
+    let mut close_hovered = false;
+    if closable {
+        let close = ui.interact(close_rect, id.with("close"), Sense::click());
+        close.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Close tab"));
+        close_clicked = close.clicked();
+        close_hovered = close.hovered();
+
+        if tab.hovered() || close_hovered || (active && !unsaved) {
+            let visuals = ui.style().interact(&close);
+            let x_rect = close_rect.shrink(3.0).expand(visuals.expansion);
+            let stroke = visuals.fg_stroke;
+            ui.painter()
+                .line_segment([x_rect.left_top(), x_rect.right_bottom()], stroke);
+            ui.painter()
+                .line_segment([x_rect.right_top(), x_rect.left_bottom()], stroke);
+        }
+    }
+    if unsaved && !(closable && (tab.hovered() || close_hovered)) {
+        ui.painter().circle_filled(
+            close_rect.center(),
+            0.2 * close_size,
+            ui.visuals().text_color(),
+        );
+    }
+
+    let text_pos = pos2(
+        rect.min.x + padding,
+        rect.center().y - 0.5 * galley.size().y,
+    );
+    ui.painter().galley(text_pos, galley, visuals.text_color());
+
+    TabResponse { tab, close_clicked }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_tab() {
+        for source in 0..4 {
+            for destination in 0..4 {
+                let mut tabs = [0, 1, 2, 3];
+                move_tab(&mut tabs, source, destination);
+                assert_eq!(tabs[destination], source);
+                for index in 0..4 {
+                    assert_eq!(tabs[moved_index(index, source, destination)], index);
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::pass_state::PerWidgetTooltipState;
 use crate::{
     AreaState, Context, Id, InnerResponse, LayerId, Layout, Order, Popup, PopupAnchor, PopupKind,
-    Response, Sense,
+    Response, Sense, Ui, WidgetText,
 };
 use emath::Vec2;
 
@@ -13,6 +13,9 @@ pub struct Tooltip<'a> {
 
     /// The id of the widget that owns this tooltip.
     parent_widget: Id,
+
+    /// Allow selecting the text of the tooltip.
+    interactive: bool,
 }
 
 impl Tooltip<'_> {
@@ -31,6 +34,7 @@ impl Tooltip<'_> {
                 .sense(Sense::hover()),
             parent_layer,
             parent_widget,
+            interactive: false,
         }
     }
 
@@ -50,6 +54,7 @@ impl Tooltip<'_> {
                 .sense(Sense::hover()),
             parent_layer,
             parent_widget,
+            interactive: false,
         }
     }
 
@@ -64,6 +69,7 @@ impl Tooltip<'_> {
             popup,
             parent_layer: response.layer_id,
             parent_widget: response.id,
+            interactive: false,
         }
     }
 
@@ -115,12 +121,25 @@ impl Tooltip<'_> {
         self
     }
 
+    /// Allow selecting the text in the tooltip.
+    ///
+    /// By default the labels of a tooltip are not selectable,
+    /// so that the tooltip closes when the pointer leaves the widget.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
     /// Show the tooltip
     pub fn show<R>(self, content: impl FnOnce(&mut crate::Ui) -> R) -> Option<InnerResponse<R>> {
         let Self {
             mut popup,
             parent_layer,
             parent_widget,
+            interactive,
         } = self;
 
         if !popup.is_open() {
@@ -155,7 +174,9 @@ impl Tooltip<'_> {
             // which also mean they won't stick around so you can click them.
             // Only tooltips that have actual interactive stuff (buttons, links, …)
             // will stick around when you try to click them.
-            ui.style_mut().interaction.selectable_labels = false;
+            if !interactive {
+                ui.style_mut().interaction.selectable_labels = false;
+            }
 
             content(ui)
         });
@@ -212,6 +233,19 @@ impl Tooltip<'_> {
 
     /// Should we show a tooltip for this response?
     pub fn should_show_tooltip(response: &Response) -> bool {
+        Self::should_show_tooltip_with(response, None, false)
+    }
+
+    /// Like [`Self::should_show_tooltip`], with some overrides.
+    ///
+    /// * `delay`: override [`crate::style::Interaction::tooltip_delay`] for this widget.
+    /// * `stay_open_when_hovered`: keep the tooltip open while the pointer is over it
+    ///   (or on its way to it), even if it has no interactive widgets.
+    pub fn should_show_tooltip_with(
+        response: &Response,
+        delay: Option<f32>,
+        stay_open_when_hovered: bool,
+    ) -> bool {
         if response.ctx.memory(|mem| mem.everything_is_visible()) {
             return true;
         }
@@ -228,7 +262,7 @@ impl Tooltip<'_> {
 
         let style = response.ctx.style();
 
-        let tooltip_delay = style.interaction.tooltip_delay;
+        let tooltip_delay = delay.unwrap_or(style.interaction.tooltip_delay);
         let tooltip_grace_time = style.interaction.tooltip_grace_time;
 
         let (
@@ -271,7 +305,7 @@ impl Tooltip<'_> {
                     .any(|w| w.enabled && w.sense.interactive())
             });
 
-            if tooltip_has_interactive_widget {
+            if stay_open_when_hovered || tooltip_has_interactive_widget {
                 // We keep the tooltip open if hovered,
                 // or if the pointer is on its way to it,
                 // so that the user can interact with the tooltip
@@ -397,3 +431,121 @@ impl Tooltip<'_> {
         })
     }
 }
+
+/// A tooltip with a structured layout: a title, a body, and a keyboard shortcut hint.
+///
+/// Use with [`Response::on_hover_rich`]:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+/// ui.button("💾")
+///     .on_hover_rich(
+///         egui::RichTooltip::new()
+///             .title("Save")
+///             .body("Save the document to disk.")
+///             .shortcut(ui.ctx().format_shortcut(&shortcut)),
+///     );
+/// # });
+/// ```
+#[must_use = "You should pass this to `Response::on_hover_rich`"]
+#[derive(Clone, Default)]
+pub struct RichTooltip {
+    title: Option<WidgetText>,
+    body: Option<WidgetText>,
+    shortcut: Option<WidgetText>,
+    delay: Option<f32>,
+    interactive: bool,
+}
+
+impl RichTooltip {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A title, shown in bold at the top.
+    #[inline]
+    pub fn title(mut self, title: impl Into<WidgetText>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The main text of the tooltip.
+    #[inline]
+    pub fn body(mut self, body: impl Into<WidgetText>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// A keyboard shortcut hint, shown weakly next to the title.
+    ///
+    /// See [`Context::format_shortcut`].
+    #[inline]
+    pub fn shortcut(mut self, shortcut: impl Into<WidgetText>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// How long to hover the widget before the tooltip is shown, in seconds.
+    ///
+    /// Default: [`crate::style::Interaction::tooltip_delay`].
+    #[inline]
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Keep the tooltip open while the user hovers it, and allow selecting its text.
+    ///
+    /// Use this if you put interactive content in the tooltip with [`Self::show`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Show the tooltip if the (enabled) widget is hovered,
+    /// with extra content below the body.
+    pub fn show<R>(
+        self,
+        response: &Response,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let Self {
+            title,
+            body,
+            shortcut,
+            delay,
+            interactive,
+        } = self;
+
+        let open =
+            response.enabled() && Tooltip::should_show_tooltip_with(response, delay, interactive);
+        let mut tooltip = Tooltip::for_widget(response).interactive(interactive);
+        tooltip.popup = tooltip.popup.open(open);
+
+        tooltip.show(|ui| {
+            // Prevent `Area` auto-sizing from shrinking tooltips with dynamic content.
+            // See https://github.com/emilk/egui/issues/5167
+            ui.set_max_width(ui.spacing().tooltip_width);
+
+            if title.is_some() || shortcut.is_some() {
+                ui.horizontal(|ui| {
+                    if let Some(title) = title {
+                        ui.label(title.strong());
+                    }
+                    if let Some(shortcut) = shortcut {
+                        ui.add_space(ui.spacing().item_spacing.x);
+                        ui.label(shortcut.weak());
+                    }
+                });
+            }
+            if let Some(body) = body {
+                ui.label(body);
+            }
+            add_contents(ui)
+        })
+    }
+}
@@ -19,6 +19,7 @@ use super::{Area, Frame, Resize, ScrollArea, area, resize};
 /// * if the window has a scroll area (off by default)
 /// * if the window can be collapsed (minimized) to just the title bar (yes, by default)
 /// * if there should be a close button (none by default)
+/// * if the window can be minimized to a strip along the bottom of the screen (no, by default)
 ///
 /// ```
 /// # egui::__run_test_ctx(|ctx| {
@@ -44,6 +45,9 @@ pub struct Window<'open> {
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
+    snap: bool,
+    tile: bool,
+    minimizable: bool,
 }
 
 impl<'open> Window<'open> {
@@ -67,6 +71,9 @@ impl<'open> Window<'open> {
             default_open: true,
             with_title_bar: true,
             fade_out: true,
+            snap: false,
+            tile: false,
+            minimizable: false,
         }
     }
 
@@ -113,6 +120,30 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Snap the window to the edges of the screen and of other windows when dragged.
+    ///
+    /// How close an edge must be to snap is set by [`crate::style::Interaction::window_snap_distance`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Resize the window to fill half of the screen when it is dropped at an edge of the screen,
+    /// or a quarter of it when dropped in a corner.
+    ///
+    /// While dragging, a preview of where the window will go is shown.
+    /// Only resizable windows are tiled.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn tile(mut self, tile: bool) -> Self {
+        self.tile = tile;
+        self
+    }
+
     /// `order(Order::Foreground)` for a Window that should always be on top
     #[inline]
     pub fn order(mut self, order: Order) -> Self {
@@ -349,6 +380,20 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Add a minimize-button to the title bar.
+    ///
+    /// A minimized window is hidden, and instead shown as just its title
+    /// in a strip along the bottom of the screen. Clicking it restores the window.
+    ///
+    /// See also [`Self::set_minimized`] and [`Self::restore`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.minimizable = minimizable;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     #[inline]
@@ -411,6 +456,41 @@ impl<'open> Window<'open> {
 }
 
 impl Window<'_> {
+    /// Is the window with the given id minimized?
+    ///
+    /// The id of a window is [`Id::new`] of its title, unless set with [`Self::id`].
+    pub fn is_minimized(ctx: &Context, id: Id) -> bool {
+        ctx.data_mut(|d| d.get_persisted(id.with("minimized")))
+            .unwrap_or(false)
+    }
+
+    /// Minimize or restore the window with the given id.
+    ///
+    /// This works even if the window is not [`Self::minimizable`].
+    pub fn set_minimized(ctx: &Context, id: Id, minimized: bool) {
+        ctx.data_mut(|d| d.insert_persisted(id.with("minimized"), minimized));
+    }
+
+    /// Is the window with the given id collapsed to just its title bar?
+    ///
+    /// Returns `false` if the window has never been shown.
+    pub fn is_collapsed(ctx: &Context, id: Id) -> bool {
+        CollapsingState::load(ctx, id.with("collapsing")).is_some_and(|state| !state.is_open())
+    }
+
+    /// Collapse or expand the window with the given id, like clicking the collapsing triangle.
+    pub fn set_collapsed(ctx: &Context, id: Id, collapsed: bool) {
+        let mut state = CollapsingState::load_with_default_open(ctx, id.with("collapsing"), true);
+        state.set_open(!collapsed);
+        state.store(ctx);
+    }
+
+    /// Un-minimize and expand the window with the given id.
+    pub fn restore(ctx: &Context, id: Id) {
+        Self::set_minimized(ctx, id, false);
+        Self::set_collapsed(ctx, id, false);
+    }
+
     /// Returns `None` if the window is not open (if [`Window::open`] was called with `&mut false`).
     /// Returns `Some(InnerResponse { inner: None })` if the window is collapsed.
     #[inline]
@@ -438,6 +518,9 @@ impl Window<'_> {
             default_open,
             with_title_bar,
             fade_out,
+            snap,
+            tile,
+            minimizable,
         } = self;
 
         let header_color =
@@ -455,6 +538,10 @@ impl Window<'_> {
             return None;
         }
 
+        if is_open && Self::is_minimized(ctx, area.id) {
+            return Some(show_minimized(ctx, &area, title));
+        }
+
         let area_id = area.id;
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
@@ -468,6 +555,7 @@ impl Window<'_> {
         let mut resize = resize.id(resize_id);
 
         let on_top = Some(area_layer_id) == ctx.top_layer_id();
+        let movable = area.is_enabled() && area.is_movable();
         let mut area = area.begin(ctx);
 
         area.with_widget_info(|| WidgetInfo::labeled(WidgetType::Window, true, title.text()));
@@ -503,6 +591,21 @@ impl Window<'_> {
             resize.max_size.y = resize.max_size.y.min(max_height);
         }
 
+        let margins = window_frame.total_margin().sum()
+            + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
+
+        if snap || tile {
+            snap_and_tile_window(
+                ctx,
+                &mut area,
+                area_layer_id,
+                snap,
+                tile && possible.resizable(),
+                margins,
+                resize_id,
+            );
+        }
+
         // First check for resize to avoid frame delay:
         let last_frame_outer_rect = area.state().rect();
         let resize_interaction = ctx.with_accessibility_parent(area.id(), || {
@@ -515,18 +618,23 @@ impl Window<'_> {
             )
         });
 
-        {
-            let margins = window_frame.total_margin().sum()
-                + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
+        resize_response(
+            resize_interaction,
+            ctx,
+            margins,
+            area_layer_id,
+            &mut area,
+            resize_id,
+        );
 
-            resize_response(
-                resize_interaction,
-                ctx,
-                margins,
-                area_layer_id,
-                &mut area,
-                resize_id,
-            );
+        let mut close_with_keyboard = false;
+        if on_top {
+            move_and_resize_with_keyboard(ctx, &mut area, movable, possible, margins, resize_id);
+            if open.is_some() {
+                let close = ctx.options(|o| o.window_shortcuts.close);
+                close_with_keyboard =
+                    close.is_some_and(|close| ctx.input_mut(|i| i.consume_shortcut(&close)));
+            }
         }
 
         let mut area_content_ui = area.content_ui(ctx);
@@ -551,6 +659,7 @@ impl Window<'_> {
                         &frame.content_ui,
                         title,
                         show_close_button,
+                        minimizable,
                         collapsible,
                         window_frame,
                         title_bar_height_with_margin,
@@ -626,13 +735,18 @@ impl Window<'_> {
                         );
                     }
 
+                    let mut minimize = false;
                     title_bar.ui(
                         &mut area_content_ui,
                         &content_response,
                         open.as_deref_mut(),
+                        minimizable.then_some(&mut minimize),
                         &mut collapsing,
                         collapsible,
                     );
+                    if minimize {
+                        Window::set_minimized(ctx, area_id, true);
+                    }
                 }
 
                 collapsing.store(ctx);
@@ -645,7 +759,7 @@ impl Window<'_> {
 
         let full_response = area.end(ctx, area_content_ui);
 
-        if full_response.should_close() {
+        if full_response.should_close() || close_with_keyboard {
             if let Some(open) = open {
                 *open = false;
             }
@@ -659,6 +773,61 @@ impl Window<'_> {
     }
 }
 
+/// Where the next minimized window goes in the strip along the bottom of the screen.
+#[derive(Clone, Copy)]
+struct MinimizedStrip {
+    pass_nr: u64,
+    next_pos: Pos2,
+}
+
+/// Shows a minimized window as just its title, in a strip along the bottom of the screen.
+fn show_minimized<R>(ctx: &Context, area: &Area, title: WidgetText) -> InnerResponse<Option<R>> {
+    let bounds = ctx.available_rect();
+    let spacing = ctx.style().spacing.item_spacing;
+
+    // Minimized windows are laid out left-to-right in the order they are shown each pass:
+    let strip_id = Id::new("egui::window::minimized_strip");
+    let pass_nr = ctx.cumulative_pass_nr();
+    let pos = ctx
+        .data(|d| d.get_temp::<MinimizedStrip>(strip_id))
+        .filter(|strip| strip.pass_nr == pass_nr)
+        .map_or(
+            bounds.left_bottom() + vec2(spacing.x, -spacing.y),
+            |strip| strip.next_pos,
+        );
+
+    let InnerResponse {
+        inner: response,
+        response: strip_response,
+    } = Area::new(area.id.with("minimized"))
+        .kind(UiKind::Window)
+        .order(area.layer().order)
+        .pivot(Align2::LEFT_BOTTOM)
+        .fixed_pos(pos)
+        .show(ctx, |ui| {
+            Frame::window(ui.style())
+                .inner_margin(ui.spacing().button_padding)
+                .show(ui, |ui| {
+                    ui.add(Button::new(title).frame(false))
+                        .on_hover_text("Restore window")
+                })
+                .inner
+        });
+
+    if response.clicked() {
+        Window::restore(ctx, area.id);
+        ctx.move_to_top(area.layer());
+    }
+
+    let next_pos = pos2(strip_response.rect.right() + spacing.x, pos.y);
+    ctx.data_mut(|d| d.insert_temp(strip_id, MinimizedStrip { pass_nr, next_pos }));
+
+    InnerResponse {
+        inner: None,
+        response,
+    }
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -849,6 +1018,368 @@ fn resize_response(
     ctx.memory_mut(|mem| mem.areas_mut().move_to_top(area_layer_id));
 }
 
+/// The position of a window being dragged, before snapping.
+#[derive(Clone, Copy)]
+struct SnapState {
+    free_min: Pos2,
+}
+
+fn snap_and_tile_window(
+    ctx: &Context,
+    area: &mut area::Prepared,
+    layer_id: LayerId,
+    snap: bool,
+    tile: bool,
+    margins: Vec2,
+    resize_id: Id,
+) {
+    let move_response = area.move_response();
+    if !move_response.dragged() && !move_response.drag_stopped() {
+        return;
+    }
+    let drag_started = move_response.drag_started();
+    let drag_stopped = move_response.drag_stopped();
+    let drag_delta = move_response.drag_delta();
+
+    let distance = ctx.style().interaction.window_snap_distance;
+    let bounds = area.constrain_rect();
+    let rect = area.state().rect();
+
+    // We keep track of where the window would be without snapping,
+    // so that it unsnaps when the user drags it far enough:
+    let state_id = area.id().with("snap");
+    let free_min = match ctx.data(|d| d.get_temp::<SnapState>(state_id)) {
+        Some(state) if !drag_started => state.free_min + drag_delta,
+        _ => rect.min,
+    };
+    if drag_stopped {
+        ctx.data_mut(|d| d.remove::<SnapState>(state_id));
+    } else {
+        ctx.data_mut(|d| d.insert_temp(state_id, SnapState { free_min }));
+    }
+
+    let tile_rect = if tile {
+        ctx.input(|i| i.pointer.interact_pos())
+            .and_then(|pointer| tile_rect(bounds, pointer, distance))
+    } else {
+        None
+    };
+
+    if let Some(tile_rect) = tile_rect {
+        if drag_stopped {
+            area.state_mut().set_left_top_pos(tile_rect.min);
+            if let Some(mut state) = resize::State::load(ctx, resize_id) {
+                state.requested_size = Some(tile_rect.size() - margins);
+                state.store(ctx, resize_id);
+            }
+            return;
+        }
+
+        // Painted before the window contents, so it ends up behind the window:
+        let visuals = &ctx.style().visuals;
+        ctx.layer_painter(layer_id).rect(
+            tile_rect,
+            visuals.window_corner_radius,
+            visuals.selection.bg_fill.gamma_multiply(0.3),
+            visuals.selection.stroke,
+            StrokeKind::Inside,
+        );
+    }
+
+    let mut new_rect = Rect::from_min_size(free_min, rect.size());
+    if let Some(grid) = area::layout_grid(ctx) {
+        new_rect = Rect::from_min_size(area::snap_to_grid(free_min, grid), rect.size());
+    }
+    if snap {
+        let others: Vec<Rect> = ctx.memory(|mem| {
+            mem.areas()
+                .visible_windows()
+                .filter(|(other, _)| *other != layer_id)
+                .map(|(_, state)| state.rect())
+                .collect()
+        });
+        new_rect = new_rect.translate(snap_offset(new_rect, bounds, &others, distance));
+    }
+    if area.constrain() {
+        new_rect = Context::constrain_window_rect_to_area(new_rect, bounds);
+    }
+    area.state_mut().set_left_top_pos(new_rect.min);
+}
+
+/// How much to move `rect` so that its edges line up with the edges of `bounds` or `others`,
+/// if they are within `distance`.
+fn snap_offset(rect: Rect, bounds: Rect, others: &[Rect], distance: f32) -> Vec2 {
+    fn closest(best: &mut Option<f32>, offset: f32, distance: f32) {
+        if offset.abs() <= distance && best.is_none_or(|best| offset.abs() < best.abs()) {
+            *best = Some(offset);
+        }
+    }
+
+    let mut x = None;
+    let mut y = None;
+
+    closest(&mut x, bounds.left() - rect.left(), distance);
+    closest(&mut x, bounds.right() - rect.right(), distance);
+    closest(&mut y, bounds.top() - rect.top(), distance);
+    closest(&mut y, bounds.bottom() - rect.bottom(), distance);
+
+    for other in others {
+        let near = other.expand(distance);
+
+        // Only snap to edges we are next to:
+        if near.y_range().intersects(rect.y_range()) {
+            for edge in [rect.left(), rect.right()] {
+                for target in [other.left(), other.right()] {
+                    closest(&mut x, target - edge, distance);
+                }
+            }
+        }
+        if near.x_range().intersects(rect.x_range()) {
+            for edge in [rect.top(), rect.bottom()] {
+                for target in [other.top(), other.bottom()] {
+                    closest(&mut y, target - edge, distance);
+                }
+            }
+        }
+    }
+
+    vec2(x.unwrap_or(0.0), y.unwrap_or(0.0))
+}
+
+/// Where a window should be tiled if it is dropped at `pointer`:
+/// half of `bounds` at an edge, or a quarter of it in a corner.
+fn tile_rect(bounds: Rect, pointer: Pos2, distance: f32) -> Option<Rect> {
+    let left = pointer.x <= bounds.left() + distance;
+    let right = bounds.right() - distance <= pointer.x;
+    let top = pointer.y <= bounds.top() + distance;
+    let bottom = bounds.bottom() - distance <= pointer.y;
+
+    if !(left || right || top || bottom) {
+        return None;
+    }
+
+    let center = bounds.center();
+    let mut rect = bounds;
+    if left {
+        rect.max.x = center.x;
+    } else if right {
+        rect.min.x = center.x;
+    }
+    if top {
+        rect.max.y = center.y;
+    } else if bottom {
+        rect.min.y = center.y;
+    }
+    Some(rect.round_ui())
+}
+
+/// Keyboard shortcuts for managing [`Window`]s, set in [`crate::Options::window_shortcuts`].
+///
+/// Each shortcut can be rebound, or turned off by setting it to `None`.
+/// The shortcuts act on the window on top, i.e. the one most recently clicked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WindowShortcuts {
+    /// Bring the next window to the top, cycling through all of them.
+    ///
+    /// Default: Ctrl+F6.
+    pub focus_next: Option<KeyboardShortcut>,
+
+    /// Bring the previous window to the top.
+    ///
+    /// Default: Ctrl+Shift+F6.
+    pub focus_previous: Option<KeyboardShortcut>,
+
+    /// Close the window on top, if it has a close button.
+    ///
+    /// Default: Ctrl+F4.
+    pub close: Option<KeyboardShortcut>,
+
+    /// Hold these and press the arrow keys to move the window on top.
+    ///
+    /// Default: Ctrl+Alt.
+    pub move_modifiers: Option<Modifiers>,
+
+    /// Hold these and press the arrow keys to resize the window on top.
+    ///
+    /// Default: Ctrl+Alt+Shift.
+    pub resize_modifiers: Option<Modifiers>,
+
+    /// How many points each arrow key press moves or resizes a window.
+    ///
+    /// Default: `10.0`.
+    pub step: f32,
+}
+
+impl Default for WindowShortcuts {
+    fn default() -> Self {
+        Self {
+            focus_next: Some(KeyboardShortcut::new(Modifiers::CTRL, Key::F6)),
+            focus_previous: Some(KeyboardShortcut::new(
+                Modifiers::CTRL | Modifiers::SHIFT,
+                Key::F6,
+            )),
+            close: Some(KeyboardShortcut::new(Modifiers::CTRL, Key::F4)),
+            move_modifiers: Some(Modifiers::CTRL | Modifiers::ALT),
+            resize_modifiers: Some(Modifiers::CTRL | Modifiers::ALT | Modifiers::SHIFT),
+            step: 10.0,
+        }
+    }
+}
+
+impl WindowShortcuts {
+    /// Show the shortcuts in the ui.
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let Self {
+            focus_next,
+            focus_previous,
+            close,
+            move_modifiers,
+            resize_modifiers,
+            step,
+        } = self;
+
+        let shortcut_ui = |ui: &mut Ui, label: &str, shortcut: &Option<KeyboardShortcut>| {
+            ui.label(label);
+            match shortcut {
+                Some(shortcut) => ui.label(ui.ctx().format_shortcut(shortcut)),
+                None => ui.weak("None"),
+            };
+            ui.end_row();
+        };
+        let modifiers_ui = |ui: &mut Ui, label: &str, modifiers: &mut Option<Modifiers>| {
+            ui.label(label);
+            match modifiers {
+                Some(modifiers) => modifiers.ui(ui),
+                None => {
+                    ui.weak("None");
+                }
+            }
+            ui.end_row();
+        };
+
+        Grid::new("WindowShortcuts")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                shortcut_ui(ui, "Focus next window", focus_next);
+                shortcut_ui(ui, "Focus previous window", focus_previous);
+                shortcut_ui(ui, "Close window", close);
+                modifiers_ui(ui, "Move window (+ arrows)", move_modifiers);
+                modifiers_ui(ui, "Resize window (+ arrows)", resize_modifiers);
+
+                ui.label("Step");
+                ui.add(DragValue::new(step).range(1.0..=100.0));
+                ui.end_row();
+            });
+    }
+}
+
+/// Bring the next or previous window to the top, if the user pressed
+/// [`WindowShortcuts::focus_next`] or [`WindowShortcuts::focus_previous`].
+///
+/// Called by [`Context`] at the end of each pass.
+pub(crate) fn cycle_windows_with_keyboard(ctx: &Context) {
+    let shortcuts = ctx.options(|o| o.window_shortcuts);
+    let consume = |shortcut: Option<KeyboardShortcut>| {
+        shortcut.is_some_and(|shortcut| ctx.input_mut(|i| i.consume_shortcut(&shortcut)))
+    };
+    // The more specific shortcut first, since Shift is ignored when matching:
+    let previous = consume(shortcuts.focus_previous);
+    let next = !previous && consume(shortcuts.focus_next);
+    if !previous && !next {
+        return;
+    }
+
+    ctx.memory_mut(|mem| {
+        let areas = mem.areas_mut();
+        let visible: Vec<LayerId> = areas.visible_windows().map(|(layer, _)| layer).collect();
+        // Back to front:
+        let windows: Vec<LayerId> = areas
+            .order()
+            .iter()
+            .filter(|layer| visible.contains(layer))
+            .copied()
+            .collect();
+        if let (Some(&bottom), Some(&top)) = (windows.first(), windows.last()) {
+            if next {
+                areas.move_to_bottom(top);
+            } else {
+                areas.move_to_top(bottom);
+            }
+        }
+    });
+}
+
+/// Move or resize the window on top with the arrow keys, see [`WindowShortcuts`].
+fn move_and_resize_with_keyboard(
+    ctx: &Context,
+    area: &mut area::Prepared,
+    movable: bool,
+    possible: PossibleInteractions,
+    margins: Vec2,
+    resize_id: Id,
+) {
+    let shortcuts = ctx.options(|o| o.window_shortcuts);
+    let arrows = |i: &mut InputState, modifiers: Option<Modifiers>| {
+        let Some(modifiers) = modifiers else {
+            return Vec2::ZERO;
+        };
+        let mut delta = Vec2::ZERO;
+        for (key, direction) in [
+            (Key::ArrowLeft, Vec2::LEFT),
+            (Key::ArrowRight, Vec2::RIGHT),
+            (Key::ArrowUp, Vec2::UP),
+            (Key::ArrowDown, Vec2::DOWN),
+        ] {
+            delta += i.count_and_consume_key(modifiers, key) as f32 * direction;
+        }
+        delta * shortcuts.step
+    };
+    // Resize first, since its modifiers are the more specific:
+    let (resize_delta, move_delta) = ctx.input_mut(|i| {
+        let resize_delta = if possible.resize_right || possible.resize_bottom {
+            arrows(i, shortcuts.resize_modifiers)
+        } else {
+            Vec2::ZERO
+        };
+        let move_delta = if movable {
+            arrows(i, shortcuts.move_modifiers)
+        } else {
+            Vec2::ZERO
+        };
+        (resize_delta, move_delta)
+    });
+
+    let rect = area.state().rect();
+    if move_delta != Vec2::ZERO {
+        let mut new_rect = rect.translate(move_delta);
+        if area.constrain() {
+            new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
+        }
+        area.state_mut().set_left_top_pos(new_rect.min);
+    }
+    if resize_delta != Vec2::ZERO {
+        let resize_delta = vec2(
+            if possible.resize_right {
+                resize_delta.x
+            } else {
+                0.0
+            },
+            if possible.resize_bottom {
+                resize_delta.y
+            } else {
+                0.0
+            },
+        );
+        if let Some(mut state) = resize::State::load(ctx, resize_id) {
+            state.requested_size = Some(rect.size() + resize_delta - margins);
+            state.store(ctx, resize_id);
+        }
+    }
+}
+
 /// Acts on outer rect (outside the stroke)
 fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Option<Rect> {
     if !interaction.any_dragged() {
@@ -876,6 +1407,23 @@ fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Opt
     // Return to having the rect outside the stroke:
     rect = rect.expand(interaction.window_frame.stroke.width / 2.0);
 
+    if let Some(grid) = area::layout_grid(ctx) {
+        let snapped = Rect::from_min_max(
+            area::snap_to_grid(rect.min, grid),
+            area::snap_to_grid(rect.max, grid),
+        );
+        if interaction.left.drag {
+            rect.min.x = snapped.min.x;
+        } else if interaction.right.drag {
+            rect.max.x = snapped.max.x;
+        }
+        if interaction.top.drag {
+            rect.min.y = snapped.min.y;
+        } else if interaction.bottom.drag {
+            rect.max.y = snapped.max.y;
+        }
+    }
+
     Some(rect.round_ui())
 }
 
@@ -1122,10 +1670,12 @@ struct TitleBar {
 }
 
 impl TitleBar {
+    #[expect(clippy::fn_params_excessive_bools)]
     fn new(
         ui: &Ui,
         title: WidgetText,
         show_close_button: bool,
+        show_minimize_button: bool,
         collapsible: bool,
         window_frame: Frame,
         title_bar_height_with_margin: f32,
@@ -1150,9 +1700,13 @@ impl TitleBar {
             TextStyle::Heading,
         );
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title should be centered):
-            2.0 * (left_pad + button_size.x + item_spacing.x) + title_galley.size().x
+        // The close- and minimize buttons are on the right:
+        let num_right_buttons = show_close_button as usize + show_minimize_button as usize;
+        let minimum_width = if collapsible || num_right_buttons > 0 {
+            // If at least one button is shown we make room for buttons on both sides (since title should be centered):
+            let num_buttons_per_side = num_right_buttons.max(1) as f32;
+            2.0 * (left_pad + num_buttons_per_side * (button_size.x + item_spacing.x))
+                + title_galley.size().x
         } else {
             left_pad + title_galley.size().x + left_pad
         };
@@ -1182,6 +1736,8 @@ impl TitleBar {
     ///   a result of rendering the window content
     /// - `open`: if `None`, no "Close" button will be rendered, otherwise renders and processes
     ///   the "Close" button and writes a `false` if window was closed
+    /// - `minimize`: if `None`, no "Minimize" button will be rendered, otherwise renders and processes
+    ///   the "Minimize" button and writes a `true` if it was clicked
     /// - `collapsing`: holds the current expanding state. Can be changed by double click on the
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
@@ -1191,6 +1747,7 @@ impl TitleBar {
         ui: &mut Ui,
         content_response: &Option<Response>,
         open: Option<&mut bool>,
+        minimize: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
     ) {
@@ -1217,6 +1774,8 @@ impl TitleBar {
             });
         }
 
+        let show_close_button = open.is_some();
+        let show_minimize_button = minimize.is_some();
         if let Some(open) = open {
             // Add close button now that we know our full width:
             if self.close_button_ui(ui).clicked() {
@@ -1224,6 +1783,13 @@ impl TitleBar {
             }
         }
 
+        if let Some(minimize) = minimize {
+            // To the left of the close button, if any:
+            if self.minimize_button_ui(ui, show_close_button).clicked() {
+                *minimize = true;
+            }
+        }
+
         let text_pos =
             emath::align::center_size_in_rect(self.title_galley.size(), title_inner_rect)
                 .left_top();
@@ -1249,8 +1815,11 @@ impl TitleBar {
                 .hline(title_inner_rect.x_range(), y, window_frame.stroke);
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        // Don't cover the close-, minimize- and collapse buttons:
+        let mut double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        if show_close_button && show_minimize_button {
+            double_click_rect.max.x -= title_inner_rect.height();
+        }
 
         if false {
             ui.ctx().debug_painter().debug_rect(
@@ -1285,6 +1854,21 @@ impl TitleBar {
         let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
         close_button(ui, button_rect)
     }
+
+    /// Paints the "Minimize" button at the right side of the title bar,
+    /// to the left of the "Close" button if there is one,
+    /// and processes clicks on it.
+    fn minimize_button_ui(&self, ui: &mut Ui, has_close_button: bool) -> Response {
+        let mut slot_rect = Align2::RIGHT_CENTER
+            .align_size_within_rect(Vec2::splat(self.inner_rect.height()), self.inner_rect);
+        if has_close_button {
+            slot_rect = slot_rect.translate(vec2(-self.inner_rect.height(), 0.0));
+        }
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let button_rect = Rect::from_center_size(slot_rect.center(), button_size);
+        let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
+        minimize_button(ui, button_rect)
+    }
 }
 
 /// Paints the "Close" button of the window and processes clicks on it.
@@ -1314,3 +1898,22 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+/// Paints the "Minimize" button of the window and processes clicks on it.
+///
+/// The minimize button is just a `_` symbol, painted like the close button.
+fn minimize_button(ui: &mut Ui, rect: Rect) -> Response {
+    let minimize_id = ui.auto_id_with("window_minimize_button");
+    let response = ui.interact(rect, minimize_id, Sense::click());
+    response.widget_info(|| {
+        WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Minimize window")
+    });
+
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    ui.painter()
+        .line_segment([rect.left_bottom(), rect.right_bottom()], visuals.fg_stroke);
+    response
+}
@@ -0,0 +1,334 @@
+//! A multi-step form, see [`Wizard`].
+
+use crate::{
+    Align, Button, Context, Id, Layout, Rect, Response, Sense, TextStyle, Ui, UiBuilder, Vec2,
+    WidgetText, vec2,
+};
+
+/// The persisted state of a [`Wizard`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WizardState {
+    /// The index of the current step.
+    pub step: usize,
+
+    /// The furthest step that has been reached, which can be jumped back to from the header.
+    pub reached: usize,
+}
+
+impl WizardState {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// A form split into several steps, e.g. for a setup assistant.
+///
+/// A header shows all the steps, and which one is current.
+/// Below it the current step is shown, and below that "Back" and "Next" buttons
+/// (and "Finish" on the last step).
+/// The step can check its inputs and disable "Next" until they are valid,
+/// see [`WizardUi::disable_next`].
+///
+/// Each step has its own [`Id`] namespace, so the state of its widgets
+/// (e.g. scroll position) is kept when going to another step and back.
+/// The current step is persisted in [`crate::Memory`], see [`WizardState`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// let response = egui::Wizard::new("setup", ["Welcome", "Account", "Done"]).show(ui, |wizard| {
+///     match wizard.step() {
+///         0 => {
+///             wizard.ui.label("Welcome to the app!");
+///         }
+///         1 => {
+///             wizard.ui.text_edit_singleline(&mut name);
+///             if name.is_empty() {
+///                 wizard.disable_next("Please enter a name");
+///             }
+///         }
+///         _ => {
+///             wizard.ui.label("All set.");
+///         }
+///     }
+/// });
+/// if response.finished {
+///     // Close the wizard.
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Wizard {
+    id_salt: Id,
+    titles: Vec<WidgetText>,
+    animated: bool,
+}
+
+impl Wizard {
+    /// Create a new [`Wizard`] with a locally unique identifier, and the titles of its steps.
+    pub fn new(
+        id_salt: impl std::hash::Hash,
+        titles: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            titles: titles.into_iter().map(Into::into).collect(),
+            animated: true,
+        }
+    }
+
+    /// Slide the steps in from the side when going to the next or previous step.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_step: impl FnOnce(&mut WizardUi<'_>) -> R,
+    ) -> WizardResponse<R> {
+        let Self {
+            id_salt,
+            titles,
+            animated,
+        } = self;
+        let num_steps = titles.len().max(1);
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state = WizardState::load(ui.ctx(), id).unwrap_or_default();
+        state.step = state.step.min(num_steps - 1);
+        state.reached = state.reached.clamp(state.step, num_steps - 1);
+        let mut new_step = state.step;
+
+        let response = ui.vertical(|ui| {
+            if let Some(step) = header_ui(ui, &titles, state) {
+                new_step = step;
+            }
+            ui.separator();
+
+            let inner = step_ui(ui, id, state.step, animated, |ui| {
+                let mut wizard = WizardUi {
+                    ui,
+                    step: state.step,
+                    num_steps,
+                    next_disabled_reason: None,
+                    back_disabled: false,
+                };
+                let inner = add_step(&mut wizard);
+                (inner, wizard.next_disabled_reason, wizard.back_disabled)
+            });
+            let (inner, next_disabled_reason, back_disabled) = inner;
+
+            ui.separator();
+            let finished = ui
+                .horizontal(|ui| {
+                    let back =
+                        ui.add_enabled(0 < state.step && !back_disabled, Button::new("Back"));
+                    if back.clicked() {
+                        new_step = state.step - 1;
+                    }
+
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        let is_last = state.step + 1 == num_steps;
+                        let text = if is_last { "Finish" } else { "Next" };
+                        let mut next =
+                            ui.add_enabled(next_disabled_reason.is_none(), Button::new(text));
+                        if let Some(reason) = next_disabled_reason {
+                            next = next.on_disabled_hover_text(reason.clone());
+                            ui.label(reason);
+                        }
+                        if next.clicked() && !is_last {
+                            new_step = state.step + 1;
+                        }
+                        next.clicked() && is_last
+                    })
+                    .inner
+                })
+                .inner;
+
+            (inner, finished)
+        });
+        let (inner, finished) = response.inner;
+
+        let previous_step = state.step;
+        state.step = new_step;
+        state.reached = state.reached.max(new_step);
+        state.store(ui.ctx(), id);
+
+        WizardResponse {
+            inner,
+            response: response.response,
+            step: new_step,
+            step_changed: new_step != previous_step,
+            finished,
+        }
+    }
+}
+
+/// The header with all the steps. Returns the step the user clicked, if any.
+fn header_ui(ui: &mut Ui, titles: &[WidgetText], state: WizardState) -> Option<usize> {
+    let mut clicked = None;
+    ui.horizontal_wrapped(|ui| {
+        for (i, title) in titles.iter().enumerate() {
+            if 0 < i {
+                let (rect, _) = ui.allocate_exact_size(vec2(16.0, 1.0), Sense::hover());
+                ui.painter().hline(
+                    rect.x_range(),
+                    rect.center().y,
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                );
+            }
+
+            let can_jump = i != state.step && i <= state.reached;
+            let sense = if can_jump {
+                Sense::click()
+            } else {
+                Sense::hover()
+            };
+            let response = ui
+                .scope_builder(UiBuilder::new().sense(sense), |ui| {
+                    ui.horizontal(|ui| {
+                        step_number_ui(ui, i, state);
+                        let title = if i == state.step {
+                            title.clone().strong()
+                        } else if state.reached < i {
+                            title.clone().weak()
+                        } else {
+                            title.clone()
+                        };
+                        ui.add(crate::Label::new(title).selectable(false));
+                    });
+                })
+                .response;
+            if response.clicked() {
+                clicked = Some(i);
+            }
+            if can_jump {
+                response.on_hover_cursor(crate::CursorIcon::PointingHand);
+            }
+        }
+    });
+    clicked
+}
+
+/// A circle with the number of the step in it.
+fn step_number_ui(ui: &mut Ui, index: usize, state: WizardState) {
+    let size = ui.text_style_height(&TextStyle::Body) + 4.0;
+    let (rect, _) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+    let visuals = ui.visuals();
+    let (fill, text_color) = if index == state.step {
+        (visuals.selection.bg_fill, visuals.selection.stroke.color)
+    } else if index <= state.reached {
+        (visuals.widgets.inactive.bg_fill, visuals.text_color())
+    } else {
+        (visuals.extreme_bg_color, visuals.weak_text_color())
+    };
+    let painter = ui.painter();
+    painter.circle(
+        rect.center(),
+        0.5 * size,
+        fill,
+        visuals.widgets.noninteractive.bg_stroke,
+    );
+    painter.text(
+        rect.center(),
+        crate::Align2::CENTER_CENTER,
+        (index + 1).to_string(),
+        TextStyle::Small.resolve(ui.style()),
+        text_color,
+    );
+}
+
+/// Show the current step, sliding it in from the side after the step changed.
+fn step_ui<R>(
+    ui: &mut Ui,
+    id: Id,
+    step: usize,
+    animated: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let shown_step = if animated {
+        ui.ctx()
+            .animate_value_with_time(id.with("step"), step as f32, ui.style().animation_time)
+    } else {
+        step as f32
+    };
+    // -1 when coming from the previous step, +1 when coming from the next one:
+    let offset = (shown_step - step as f32).clamp(-1.0, 1.0);
+
+    let max_rect = ui.available_rect_before_wrap();
+    let mut child_ui = ui.new_child(
+        UiBuilder::new()
+            .id_salt(("step", step))
+            .max_rect(max_rect.translate(vec2(-offset * max_rect.width(), 0.0))),
+    );
+    child_ui.set_clip_rect(
+        ui.clip_rect()
+            .intersect(Rect::everything_below(max_rect.top())),
+    );
+    child_ui.multiply_opacity(1.0 - offset.abs());
+    let inner = add_contents(&mut child_ui);
+
+    let size = vec2(max_rect.width(), child_ui.min_rect().height());
+    ui.allocate_exact_size(size, Sense::hover());
+    inner
+}
+
+/// The [`Ui`] of the current step of a [`Wizard`], see [`Wizard::show`].
+pub struct WizardUi<'a> {
+    /// Add the contents of the step to this.
+    pub ui: &'a mut Ui,
+
+    step: usize,
+    num_steps: usize,
+    next_disabled_reason: Option<WidgetText>,
+    back_disabled: bool,
+}
+
+impl WizardUi<'_> {
+    /// The index of the current step.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn num_steps(&self) -> usize {
+        self.num_steps
+    }
+
+    /// Disable the "Next" (or "Finish") button, e.g. because an input is invalid,
+    /// and show the reason next to it.
+    pub fn disable_next(&mut self, reason: impl Into<WidgetText>) {
+        self.next_disabled_reason = Some(reason.into());
+    }
+
+    /// Disable the "Back" button, e.g. while the step is busy.
+    pub fn disable_back(&mut self) {
+        self.back_disabled = true;
+    }
+}
+
+/// Returned by [`Wizard::show`].
+pub struct WizardResponse<R> {
+    /// What the step closure returned.
+    pub inner: R,
+
+    pub response: Response,
+
+    /// The step that will be shown next frame.
+    pub step: usize,
+
+    /// Did the user go to another step this frame?
+    pub step_changed: bool,
+
+    /// Did the user click "Finish" on the last step?
+    pub finished: bool,
+}
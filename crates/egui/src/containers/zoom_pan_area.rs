@@ -0,0 +1,227 @@
+//! A zoomable and pannable canvas that remembers its view, see [`ZoomPanArea`].
+
+use crate::{
+    Context, Id, InnerResponse, Pos2, Rangef, Rect, Response, Sense, Ui, UiBuilder, Vec2,
+    emath::TSTransform,
+};
+
+use super::{DragPanButtons, Scene, scene::fit_to_rect_in_scene};
+
+/// The persisted state of a [`ZoomPanArea`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ZoomPanState {
+    /// The part of the contents that is in view, in the coordinates of the contents.
+    pub scene_rect: Rect,
+
+    /// The bounds of the contents, last time they were shown.
+    pub content_rect: Rect,
+}
+
+impl Default for ZoomPanState {
+    fn default() -> Self {
+        // An invalid scene rect makes the scene fit its contents:
+        Self {
+            scene_rect: Rect::ZERO,
+            content_rect: Rect::ZERO,
+        }
+    }
+}
+
+impl ZoomPanState {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Show all of the contents, as large as the zoom range allows.
+    pub fn zoom_to_fit(&mut self) {
+        self.scene_rect = self.content_rect;
+    }
+}
+
+/// A canvas that can be zoomed and panned, e.g. for a node editor or a map view.
+///
+/// This is a [`Scene`] that stores its view in [`crate::Memory`], so you don't have to.
+/// It fills the available space, and:
+/// * pans when dragging the background, or scrolling
+/// * zooms around the pointer when pinching, or scrolling with ctrl/cmd held down
+/// * zooms to fit the contents when double-clicking the background (see [`Self::fit_on_double_click`])
+///
+/// The contents are laid out in their own coordinates, and the returned [`ZoomPanResponse`]
+/// can convert between those and screen coordinates.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = egui::ZoomPanArea::new("canvas")
+///     .zoom_range(0.1..=4.0)
+///     .show(ui, |ui| {
+///         ui.put(
+///             egui::Rect::from_min_size(egui::pos2(100.0, 100.0), egui::vec2(80.0, 30.0)),
+///             egui::Button::new("Node"),
+///         );
+///     });
+/// if let Some(pointer) = ui.ctx().pointer_hover_pos() {
+///     let _pointer_on_canvas = response.scene_from_screen(pointer);
+/// }
+/// if ui.button("Fit").clicked() {
+///     response.zoom_to_fit();
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .show()"]
+pub struct ZoomPanArea {
+    id_salt: Id,
+    scene: Scene,
+    zoom_range: Rangef,
+    fit_on_double_click: bool,
+}
+
+impl ZoomPanArea {
+    /// Create a new [`ZoomPanArea`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        let zoom_range = Rangef::new(f32::EPSILON, 1.0);
+        Self {
+            id_salt: Id::new(id_salt),
+            scene: Scene::new().zoom_range(zoom_range),
+            zoom_range,
+            fit_on_double_click: true,
+        }
+    }
+
+    /// Specify what type of input the background should respond to.
+    ///
+    /// See [`Scene::sense`].
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.scene = self.scene.sense(sense);
+        self
+    }
+
+    /// Set the allowed zoom range.
+    ///
+    /// See [`Scene::zoom_range`].
+    #[inline]
+    pub fn zoom_range(mut self, zoom_range: impl Into<Rangef>) -> Self {
+        self.zoom_range = zoom_range.into();
+        self.scene = self.scene.zoom_range(self.zoom_range);
+        self
+    }
+
+    /// Set the maximum size of the inner [`Ui`] that will be created.
+    ///
+    /// See [`Scene::max_inner_size`].
+    #[inline]
+    pub fn max_inner_size(mut self, max_inner_size: impl Into<Vec2>) -> Self {
+        self.scene = self.scene.max_inner_size(max_inner_size);
+        self
+    }
+
+    /// Specify which pointer buttons can be used to pan by clicking and dragging.
+    ///
+    /// See [`Scene::drag_pan_buttons`].
+    #[inline]
+    pub fn drag_pan_buttons(mut self, flags: DragPanButtons) -> Self {
+        self.scene = self.scene.drag_pan_buttons(flags);
+        self
+    }
+
+    /// Zoom to fit the contents when the background is double-clicked.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn fit_on_double_click(mut self, fit_on_double_click: bool) -> Self {
+        self.fit_on_double_click = fit_on_double_click;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ZoomPanResponse<R> {
+        let Self {
+            id_salt,
+            scene,
+            zoom_range,
+            fit_on_double_click,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state = ZoomPanState::load(ui.ctx(), id).unwrap_or_default();
+        let outer_rect = ui.available_rect_before_wrap();
+
+        let InnerResponse { inner, response } = ui
+            .scope_builder(UiBuilder::new().id_salt(id_salt), |ui| {
+                scene.show(ui, &mut state.scene_rect, |ui| {
+                    let inner = add_contents(ui);
+                    state.content_rect = ui.min_rect();
+                    inner
+                })
+            })
+            .inner;
+
+        if fit_on_double_click && response.double_clicked() {
+            state.zoom_to_fit();
+        }
+        state.store(ui.ctx(), id);
+
+        ZoomPanResponse {
+            inner,
+            response,
+            id,
+            state,
+            to_global: fit_to_rect_in_scene(outer_rect, state.scene_rect, zoom_range),
+        }
+    }
+}
+
+/// Returned by [`ZoomPanArea::show`].
+pub struct ZoomPanResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the background.
+    ///
+    /// Note that positions from this (e.g. [`Response::hover_pos`]) are in the coordinates of the contents.
+    pub response: Response,
+
+    /// Where the state is stored.
+    pub id: Id,
+
+    /// The state at the end of the frame.
+    pub state: ZoomPanState,
+
+    /// From the coordinates of the contents to screen coordinates.
+    pub to_global: TSTransform,
+}
+
+impl<R> ZoomPanResponse<R> {
+    /// The current zoom factor, where `1.0` means the contents are shown at their normal size.
+    pub fn zoom(&self) -> f32 {
+        self.to_global.scaling
+    }
+
+    /// Convert a screen position (e.g. of the pointer) to the coordinates of the contents.
+    pub fn scene_from_screen(&self, pos: Pos2) -> Pos2 {
+        self.to_global.inverse() * pos
+    }
+
+    /// Convert a position in the coordinates of the contents to screen coordinates.
+    pub fn screen_from_scene(&self, pos: Pos2) -> Pos2 {
+        self.to_global * pos
+    }
+
+    /// Show all of the contents from the next frame on.
+    pub fn zoom_to_fit(&self) {
+        let ctx = &self.response.ctx;
+        let mut state = self.state;
+        state.zoom_to_fit();
+        state.store(ctx, self.id);
+        ctx.request_repaint();
+    }
+}
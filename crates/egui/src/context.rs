@@ -5,7 +5,7 @@ use std::{borrow::Cow, cell::RefCell, panic::Location, sync::Arc, time::Duration
 use emath::{GuiRounding as _, OrderedFloat};
 use epaint::{
     ClippedPrimitive, ClippedShape, Color32, ImageData, ImageDelta, Pos2, Rect, StrokeKind,
-    TessellationOptions, TextureAtlas, TextureId, Vec2,
+    TessellationOptions, TextureAtlas, TextureId, TiledTexture, Vec2,
     emath::{self, TSTransform},
     mutex::RwLock,
     stats::PaintStats,
@@ -15,15 +15,17 @@ use epaint::{
 };
 
 use crate::{
-    Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
-    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
-    ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, ViewportBuilder,
-    ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput,
-    Widget as _, WidgetRect, WidgetText,
-    animation_manager::AnimationManager,
+    Align2, ColorImage, Cursor, CursorIcon, CustomCursorId, DeferredViewportUiCallback,
+    FontDefinitions, Grid, Id, ImmediateViewport, ImmediateViewportRendererCallback, Key,
+    KeyboardShortcut, Label, LayerId, Memory, ModifierNames, Modifiers, NumExt as _, Order,
+    Painter, RawInput, Response, RichText, ScrollArea, Sense, Style, TextStyle, TextureHandle,
+    TextureOptions, Ui, ViewportBuilder, ViewportCommand, ViewportId, ViewportIdMap,
+    ViewportIdPair, ViewportIdSet, ViewportOutput, Widget as _, WidgetRect, WidgetText,
+    animation::{Animatable, Keyframes, Tween},
+    animation_manager::{AnimationManager, AnimationState},
     containers::{self, area::AreaState},
     data::output::PlatformOutput,
+    dirty_region::DirtyRegionTracker,
     epaint, hit_test,
     input_state::{InputState, MultiTouchInfo, PointerEvent},
     interaction,
@@ -31,7 +33,7 @@ use crate::{
     load::{self, Bytes, Loaders, SizedTexture},
     memory::{Options, Theme},
     os::OperatingSystem,
-    output::FullOutput,
+    output::{FullOutput, OutputEvent, WidgetInteraction},
     pass_state::PassState,
     resize, response, scroll_area,
     util::IdTypeMap,
@@ -102,11 +104,32 @@ struct NamedContextCallback {
     callback: ContextCallback,
 }
 
+/// Callback for [`Context::on_widget_interaction`].
+pub type WidgetInteractionCallback = Arc<dyn Fn(&WidgetInteraction) + Send + Sync>;
+
+#[derive(Clone)]
+struct NamedWidgetInteractionCallback {
+    debug_name: &'static str,
+    callback: WidgetInteractionCallback,
+}
+
+/// A cursor image registered with [`Context::register_cursor`].
+#[derive(Clone)]
+struct RegisteredCursor {
+    image: Arc<ColorImage>,
+
+    /// In pixels.
+    hotspot: Vec2,
+
+    texture: TextureHandle,
+}
+
 /// Callbacks that users can register
 #[derive(Clone, Default)]
 struct Plugins {
     pub on_begin_pass: Vec<NamedContextCallback>,
     pub on_end_pass: Vec<NamedContextCallback>,
+    pub on_widget_interaction: Vec<NamedWidgetInteractionCallback>,
 }
 
 impl Plugins {
@@ -262,6 +285,9 @@ pub struct ViewportState {
     /// State related to repaint scheduling.
     repaint: ViewportRepaintInfo,
 
+    /// What was painted last frame, see [`crate::Options::track_dirty_region`].
+    dirty_region: DirtyRegionTracker,
+
     // ----------------------
     // Updated at the start of the pass:
     //
@@ -337,6 +363,48 @@ impl RepaintCause {
     }
 }
 
+/// Two widgets that used the same [`Id`] in the same pass.
+///
+/// See [`Context::check_for_id_clash`] and [`Context::id_clashes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdClash {
+    /// The [`Id`] that was used twice.
+    pub id: Id,
+
+    /// What kind of thing used the [`Id`] the second time, e.g. `"widget"` or `"Grid"`.
+    pub what: String,
+
+    /// Where the first user of the [`Id`] is.
+    pub first_rect: Rect,
+
+    /// Where the second user of the [`Id`] is.
+    pub second_rect: Rect,
+
+    /// The code that first used the [`Id`].
+    pub first_location: &'static Location<'static>,
+
+    /// The code that used the [`Id`] the second time.
+    pub second_location: &'static Location<'static>,
+}
+
+impl std::fmt::Display for IdClash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            id,
+            what,
+            first_rect: _,
+            second_rect: _,
+            first_location,
+            second_location,
+        } = self;
+        write!(
+            f,
+            "{what} ID {} used at {first_location} and at {second_location}",
+            id.short_debug_format()
+        )
+    }
+}
+
 /// Per-viewport state related to repaint scheduling.
 struct ViewportRepaintInfo {
     /// Monotonically increasing counter.
@@ -374,6 +442,11 @@ struct ViewportRepaintInfo {
     /// If this was zero, we are repainting as quickly as possible
     /// (as far as we know).
     prev_pass_paint_delay: Duration,
+
+    /// The [`crate::InputState::time`] of the last pass with any input events.
+    ///
+    /// Used by [`crate::PowerPolicy::LowPower`].
+    last_input_time: Option<f64>,
 }
 
 impl Default for ViewportRepaintInfo {
@@ -392,6 +465,8 @@ impl Default for ViewportRepaintInfo {
             prev_causes: Default::default(),
 
             prev_pass_paint_delay: Duration::MAX,
+
+            last_input_time: None,
         }
     }
 }
@@ -441,6 +516,7 @@ struct ContextImpl {
     paint_stats: PaintStats,
 
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
+    idle_callback: Option<Box<dyn Fn(ViewportId) + Send + Sync>>,
 
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
@@ -451,10 +527,29 @@ struct ContextImpl {
     is_accesskit_enabled: bool,
 
     loaders: Arc<Loaders>,
+
+    /// See [`Context::start_recording_input`].
+    input_recorder: Option<crate::InputRecorder>,
+
+    /// See [`Context::set_profiling`].
+    profiler: crate::profiler::FrameProfiler,
 }
 
 impl ContextImpl {
     fn begin_pass(&mut self, mut new_raw_input: RawInput) {
+        if let Some(recorder) = &mut self.input_recorder {
+            // Later passes of the same frame are not recorded, as replaying the frame runs them again:
+            let is_first_pass = self
+                .viewports
+                .get(&new_raw_input.viewport_id)
+                .is_none_or(|viewport| viewport.output.num_completed_passes == 0);
+            if is_first_pass {
+                recorder.record(&new_raw_input);
+            }
+        }
+
+        translate_gamepad_back(&mut new_raw_input.events);
+
         let viewport_id = new_raw_input.viewport_id;
         let parent_id = new_raw_input
             .viewports
@@ -464,6 +559,15 @@ impl ContextImpl {
         let ids = ViewportIdPair::from_self_and_parent(viewport_id, parent_id);
 
         let is_outermost_viewport = self.viewport_stack.is_empty(); // not necessarily root, just outermost immediate viewport
+
+        let viewport = self.viewports.get(&viewport_id);
+        if is_outermost_viewport && viewport.is_none_or(|v| v.output.num_completed_passes == 0) {
+            let frame_nr = viewport.map_or(0, |v| v.repaint.cumulative_frame_nr);
+            self.profiler.begin_frame(frame_nr);
+        }
+        self.profiler.begin_scope("pass");
+        self.profiler.begin_scope("input");
+
         self.viewport_stack.push(ids);
 
         self.begin_pass_repaint_logic(viewport_id);
@@ -503,8 +607,15 @@ impl ContextImpl {
             pixels_per_point,
             self.memory.options.input_options,
         );
+        viewport
+            .input
+            .recognize_gestures(&self.memory.options.style().interaction);
         let repaint_after = viewport.input.wants_repaint_after();
 
+        if !viewport.input.raw.events.is_empty() || viewport.repaint.last_input_time.is_none() {
+            viewport.repaint.last_input_time = Some(viewport.input.time);
+        }
+
         let screen_rect = viewport.input.screen_rect;
 
         viewport.this_pass.begin_pass(screen_rect);
@@ -566,6 +677,8 @@ impl ContextImpl {
 
         self.update_fonts_mut();
 
+        self.profiler.end_scope(); // input
+
         if let Some(delay) = repaint_after {
             self.request_repaint_after(delay, viewport_id, RepaintCause::new());
         }
@@ -633,6 +746,7 @@ impl ContextImpl {
 
         {
             profiling::scope!("Fonts::begin_pass");
+            fonts.set_galley_cache_budget(self.memory.options.galley_cache_budget);
             fonts.begin_pass(pixels_per_point, max_texture_side, text_alpha_from_coverage);
         }
 
@@ -778,6 +892,7 @@ impl Default for Context {
         crate::debug_text::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        crate::Dialogs::register(&ctx);
 
         ctx
     }
@@ -823,7 +938,10 @@ impl Context {
         let viewport_id = new_input.viewport_id;
         let max_passes = self.write(|ctx| ctx.memory.options.max_passes.get());
 
-        let mut output = FullOutput::default();
+        let mut output = FullOutput {
+            dirty_rect: Some(Rect::NOTHING), // Each pass adds what it changed
+            ..Default::default()
+        };
         debug_assert_eq!(
             output.platform_output.num_completed_passes, 0,
             "output must be fresh, but had {} passes",
@@ -921,6 +1039,82 @@ impl Context {
     pub fn begin_frame(&self, new_input: RawInput) {
         self.begin_pass(new_input);
     }
+
+    /// Start recording the [`RawInput`] of each frame, to replay it later.
+    ///
+    /// Any recording in progress is discarded. See [`crate::InputRecorder`].
+    pub fn start_recording_input(&self) {
+        self.write(|ctx| ctx.input_recorder = Some(Default::default()));
+    }
+
+    /// Stop recording the input, and return the recording.
+    ///
+    /// `None` if [`Self::start_recording_input`] wasn't called.
+    pub fn stop_recording_input(&self) -> Option<crate::InputRecorder> {
+        self.write(|ctx| ctx.input_recorder.take())
+    }
+
+    /// Is the input being recorded? See [`Self::start_recording_input`].
+    pub fn is_recording_input(&self) -> bool {
+        self.read(|ctx| ctx.input_recorder.is_some())
+    }
+
+    /// Turn the built-in frame profiler on or off.
+    ///
+    /// When on, egui times the phases of each frame, starting with the next one.
+    /// See [`crate::profiler`], [`Self::frame_profiles`] and [`Self::profile`].
+    ///
+    /// Does nothing without the `frame_profiler` feature.
+    pub fn set_profiling(&self, enabled: bool) {
+        self.write(|ctx| ctx.profiler.set_enabled(enabled));
+    }
+
+    /// Is the frame profiler on? See [`Self::set_profiling`].
+    pub fn is_profiling(&self) -> bool {
+        self.read(|ctx| ctx.profiler.is_enabled())
+    }
+
+    /// Time a part of the frame for the frame profiler, until the returned guard is dropped.
+    ///
+    /// Does nothing if the profiler is off. Scopes can be nested.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let textures_delta = egui::TexturesDelta::default();
+    /// {
+    ///     let _scope = ctx.profile_scope("texture upload");
+    ///     // upload `textures_delta`…
+    /// }
+    /// ```
+    pub fn profile_scope(&self, name: impl Into<String>) -> crate::profiler::ProfileScopeGuard {
+        let is_recording = self.write(|ctx| {
+            ctx.profiler.begin_scope(name);
+            ctx.profiler.is_recording()
+        });
+        crate::profiler::ProfileScopeGuard::new(is_recording.then(|| self.clone()))
+    }
+
+    pub(crate) fn end_profile_scope(&self) {
+        self.write(|ctx| ctx.profiler.end_scope());
+    }
+
+    /// The timings of the last frames, oldest first, if the profiler is on.
+    ///
+    /// A frame shows up here once the next one starts,
+    /// so that scopes after [`Self::end_pass`], like tessellation, are included.
+    pub fn frame_profiles(&self) -> Vec<Arc<crate::profiler::FrameProfile>> {
+        self.read(|ctx| ctx.profiler.frames())
+    }
+
+    /// Turn on the frame profiler, and show its timings in a window.
+    ///
+    /// Call this every frame you want to see the window. See also [`Self::profiler_ui`].
+    pub fn profile(&self) {
+        self.set_profiling(true);
+        crate::Window::new("🔥 Profiler")
+            .default_width(500.0)
+            .show(self, |ui| self.profiler_ui(ui));
+    }
 }
 
 /// ## Borrows parts of [`Context`]
@@ -1095,14 +1289,30 @@ impl Context {
     /// The given [`Rect`] should be approximately where the widget will be.
     /// The most important thing is that [`Rect::min`] is approximately correct,
     /// because that's where the warning will be painted. If you don't know what size to pick, just pick [`Vec2::ZERO`].
+    ///
+    /// The error shows the source location of both users of the [`Id`].
+    /// This is the innermost caller that is not marked with `#[track_caller]`,
+    /// which for the built-in widgets is the code calling e.g. [`Ui::add`] or [`Ui::button`].
+    ///
+    /// All clashes are recorded, even when [`crate::Options::warn_on_id_clash`] is off.
+    /// Read them with [`Self::id_clashes`].
+    #[track_caller]
     pub fn check_for_id_clash(&self, id: Id, new_rect: Rect, what: &str) {
-        let prev_rect = self.pass_state_mut(move |state| state.used_ids.insert(id, new_rect));
+        use crate::pass_state::UsedId;
 
-        if !self.options(|opt| opt.warn_on_id_clash) {
-            return;
-        }
+        let location = Location::caller();
+        let prev = self.pass_state_mut(move |state| {
+            state.used_ids.insert(
+                id,
+                UsedId {
+                    rect: new_rect,
+                    location,
+                },
+            )
+        });
 
-        let Some(prev_rect) = prev_rect else { return };
+        let Some(prev) = prev else { return };
+        let prev_rect = prev.rect;
 
         // It is ok to reuse the same ID for e.g. a frame around a widget,
         // or to check for interaction with the same widget twice:
@@ -1112,6 +1322,20 @@ impl Context {
             return;
         }
 
+        let clash = IdClash {
+            id,
+            what: what.to_owned(),
+            first_rect: prev_rect,
+            second_rect: new_rect,
+            first_location: prev.location,
+            second_location: location,
+        };
+        self.pass_state_mut(|state| state.id_clashes.push(clash));
+
+        if !self.options(|opt| opt.warn_on_id_clash) {
+            return;
+        }
+
         let show_error = |widget_rect: Rect, text: String| {
             let screen_rect = self.screen_rect();
 
@@ -1149,10 +1373,12 @@ impl Context {
                     painter.error(
                         tooltip_pos,
                         format!("Widget is {} this text.\n\n\
+                             First used at {}\n\
+                             and then at {location}\n\n\
                              ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
                              or when things like Plot and Grid:s aren't given unique id_salt:s.\n\n\
                              Sometimes the solution is to use ui.push_id.",
-                                if below { "above" } else { "below" }),
+                                if below { "above" } else { "below" }, prev.location),
                     );
                 }
             }
@@ -1161,13 +1387,44 @@ impl Context {
         let id_str = id.short_debug_format();
 
         if prev_rect.min.distance(new_rect.min) < 4.0 {
+            // Mark both, but only show one text, since they would overlap:
+            self.debug_painter().rect_stroke(
+                prev_rect,
+                0.0,
+                (1.0, self.style().visuals.error_fg_color),
+                StrokeKind::Outside,
+            );
             show_error(new_rect, format!("Double use of {what} ID {id_str}"));
         } else {
-            show_error(prev_rect, format!("First use of {what} ID {id_str}"));
-            show_error(new_rect, format!("Second use of {what} ID {id_str}"));
+            show_error(
+                prev_rect,
+                format!("First use of {what} ID {id_str} ({})", prev.location),
+            );
+            show_error(
+                new_rect,
+                format!("Second use of {what} ID {id_str} ({location})"),
+            );
         }
     }
 
+    /// The [`Id`] clashes found in the last completed pass.
+    ///
+    /// Use this in tests to make sure no two widgets share an [`Id`]:
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let _ = ctx.run(Default::default(), |ctx| {
+    /// #     egui::CentralPanel::default().show(ctx, |ui| {
+    /// #         ui.label("Hello");
+    /// #     });
+    /// # });
+    /// let clashes = ctx.id_clashes();
+    /// assert!(clashes.is_empty(), "Found Id clashes: {clashes:#?}");
+    /// ```
+    pub fn id_clashes(&self) -> Vec<IdClash> {
+        self.prev_pass_state(|state| state.id_clashes.clone())
+    }
+
     // ---------------------------------------------------------------------
 
     /// Create a widget and check for interaction.
@@ -1180,6 +1437,7 @@ impl Context {
     ///
     /// `allow_focus` should usually be true, unless you call this function multiple times with the
     /// same widget, then `allow_focus` should only be true once (like in [`Ui::new`] (true) and [`Ui::remember_min_rect`] (false)).
+    #[track_caller]
     pub(crate) fn create_widget(&self, w: WidgetRect, allow_focus: bool) -> Response {
         let interested_in_focus = w.enabled
             && w.sense.is_focusable()
@@ -1196,6 +1454,16 @@ impl Context {
 
             if allow_focus && interested_in_focus {
                 ctx.memory.interested_in_focus(w.id, w.layer_id);
+
+                if ctx.memory.take_focus_reveal(w.id) {
+                    // The user moved focus here with the keyboard, so make sure they can see it:
+                    use crate::pass_state::ScrollTarget;
+                    let animation = ctx.memory.options.style().scroll_animation;
+                    ctx.viewport().this_pass.scroll_target = [
+                        Some(ScrollTarget::new(w.rect.x_range(), None, animation)),
+                        Some(ScrollTarget::new(w.rect.y_range(), None, animation)),
+                    ];
+                }
             }
         });
 
@@ -1342,7 +1610,7 @@ impl Context {
             if enabled
                 && sense.senses_click()
                 && memory.has_focus(id)
-                && (input.key_pressed(Key::Space) || input.key_pressed(Key::Enter))
+                && input.events.iter().any(is_activation_event)
             {
                 // Space/enter works like a primary click for e.g. selected buttons
                 res.flags.set(Flags::FAKE_PRIMARY_CLICKED, true);
@@ -1413,7 +1681,8 @@ impl Context {
             let is_interacted_with = res.is_pointer_button_down_on()
                 || res.long_touched()
                 || clicked
-                || res.drag_stopped();
+                || res.drag_stopped()
+                || interaction.pointer_capture == Some(id);
             if is_interacted_with {
                 res.interact_pointer_pos = input.pointer.interact_pos();
                 if let (Some(to_global), Some(pos)) = (
@@ -1440,20 +1709,21 @@ impl Context {
 
     /// This is called by [`Response::widget_info`], but can also be called directly.
     ///
-    /// With some debug flags it will store the widget info in [`crate::WidgetRects`] for later display.
+    /// With some debug flags, or [`crate::Options::record_widget_info`],
+    /// it will store the widget info in [`crate::WidgetRects`] for later display.
     #[inline]
     pub fn register_widget_info(&self, id: Id, make_info: impl Fn() -> crate::WidgetInfo) {
-        #[cfg(debug_assertions)]
         self.write(|ctx| {
-            if ctx.memory.options.style().debug.show_interactive_widgets {
+            let options = &ctx.memory.options;
+            #[cfg(debug_assertions)]
+            let record =
+                options.record_widget_info || options.style().debug.show_interactive_widgets;
+            #[cfg(not(debug_assertions))]
+            let record = options.record_widget_info;
+            if record {
                 ctx.viewport().this_pass.widgets.set_info(id, make_info());
             }
         });
-
-        #[cfg(not(debug_assertions))]
-        {
-            _ = (self, id, make_info);
-        }
     }
 
     /// Get a full-screen painter for a new or existing layer
@@ -1513,6 +1783,115 @@ impl Context {
     /// ```
     pub fn set_cursor_icon(&self, cursor_icon: CursorIcon) {
         self.output_mut(|o| o.cursor_icon = cursor_icon);
+        self.pass_state_mut(|fs| fs.custom_cursor = None);
+    }
+
+    /// Set the cursor, either to a [`CursorIcon`] or to a custom cursor from [`Self::register_cursor`].
+    ///
+    /// The last call in a pass wins.
+    /// See also [`Response::on_hover_cursor`], which only lets widgets on top change the cursor.
+    pub fn set_cursor(&self, cursor: impl Into<Cursor>) {
+        match cursor.into() {
+            Cursor::Icon(icon) => self.set_cursor_icon(icon),
+            Cursor::Custom(id) => self.pass_state_mut(|fs| fs.custom_cursor = Some(id)),
+        }
+    }
+
+    /// Set the cursor on behalf of a widget on the given layer,
+    /// unless a widget on a layer above it has already set it this pass.
+    pub(crate) fn set_widget_cursor(&self, layer_id: LayerId, cursor: Cursor) {
+        let is_below_current = self
+            .pass_state(|fs| fs.cursor_layer)
+            .is_some_and(|current| {
+                self.memory(|mem| mem.areas().compare_order(layer_id, current))
+                    .is_lt()
+            });
+        if !is_below_current {
+            self.pass_state_mut(|fs| fs.cursor_layer = Some(layer_id));
+            self.set_cursor(cursor);
+        }
+    }
+
+    /// Register an RGBA image to use as a mouse cursor, e.g. with [`Response::on_hover_cursor`].
+    ///
+    /// `hotspot` is the pixel of the image that is the tip of the cursor.
+    ///
+    /// The image is shown at its pixel size, regardless of [`Self::pixels_per_point`].
+    /// egui paints the cursor itself (and hides the platform cursor) while it is active,
+    /// so it works with every integration, but can lag behind the pointer by a frame.
+    ///
+    /// It is cheap to call this every frame with the same image:
+    /// the image is only uploaded again when it changes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let image = egui::ColorImage::filled([16, 16], egui::Color32::RED);
+    /// let cursor = ui.ctx().register_cursor("red square", image, [8, 8]);
+    /// ui.button("Hover me").on_hover_cursor(cursor);
+    /// # });
+    /// ```
+    pub fn register_cursor(
+        &self,
+        id_salt: impl std::hash::Hash,
+        image: impl Into<Arc<ColorImage>>,
+        hotspot: [usize; 2],
+    ) -> CustomCursorId {
+        let id = Id::new(id_salt).with("egui::custom_cursor");
+        let image = image.into();
+        let hotspot = vec2(hotspot[0] as f32, hotspot[1] as f32);
+
+        let existing = self.data(|d| d.get_temp::<RegisteredCursor>(id));
+        let unchanged = existing.is_some_and(|existing| {
+            existing.hotspot == hotspot
+                && (Arc::ptr_eq(&existing.image, &image) || existing.image == image)
+        });
+        if !unchanged {
+            let texture = self.load_texture(
+                format!("cursor {id:?}"),
+                image.clone(),
+                TextureOptions::NEAREST,
+            );
+            self.data_mut(|d| {
+                d.insert_temp(
+                    id,
+                    RegisteredCursor {
+                        image,
+                        hotspot,
+                        texture,
+                    },
+                );
+            });
+        }
+        CustomCursorId(id)
+    }
+
+    /// Paint the custom cursor set this pass, if any, in place of the platform cursor.
+    fn paint_custom_cursor(&self) {
+        let Some(CustomCursorId(id)) = self.pass_state(|fs| fs.custom_cursor) else {
+            return;
+        };
+        let Some(cursor) = self.data(|d| d.get_temp::<RegisteredCursor>(id)) else {
+            return;
+        };
+        let Some(pointer) = self.input(|i| i.pointer.latest_pos()) else {
+            return;
+        };
+
+        self.output_mut(|o| o.cursor_icon = CursorIcon::None);
+
+        let points_per_pixel = 1.0 / self.pixels_per_point();
+        let [width, height] = cursor.image.size;
+        let rect = Rect::from_min_size(
+            pointer - points_per_pixel * cursor.hotspot,
+            points_per_pixel * vec2(width as f32, height as f32),
+        );
+        self.layer_painter(LayerId::new(Order::Debug, Id::new("egui::custom_cursor")))
+            .image(
+                cursor.texture.id(),
+                rect,
+                Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+                Color32::WHITE,
+            );
     }
 
     /// Add a command to [`PlatformOutput::commands`],
@@ -1535,13 +1914,40 @@ impl Context {
 
     /// Copy the given text to the system clipboard.
     ///
+    /// The text is also added to the [`crate::ClipboardHistory`].
+    ///
     /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
     /// HTTPS or localhost). If this method is used outside of a secure context, it will log an
     /// error and do nothing. See <https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts>.
     pub fn copy_text(&self, text: String) {
+        crate::ClipboardHistory::add(self, &text);
         self.send_cmd(crate::OutputCommand::CopyText(text));
     }
 
+    /// Tell screen-reader users about something that happened, e.g. "Upload complete".
+    ///
+    /// Use this for events that are not tied to a widget the user is interacting with,
+    /// such as the result of a background task.
+    /// [`Priority::Assertive`](crate::Priority::Assertive) interrupts whatever the screen reader is saying.
+    ///
+    /// With the `accesskit` feature, the latest announcement of each priority is kept in a live region
+    /// of the AccessKit tree. It is also added to [`crate::PlatformOutput::announcements`],
+    /// which `eframe` speaks on the web with the `web_screen_reader` feature.
+    pub fn announce(&self, text: impl Into<String>, priority: crate::Priority) {
+        let text = text.into();
+        self.write(|ctx| {
+            let viewport_id = ctx.viewport_id();
+            ctx.memory
+                .data
+                .get_temp_mut_or_default::<LiveRegions>(LiveRegions::id(viewport_id))
+                .set(priority, text.clone());
+            ctx.viewport()
+                .output
+                .announcements
+                .push(crate::Announcement { text, priority });
+        });
+    }
+
     /// Copy the given image to the system clipboard.
     ///
     /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
@@ -1551,6 +1957,37 @@ impl Context {
         self.send_cmd(crate::OutputCommand::CopyImage(image));
     }
 
+    /// Copy the given content to the system clipboard, e.g. html or a list of files.
+    ///
+    /// Not all integrations support all kinds of content.
+    /// Unsupported content is copied as text, see [`crate::ClipboardContent::to_text`].
+    ///
+    /// See also [`crate::InputState::pasted_content`].
+    pub fn copy_content(&self, content: crate::ClipboardContent) {
+        match content {
+            crate::ClipboardContent::Text(text) => self.copy_text(text),
+            crate::ClipboardContent::Html { html, alt_text } => {
+                crate::ClipboardHistory::add(self, &alt_text);
+                self.send_cmd(crate::OutputCommand::CopyHtml { html, alt_text });
+            }
+            crate::ClipboardContent::Image(image) => self.copy_image(image),
+            crate::ClipboardContent::Files(paths) => {
+                self.send_cmd(crate::OutputCommand::CopyFiles(paths));
+            }
+        }
+    }
+
+    /// Tell the other app what will happen if the files or data it is dragging over egui are dropped,
+    /// which it usually shows with its cursor.
+    ///
+    /// Call this each pass while [`crate::Response::dnd_hover_os_files`] is non-empty,
+    /// e.g. with [`crate::DropEffect::None`] when the pointer is over a widget that doesn't accept them.
+    ///
+    /// Supported by `eframe` on the web.
+    pub fn set_drop_effect(&self, drop_effect: crate::DropEffect) {
+        self.output_mut(|o| o.drop_effect = Some(drop_effect));
+    }
+
     fn can_show_modifier_symbols(&self) -> bool {
         let ModifierNames {
             alt,
@@ -1790,6 +2227,15 @@ impl Context {
         self.read(|ctx| ctx.has_requested_repaint(viewport_id))
     }
 
+    /// How many times has a repaint been requested for the current viewport this pass?
+    pub(crate) fn num_repaint_requests(&self) -> usize {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .map_or(0, |v| v.repaint.causes.len())
+        })
+    }
+
     /// Why are we repainting?
     ///
     /// This can be helpful in debugging why egui is constantly repainting.
@@ -1815,6 +2261,55 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_callback = Some(callback));
     }
 
+    /// For integrations: this callback will be called at the end of a pass
+    /// if the viewport doesn't need to repaint until there is new input.
+    ///
+    /// This lets you park your render loop, lower the GPU clocks, etc,
+    /// until the next input event or call to [`Self::request_repaint`]
+    /// (which calls the callback set by [`Self::set_request_repaint_callback`]).
+    ///
+    /// See also [`crate::PowerPolicy`].
+    ///
+    /// Note that only one callback can be set. Any new call overrides the previous callback.
+    pub fn set_idle_callback(&self, callback: impl Fn(ViewportId) + Send + Sync + 'static) {
+        let callback = Box::new(callback);
+        self.write(|ctx| ctx.idle_callback = Some(callback));
+    }
+
+    /// Request a repaint that is only needed for cosmetic reasons, like a blinking text cursor.
+    ///
+    /// This is the same as [`Self::request_repaint_after_secs`],
+    /// except it is ignored when [`Self::is_low_power_idle`],
+    /// so that the app can stop repainting completely.
+    /// The next input event will wake it up again.
+    #[track_caller]
+    pub fn request_low_priority_repaint_after_secs(&self, seconds: f32) {
+        if !self.is_low_power_idle() {
+            self.request_repaint_after_secs(seconds);
+        }
+    }
+
+    /// Has the user been idle for long enough that low-priority repaints are ignored?
+    ///
+    /// This depends on [`crate::Options::power_policy`], and is always `false` for [`crate::PowerPolicy::Normal`].
+    ///
+    /// Blinking and other purely cosmetic animations should stop when this is `true`.
+    pub fn is_low_power_idle(&self) -> bool {
+        self.read(|ctx| {
+            let crate::PowerPolicy::LowPower { idle_after } = ctx.memory.options.power_policy
+            else {
+                return false;
+            };
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .is_some_and(|viewport| {
+                    let time = viewport.input.time;
+                    let last_input_time = viewport.repaint.last_input_time.unwrap_or(time);
+                    f64::from(idle_after) <= time - last_input_time
+                })
+        })
+    }
+
     /// Request to discard the visual output of this pass,
     /// and to immediately do another one.
     ///
@@ -1893,6 +2388,48 @@ impl Context {
         };
         self.write(|ctx| ctx.plugins.on_end_pass.push(named_cb));
     }
+
+    /// Call the given callback whenever the user interacts with a widget,
+    /// e.g. clicks a button or changes a value.
+    ///
+    /// This is opt-in usage telemetry: it lets you instrument which features are used
+    /// without changing every call site.
+    /// It covers the same interactions as [`crate::PlatformOutput::events`],
+    /// so it works for all widgets that call [`Response::widget_info`].
+    ///
+    /// The callback is called while the widget is being added,
+    /// so it should be quick, e.g. just send the interaction on to somewhere else.
+    pub fn on_widget_interaction(&self, debug_name: &'static str, cb: WidgetInteractionCallback) {
+        let named_cb = NamedWidgetInteractionCallback {
+            debug_name,
+            callback: cb,
+        };
+        self.write(|ctx| ctx.plugins.on_widget_interaction.push(named_cb));
+    }
+
+    /// Report an interaction to the callbacks registered with [`Self::on_widget_interaction`].
+    pub(crate) fn report_widget_interaction(&self, id: Id, event: &OutputEvent) {
+        let callbacks = self.read(|ctx| ctx.plugins.on_widget_interaction.clone());
+        if callbacks.is_empty() {
+            return;
+        }
+
+        profiling::function_scope!();
+        let interaction = WidgetInteraction {
+            id,
+            event: event.clone(),
+            time: self.input(|i| i.time),
+            frame_nr: self.cumulative_frame_nr(),
+        };
+        for NamedWidgetInteractionCallback {
+            debug_name: _name,
+            callback,
+        } in &callbacks
+        {
+            profiling::scope!("plugin", _name);
+            (callback)(&interaction);
+        }
+    }
 }
 
 impl Context {
@@ -2207,7 +2744,7 @@ impl Context {
         let max_texture_side = self.input(|i| i.max_texture_side);
         debug_assert!(
             image.width() <= max_texture_side && image.height() <= max_texture_side,
-            "Texture {:?} has size {}x{}, but the maximum texture side is {}",
+            "Texture {:?} has size {}x{}, but the maximum texture side is {}. Use `load_tiled_texture` instead.",
             name,
             image.width(),
             image.height(),
@@ -2218,6 +2755,31 @@ impl Context {
         TextureHandle::new(tex_mngr, tex_id)
     }
 
+    /// Like [`Self::load_texture`], but for images of any size.
+    ///
+    /// Images larger than the maximum texture side of the backend
+    /// are split into several textures.
+    ///
+    /// ⚠️ Make sure to only call this ONCE for each image, i.e. NOT in your main GUI code.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let huge_image = egui::ColorImage::example();
+    /// let texture = ui.ctx().load_tiled_texture("map", &huge_image, Default::default());
+    /// let (rect, _response) = ui.allocate_exact_size(egui::vec2(400.0, 300.0), egui::Sense::hover());
+    /// ui.painter().add(texture.to_shape(rect, egui::Color32::WHITE));
+    /// # });
+    /// ```
+    pub fn load_tiled_texture(
+        &self,
+        name: &str,
+        image: &ColorImage,
+        options: TextureOptions,
+    ) -> TiledTexture {
+        let max_texture_side = self.input(|i| i.max_texture_side);
+        TiledTexture::new(&self.tex_manager(), name, image, options, max_texture_side)
+    }
+
     /// Low-level texture manager.
     ///
     /// In general it is easier to use [`Self::load_texture`] and [`TextureHandle`].
@@ -2252,13 +2814,19 @@ impl Context {
     pub fn end_pass(&self) -> FullOutput {
         profiling::function_scope!();
 
+        self.write(|ctx| ctx.profiler.begin_scope("end_pass"));
+
         if self.options(|o| o.zoom_with_keyboard) {
             crate::gui_zoom::zoom_with_keyboard(self);
         }
+        crate::containers::window::cycle_windows_with_keyboard(self);
 
         // Plugins run just before the pass ends.
         self.read(|ctx| ctx.plugins.clone()).on_end_pass(self);
 
+        self.paint_custom_cursor();
+        self.paint_gamepad_focus();
+
         #[cfg(debug_assertions)]
         self.debug_painting();
 
@@ -2272,27 +2840,160 @@ impl Context {
         self.end_pass()
     }
 
-    /// Called at the end of the pass.
-    #[cfg(debug_assertions)]
-    fn debug_painting(&self) {
-        let paint_widget = |widget: &WidgetRect, text: &str, color: Color32| {
-            let rect = widget.interact_rect;
-            if rect.is_positive() {
-                let painter = Painter::new(self.clone(), widget.layer_id, Rect::EVERYTHING);
-                painter.debug_rect(rect, color, text);
-            }
+    /// A ring around the focused widget while navigating with a gamepad, see [`crate::Event::GamepadNav`].
+    fn paint_gamepad_focus(&self) {
+        let focused = self.memory(|mem| {
+            mem.is_navigating_with_gamepad()
+                .then(|| mem.focused())
+                .flatten()
+        });
+        let Some(widget) =
+            focused.and_then(|id| self.viewport(|v| v.this_pass.widgets.get(id).copied()))
+        else {
+            return;
         };
+        let style = self.style();
+        let stroke = style.visuals.gamepad_focus_stroke;
+        let rect = widget.interact_rect.expand(stroke.width);
+        if rect.is_positive() {
+            let painter = Painter::new(self.clone(), widget.layer_id, Rect::EVERYTHING);
+            painter.rect_stroke(
+                rect,
+                style.visuals.widgets.active.corner_radius,
+                stroke,
+                epaint::StrokeKind::Outside,
+            );
+        }
+    }
 
-        let paint_widget_id = |id: Id, text: &str, color: Color32| {
-            if let Some(widget) =
-                self.write(|ctx| ctx.viewport().this_pass.widgets.get(id).copied())
-            {
-                paint_widget(&widget, text, color);
+    /// See [`crate::style::DebugOptions::show_layer_stats`].
+    #[cfg(debug_assertions)]
+    fn paint_layer_stats(&self) {
+        let widgets = self.write(|ctx| ctx.viewport().this_pass.widgets.clone());
+        let layers: Vec<(LayerId, usize, Rect)> = self.graphics(|graphics| {
+            graphics
+                .layers()
+                .map(|(layer_id, list)| {
+                    let mut num_shapes = 0;
+                    let mut bounds = Rect::NOTHING;
+                    for ClippedShape { clip_rect, shape } in list.all_entries() {
+                        for_each_leaf_shape(shape, &mut |shape| {
+                            num_shapes += 1;
+                            bounds |= shape.visual_bounding_rect().intersect(*clip_rect);
+                        });
+                    }
+                    (layer_id, num_shapes, bounds)
+                })
+                .collect()
+        });
+
+        for (layer_id, num_shapes, bounds) in layers {
+            if layer_id == LayerId::debug() || num_shapes == 0 {
+                continue;
             }
-        };
+            let num_widgets = widgets.get_layer(layer_id).count();
+            let pos = if bounds.is_positive() {
+                bounds.left_top()
+            } else {
+                Pos2::ZERO
+            };
+            Painter::new(self.clone(), layer_id, Rect::EVERYTHING).debug_text(
+                pos,
+                Align2::LEFT_TOP,
+                Color32::LIGHT_BLUE,
+                format!(
+                    "{:?} {:?}: {num_widgets} widgets, {num_shapes} shapes",
+                    layer_id.order, layer_id.id
+                ),
+            );
+        }
+    }
 
-        if self.style().debug.show_interactive_widgets {
-            // Show all interactive widgets:
+    /// See [`crate::style::DebugOptions::show_overdraw`].
+    #[cfg(debug_assertions)]
+    fn paint_overdraw(&self) {
+        const CELL_SIZE: f32 = 8.0;
+
+        let screen_rect = self.screen_rect();
+        let num_columns = (screen_rect.width() / CELL_SIZE).ceil() as usize;
+        let num_rows = (screen_rect.height() / CELL_SIZE).ceil() as usize;
+        let mut counts = vec![0_u32; num_columns * num_rows];
+
+        let to_global = self.memory(|m| m.to_global.clone());
+        let rects: Vec<Rect> = self.graphics(|graphics| {
+            let mut rects = vec![];
+            for (layer_id, list) in graphics.layers() {
+                if layer_id == LayerId::debug() {
+                    continue;
+                }
+                let transform = to_global.get(&layer_id);
+                for ClippedShape { clip_rect, shape } in list.all_entries() {
+                    for_each_leaf_shape(shape, &mut |shape| {
+                        let rect = shape.visual_bounding_rect().intersect(*clip_rect);
+                        if rect.is_positive() {
+                            rects.push(transform.map_or(rect, |t| t.mul_rect(rect)));
+                        }
+                    });
+                }
+            }
+            rects
+        });
+
+        for rect in rects {
+            let rect = rect.intersect(screen_rect);
+            if !rect.is_positive() {
+                continue;
+            }
+            let min = ((rect.min - screen_rect.min) / CELL_SIZE).floor();
+            let max = ((rect.max - screen_rect.min) / CELL_SIZE).ceil();
+            for row in min.y as usize..(max.y as usize).min(num_rows) {
+                for column in min.x as usize..(max.x as usize).min(num_columns) {
+                    counts[row * num_columns + column] += 1;
+                }
+            }
+        }
+
+        let painter = self.debug_painter();
+        for (i, &count) in counts.iter().enumerate() {
+            if count < 2 {
+                continue;
+            }
+            // Green for a few layers of paint, red for many:
+            let t = ((count - 2) as f32 / 8.0).min(1.0);
+            let color = Color32::GREEN
+                .lerp_to_gamma(Color32::RED, t)
+                .gamma_multiply(0.4);
+            let min = screen_rect.min
+                + CELL_SIZE * emath::vec2((i % num_columns) as f32, (i / num_columns) as f32);
+            painter.rect_filled(
+                Rect::from_min_size(min, emath::Vec2::splat(CELL_SIZE)),
+                0.0,
+                color,
+            );
+        }
+    }
+
+    /// Called at the end of the pass.
+    #[cfg(debug_assertions)]
+    fn debug_painting(&self) {
+        let paint_widget = |widget: &WidgetRect, text: &str, color: Color32| {
+            let rect = widget.interact_rect;
+            if rect.is_positive() {
+                let painter = Painter::new(self.clone(), widget.layer_id, Rect::EVERYTHING);
+                painter.debug_rect(rect, color, text);
+            }
+        };
+
+        let paint_widget_id = |id: Id, text: &str, color: Color32| {
+            if let Some(widget) =
+                self.write(|ctx| ctx.viewport().this_pass.widgets.get(id).copied())
+            {
+                paint_widget(&widget, text, color);
+            }
+        };
+
+        if self.style().debug.show_interactive_widgets {
+            // Show all interactive widgets:
             let rects = self.write(|ctx| ctx.viewport().this_pass.widgets.clone());
             for (layer_id, rects) in rects.layers() {
                 let painter = Painter::new(self.clone(), *layer_id, Rect::EVERYTHING);
@@ -2325,6 +3026,7 @@ impl Context {
                     drag_stopped: _,
                     contains_pointer,
                     hovered,
+                    routed_pointer_events: _,
                 } = interact_widgets;
 
                 if true {
@@ -2396,6 +3098,58 @@ impl Context {
             }
         }
 
+        let debug = self.style().debug;
+
+        if debug.show_layer_stats {
+            self.paint_layer_stats();
+        }
+
+        if debug.show_overdraw {
+            self.paint_overdraw();
+        }
+
+        if debug.show_repaint_causes {
+            let (prev_widgets, widgets) = self.write(|ctx| {
+                let viewport = ctx.viewport();
+                (
+                    viewport.prev_pass.widgets.clone(),
+                    viewport.this_pass.widgets.clone(),
+                )
+            });
+            for (_, rects) in widgets.layers() {
+                for widget in rects {
+                    match prev_widgets.get(widget.id) {
+                        None => paint_widget(widget, "new", Color32::ORANGE),
+                        Some(prev) if prev != widget => {
+                            paint_widget(widget, "changed", Color32::ORANGE);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            for (_, rects) in prev_widgets.layers() {
+                for widget in rects {
+                    if widgets.get(widget.id).is_none() {
+                        paint_widget(widget, "removed", Color32::DARK_RED);
+                    }
+                }
+            }
+
+            let causes = self.repaint_causes();
+            if !causes.is_empty() {
+                let mut text = "Repaint causes:".to_owned();
+                for cause in causes {
+                    text += &format!("\n  {cause}");
+                }
+                self.debug_painter().debug_text(
+                    self.screen_rect().left_bottom(),
+                    Align2::LEFT_BOTTOM,
+                    Color32::ORANGE,
+                    text,
+                );
+            }
+        }
+
         if let Some(debug_rect) = self.pass_state_mut(|fs| fs.debug_rect.take()) {
             debug_rect.paint(&self.debug_painter());
         }
@@ -2420,12 +3174,85 @@ impl Context {
     }
 }
 
+/// Call `f` for each shape in `shape`, looking inside [`epaint::Shape::Vec`].
+#[cfg(debug_assertions)]
+fn for_each_leaf_shape(shape: &epaint::Shape, f: &mut impl FnMut(&epaint::Shape)) {
+    match shape {
+        epaint::Shape::Noop => {}
+        epaint::Shape::Vec(shapes) => {
+            for shape in shapes {
+                for_each_leaf_shape(shape, f);
+            }
+        }
+        shape => f(shape),
+    }
+}
+
+/// The latest announcement of each [`crate::Priority`], see [`Context::announce`].
+#[derive(Clone, Default)]
+struct LiveRegions {
+    polite: String,
+    assertive: String,
+}
+
+impl LiveRegions {
+    fn id(viewport_id: ViewportId) -> Id {
+        Id::new("__egui_live_regions").with(viewport_id)
+    }
+
+    fn set(&mut self, priority: crate::Priority, text: String) {
+        match priority {
+            crate::Priority::Polite => self.polite = text,
+            crate::Priority::Assertive => self.assertive = text,
+        }
+    }
+
+    /// Add the live regions as children of the root node.
+    #[cfg(feature = "accesskit")]
+    fn add_accesskit_nodes(
+        &self,
+        root_id: accesskit::NodeId,
+        nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>,
+    ) {
+        let regions = [
+            (
+                &self.polite,
+                accesskit::Role::Status,
+                accesskit::Live::Polite,
+            ),
+            (
+                &self.assertive,
+                accesskit::Role::Alert,
+                accesskit::Live::Assertive,
+            ),
+        ];
+        for (text, role, live) in regions {
+            if text.is_empty() {
+                continue;
+            }
+            let id = Self::id(ViewportId::ROOT).with(role as u8).accesskit_id();
+            let mut node = accesskit::Node::new(role);
+            node.set_live(live);
+            node.set_label(text.clone());
+            if let Some((_, root)) = nodes.iter_mut().find(|(node_id, _)| *node_id == root_id) {
+                root.push_child(id);
+            }
+            nodes.push((id, node));
+        }
+    }
+}
+
 impl ContextImpl {
     fn end_pass(&mut self) -> FullOutput {
         let ended_viewport_id = self.viewport_id();
         let viewport = self.viewports.entry(ended_viewport_id).or_default();
         let pixels_per_point = viewport.input.pixels_per_point;
 
+        if let Some(budget) = self.memory.options.image_memory_budget {
+            self.loaders.evict_least_recently_used(budget);
+        } else {
+            self.loaders.forget_all_usage();
+        }
         self.loaders.end_pass(viewport.repaint.cumulative_pass_nr);
 
         viewport.repaint.cumulative_pass_nr += 1;
@@ -2476,6 +3303,13 @@ impl ContextImpl {
                         .map(|(id, node)| (id.accesskit_id(), node))
                         .collect()
                 };
+                let mut nodes: Vec<(accesskit::NodeId, accesskit::Node)> = nodes;
+                let live_regions = self
+                    .memory
+                    .data
+                    .get_temp::<LiveRegions>(LiveRegions::id(ended_viewport_id))
+                    .unwrap_or_default();
+                live_regions.add_accesskit_nodes(root_id, &mut nodes);
                 let focus_id = self
                     .memory
                     .focused()
@@ -2488,11 +3322,32 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
+        let (shapes, layers) = viewport
             .graphics
-            .drain(self.memory.areas().order(), &self.memory.to_global);
+            .drain_with_layers(self.memory.areas().order(), &self.memory.to_global);
+
+        let dirty_rect = if self.memory.options.track_dirty_region {
+            let tessellation_options = &self.memory.options.tessellation_options;
+            let feathering = if tessellation_options.feathering {
+                tessellation_options.feathering_size_in_pixels / pixels_per_point
+            } else {
+                0.0
+            };
+            viewport.dirty_region.update(
+                viewport.input.screen_rect(),
+                pixels_per_point,
+                feathering,
+                !textures_delta.is_empty(),
+                &shapes,
+                &layers,
+            )
+        } else {
+            viewport.dirty_region.clear();
+            None
+        };
 
-        let mut repaint_needed = false;
+        // The focus moves at the start of the next pass:
+        let mut repaint_needed = self.memory.has_pending_focus_change();
 
         if self.memory.options.repaint_on_widget_change {
             profiling::scope!("compare-widget-rects");
@@ -2506,6 +3361,12 @@ impl ContextImpl {
         if repaint_needed {
             self.request_repaint(ended_viewport_id, RepaintCause::new());
         }
+
+        if !self.has_requested_repaint(&ended_viewport_id) {
+            if let Some(callback) = &self.idle_callback {
+                (callback)(ended_viewport_id);
+            }
+        }
         //  -------------------
 
         let all_viewport_ids = self.all_viewport_ids();
@@ -2610,11 +3471,15 @@ impl ContextImpl {
 
         platform_output.num_completed_passes += 1;
 
+        self.profiler.end_scope(); // end_pass
+        self.profiler.end_scope(); // pass
+
         FullOutput {
             platform_output,
             textures_delta,
             shapes,
             pixels_per_point,
+            dirty_rect,
             viewport_output,
         }
     }
@@ -2638,6 +3503,7 @@ impl Context {
         // it takes to tessellate them, so it is not a worth optimization.
 
         self.write(|ctx| {
+            ctx.profiler.begin_scope("tessellate");
             let tessellation_options = ctx.memory.options.tessellation_options;
             let texture_atlas = if let Some(fonts) = ctx.fonts.get(&pixels_per_point.into()) {
                 fonts.texture_atlas()
@@ -2668,6 +3534,7 @@ impl Context {
                 .tessellate_shapes(shapes)
             };
             ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+            ctx.profiler.end_scope();
             clipped_primitives
         })
     }
@@ -2745,6 +3612,20 @@ impl Context {
         self.memory(|m| m.focused().is_some())
     }
 
+    /// Move the keyboard focus to the next widget in a focus scope, as if tab was pressed in it.
+    ///
+    /// See [`crate::Ui::focus_scope`] and [`Memory::focus_next_in`].
+    pub fn focus_next_in(&self, scope: Id) {
+        self.memory_mut(|m| m.focus_next_in(scope));
+    }
+
+    /// Move the keyboard focus to the previous widget in a focus scope, as if shift+tab was pressed in it.
+    ///
+    /// See [`crate::Ui::focus_scope`] and [`Memory::focus_previous_in`].
+    pub fn focus_previous_in(&self, scope: Id) {
+        self.memory_mut(|m| m.focus_previous_in(scope));
+    }
+
     /// Highlight this widget, to make it look like it is hovered, even if it isn't.
     ///
     /// If you call this after the widget has been fully rendered,
@@ -2873,6 +3754,53 @@ impl Context {
         self.memory_mut(|mem| mem.areas_mut().move_to_top(layer_id));
     }
 
+    /// Capture the positions, sizes, collapsed/minimized state and z-order
+    /// of all [`crate::Area`]s and [`crate::Window`]s.
+    ///
+    /// Restore it later with [`Self::restore_window_layout`].
+    pub fn save_window_layout(&self) -> crate::WindowLayout {
+        crate::WindowLayout::save(self)
+    }
+
+    /// Move, resize, collapse and restack windows according to a layout
+    /// captured with [`Self::save_window_layout`].
+    ///
+    /// Takes effect the next time each window is shown.
+    pub fn restore_window_layout(&self, layout: &crate::WindowLayout) {
+        layout.restore(self);
+    }
+
+    /// Encode the parts of [`Memory`] that should survive a restart,
+    /// e.g. to write them to a file or web local storage.
+    ///
+    /// Restore them with [`Self::load_memory`].
+    ///
+    /// # Errors
+    /// Fails if the memory can't be encoded, or doesn't fit in [`crate::MemoryPersistence::max_bytes`].
+    #[cfg(feature = "persistence")]
+    pub fn save_memory(
+        &self,
+        persistence: &crate::MemoryPersistence,
+    ) -> Result<Vec<u8>, crate::PersistenceError> {
+        self.memory(|mem| persistence.save(mem))
+    }
+
+    /// Restore [`Memory`] saved with [`Self::save_memory`], e.g. when the app starts.
+    ///
+    /// On error the memory is left untouched.
+    ///
+    /// # Errors
+    /// Fails if the bytes can't be decoded (e.g. they are from an incompatible version of egui),
+    /// or if they were saved with a version of your app that can't be migrated.
+    #[cfg(feature = "persistence")]
+    pub fn load_memory(
+        &self,
+        persistence: &crate::MemoryPersistence,
+        bytes: &[u8],
+    ) -> Result<(), crate::PersistenceError> {
+        self.memory_mut(|mem| persistence.load(bytes, mem))
+    }
+
     /// Mark the `child` layer as a sublayer of `parent`.
     ///
     /// Sublayers are moved directly above the parent layer at the end of the frame. This is mainly
@@ -2938,6 +3866,8 @@ impl Context {
 }
 
 /// ## Animation
+///
+/// All animations jump straight to their end when [`Style::reduced_motion`] is set.
 impl Context {
     /// Returns a value in the range [0, 1], to indicate "how on" this thing is.
     ///
@@ -2996,6 +3926,11 @@ impl Context {
         animation_time: f32,
         easing: fn(f32) -> f32,
     ) -> f32 {
+        let animation_time = if self.style().reduced_motion {
+            0.0
+        } else {
+            animation_time
+        };
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_bool(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
@@ -3021,24 +3956,143 @@ impl Context {
     ///
     /// At the first call the value is written to memory.
     /// When it is called with a new value, it linearly interpolates to it in the given time.
+    ///
+    /// See [`Self::animate_tween`] for other easing functions and types of values.
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
-        let animated_value = self.write(|ctx| {
-            ctx.animation_manager.animate_value(
+        self.animate_tween(id, target_value, Tween::new(animation_time))
+    }
+
+    /// Smoothly animate a value, e.g. an `f32`, a [`Vec2`], or a [`Color32`].
+    ///
+    /// At the first call the value is written to memory.
+    /// Whenever it is called with a new `target_value`, it animates there from
+    /// wherever the previous animation currently is, using the given [`Tween`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Tween, emath::easing};
+    /// let hovered = ui.rect_contains_pointer(ui.max_rect());
+    /// let target = if hovered { egui::vec2(0.0, -4.0) } else { egui::Vec2::ZERO };
+    /// let offset = ui.ctx().animate_tween(
+    ///     ui.id().with("lift"),
+    ///     target,
+    ///     Tween::new(0.3).with_easing(easing::spring),
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// The function will call [`Self::request_repaint()`] when appropriate,
+    /// and [`Self::on_animation_done`] callbacks once the target is reached.
+    #[track_caller] // To track repaint cause
+    pub fn animate_tween<T: Animatable>(&self, id: Id, target_value: T, tween: Tween) -> T {
+        if self.style().reduced_motion {
+            let tween = Tween {
+                duration: 0.0,
+                delay: 0.0,
+                ..tween
+            };
+            let state = self.write(|ctx| {
+                ctx.animation_manager
+                    .animate_tween(
+                        &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
+                        id,
+                        target_value.clone(),
+                        tween,
+                    )
+                    .1
+            });
+            if state == AnimationState::JustFinished {
+                self.animation_done(id);
+            }
+            return target_value;
+        }
+
+        let (animated_value, state) = self.write(|ctx| {
+            ctx.animation_manager.animate_tween(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
-                animation_time,
                 id,
                 target_value,
+                tween,
             )
         });
-        let animation_in_progress = animated_value != target_value;
-        if animation_in_progress {
-            self.request_repaint();
+        match state {
+            AnimationState::Idle => {}
+            AnimationState::Running => self.request_repaint(),
+            AnimationState::JustFinished => self.animation_done(id),
         }
+        animated_value
+    }
 
+    /// Play a chain of tweens, starting the first time this is called with the given `id`.
+    ///
+    /// Once done, the last value is returned, until [`Self::reset_animation`] is called.
+    /// [`Keyframes::looping`] animations are never done.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Keyframes, Tween};
+    /// let shake = Keyframes::new(0.0)
+    ///     .then(-4.0, Tween::new(0.05))
+    ///     .then(4.0, Tween::new(0.1))
+    ///     .then(0.0, Tween::new(0.05));
+    /// let id = ui.id().with("shake");
+    /// let x = ui.ctx().animate_keyframes(id, &shake);
+    /// if ui.button("Shake again").clicked() {
+    ///     ui.ctx().reset_animation(id);
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// The function will call [`Self::request_repaint()`] when appropriate,
+    /// and [`Self::on_animation_done`] callbacks once done.
+    #[track_caller] // To track repaint cause
+    pub fn animate_keyframes<T: Animatable>(&self, id: Id, keyframes: &Keyframes<T>) -> T {
+        let reduced_motion = self.style().reduced_motion;
+        let (animated_value, state) = self.write(|ctx| {
+            ctx.animation_manager.animate_keyframes(
+                &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
+                id,
+                keyframes,
+                reduced_motion,
+            )
+        });
+        match state {
+            AnimationState::Idle => {}
+            AnimationState::Running => self.request_repaint(),
+            AnimationState::JustFinished => self.animation_done(id),
+        }
         animated_value
     }
 
+    /// Call `callback` each time a [`Self::animate_tween`] or [`Self::animate_keyframes`]
+    /// with this `id` is done.
+    ///
+    /// This replaces any earlier callback for the same `id`, so it is fine to call this every frame.
+    /// The callback is called from the `animate_*` call on the frame the animation finishes.
+    pub fn on_animation_done(&self, id: Id, callback: impl Fn(&Self) + Send + Sync + 'static) {
+        self.write(|ctx| {
+            ctx.animation_manager
+                .done_callbacks
+                .insert(id, Arc::new(callback));
+        });
+    }
+
+    fn animation_done(&self, id: Id) {
+        let callback = self.read(|ctx| ctx.animation_manager.done_callbacks.get(&id).cloned());
+        if let Some(callback) = callback {
+            callback(self);
+        }
+    }
+
+    /// Forget the state of the animations with this `id`.
+    ///
+    /// Keyframes start over, and tweens jump straight to their next target.
+    /// Callbacks registered with [`Self::on_animation_done`] are kept.
+    pub fn reset_animation(&self, id: Id) {
+        self.write(|ctx| ctx.animation_manager.reset(id));
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write(|ctx| ctx.animation_manager = Default::default());
@@ -3136,11 +4190,19 @@ impl Context {
 
         ui.add_space(16.0);
 
+        let stats = self.fonts(|f| f.galley_cache_stats());
         ui.label(format!(
-            "There are {} text galleys in the layout cache",
-            self.fonts(|f| f.num_galleys_in_cache())
+            "There are {} text galleys in the layout cache, using {:.1} / {:.1} MiB",
+            stats.num_galleys,
+            stats.num_bytes as f64 / (1024.0 * 1024.0),
+            stats.budget as f64 / (1024.0 * 1024.0),
         ))
-        .on_hover_text("This is approximately the number of text strings on screen");
+        .on_hover_text("Text used in the previous frame is kept even if it exceeds the budget");
+        ui.label(format!(
+            "Layout cache hit ratio: {:.1}% ({} evicted)",
+            100.0 * stats.hit_ratio(),
+            stats.evictions
+        ));
         ui.add_space(16.0);
 
         CollapsingHeader::new("🔃 Repaint Causes")
@@ -3162,6 +4224,13 @@ impl Context {
                 input.ui(ui);
             });
 
+        CollapsingHeader::new("🕑 Input latency")
+            .default_open(false)
+            .show(ui, |ui| {
+                let latency = ui.input(|i| i.latency.clone());
+                latency.ui(ui);
+            });
+
         CollapsingHeader::new("📊 Paint stats")
             .default_open(false)
             .show(ui, |ui| {
@@ -3205,6 +4274,11 @@ impl Context {
             });
     }
 
+    /// Show the timings of the frame profiler, see [`Self::set_profiling`].
+    pub fn profiler_ui(&self, ui: &mut Ui) {
+        crate::profiler::profiler_ui(self, ui);
+    }
+
     /// Show stats about the allocated textures.
     pub fn texture_ui(&self, ui: &mut crate::Ui) {
         let tex_mngr = self.tex_manager();
@@ -3284,6 +4358,7 @@ impl Context {
                 bytes,
                 image,
                 texture,
+                ..
             } = loaders.as_ref();
 
             for loader in bytes.lock().iter() {
@@ -3430,6 +4505,129 @@ impl Context {
             interaction.ui(ui);
         });
     }
+
+    /// Lists the widget state stored in [`Self::data`], with their types and sizes.
+    ///
+    /// Use this to find state that is no longer used (e.g. because the widget is gone,
+    /// or because its [`Id`] changes every frame), and to purge it.
+    pub fn data_inspector_ui(&self, ui: &mut Ui) {
+        #[derive(Clone)]
+        struct InspectorState {
+            search: String,
+            stale_after_passes: u64,
+            only_stale: bool,
+        }
+
+        let state_id = ui.id().with("data_inspector");
+        let mut state = ui.data(|d| d.get_temp(state_id)).unwrap_or(InspectorState {
+            search: String::new(),
+            stale_after_passes: 600,
+            only_stale: false,
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Search:");
+            ui.text_edit_singleline(&mut state.search)
+                .on_hover_text("Filter by Id or type name");
+        });
+        ui.horizontal(|ui| {
+            ui.label("Stale after");
+            ui.add(crate::DragValue::new(&mut state.stale_after_passes).range(1..=u64::MAX));
+            ui.label("passes");
+            ui.checkbox(&mut state.only_stale, "Only show stale");
+        });
+
+        let mut entries: Vec<_> = self.data(|d| d.entries().collect());
+        let num_entries = entries.len();
+        let total_bytes: usize = entries.iter().map(|e| e.num_bytes).sum();
+        let is_stale =
+            |e: &crate::util::IdTypeMapEntry| e.passes_since_used >= state.stale_after_passes;
+        let num_stale = entries.iter().filter(|e| is_stale(e)).count();
+
+        let search = state.search.to_lowercase();
+        entries.retain(|e| {
+            let matches_search = search.is_empty()
+                || e.type_name
+                    .is_some_and(|name| name.to_lowercase().contains(&search))
+                || e.id
+                    .is_some_and(|id| id.short_debug_format().to_lowercase().contains(&search));
+            matches_search && (!state.only_stale || is_stale(e))
+        });
+        entries.sort_by_key(|e| (e.type_name, std::cmp::Reverse(e.passes_since_used)));
+
+        ui.label(format!(
+            "{num_entries} values using {total_bytes} bytes, of which {num_stale} are stale."
+        ));
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(num_stale > 0, crate::Button::new("Purge stale"))
+                .clicked()
+            {
+                let stale_after_passes = state.stale_after_passes;
+                self.data_mut(|d| d.retain(|e| e.passes_since_used < stale_after_passes));
+            }
+            if ui
+                .add_enabled(!entries.is_empty(), crate::Button::new("Purge shown"))
+                .on_hover_text("Remove all values that match the filter")
+                .clicked()
+            {
+                self.data_mut(|d| {
+                    for entry in &entries {
+                        d.remove_entry(entry);
+                    }
+                });
+            }
+        });
+
+        ui.separator();
+
+        ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+            Grid::new("data_inspector_grid")
+                .num_columns(5)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Id");
+                    ui.strong("Type");
+                    ui.strong("Bytes");
+                    ui.strong("Unused for");
+                    ui.end_row();
+
+                    for entry in &entries {
+                        let id_text = entry
+                            .id
+                            .map_or_else(|| "?".to_owned(), |id| id.short_debug_format());
+                        ui.monospace(id_text);
+
+                        let type_name = entry.type_name.unwrap_or("<serialized>");
+                        let mut type_text = RichText::new(type_name).monospace();
+                        if entry.persisted {
+                            type_text = type_text.strong();
+                        }
+                        ui.label(type_text).on_hover_text(if entry.persisted {
+                            "Persisted"
+                        } else {
+                            "Temporary"
+                        });
+
+                        ui.monospace(entry.num_bytes.to_string());
+
+                        let unused = format!("{} passes", entry.passes_since_used);
+                        if is_stale(entry) {
+                            ui.colored_label(ui.visuals().warn_fg_color, unused);
+                        } else {
+                            ui.monospace(unused);
+                        }
+
+                        if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                            self.data_mut(|d| d.remove_entry(entry));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+        ui.data_mut(|d| d.insert_temp(state_id, state));
+    }
 }
 
 impl Context {
@@ -3441,6 +4639,27 @@ impl Context {
     }
 }
 
+/// ## Dialogs
+impl Context {
+    /// Ready-made modal dialogs: confirm, prompt, and alert.
+    ///
+    /// See [`crate::Dialogs`].
+    pub fn dialogs(&self) -> crate::Dialogs {
+        crate::Dialogs::new(self.clone())
+    }
+}
+
+/// ## Collapsing headers
+impl Context {
+    /// Open or close all [`crate::CollapsingHeader`]s everywhere,
+    /// e.g. for "Expand all" and "Collapse all" buttons.
+    ///
+    /// See also [`Ui::set_all_collapsing_open`] and [`crate::collapsing_header::CollapsingState::is_open_by_id`].
+    pub fn set_all_collapsing_open(&self, open: bool) {
+        crate::collapsing_header::request_all_open(self, None, open);
+    }
+}
+
 /// ## Accessibility
 impl Context {
     /// Call the provided function with the given ID pushed on the stack of
@@ -3512,6 +4731,12 @@ impl Context {
     pub fn disable_accesskit(&self) {
         self.write(|ctx| ctx.is_accesskit_enabled = false);
     }
+
+    /// Is generation of AccessKit tree updates enabled?
+    #[cfg(feature = "accesskit")]
+    pub(crate) fn is_accesskit_enabled(&self) -> bool {
+        self.read(|ctx| ctx.is_accesskit_enabled)
+    }
 }
 
 /// ## Image loading
@@ -3584,6 +4809,7 @@ impl Context {
         for loader in loaders.texture.lock().iter() {
             loader.forget(uri);
         }
+        loaders.forget_usage(uri);
     }
 
     /// Release all memory and textures related to images used in [`Ui::image`] or [`crate::Image`].
@@ -3606,6 +4832,7 @@ impl Context {
         for loader in loaders.texture.lock().iter() {
             loader.forget_all();
         }
+        loaders.forget_all_usage();
     }
 
     /// Try loading the bytes from the given uri using any available bytes loaders.
@@ -3630,6 +4857,9 @@ impl Context {
         profiling::function_scope!(uri);
 
         let loaders = self.loaders();
+        if self.options(|o| o.image_memory_budget.is_some()) {
+            loaders.mark_used(uri);
+        }
         let bytes_loaders = loaders.bytes.lock();
 
         // Try most recently added loaders first (hence `.rev()`)
@@ -3668,6 +4898,9 @@ impl Context {
         profiling::function_scope!(uri);
 
         let loaders = self.loaders();
+        if self.options(|o| o.image_memory_budget.is_some()) {
+            loaders.mark_used(uri);
+        }
         let image_loaders = loaders.image.lock();
         if image_loaders.is_empty() {
             return Err(load::LoadError::NoImageLoaders);
@@ -3718,6 +4951,9 @@ impl Context {
         profiling::function_scope!(uri);
 
         let loaders = self.loaders();
+        if self.options(|o| o.image_memory_budget.is_some()) {
+            loaders.mark_used(uri);
+        }
         let texture_loaders = loaders.texture.lock();
 
         // Try most recently added loaders first (hence `.rev()`)
@@ -3731,6 +4967,18 @@ impl Context {
         Err(load::LoadError::NoMatchingTextureLoader)
     }
 
+    /// Start loading and decoding the image at `uri` in the background,
+    /// so that it is ready once it is shown, e.g. the next few images of a gallery.
+    ///
+    /// Call this each pass for as long as the image may be needed soon.
+    /// Like shown images, prefetched images are not forgotten when over
+    /// [`crate::Options::image_memory_budget`].
+    ///
+    /// Any errors are reported once the image is shown.
+    pub fn prefetch_image(&self, uri: &str) {
+        let _ = self.try_load_image(uri, load::SizeHint::default());
+    }
+
     /// The loaders of bytes, images, and textures.
     pub fn loaders(&self) -> Arc<Loaders> {
         self.read(|this| this.loaders.clone())
@@ -3743,6 +4991,34 @@ impl Context {
                 || this.loaders.bytes.lock().iter().any(|i| i.has_pending())
         })
     }
+
+    /// How far along the loading of the image at `uri` is, e.g. to show the progress of a gallery.
+    ///
+    /// Unlike [`Self::try_load_texture`], this does not start loading the image.
+    pub fn image_load_state(&self, uri: &str) -> load::LoadState {
+        use load::LoadState;
+
+        let loaders = self.loaders();
+        for loader in loaders.texture.lock().iter().rev() {
+            if let Some(state) = loader.load_state(uri) {
+                return state;
+            }
+        }
+        for loader in loaders.image.lock().iter().rev() {
+            if let Some(state) = loader.load_state(uri) {
+                return state;
+            }
+        }
+        for loader in loaders.bytes.lock().iter().rev() {
+            match loader.load_state(uri) {
+                // The image loaders are about to decode it:
+                Some(LoadState::Ready) => return LoadState::Decoding,
+                Some(state) => return state,
+                None => {}
+            }
+        }
+        LoadState::Unloaded
+    }
 }
 
 /// ## Viewports
@@ -4012,6 +5288,32 @@ impl Context {
         self.interaction_snapshot(|i| i.drag_stopped)
     }
 
+    /// The widget that has captured the pointer, if any.
+    ///
+    /// See [`crate::Response::capture_pointer`].
+    pub fn pointer_capture_id(&self) -> Option<Id> {
+        self.memory(|mem| mem.interaction().pointer_capture)
+    }
+
+    /// Let this widget get all pointer events, wherever the pointer is.
+    ///
+    /// Ignored unless a pointer button is down.
+    /// See [`crate::Response::capture_pointer`].
+    pub fn capture_pointer(&self, id: Id) {
+        if self.input(|i| i.pointer.any_down()) {
+            self.memory_mut(|mem| mem.interaction_mut().pointer_capture = Some(id));
+        }
+    }
+
+    /// Release the pointer capture, if this widget has it.
+    pub fn release_pointer_capture(&self, id: Id) {
+        self.memory_mut(|mem| {
+            mem.interaction_mut()
+                .pointer_capture
+                .take_if(|captured| *captured == id);
+        });
+    }
+
     /// Set which widget is being dragged.
     pub fn set_dragged_id(&self, id: Id) {
         self.write(|ctx| {
@@ -4051,6 +5353,47 @@ impl Context {
     }
 }
 
+/// Does this event activate the focused widget, like a click?
+///
+/// Holding the key down doesn't repeat the click,
+/// and shortcuts like `Ctrl+Enter` are left to the app.
+fn is_activation_event(event: &crate::Event) -> bool {
+    matches!(
+        event,
+        crate::Event::Key {
+            key: Key::Space | Key::Enter,
+            pressed: true,
+            repeat: false,
+            modifiers,
+            ..
+        } if !(modifiers.alt || modifiers.ctrl || modifiers.command)
+    ) || matches!(event, crate::Event::GamepadNav(crate::GamepadNav::Activate))
+}
+
+/// [`crate::GamepadNav::Back`] works like [`Key::Escape`], e.g. to close popups,
+/// so follow it with a tap of that.
+fn translate_gamepad_back(events: &mut Vec<crate::Event>) {
+    let is_back =
+        |event: &crate::Event| matches!(event, crate::Event::GamepadNav(crate::GamepadNav::Back));
+    if !events.iter().any(is_back) {
+        return;
+    }
+    *events = std::mem::take(events)
+        .into_iter()
+        .flat_map(|event| {
+            let escape = |pressed| crate::Event::Key {
+                key: Key::Escape,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers: Default::default(),
+            };
+            let tap = is_back(&event).then(|| [escape(true), escape(false)]);
+            std::iter::once(event).chain(tap.into_iter().flatten())
+        })
+        .collect();
+}
+
 #[test]
 fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -4199,4 +5542,110 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_id_clashes() {
+        use crate::{Button, CentralPanel, Id, Sense, pos2, vec2};
+
+        let ctx = Context::default();
+        let mut clashing_lines = vec![];
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let id = Id::new("same");
+                let rect = crate::Rect::from_min_size(pos2(10.0, 10.0), vec2(20.0, 20.0));
+                clashing_lines.push(line!() + 1);
+                let _ = ui.interact(rect, id, Sense::click());
+                clashing_lines.push(line!() + 1);
+                let _ = ui.interact(rect.translate(vec2(50.0, 0.0)), id, Sense::click());
+
+                // Different ids are fine:
+                let _ = ui.add(Button::new("A"));
+                let _ = ui.add(Button::new("B"));
+
+                // Reusing an id for the same rect is fine:
+                let other = Id::new("other");
+                ui.ctx().check_for_id_clash(other, rect, "thing");
+                ui.ctx().check_for_id_clash(other, rect, "thing");
+            });
+        });
+
+        let clashes = ctx.id_clashes();
+        assert_eq!(clashes.len(), 1, "{clashes:#?}");
+        let clash = &clashes[0];
+        assert_eq!(clash.id, Id::new("same"));
+        assert_eq!(clash.what, "widget");
+        assert_eq!(clash.first_location.file(), file!());
+        assert_eq!(clash.first_location.line(), clashing_lines[0]);
+        assert_eq!(clash.second_location.file(), file!());
+        assert_eq!(clash.second_location.line(), clashing_lines[1]);
+    }
+
+    #[test]
+    fn test_system_high_contrast() {
+        use crate::{Color32, RawInput, Theme, Visuals};
+
+        let ctx = Context::default();
+        let run = |high_contrast| {
+            let input = RawInput {
+                high_contrast: Some(high_contrast),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |_| {});
+        };
+        let dark = || ctx.style_of(Theme::Dark).visuals.clone();
+        let light = || ctx.style_of(Theme::Light).visuals.clone();
+
+        run(true);
+        assert_eq!(dark(), Visuals::dark().high_contrast());
+        assert_eq!(light(), Visuals::light().high_contrast());
+
+        // The app changes the dark visuals while high contrast is on:
+        let mut custom = Visuals::dark();
+        custom.panel_fill = Color32::RED;
+        ctx.set_visuals_of(Theme::Dark, custom.clone());
+
+        run(false);
+        assert_eq!(dark(), custom, "The visuals set by the app are kept");
+        assert_eq!(light(), Visuals::light(), "Unchanged visuals are reverted");
+    }
+
+    #[test]
+    fn test_dirty_rect() {
+        use crate::{CentralPanel, RawInput, Rect, pos2, vec2};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.track_dirty_region = true);
+        let input = || RawInput {
+            screen_rect: Some(Rect::from_min_size(pos2(0.0, 0.0), vec2(200.0, 200.0))),
+            ..Default::default()
+        };
+
+        let run = |counter: i32| {
+            let mut counter_rect = Rect::NOTHING;
+            let output = ctx.run(input(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Static");
+                    counter_rect = ui.label(counter.to_string()).rect;
+                });
+            });
+            (output.dirty_rect, counter_rect)
+        };
+
+        assert_eq!(run(0).0, None, "The first frame is fully repainted");
+        for _ in 0..3 {
+            run(0); // Let the fonts and layout settle
+        }
+        assert_eq!(run(0).0, Some(Rect::NOTHING), "Nothing changed");
+
+        let (dirty_rect, counter_rect) = run(1);
+        let dirty_rect = dirty_rect.expect("Only a partial repaint is needed");
+        assert!(dirty_rect.is_positive(), "{dirty_rect:?}");
+        assert!(
+            counter_rect.expand(1.0).contains_rect(dirty_rect),
+            "Only the changed label should be repainted, got {dirty_rect:?}"
+        );
+
+        ctx.options_mut(|o| o.track_dirty_region = false);
+        assert_eq!(run(1).0, None);
+    }
 }
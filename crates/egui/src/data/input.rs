@@ -37,6 +37,14 @@ pub struct RawInput {
     /// `None` will be treated as "same as last frame", with the default being a very big area.
     pub screen_rect: Option<Rect>,
 
+    /// The part of [`Self::screen_rect`] hidden behind an on-screen (virtual) keyboard, in points.
+    ///
+    /// Set by integrations on platforms with on-screen keyboards, such as mobile and web.
+    /// `None` means there is no on-screen keyboard showing, or that the integration doesn't know.
+    ///
+    /// egui uses this to scroll a focused [`crate::TextEdit`] into view above the keyboard.
+    pub virtual_keyboard_rect: Option<Rect>,
+
     /// Maximum size of one side of the font texture.
     ///
     /// Ask your graphics drivers about this. This corresponds to `GL_MAX_TEXTURE_SIZE`.
@@ -63,6 +71,19 @@ pub struct RawInput {
     /// and/or the pointer (mouse/touch) with [`crate::Context::is_using_pointer`].
     pub events: Vec<Event>,
 
+    /// When each of the [`Self::events`] was received, in the same clock as [`Self::time`].
+    ///
+    /// Either empty (unknown), or as long as [`Self::events`].
+    /// Used to measure [`crate::InputLatency`].
+    ///
+    /// If you change [`Self::time`] to another clock, use [`Self::set_time`] to keep these in sync.
+    pub event_times: Vec<f64>,
+
+    /// When the previous frame of this viewport was done painting, in the same clock as [`Self::time`].
+    ///
+    /// Used to measure [`crate::InputLatency`].
+    pub last_frame_painted: Option<f64>,
+
     /// Dragged files hovering over egui.
     pub hovered_files: Vec<HoveredFile>,
 
@@ -81,6 +102,16 @@ pub struct RawInput {
     ///
     /// `None` means "don't know".
     pub system_theme: Option<Theme>,
+
+    /// Has the user asked the OS to minimize animations ("prefers reduced motion")?
+    ///
+    /// `None` means "don't know".
+    pub reduced_motion: Option<bool>,
+
+    /// Has the user asked the OS for more contrast ("prefers high contrast")?
+    ///
+    /// `None` means "don't know".
+    pub high_contrast: Option<bool>,
 }
 
 impl Default for RawInput {
@@ -89,15 +120,20 @@ impl Default for RawInput {
             viewport_id: ViewportId::ROOT,
             viewports: std::iter::once((ViewportId::ROOT, Default::default())).collect(),
             screen_rect: None,
+            virtual_keyboard_rect: None,
             max_texture_side: None,
             time: None,
             predicted_dt: 1.0 / 60.0,
             modifiers: Modifiers::default(),
             events: vec![],
+            event_times: vec![],
+            last_frame_painted: None,
             hovered_files: Default::default(),
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
             system_theme: None,
+            reduced_motion: None,
+            high_contrast: None,
         }
     }
 }
@@ -122,15 +158,20 @@ impl RawInput {
                 .map(|(id, info)| (*id, info.take()))
                 .collect(),
             screen_rect: self.screen_rect.take(),
+            virtual_keyboard_rect: self.virtual_keyboard_rect,
             max_texture_side: self.max_texture_side.take(),
             time: self.time,
             predicted_dt: self.predicted_dt,
             modifiers: self.modifiers,
             events: std::mem::take(&mut self.events),
+            event_times: std::mem::take(&mut self.event_times),
+            last_frame_painted: self.last_frame_painted.take(),
             hovered_files: self.hovered_files.clone(),
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
             system_theme: self.system_theme,
+            reduced_motion: self.reduced_motion,
+            high_contrast: self.high_contrast,
         }
     }
 
@@ -140,29 +181,72 @@ impl RawInput {
             viewport_id: viewport_ids,
             viewports,
             screen_rect,
+            virtual_keyboard_rect,
             max_texture_side,
             time,
             predicted_dt,
             modifiers,
             mut events,
+            mut event_times,
+            last_frame_painted,
             mut hovered_files,
             mut dropped_files,
             focused,
             system_theme,
+            reduced_motion,
+            high_contrast,
         } = newer;
 
+        if self.event_times.len() != self.events.len() || event_times.len() != events.len() {
+            // We don't know the times of some of the events:
+            self.event_times.clear();
+            event_times.clear();
+        }
+
         self.viewport_id = viewport_ids;
         self.viewports = viewports;
         self.screen_rect = screen_rect.or(self.screen_rect);
+        self.virtual_keyboard_rect = virtual_keyboard_rect; // use latest
         self.max_texture_side = max_texture_side.or(self.max_texture_side);
         self.time = time; // use latest time
         self.predicted_dt = predicted_dt; // use latest dt
         self.modifiers = modifiers; // use latest
         self.events.append(&mut events);
+        self.event_times.append(&mut event_times);
+        self.last_frame_painted = last_frame_painted.or(self.last_frame_painted);
         self.hovered_files.append(&mut hovered_files);
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
         self.system_theme = system_theme;
+        self.reduced_motion = reduced_motion;
+        self.high_contrast = high_contrast;
+    }
+
+    /// Set [`Self::time`], moving [`Self::event_times`] and [`Self::last_frame_painted`]
+    /// to the new clock.
+    pub fn set_time(&mut self, time: f64) {
+        if let Some(old_time) = self.time {
+            let shift = time - old_time;
+            for event_time in &mut self.event_times {
+                *event_time += shift;
+            }
+            if let Some(painted) = &mut self.last_frame_painted {
+                *painted += shift;
+            }
+        } else {
+            self.event_times.clear();
+            self.last_frame_painted = None;
+        }
+        self.time = Some(time);
+    }
+
+    /// The times of [`Self::events`], if known for all of them.
+    pub(crate) fn known_event_times(&self) -> &[f64] {
+        if self.event_times.len() == self.events.len() {
+            &self.event_times
+        } else {
+            &[]
+        }
     }
 }
 
@@ -378,6 +462,53 @@ pub struct DroppedFile {
 
     /// Set by the `eframe` web backend.
     pub bytes: Option<std::sync::Arc<[u8]>>,
+
+    /// Text dropped from another app, e.g. a selection or a link, instead of a file.
+    ///
+    /// Set by the `eframe` web backend.
+    pub text: Option<String>,
+}
+
+/// Typed content on the system clipboard, see [`Event::PasteContent`] and [`crate::Context::copy_content`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ClipboardContent {
+    /// Plain text.
+    Text(String),
+
+    /// Rich text, e.g. copied from a web page.
+    Html {
+        html: String,
+
+        /// Plain text version of the html, for apps that don't understand html.
+        alt_text: String,
+    },
+
+    /// An image, e.g. a screenshot.
+    Image(ColorImage),
+
+    /// Paths to files, e.g. copied in a file manager.
+    Files(Vec<std::path::PathBuf>),
+}
+
+impl ClipboardContent {
+    /// A plain text version of the content, if any.
+    ///
+    /// Files are listed as their paths, one per line.
+    pub fn to_text(&self) -> Option<String> {
+        match self {
+            Self::Text(text) => Some(text.clone()),
+            Self::Html { alt_text, .. } => Some(alt_text.clone()),
+            Self::Image(_) => None,
+            Self::Files(paths) => Some(
+                paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
+    }
 }
 
 /// An input event generated by the integration.
@@ -395,6 +526,20 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event (e.g. Cmd+V) of something other than plain text.
+    ///
+    /// Sent once for each kind of content on the clipboard, e.g. both [`ClipboardContent::Html`] and
+    /// [`ClipboardContent::Image`] when pasting from a browser.
+    /// If the clipboard also has plain text, a [`Self::Paste`] is sent too, so most widgets can ignore this.
+    ///
+    /// Which kinds are sent depends on the integration. On native, `egui-winit` only sends
+    /// [`ClipboardContent::Image`], and only when there is no text on the clipboard;
+    /// it never sends [`ClipboardContent::Html`] or [`ClipboardContent::Files`].
+    /// Likewise, copying [`ClipboardContent::Files`] with `egui-winit` copies the paths as text.
+    ///
+    /// See [`crate::InputState::pasted_content`].
+    PasteContent(ClipboardContent),
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -530,6 +675,15 @@ pub enum Event {
     /// The native window gained or lost focused (e.g. the user clicked alt-tab).
     WindowFocused(bool),
 
+    /// Navigation with a gamepad or a TV remote, e.g. from its d-pad and buttons.
+    ///
+    /// The directions move the focus to the nearest widget in that direction,
+    /// [`GamepadNav::Activate`] clicks the focused widget, and [`GamepadNav::Back`] works like [`Key::Escape`].
+    ///
+    /// While navigating with a gamepad, the focused widget has a ring around it,
+    /// see [`crate::style::Visuals::gamepad_focus_stroke`].
+    GamepadNav(GamepadNav),
+
     /// An assistive technology (e.g. screen reader) requested an action.
     #[cfg(feature = "accesskit")]
     AccessKitActionRequest(accesskit::ActionRequest),
@@ -545,6 +699,53 @@ pub enum Event {
     },
 }
 
+/// Synthesizing text input, e.g. to test text widgets without a windowing backend.
+impl Event {
+    /// The events of an IME composing text, in the order `egui-winit` sends them:
+    /// the IME is enabled, shows each of the `preedits` in turn, commits `commit`,
+    /// and is disabled again.
+    ///
+    /// For instance, typing "かな" with a Japanese IME could be
+    /// `Event::ime_composition(&["k", "か", "かn", "かな"], "かな")`.
+    pub fn ime_composition(preedits: &[&str], commit: &str) -> Vec<Self> {
+        std::iter::once(ImeEvent::Enabled)
+            .chain(
+                preedits
+                    .iter()
+                    .map(|&preedit| ImeEvent::Preedit(preedit.to_owned())),
+            )
+            .chain([ImeEvent::Commit(commit.to_owned()), ImeEvent::Disabled])
+            .map(Self::Ime)
+            .collect()
+    }
+
+    /// The events of typing `text` by pressing several keys in a row, where only the last key
+    /// produces any text.
+    ///
+    /// This is how dead keys work, e.g. `´` followed by `e` for "é" would be
+    /// `Event::composed_keys(&[Key::Quote, Key::E], "é")`,
+    /// where the dead key is given by its physical key, since it has no logical key.
+    /// It is also how a compose key sequence arrives (the compose key itself is not reported).
+    pub fn composed_keys(keys: &[Key], text: &str) -> Vec<Self> {
+        let key_event = |key: Key, pressed: bool| Self::Key {
+            key,
+            physical_key: Some(key),
+            pressed,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+        let mut events = Vec::with_capacity(2 * keys.len() + 1);
+        for (i, &key) in keys.iter().enumerate() {
+            events.push(key_event(key, true));
+            if i + 1 == keys.len() && !text.is_empty() {
+                events.push(Self::Text(text.to_owned()));
+            }
+            events.push(key_event(key, false));
+        }
+        events
+    }
+}
+
 /// IME event.
 ///
 /// See <https://docs.rs/winit/latest/winit/event/enum.Ime.html>
@@ -1120,15 +1321,20 @@ impl RawInput {
             viewport_id,
             viewports,
             screen_rect,
+            virtual_keyboard_rect,
             max_texture_side,
             time,
             predicted_dt,
             modifiers,
             events,
+            event_times: _,
+            last_frame_painted: _,
             hovered_files,
             dropped_files,
             focused,
             system_theme,
+            reduced_motion,
+            high_contrast,
         } = self;
 
         ui.label(format!("Active viewport: {viewport_id:?}"));
@@ -1141,6 +1347,9 @@ impl RawInput {
             });
         }
         ui.label(format!("screen_rect: {screen_rect:?} points"));
+        ui.label(format!(
+            "virtual_keyboard_rect: {virtual_keyboard_rect:?} points"
+        ));
 
         ui.label(format!("max_texture_side: {max_texture_side:?}"));
         if let Some(time) = time {
@@ -1154,6 +1363,8 @@ impl RawInput {
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
         ui.label(format!("system_theme: {system_theme:?}"));
+        ui.label(format!("reduced_motion: {reduced_motion:?}"));
+        ui.label(format!("high_contrast: {high_contrast:?}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
@@ -1195,6 +1406,22 @@ pub enum TouchPhase {
     Cancel,
 }
 
+/// See [`Event::GamepadNav`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GamepadNav {
+    Up,
+    Down,
+    Left,
+    Right,
+
+    /// Click the focused widget, like [`Key::Enter`] does.
+    Activate,
+
+    /// Go back, e.g. close a popup, like [`Key::Escape`] does.
+    Back,
+}
+
 /// The unit associated with the numeric value of a mouse wheel event
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
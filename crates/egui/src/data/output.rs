@@ -28,6 +28,22 @@ pub struct FullOutput {
     /// You can pass this to [`crate::Context::tessellate`] together with [`Self::shapes`].
     pub pixels_per_point: f32,
 
+    /// The part of the viewport that changed since the previous frame, in points.
+    ///
+    /// It includes the anti-aliasing feathering, and is rounded outwards to whole pixels.
+    /// A custom integration that keeps the previous frame around (e.g. a software renderer
+    /// or a single-buffered display) only needs to repaint this part of it.
+    /// This assumes the backend painted every previous frame of this viewport.
+    ///
+    /// The integrations in `eframe` ignore this and always repaint everything.
+    ///
+    /// `None` means the whole viewport should be repainted.
+    /// This is always the case unless [`crate::Options::track_dirty_region`] is set,
+    /// and also happens on the first frame, on resize, or when textures change.
+    ///
+    /// An empty rectangle means nothing changed.
+    pub dirty_rect: Option<crate::Rect>,
+
     /// All the active viewports, including the root.
     ///
     /// It is up to the integration to spawn a native window for each viewport,
@@ -43,6 +59,7 @@ impl FullOutput {
             textures_delta,
             shapes,
             pixels_per_point,
+            dirty_rect,
             viewport_output,
         } = newer;
 
@@ -50,6 +67,7 @@ impl FullOutput {
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
         self.pixels_per_point = pixels_per_point; // Use latest
+        self.dirty_rect = self.dirty_rect.zip(dirty_rect).map(|(a, b)| a | b); // Repaint both
 
         for (id, new_viewport) in viewport_output {
             match self.viewport_output.entry(id) {
@@ -79,6 +97,26 @@ pub struct IMEOutput {
     pub cursor_rect: crate::Rect,
 }
 
+/// How urgently an announcement should interrupt the user, see [`crate::Context::announce`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Priority {
+    /// Announced when the user is idle, e.g. "Upload complete".
+    #[default]
+    Polite,
+
+    /// Announced immediately, interrupting the user, e.g. "Connection lost".
+    Assertive,
+}
+
+/// A message for screen-reader users, see [`crate::Context::announce`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Announcement {
+    pub text: String,
+    pub priority: Priority,
+}
+
 /// Commands that the egui integration should execute at the end of a frame.
 ///
 /// Commands that are specific to a viewport should be put in [`crate::ViewportCommand`] instead.
@@ -93,10 +131,47 @@ pub enum OutputCommand {
     /// Put this image to the system clipboard.
     CopyImage(crate::ColorImage),
 
+    /// Put this rich text to the system clipboard, with a plain text fallback.
+    CopyHtml { html: String, alt_text: String },
+
+    /// Put these file paths to the system clipboard, so they can be pasted in a file manager.
+    CopyFiles(Vec<std::path::PathBuf>),
+
     /// Open this url in a browser.
     OpenUrl(OpenUrl),
 }
 
+/// What will happen to data dropped from another app, shown by the cursor of the other app.
+///
+/// See [`crate::Context::set_drop_effect`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DropEffect {
+    /// The data can't be dropped here.
+    None,
+
+    /// The data will be copied.
+    Copy,
+
+    /// The data will be moved.
+    Move,
+
+    /// The data will be linked to.
+    Link,
+}
+
+impl DropEffect {
+    /// The name of this effect in the HTML drag-and-drop API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Copy => "copy",
+            Self::Move => "move",
+            Self::Link => "link",
+        }
+    }
+}
+
 /// The non-rendering part of what egui emits each frame.
 ///
 /// You can access (and modify) this with [`crate::Context::output`].
@@ -111,6 +186,11 @@ pub struct PlatformOutput {
     /// Set the cursor to this icon.
     pub cursor_icon: CursorIcon,
 
+    /// What will happen to the files or data dragged over egui from another app, if dropped.
+    ///
+    /// If `None`, the integration decides. See [`crate::Context::set_drop_effect`].
+    pub drop_effect: Option<DropEffect>,
+
     /// If set, open this url.
     #[deprecated = "Use `Context::open_url` or `PlatformOutput::commands` instead"]
     pub open_url: Option<OpenUrl>,
@@ -132,6 +212,12 @@ pub struct PlatformOutput {
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
+    /// Messages for screen-reader users, see [`crate::Context::announce`].
+    ///
+    /// With the `accesskit` feature these are also exposed as live regions in the AccessKit tree.
+    /// Integrations without AccessKit support can send them to a text-to-speech system instead.
+    pub announcements: Vec<Announcement>,
+
     /// Is there a mutable [`TextEdit`](crate::TextEdit) under the cursor?
     /// Use by `eframe` web to show/hide mobile keyboard and IME agent.
     pub mutable_text_under_cursor: bool,
@@ -166,7 +252,28 @@ pub struct PlatformOutput {
 
 impl PlatformOutput {
     /// This can be used by a text-to-speech system to describe the events (if any).
+    ///
+    /// Includes the [`Self::announcements`], assertive ones first.
     pub fn events_description(&self) -> String {
+        let mut announcements: Vec<&Announcement> = self.announcements.iter().collect();
+        announcements.sort_by_key(|announcement| announcement.priority != Priority::Assertive);
+        let mut description = announcements
+            .iter()
+            .map(|announcement| announcement.text.as_str())
+            .collect::<Vec<_>>()
+            .join(". ");
+
+        let event_description = self.last_event_description();
+        if !event_description.is_empty() {
+            if !description.is_empty() {
+                description += ". ";
+            }
+            description += &event_description;
+        }
+        description
+    }
+
+    fn last_event_description(&self) -> String {
         // only describe last event:
         if let Some(event) = self.events.iter().next_back() {
             match event {
@@ -190,9 +297,11 @@ impl PlatformOutput {
         let Self {
             mut commands,
             cursor_icon,
+            drop_effect,
             open_url,
             copied_text,
             mut events,
+            mut announcements,
             mutable_text_under_cursor,
             ime,
             #[cfg(feature = "accesskit")]
@@ -203,6 +312,7 @@ impl PlatformOutput {
 
         self.commands.append(&mut commands);
         self.cursor_icon = cursor_icon;
+        self.drop_effect = drop_effect.or(self.drop_effect);
         if open_url.is_some() {
             self.open_url = open_url;
         }
@@ -210,6 +320,7 @@ impl PlatformOutput {
             self.copied_text = copied_text;
         }
         self.events.append(&mut events);
+        self.announcements.append(&mut announcements);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.ime = ime.or(self.ime);
         self.num_completed_passes += num_completed_passes;
@@ -461,6 +572,39 @@ impl Default for CursorIcon {
     }
 }
 
+/// Identifies a custom cursor image, registered with [`crate::Context::register_cursor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CustomCursorId(pub(crate) crate::Id);
+
+/// A mouse cursor: either one of the standard [`CursorIcon`]s,
+/// or a custom image registered with [`crate::Context::register_cursor`].
+///
+/// Used by [`crate::Response::on_hover_cursor`] and [`crate::Context::set_cursor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Cursor {
+    /// One of the cursors of the platform.
+    Icon(CursorIcon),
+
+    /// A cursor image, painted by egui in place of the platform cursor.
+    Custom(CustomCursorId),
+}
+
+impl From<CursorIcon> for Cursor {
+    #[inline]
+    fn from(icon: CursorIcon) -> Self {
+        Self::Icon(icon)
+    }
+}
+
+impl From<CustomCursorId> for Cursor {
+    #[inline]
+    fn from(id: CustomCursorId) -> Self {
+        Self::Custom(id)
+    }
+}
+
 /// Things that happened during this frame that the integration may be interested in.
 ///
 /// In particular, these events may be useful for accessibility, i.e. for screen readers.
@@ -512,6 +656,23 @@ impl std::fmt::Debug for OutputEvent {
     }
 }
 
+/// A user interaction with a widget, as reported to the callbacks
+/// registered with [`crate::Context::on_widget_interaction`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetInteraction {
+    /// The widget that was interacted with.
+    pub id: crate::Id,
+
+    /// What happened, including the type and label of the widget.
+    pub event: OutputEvent,
+
+    /// [`crate::InputState::time`] of the frame it happened in.
+    pub time: f64,
+
+    /// [`crate::Context::cumulative_frame_nr`] of the frame it happened in.
+    pub frame_nr: u64,
+}
+
 /// Describes a widget such as a [`crate::Button`] or a [`crate::TextEdit`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -716,6 +877,12 @@ impl WidgetInfo {
             WidgetType::CollapsingHeader => "collapsing header",
             WidgetType::ProgressIndicator => "progress indicator",
             WidgetType::Window => "window",
+            WidgetType::Menu => "menu",
+            WidgetType::MenuItem => "menu item",
+            WidgetType::Table => "table",
+            WidgetType::TableRow => "row",
+            WidgetType::TableCell => "cell",
+            WidgetType::ColumnHeader => "column header",
             WidgetType::Label | WidgetType::Other => "",
         };
 
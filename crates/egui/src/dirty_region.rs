@@ -0,0 +1,264 @@
+//! Tracks which parts of a viewport changed between frames.
+//!
+//! See [`crate::Options::track_dirty_region`] and [`crate::FullOutput::dirty_rect`].
+
+use std::ops::Range;
+
+use epaint::{ClippedShape, Shape};
+
+use crate::{LayerId, Rect, pos2};
+
+/// The shapes of one layer, as painted last frame.
+struct LayerSnapshot {
+    layer_id: LayerId,
+    shapes: Vec<ClippedShape>,
+
+    /// The clipped visual bounds of each shape.
+    shape_bounds: Vec<Rect>,
+
+    /// Union of [`Self::shape_bounds`].
+    bounds: Rect,
+
+    /// Contains a [`Shape::Callback`], whose output we cannot compare.
+    has_callback: bool,
+}
+
+impl LayerSnapshot {
+    fn new(layer_id: LayerId, shapes: &[ClippedShape]) -> Self {
+        let shape_bounds: Vec<Rect> = shapes
+            .iter()
+            .map(|ClippedShape { clip_rect, shape }| {
+                shape.visual_bounding_rect().intersect(*clip_rect)
+            })
+            .collect();
+        Self {
+            layer_id,
+            shapes: shapes.to_vec(),
+            bounds: shape_bounds.iter().fold(Rect::NOTHING, |a, &b| a | b),
+            shape_bounds,
+            has_callback: shapes.iter().any(|s| contains_callback(&s.shape)),
+        }
+    }
+
+    /// The area where `self` and `new` are painted differently.
+    ///
+    /// The other shapes of the layer are the same, so only the shapes that differ
+    /// (at their old and new positions) need repainting.
+    fn diff(&self, new: &Self) -> Rect {
+        if !self.has_callback && !new.has_callback && self.shapes == new.shapes {
+            return Rect::NOTHING;
+        }
+
+        let mut dirty = Rect::NOTHING;
+        for i in 0..self.shapes.len().max(new.shapes.len()) {
+            let old_shape = self.shapes.get(i);
+            let new_shape = new.shapes.get(i);
+            let unchanged =
+                old_shape == new_shape && !old_shape.is_some_and(|s| contains_callback(&s.shape));
+            if !unchanged {
+                dirty |= self.shape_bounds.get(i).copied().unwrap_or(Rect::NOTHING);
+                dirty |= new.shape_bounds.get(i).copied().unwrap_or(Rect::NOTHING);
+            }
+        }
+        dirty
+    }
+}
+
+fn contains_callback(shape: &Shape) -> bool {
+    match shape {
+        Shape::Callback(_) => true,
+        Shape::Vec(shapes) => shapes.iter().any(contains_callback),
+        _ => false,
+    }
+}
+
+/// Compares the shapes of each layer with the previous frame.
+#[derive(Default)]
+pub(crate) struct DirtyRegionTracker {
+    /// `None` before the first frame, or after tracking was turned off.
+    prev: Option<PrevFrame>,
+}
+
+struct PrevFrame {
+    screen_rect: Rect,
+    pixels_per_point: f32,
+
+    /// In paint order.
+    layers: Vec<LayerSnapshot>,
+}
+
+impl DirtyRegionTracker {
+    /// Forget the previous frame, so that the next frame is fully repainted.
+    pub fn clear(&mut self) {
+        self.prev = None;
+    }
+
+    /// Compare the shapes of this frame with the previous one.
+    ///
+    /// `layers` are the ranges of `shapes` belonging to each layer, in paint order.
+    /// `feathering` is how far (in points) the anti-aliasing of a shape reaches
+    /// outside of its bounding rectangle.
+    ///
+    /// Returns the area that needs repainting, rounded outwards to whole pixels,
+    /// or `None` if the whole viewport should be repainted.
+    pub fn update(
+        &mut self,
+        screen_rect: Rect,
+        pixels_per_point: f32,
+        feathering: f32,
+        textures_changed: bool,
+        shapes: &[ClippedShape],
+        layers: &[(LayerId, Range<usize>)],
+    ) -> Option<Rect> {
+        profiling::function_scope!();
+
+        let new_layers: Vec<LayerSnapshot> = layers
+            .iter()
+            .map(|(layer_id, range)| LayerSnapshot::new(*layer_id, &shapes[range.clone()]))
+            .collect();
+
+        let prev = self.prev.replace(PrevFrame {
+            screen_rect,
+            pixels_per_point,
+            layers: new_layers,
+        })?;
+        let new = self.prev.as_ref()?;
+
+        if textures_changed
+            || prev.screen_rect != new.screen_rect
+            || prev.pixels_per_point != new.pixels_per_point
+        {
+            // Anything could look different.
+            return None;
+        }
+
+        let mut dirty = Rect::NOTHING;
+
+        // Layers are painted on top of each other, so if the order changes,
+        // every layer from that point on may be painted differently:
+        let num_same_order = prev
+            .layers
+            .iter()
+            .zip(&new.layers)
+            .take_while(|(a, b)| a.layer_id == b.layer_id)
+            .count();
+
+        for (old, new) in prev.layers.iter().zip(&new.layers).take(num_same_order) {
+            dirty |= old.diff(new);
+        }
+        for layer in prev.layers[num_same_order..]
+            .iter()
+            .chain(&new.layers[num_same_order..])
+        {
+            dirty |= layer.bounds;
+        }
+
+        if !dirty.is_positive() {
+            return Some(Rect::NOTHING);
+        }
+        let dirty = round_outwards_to_pixels(dirty.expand(feathering), pixels_per_point);
+        Some(dirty.intersect(screen_rect))
+    }
+}
+
+/// The smallest rectangle of whole physical pixels containing `rect`.
+fn round_outwards_to_pixels(rect: Rect, pixels_per_point: f32) -> Rect {
+    let floor = |x: f32| (x * pixels_per_point).floor() / pixels_per_point;
+    let ceil = |x: f32| (x * pixels_per_point).ceil() / pixels_per_point;
+    Rect::from_min_max(
+        pos2(floor(rect.min.x), floor(rect.min.y)),
+        pos2(ceil(rect.max.x), ceil(rect.max.y)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color32, Id, Order, pos2, vec2};
+
+    fn rect_shape(rect: Rect) -> ClippedShape {
+        ClippedShape {
+            clip_rect: Rect::EVERYTHING,
+            shape: Shape::rect_filled(rect, 0.0, Color32::RED),
+        }
+    }
+
+    #[test]
+    fn test_dirty_region() {
+        let screen_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+        let a = LayerId::new(Order::Middle, Id::new("a"));
+        let b = LayerId::new(Order::Middle, Id::new("b"));
+        let rect_a = Rect::from_min_size(pos2(10.0, 10.0), vec2(10.0, 10.0));
+        let rect_b = Rect::from_min_size(pos2(50.0, 50.0), vec2(10.0, 10.0));
+        let moved_b = rect_b.translate(vec2(5.0, 0.0));
+
+        let mut tracker = DirtyRegionTracker::default();
+        let update = |tracker: &mut DirtyRegionTracker, order: [LayerId; 2], rect_b| {
+            let shapes = [rect_shape(rect_a), rect_shape(rect_b)];
+            let shapes = if order[0] == a {
+                shapes
+            } else {
+                [shapes[1].clone(), shapes[0].clone()]
+            };
+            tracker.update(
+                screen_rect,
+                1.0,
+                0.0,
+                false,
+                &shapes,
+                &[(order[0], 0..1), (order[1], 1..2)],
+            )
+        };
+
+        assert_eq!(
+            update(&mut tracker, [a, b], rect_b),
+            None,
+            "First frame is fully repainted"
+        );
+        assert_eq!(
+            update(&mut tracker, [a, b], rect_b),
+            Some(Rect::NOTHING),
+            "Nothing changed"
+        );
+        assert_eq!(
+            update(&mut tracker, [a, b], moved_b),
+            Some(rect_b.union(moved_b)),
+            "Old and new position of the moved layer"
+        );
+        assert_eq!(
+            update(&mut tracker, [b, a], moved_b),
+            Some(rect_a.union(moved_b)),
+            "Reordered layers"
+        );
+
+        tracker.clear();
+        assert_eq!(update(&mut tracker, [b, a], moved_b), None);
+    }
+
+    #[test]
+    fn test_dirty_region_includes_feathering() {
+        let screen_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+        let layer = LayerId::new(Order::Middle, Id::new("a"));
+        let old = Rect::from_min_size(pos2(10.2, 10.2), vec2(10.0, 10.0));
+        let new = old.translate(vec2(0.1, 0.0));
+
+        let mut tracker = DirtyRegionTracker::default();
+        let mut update = |rect| {
+            tracker.update(
+                screen_rect,
+                2.0,
+                0.5,
+                false,
+                &[rect_shape(rect)],
+                &[(layer, 0..1)],
+            )
+        };
+
+        assert_eq!(update(old), None);
+        assert_eq!(
+            update(new),
+            Some(Rect::from_min_max(pos2(9.5, 9.5), pos2(21.0, 21.0))),
+            "Expanded by the feathering, then out to whole pixels"
+        );
+    }
+}
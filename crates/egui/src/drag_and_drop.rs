@@ -1,4 +1,7 @@
-use std::{any::Any, sync::Arc};
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
 
 use crate::{Context, CursorIcon, Id};
 
@@ -9,6 +12,7 @@ use crate::{Context, CursorIcon, Id};
 /// For a higher-level API, see:
 /// - [`crate::Ui::dnd_drag_source`]
 /// - [`crate::Ui::dnd_drop_zone`]
+/// - [`crate::DropTarget`]
 /// - [`crate::Response::dnd_set_drag_payload`]
 /// - [`crate::Response::dnd_hover_payload`]
 /// - [`crate::Response::dnd_release_payload`]
@@ -109,6 +113,7 @@ impl DragAndDrop {
     /// Retrieve and clear the payload, if any.
     ///
     /// Returns `None` if there is no payload, or if it is not of the requested type.
+    /// A payload of another type is left as is.
     ///
     /// Returns `Some` both during a drag and on the frame the pointer is released
     /// (if there is a payload).
@@ -118,8 +123,21 @@ impl DragAndDrop {
     {
         ctx.data_mut(|data| {
             let state = data.get_temp_mut_or_default::<Self>(Id::NULL);
-            let payload = state.payload.take()?;
-            payload.downcast().ok()
+            if !state.payload.as_ref()?.is::<Payload>() {
+                return None;
+            }
+            state.payload.take()?.downcast().ok()
+        })
+    }
+
+    /// The [`TypeId`] of the payload, if any.
+    ///
+    /// Useful for checking a payload against a list of accepted types, like [`crate::DropTarget`] does.
+    pub fn payload_type_id(ctx: &Context) -> Option<TypeId> {
+        ctx.data(|data| {
+            let state = data.get_temp::<Self>(Id::NULL)?;
+            let payload = state.payload?;
+            Some((*payload).type_id())
         })
     }
 
@@ -0,0 +1,108 @@
+//! Record the input of a [`Context`] to replay it later, see [`InputRecorder`].
+
+use crate::{Context, FullOutput, RawInput};
+
+/// The [`RawInput`] of each frame, recorded to be replayed later.
+///
+/// This is useful for saving the steps to reproduce a bug, for replaying a demo,
+/// or for testing a sequence of interactions.
+/// With the `serde` feature, a recording can be saved to a file, e.g. as RON or JSON.
+///
+/// Record what a [`Context`] gets with [`Context::start_recording_input`] and
+/// [`Context::stop_recording_input`], or add frames yourself with [`Self::record`].
+///
+/// ```
+/// let ctx = egui::Context::default();
+/// ctx.start_recording_input();
+/// let _ = ctx.run(egui::RawInput::default(), |ctx| {
+///     egui::CentralPanel::default().show(ctx, |ui| {
+///         ui.label("Hello egui!");
+///     });
+/// });
+/// let recording = ctx.stop_recording_input().unwrap_or_default();
+/// assert_eq!(recording.frames.len(), 1);
+///
+/// // Later, in a fresh context:
+/// let ctx = egui::Context::default();
+/// recording.replay(&ctx, |ctx| {
+///     egui::CentralPanel::default().show(ctx, |ui| {
+///         ui.label("Hello egui!");
+///     });
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InputRecorder {
+    /// The input of each frame, oldest first.
+    pub frames: Vec<RawInput>,
+}
+
+impl InputRecorder {
+    /// Add the input of a frame.
+    pub fn record(&mut self, raw_input: &RawInput) {
+        self.frames.push(raw_input.clone());
+    }
+
+    /// Run `run_ui` once for each recorded frame, with the recorded input, and return the output of each frame.
+    ///
+    /// The input includes the time, so the replay doesn't depend on how fast it runs.
+    /// To get the same results as during the recording, replay into a fresh [`Context`],
+    /// with the same options and fonts, and the same ui code and state.
+    pub fn replay(&self, ctx: &Context, mut run_ui: impl FnMut(&Context)) -> Vec<FullOutput> {
+        self.frames
+            .iter()
+            .map(|raw_input| ctx.run(raw_input.clone(), &mut run_ui))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CentralPanel, Context, Event, Modifiers, PointerButton, RawInput, pos2};
+
+    #[test]
+    fn test_record_and_replay_clicks() {
+        fn run_ui(ctx: &Context, clicks: &mut usize) {
+            CentralPanel::default().show(ctx, |ui| {
+                if ui.button("Click me").clicked() {
+                    *clicks += 1;
+                }
+            });
+        }
+
+        let click = |time: f64, pressed: bool| RawInput {
+            time: Some(time),
+            events: vec![
+                Event::PointerMoved(pos2(20.0, 15.0)),
+                Event::PointerButton {
+                    pos: pos2(20.0, 15.0),
+                    button: PointerButton::Primary,
+                    pressed,
+                    modifiers: Modifiers::NONE,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let ctx = Context::default();
+        let mut clicks = 0;
+        let _ = ctx.run(RawInput::default(), |ctx| run_ui(ctx, &mut clicks));
+        ctx.start_recording_input();
+        for (i, pressed) in [true, false, true, false].into_iter().enumerate() {
+            let input = click(1.0 + 0.1 * i as f64, pressed);
+            let _ = ctx.run(input, |ctx| run_ui(ctx, &mut clicks));
+        }
+        let recording = ctx.stop_recording_input().unwrap();
+        assert_eq!(clicks, 2);
+        assert_eq!(recording.frames.len(), 4);
+        assert_eq!(ctx.stop_recording_input(), None);
+
+        // The first frame of a fresh context lays out the button, so there is something to click:
+        let ctx = Context::default();
+        let mut replayed_clicks = 0;
+        let _ = ctx.run(RawInput::default(), |ctx| run_ui(ctx, &mut replayed_clicks));
+        let outputs = recording.replay(&ctx, |ctx| run_ui(ctx, &mut replayed_clicks));
+        assert_eq!(outputs.len(), 4);
+        assert_eq!(replayed_clicks, 2);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    data::input::{Event, TouchDeviceId, TouchId, TouchPhase},
+    emath::{Pos2, Vec2},
+    style::Interaction,
+    util::History,
+};
+
+use super::MultiTouchInfo;
+
+/// A touch gesture, recognized from the touches of this frame.
+///
+/// See [`crate::InputState::gestures`] and [`crate::Response::gesture`].
+/// The thresholds for recognizing them are in [`crate::style::Interaction`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Gesture {
+    /// A finger touched the screen and let go again without moving.
+    Tap { pos: Pos2 },
+
+    /// A second tap close to the first one, and soon after it.
+    ///
+    /// The second tap is reported as this instead of as a [`Self::Tap`].
+    DoubleTap { pos: Pos2 },
+
+    /// A finger has been held still on the screen for [`Interaction::long_press_duration`].
+    ///
+    /// Reported once, while the finger is still down. Letting go afterwards is not a tap.
+    LongPress { pos: Pos2 },
+
+    /// A finger moved and let go while still moving fast.
+    Swipe {
+        /// Where the finger first touched the screen.
+        start: Pos2,
+
+        /// In points per second, when the finger let go.
+        velocity: Vec2,
+    },
+
+    /// Two or more fingers turned around their center this frame.
+    Rotate {
+        center: Pos2,
+
+        /// Clockwise, in radians.
+        angle: f32,
+    },
+
+    /// Two or more fingers moved closer together (`zoom < 1`) or further apart (`zoom > 1`) this frame.
+    Pinch {
+        center: Pos2,
+
+        /// Relative to the previous frame.
+        zoom: f32,
+    },
+}
+
+impl Gesture {
+    /// Where the gesture happened: the touch of a tap or long press,
+    /// the start of a swipe, or the center of the fingers of a pinch or rotation.
+    pub fn pos(&self) -> Pos2 {
+        match *self {
+            Self::Tap { pos } | Self::DoubleTap { pos } | Self::LongPress { pos } => pos,
+            Self::Swipe { start, .. } => start,
+            Self::Rotate { center, .. } | Self::Pinch { center, .. } => center,
+        }
+    }
+}
+
+/// A single finger on the screen, which may become a tap, long press or swipe.
+#[derive(Clone, Debug)]
+struct Press {
+    device_id: TouchDeviceId,
+    id: TouchId,
+    start_pos: Pos2,
+    start_time: f64,
+    pos_history: History<Pos2>,
+
+    /// Moved too far to be a tap or long press.
+    moved: bool,
+
+    /// Already reported as a long press, so letting go is nothing.
+    long_pressed: bool,
+}
+
+/// Turns touches into [`Gesture`]s.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct GestureRecognizer {
+    press: Option<Press>,
+
+    /// How many fingers are on the screen.
+    num_touches: usize,
+
+    /// When and where the last tap was, for double taps.
+    last_tap: Option<(f64, Pos2)>,
+
+    /// The gestures of this frame.
+    gestures: Vec<Gesture>,
+
+    /// When the finger that is held down becomes a long press, unless it moves or lets go.
+    long_press_time: Option<f64>,
+}
+
+impl GestureRecognizer {
+    pub fn gestures(&self) -> &[Gesture] {
+        &self.gestures
+    }
+
+    pub fn update(
+        &mut self,
+        events: &[Event],
+        time: f64,
+        multi_touch: Option<MultiTouchInfo>,
+        options: &Interaction,
+    ) {
+        self.gestures.clear();
+
+        for event in events {
+            let Event::Touch {
+                device_id,
+                id,
+                phase,
+                pos,
+                ..
+            } = *event
+            else {
+                continue;
+            };
+            let is_press = |press: &mut Press| press.device_id == device_id && press.id == id;
+
+            match phase {
+                TouchPhase::Start => {
+                    self.num_touches += 1;
+                    // A second finger makes it a pinch or rotation instead:
+                    self.press = (self.num_touches == 1).then(|| {
+                        let mut pos_history = History::new(2..1000, 0.1);
+                        pos_history.add(time, pos);
+                        Press {
+                            device_id,
+                            id,
+                            start_pos: pos,
+                            start_time: time,
+                            pos_history,
+                            moved: false,
+                            long_pressed: false,
+                        }
+                    });
+                }
+                TouchPhase::Move => {
+                    if let Some(press) = self
+                        .press
+                        .as_mut()
+                        .filter(|press| press.device_id == device_id && press.id == id)
+                    {
+                        press.pos_history.add(time, pos);
+                        press.moved |= press.start_pos.distance(pos) > options.tap_max_distance;
+                    }
+                }
+                TouchPhase::End => {
+                    self.num_touches = self.num_touches.saturating_sub(1);
+                    if let Some(mut press) = self.press.take_if(is_press) {
+                        press.pos_history.add(time, pos);
+                        press.moved |= press.start_pos.distance(pos) > options.tap_max_distance;
+                        self.release(press, pos, time, options);
+                    }
+                }
+                TouchPhase::Cancel => {
+                    self.num_touches = self.num_touches.saturating_sub(1);
+                    self.press.take_if(is_press);
+                }
+            }
+        }
+
+        self.long_press_time = None;
+        if let Some(press) = &mut self.press {
+            if !press.moved && !press.long_pressed {
+                let long_press_time = press.start_time + options.long_press_duration as f64;
+                if long_press_time <= time {
+                    press.long_pressed = true;
+                    self.gestures.push(Gesture::LongPress {
+                        pos: press.start_pos,
+                    });
+                } else {
+                    self.long_press_time = Some(long_press_time);
+                }
+            }
+        }
+
+        if let Some(multi_touch) = multi_touch {
+            let center = multi_touch.center_pos;
+            if multi_touch.rotation_delta != 0.0 {
+                self.gestures.push(Gesture::Rotate {
+                    center,
+                    angle: multi_touch.rotation_delta,
+                });
+            }
+            if multi_touch.zoom_delta != 1.0 {
+                self.gestures.push(Gesture::Pinch {
+                    center,
+                    zoom: multi_touch.zoom_delta,
+                });
+            }
+        }
+    }
+
+    fn release(&mut self, mut press: Press, pos: Pos2, time: f64, options: &Interaction) {
+        if press.long_pressed {
+            return;
+        }
+
+        if !press.moved {
+            let is_double_tap = self.last_tap.is_some_and(|(last_time, last_pos)| {
+                (time - last_time) as f32 <= options.double_tap_delay
+                    && last_pos.distance(pos) <= options.tap_max_distance
+            });
+            if is_double_tap {
+                self.last_tap = None;
+                self.gestures.push(Gesture::DoubleTap { pos });
+            } else {
+                self.last_tap = Some((time, pos));
+                self.gestures.push(Gesture::Tap { pos });
+            }
+        } else {
+            // Only the last moments count, so stopping before letting go is no swipe:
+            press.pos_history.flush(time);
+            let velocity = press.pos_history.velocity().unwrap_or_default();
+            if options.swipe_min_velocity <= velocity.length() {
+                self.gestures.push(Gesture::Swipe {
+                    start: press.start_pos,
+                    velocity,
+                });
+            }
+        }
+    }
+
+    /// When the finger that is held down becomes a long press, unless it moves or lets go.
+    pub fn long_press_time(&self) -> Option<f64> {
+        self.long_press_time
+    }
+}
@@ -0,0 +1,141 @@
+use crate::util::History;
+
+/// How long it takes from input arriving until egui reacts to it on screen.
+///
+/// This is measured from the timestamps in [`crate::RawInput::event_times`]
+/// and [`crate::RawInput::last_frame_painted`], so it only works with integrations that set them.
+///
+/// Each frame with events gives one sample, split in two:
+/// * input → pass: from the oldest event being received until the ui pass that handles it begins
+/// * pass → paint: from the pass beginning until that frame has been painted
+#[derive(Clone, Debug)]
+pub struct InputLatency {
+    input_to_pass: History<f32>,
+    pass_to_paint: History<f32>,
+    total: History<f32>,
+
+    /// Time of the oldest event, and the time of the pass that handled it,
+    /// while we wait for the integration to tell us when that frame was painted.
+    pending: Option<(f64, f64)>,
+}
+
+impl Default for InputLatency {
+    fn default() -> Self {
+        let history = || History::new(0..300, 2.0);
+        Self {
+            input_to_pass: history(),
+            pass_to_paint: history(),
+            total: history(),
+            pending: None,
+        }
+    }
+}
+
+impl InputLatency {
+    pub(crate) fn begin_pass(
+        &mut self,
+        time: f64,
+        event_times: &[f64],
+        last_frame_painted: Option<f64>,
+    ) {
+        if let (Some(painted), Some((event_time, pass_time))) = (last_frame_painted, self.pending) {
+            if pass_time <= painted {
+                self.pending = None;
+                self.pass_to_paint.add(time, (painted - pass_time) as f32);
+                self.total.add(time, (painted - event_time) as f32);
+            }
+        }
+
+        let oldest_event = event_times.iter().copied().reduce(f64::min);
+        if let Some(oldest_event) = oldest_event {
+            self.input_to_pass
+                .add(time, (time - oldest_event).max(0.0) as f32);
+            if self.pending.is_none() {
+                self.pending = Some((oldest_event, time));
+            }
+        }
+
+        for history in [
+            &mut self.input_to_pass,
+            &mut self.pass_to_paint,
+            &mut self.total,
+        ] {
+            history.flush(time);
+        }
+    }
+
+    /// Average time in seconds from an event arriving until the pass handling it begins,
+    /// over the last couple of seconds.
+    pub fn input_to_pass(&self) -> Option<f32> {
+        self.input_to_pass.average()
+    }
+
+    /// Average time in seconds from a pass beginning until its frame was painted,
+    /// over the last couple of seconds.
+    pub fn pass_to_paint(&self) -> Option<f32> {
+        self.pass_to_paint.average()
+    }
+
+    /// Average time in seconds from an event arriving until the frame reacting to it was painted,
+    /// over the last couple of seconds.
+    pub fn total(&self) -> Option<f32> {
+        self.total.average()
+    }
+
+    pub fn ui(&self, ui: &mut crate::Ui) {
+        let stats = |history: &History<f32>| {
+            let max = history.values().reduce(f32::max);
+            match (history.average(), max) {
+                (Some(average), Some(max)) => {
+                    format!("{:.1} ms (max {:.1} ms)", 1e3 * average, 1e3 * max)
+                }
+                _ => "-".to_owned(),
+            }
+        };
+
+        crate::Grid::new("InputLatency")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Input → pass")
+                    .on_hover_text("From an event arriving until the ui pass handling it begins");
+                ui.monospace(stats(&self.input_to_pass));
+                ui.end_row();
+
+                ui.label("Pass → paint")
+                    .on_hover_text("From the ui pass beginning until its frame has been painted");
+                ui.monospace(stats(&self.pass_to_paint));
+                ui.end_row();
+
+                ui.label("Input → paint").on_hover_text(
+                    "From an event arriving until the frame reacting to it has been painted",
+                );
+                ui.monospace(stats(&self.total));
+                ui.end_row();
+            });
+
+        if self.input_to_pass.is_empty() {
+            ui.label("No event timestamps: the integration does not set `RawInput::event_times`.");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputLatency;
+
+    #[test]
+    fn latency_from_timestamps() {
+        let mut latency = InputLatency::default();
+
+        // An event arrives at 1.0, and is handled by the pass at 1.01…
+        latency.begin_pass(1.01, &[1.0, 1.005], None);
+        assert!((latency.input_to_pass().unwrap() - 0.01).abs() < 1e-4);
+        assert_eq!(latency.total(), None);
+
+        // …which is painted at 1.02, as reported at the start of the next pass:
+        latency.begin_pass(1.03, &[], Some(1.02));
+        assert!((latency.pass_to_paint().unwrap() - 0.01).abs() < 1e-4);
+        assert!((latency.total().unwrap() - 0.02).abs() < 1e-4);
+    }
+}
@@ -1,8 +1,10 @@
+mod gestures;
+mod latency;
 mod touch_state;
 
 use crate::data::input::{
-    Event, EventFilter, KeyboardShortcut, Modifiers, MouseWheelUnit, NUM_POINTER_BUTTONS,
-    PointerButton, RawInput, TouchDeviceId, ViewportInfo,
+    ClipboardContent, Event, EventFilter, KeyboardShortcut, Modifiers, MouseWheelUnit,
+    NUM_POINTER_BUTTONS, PointerButton, RawInput, TouchDeviceId, ViewportInfo,
 };
 use crate::{
     emath::{NumExt as _, Pos2, Rect, Vec2, vec2},
@@ -14,6 +16,9 @@ use std::{
 };
 
 pub use crate::Key;
+pub use gestures::Gesture;
+use gestures::GestureRecognizer;
+pub use latency::InputLatency;
 pub use touch_state::MultiTouchInfo;
 use touch_state::TouchState;
 
@@ -38,10 +43,14 @@ pub struct InputOptions {
     /// This is to support "press and hold for context menu" on touch screens.
     pub max_click_duration: f64,
 
-    /// The new pointer press must come within this many seconds from previous pointer release
-    /// for double click (or when this value is doubled, triple click) to count.
+    /// A click must come within this many seconds from the previous click
+    /// for a double click to count, and likewise for triple clicks and so on.
     pub max_double_click_delay: f64,
 
+    /// A click must be within this distance from the previous click
+    /// for a double click to count, and likewise for triple clicks and so on.
+    pub max_double_click_dist: f32,
+
     /// When this modifier is down, all scroll events are treated as zoom events.
     ///
     /// The default is CTRL/CMD, and it is STRONGLY recommended to NOT change this.
@@ -76,6 +85,7 @@ impl Default for InputOptions {
             max_click_dist: 6.0,
             max_click_duration: 0.8,
             max_double_click_delay: 0.3,
+            max_double_click_dist: 6.0,
             zoom_modifier: Modifiers::COMMAND,
             horizontal_scroll_modifier: Modifiers::SHIFT,
             vertical_scroll_modifier: Modifiers::ALT,
@@ -92,6 +102,7 @@ impl InputOptions {
             max_click_dist,
             max_click_duration,
             max_double_click_delay,
+            max_double_click_dist,
             zoom_modifier,
             horizontal_scroll_modifier,
             vertical_scroll_modifier,
@@ -143,6 +154,11 @@ impl InputOptions {
                 .on_hover_text("Max time interval for double click to count");
                 ui.end_row();
 
+                ui.label("Max double click distance");
+                ui.add(crate::DragValue::new(max_double_click_dist).range(0.0..=f32::INFINITY))
+                    .on_hover_text("Max distance to the previous click for double click to count");
+                ui.end_row();
+
                 ui.label("zoom_modifier");
                 zoom_modifier.ui(ui);
                 ui.end_row();
@@ -231,6 +247,11 @@ pub struct InputState {
     /// Position and size of the egui area.
     pub screen_rect: Rect,
 
+    /// The part of [`Self::screen_rect`] hidden behind an on-screen keyboard, if any.
+    ///
+    /// See [`crate::RawInput::virtual_keyboard_rect`].
+    pub virtual_keyboard_rect: Option<Rect>,
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     pub pixels_per_point: f32,
 
@@ -295,6 +316,13 @@ pub struct InputState {
     /// In-order events received this frame
     pub events: Vec<Event>,
 
+    /// How long it takes from input arriving until egui reacts to it on screen.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub latency: InputLatency,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    gestures: GestureRecognizer,
+
     /// Input state management configuration.
     ///
     /// This gets copied from `egui::Options` at the start of each frame for convenience.
@@ -316,6 +344,7 @@ impl Default for InputState {
             zoom_factor_delta: 1.0,
 
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
+            virtual_keyboard_rect: None,
             pixels_per_point: 1.0,
             max_texture_side: 2048,
             time: 0.0,
@@ -326,6 +355,8 @@ impl Default for InputState {
             modifiers: Default::default(),
             keys_down: Default::default(),
             events: Default::default(),
+            latency: Default::default(),
+            gestures: Default::default(),
             options: Default::default(),
         }
     }
@@ -353,7 +384,14 @@ impl InputState {
             new.predicted_dt
         };
 
+        self.latency
+            .begin_pass(time, new.known_event_times(), new.last_frame_painted);
+
         let screen_rect = new.screen_rect.unwrap_or(self.screen_rect);
+        let virtual_keyboard_rect = new
+            .virtual_keyboard_rect
+            .map(|keyboard_rect| keyboard_rect.intersect(screen_rect))
+            .filter(|keyboard_rect| keyboard_rect.is_positive());
         self.create_touch_states_for_new_devices(&new.events);
         for touch_state in self.touch_states.values_mut() {
             touch_state.begin_pass(time, &new, self.pointer.interact_pos);
@@ -504,6 +542,7 @@ impl InputState {
             zoom_factor_delta,
 
             screen_rect,
+            virtual_keyboard_rect,
             pixels_per_point,
             max_texture_side: new.max_texture_side.unwrap_or(self.max_texture_side),
             time,
@@ -514,6 +553,8 @@ impl InputState {
             modifiers: new.modifiers,
             keys_down,
             events: new.events.clone(), // TODO(emilk): remove clone() and use raw.events
+            latency: self.latency,
+            gestures: self.gestures,
             raw: new,
             options,
         }
@@ -530,6 +571,18 @@ impl InputState {
         self.screen_rect
     }
 
+    /// The part of [`Self::screen_rect`] above the on-screen keyboard, if any.
+    ///
+    /// On-screen keyboards slide up from the bottom of the screen,
+    /// so this is the screen rect cut off at the top of [`Self::virtual_keyboard_rect`].
+    pub fn unoccluded_screen_rect(&self) -> Rect {
+        let mut rect = self.screen_rect;
+        if let Some(keyboard_rect) = self.virtual_keyboard_rect {
+            rect.max.y = rect.max.y.min(keyboard_rect.min.y).max(rect.min.y);
+        }
+        rect
+    }
+
     /// Uniform zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
@@ -614,6 +667,13 @@ impl InputState {
             return Some(Duration::ZERO);
         }
 
+        if let Some(long_press_time) = self.gestures.long_press_time() {
+            // Wake up to report the `Gesture::LongPress`:
+            return Some(Duration::from_secs_f64(
+                (long_press_time - self.time).max(0.0),
+            ));
+        }
+
         if self.any_touches() && !self.pointer.is_decidedly_dragging() {
             // We need to wake up and check for press-and-hold for the context menu.
             if let Some(press_start_time) = self.pointer.press_start_time {
@@ -691,6 +751,21 @@ impl InputState {
         self.consume_key(modifiers, logical_key)
     }
 
+    /// Everything pasted this frame, from both [`Event::Paste`] and [`Event::PasteContent`].
+    ///
+    /// Plain text comes first, followed by richer content such as html, images and files.
+    pub fn pasted_content(&self) -> Vec<ClipboardContent> {
+        let text = self.events.iter().filter_map(|event| match event {
+            Event::Paste(text) => Some(ClipboardContent::Text(text.clone())),
+            _ => None,
+        });
+        let rich = self.events.iter().filter_map(|event| match event {
+            Event::PasteContent(content) => Some(content.clone()),
+            _ => None,
+        });
+        text.chain(rich).collect()
+    }
+
     /// Was the given key pressed this frame?
     ///
     /// Includes key-repeat events.
@@ -785,6 +860,20 @@ impl InputState {
         self.touch_states.values().find_map(|t| t.info())
     }
 
+    /// The touch gestures of this frame, e.g. taps, swipes and pinches.
+    ///
+    /// See also [`crate::Response::gesture`] for the gestures on a widget.
+    pub fn gestures(&self) -> &[Gesture] {
+        self.gestures.gestures()
+    }
+
+    /// Recognize the [`Gesture`]s of this frame, using the thresholds in `options`.
+    pub(crate) fn recognize_gestures(&mut self, options: &crate::style::Interaction) {
+        let multi_touch = self.multi_touch();
+        self.gestures
+            .update(&self.events, self.time, multi_touch, options);
+    }
+
     /// True if there currently are any fingers touching egui.
     pub fn any_touches(&self) -> bool {
         self.touch_states.values().any(|t| t.any_touches())
@@ -877,23 +966,13 @@ impl InputState {
 pub(crate) struct Click {
     pub pos: Pos2,
 
-    /// 1 or 2 (double-click) or 3 (triple-click)
+    /// 1, or 2 (double-click), or 3 (triple-click), and so on.
     pub count: u32,
 
     /// Allows you to check for e.g. shift-click
     pub modifiers: Modifiers,
 }
 
-impl Click {
-    pub fn is_double(&self) -> bool {
-        self.count == 2
-    }
-
-    pub fn is_triple(&self) -> bool {
-        self.count == 3
-    }
-}
-
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub(crate) enum PointerEvent {
@@ -985,9 +1064,12 @@ pub struct PointerState {
     /// Used to check for double-clicks.
     last_click_time: f64,
 
-    /// When did the pointer get click two clicks ago?
-    /// Used to check for triple-clicks.
-    last_last_click_time: f64,
+    /// Where and with what button the pointer clicked last.
+    /// Used to check for double-clicks.
+    last_click: Option<(Pos2, PointerButton)>,
+
+    /// How many clicks in a row there were, up to and including the last one.
+    last_click_count: u32,
 
     /// When was the pointer last moved?
     /// Used for things like showing hover ui/tooltip with a delay.
@@ -1019,7 +1101,8 @@ impl Default for PointerState {
             has_moved_too_much_for_a_click: false,
             started_decidedly_dragging: false,
             last_click_time: f64::NEG_INFINITY,
-            last_last_click_time: f64::NEG_INFINITY,
+            last_click: None,
+            last_click_count: 0,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
             options: Default::default(),
@@ -1093,20 +1176,22 @@ impl PointerState {
                         let clicked = self.could_any_button_be_click();
 
                         let click = if clicked {
-                            let double_click =
-                                (time - self.last_click_time) < self.options.max_double_click_delay;
-                            let triple_click = (time - self.last_last_click_time)
-                                < (self.options.max_double_click_delay * 2.0);
-                            let count = if triple_click {
-                                3
-                            } else if double_click {
-                                2
+                            let is_next_click = (time - self.last_click_time)
+                                < self.options.max_double_click_delay
+                                && self.last_click.is_some_and(|(last_pos, last_button)| {
+                                    last_button == button
+                                        && last_pos.distance(pos)
+                                            <= self.options.max_double_click_dist
+                                });
+                            let count = if is_next_click {
+                                self.last_click_count + 1
                             } else {
                                 1
                             };
 
-                            self.last_last_click_time = self.last_click_time;
                             self.last_click_time = time;
+                            self.last_click = Some((pos, button));
+                            self.last_click_count = count;
 
                             Some(Click {
                                 pos,
@@ -1350,27 +1435,32 @@ impl PointerState {
 
     /// Was the button given double clicked this frame?
     pub fn button_double_clicked(&self, button: PointerButton) -> bool {
-        self.pointer_events.iter().any(|event| {
-            matches!(
-                &event,
-                PointerEvent::Released {
-                    click: Some(click),
-                    button: b,
-                } if *b == button && click.is_double()
-            )
-        })
+        self.button_clicked_n(button, 2)
     }
 
     /// Was the button given triple clicked this frame?
     pub fn button_triple_clicked(&self, button: PointerButton) -> bool {
-        self.pointer_events.iter().any(|event| {
-            matches!(
-                &event,
-                PointerEvent::Released {
-                    click: Some(click),
-                    button: b,
-                } if *b == button && click.is_triple()
-            )
+        self.button_clicked_n(button, 3)
+    }
+
+    /// Was the button given clicked `n` times in a row, with this frame's click being the `n`th?
+    ///
+    /// How close in time and space the clicks must be is set by
+    /// [`InputOptions::max_double_click_delay`] and [`InputOptions::max_double_click_dist`].
+    pub fn button_clicked_n(&self, button: PointerButton, n: u32) -> bool {
+        self.button_click_count(button) == Some(n)
+    }
+
+    /// If the button given was clicked this frame, how many clicks in a row is this?
+    ///
+    /// `1` for a single click, `2` for a double click, and so on.
+    pub fn button_click_count(&self, button: PointerButton) -> Option<u32> {
+        self.pointer_events.iter().find_map(|event| match event {
+            PointerEvent::Released {
+                click: Some(click),
+                button: b,
+            } if *b == button => Some(click.count),
+            _ => None,
         })
     }
 
@@ -1489,6 +1579,7 @@ impl InputState {
 
             zoom_factor_delta,
             screen_rect,
+            virtual_keyboard_rect,
             pixels_per_point,
             max_texture_side,
             time,
@@ -1499,6 +1590,8 @@ impl InputState {
             modifiers,
             keys_down,
             events,
+            latency: _,
+            gestures,
             options: _,
         } = self;
 
@@ -1541,6 +1634,9 @@ impl InputState {
         ui.label(format!("zoom_factor_delta: {zoom_factor_delta:4.2}x"));
 
         ui.label(format!("screen_rect: {screen_rect:?} points"));
+        ui.label(format!(
+            "virtual_keyboard_rect: {virtual_keyboard_rect:?} points"
+        ));
         ui.label(format!(
             "{pixels_per_point} physical pixels for each logical point"
         ));
@@ -1557,6 +1653,7 @@ impl InputState {
         ui.label(format!("focused:   {focused}"));
         ui.label(format!("modifiers: {modifiers:#?}"));
         ui.label(format!("keys_down: {keys_down:?}"));
+        ui.label(format!("gestures: {:?}", gestures.gestures()));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
@@ -1582,7 +1679,8 @@ impl PointerState {
             has_moved_too_much_for_a_click,
             started_decidedly_dragging,
             last_click_time,
-            last_last_click_time,
+            last_click,
+            last_click_count,
             pointer_events,
             last_move_time,
             options: _,
@@ -1607,7 +1705,8 @@ impl PointerState {
             "started_decidedly_dragging: {started_decidedly_dragging}"
         ));
         ui.label(format!("last_click_time: {last_click_time:#?}"));
-        ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
+        ui.label(format!("last_click: {last_click:?}"));
+        ui.label(format!("last_click_count: {last_click_count}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
     }
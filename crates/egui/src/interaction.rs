@@ -1,6 +1,8 @@
 //! How mouse and touch interzcts with widgets.
 
-use crate::{Id, InputState, Key, WidgetRects, hit_test, id, input_state, memory};
+use crate::{
+    Id, InputState, Key, PointerButton, Pos2, WidgetRects, hit_test, id, input_state, memory,
+};
 
 use self::{hit_test::WidgetHits, id::IdSet, input_state::PointerEvent, memory::InteractionState};
 
@@ -54,6 +56,44 @@ pub struct InteractionSnapshot {
     /// This is usually a larger set than [`Self::hovered`],
     /// and can be used for e.g. drag-and-drop zones.
     pub contains_pointer: IdSet,
+
+    /// The pointer events of this frame, in order, and which widget got each of them.
+    pub routed_pointer_events: Vec<RoutedPointerEvent>,
+}
+
+/// A pointer event, and the widget that got it.
+///
+/// See [`InteractionSnapshot::routed_pointer_events`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoutedPointerEvent {
+    /// Goes to the widget that captured the pointer, else the dragged widget,
+    /// else the interactive widget under the pointer.
+    Moved { pos: Pos2, widget: Option<Id> },
+
+    /// Goes to the widget that captured the pointer, else the interactive widget under the pointer.
+    Pressed {
+        pos: Pos2,
+        button: PointerButton,
+        widget: Option<Id>,
+    },
+
+    /// Goes to the widget that was clicked or dragged,
+    /// else the widget that captured the pointer.
+    Released {
+        button: PointerButton,
+        widget: Option<Id>,
+    },
+}
+
+impl RoutedPointerEvent {
+    /// The widget that got the event, if any.
+    pub fn widget(&self) -> Option<Id> {
+        match *self {
+            Self::Moved { widget, .. }
+            | Self::Pressed { widget, .. }
+            | Self::Released { widget, .. } => widget,
+        }
+    }
 }
 
 impl InteractionSnapshot {
@@ -66,6 +106,7 @@ impl InteractionSnapshot {
             drag_stopped,
             hovered,
             contains_pointer,
+            routed_pointer_events,
         } = self;
 
         fn id_ui<'a>(ui: &mut crate::Ui, widgets: impl IntoIterator<Item = &'a Id>) {
@@ -102,6 +143,25 @@ impl InteractionSnapshot {
             ui.label("contains_pointer");
             id_ui(ui, contains_pointer);
             ui.end_row();
+
+            ui.label("routed_pointer_events");
+            ui.vertical(|ui| {
+                for event in routed_pointer_events {
+                    let widget = event
+                        .widget()
+                        .map_or_else(|| "-".to_owned(), |id| id.short_debug_format());
+                    match event {
+                        RoutedPointerEvent::Moved { .. } => ui.label(format!("Moved: {widget}")),
+                        RoutedPointerEvent::Pressed { button, .. } => {
+                            ui.label(format!("Pressed {button:?}: {widget}"))
+                        }
+                        RoutedPointerEvent::Released { button, .. } => {
+                            ui.label(format!("Released {button:?}: {widget}"))
+                        }
+                    };
+                }
+            });
+            ui.end_row();
         });
     }
 }
@@ -130,9 +190,22 @@ pub(crate) fn interact(
         }
     }
 
+    if let Some(id) = interaction.pointer_capture {
+        if !widgets.contains(id) {
+            // The widget that captured the pointer is gone.
+            interaction.pointer_capture = None;
+        } else if !input.pointer.any_down() && !input.pointer.any_released() {
+            // The capture only lasts while a button is held down.
+            interaction.pointer_capture = None;
+        }
+    }
+    let captured = interaction.pointer_capture.and_then(|id| widgets.get(id));
+
     let mut clicked = None;
     let mut dragged = prev_snapshot.dragged;
     let mut long_touched = None;
+    let mut routed_pointer_events = Vec::with_capacity(input.pointer.pointer_events.len());
+    let under_pointer = hits.click.or(hits.drag).map(|w| w.id);
 
     if input.key_pressed(Key::Escape) {
         // Abort dragging on escape
@@ -157,29 +230,55 @@ pub(crate) fn interact(
     // Note: in the current code a press-release in the same frame is NOT considered a drag.
     for pointer_event in &input.pointer.pointer_events {
         match pointer_event {
-            PointerEvent::Moved(_) => {}
+            PointerEvent::Moved(pos) => {
+                routed_pointer_events.push(RoutedPointerEvent::Moved {
+                    pos: *pos,
+                    widget: captured.map(|w| w.id).or(dragged).or(under_pointer),
+                });
+            }
+
+            PointerEvent::Pressed { position, button } => {
+                // The widget that captured the pointer gets the press, wherever it is:
+                let (click_hit, drag_hit) = match captured {
+                    Some(w) => (
+                        w.sense.senses_click().then_some(w.id),
+                        w.sense.senses_drag().then_some(w.id),
+                    ),
+                    None => (hits.click.map(|w| w.id), hits.drag.map(|w| w.id)),
+                };
 
-            PointerEvent::Pressed { .. } => {
                 // Maybe new click?
                 if interaction.potential_click_id.is_none() {
-                    interaction.potential_click_id = hits.click.map(|w| w.id);
+                    interaction.potential_click_id = click_hit;
                 }
 
                 // Maybe new drag?
                 if interaction.potential_drag_id.is_none() {
-                    interaction.potential_drag_id = hits.drag.map(|w| w.id);
+                    interaction.potential_drag_id = drag_hit;
                 }
+
+                routed_pointer_events.push(RoutedPointerEvent::Pressed {
+                    pos: *position,
+                    button: *button,
+                    widget: captured.map(|w| w.id).or(under_pointer),
+                });
             }
 
-            PointerEvent::Released { click, button: _ } => {
+            PointerEvent::Released { click, button } => {
+                let mut widget = dragged;
                 if click.is_some() && !input.pointer.is_decidedly_dragging() {
-                    if let Some(widget) = interaction
+                    if let Some(w) = interaction
                         .potential_click_id
                         .and_then(|id| widgets.get(id))
                     {
-                        clicked = Some(widget.id);
+                        clicked = Some(w.id);
+                        widget = Some(w.id);
                     }
                 }
+                routed_pointer_events.push(RoutedPointerEvent::Released {
+                    button: *button,
+                    widget: widget.or(captured.map(|w| w.id)),
+                });
 
                 interaction.potential_drag_id = None;
                 interaction.potential_click_id = None;
@@ -218,6 +317,11 @@ pub(crate) fn interact(
         interaction.potential_drag_id = None;
     }
 
+    if input.pointer.any_released() && !input.pointer.any_down() {
+        // Letting go of the last button releases the capture:
+        interaction.pointer_capture = None;
+    }
+
     // ------------------------------------------------------------------------
 
     let drag_changed = dragged != prev_snapshot.dragged;
@@ -237,16 +341,20 @@ pub(crate) fn interact(
         .iter()
         .chain(&hits.click)
         .chain(&hits.drag)
+        .chain(captured)
         .map(|w| w.id)
         .collect();
 
-    let hovered = if clicked.is_some() || dragged.is_some() || long_touched.is_some() {
-        // If currently clicking or dragging, only that and nothing else is hovered.
+    let is_interacting =
+        clicked.is_some() || dragged.is_some() || long_touched.is_some() || captured.is_some();
+    let hovered = if is_interacting {
+        // If currently clicking, dragging or capturing, only that and nothing else is hovered.
         clicked
             .iter()
             .chain(&dragged)
             .chain(&long_touched)
             .copied()
+            .chain(captured.map(|w| w.id))
             .collect()
     } else {
         // We may be hovering an interactive widget or two.
@@ -295,5 +403,6 @@ pub(crate) fn interact(
         drag_stopped,
         contains_pointer,
         hovered,
+        routed_pointer_events,
     }
 }
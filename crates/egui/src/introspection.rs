@@ -153,6 +153,9 @@ impl Widget for &mut epaint::TessellationOptions {
                 epsilon: _,
                 parallel_tessellation,
                 validate_meshes,
+                instanced_primitives: _, // depends on the backend
+                sdf_rects: _,            // depends on the backend
+                backdrop_blur: _,        // depends on the backend
             } = self;
 
             ui.horizontal(|ui| {
@@ -212,11 +215,13 @@ impl Widget for &memory::InteractionState {
         let memory::InteractionState {
             potential_click_id,
             potential_drag_id,
+            pointer_capture,
         } = self;
 
         ui.vertical(|ui| {
             ui.label(format!("potential_click_id: {potential_click_id:?}"));
             ui.label(format!("potential_drag_id: {potential_drag_id:?}"));
+            ui.label(format!("pointer_capture: {pointer_capture:?}"));
         })
         .response
     }
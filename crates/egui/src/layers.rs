@@ -1,6 +1,8 @@
 //! Handles paint layers, i.e. how things
 //! are sometimes painted behind or in front of other things.
 
+use std::ops::Range;
+
 use crate::{Id, IdMap, Rect, ahash, epaint};
 use epaint::{ClippedShape, Shape, emath::TSTransform};
 
@@ -212,6 +214,15 @@ impl GraphicLayers {
         self.0[layer_id.order as usize].get(&layer_id.id)
     }
 
+    /// All layers and their [`PaintList`]s, in no particular order.
+    pub fn layers(&self) -> impl Iterator<Item = (LayerId, &PaintList)> + '_ {
+        Order::ALL.iter().flat_map(move |&order| {
+            self.0[order as usize]
+                .iter()
+                .map(move |(&id, list)| (LayerId { order, id }, list))
+        })
+    }
+
     /// Get the [`PaintList`] for the given [`LayerId`].
     pub fn get_mut(&mut self, layer_id: LayerId) -> Option<&mut PaintList> {
         self.0[layer_id.order as usize].get_mut(&layer_id.id)
@@ -222,9 +233,20 @@ impl GraphicLayers {
         area_order: &[LayerId],
         to_global: &ahash::HashMap<LayerId, TSTransform>,
     ) -> Vec<ClippedShape> {
+        self.drain_with_layers(area_order, to_global).0
+    }
+
+    /// Like [`Self::drain`], but also returns which range of the shapes belongs to which layer,
+    /// in paint order.
+    pub fn drain_with_layers(
+        &mut self,
+        area_order: &[LayerId],
+        to_global: &ahash::HashMap<LayerId, TSTransform>,
+    ) -> (Vec<ClippedShape>, Vec<(LayerId, Range<usize>)>) {
         profiling::function_scope!();
 
         let mut all_shapes: Vec<_> = Default::default();
+        let mut layers: Vec<_> = Default::default();
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -244,13 +266,19 @@ impl GraphicLayers {
                                 clipped_shape.shape.transform(*to_global);
                             }
                         }
+                        let start = all_shapes.len();
                         all_shapes.append(&mut list.0);
+                        layers.push((*layer_id, start..all_shapes.len()));
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
             for (id, list) in order_map {
+                if list.is_empty() {
+                    continue; // Already drained above
+                }
+
                 let layer_id = LayerId::new(order, *id);
 
                 if let Some(to_global) = to_global.get(&layer_id) {
@@ -260,10 +288,12 @@ impl GraphicLayers {
                     }
                 }
 
+                let start = all_shapes.len();
                 all_shapes.append(&mut list.0);
+                layers.push((layer_id, start..all_shapes.len()));
             }
         }
 
-        all_shapes
+        (all_shapes, layers)
     }
 }
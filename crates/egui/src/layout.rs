@@ -117,6 +117,60 @@ impl Direction {
 
 // ----------------------------------------------------------------------------
 
+/// Which way text and widgets flow, see [`crate::Style::layout_direction`].
+///
+/// In [`Self::Rtl`] mode the default layouts are mirrored:
+/// [`crate::Ui::vertical`] and top-level [`Ui`][`crate::Ui`]:s are right-aligned,
+/// [`crate::Ui::horizontal`] places widgets from right to left,
+/// icons (e.g. of checkboxes) go on the right of their text,
+/// and [`crate::SidePanel::left`] ends up on the right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LayoutDirection {
+    /// Left to right, e.g. for English.
+    #[default]
+    Ltr,
+
+    /// Right to left, e.g. for Arabic and Hebrew.
+    Rtl,
+}
+
+impl LayoutDirection {
+    #[inline(always)]
+    pub fn is_rtl(self) -> bool {
+        self == Self::Rtl
+    }
+
+    /// The side where lines start: [`Align::LEFT`] for left-to-right, [`Align::RIGHT`] for right-to-left.
+    #[inline]
+    pub fn start(self) -> Align {
+        match self {
+            Self::Ltr => Align::LEFT,
+            Self::Rtl => Align::RIGHT,
+        }
+    }
+
+    /// The side where lines end: [`Align::RIGHT`] for left-to-right, [`Align::LEFT`] for right-to-left.
+    #[inline]
+    pub fn end(self) -> Align {
+        match self {
+            Self::Ltr => Align::RIGHT,
+            Self::Rtl => Align::LEFT,
+        }
+    }
+
+    /// The horizontal [`Direction`] in which lines are written.
+    #[inline]
+    pub fn horizontal(self) -> Direction {
+        match self {
+            Self::Ltr => Direction::LeftToRight,
+            Self::Rtl => Direction::RightToLeft,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// The layout of a [`Ui`][`crate::Ui`], e.g. "vertical & centered".
 ///
 /// ```
@@ -405,23 +405,29 @@
 #![allow(clippy::float_cmp)]
 #![allow(clippy::manual_range_contains)]
 
+mod accessibility;
+pub mod animation;
 mod animation_manager;
+pub mod automation;
 pub mod cache;
 pub mod containers;
 mod context;
 mod data;
 pub mod debug_text;
+mod dirty_region;
 mod drag_and_drop;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
 mod id;
+mod input_recorder;
 mod input_state;
 mod interaction;
 pub mod introspection;
 pub mod layers;
 mod layout;
 pub mod load;
+mod memoize;
 mod memory;
 #[deprecated = "Use `egui::containers::menu` instead"]
 pub mod menu;
@@ -429,10 +435,13 @@ pub mod os;
 mod painter;
 mod pass_state;
 pub(crate) mod placer;
+pub mod profiler;
+pub mod promise;
 pub mod response;
 mod sense;
 pub mod style;
 pub mod text_selection;
+mod track_grid;
 mod ui;
 mod ui_builder;
 mod ui_stack;
@@ -465,12 +474,22 @@ pub use emath::{
     remap_clamp, vec2,
 };
 pub use epaint::{
-    ClippedPrimitive, ColorImage, CornerRadius, ImageData, Margin, Mesh, PaintCallback,
-    PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle, TextureId, mutex,
+    Brush, ClippedPrimitive, ColorImage, CornerRadius, Gradient, ImageData, Margin, Mesh,
+    NineSlice, PaintCallback, PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle,
+    TextureId, TiledTexture, mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
 };
 
+#[cfg(feature = "persistence_json")]
+pub use memory::JsonFormat;
+#[cfg(feature = "persistence_postcard")]
+pub use memory::PostcardFormat;
+#[cfg(feature = "persistence")]
+pub use memory::{
+    MemoryPersistence, PersistedMemory, PersistenceError, PersistenceFormat, RonFormat,
+};
+
 pub mod text {
     pub use crate::text_selection::CCursorRange;
     pub use epaint::text::{
@@ -480,31 +499,38 @@ pub mod text {
 }
 
 pub use self::{
+    accessibility::{AccessibilityAction, AccessibilityNode},
+    animation::{Animatable, Keyframes, Transition, Tween},
     atomics::*,
     containers::{menu::MenuBar, *},
-    context::{Context, RepaintCause, RequestRepaintInfo},
+    context::{Context, IdClash, RepaintCause, RequestRepaintInfo, WidgetInteractionCallback},
     data::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
-            UserAttentionType, WidgetInfo,
+            self, Announcement, Cursor, CursorIcon, CustomCursorId, DropEffect, FullOutput,
+            OpenUrl, OutputCommand, PlatformOutput, Priority, UserAttentionType, WidgetInfo,
+            WidgetInteraction,
         },
     },
     drag_and_drop::DragAndDrop,
     epaint::text::TextWrapMode,
     grid::Grid,
-    id::{Id, IdMap},
-    input_state::{InputOptions, InputState, MultiTouchInfo, PointerState},
+    id::{Id, IdMap, IdSet},
+    input_recorder::InputRecorder,
+    input_state::{Gesture, InputLatency, InputOptions, InputState, MultiTouchInfo, PointerState},
+    interaction::{InteractionSnapshot, RoutedPointerEvent},
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
-    memory::{Memory, Options, Theme, ThemePreference},
+    memory::{AreaLayout, Memory, Options, PowerPolicy, Theme, ThemePreference, WindowLayout},
     painter::Painter,
+    promise::{Promise, PromiseSender, PromiseState},
     response::{InnerResponse, Response},
     sense::Sense,
     style::{FontSelection, Spacing, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
+    track_grid::{GridCell, Track, TrackGrid, TrackGridUi, TrackRef},
     ui::Ui,
     ui_builder::UiBuilder,
     ui_stack::*,
@@ -678,12 +704,64 @@ pub enum WidgetType {
 
     Window,
 
+    /// A popup menu, containing [`Self::MenuItem`]s.
+    Menu,
+
+    /// An item in a [`Self::Menu`].
+    MenuItem,
+
+    /// A table or grid of data, containing [`Self::TableRow`]s.
+    Table,
+
+    /// A row in a [`Self::Table`].
+    TableRow,
+
+    /// A cell in a [`Self::Table`].
+    TableCell,
+
+    /// A header cell of a column in a [`Self::Table`].
+    ColumnHeader,
+
     /// If you cannot fit any of the above slots.
     ///
     /// If this is something you think should be added, file an issue.
     Other,
 }
 
+impl WidgetType {
+    /// The [`accesskit::Role`] of widgets of this type.
+    #[cfg(feature = "accesskit")]
+    pub fn accesskit_role(self) -> accesskit::Role {
+        use accesskit::Role;
+
+        match self {
+            Self::Label => Role::Label,
+            Self::Link => Role::Link,
+            Self::TextEdit => Role::TextInput,
+            Self::Button | Self::ImageButton | Self::CollapsingHeader | Self::SelectableLabel => {
+                Role::Button
+            }
+            Self::Image => Role::Image,
+            Self::Checkbox => Role::CheckBox,
+            Self::RadioButton => Role::RadioButton,
+            Self::RadioGroup => Role::RadioGroup,
+            Self::ComboBox => Role::ComboBox,
+            Self::Slider => Role::Slider,
+            Self::DragValue => Role::SpinButton,
+            Self::ColorButton => Role::ColorWell,
+            Self::ProgressIndicator => Role::ProgressIndicator,
+            Self::Window => Role::Window,
+            Self::Menu => Role::Menu,
+            Self::MenuItem => Role::MenuItem,
+            Self::Table => Role::Table,
+            Self::TableRow => Role::Row,
+            Self::TableCell => Role::Cell,
+            Self::ColumnHeader => Role::ColumnHeader,
+            Self::Other => Role::Unknown,
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// For use in tests; especially doctests.
@@ -140,6 +140,25 @@ impl std::error::Error for LoadError {}
 
 pub type Result<T, E = LoadError> = std::result::Result<T, E>;
 
+/// How far along the loading of an image is, see [`Context::image_load_state`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoadState {
+    /// Not loaded, e.g. because it hasn't been shown yet, or was forgotten.
+    Unloaded,
+
+    /// Reading or downloading the bytes.
+    Fetching,
+
+    /// Decoding the bytes into an image.
+    Decoding,
+
+    /// Ready to be shown.
+    Ready,
+
+    /// Loading failed, with this error message.
+    Failed(String),
+}
+
 /// Given as a hint for image loading requests.
 ///
 /// Used mostly for rendering SVG:s to a good size.
@@ -357,10 +376,28 @@ pub trait BytesLoader {
     /// If the loader caches any data, this should return the size of that cache.
     fn byte_size(&self) -> usize;
 
+    /// If the loader caches any data for `uri`, this should return the size of that data.
+    ///
+    /// Used by [`Loaders::evict_least_recently_used`] to keep track of how much memory it frees.
+    /// Returning `None` means the size is unknown, and [`Self::byte_size`] is measured instead.
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        let _ = uri;
+        None
+    }
+
     /// Returns `true` if some data is currently being loaded.
     fn has_pending(&self) -> bool {
         false
     }
+
+    /// How far along the loading of `uri` is, without starting to load it.
+    ///
+    /// Returns [`LoadState::Fetching`], [`LoadState::Ready`], or [`LoadState::Failed`] if this loader knows about `uri`,
+    /// and `None` otherwise.
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let _ = uri;
+        None
+    }
 }
 
 /// Represents an image which is currently being loaded.
@@ -431,12 +468,30 @@ pub trait ImageLoader {
     /// If the loader caches any data, this should return the size of that cache.
     fn byte_size(&self) -> usize;
 
+    /// If the loader caches any data for `uri`, this should return the size of that data.
+    ///
+    /// Used by [`Loaders::evict_least_recently_used`] to keep track of how much memory it frees.
+    /// Returning `None` means the size is unknown, and [`Self::byte_size`] is measured instead.
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        let _ = uri;
+        None
+    }
+
     /// Returns `true` if some image is currently being loaded.
     ///
     /// NOTE: You probably also want to check [`BytesLoader::has_pending`].
     fn has_pending(&self) -> bool {
         false
     }
+
+    /// How far along the loading of `uri` is, without starting to load it.
+    ///
+    /// Returns [`LoadState::Decoding`], [`LoadState::Ready`], or [`LoadState::Failed`] if this loader knows about `uri`,
+    /// and `None` otherwise.
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let _ = uri;
+        None
+    }
 }
 
 /// A texture with a known size.
@@ -580,6 +635,23 @@ pub trait TextureLoader {
 
     /// If the loader caches any data, this should return the size of that cache.
     fn byte_size(&self) -> usize;
+
+    /// If the loader caches any data for `uri`, this should return the size of that data.
+    ///
+    /// Used by [`Loaders::evict_least_recently_used`] to keep track of how much memory it frees.
+    /// Returning `None` means the size is unknown, and [`Self::byte_size`] is measured instead.
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        let _ = uri;
+        None
+    }
+
+    /// How far along the loading of `uri` is, without starting to load it.
+    ///
+    /// Returns [`LoadState::Ready`] if this loader has a texture for `uri`, and `None` otherwise.
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let _ = uri;
+        None
+    }
 }
 
 type BytesLoaderImpl = Arc<dyn BytesLoader + Send + Sync + 'static>;
@@ -593,6 +665,18 @@ pub struct Loaders {
     pub bytes: Mutex<Vec<BytesLoaderImpl>>,
     pub image: Mutex<Vec<ImageLoaderImpl>>,
     pub texture: Mutex<Vec<TextureLoaderImpl>>,
+
+    /// When each URI was last used, see [`Self::evict_least_recently_used`].
+    usage: Mutex<Usage>,
+}
+
+#[derive(Clone, Default)]
+struct Usage {
+    /// The index of the current pass.
+    pass_index: u64,
+
+    /// The pass in which each URI was last loaded.
+    last_used: HashMap<String, u64>,
 }
 
 impl Default for Loaders {
@@ -604,6 +688,7 @@ impl Default for Loaders {
             // By default we only include `DefaultTextureLoader`.
             texture: Mutex::new(vec![Arc::new(DefaultTextureLoader::default())]),
             include,
+            usage: Default::default(),
         }
     }
 }
@@ -616,6 +701,7 @@ impl Loaders {
             bytes,
             image,
             texture,
+            usage,
         } = self;
 
         include.end_pass(pass_index);
@@ -628,5 +714,259 @@ impl Loaders {
         for loader in texture.lock().iter() {
             loader.end_pass(pass_index);
         }
+        usage.lock().pass_index = pass_index + 1;
+    }
+
+    /// Remember that `uri` is used in the current pass.
+    ///
+    /// Only called if [`crate::Options::image_memory_budget`] is set.
+    pub(crate) fn mark_used(&self, uri: &str) {
+        let mut usage = self.usage.lock();
+        let pass_index = usage.pass_index;
+        if let Some(last_used) = usage.last_used.get_mut(uri) {
+            *last_used = pass_index;
+        } else {
+            usage.last_used.insert(uri.to_owned(), pass_index);
+        }
+    }
+
+    /// Stop remembering when `uri` was last used, e.g. because it was forgotten.
+    pub(crate) fn forget_usage(&self, uri: &str) {
+        self.usage.lock().last_used.remove(uri);
+    }
+
+    /// Stop remembering when any URI was last used.
+    pub(crate) fn forget_all_usage(&self) {
+        self.usage.lock().last_used.clear();
+    }
+
+    /// The size of the caches of all loaders,
+    /// except for the bytes added with [`Context::include_bytes`], which can't be reloaded.
+    pub fn byte_size(&self) -> usize {
+        let bytes: usize = self
+            .bytes
+            .lock()
+            .iter()
+            .filter(|loader| loader.id() != self.include.id())
+            .map(|loader| loader.byte_size())
+            .sum();
+        let image: usize = self.image.lock().iter().map(|l| l.byte_size()).sum();
+        let texture: usize = self.texture.lock().iter().map(|l| l.byte_size()).sum();
+        bytes + image + texture
+    }
+
+    /// Forget the least recently used images, until [`Self::byte_size`] is at most `budget`.
+    ///
+    /// Images used in the current or previous pass are never forgotten, even if that means going over budget.
+    ///
+    /// This is called at the end of each pass if [`crate::Options::image_memory_budget`] is set.
+    pub fn evict_least_recently_used(&self, budget: usize) {
+        profiling::function_scope!();
+
+        let mut byte_size = self.byte_size();
+        if byte_size <= budget {
+            return;
+        }
+
+        let mut candidates: Vec<(u64, String)> = {
+            let usage = self.usage.lock();
+            usage
+                .last_used
+                .iter()
+                .filter(|(_, last_used)| **last_used + 1 < usage.pass_index)
+                .map(|(uri, last_used)| (*last_used, uri.clone()))
+                .collect()
+        };
+        candidates.sort();
+
+        for (_, uri) in candidates {
+            #[cfg(feature = "log")]
+            log::trace!("evicting {uri:?}");
+
+            let mut freed = 0;
+            for loader in self.bytes.lock().iter() {
+                if loader.id() != self.include.id() {
+                    freed += forget_measured(
+                        loader.byte_size_of(&uri),
+                        || loader.byte_size(),
+                        || {
+                            loader.forget(&uri);
+                        },
+                    );
+                }
+            }
+            for loader in self.image.lock().iter() {
+                freed += forget_measured(
+                    loader.byte_size_of(&uri),
+                    || loader.byte_size(),
+                    || {
+                        loader.forget(&uri);
+                    },
+                );
+            }
+            for loader in self.texture.lock().iter() {
+                freed += forget_measured(
+                    loader.byte_size_of(&uri),
+                    || loader.byte_size(),
+                    || {
+                        loader.forget(&uri);
+                    },
+                );
+            }
+            self.forget_usage(&uri);
+
+            byte_size = byte_size.saturating_sub(freed);
+            if byte_size <= budget {
+                break;
+            }
+        }
+    }
+}
+
+/// Calls `forget` and returns how many bytes that freed.
+///
+/// Uses `byte_size_of` if the loader knows it, and otherwise measures `byte_size` before and after.
+fn forget_measured(
+    byte_size_of: Option<usize>,
+    byte_size: impl Fn() -> usize,
+    forget: impl FnOnce(),
+) -> usize {
+    if let Some(size) = byte_size_of {
+        forget();
+        size
+    } else {
+        let before = byte_size();
+        forget();
+        before.saturating_sub(byte_size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Loads a 5x5 image of 100 bytes for any URI.
+    #[derive(Default)]
+    struct TestLoader {
+        cache: Mutex<HashMap<String, Arc<ColorImage>>>,
+    }
+
+    impl TestLoader {
+        fn cached(&self) -> Vec<String> {
+            let mut uris: Vec<String> = self.cache.lock().keys().cloned().collect();
+            uris.sort();
+            uris
+        }
+    }
+
+    impl ImageLoader for TestLoader {
+        fn id(&self) -> &'static str {
+            generate_loader_id!(TestLoader)
+        }
+
+        fn load(&self, _ctx: &Context, uri: &str, _size_hint: SizeHint) -> ImageLoadResult {
+            let image = self
+                .cache
+                .lock()
+                .entry(uri.to_owned())
+                .or_insert_with(|| Arc::new(ColorImage::filled([5, 5], epaint::Color32::RED)))
+                .clone();
+            Ok(ImagePoll::Ready { image })
+        }
+
+        fn forget(&self, uri: &str) {
+            self.cache.lock().remove(uri);
+        }
+
+        fn forget_all(&self) {
+            self.cache.lock().clear();
+        }
+
+        fn byte_size(&self) -> usize {
+            self.cache.lock().len() * 100
+        }
+    }
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.image_memory_budget = Some(250));
+        let loader = Arc::new(TestLoader::default());
+        ctx.add_image_loader(loader.clone());
+        let run = |uris: &[&str]| {
+            let _ = ctx.run(Default::default(), |ctx| {
+                for uri in uris {
+                    ctx.prefetch_image(uri);
+                }
+            });
+            loader.cached()
+        };
+
+        assert_eq!(run(&["a", "b", "c"]), ["a", "b", "c"], "All are in use");
+        assert_eq!(
+            run(&["c"]),
+            ["a", "b", "c"],
+            "All were used in the previous pass"
+        );
+        assert_eq!(
+            run(&["d"]),
+            ["c", "d"],
+            "The least recently used are forgotten until under budget"
+        );
+    }
+
+    #[test]
+    fn test_usage_is_only_tracked_with_a_budget() {
+        let ctx = Context::default();
+        ctx.add_image_loader(Arc::new(TestLoader::default()));
+        let run = |uris: &[&str]| {
+            let _ = ctx.run(Default::default(), |ctx| {
+                for uri in uris {
+                    ctx.prefetch_image(uri);
+                }
+            });
+            let mut tracked: Vec<String> = ctx
+                .loaders()
+                .usage
+                .lock()
+                .last_used
+                .keys()
+                .cloned()
+                .collect();
+            tracked.sort();
+            tracked
+        };
+
+        assert!(run(&["a", "b"]).is_empty(), "No budget, no tracking");
+
+        ctx.options_mut(|o| o.image_memory_budget = Some(1000));
+        assert_eq!(run(&["a", "b"]), ["a", "b"]);
+
+        ctx.forget_image("a");
+        assert_eq!(run(&[]), ["b"], "Forgotten images are no longer tracked");
+
+        ctx.forget_all_images();
+        assert!(run(&[]).is_empty());
+
+        let _ = run(&["a"]);
+        ctx.options_mut(|o| o.image_memory_budget = None);
+        assert!(
+            run(&["a"]).is_empty(),
+            "Removing the budget clears the tracking"
+        );
+    }
+
+    #[test]
+    fn test_image_load_state() {
+        let ctx = Context::default();
+        ctx.add_image_loader(Arc::new(TestLoader::default()));
+        let uri = "bytes://test.png";
+        assert_eq!(ctx.image_load_state(uri), LoadState::Unloaded);
+
+        ctx.include_bytes(uri, b"test");
+        assert_eq!(ctx.image_load_state(uri), LoadState::Decoding);
+
+        let _ = ctx.try_load_texture(uri, TextureOptions::default(), SizeHint::default());
+        assert_eq!(ctx.image_load_state(uri), LoadState::Ready);
     }
 }
@@ -1,6 +1,6 @@
 use super::{
-    Bytes, BytesLoadResult, BytesLoader, BytesPoll, Context, Cow, HashMap, LoadError, Mutex,
-    generate_loader_id,
+    Bytes, BytesLoadResult, BytesLoader, BytesPoll, Context, Cow, HashMap, LoadError, LoadState,
+    Mutex, generate_loader_id,
 };
 
 /// Maps URI:s to [`Bytes`], e.g. found with `include_bytes!`.
@@ -69,4 +69,15 @@ impl BytesLoader for DefaultBytesLoader {
     fn byte_size(&self) -> usize {
         self.cache.lock().values().map(|bytes| bytes.len()).sum()
     }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, |bytes| bytes.len()))
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        self.cache
+            .lock()
+            .contains_key(uri)
+            .then_some(LoadState::Ready)
+    }
 }
@@ -3,8 +3,8 @@ use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
 use emath::Vec2;
 
 use super::{
-    BytesLoader as _, Context, HashMap, ImagePoll, Mutex, SizeHint, SizedTexture, TextureHandle,
-    TextureLoadResult, TextureLoader, TextureOptions, TexturePoll,
+    BytesLoader as _, Context, HashMap, ImagePoll, LoadState, Mutex, SizeHint, SizedTexture,
+    TextureHandle, TextureLoadResult, TextureLoader, TextureOptions, TexturePoll,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -131,19 +131,36 @@ impl TextureLoader for DefaultTextureLoader {
     }
 
     fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(bucket_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(
+            self.cache
+                .lock()
+                .iter()
+                .filter(|(key, _)| key.uri == uri)
+                .map(|(_, bucket)| bucket_byte_size(bucket))
+                .sum(),
+        )
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
         self.cache
             .lock()
-            .values()
-            .map(|bucket| {
-                bucket
-                    .values()
-                    .map(|texture| texture.handle.byte_size())
-                    .sum::<usize>()
-            })
-            .sum()
+            .iter()
+            .any(|(key, bucket)| key.uri == uri && !bucket.is_empty())
+            .then_some(LoadState::Ready)
     }
 }
 
 fn is_svg(uri: &str) -> bool {
     uri.ends_with(".svg")
 }
+
+fn bucket_byte_size(bucket: &Bucket) -> usize {
+    bucket
+        .values()
+        .map(|texture| texture.handle.byte_size())
+        .sum()
+}
@@ -0,0 +1,233 @@
+//! Replaying the output of a [`Ui`] subtree instead of laying it out again, see [`Ui::memoize`].
+
+use std::{hash::Hash, sync::Arc};
+
+use emath::TSTransform;
+use epaint::ClippedShape;
+
+use crate::{Id, Pos2, Rect, Ui, WidgetRect, text_selection::LabelSelectionState};
+
+/// What a memoized subtree painted and allocated, last time it was laid out.
+#[derive(Clone)]
+pub(crate) struct Recording {
+    /// Hash of the user inputs, and of everything else that affects the output.
+    key: u64,
+
+    /// The available size across the layout direction (e.g. width in a top-down layout).
+    cross_size: f32,
+
+    /// Where the subtree started.
+    origin: Pos2,
+
+    /// The space used by the subtree.
+    min_rect: Rect,
+
+    /// The clip rect of the subtree. Shapes with this clip rect get the new one on replay.
+    clip_rect: Rect,
+
+    shapes: Arc<Vec<ClippedShape>>,
+
+    /// The widgets in the subtree, so they can be hovered and keep their place in the tab order.
+    widgets: Arc<Vec<WidgetRect>>,
+}
+
+impl Recording {
+    /// Everything except the position that affects the output of the subtree.
+    pub fn key(ui: &Ui, inputs: impl Hash) -> u64 {
+        let ctx = ui.ctx();
+        Id::new((
+            inputs,
+            ui.is_enabled(),
+            ui.is_read_only(),
+            ctx.theme(),
+            ctx.pixels_per_point().to_bits(),
+        ))
+        .value()
+    }
+
+    /// Can the subtree be memoized in this `ui` at all?
+    pub fn is_possible(ui: &Ui) -> bool {
+        #[cfg(feature = "accesskit")]
+        if ui.ctx().is_accesskit_enabled() {
+            // Replaying would leave the widgets out of the accessibility tree.
+            return false;
+        }
+        ui.is_visible() && !LabelSelectionState::load(ui.ctx()).has_selection()
+    }
+
+    /// Lay out the subtree for real, and record what it did.
+    ///
+    /// Returns `None` if the result can't be replayed, e.g. because it is being animated.
+    pub fn record(ui: &mut Ui, key: u64, add_contents: impl FnOnce(&mut Ui)) -> Option<Self> {
+        let ctx = ui.ctx().clone();
+        let layer_id = ui.layer_id();
+        let first_shape = ctx.graphics(|g| g.get(layer_id).map_or(0, |list| list.next_idx().0));
+        let first_widget = ctx.pass_state(|fs| fs.widgets.get_layer(layer_id).count());
+        let num_repaint_requests = ctx.num_repaint_requests();
+
+        add_contents(ui);
+
+        if ui.is_sizing_pass()
+            || ctx.will_discard()
+            || ctx.num_repaint_requests() != num_repaint_requests
+        {
+            return None;
+        }
+
+        let shapes = ctx.graphics(|g| {
+            g.get(layer_id).map_or_else(Vec::new, |list| {
+                list.all_entries().skip(first_shape).cloned().collect()
+            })
+        });
+        let widgets = ctx.pass_state(|fs| {
+            fs.widgets
+                .get_layer(layer_id)
+                .skip(first_widget)
+                .copied()
+                .collect()
+        });
+
+        let recording = Self {
+            key,
+            cross_size: cross_size(ui),
+            origin: ui.max_rect().min,
+            min_rect: ui.min_rect(),
+            clip_rect: ui.clip_rect(),
+            shapes: Arc::new(shapes),
+            widgets: Arc::new(widgets),
+        };
+        (!recording.is_live(ui)).then_some(recording)
+    }
+
+    /// Can we replay this in `ui`, or does the subtree need to be laid out again?
+    pub fn can_replay(&self, ui: &Ui, key: u64) -> bool {
+        self.key == key && self.cross_size == cross_size(ui) && !self.is_live(ui)
+    }
+
+    /// Is the user interacting with the subtree, so that it may look different next time?
+    fn is_live(&self, ui: &Ui) -> bool {
+        let min_rect = self.min_rect.translate(ui.max_rect().min - self.origin);
+        let focused = ui.ctx().memory(|mem| mem.focused());
+        let dragged = ui.ctx().dragged_id();
+        ui.rect_contains_pointer(min_rect)
+            || self.widgets.iter().any(|widget| {
+                widget.sense.interactive()
+                    && (Some(widget.id) == focused || Some(widget.id) == dragged)
+            })
+    }
+
+    /// Paint the recorded shapes, register the recorded widgets,
+    /// and allocate the recorded space, at the cursor of `ui`.
+    pub fn replay(&self, ui: &mut Ui) {
+        let delta = ui.max_rect().min - self.origin;
+        let clip_rect = ui.clip_rect();
+        let layer_id = ui.layer_id();
+        ui.ctx().graphics_mut(|g| {
+            let list = g.entry(layer_id);
+            for ClippedShape {
+                clip_rect: recorded_clip_rect,
+                shape,
+            } in self.shapes.iter()
+            {
+                let clip_rect = if *recorded_clip_rect == self.clip_rect {
+                    clip_rect
+                } else {
+                    recorded_clip_rect.translate(delta).intersect(clip_rect)
+                };
+                let mut shape = shape.clone();
+                shape.translate(delta);
+                list.add(clip_rect, shape);
+            }
+        });
+        for widget in self.widgets.iter() {
+            let mut widget = widget.transform(TSTransform::from_translation(delta));
+            widget.interact_rect = widget.interact_rect.intersect(clip_rect);
+            let _ = ui.ctx().create_widget(widget, true);
+        }
+        ui.expand_to_include_rect(self.min_rect.translate(delta));
+    }
+}
+
+fn cross_size(ui: &Ui) -> f32 {
+    if ui.layout().main_dir().is_horizontal() {
+        ui.max_rect().height()
+    } else {
+        ui.max_rect().width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CentralPanel, Context, Event, RawInput, Vec2, vec2};
+
+    #[test]
+    fn memoized_ui_is_replayed() {
+        let ctx = Context::default();
+        let mut num_calls = 0;
+        let mut inputs = 0;
+        let mut offset = 0.0;
+        let first_label = std::cell::Cell::new(None);
+        let run = |num_calls: &mut usize, inputs: i32, offset: f32| {
+            let mut rect = None;
+            let output = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.add_space(offset);
+                    let response = ui.memoize("labels", inputs, |ui| {
+                        *num_calls += 1;
+                        for i in 0..10 {
+                            let response = ui.label(format!("Label {i} of {inputs}"));
+                            if i == 0 {
+                                first_label.set(Some((response.id, response.rect)));
+                            }
+                        }
+                    });
+                    rect = Some(response.rect);
+                });
+            });
+            (rect.unwrap(), output.shapes)
+        };
+
+        let (first_rect, first_shapes) = run(&mut num_calls, inputs, offset);
+        assert_eq!(num_calls, 1);
+
+        // Same inputs: replayed.
+        let (rect, shapes) = run(&mut num_calls, inputs, offset);
+        assert_eq!(num_calls, 1);
+        assert_eq!(rect, first_rect);
+        assert_eq!(shapes.len(), first_shapes.len());
+
+        // Moved down: still replayed, but further down.
+        offset = 50.0;
+        let (rect, _) = run(&mut num_calls, inputs, offset);
+        assert_eq!(num_calls, 1);
+        assert_eq!(rect, first_rect.translate(vec2(0.0, 50.0)));
+
+        // The replayed widgets are still there, so they can be hovered:
+        let (label_id, label_rect) = first_label.get().unwrap();
+        assert_eq!(
+            ctx.prev_pass_state(|fs| fs.widgets.get(label_id).map(|widget| widget.rect)),
+            Some(label_rect.translate(vec2(0.0, 50.0)))
+        );
+
+        // New inputs: laid out again.
+        inputs = 1;
+        run(&mut num_calls, inputs, offset);
+        assert_eq!(num_calls, 2);
+
+        // Hovered: laid out again.
+        let mut hovered_input = RawInput::default();
+        hovered_input
+            .events
+            .push(Event::PointerMoved(rect.min + Vec2::splat(5.0)));
+        let _ = ctx.run(hovered_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add_space(offset);
+                ui.memoize("labels", inputs, |ui| {
+                    num_calls += 1;
+                    ui.label("Hovered");
+                });
+            });
+        });
+        assert_eq!(num_calls, 3);
+    }
+}
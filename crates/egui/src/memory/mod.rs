@@ -6,13 +6,27 @@ use ahash::{HashMap, HashSet};
 use epaint::emath::TSTransform;
 
 use crate::{
-    EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2, ViewportId,
-    ViewportIdMap, ViewportIdSet, area, vec2,
+    EventFilter, Id, IdMap, IdSet, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2,
+    ViewportId, ViewportIdMap, ViewportIdSet, area, pass_state::UsedId, vec2,
 };
 
 mod theme;
 pub use theme::{Theme, ThemePreference};
 
+mod window_layout;
+pub use window_layout::{AreaLayout, WindowLayout};
+
+#[cfg(feature = "persistence")]
+mod persistence;
+#[cfg(feature = "persistence_json")]
+pub use persistence::JsonFormat;
+#[cfg(feature = "persistence_postcard")]
+pub use persistence::PostcardFormat;
+#[cfg(feature = "persistence")]
+pub use persistence::{
+    MemoryPersistence, PersistedMemory, PersistenceError, PersistenceFormat, RonFormat,
+};
+
 // ----------------------------------------------------------------------------
 
 /// The data that egui persists between frames.
@@ -177,6 +191,39 @@ impl FocusDirection {
 
 // ----------------------------------------------------------------------------
 
+/// How eager egui is to repaint while the user is not interacting with it.
+///
+/// See [`Options::power_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PowerPolicy {
+    /// All repaint requests are honored.
+    ///
+    /// For instance, a blinking text cursor keeps the app repainting a few times per second for as long as it is focused.
+    #[default]
+    Normal,
+
+    /// Once the user has been idle for a while, low-priority repaint requests are ignored,
+    /// and blinking things stop blinking.
+    ///
+    /// This lets the app stop repainting completely until there is new input,
+    /// which saves battery.
+    ///
+    /// Animations and normal repaint requests are still honored.
+    ///
+    /// See [`crate::Context::request_low_priority_repaint_after_secs`]
+    /// and [`crate::Context::set_idle_callback`].
+    LowPower {
+        /// How many seconds without any input before ignoring low-priority repaint requests.
+        idle_after: f32,
+    },
+}
+
+impl PowerPolicy {
+    /// [`Self::LowPower`] after a few seconds without input.
+    pub const LOW_POWER: Self = Self::LowPower { idle_after: 5.0 };
+}
+
 /// Some global options that you can read and write.
 ///
 /// See also [`crate::style::DebugOptions`].
@@ -209,6 +256,28 @@ pub struct Options {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) system_theme: Option<Theme>,
 
+    /// Apply the accessibility preferences of the operating system
+    /// ([`RawInput::reduced_motion`] and [`RawInput::high_contrast`]) to the styles.
+    ///
+    /// When the OS asks for reduced motion, [`Style::reduced_motion`] is turned on.
+    /// When it asks for high contrast, [`crate::Visuals::high_contrast`] is applied,
+    /// and undone again when it stops asking.
+    ///
+    /// The styles are only changed when the OS preference changes,
+    /// so you can still override them afterwards.
+    ///
+    /// Default: `true`.
+    pub follow_system_accessibility: bool,
+
+    /// The last known [`RawInput::reduced_motion`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    system_reduced_motion: Option<bool>,
+
+    /// The visuals of the dark and light style from before and after [`crate::Visuals::high_contrast`]
+    /// was applied, if it is applied.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    high_contrast_visuals: Option<Box<[HighContrastVisuals; 2]>>,
+
     /// Global zoom factor of the UI.
     ///
     /// This is used to calculate the `pixels_per_point`
@@ -279,11 +348,35 @@ pub struct Options {
     /// if you are changing [`Style::text_styles`], or have a lot of text styles.
     pub preload_font_glyphs: bool,
 
+    /// How many bytes of laid out text to keep in the cache for reuse in later frames,
+    /// on top of the text used in the previous frame.
+    ///
+    /// A larger budget saves CPU when the same texts come and go, e.g. when scrolling a large table.
+    ///
+    /// Default is [`epaint::text::Fonts::DEFAULT_GALLEY_CACHE_BUDGET`].
+    pub galley_cache_budget: usize,
+
     /// Check reusing of [`Id`]s, and show a visual warning on screen when one is found.
     ///
     /// By default this is `true` in debug builds.
     pub warn_on_id_clash: bool,
 
+    /// Keep the [`crate::WidgetInfo`] of each widget of the last pass,
+    /// so widgets can be found by their label and type, e.g. by [`crate::automation::Harness`].
+    ///
+    /// Default is `false`.
+    pub record_widget_info: bool,
+
+    /// Compare the shapes of each layer with the previous frame,
+    /// and report what changed in [`crate::FullOutput::dirty_rect`].
+    ///
+    /// This lets a custom integration repaint only the changed part of the screen;
+    /// `eframe` always repaints everything.
+    /// It costs a copy of all shapes each frame, so it is off by default.
+    ///
+    /// Default is `false`.
+    pub track_dirty_region: bool,
+
     /// Options related to input state handling.
     pub input_options: crate::input_state::InputOptions,
 
@@ -299,6 +392,25 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// If set, the image loaders forget the least recently used images at the end of each pass
+    /// while their caches use more than this many bytes.
+    ///
+    /// Images used in the current or previous pass, or prefetched with
+    /// [`crate::Context::prefetch_image`], are never forgotten.
+    /// Forgotten images are loaded again if they are shown again.
+    ///
+    /// Default is `None` (no limit).
+    pub image_memory_budget: Option<usize>,
+
+    /// Should egui stop repainting for cosmetic reasons (like a blinking text cursor)
+    /// when the user is idle?
+    ///
+    /// Default is [`PowerPolicy::Normal`].
+    pub power_policy: PowerPolicy,
+
+    /// Keyboard shortcuts for cycling through, closing, moving and resizing [`crate::Window`]s.
+    pub window_shortcuts: crate::WindowShortcuts,
 }
 
 impl Default for Options {
@@ -309,6 +421,9 @@ impl Default for Options {
             theme_preference: Default::default(),
             fallback_theme: Theme::Dark,
             system_theme: None,
+            follow_system_accessibility: true,
+            system_reduced_motion: None,
+            high_contrast_visuals: None,
             zoom_factor: 1.0,
             zoom_with_keyboard: true,
             tessellation_options: Default::default(),
@@ -316,18 +431,68 @@ impl Default for Options {
             max_passes: NonZeroUsize::new(2).unwrap(),
             screen_reader: false,
             preload_font_glyphs: true,
+            galley_cache_budget: epaint::text::Fonts::DEFAULT_GALLEY_CACHE_BUDGET,
             warn_on_id_clash: cfg!(debug_assertions),
+            record_widget_info: false,
+            track_dirty_region: false,
 
             // Input:
             input_options: Default::default(),
             reduce_texture_memory: false,
+            image_memory_budget: None,
+            power_policy: PowerPolicy::Normal,
+            window_shortcuts: Default::default(),
         }
     }
 }
 
+/// See [`Options::high_contrast_visuals`].
+#[derive(Clone, Debug, PartialEq)]
+struct HighContrastVisuals {
+    before: crate::Visuals,
+    applied: crate::Visuals,
+}
+
 impl Options {
     pub(crate) fn begin_pass(&mut self, new_raw_input: &RawInput) {
         self.system_theme = new_raw_input.system_theme;
+        if self.follow_system_accessibility {
+            self.apply_system_accessibility(new_raw_input);
+        }
+    }
+
+    fn apply_system_accessibility(&mut self, new_raw_input: &RawInput) {
+        if new_raw_input.reduced_motion != self.system_reduced_motion {
+            self.system_reduced_motion = new_raw_input.reduced_motion;
+            if let Some(reduced_motion) = new_raw_input.reduced_motion {
+                for style in [&mut self.dark_style, &mut self.light_style] {
+                    std::sync::Arc::make_mut(style).reduced_motion = reduced_motion;
+                }
+            }
+        }
+
+        let high_contrast = new_raw_input.high_contrast.unwrap_or(false);
+        if high_contrast != self.high_contrast_visuals.is_some() {
+            let styles = [&mut self.dark_style, &mut self.light_style];
+            if high_contrast {
+                self.high_contrast_visuals = Some(Box::new(styles.map(|style| {
+                    let style = std::sync::Arc::make_mut(style);
+                    let before = style.visuals.clone();
+                    style.visuals = before.clone().high_contrast();
+                    HighContrastVisuals {
+                        before,
+                        applied: style.visuals.clone(),
+                    }
+                })));
+            } else if let Some(visuals) = self.high_contrast_visuals.take() {
+                for (style, visuals) in styles.into_iter().zip(*visuals) {
+                    // Keep any visuals the app set in the meantime:
+                    if style.visuals == visuals.applied {
+                        std::sync::Arc::make_mut(style).visuals = visuals.before;
+                    }
+                }
+            }
+        }
     }
 
     /// The currently active theme (may depend on the system theme).
@@ -365,6 +530,9 @@ impl Options {
             theme_preference,
             fallback_theme: _,
             system_theme: _,
+            follow_system_accessibility,
+            system_reduced_motion: _,
+            high_contrast_visuals: _,
             zoom_factor: _, // TODO(emilk)
             zoom_with_keyboard,
             tessellation_options,
@@ -372,9 +540,15 @@ impl Options {
             max_passes,
             screen_reader: _, // needs to come from the integration
             preload_font_glyphs: _,
+            galley_cache_budget,
             warn_on_id_clash,
+            record_widget_info: _, // for automation, not interactive use
+            track_dirty_region: _, // needs to be supported by the integration
             input_options,
             reduce_texture_memory,
+            image_memory_budget,
+            power_policy,
+            window_shortcuts,
         } = self;
 
         use crate::Widget as _;
@@ -400,7 +574,68 @@ impl Options {
 
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
+                ui.checkbox(
+                    follow_system_accessibility,
+                    "Follow the system reduced motion and high contrast settings",
+                );
+
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.horizontal(|ui| {
+                    let mut limited = image_memory_budget.is_some();
+                    ui.checkbox(&mut limited, "Image memory budget:");
+                    if limited {
+                        let mut mib = image_memory_budget.unwrap_or(256 * 1024 * 1024) as f64
+                            / (1024.0 * 1024.0);
+                        ui.add(
+                            crate::DragValue::new(&mut mib)
+                                .range(0.0..=16384.0)
+                                .suffix(" MiB"),
+                        );
+                        *image_memory_budget = Some((mib * 1024.0 * 1024.0) as usize);
+                    } else {
+                        *image_memory_budget = None;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Text layout cache budget:");
+                    let mut mib = *galley_cache_budget as f64 / (1024.0 * 1024.0);
+                    if ui
+                        .add(
+                            crate::DragValue::new(&mut mib)
+                                .range(0.0..=256.0)
+                                .speed(0.1)
+                                .suffix(" MiB"),
+                        )
+                        .changed()
+                    {
+                        *galley_cache_budget = (mib * 1024.0 * 1024.0) as usize;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Power policy:");
+                    ui.radio_value(power_policy, PowerPolicy::Normal, "Normal");
+                    if ui
+                        .radio(
+                            matches!(power_policy, PowerPolicy::LowPower { .. }),
+                            "Low power",
+                        )
+                        .clicked()
+                        && *power_policy == PowerPolicy::Normal
+                    {
+                        *power_policy = PowerPolicy::LOW_POWER;
+                    }
+                    if let PowerPolicy::LowPower { idle_after } = power_policy {
+                        ui.add(
+                            crate::DragValue::new(idle_after)
+                                .range(0.0..=60.0)
+                                .suffix(" s"),
+                        )
+                        .on_hover_text("Seconds without input before going idle");
+                    }
+                });
             });
 
         CollapsingHeader::new("🎑 Style")
@@ -430,6 +665,12 @@ impl Options {
                 input_options.ui(ui);
             });
 
+        CollapsingHeader::new("🗖 Window shortcuts")
+            .default_open(false)
+            .show(ui, |ui| {
+                window_shortcuts.ui(ui);
+            });
+
         ui.vertical_centered(|ui| crate::reset_button(ui, self, "Reset all"));
     }
 }
@@ -458,6 +699,11 @@ pub(crate) struct InteractionState {
     /// as that can only happen after the mouse has moved a bit
     /// (at least if the widget is interesated in both clicks and drags).
     pub potential_drag_id: Option<Id>,
+
+    /// The widget that gets all pointer events, wherever the pointer is.
+    ///
+    /// See [`crate::Response::capture_pointer`].
+    pub pointer_capture: Option<Id>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -475,16 +721,43 @@ pub(crate) struct Focus {
     #[cfg(feature = "accesskit")]
     id_requested_by_accesskit: Option<accesskit::NodeId>,
 
-    /// If set, the next widget that is interested in focus will automatically get it.
-    /// Probably because the user pressed Tab.
-    give_to_next: bool,
+    /// The widgets interested in focus this pass, in the order they were added.
+    tab_stops: Vec<TabStop>,
+
+    /// Where each widget is in [`Self::tab_stops`].
+    tab_stop_indices: IdMap<usize>,
+
+    /// The focus scopes we are currently in, innermost last. See [`crate::Ui::focus_scope`].
+    scope_stack: Vec<Id>,
+
+    /// The focus scope each focus scope is in, for this pass.
+    scope_parents: IdMap<Option<Id>>,
+
+    /// Move the focus to the next or previous widget in this focus scope at the end of the pass.
+    move_in_scope: Option<(FocusDirection, Id)>,
 
-    /// The last widget interested in focus.
-    last_interested: Option<Id>,
+    /// Where to put the focus back when a popup or modal closes,
+    /// see [`Memory::restore_focus_when_closed`].
+    restore_points: IdMap<RestorePoint>,
 
     /// Set when looking for widget with navigational keys like arrows, tab, shift+tab.
     focus_direction: FocusDirection,
 
+    /// Was [`Self::focus_direction`] set by a [`crate::Event::GamepadNav`]?
+    focus_direction_from_gamepad: bool,
+
+    /// Is the user navigating with a gamepad? Cleared when the pointer is used.
+    navigating_with_gamepad: bool,
+
+    /// Widgets that the gamepad navigation skips this pass, see [`crate::Response::gamepad_navigable`].
+    not_gamepad_navigable: IdSet,
+
+    /// A widget that was given focus with the keyboard, and should be scrolled into view.
+    reveal: Option<Id>,
+
+    /// The widget the arrow keys (or a gamepad) moved the focus to at the end of the previous pass.
+    focused_by_arrow_keys: Option<Id>,
+
     /// The top-most modal layer from the previous frame.
     top_modal_layer: Option<LayerId>,
 
@@ -495,6 +768,27 @@ pub(crate) struct Focus {
     focus_widgets_cache: IdMap<Rect>,
 }
 
+/// A widget that can be focused with tab and shift+tab.
+#[derive(Clone, Copy, Debug)]
+struct TabStop {
+    id: Id,
+
+    /// The innermost focus scope of the widget.
+    scope: Option<Id>,
+
+    /// See [`crate::Response::tab_index`].
+    tab_index: i32,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RestorePoint {
+    /// The widget that had focus when the popup or modal opened.
+    focused: Option<Id>,
+
+    /// Is the popup or modal still open?
+    open: bool,
+}
+
 /// The widget with focus.
 #[derive(Clone, Copy, Debug)]
 struct FocusWidget {
@@ -536,9 +830,35 @@ impl Focus {
             self.id_requested_by_accesskit = None;
         }
 
+        self.tab_stops.clear();
+        self.tab_stop_indices.clear();
+        self.scope_stack.clear();
+        self.scope_parents.clear();
+        self.not_gamepad_navigable.clear();
         self.focus_direction = FocusDirection::None;
+        self.focus_direction_from_gamepad = false;
 
         for event in &new_input.events {
+            match event {
+                crate::Event::GamepadNav(nav) => {
+                    self.navigating_with_gamepad = true;
+                    if let Some(direction) = match nav {
+                        crate::GamepadNav::Up => Some(FocusDirection::Up),
+                        crate::GamepadNav::Right => Some(FocusDirection::Right),
+                        crate::GamepadNav::Down => Some(FocusDirection::Down),
+                        crate::GamepadNav::Left => Some(FocusDirection::Left),
+                        crate::GamepadNav::Activate | crate::GamepadNav::Back => None,
+                    } {
+                        self.focus_direction = direction;
+                        self.focus_direction_from_gamepad = true;
+                    }
+                }
+                crate::Event::PointerMoved(_) | crate::Event::PointerButton { .. } => {
+                    self.navigating_with_gamepad = false;
+                }
+                _ => {}
+            }
+
             if !event_filter.matches(event) {
                 if let crate::Event::Key {
                     key,
@@ -553,7 +873,8 @@ impl Focus {
                         crate::Key::ArrowDown => Some(FocusDirection::Down),
                         crate::Key::ArrowLeft => Some(FocusDirection::Left),
 
-                        crate::Key::Tab => {
+                        // Ctrl+Tab is for switching tabs, see `TabBar`:
+                        crate::Key::Tab if !modifiers.ctrl => {
                             if modifiers.shift {
                                 Some(FocusDirection::Previous)
                             } else {
@@ -567,6 +888,7 @@ impl Focus {
                         _ => None,
                     } {
                         self.focus_direction = cardinality;
+                        self.focus_direction_from_gamepad = false;
                     }
                 }
             }
@@ -585,13 +907,37 @@ impl Focus {
         }
     }
 
-    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
+    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<UsedId>) {
+        self.move_in_tab_order();
+
+        self.focused_by_arrow_keys = None;
         if self.focus_direction.is_cardinal() {
-            if let Some(found_widget) = self.find_widget_in_direction(used_ids) {
+            let found_widget = if self.focus_direction_from_gamepad && self.focused().is_none() {
+                // Nothing to move from, so start at the first widget:
+                self.tab_stops
+                    .iter()
+                    .find(|stop| {
+                        0 <= stop.tab_index && !self.not_gamepad_navigable.contains(&stop.id)
+                    })
+                    .map(|stop| stop.id)
+            } else {
+                self.find_widget_in_direction(used_ids)
+            };
+            if let Some(found_widget) = found_widget {
                 self.focused_widget = Some(FocusWidget::new(found_widget));
+                self.reveal = Some(found_widget);
+                self.focused_by_arrow_keys = Some(found_widget);
             }
         }
 
+        if self.reveal.is_some()
+            && self.reveal != self.focused()
+            && self.reveal != self.id_next_frame
+        {
+            // Focus moved on before the widget could be revealed.
+            self.reveal = None;
+        }
+
         if let Some(focused_widget) = self.focused_widget {
             // Allow calling `request_focus` one frame and not using it until next frame
             let recently_gained_focus = self.id_previous_frame != Some(focused_widget.id);
@@ -602,11 +948,25 @@ impl Focus {
             }
         }
 
-        self.top_modal_layer = self.top_modal_layer_current_frame.take();
-    }
+        let no_focus = self.focused_widget.is_none() && self.id_next_frame.is_none();
+        let mut restore = None;
+        self.restore_points.retain(|_, point| {
+            if point.open {
+                point.open = false;
+                true
+            } else {
+                // Closed, so give the focus back, unless it moved elsewhere:
+                if no_focus {
+                    restore = restore.or(point.focused);
+                }
+                false
+            }
+        });
+        if restore.is_some() {
+            self.id_next_frame = restore;
+        }
 
-    pub(crate) fn had_focus_last_frame(&self, id: Id) -> bool {
-        self.id_previous_frame == Some(id)
+        self.top_modal_layer = self.top_modal_layer_current_frame.take();
     }
 
     fn interested_in_focus(&mut self, id: Id) {
@@ -615,7 +975,6 @@ impl Focus {
             if self.id_requested_by_accesskit == Some(id.accesskit_id()) {
                 self.focused_widget = Some(FocusWidget::new(id));
                 self.id_requested_by_accesskit = None;
-                self.give_to_next = false;
                 self.reset_focus();
             }
         }
@@ -625,35 +984,98 @@ impl Focus {
             .entry(id)
             .or_insert(Rect::EVERYTHING);
 
-        if self.give_to_next && !self.had_focus_last_frame(id) {
-            self.focused_widget = Some(FocusWidget::new(id));
-            self.give_to_next = false;
-        } else if self.focused() == Some(id) {
-            if self.focus_direction == FocusDirection::Next {
-                self.focused_widget = None;
-                self.give_to_next = true;
-                self.reset_focus();
-            } else if self.focus_direction == FocusDirection::Previous {
-                self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
-                self.reset_focus();
+        if let std::collections::hash_map::Entry::Vacant(entry) = self.tab_stop_indices.entry(id) {
+            entry.insert(self.tab_stops.len());
+            self.tab_stops.push(TabStop {
+                id,
+                scope: self.scope_stack.last().copied(),
+                tab_index: 0,
+            });
+        }
+    }
+
+    fn set_tab_index(&mut self, id: Id, tab_index: i32) {
+        if let Some(&index) = self.tab_stop_indices.get(&id) {
+            self.tab_stops[index].tab_index = tab_index;
+        }
+    }
+
+    fn begin_scope(&mut self, scope: Id) {
+        self.scope_parents
+            .insert(scope, self.scope_stack.last().copied());
+        self.scope_stack.push(scope);
+    }
+
+    fn end_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    /// Is a widget in the focus scope `widget_scope` also in `scope`?
+    fn is_in_scope(&self, widget_scope: Option<Id>, scope: Id) -> bool {
+        let mut widget_scope = widget_scope;
+        while let Some(inner) = widget_scope {
+            if inner == scope {
+                return true;
             }
-        } else if self.focus_direction == FocusDirection::Next
-            && self.focused_widget.is_none()
-            && !self.give_to_next
-        {
-            // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
-            self.focused_widget = Some(FocusWidget::new(id));
-            self.reset_focus();
-        } else if self.focus_direction == FocusDirection::Previous
-            && self.focused_widget.is_none()
-            && !self.give_to_next
-        {
-            // nothing has focus and the user pressed Shift+Tab - give focus to the last widgets that wants it:
-            self.focused_widget = self.last_interested.map(FocusWidget::new);
-            self.reset_focus();
+            widget_scope = self.scope_parents.get(&inner).copied().flatten();
+        }
+        false
+    }
+
+    /// Handle tab and shift+tab, and [`Memory::focus_next_in`].
+    ///
+    /// Tab moves the focus within the focus scope of the focused widget, wrapping around at the end.
+    fn move_in_tab_order(&mut self) {
+        let (direction, scope) = if let Some((direction, scope)) = self.move_in_scope.take() {
+            (direction, Some(scope))
+        } else if matches!(
+            self.focus_direction,
+            FocusDirection::Next | FocusDirection::Previous
+        ) {
+            let scope = self
+                .focused()
+                .and_then(|id| self.tab_stop_indices.get(&id))
+                .and_then(|&index| self.tab_stops[index].scope);
+            (self.focus_direction, scope)
+        } else {
+            return;
+        };
+
+        // Positive tab indices come first, then the rest in the order they were added:
+        let mut order: Vec<&TabStop> = self
+            .tab_stops
+            .iter()
+            .filter(|stop| {
+                0 <= stop.tab_index && scope.is_none_or(|scope| self.is_in_scope(stop.scope, scope))
+            })
+            .collect();
+        order.sort_by_key(|stop| {
+            if 0 < stop.tab_index {
+                stop.tab_index
+            } else {
+                i32::MAX
+            }
+        });
+        if order.is_empty() {
+            return;
         }
 
-        self.last_interested = Some(id);
+        let current = self
+            .focused()
+            .and_then(|id| order.iter().position(|stop| stop.id == id));
+        let n = order.len();
+        let next = match (direction, current) {
+            (FocusDirection::Previous, Some(i)) => (i + n - 1) % n,
+            (FocusDirection::Previous, None) => n - 1,
+            (_, Some(i)) => (i + 1) % n,
+            (_, None) => 0,
+        };
+        let next = order[next].id;
+
+        // Delayed a frame so `gained_focus` works:
+        self.id_next_frame = Some(next);
+        self.reveal = Some(next);
+        self.reset_focus();
     }
 
     fn set_modal_layer(&mut self, layer_id: LayerId) {
@@ -668,7 +1090,7 @@ impl Focus {
         self.focus_direction = FocusDirection::None;
     }
 
-    fn find_widget_in_direction(&mut self, new_rects: &IdMap<Rect>) -> Option<Id> {
+    fn find_widget_in_direction(&mut self, new_rects: &IdMap<UsedId>) -> Option<Id> {
         // NOTE: `new_rects` here include some widgets _not_ interested in focus.
 
         /// * negative if `a` is left of `b`
@@ -698,8 +1120,8 @@ impl Focus {
 
         // Update cache with new rects
         self.focus_widgets_cache.retain(|id, old_rect| {
-            if let Some(new_rect) = new_rects.get(id) {
-                *old_rect = *new_rect;
+            if let Some(used) = new_rects.get(id) {
+                *old_rect = used.rect;
                 true // Keep the item
             } else {
                 false // Remove the item
@@ -712,7 +1134,10 @@ impl Focus {
         let mut best_id = None;
 
         for (candidate_id, candidate_rect) in &self.focus_widgets_cache {
-            if *candidate_id == current_focused.id {
+            if *candidate_id == current_focused.id
+                || (self.focus_direction_from_gamepad
+                    && self.not_gamepad_navigable.contains(candidate_id))
+            {
                 continue;
             }
 
@@ -760,6 +1185,7 @@ impl Memory {
         // self.interactions  is handled elsewhere
 
         self.options.begin_pass(new_raw_input);
+        self.data.begin_pass();
 
         self.focus
             .entry(self.viewport_id)
@@ -767,7 +1193,7 @@ impl Memory {
             .begin_pass(new_raw_input);
     }
 
-    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
+    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<UsedId>) {
         self.caches.update();
         self.areas_mut().end_pass();
         self.focus_mut().end_pass(used_ids);
@@ -840,6 +1266,14 @@ impl Memory {
         !self.had_focus_last_frame(id) && self.has_focus(id)
     }
 
+    /// Did the arrow keys just move the focus to this widget?
+    ///
+    /// Used for selection that follows the focus, e.g. in a group of radio buttons.
+    pub(crate) fn gained_focus_from_arrow_keys(&self, id: Id) -> bool {
+        self.focus()
+            .is_some_and(|f| f.focused_by_arrow_keys == Some(id) && f.focused() == Some(id))
+    }
+
     /// Does this widget have keyboard focus?
     ///
     /// This function does not consider whether the UI as a whole (e.g. window)
@@ -879,6 +1313,20 @@ impl Memory {
         self.focus_mut().focused_widget = Some(FocusWidget::new(id));
     }
 
+    /// The event filter of the focused widget, see [`Self::set_focus_lock_filter`].
+    pub(crate) fn focused_event_filter(&self) -> EventFilter {
+        self.focus()
+            .and_then(|focus| focus.focused_widget)
+            .map(|widget| widget.filter)
+            .unwrap_or_default()
+    }
+
+    /// Don't move the keyboard focus with the arrow keys or tab this pass,
+    /// e.g. because a widget already handled them.
+    pub(crate) fn cancel_focus_move(&mut self) {
+        self.focus_mut().reset_focus();
+    }
+
     /// Surrender keyboard focus for a specific widget.
     /// See also [`crate::Response::surrender_focus`].
     #[inline(always)]
@@ -886,6 +1334,11 @@ impl Memory {
         let focus = self.focus_mut();
         if focus.focused() == Some(id) {
             focus.focused_widget = None;
+
+            // Giving up the focus on purpose means it shouldn't come back when a popup closes:
+            for point in focus.restore_points.values_mut() {
+                point.focused = None;
+            }
         }
     }
 
@@ -930,6 +1383,92 @@ impl Memory {
         self.focus_mut().interested_in_focus(id);
     }
 
+    /// Will the focus move to another widget at the start of the next pass?
+    pub(crate) fn has_pending_focus_change(&self) -> bool {
+        self.focus().is_some_and(|f| f.id_next_frame.is_some())
+    }
+
+    /// See [`crate::Response::gamepad_navigable`].
+    pub(crate) fn set_gamepad_navigable(&mut self, id: Id, navigable: bool) {
+        let not_navigable = &mut self.focus_mut().not_gamepad_navigable;
+        if navigable {
+            not_navigable.remove(&id);
+        } else {
+            not_navigable.insert(id);
+        }
+    }
+
+    /// Is the user navigating with a gamepad, see [`crate::Event::GamepadNav`]?
+    ///
+    /// This stays on until the pointer is used, so you can e.g. show gamepad button hints meanwhile.
+    pub fn is_navigating_with_gamepad(&self) -> bool {
+        self.focus().is_some_and(|f| f.navigating_with_gamepad)
+    }
+
+    /// Set the position of a widget in the tab order, see [`crate::Response::tab_index`].
+    pub(crate) fn set_tab_index(&mut self, id: Id, tab_index: i32) {
+        self.focus_mut().set_tab_index(id, tab_index);
+    }
+
+    /// Widgets added from now on are in this focus scope, see [`crate::Ui::focus_scope`].
+    pub(crate) fn begin_focus_scope(&mut self, scope: Id) {
+        self.focus_mut().begin_scope(scope);
+    }
+
+    /// Ends the focus scope started with [`Self::begin_focus_scope`].
+    pub(crate) fn end_focus_scope(&mut self) {
+        self.focus_mut().end_scope();
+    }
+
+    /// Move the focus to the next widget in the given focus scope, as if tab was pressed in it.
+    ///
+    /// If no widget in the scope has focus, the first one gets it.
+    /// The focus moves at the end of the pass. See [`crate::Ui::focus_scope`].
+    pub fn focus_next_in(&mut self, scope: Id) {
+        self.focus_mut().move_in_scope = Some((FocusDirection::Next, scope));
+    }
+
+    /// Move the focus to the previous widget in the given focus scope, as if shift+tab was pressed in it.
+    ///
+    /// If no widget in the scope has focus, the last one gets it.
+    /// The focus moves at the end of the pass. See [`crate::Ui::focus_scope`].
+    pub fn focus_previous_in(&mut self, scope: Id) {
+        self.focus_mut().move_in_scope = Some((FocusDirection::Previous, scope));
+    }
+
+    /// Give the focus back to the widget that had it when `id` was opened,
+    /// once this is no longer called for `id`.
+    ///
+    /// Call this every pass while e.g. a popup or modal is open.
+    /// The focus is only given back if nothing else has focus when it closes,
+    /// e.g. because the focused widget was in the popup, or escape was pressed.
+    /// [`crate::Popup`] and [`crate::Modal`] do this automatically.
+    pub fn restore_focus_when_closed(&mut self, id: Id) {
+        let focus = self.focus_mut();
+        let focused = focus.focused();
+        focus
+            .restore_points
+            .entry(id)
+            .or_insert(RestorePoint {
+                focused,
+                open: true,
+            })
+            .open = true;
+    }
+
+    /// Was this widget just given focus with the keyboard, so that it should be scrolled into view?
+    ///
+    /// Returns `true` only once per focus change.
+    pub(crate) fn take_focus_reveal(&mut self, id: Id) -> bool {
+        let focus = self.focus_mut();
+        if focus.reveal == Some(id) {
+            focus.reveal = None;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Limit focus to widgets on the given layer and above.
     /// If this is called multiple times per frame, the top layer wins.
     pub fn set_modal_layer(&mut self, layer_id: LayerId) {
@@ -1193,6 +1732,27 @@ impl Areas {
         }
     }
 
+    /// Like [`Self::set_state`], but without marking the area as visible.
+    pub(crate) fn restore_state(&mut self, layer_id: LayerId, state: area::AreaState) {
+        self.areas.insert(layer_id.id, state);
+        if !self.order.contains(&layer_id) {
+            self.order.push(layer_id);
+        }
+    }
+
+    /// Put the given layers on top of the others in their [`Order`], in the given back-to-front order.
+    pub(crate) fn restore_order(&mut self, back_to_front: &[LayerId]) {
+        self.order.retain(|layer| !back_to_front.contains(layer));
+        self.order.extend_from_slice(back_to_front);
+        self.order.sort_by_key(|layer| layer.order); // stable sort
+        self.order_map = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, i))
+            .collect();
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(
         &self,
@@ -1252,6 +1812,14 @@ impl Areas {
         }
     }
 
+    /// Put this layer below all other layers of the same [`Order`], e.g. to cycle through windows.
+    pub fn move_to_bottom(&mut self, layer_id: LayerId) {
+        self.wants_to_be_on_top.remove(&layer_id);
+        self.order.retain(|layer| *layer != layer_id);
+        // The order is sorted by `Order` at the end of the pass, keeping this first of its kind:
+        self.order.insert(0, layer_id);
+    }
+
     /// Mark the `child` layer as a sublayer of `parent`.
     ///
     /// Sublayers are moved directly above the parent layer at the end of the frame. This is mainly
@@ -0,0 +1,674 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use epaint::emath::TSTransform;
+
+use crate::{
+    AreaState, Id, IdMap, IdSet, LayerId, ViewportIdMap,
+    util::{IdTypeMap, IdTypeMapEntry, id_type_map::TypeId},
+};
+
+use super::{Areas, Memory, Options};
+
+/// The parts of [`Memory`] that survive a restart, as written by a [`PersistenceFormat`].
+///
+/// You normally don't need to touch this directly; use [`MemoryPersistence`] instead.
+/// It is handed to the migrations registered with [`MemoryPersistence::migration`],
+/// so that they can fix up state saved by an older version of your app.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct PersistedMemory {
+    /// The schema version of the app that saved this, see [`MemoryPersistence::version`].
+    pub version: u32,
+
+    /// See [`Memory::options`].
+    pub options: Options,
+
+    /// See [`Memory::data`].
+    pub data: IdTypeMap,
+
+    /// Window positions and stacking order, per viewport.
+    areas: ViewportIdMap<PersistedAreas>,
+
+    /// See [`Memory::to_global`].
+    to_global: Vec<(LayerId, TSTransform)>,
+}
+
+/// The persisted part of [`Areas`].
+///
+/// Unlike [`Areas`] this has no maps keyed by [`LayerId`], so it can be written as JSON.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+struct PersistedAreas {
+    areas: IdMap<AreaState>,
+
+    /// Back-to-front, top is last.
+    order: Vec<LayerId>,
+
+    visible: Vec<LayerId>,
+}
+
+impl From<&Areas> for PersistedAreas {
+    fn from(areas: &Areas) -> Self {
+        Self {
+            areas: areas.areas.clone(),
+            order: areas.order.clone(),
+            visible: areas.visible_areas_last_frame.iter().copied().collect(),
+        }
+    }
+}
+
+impl From<PersistedAreas> for Areas {
+    fn from(persisted: PersistedAreas) -> Self {
+        let PersistedAreas {
+            areas,
+            order,
+            visible,
+        } = persisted;
+        let order_map = order.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        Self {
+            areas,
+            visible_areas_last_frame: visible.into_iter().collect(),
+            order,
+            order_map,
+            ..Default::default()
+        }
+    }
+}
+
+impl PersistedMemory {
+    fn from_memory(memory: &Memory, version: u32) -> Self {
+        Self {
+            version,
+            options: memory.options.clone(),
+            data: memory.data.clone(),
+            areas: memory
+                .areas
+                .iter()
+                .map(|(viewport_id, areas)| (*viewport_id, areas.into()))
+                .collect(),
+            to_global: memory
+                .to_global
+                .iter()
+                .map(|(layer_id, transform)| (*layer_id, *transform))
+                .collect(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Why [`Memory`] could not be saved or loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PersistenceError {
+    /// The [`PersistenceFormat`] failed to encode the memory.
+    Encode(String),
+
+    /// The [`PersistenceFormat`] failed to decode the bytes,
+    /// e.g. because they were written in another format or by an incompatible version of egui.
+    Decode(String),
+
+    /// The memory was saved by a newer version of the app.
+    NewerVersion {
+        /// The version the memory was saved with.
+        saved: u32,
+
+        /// See [`MemoryPersistence::version`].
+        current: u32,
+    },
+
+    /// There is no [`MemoryPersistence::migration`] from this version.
+    NoMigration {
+        /// The version that could not be migrated.
+        from: u32,
+    },
+
+    /// The memory is larger than [`MemoryPersistence::max_bytes`], even with all widget state dropped.
+    TooLarge {
+        /// The size of the smallest encoding.
+        num_bytes: usize,
+
+        /// See [`MemoryPersistence::max_bytes`].
+        max_bytes: usize,
+    },
+}
+
+impl std::fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Encode(err) => write!(f, "Failed to encode egui memory: {err}"),
+            Self::Decode(err) => write!(f, "Failed to decode egui memory: {err}"),
+            Self::NewerVersion { saved, current } => write!(
+                f,
+                "egui memory was saved with version {saved}, which is newer than the current version {current}"
+            ),
+            Self::NoMigration { from } => {
+                write!(f, "No migration of egui memory from version {from}")
+            }
+            Self::TooLarge {
+                num_bytes,
+                max_bytes,
+            } => write!(
+                f,
+                "egui memory is {num_bytes} bytes, which is more than the budget of {max_bytes} bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PersistenceError {}
+
+// ----------------------------------------------------------------------------
+
+/// Turns [`PersistedMemory`] into bytes, and back again.
+///
+/// egui comes with [`RonFormat`], `JsonFormat` (feature `persistence_json`)
+/// and `PostcardFormat` (feature `persistence_postcard`).
+/// Implement this to use any other format.
+pub trait PersistenceFormat: Send + Sync {
+    /// Write the memory as bytes.
+    ///
+    /// # Errors
+    /// [`PersistenceError::Encode`] if the memory can't be written in this format.
+    fn encode(&self, memory: &PersistedMemory) -> Result<Vec<u8>, PersistenceError>;
+
+    /// Read memory written by [`Self::encode`].
+    ///
+    /// # Errors
+    /// [`PersistenceError::Decode`] if the bytes are not valid in this format.
+    fn decode(&self, bytes: &[u8]) -> Result<PersistedMemory, PersistenceError>;
+}
+
+/// Persist [`Memory`] as [RON](https://github.com/ron-rs/ron).
+///
+/// This is the same format that `eframe` uses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RonFormat;
+
+impl PersistenceFormat for RonFormat {
+    fn encode(&self, memory: &PersistedMemory) -> Result<Vec<u8>, PersistenceError> {
+        ron::to_string(memory)
+            .map(String::into_bytes)
+            .map_err(|err| PersistenceError::Encode(err.to_string()))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<PersistedMemory, PersistenceError> {
+        let ron =
+            std::str::from_utf8(bytes).map_err(|err| PersistenceError::Decode(err.to_string()))?;
+        ron::from_str(ron).map_err(|err| PersistenceError::Decode(err.to_string()))
+    }
+}
+
+/// Persist [`Memory`] as JSON, e.g. for web local storage.
+#[cfg(feature = "persistence_json")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonFormat;
+
+#[cfg(feature = "persistence_json")]
+impl PersistenceFormat for JsonFormat {
+    fn encode(&self, memory: &PersistedMemory) -> Result<Vec<u8>, PersistenceError> {
+        serde_json::to_vec(memory).map_err(|err| PersistenceError::Encode(err.to_string()))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<PersistedMemory, PersistenceError> {
+        serde_json::from_slice(bytes).map_err(|err| PersistenceError::Decode(err.to_string()))
+    }
+}
+
+/// Persist [`Memory`] in the compact binary [`postcard`](https://docs.rs/postcard) format.
+///
+/// Postcard is not self-describing, so a decode will fail after any change to the persisted types,
+/// e.g. after upgrading egui. The app then starts with fresh [`Memory`].
+#[cfg(feature = "persistence_postcard")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PostcardFormat;
+
+#[cfg(feature = "persistence_postcard")]
+impl PersistenceFormat for PostcardFormat {
+    fn encode(&self, memory: &PersistedMemory) -> Result<Vec<u8>, PersistenceError> {
+        postcard::to_stdvec(memory).map_err(|err| PersistenceError::Encode(err.to_string()))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<PersistedMemory, PersistenceError> {
+        postcard::from_bytes(bytes).map_err(|err| PersistenceError::Decode(err.to_string()))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+type Migration = Arc<dyn Fn(&mut PersistedMemory) + Send + Sync>;
+
+/// Controls how [`Memory`] is saved and loaded, and what survives a restart.
+///
+/// Save with [`crate::Context::save_memory`] and load with [`crate::Context::load_memory`].
+/// Where the bytes go is up to you, e.g. a file or web local storage.
+/// If you use this with `eframe`, return `false` from `App::persist_egui_memory`
+/// and save the bytes in `App::save` instead.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// use egui::{Id, MemoryPersistence, RonFormat};
+///
+/// let persistence = MemoryPersistence::new(RonFormat)
+///     .version(2)
+///     .migration(1, |memory| memory.data.remove::<f32>(Id::new("old_zoom")))
+///     .exclude_id(Id::new("search_results"))
+///     .max_bytes(64 * 1024);
+///
+/// let bytes = ctx.save_memory(&persistence).unwrap();
+/// // … and on the next start:
+/// ctx.load_memory(&persistence, &bytes).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct MemoryPersistence {
+    format: Arc<dyn PersistenceFormat>,
+    version: u32,
+    migrations: BTreeMap<u32, Migration>,
+    persist_options: bool,
+    persist_areas: bool,
+    excluded_ids: IdSet,
+    excluded_types: ahash::HashSet<TypeId>,
+    filter: Option<Arc<dyn Fn(&IdTypeMapEntry) -> bool + Send + Sync>>,
+    max_bytes: Option<usize>,
+}
+
+impl std::fmt::Debug for MemoryPersistence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryPersistence")
+            .field("version", &self.version)
+            .field("migrations", &self.migrations.keys().collect::<Vec<_>>())
+            .field("persist_options", &self.persist_options)
+            .field("persist_areas", &self.persist_areas)
+            .field("excluded_ids", &self.excluded_ids)
+            .field("excluded_types", &self.excluded_types)
+            .field("max_bytes", &self.max_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for MemoryPersistence {
+    fn default() -> Self {
+        Self::new(RonFormat)
+    }
+}
+
+impl MemoryPersistence {
+    /// Persist everything, using the given format.
+    pub fn new(format: impl PersistenceFormat + 'static) -> Self {
+        Self {
+            format: Arc::new(format),
+            version: 0,
+            migrations: Default::default(),
+            persist_options: true,
+            persist_areas: true,
+            excluded_ids: Default::default(),
+            excluded_types: Default::default(),
+            filter: None,
+            max_bytes: None,
+        }
+    }
+
+    /// Persist as JSON.
+    #[cfg(feature = "persistence_json")]
+    pub fn json() -> Self {
+        Self::new(JsonFormat)
+    }
+
+    /// Persist in the binary postcard format.
+    #[cfg(feature = "persistence_postcard")]
+    pub fn postcard() -> Self {
+        Self::new(PostcardFormat)
+    }
+
+    /// The schema version of your persisted state.
+    ///
+    /// Bump this whenever you change a persisted type in an incompatible way,
+    /// and register a [`Self::migration`] from the old version.
+    ///
+    /// Default: 0.
+    #[inline]
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Upgrade memory saved with version `from` to version `from + 1`.
+    ///
+    /// When loading, the migrations are run in order until the memory is at [`Self::version`].
+    /// If one is missing, loading fails with [`PersistenceError::NoMigration`].
+    ///
+    /// Persisted values in [`PersistedMemory::data`] that have not been read yet are
+    /// still serialized, so a migration can simply remove them, or read them with
+    /// [`IdTypeMap::get_persisted`] and insert a converted value.
+    pub fn migration(
+        mut self,
+        from: u32,
+        migrate: impl Fn(&mut PersistedMemory) + Send + Sync + 'static,
+    ) -> Self {
+        self.migrations.insert(from, Arc::new(migrate));
+        self
+    }
+
+    /// Persist [`Memory::options`], e.g. the zoom factor and theme?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn persist_options(mut self, persist_options: bool) -> Self {
+        self.persist_options = persist_options;
+        self
+    }
+
+    /// Persist the positions and stacking order of [`crate::Area`]s and [`crate::Window`]s?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn persist_areas(mut self, persist_areas: bool) -> Self {
+        self.persist_areas = persist_areas;
+        self
+    }
+
+    /// Don't persist anything stored under this [`Id`].
+    ///
+    /// This covers all values in [`Memory::data`] with this id, whatever their type,
+    /// and the position of an [`crate::Area`] or [`crate::Window`] with this id.
+    #[inline]
+    pub fn exclude_id(mut self, id: impl Into<Id>) -> Self {
+        self.excluded_ids.insert(id.into());
+        self
+    }
+
+    /// Don't persist any values of this type in [`Memory::data`].
+    #[inline]
+    pub fn exclude_type<T: 'static>(mut self) -> Self {
+        self.excluded_types.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Only persist the values in [`Memory::data`] for which this returns `true`.
+    ///
+    /// This is applied after [`Self::exclude_id`] and [`Self::exclude_type`].
+    #[inline]
+    pub fn filter(
+        mut self,
+        keep: impl Fn(&IdTypeMapEntry) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filter = Some(Arc::new(keep));
+        self
+    }
+
+    /// The largest the saved memory may be, in bytes.
+    ///
+    /// If it would be larger, the values in [`Memory::data`] that were read the longest time ago
+    /// are dropped until it fits.
+    ///
+    /// Default: no limit.
+    #[inline]
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    fn keep(&self, entry: &IdTypeMapEntry) -> bool {
+        !self.excluded_ids.contains(&entry.stored_id())
+            && !self.excluded_types.contains(&entry.type_id)
+            && self.filter.as_ref().is_none_or(|keep| keep(entry))
+    }
+
+    /// Remove everything that shouldn't be persisted.
+    fn apply_filters(&self, memory: &mut PersistedMemory) {
+        if !self.persist_options {
+            memory.options = Default::default();
+        }
+        if self.persist_areas {
+            for areas in memory.areas.values_mut() {
+                areas.areas.retain(|id, _| !self.excluded_ids.contains(id));
+                areas
+                    .order
+                    .retain(|layer_id| !self.excluded_ids.contains(&layer_id.id));
+                areas
+                    .visible
+                    .retain(|layer_id| !self.excluded_ids.contains(&layer_id.id));
+            }
+            memory
+                .to_global
+                .retain(|(layer_id, _)| !self.excluded_ids.contains(&layer_id.id));
+        } else {
+            memory.areas.clear();
+            memory.to_global.clear();
+        }
+        memory
+            .data
+            .retain(|entry| !entry.persisted || self.keep(entry));
+    }
+
+    /// Encode the memory, respecting the filters and size budget.
+    ///
+    /// # Errors
+    /// [`PersistenceError::Encode`] from the [`PersistenceFormat`],
+    /// or [`PersistenceError::TooLarge`] if the memory doesn't fit in [`Self::max_bytes`].
+    pub fn save(&self, memory: &Memory) -> Result<Vec<u8>, PersistenceError> {
+        profiling::function_scope!();
+
+        let mut persisted = PersistedMemory::from_memory(memory, self.version);
+        self.apply_filters(&mut persisted);
+
+        let mut bytes = self.format.encode(&persisted)?;
+
+        let Some(max_bytes) = self.max_bytes else {
+            return Ok(bytes);
+        };
+
+        // Drop the values that were read the longest time ago first:
+        let mut entries: Vec<IdTypeMapEntry> =
+            persisted.data.entries().filter(|e| e.persisted).collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.passes_since_used));
+        let mut entries = entries.into_iter();
+
+        while max_bytes < bytes.len() {
+            let excess = bytes.len() - max_bytes;
+            let mut num_removed = 0;
+            let mut bytes_removed = 0;
+            for entry in entries.by_ref() {
+                persisted.data.remove_entry(&entry);
+                num_removed += 1;
+                bytes_removed += entry.num_bytes;
+                if excess <= bytes_removed {
+                    break;
+                }
+            }
+            if num_removed == 0 {
+                return Err(PersistenceError::TooLarge {
+                    num_bytes: bytes.len(),
+                    max_bytes,
+                });
+            }
+            bytes = self.format.encode(&persisted)?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Decode memory saved with [`Self::save`], migrating it to [`Self::version`],
+    /// and replace the persisted parts of the given [`Memory`] with it.
+    ///
+    /// On error the [`Memory`] is left untouched.
+    ///
+    /// # Errors
+    /// [`PersistenceError::Decode`] from the [`PersistenceFormat`],
+    /// [`PersistenceError::NewerVersion`] if the memory was saved by a newer version of the app,
+    /// or [`PersistenceError::NoMigration`] if a [`Self::migration`] is missing.
+    pub fn load(&self, bytes: &[u8], memory: &mut Memory) -> Result<(), PersistenceError> {
+        profiling::function_scope!();
+
+        let mut persisted = self.format.decode(bytes)?;
+
+        if self.version < persisted.version {
+            return Err(PersistenceError::NewerVersion {
+                saved: persisted.version,
+                current: self.version,
+            });
+        }
+        while persisted.version < self.version {
+            let from = persisted.version;
+            let migrate = self
+                .migrations
+                .get(&from)
+                .ok_or(PersistenceError::NoMigration { from })?;
+            migrate(&mut persisted);
+            persisted.version = from + 1;
+        }
+
+        self.apply_filters(&mut persisted);
+
+        let PersistedMemory {
+            version: _,
+            options,
+            mut data,
+            areas,
+            to_global,
+        } = persisted;
+
+        data.set_max_bytes_per_type(memory.data.max_bytes_per_type());
+
+        if self.persist_options {
+            memory.options = options;
+        }
+        if self.persist_areas {
+            memory.areas = areas
+                .into_iter()
+                .map(|(viewport_id, areas)| (viewport_id, areas.into()))
+                .collect();
+            memory.areas.entry(memory.viewport_id).or_default();
+            memory.to_global = to_global.into_iter().collect();
+        }
+        memory.data = data;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Id, Pos2, Window, pos2};
+
+    fn show_window(ctx: &Context, default_pos: Pos2) {
+        let _ = ctx.run(Default::default(), |ctx| {
+            Window::new("Settings")
+                .default_pos(default_pos)
+                .show(ctx, |ui| {
+                    ui.label("Hello");
+                });
+        });
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let window_pos = pos2(120.0, 80.0);
+        let counter = Id::new("counter");
+
+        let formats = [
+            MemoryPersistence::new(RonFormat),
+            #[cfg(feature = "persistence_json")]
+            MemoryPersistence::json(),
+            #[cfg(feature = "persistence_postcard")]
+            MemoryPersistence::postcard(),
+        ];
+        for persistence in formats {
+            let ctx = Context::default();
+            show_window(&ctx, window_pos);
+            ctx.data_mut(|data| data.insert_persisted(counter, 42_i32));
+            ctx.options_mut(|options| options.zoom_factor = 1.5);
+
+            let bytes = ctx.save_memory(&persistence).unwrap();
+
+            // Without persistence the window would open at the top left:
+            let restored = Context::default();
+            restored.load_memory(&persistence, &bytes).unwrap();
+            show_window(&restored, pos2(0.0, 0.0));
+
+            let rect = restored
+                .memory(|mem| mem.area_rect(Id::new("Settings")))
+                .unwrap();
+            assert_eq!(rect.min, window_pos, "{persistence:?}");
+            assert_eq!(
+                restored.data_mut(|data| data.get_persisted::<i32>(counter)),
+                Some(42)
+            );
+            assert_eq!(restored.options(|options| options.zoom_factor), 1.5);
+        }
+    }
+
+    #[test]
+    fn test_filters_migrations_and_budget() {
+        let ctx = Context::default();
+        let _ = ctx.run(Default::default(), |_| {});
+        ctx.data_mut(|data| {
+            data.insert_persisted(Id::new("kept"), 1_i32);
+            data.insert_persisted(Id::new("secret"), 2_i32);
+            data.insert_persisted(Id::new("scroll"), 3.0_f32);
+        });
+
+        // Selective persistence:
+        let persistence = MemoryPersistence::default()
+            .exclude_id(Id::new("secret"))
+            .exclude_type::<f32>();
+        let bytes = ctx.save_memory(&persistence).unwrap();
+        let loaded = Context::default();
+        loaded.load_memory(&persistence, &bytes).unwrap();
+        loaded.data_mut(|data| {
+            assert_eq!(data.get_persisted::<i32>(Id::new("kept")), Some(1));
+            assert_eq!(data.get_persisted::<i32>(Id::new("secret")), None);
+            assert_eq!(data.get_persisted::<f32>(Id::new("scroll")), None);
+        });
+
+        // Migrations:
+        let v0 = MemoryPersistence::default();
+        let bytes = ctx.save_memory(&v0).unwrap();
+        let v2 = MemoryPersistence::default()
+            .version(2)
+            .migration(0, |memory| {
+                let old = memory.data.get_persisted::<i32>(Id::new("kept")).unwrap();
+                memory.data.insert_persisted(Id::new("kept"), old * 10);
+            })
+            .migration(1, |memory| memory.data.remove::<i32>(Id::new("secret")));
+        let loaded = Context::default();
+        loaded.load_memory(&v2, &bytes).unwrap();
+        loaded.data_mut(|data| {
+            assert_eq!(data.get_persisted::<i32>(Id::new("kept")), Some(10));
+            assert_eq!(data.get_persisted::<i32>(Id::new("secret")), None);
+        });
+
+        let bytes = ctx.save_memory(&v2).unwrap();
+        assert_eq!(
+            Context::default().load_memory(&v0, &bytes),
+            Err(PersistenceError::NewerVersion {
+                saved: 2,
+                current: 0
+            })
+        );
+        let v3 = MemoryPersistence::default().version(3);
+        assert_eq!(
+            Context::default().load_memory(&v3, &bytes),
+            Err(PersistenceError::NoMigration { from: 2 })
+        );
+
+        // Size budget, dropping the least recently used state first:
+        let _ = ctx.run(Default::default(), |ctx| {
+            ctx.data_mut(|data| data.get_persisted::<i32>(Id::new("kept")));
+        });
+        let full = ctx.save_memory(&v0).unwrap();
+        let budget = MemoryPersistence::default().max_bytes(full.len() - 1);
+        let bytes = ctx.save_memory(&budget).unwrap();
+        assert!(bytes.len() < full.len());
+        let loaded = Context::default();
+        loaded.load_memory(&budget, &bytes).unwrap();
+        loaded.data_mut(|data| {
+            assert_eq!(data.get_persisted::<i32>(Id::new("kept")), Some(1));
+        });
+
+        assert!(matches!(
+            ctx.save_memory(&MemoryPersistence::default().max_bytes(1)),
+            Err(PersistenceError::TooLarge { .. })
+        ));
+    }
+}
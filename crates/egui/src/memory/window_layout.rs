@@ -0,0 +1,116 @@
+use crate::{
+    Align2, AreaState, Context, LayerId, Pos2, Vec2, Window, collapsing_header::CollapsingState,
+    containers::resize,
+};
+
+/// Where all [`crate::Area`]s and [`crate::Window`]s are, and how they are stacked.
+///
+/// Use this to let users save and switch between named workspace layouts.
+///
+/// Captured with [`Context::save_window_layout`], and applied with [`Context::restore_window_layout`].
+/// With the `serde` feature this can be serialized, e.g. to store it in the app storage.
+///
+/// Areas and windows that are not in the layout are left as they are.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WindowLayout {
+    /// All areas, back-to-front (top is last).
+    pub areas: Vec<AreaLayout>,
+}
+
+/// The layout of a single [`crate::Area`] or [`crate::Window`] in a [`WindowLayout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AreaLayout {
+    /// Which area this is.
+    pub layer_id: LayerId,
+
+    /// Position of the pivot. See [`AreaState::pivot_pos`].
+    pub pivot_pos: Pos2,
+
+    /// See [`AreaState::pivot`].
+    pub pivot: Align2,
+
+    /// The size the user picked for a resizable [`crate::Window`], if any.
+    pub size: Option<Vec2>,
+
+    /// Is the [`crate::Window`] collapsed? `None` if it has never been collapsed or expanded.
+    pub collapsed: Option<bool>,
+
+    /// Is the [`crate::Window`] minimized? See [`Window::minimizable`].
+    pub minimized: bool,
+}
+
+// Same ids as `Window` uses for its parts:
+fn resize_id(layer_id: LayerId) -> crate::Id {
+    layer_id.id.with("resize")
+}
+
+fn collapsing_id(layer_id: LayerId) -> crate::Id {
+    layer_id.id.with("collapsing")
+}
+
+impl WindowLayout {
+    pub(crate) fn save(ctx: &Context) -> Self {
+        let areas: Vec<(LayerId, AreaState)> = ctx.memory(|mem| {
+            let areas = mem.areas();
+            areas
+                .order()
+                .iter()
+                .filter_map(|&layer_id| Some((layer_id, *areas.get(layer_id.id)?)))
+                .collect()
+        });
+
+        let areas = areas
+            .into_iter()
+            .filter_map(|(layer_id, state)| {
+                Some(AreaLayout {
+                    layer_id,
+                    pivot_pos: state.pivot_pos?,
+                    pivot: state.pivot,
+                    size: resize::State::load(ctx, resize_id(layer_id))
+                        .map(|state| state.desired_size),
+                    collapsed: CollapsingState::load(ctx, collapsing_id(layer_id))
+                        .map(|state| !state.is_open()),
+                    minimized: Window::is_minimized(ctx, layer_id.id),
+                })
+            })
+            .collect();
+
+        Self { areas }
+    }
+
+    pub(crate) fn restore(&self, ctx: &Context) {
+        for area in &self.areas {
+            let AreaLayout {
+                layer_id,
+                pivot_pos,
+                pivot,
+                size,
+                collapsed,
+                minimized,
+            } = *area;
+
+            if let Some(size) = size {
+                resize::State::request_size(ctx, resize_id(layer_id), size);
+            }
+            if let Some(collapsed) = collapsed {
+                Window::set_collapsed(ctx, layer_id.id, collapsed);
+            }
+            if minimized != Window::is_minimized(ctx, layer_id.id) {
+                Window::set_minimized(ctx, layer_id.id, minimized);
+            }
+
+            ctx.memory_mut(|mem| {
+                let areas = mem.areas_mut();
+                let mut state = areas.get(layer_id.id).copied().unwrap_or_default();
+                state.pivot_pos = Some(pivot_pos);
+                state.pivot = pivot;
+                areas.restore_state(layer_id, state);
+            });
+        }
+
+        let order: Vec<LayerId> = self.areas.iter().map(|area| area.layer_id).collect();
+        ctx.memory_mut(|mem| mem.areas_mut().restore_order(&order));
+    }
+}
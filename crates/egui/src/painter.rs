@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use emath::GuiRounding as _;
 use epaint::{
-    CircleShape, ClippedShape, CornerRadius, PathStroke, RectShape, Shape, Stroke, StrokeKind,
+    Brush, CircleShape, ClippedShape, CornerRadius, MaskedShape, PathBuilder, PathStroke,
+    RectShape, Shadow, Shape, Stroke, StrokeKind,
     text::{Fonts, Galley, LayoutJob},
 };
 
@@ -32,6 +33,10 @@ pub struct Painter {
     /// This means nothing outside of this rectangle will be visible on screen.
     clip_rect: Rect,
 
+    /// If set, everything painted in this [`Painter`] is masked by this shape,
+    /// see [`Shape::masked`].
+    clip_shape: Option<Arc<Shape>>,
+
     /// If set, all shapes will have their colors modified to be closer to this.
     /// This is used to implement grayed out interfaces.
     fade_to_color: Option<Color32>,
@@ -51,6 +56,7 @@ impl Painter {
             pixels_per_point,
             layer_id,
             clip_rect,
+            clip_shape: None,
             fade_to_color: None,
             opacity_factor: 1.0,
         }
@@ -74,6 +80,16 @@ impl Painter {
         new_self
     }
 
+    /// Create a painter that only paints inside of `clip_shape`, e.g. a circle or a rounded rectangle.
+    ///
+    /// This is combined with the clip rectangle and any clip shape of this [`Painter`].
+    /// See [`Self::shrink_clip_shape`] for more.
+    pub fn with_clip_shape(&self, clip_shape: impl Into<Shape>) -> Self {
+        let mut new_self = self.clone();
+        new_self.shrink_clip_shape(clip_shape);
+        new_self
+    }
+
     /// Redirect where you are painting.
     ///
     /// It is undefined behavior to change the [`LayerId`]
@@ -182,6 +198,39 @@ impl Painter {
         self.clip_rect = clip_rect;
     }
 
+    /// If set, everything painted in this [`Painter`] is only visible inside of this shape.
+    #[inline]
+    pub fn clip_shape(&self) -> Option<&Shape> {
+        self.clip_shape.as_deref()
+    }
+
+    /// Only paint inside of `clip_shape` (as well as inside of the current clip rectangle and clip shape).
+    ///
+    /// The shape acts as a mask (see [`Shape::masked`]): its colors are ignored,
+    /// but semi-transparent parts make whatever is painted semi-transparent.
+    /// For instance, use [`Shape::circle_filled`] for an avatar,
+    /// or a thick arc for a progress ring.
+    ///
+    /// This only affects painting.
+    /// Interaction is still limited by the clip rectangle only.
+    ///
+    /// See also: [`Self::set_clip_shape`].
+    pub fn shrink_clip_shape(&mut self, clip_shape: impl Into<Shape>) {
+        let clip_shape = clip_shape.into();
+        self.clip_rect = self.clip_rect.intersect(clip_shape.visual_bounding_rect());
+        self.clip_shape = Some(Arc::new(match self.clip_shape.take() {
+            Some(outer) => Shape::masked(clip_shape, Arc::unwrap_or_clone(outer)),
+            None => clip_shape,
+        }));
+    }
+
+    /// Set or remove the clip shape, see [`Self::shrink_clip_shape`].
+    ///
+    /// Unlike [`Self::shrink_clip_shape`], this does not change the clip rectangle.
+    pub fn set_clip_shape(&mut self, clip_shape: Option<Shape>) {
+        self.clip_shape = clip_shape.map(Arc::new);
+    }
+
     /// Useful for pixel-perfect rendering of lines that are one pixel wide (or any odd number of pixels).
     #[inline]
     pub fn round_to_pixel_center(&self, point: f32) -> f32 {
@@ -238,6 +287,12 @@ impl Painter {
         if self.opacity_factor < 1.0 {
             multiply_opacity(shape, self.opacity_factor);
         }
+        if let Some(clip_shape) = &self.clip_shape {
+            *shape = Shape::Masked(MaskedShape {
+                shape: Arc::new(std::mem::replace(shape, Shape::Noop)),
+                mask: clip_shape.clone(),
+            });
+        }
     }
 
     /// It is up to the caller to make sure there is room for this.
@@ -253,6 +308,15 @@ impl Painter {
         }
     }
 
+    /// Add a shape with a [`Shadow`] beneath it, see [`Shadow::cast_by`].
+    ///
+    /// Returns the index of the shape (not of the shadow).
+    pub fn add_with_shadow(&self, shape: impl Into<Shape>, shadow: Shadow) -> ShapeIdx {
+        let shape = shape.into();
+        self.add(shadow.cast_by(&shape));
+        self.add(shape)
+    }
+
     /// Add many shapes at once.
     ///
     /// Calling this once is generally faster than calling [`Self::add`] multiple times.
@@ -260,7 +324,7 @@ impl Painter {
         if self.fade_to_color == Some(Color32::TRANSPARENT) || self.opacity_factor == 0.0 {
             return;
         }
-        if self.fade_to_color.is_some() || self.opacity_factor < 1.0 {
+        if self.fade_to_color.is_some() || self.opacity_factor < 1.0 || self.clip_shape.is_some() {
             let shapes = shapes.into_iter().map(|mut shape| {
                 self.transform_shape(&mut shape);
                 shape
@@ -361,6 +425,30 @@ impl Painter {
         self.add(Shape::line(points, stroke))
     }
 
+    /// Fill and stroke a path built out of lines, arcs, and curves.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{epaint::PathBuilder, pos2, Color32, Stroke};
+    /// use std::f32::consts::PI;
+    ///
+    /// let center = pos2(50.0, 50.0);
+    /// let needle = PathBuilder::new()
+    ///     .arc(center, 4.0, 0.0, PI)
+    ///     .line_to(pos2(50.0, 10.0))
+    ///     .close();
+    /// ui.painter().add_path(&needle, Color32::RED, Stroke::NONE);
+    /// # });
+    /// ```
+    pub fn add_path(
+        &self,
+        path: &PathBuilder,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(path.to_shape(fill.into(), stroke))
+    }
+
     /// Paints a horizontal line.
     pub fn hline(&self, x: impl Into<Rangef>, y: f32, stroke: impl Into<Stroke>) -> ShapeIdx {
         self.add(Shape::hline(x, y, stroke))
@@ -409,6 +497,19 @@ impl Painter {
         })
     }
 
+    /// Fill a circle with a gradient or texture.
+    ///
+    /// The positions of the [`Brush`] are relative to the bounding rectangle of the circle.
+    pub fn circle_brush(
+        &self,
+        center: Pos2,
+        radius: f32,
+        brush: impl Into<Arc<Brush>>,
+    ) -> ShapeIdx {
+        let rect = Rect::from_center_size(center, Vec2::splat(2.0 * radius));
+        self.rect_brush(rect, CornerRadius::from(radius), brush)
+    }
+
     /// See also [`Self::rect_filled`] and [`Self::rect_stroke`].
     pub fn rect(
         &self,
@@ -446,6 +547,24 @@ impl Painter {
         self.add(RectShape::stroke(rect, corner_radius, stroke, stroke_kind))
     }
 
+    /// Fill a rectangle with a gradient or texture.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let rect = ui.max_rect();
+    /// let brush = egui::Brush::vertical_gradient(egui::Color32::WHITE, egui::Color32::BLACK);
+    /// ui.painter().rect_brush(rect, 4.0, brush);
+    /// # });
+    /// ```
+    pub fn rect_brush(
+        &self,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        brush: impl Into<Arc<Brush>>,
+    ) -> ShapeIdx {
+        self.add(RectShape::filled(rect, corner_radius, Color32::WHITE).with_brush(brush))
+    }
+
     /// Show an arrow starting at `origin` and going in the direction of `vec`, with the length `vec.length()`.
     pub fn arrow(&self, origin: Pos2, vec: Vec2, stroke: impl Into<Stroke>) {
         use crate::emath::Rot2;
@@ -513,6 +632,24 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint a single line of text along `path`,
+    /// starting `start_distance` points from its start.
+    ///
+    /// See [`Shape::text_along_path`].
+    pub fn text_along_path(
+        &self,
+        path: &[Pos2],
+        start_distance: f32,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+    ) -> ShapeIdx {
+        let shape = self.fonts(|fonts| {
+            Shape::text_along_path(fonts, path, start_distance, text, font_id, text_color)
+        });
+        self.add(shape)
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -1,6 +1,11 @@
 use ahash::HashMap;
 
-use crate::{Align, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, id::IdSet, style};
+use std::panic::Location;
+
+use crate::{
+    Align, CustomCursorId, Id, IdClash, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, id::IdSet,
+    style,
+};
 
 #[cfg(debug_assertions)]
 use crate::{Align2, Color32, FontId, NumExt as _, Painter, pos2};
@@ -20,6 +25,16 @@ impl TooltipPassState {
     }
 }
 
+/// Where an [`Id`] was used, see [`PassState::used_ids`].
+#[derive(Clone, Copy, Debug)]
+pub struct UsedId {
+    /// Approximately where the widget is.
+    pub rect: Rect,
+
+    /// The code that used the [`Id`].
+    pub location: &'static Location<'static>,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct PerWidgetTooltipState {
     /// Bounding rectangle for all widget and all previous tooltips.
@@ -188,7 +203,10 @@ impl DebugRect {
 #[derive(Clone)]
 pub struct PassState {
     /// All [`Id`]s that were used this pass.
-    pub used_ids: IdMap<Rect>,
+    pub used_ids: IdMap<UsedId>,
+
+    /// [`Id`]s that were used by more than one widget this pass.
+    pub id_clashes: Vec<IdClash>,
 
     /// All widgets produced this pass.
     pub widgets: WidgetRects,
@@ -231,6 +249,14 @@ pub struct PassState {
     /// Highlight these widgets the next pass.
     pub highlight_next_pass: IdSet,
 
+    /// The custom cursor to paint at the end of the pass, if any.
+    pub custom_cursor: Option<CustomCursorId>,
+
+    /// The layer of the widget that last set the cursor this pass.
+    ///
+    /// Used to stop widgets below it from overriding its cursor.
+    pub cursor_layer: Option<LayerId>,
+
     #[cfg(debug_assertions)]
     pub debug_rect: Option<DebugRect>,
 }
@@ -239,6 +265,7 @@ impl Default for PassState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            id_clashes: Default::default(),
             widgets: Default::default(),
             layers: Default::default(),
             tooltips: Default::default(),
@@ -250,6 +277,8 @@ impl Default for PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
             highlight_next_pass: Default::default(),
+            custom_cursor: None,
+            cursor_layer: None,
 
             #[cfg(debug_assertions)]
             debug_rect: None,
@@ -262,6 +291,7 @@ impl PassState {
         profiling::function_scope!();
         let Self {
             used_ids,
+            id_clashes,
             widgets,
             tooltips,
             layers,
@@ -273,12 +303,15 @@ impl PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state,
             highlight_next_pass,
+            custom_cursor,
+            cursor_layer,
 
             #[cfg(debug_assertions)]
             debug_rect,
         } = self;
 
         used_ids.clear();
+        id_clashes.clear();
         widgets.clear();
         tooltips.clear();
         layers.clear();
@@ -287,6 +320,8 @@ impl PassState {
         *used_by_panels = Rect::NOTHING;
         *scroll_target = [None, None];
         *scroll_delta = Default::default();
+        *custom_cursor = None;
+        *cursor_layer = None;
 
         #[cfg(debug_assertions)]
         {
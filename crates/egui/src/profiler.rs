@@ -0,0 +1,441 @@
+//! A built-in frame profiler, for finding out where the time of a frame goes.
+//!
+//! Turn it on with [`crate::Context::set_profiling`], or show the profiler window with [`crate::Context::profile`].
+//! This needs the `frame_profiler` feature; without it the profiler is always off.
+//!
+//! egui records the phases of each frame (handling input, the layout of each [`crate::Area`],
+//! ending the pass and tessellation), and you can add your own with [`crate::Context::profile_scope`],
+//! e.g. for uploading textures in your integration.
+//!
+//! For profiling the whole app, including the internals of egui, use the `profiling` crate with e.g. `puffin` instead.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+#[cfg(feature = "frame_profiler")]
+use web_time::Instant;
+
+use crate::{Align2, Color32, Context, FontId, Rect, Sense, Stroke, Ui, Vec2, pos2, vec2};
+
+/// How many frames the profiler keeps.
+const MAX_FRAMES: usize = 120;
+
+/// A timed part of a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileScope {
+    pub name: String,
+
+    /// In seconds since the start of the frame.
+    pub start: f32,
+
+    /// In seconds.
+    pub duration: f32,
+
+    /// How many scopes this is nested in.
+    pub depth: usize,
+}
+
+impl ProfileScope {
+    /// In seconds since the start of the frame.
+    pub fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+}
+
+/// The timings of one frame, see [`crate::Context::frame_profiles`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameProfile {
+    /// See [`crate::Context::cumulative_frame_nr`].
+    pub frame_nr: u64,
+
+    /// In the order they started, so parents come before their children.
+    pub scopes: Vec<ProfileScope>,
+}
+
+impl FrameProfile {
+    /// From the start of the frame until the end of the last scope, in seconds.
+    ///
+    /// Time spent waiting for the next frame is not counted.
+    pub fn duration(&self) -> f32 {
+        self.scopes
+            .iter()
+            .map(ProfileScope::end)
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Without the `frame_profiler` feature there is no clock, and no frame is ever recorded.
+#[cfg(not(feature = "frame_profiler"))]
+#[derive(Clone, Copy)]
+struct Instant;
+
+#[cfg(not(feature = "frame_profiler"))]
+impl Instant {
+    fn now() -> Self {
+        Self
+    }
+
+    #[expect(clippy::unused_self)]
+    fn elapsed(self) -> std::time::Duration {
+        std::time::Duration::ZERO
+    }
+}
+
+struct FrameInProgress {
+    frame_nr: u64,
+    start: Instant,
+    scopes: Vec<ProfileScope>,
+
+    /// Indices into `scopes` of the scopes that haven't ended yet.
+    open: Vec<usize>,
+}
+
+impl FrameInProgress {
+    fn finish(mut self) -> FrameProfile {
+        while !self.open.is_empty() {
+            self.end_scope();
+        }
+        FrameProfile {
+            frame_nr: self.frame_nr,
+            scopes: self.scopes,
+        }
+    }
+
+    fn end_scope(&mut self) {
+        if let Some(index) = self.open.pop() {
+            let now = self.start.elapsed().as_secs_f32();
+            let scope = &mut self.scopes[index];
+            scope.duration = now - scope.start;
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct FrameProfiler {
+    enabled: bool,
+    current: Option<FrameInProgress>,
+
+    /// The last finished frames, oldest first.
+    frames: VecDeque<Arc<FrameProfile>>,
+}
+
+impl FrameProfiler {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled && cfg!(feature = "frame_profiler");
+        if !enabled {
+            self.current = None;
+        }
+    }
+
+    /// The previous frame, if any, is finished.
+    pub fn begin_frame(&mut self, frame_nr: u64) {
+        if let Some(frame) = self.current.take() {
+            if self.frames.len() == MAX_FRAMES {
+                self.frames.pop_front();
+            }
+            self.frames.push_back(Arc::new(frame.finish()));
+        }
+        if self.enabled {
+            self.current = Some(FrameInProgress {
+                frame_nr,
+                start: Instant::now(),
+                scopes: Vec::new(),
+                open: Vec::new(),
+            });
+        }
+    }
+
+    /// Is a frame being profiled right now?
+    pub fn is_recording(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Does nothing unless a frame is being profiled.
+    pub fn begin_scope(&mut self, name: impl Into<String>) {
+        if let Some(frame) = &mut self.current {
+            frame.open.push(frame.scopes.len());
+            frame.scopes.push(ProfileScope {
+                name: name.into(),
+                start: frame.start.elapsed().as_secs_f32(),
+                duration: 0.0,
+                depth: frame.open.len() - 1,
+            });
+        }
+    }
+
+    pub fn end_scope(&mut self) {
+        if let Some(frame) = &mut self.current {
+            frame.end_scope();
+        }
+    }
+
+    pub fn frames(&self) -> Vec<Arc<FrameProfile>> {
+        self.frames.iter().cloned().collect()
+    }
+}
+
+/// Ends a scope of the frame profiler when dropped, see [`crate::Context::profile_scope`].
+#[must_use = "The scope ends when this is dropped"]
+pub struct ProfileScopeGuard {
+    /// `None` if the profiler is off.
+    ctx: Option<Context>,
+}
+
+impl ProfileScopeGuard {
+    pub(crate) fn new(ctx: Option<Context>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl Drop for ProfileScopeGuard {
+    fn drop(&mut self) {
+        if let Some(ctx) = &self.ctx {
+            ctx.end_profile_scope();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which frame the profiler window shows.
+#[derive(Clone, Copy, Default)]
+struct ProfilerUiState {
+    /// Keep showing this frame instead of the latest one.
+    paused_at: Option<u64>,
+}
+
+/// Shows the durations of the last frames, and a flame graph of one of them.
+pub(crate) fn profiler_ui(ctx: &Context, ui: &mut Ui) {
+    let frames = ctx.frame_profiles();
+    if !cfg!(feature = "frame_profiler") {
+        ui.label("The profiler needs the `frame_profiler` feature of egui.");
+        return;
+    }
+    if !ctx.is_profiling() {
+        ui.label("The profiler is off. See Context::set_profiling.");
+        return;
+    }
+    let Some(latest) = frames.last() else {
+        ui.label("Waiting for the first frame…");
+        return;
+    };
+
+    let state_id = ui.id().with("profiler_ui");
+    let mut state: ProfilerUiState = ui.data(|d| d.get_temp(state_id).unwrap_or_default());
+
+    let selected = state
+        .paused_at
+        .and_then(|nr| frames.iter().find(|frame| frame.frame_nr == nr))
+        .unwrap_or(latest)
+        .clone();
+
+    ui.horizontal(|ui| {
+        let mut paused = state.paused_at.is_some();
+        if ui.checkbox(&mut paused, "Paused").changed() {
+            state.paused_at = paused.then_some(selected.frame_nr);
+        }
+        ui.label(format!(
+            "Frame {}: {:.2} ms",
+            selected.frame_nr,
+            1e3 * selected.duration()
+        ));
+    });
+
+    if let Some(frame_nr) = frame_durations_ui(ui, &frames, selected.frame_nr) {
+        state.paused_at = Some(frame_nr);
+    }
+
+    ui.separator();
+    flame_graph_ui(ui, &selected);
+
+    ui.data_mut(|d| d.insert_temp(state_id, state));
+}
+
+/// A bar per frame. Returns the frame that was clicked, if any.
+fn frame_durations_ui(ui: &mut Ui, frames: &[Arc<FrameProfile>], selected: u64) -> Option<u64> {
+    let height = 40.0;
+    let (rect, response) =
+        ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::click());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+    let max_duration = frames
+        .iter()
+        .map(|frame| frame.duration())
+        .fold(1e-3, f32::max);
+    let bar_width = rect.width() / MAX_FRAMES as f32;
+    let bar_rect = |i: usize, frame: &FrameProfile| {
+        let right = rect.right() - (frames.len() - 1 - i) as f32 * bar_width;
+        let top = rect.bottom() - rect.height() * frame.duration() / max_duration;
+        Rect::from_min_max(pos2(right - bar_width, top), pos2(right, rect.bottom()))
+    };
+
+    let mut clicked = None;
+    for (i, frame) in frames.iter().enumerate() {
+        let bar_rect = bar_rect(i, frame);
+        let is_hovered = response
+            .hover_pos()
+            .is_some_and(|pos| bar_rect.x_range().contains(pos.x));
+        let color = if frame.frame_nr == selected {
+            visuals.selection.bg_fill
+        } else if is_hovered {
+            visuals.widgets.hovered.fg_stroke.color
+        } else {
+            visuals.widgets.inactive.fg_stroke.color
+        };
+        painter.rect_filled(bar_rect.shrink2(vec2(0.5, 0.0)), 0.0, color);
+
+        if is_hovered {
+            response.clone().on_hover_text(format!(
+                "Frame {}: {:.2} ms",
+                frame.frame_nr,
+                1e3 * frame.duration()
+            ));
+            if response.clicked() {
+                clicked = Some(frame.frame_nr);
+            }
+        }
+    }
+    clicked
+}
+
+/// Each scope is a bar below its parent, as wide as its duration.
+fn flame_graph_ui(ui: &mut Ui, frame: &FrameProfile) {
+    let row_height = 18.0;
+    let num_rows = frame.scopes.iter().map(|s| s.depth + 1).max().unwrap_or(1);
+    let (rect, response) = ui.allocate_exact_size(
+        vec2(ui.available_width(), num_rows as f32 * row_height),
+        Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    let font_id = FontId::monospace(11.0);
+
+    let points_per_second = rect.width() / frame.duration().max(1e-6);
+    for (i, scope) in frame.scopes.iter().enumerate() {
+        let min = rect.min
+            + vec2(
+                scope.start * points_per_second,
+                scope.depth as f32 * row_height,
+            );
+        let size = vec2(scope.duration * points_per_second, row_height).max(Vec2::new(1.0, 0.0));
+        let scope_rect = Rect::from_min_size(min, size).shrink(0.5);
+
+        // Alternate the colors, so neighbors can be told apart:
+        let color = if (i + scope.depth) % 2 == 0 {
+            Color32::from_rgb(200, 110, 50)
+        } else {
+            Color32::from_rgb(220, 160, 60)
+        };
+        painter.rect(
+            scope_rect,
+            2.0,
+            color,
+            Stroke::NONE,
+            crate::StrokeKind::Inside,
+        );
+
+        let text = format!("{} {:.2} ms", scope.name, 1e3 * scope.duration);
+        let galley = painter.layout_no_wrap(text, font_id.clone(), Color32::BLACK);
+        if galley.size().x < scope_rect.width() {
+            painter.galley(
+                Align2::LEFT_CENTER
+                    .align_size_within_rect(galley.size(), scope_rect.shrink2(vec2(3.0, 0.0)))
+                    .min,
+                galley,
+                Color32::BLACK,
+            );
+        }
+
+        if response
+            .hover_pos()
+            .is_some_and(|pos| scope_rect.contains(pos))
+        {
+            response.clone().on_hover_text(format!(
+                "{}\n{:.3} ms, starting at {:.3} ms",
+                scope.name,
+                1e3 * scope.duration,
+                1e3 * scope.start
+            ));
+        }
+    }
+
+    if frame.scopes.is_empty() {
+        painter.text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            "No scopes",
+            font_id,
+            visuals.weak_text_color(),
+        );
+    }
+}
+
+#[cfg(all(test, feature = "frame_profiler"))]
+mod tests {
+    use crate::{Context, RawInput, Window};
+
+    #[test]
+    fn test_frame_profile_scopes() {
+        let ctx = Context::default();
+        ctx.set_profiling(true);
+
+        for _ in 0..3 {
+            let output = ctx.run(RawInput::default(), |ctx| {
+                Window::new("Window").show(ctx, |ui| {
+                    let _scope = ui.ctx().profile_scope("my scope");
+                    ui.label("Hello");
+                });
+            });
+            let _scope = ctx.profile_scope("texture upload");
+            let _ = ctx.tessellate(output.shapes, output.pixels_per_point);
+        }
+
+        let frames = ctx.frame_profiles();
+        assert_eq!(frames.len(), 2, "the last frame isn't finished yet");
+        let frame = &frames[1];
+        assert_eq!(frame.frame_nr, 1);
+
+        let scope = |name: &str| {
+            frame
+                .scopes
+                .iter()
+                .find(|scope| scope.name.starts_with(name))
+                .unwrap_or_else(|| panic!("No scope {name:?} in {:?}", frame.scopes))
+        };
+        assert_eq!(scope("pass").depth, 0);
+        assert_eq!(scope("input").depth, 1);
+        assert_eq!(scope("Window").depth, 1);
+        assert_eq!(scope("my scope").depth, 2);
+        assert_eq!(scope("end_pass").depth, 1);
+        assert_eq!(scope("texture upload").depth, 0);
+        assert_eq!(scope("tessellate").depth, 1);
+
+        let window = scope("Window");
+        assert!(window.start <= scope("my scope").start);
+        assert!(scope("my scope").end() <= window.end());
+        assert!(scope("pass").end() <= scope("texture upload").start);
+        assert!(0.0 < frame.duration());
+
+        // The profiler window shows itself:
+        for _ in 0..2 {
+            let _ = ctx.run(RawInput::default(), |ctx| ctx.profile());
+        }
+        let frame = ctx.frame_profiles().pop().unwrap();
+        assert!(
+            frame
+                .scopes
+                .iter()
+                .any(|scope| scope.name.starts_with("Window"))
+        );
+
+        ctx.set_profiling(false);
+        let _ = ctx.run(RawInput::default(), |_| {});
+        assert_eq!(ctx.frame_profiles().len(), 4);
+    }
+}
@@ -0,0 +1,263 @@
+//! A value that is being computed in the background, e.g. by a thread or an async task.
+//!
+//! See [`Promise`] and [`crate::Ui::await_promise`].
+
+use std::sync::Arc;
+
+use crate::{Context, mutex::Mutex};
+
+/// A value that will be ready at some later point, e.g. the result of a download.
+///
+/// The value is sent from another thread or async task using a [`PromiseSender`],
+/// which also wakes up the ui by requesting a repaint.
+///
+/// This is a small version of the `poll_promise` crate, which integrates with the [`crate::Ui`],
+/// see [`crate::Ui::await_promise`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let ctx = ui.ctx();
+/// let (sender, mut promise) = egui::Promise::new(ctx);
+/// std::thread::spawn(move || {
+///     sender.set_progress(0.5);
+///     sender.send(42);
+/// });
+///
+/// // In your ui code, every frame:
+/// ui.await_promise(&mut promise, |ui, value| {
+///     ui.label(format!("The answer is {value}"));
+/// });
+/// # });
+/// ```
+#[must_use = "The value is lost if the promise is dropped"]
+pub struct Promise<T> {
+    ready: Option<T>,
+    pending: Option<Arc<Mutex<Pending<T>>>>,
+}
+
+struct Pending<T> {
+    value: Option<T>,
+    progress: Option<f32>,
+
+    /// The [`PromiseSender`] was dropped without sending a value.
+    abandoned: bool,
+}
+
+/// The state of a [`Promise`], see [`Promise::poll`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PromiseState<T> {
+    /// Still waiting for the value.
+    Pending {
+        /// How far along we are, in `[0, 1]`, if known. See [`PromiseSender::set_progress`].
+        progress: Option<f32>,
+    },
+
+    /// The value has arrived.
+    Ready(T),
+
+    /// The [`PromiseSender`] was dropped without sending a value, e.g. because the thread panicked.
+    Abandoned,
+}
+
+impl<T: Send + 'static> Promise<T> {
+    /// Create a promise, and the sender that fulfills it.
+    ///
+    /// Sending a value, or reporting progress, requests a repaint of `ctx`.
+    pub fn new(ctx: &Context) -> (PromiseSender<T>, Self) {
+        let pending = Arc::new(Mutex::new(Pending {
+            value: None,
+            progress: None,
+            abandoned: false,
+        }));
+        let sender = PromiseSender {
+            pending: Some(pending.clone()),
+            ctx: ctx.clone(),
+        };
+        let promise = Self {
+            ready: None,
+            pending: Some(pending),
+        };
+        (sender, promise)
+    }
+
+    /// A promise that is already fulfilled.
+    pub fn from_ready(value: T) -> Self {
+        Self {
+            ready: Some(value),
+            pending: None,
+        }
+    }
+
+    /// Compute the value on a new thread.
+    ///
+    /// The closure can report its progress using the given [`PromiseSender`].
+    /// If it panics, the promise is [`PromiseState::Abandoned`].
+    ///
+    /// Not available on the web, where there are no threads.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_thread(
+        ctx: &Context,
+        thread_name: impl Into<String>,
+        compute: impl FnOnce(&PromiseSender<T>) -> T + Send + 'static,
+    ) -> Self {
+        let (sender, promise) = Self::new(ctx);
+        std::thread::Builder::new()
+            .name(thread_name.into())
+            .spawn(move || {
+                let value = compute(&sender);
+                sender.send(value);
+            })
+            .expect("Failed to spawn thread");
+        promise
+    }
+
+    /// Check if the value has arrived, without blocking.
+    pub fn poll(&mut self) -> PromiseState<&T> {
+        if let Some(pending) = &self.pending {
+            let mut pending = pending.lock();
+            if let Some(value) = pending.value.take() {
+                self.ready = Some(value);
+            } else if pending.abandoned {
+                return PromiseState::Abandoned;
+            } else {
+                return PromiseState::Pending {
+                    progress: pending.progress,
+                };
+            }
+        }
+        self.pending = None;
+
+        match &self.ready {
+            Some(value) => PromiseState::Ready(value),
+            None => PromiseState::Abandoned,
+        }
+    }
+
+    /// The value, if it has arrived.
+    pub fn ready(&mut self) -> Option<&T> {
+        match self.poll() {
+            PromiseState::Ready(value) => Some(value),
+            PromiseState::Pending { .. } | PromiseState::Abandoned => None,
+        }
+    }
+
+    /// The value, if it has arrived.
+    pub fn ready_mut(&mut self) -> Option<&mut T> {
+        self.poll();
+        self.ready.as_mut()
+    }
+
+    /// Take the value, if it has arrived.
+    ///
+    /// # Errors
+    /// Returns the promise back if the value has not arrived.
+    pub fn try_take(mut self) -> Result<T, Self> {
+        self.poll();
+        match self.ready.take() {
+            Some(value) => Ok(value),
+            None => Err(self),
+        }
+    }
+
+    /// Has the value arrived?
+    pub fn is_ready(&mut self) -> bool {
+        self.ready().is_some()
+    }
+
+    /// Are we still waiting for the value?
+    pub fn is_pending(&mut self) -> bool {
+        matches!(self.poll(), PromiseState::Pending { .. })
+    }
+}
+
+impl<T> std::fmt::Debug for Promise<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Promise")
+            .field("ready", &self.ready.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Fulfills a [`Promise`] from another thread or async task.
+///
+/// Dropping it without calling [`Self::send`] marks the promise as [`PromiseState::Abandoned`].
+pub struct PromiseSender<T> {
+    pending: Option<Arc<Mutex<Pending<T>>>>,
+    ctx: Context,
+}
+
+impl<T> PromiseSender<T> {
+    /// Fulfill the promise, and request a repaint so the ui can show the value.
+    pub fn send(mut self, value: T) {
+        if let Some(pending) = self.pending.take() {
+            pending.lock().value = Some(value);
+            self.ctx.request_repaint();
+        }
+    }
+
+    /// Report how far along we are, in `[0, 1]`.
+    ///
+    /// [`crate::Ui::await_promise`] shows this as a progress bar instead of a spinner.
+    pub fn set_progress(&self, progress: f32) {
+        if let Some(pending) = &self.pending {
+            pending.lock().progress = Some(progress.clamp(0.0, 1.0));
+            self.ctx.request_repaint();
+        }
+    }
+
+    /// The context that is repainted when the value arrives.
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+}
+
+impl<T> Drop for PromiseSender<T> {
+    fn drop(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            pending.lock().abandoned = true;
+            self.ctx.request_repaint();
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for PromiseSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PromiseSender").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_promise() {
+        let ctx = Context::default();
+
+        let (sender, mut promise) = Promise::new(&ctx);
+        assert_eq!(promise.poll(), PromiseState::Pending { progress: None });
+        sender.set_progress(0.25);
+        assert_eq!(
+            promise.poll(),
+            PromiseState::Pending {
+                progress: Some(0.25)
+            }
+        );
+        sender.send(42);
+        assert_eq!(promise.ready(), Some(&42));
+        assert_eq!(promise.try_take().ok(), Some(42));
+
+        let (sender, mut promise) = Promise::<i32>::new(&ctx);
+        drop(sender);
+        assert_eq!(promise.poll(), PromiseState::Abandoned);
+
+        let mut promise = Promise::spawn_thread(&ctx, "test", |_| 1 + 1);
+        let value = loop {
+            if let Some(value) = promise.ready() {
+                break *value;
+            }
+            std::thread::yield_now();
+        };
+        assert_eq!(value, 2);
+    }
+}
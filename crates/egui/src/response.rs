@@ -1,7 +1,7 @@
 use std::{any::Any, sync::Arc};
 
 use crate::{
-    Context, CursorIcon, Id, LayerId, PointerButton, Popup, PopupKind, Sense, Tooltip, Ui,
+    Context, Cursor, CursorIcon, Id, LayerId, PointerButton, Popup, PopupKind, Sense, Tooltip, Ui,
     WidgetRect, WidgetText,
     emath::{Align, Pos2, Rect, Vec2},
     pass_state,
@@ -205,18 +205,51 @@ impl Response {
         self.triple_clicked_by(PointerButton::Primary)
     }
 
+    /// Returns true if this widget was clicked `n` times in a row by the primary button,
+    /// with the `n`th click this frame.
+    ///
+    /// `clicked_n(2)` is the same as [`Self::double_clicked`], and `clicked_n(3)` as [`Self::triple_clicked`].
+    /// How close in time and space the clicks must be is set in [`crate::InputOptions`].
+    #[inline]
+    pub fn clicked_n(&self, n: u32) -> bool {
+        self.clicked_n_by(PointerButton::Primary, n)
+    }
+
+    /// Returns true if this widget was clicked `n` times in a row by the given button,
+    /// with the `n`th click this frame.
+    #[inline]
+    pub fn clicked_n_by(&self, button: PointerButton, n: u32) -> bool {
+        self.click_count_by(button) == Some(n)
+    }
+
+    /// If this widget was clicked this frame by the primary button, how many clicks in a row is this?
+    ///
+    /// `1` for a single click, `2` for a double click, and so on.
+    #[inline]
+    pub fn click_count(&self) -> Option<u32> {
+        self.click_count_by(PointerButton::Primary)
+    }
+
+    /// If this widget was clicked this frame by the given button, how many clicks in a row is this?
+    #[inline]
+    pub fn click_count_by(&self, button: PointerButton) -> Option<u32> {
+        if self.flags.contains(Flags::CLICKED) {
+            self.ctx.input(|i| i.pointer.button_click_count(button))
+        } else {
+            None
+        }
+    }
+
     /// Returns true if this widget was double-clicked this frame by the given button.
     #[inline]
     pub fn double_clicked_by(&self, button: PointerButton) -> bool {
-        self.flags.contains(Flags::CLICKED)
-            && self.ctx.input(|i| i.pointer.button_double_clicked(button))
+        self.clicked_n_by(button, 2)
     }
 
     /// Returns true if this widget was triple-clicked this frame by the given button.
     #[inline]
     pub fn triple_clicked_by(&self, button: PointerButton) -> bool {
-        self.flags.contains(Flags::CLICKED)
-            && self.ctx.input(|i| i.pointer.button_triple_clicked(button))
+        self.clicked_n_by(button, 3)
     }
 
     /// Was this widget middle-clicked or clicked while holding down a modifier key?
@@ -338,6 +371,38 @@ impl Response {
         self.ctx.memory_mut(|mem| mem.surrender_focus(self.id));
     }
 
+    /// Change where this widget is in the order of tab and shift+tab.
+    ///
+    /// Like in HTML, widgets with a positive tab index come first, from low to high,
+    /// followed by the ones with a tab index of zero (the default) in the order they were added.
+    /// Widgets with a negative tab index are skipped, but can still be clicked or focused
+    /// with [`Self::request_focus`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let (mut name, mut email) = (String::new(), String::new());
+    /// // Tab from the name goes to the "Submit" button, skipping the email:
+    /// ui.text_edit_singleline(&mut name).tab_index(1);
+    /// ui.text_edit_singleline(&mut email);
+    /// ui.button("Submit").tab_index(2);
+    /// # });
+    /// ```
+    pub fn tab_index(self, tab_index: i32) -> Self {
+        self.ctx
+            .memory_mut(|mem| mem.set_tab_index(self.id, tab_index));
+        self
+    }
+
+    /// Can the focus move to this widget with a gamepad, see [`crate::Event::GamepadNav`]?
+    ///
+    /// Pass `false` to skip e.g. decorative or rarely used widgets in a TV interface.
+    /// Default: `true`.
+    pub fn gamepad_navigable(self, navigable: bool) -> Self {
+        self.ctx
+            .memory_mut(|mem| mem.set_gamepad_navigable(self.id, navigable));
+        self
+    }
+
     /// Did a drag on this widget begin this frame?
     ///
     /// This is only true if the widget sense drags.
@@ -469,6 +534,70 @@ impl Response {
         }
     }
 
+    /// Drag-and-Drop: the files or data dragged from another app that are held over this widget, if any.
+    ///
+    /// Requires the integration to report where the pointer is while dragging, which `eframe` does on the web.
+    /// See also [`Context::set_drop_effect`].
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_hover_os_files(&self) -> Vec<crate::HoveredFile> {
+        if self.contains_pointer() {
+            self.ctx.input(|i| i.raw.hovered_files.clone())
+        } else {
+            vec![]
+        }
+    }
+
+    /// Drag-and-Drop: the files or data from another app that were dropped onto this widget this frame, if any.
+    ///
+    /// See [`Self::dnd_hover_os_files`].
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_release_os_files(&self) -> Vec<crate::DroppedFile> {
+        if self.contains_pointer() {
+            self.ctx.input(|i| i.raw.dropped_files.clone())
+        } else {
+            vec![]
+        }
+    }
+
+    /// Let this widget get all pointer events from the next pass on, even when the pointer leaves it.
+    ///
+    /// While captured, the widget is hovered and contains the pointer wherever the pointer is,
+    /// presses anywhere go to it, and [`Self::interact_pointer_pos`] is always set.
+    /// Nothing else is hovered meanwhile.
+    /// The capture is released when the last pointer button is let go,
+    /// or with [`Self::release_pointer_capture`].
+    /// It is ignored if no pointer button is down.
+    ///
+    /// This is useful for custom widgets like timelines that keep scrubbing
+    /// while the pointer is outside of them.
+    /// See [`crate::InteractionSnapshot::routed_pointer_events`] for which widget got which event.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut time = 0.0_f32;
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(200.0, 20.0), egui::Sense::drag());
+    /// if response.is_pointer_button_down_on() {
+    ///     response.capture_pointer();
+    /// }
+    /// if let Some(pos) = response.interact_pointer_pos() {
+    ///     time = egui::remap_clamp(pos.x, rect.x_range(), 0.0..=60.0);
+    /// }
+    /// # });
+    /// ```
+    pub fn capture_pointer(&self) {
+        self.ctx.capture_pointer(self.id);
+    }
+
+    /// Release the pointer capture, if this widget has it. See [`Self::capture_pointer`].
+    pub fn release_pointer_capture(&self) {
+        self.ctx.release_pointer_capture(self.id);
+    }
+
+    /// Has this widget captured the pointer? See [`Self::capture_pointer`].
+    pub fn has_pointer_capture(&self) -> bool {
+        self.ctx.pointer_capture_id() == Some(self.id)
+    }
+
     /// Where the pointer (mouse/touch) were when this widget was clicked or dragged.
     ///
     /// `None` if the widget is not being interacted with.
@@ -493,6 +622,39 @@ impl Response {
         }
     }
 
+    /// A touch gesture on this widget this frame, e.g. a long press, swipe or pinch.
+    ///
+    /// A gesture is on the widget if it starts there, or is centered there for pinches and rotations.
+    /// If there are several this frame (e.g. pinching while rotating) this is the first one,
+    /// see [`crate::InputState::gestures`] for all of them.
+    ///
+    /// The thresholds for recognizing gestures are in [`crate::style::Interaction`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut page = 0;
+    /// let response = ui.label("Swipe left for the next page");
+    /// if let Some(egui::Gesture::Swipe { velocity, .. }) = response.gesture() {
+    ///     if velocity.x < 0.0 {
+    ///         page += 1;
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn gesture(&self) -> Option<crate::Gesture> {
+        if !self.enabled() {
+            return None;
+        }
+        let gestures = self.ctx.input(|i| i.gestures().to_vec());
+        let from_global = self.ctx.layer_transform_from_global(self.layer_id);
+        gestures.into_iter().find(|gesture| {
+            let pos = gesture.pos();
+            let local_pos = from_global.map_or(pos, |from_global| from_global * pos);
+            self.interact_rect.contains(local_pos)
+                && self.ctx.layer_id_at(pos) == Some(self.layer_id)
+        })
+    }
+
     /// Is the pointer button currently down on this widget?
     ///
     /// This is true if the pointer is pressing down or dragging a widget,
@@ -575,6 +737,15 @@ impl Response {
         self
     }
 
+    /// Show a [`crate::RichTooltip`] when hovering, with a title, body, and shortcut hint.
+    ///
+    /// The tooltip will not be visible if the widget is not enabled.
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_rich(self, tooltip: crate::RichTooltip) -> Self {
+        tooltip.show(&self, |_ui| {});
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_disabled(&self).show(add_contents);
@@ -665,20 +836,26 @@ impl Response {
         })
     }
 
-    /// When hovered, use this icon for the mouse cursor.
+    /// When hovered, use this cursor.
+    ///
+    /// This can be a [`CursorIcon`], or a custom cursor registered with [`Context::register_cursor`].
+    ///
+    /// If several widgets set the cursor in the same pass, the one on the top-most layer wins.
     #[inline]
-    pub fn on_hover_cursor(self, cursor: CursorIcon) -> Self {
+    pub fn on_hover_cursor(self, cursor: impl Into<Cursor>) -> Self {
         if self.hovered() {
-            self.ctx.set_cursor_icon(cursor);
+            self.ctx.set_widget_cursor(self.layer_id, cursor.into());
         }
         self
     }
 
-    /// When hovered or dragged, use this icon for the mouse cursor.
+    /// When hovered or dragged, use this cursor.
+    ///
+    /// See [`Self::on_hover_cursor`].
     #[inline]
-    pub fn on_hover_and_drag_cursor(self, cursor: CursorIcon) -> Self {
+    pub fn on_hover_and_drag_cursor(self, cursor: impl Into<Cursor>) -> Self {
         if self.hovered() || self.dragged() {
-            self.ctx.set_cursor_icon(cursor);
+            self.ctx.set_widget_cursor(self.layer_id, cursor.into());
         }
         self
     }
@@ -708,6 +885,7 @@ impl Response {
     /// # });
     /// ```
     #[must_use]
+    #[track_caller]
     pub fn interact(&self, sense: Sense) -> Self {
         if (self.sense | sense) == self.sense {
             // Early-out: we already sense everything we need to sense.
@@ -811,6 +989,7 @@ impl Response {
         self.ctx
             .register_widget_info(self.id, || event.widget_info().clone());
 
+        self.ctx.report_widget_interaction(self.id, &event);
         self.ctx.output_mut(|o| o.events.push(event));
     }
 
@@ -839,30 +1018,10 @@ impl Response {
         builder: &mut accesskit::Node,
         info: crate::WidgetInfo,
     ) {
-        use crate::WidgetType;
         use accesskit::{Role, Toggled};
 
         self.fill_accesskit_node_common(builder);
-        builder.set_role(match info.typ {
-            WidgetType::Label => Role::Label,
-            WidgetType::Link => Role::Link,
-            WidgetType::TextEdit => Role::TextInput,
-            WidgetType::Button | WidgetType::ImageButton | WidgetType::CollapsingHeader => {
-                Role::Button
-            }
-            WidgetType::Image => Role::Image,
-            WidgetType::Checkbox => Role::CheckBox,
-            WidgetType::RadioButton => Role::RadioButton,
-            WidgetType::RadioGroup => Role::RadioGroup,
-            WidgetType::SelectableLabel => Role::Button,
-            WidgetType::ComboBox => Role::ComboBox,
-            WidgetType::Slider => Role::Slider,
-            WidgetType::DragValue => Role::SpinButton,
-            WidgetType::ColorButton => Role::ColorWell,
-            WidgetType::ProgressIndicator => Role::ProgressIndicator,
-            WidgetType::Window => Role::Window,
-            WidgetType::Other => Role::Unknown,
-        });
+        builder.set_role(info.typ.accesskit_role());
         if !info.enabled {
             builder.set_disabled();
         }
@@ -885,7 +1044,7 @@ impl Response {
             } else {
                 Toggled::False
             });
-        } else if matches!(info.typ, WidgetType::Checkbox) {
+        } else if matches!(info.typ, crate::WidgetType::Checkbox) {
             // Indeterminate state
             builder.set_toggled(Toggled::Mixed);
         }
@@ -920,6 +1079,42 @@ impl Response {
         self
     }
 
+    /// Describe this widget to assistive technologies, e.g. to give a custom widget
+    /// a role, a label, a value and the actions it supports.
+    ///
+    /// Call this after [`Self::widget_info`], since that overwrites the role and label.
+    /// The closure is only called when AccessKit is active, so this is cheap otherwise.
+    ///
+    /// The [`Context`] lock is held while the closure is called, so don't use the [`Context`] in it.
+    ///
+    /// See [`crate::AccessibilityNode`] for an example.
+    pub fn accessibility(&self, add_info: impl FnOnce(&mut crate::AccessibilityNode<'_>)) {
+        #[cfg(feature = "accesskit")]
+        self.ctx.accesskit_node_builder(self.id, |node| {
+            add_info(&mut crate::AccessibilityNode::new(node));
+        });
+        #[cfg(not(feature = "accesskit"))]
+        {
+            let _ = add_info;
+        }
+    }
+
+    /// Did an assistive technology request this action on the widget this frame?
+    ///
+    /// Always `false` when egui is compiled without the `accesskit` feature.
+    pub fn accessibility_action_requested(&self, action: crate::AccessibilityAction) -> bool {
+        #[cfg(feature = "accesskit")]
+        {
+            self.ctx
+                .input(|i| i.has_accesskit_action_request(self.id, action.accesskit_action()))
+        }
+        #[cfg(not(feature = "accesskit"))]
+        {
+            let _ = action;
+            false
+        }
+    }
+
     /// Response to secondary clicks (right-clicks) by showing the given menu.
     ///
     /// Make sure the widget senses clicks (e.g. [`crate::Button`] does, [`crate::Label`] does not).
@@ -941,6 +1136,17 @@ impl Response {
         Popup::context_menu(self).show(add_contents)
     }
 
+    /// Like [`Self::context_menu`], but build the menu with a [`crate::MenuBuilder`],
+    /// which makes it easy to add icons, shortcuts, checkable items, sections and submenus.
+    ///
+    /// See [`crate::MenuBuilder`] for an example.
+    pub fn context_menu_builder(
+        &self,
+        build: impl FnOnce(&mut crate::MenuBuilder<'_>),
+    ) -> Option<InnerResponse<()>> {
+        self.context_menu(|ui| build(&mut crate::MenuBuilder::new(ui)))
+    }
+
     /// Returns whether a context menu is currently open for this widget.
     ///
     /// See [`Self::context_menu`].
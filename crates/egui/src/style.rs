@@ -14,6 +14,10 @@ use crate::{
     reset_button_with,
 };
 
+mod overlay;
+
+pub use overlay::StyleOverlay;
+
 /// How to format numbers in e.g. a [`crate::DragValue`].
 #[derive(Clone)]
 pub struct NumberFormatter(
@@ -311,9 +315,24 @@ pub struct Style {
     /// Colors etc.
     pub visuals: Visuals,
 
+    /// Left-to-right or right-to-left.
+    ///
+    /// Right-to-left mirrors the default layouts, icon placement and panel sides,
+    /// see [`crate::LayoutDirection`].
+    pub layout_direction: crate::LayoutDirection,
+
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// Avoid animations, for users who are bothered by motion.
+    ///
+    /// When `true`, the `animate_*` functions of [`crate::Context`] jump straight to their target,
+    /// scrolling is instant, and areas appear and disappear without fading.
+    ///
+    /// This is set automatically from the operating system preference
+    /// unless [`crate::Options::follow_system_accessibility`] is turned off.
+    pub reduced_motion: bool,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -344,6 +363,27 @@ fn style_impl_send_sync() {
     assert_send_sync::<Style>();
 }
 
+#[test]
+fn style_lerp() {
+    let a = Style::default();
+    let mut b = Style {
+        visuals: Visuals::light(),
+        ..Default::default()
+    };
+    b.spacing.item_spacing = vec2(20.0, 10.0);
+
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+
+    let halfway = a.lerp(&b, 0.5);
+    assert_eq!(
+        halfway.spacing.item_spacing,
+        (a.spacing.item_spacing + b.spacing.item_spacing) / 2.0
+    );
+    assert_ne!(halfway.visuals.panel_fill, a.visuals.panel_fill);
+    assert_ne!(halfway.visuals.panel_fill, b.visuals.panel_fill);
+}
+
 impl Style {
     // TODO(emilk): rename style.interact() to maybe… `style.interactive` ?
     /// Use this style for interactive things.
@@ -373,6 +413,107 @@ impl Style {
     pub fn text_styles(&self) -> Vec<TextStyle> {
         self.text_styles.keys().cloned().collect()
     }
+
+    /// Interpolate between two styles: `t = 0.0` gives `self`, and `t = 1.0` gives `other`.
+    ///
+    /// Font sizes, spacing, and the colors of text, panels, windows and widgets are interpolated.
+    /// Everything else (e.g. font families and flags) is taken from the style `t` is closest to.
+    ///
+    /// Useful for animating between two looks, e.g. for scroll-linked animations
+    /// with [`crate::ScrollArea::scroll_progress`].
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mut style = if t < 0.5 { self.clone() } else { other.clone() };
+
+        for (text_style, font_id) in &mut style.text_styles {
+            if let (Some(a), Some(b)) = (
+                self.text_styles.get(text_style),
+                other.text_styles.get(text_style),
+            ) {
+                font_id.size = emath::lerp(a.size..=b.size, t);
+            }
+        }
+        lerp_spacing(&mut style.spacing, &self.spacing, &other.spacing, t);
+        lerp_visuals(&mut style.visuals, &self.visuals, &other.visuals, t);
+
+        style
+    }
+}
+
+fn lerp_margin(a: Margin, b: Margin, t: f32) -> Margin {
+    let lerp = |a: i8, b: i8| emath::lerp(a as f32..=b as f32, t).round() as i8;
+    Margin {
+        left: lerp(a.left, b.left),
+        right: lerp(a.right, b.right),
+        top: lerp(a.top, b.top),
+        bottom: lerp(a.bottom, b.bottom),
+    }
+}
+
+fn lerp_stroke(a: Stroke, b: Stroke, t: f32) -> Stroke {
+    Stroke::new(
+        emath::lerp(a.width..=b.width, t),
+        a.color.lerp_to_gamma(b.color, t),
+    )
+}
+
+fn lerp_spacing(spacing: &mut Spacing, a: &Spacing, b: &Spacing, t: f32) {
+    spacing.item_spacing = emath::lerp(a.item_spacing..=b.item_spacing, t);
+    spacing.window_margin = lerp_margin(a.window_margin, b.window_margin, t);
+    spacing.button_padding = emath::lerp(a.button_padding..=b.button_padding, t);
+    spacing.menu_margin = lerp_margin(a.menu_margin, b.menu_margin, t);
+    spacing.indent = emath::lerp(a.indent..=b.indent, t);
+    spacing.interact_size = emath::lerp(a.interact_size..=b.interact_size, t);
+    spacing.icon_width = emath::lerp(a.icon_width..=b.icon_width, t);
+    spacing.icon_width_inner = emath::lerp(a.icon_width_inner..=b.icon_width_inner, t);
+    spacing.icon_spacing = emath::lerp(a.icon_spacing..=b.icon_spacing, t);
+}
+
+fn lerp_visuals(visuals: &mut Visuals, a: &Visuals, b: &Visuals, t: f32) {
+    if let (Some(a), Some(b)) = (a.override_text_color, b.override_text_color) {
+        visuals.override_text_color = Some(a.lerp_to_gamma(b, t));
+    }
+    visuals.hyperlink_color = a.hyperlink_color.lerp_to_gamma(b.hyperlink_color, t);
+    visuals.faint_bg_color = a.faint_bg_color.lerp_to_gamma(b.faint_bg_color, t);
+    visuals.extreme_bg_color = a.extreme_bg_color.lerp_to_gamma(b.extreme_bg_color, t);
+    visuals.code_bg_color = a.code_bg_color.lerp_to_gamma(b.code_bg_color, t);
+    visuals.window_fill = a.window_fill.lerp_to_gamma(b.window_fill, t);
+    visuals.window_stroke = lerp_stroke(a.window_stroke, b.window_stroke, t);
+    visuals.panel_fill = a.panel_fill.lerp_to_gamma(b.panel_fill, t);
+
+    visuals.selection.bg_fill = a.selection.bg_fill.lerp_to_gamma(b.selection.bg_fill, t);
+    visuals.selection.stroke = lerp_stroke(a.selection.stroke, b.selection.stroke, t);
+
+    let widgets = [
+        (
+            &mut visuals.widgets.noninteractive,
+            &a.widgets.noninteractive,
+            &b.widgets.noninteractive,
+        ),
+        (
+            &mut visuals.widgets.inactive,
+            &a.widgets.inactive,
+            &b.widgets.inactive,
+        ),
+        (
+            &mut visuals.widgets.hovered,
+            &a.widgets.hovered,
+            &b.widgets.hovered,
+        ),
+        (
+            &mut visuals.widgets.active,
+            &a.widgets.active,
+            &b.widgets.active,
+        ),
+        (&mut visuals.widgets.open, &a.widgets.open, &b.widgets.open),
+    ];
+    for (widget, a, b) in widgets {
+        widget.bg_fill = a.bg_fill.lerp_to_gamma(b.bg_fill, t);
+        widget.weak_bg_fill = a.weak_bg_fill.lerp_to_gamma(b.weak_bg_fill, t);
+        widget.bg_stroke = lerp_stroke(a.bg_stroke, b.bg_stroke, t);
+        widget.fg_stroke = lerp_stroke(a.fg_stroke, b.fg_stroke, t);
+        widget.expansion = emath::lerp(a.expansion..=b.expansion, t);
+    }
 }
 
 /// Controls the sizes and distances between widgets.
@@ -848,6 +989,16 @@ pub struct Interaction {
     /// Radius of the interactive area of the corner of a window during drag-to-resize.
     pub resize_grab_radius_corner: f32,
 
+    /// How close the edge of a [`crate::Window`] must be to another edge to snap to it,
+    /// for windows with [`crate::Window::snap`] or [`crate::Window::tile`].
+    pub window_snap_distance: f32,
+
+    /// If set, windows and other movable [`crate::Area`]s snap to a grid with this spacing
+    /// when they are moved or resized, e.g. to arrange tool windows neatly.
+    ///
+    /// Hold down [`crate::Modifiers::alt`] to place them freely.
+    pub layout_grid: Option<f32>,
+
     /// If `false`, tooltips will show up anytime you hover anything, even if mouse is still moving
     pub show_tooltips_only_when_still: bool,
 
@@ -869,6 +1020,21 @@ pub struct Interaction {
     /// The default is `true`, but text selection can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// A touch held still for this many seconds is a [`crate::Gesture::LongPress`].
+    pub long_press_duration: f32,
+
+    /// A second tap within this many seconds of the first is a [`crate::Gesture::DoubleTap`].
+    pub double_tap_delay: f32,
+
+    /// A touch that moves further than this is no longer a [`crate::Gesture::Tap`] or [`crate::Gesture::LongPress`].
+    ///
+    /// The two taps of a [`crate::Gesture::DoubleTap`] must also be this close.
+    pub tap_max_distance: f32,
+
+    /// A touch that lets go while moving faster than this (in points per second)
+    /// is a [`crate::Gesture::Swipe`].
+    pub swipe_min_velocity: f32,
 }
 
 /// Look and feel of the text cursor.
@@ -1001,6 +1167,11 @@ pub struct Visuals {
     /// How the text cursor acts.
     pub text_cursor: TextCursorStyle,
 
+    /// The ring around the focused widget while navigating with a gamepad, see [`crate::Event::GamepadNav`].
+    ///
+    /// Thick and bright by default, so it can be seen from across the room.
+    pub gamepad_focus_stroke: Stroke,
+
     /// Allow child widgets to be just on the border and still have a stroke with some thickness
     pub clip_rect_margin: f32,
 
@@ -1267,6 +1438,21 @@ pub struct DebugOptions {
     ///
     /// See [`emath::GuiRounding`] for more.
     pub show_unaligned: bool,
+
+    /// Show how many widgets and shapes each layer has.
+    pub show_layer_stats: bool,
+
+    /// Show a heatmap of where many shapes are painted on top of each other.
+    ///
+    /// This is based on the bounding rectangles of the shapes, so it is only an estimate.
+    pub show_overdraw: bool,
+
+    /// Show the widgets that appeared, moved or disappeared since the last pass,
+    /// and list why a repaint was requested.
+    ///
+    /// Widgets that change every pass cause a repaint every frame,
+    /// see [`crate::Options::repaint_on_widget_change`].
+    pub show_repaint_causes: bool,
 }
 
 #[cfg(debug_assertions)]
@@ -1283,6 +1469,9 @@ impl Default for DebugOptions {
             show_interactive_widgets: false,
             show_widget_hits: false,
             show_unaligned: cfg!(debug_assertions),
+            show_layer_stats: false,
+            show_overdraw: false,
+            show_repaint_causes: false,
         }
     }
 }
@@ -1318,7 +1507,9 @@ impl Default for Style {
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
+            layout_direction: Default::default(),
             animation_time: 1.0 / 12.0,
+            reduced_motion: false,
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1363,11 +1554,17 @@ impl Default for Interaction {
             interact_radius: 5.0,
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
+            window_snap_distance: 8.0,
+            layout_grid: None,
             show_tooltips_only_when_still: true,
             tooltip_delay: 0.5,
             tooltip_grace_time: 0.2,
             selectable_labels: true,
             multi_widget_text_select: true,
+            long_press_duration: 0.5,
+            double_tap_delay: 0.3,
+            tap_max_distance: 12.0,
+            swipe_min_velocity: 400.0,
         }
     }
 }
@@ -1417,6 +1614,8 @@ impl Visuals {
 
             text_cursor: Default::default(),
 
+            gamepad_focus_stroke: Stroke::new(3.0, Color32::from_rgb(255, 200, 0)),
+
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
@@ -1473,9 +1672,46 @@ impl Visuals {
                 ..Default::default()
             },
 
+            gamepad_focus_stroke: Stroke::new(3.0, Color32::from_rgb(0, 110, 230)),
+
             ..Self::dark()
         }
     }
+
+    /// These visuals with stronger text and borders, for users who need more contrast.
+    ///
+    /// Applied automatically when the operating system asks for high contrast,
+    /// unless [`crate::Options::follow_system_accessibility`] is turned off.
+    pub fn high_contrast(mut self) -> Self {
+        let (text, border) = if self.dark_mode {
+            (Color32::WHITE, Color32::from_gray(200))
+        } else {
+            (Color32::BLACK, Color32::from_gray(60))
+        };
+
+        let Widgets {
+            noninteractive,
+            inactive,
+            hovered,
+            active,
+            open,
+        } = &mut self.widgets;
+        for widget in [noninteractive, inactive, open] {
+            widget.fg_stroke.color = text;
+            widget.bg_stroke = Stroke::new(widget.bg_stroke.width.max(1.0), border);
+        }
+        for widget in [hovered, active] {
+            widget.fg_stroke.color = text;
+            widget.bg_stroke = Stroke::new(widget.bg_stroke.width.max(1.0), text);
+        }
+
+        self.override_text_color = None;
+        self.weak_text_color = None;
+        self.weak_text_alpha = self.weak_text_alpha.max(0.85);
+        self.window_stroke.color = border;
+        self.disabled_alpha = self.disabled_alpha.max(0.7);
+        self
+    }
 }
 
 impl Default for Visuals {
@@ -1626,7 +1862,9 @@ impl Style {
             spacing,
             interaction,
             visuals,
+            layout_direction,
             animation_time,
+            reduced_motion,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1720,6 +1958,21 @@ impl Style {
                 });
             ui.end_row();
 
+            ui.label("Layout direction");
+            ui.horizontal(|ui| {
+                ui.selectable_value(
+                    layout_direction,
+                    crate::LayoutDirection::Ltr,
+                    "Left to right",
+                );
+                ui.selectable_value(
+                    layout_direction,
+                    crate::LayoutDirection::Rtl,
+                    "Right to left",
+                );
+            });
+            ui.end_row();
+
             ui.label("Animation duration");
             ui.add(
                 DragValue::new(animation_time)
@@ -1728,6 +1981,11 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduced motion");
+            ui.checkbox(reduced_motion, "")
+                .on_hover_text("Skip animations, smooth scrolling and fades");
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text styles", |ui| text_styles_ui(ui, text_styles));
@@ -1904,11 +2162,17 @@ impl Interaction {
             interact_radius,
             resize_grab_radius_side,
             resize_grab_radius_corner,
+            window_snap_distance,
+            layout_grid,
             show_tooltips_only_when_still,
             tooltip_delay,
             tooltip_grace_time,
             selectable_labels,
             multi_widget_text_select,
+            long_press_duration,
+            double_tap_delay,
+            tap_max_distance,
+            swipe_min_velocity,
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -1930,6 +2194,23 @@ impl Interaction {
                 ui.add(DragValue::new(resize_grab_radius_corner).range(0.0..=20.0));
                 ui.end_row();
 
+                ui.label("window_snap_distance").on_hover_text("How close a window edge must be to another edge to snap to it.");
+                ui.add(DragValue::new(window_snap_distance).range(0.0..=50.0));
+                ui.end_row();
+
+                ui.label("layout_grid").on_hover_text("Windows snap to a grid with this spacing when moved or resized. Hold Alt to place them freely.");
+                ui.horizontal(|ui| {
+                    let mut set = layout_grid.is_some();
+                    ui.checkbox(&mut set, "");
+                    if set {
+                        let spacing = layout_grid.get_or_insert(16.0);
+                        ui.add(DragValue::new(spacing).range(1.0..=100.0));
+                    } else {
+                        *layout_grid = None;
+                    }
+                });
+                ui.end_row();
+
                 ui.label("Tooltip delay").on_hover_text(
                     "Delay in seconds before showing tooltips after the mouse stops moving",
                 );
@@ -1951,6 +2232,38 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Long press duration")
+                    .on_hover_text("Hold a touch still this long for a long press");
+                ui.add(
+                    DragValue::new(long_press_duration)
+                        .range(0.1..=2.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Double tap delay")
+                    .on_hover_text("The second tap of a double tap must come within this time");
+                ui.add(
+                    DragValue::new(double_tap_delay)
+                        .range(0.1..=1.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Tap max distance").on_hover_text(
+                    "A touch that moves further than this is not a tap or long press",
+                );
+                ui.add(DragValue::new(tap_max_distance).range(0.0..=50.0));
+                ui.end_row();
+
+                ui.label("Swipe min velocity").on_hover_text(
+                    "Let go of a touch while moving faster than this (points per second) to swipe",
+                );
+                ui.add(DragValue::new(swipe_min_velocity).range(0.0..=5000.0).speed(10.0));
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -2103,6 +2416,8 @@ impl Visuals {
 
             text_cursor,
 
+            gamepad_focus_stroke,
+
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
@@ -2232,6 +2547,11 @@ impl Visuals {
             text_cursor.ui(ui);
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Gamepad focus");
+            ui.add(gamepad_focus_stroke);
+        });
+
         ui.collapsing("Window", |ui| {
             Grid::new("window")
                 .num_columns(2)
@@ -2426,6 +2746,9 @@ impl DebugOptions {
             show_interactive_widgets,
             show_widget_hits,
             show_unaligned,
+            show_layer_stats,
+            show_overdraw,
+            show_repaint_causes,
         } = self;
 
         {
@@ -2460,6 +2783,13 @@ impl DebugOptions {
             "Show rectangles not aligned to integer point coordinates",
         );
 
+        ui.checkbox(show_layer_stats, "Show widget and shape counts per layer");
+        ui.checkbox(show_overdraw, "Show an overdraw heatmap");
+        ui.checkbox(
+            show_repaint_causes,
+            "Show widgets that changed, and why we repaint",
+        );
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset debug options"));
     }
 }
@@ -2512,7 +2842,11 @@ pub enum NumericColorSpace {
 
     /// 0-1 in linear space.
     Linear,
-    // TODO(emilk): add Hex as an option
+
+    /// A hex string, like `#RRGGBB` or `#RRGGBBAA`.
+    ///
+    /// CSS colors like `rgb(255, 0, 0)` or `red` can also be entered or pasted.
+    Hex,
 }
 
 impl NumericColorSpace {
@@ -2520,13 +2854,15 @@ impl NumericColorSpace {
         let tooltip = match self {
             Self::GammaByte => "Showing color values in 0-255 gamma space",
             Self::Linear => "Showing color values in 0-1 linear space",
+            Self::Hex => "Showing color values as a hex string",
         };
 
         let mut response = ui.button(self.to_string()).on_hover_text(tooltip);
         if response.clicked() {
             *self = match self {
                 Self::GammaByte => Self::Linear,
-                Self::Linear => Self::GammaByte,
+                Self::Linear => Self::Hex,
+                Self::Hex => Self::GammaByte,
             };
             response.mark_changed();
         }
@@ -2539,6 +2875,7 @@ impl std::fmt::Display for NumericColorSpace {
         match self {
             Self::GammaByte => write!(f, "U8"),
             Self::Linear => write!(f, "F"),
+            Self::Hex => write!(f, "#"),
         }
     }
 }
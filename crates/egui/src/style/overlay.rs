@@ -0,0 +1,262 @@
+use std::collections::BTreeSet;
+
+use crate::{Style, Ui};
+
+/// A field of [`Style`] that a [`StyleOverlay`] can record.
+struct StyleField {
+    /// The path of the field, e.g. `"spacing.item_spacing"`.
+    name: &'static str,
+    differs: fn(&Style, &Style) -> bool,
+    copy: fn(&mut Style, &Style),
+}
+
+macro_rules! style_fields {
+    ($($first:ident $(. $rest:ident)*),* $(,)?) => {
+        &[$(StyleField {
+            name: concat!(stringify!($first) $(, ".", stringify!($rest))*),
+            differs: |a, b| a.$first$(.$rest)* != b.$first$(.$rest)*,
+            copy: |to, from| to.$first$(.$rest)* = from.$first$(.$rest)*.clone(),
+        }),*]
+    };
+}
+
+/// All the fields of [`Style`] that are recorded.
+///
+/// Nested structs that are edited as a whole (e.g. [`crate::style::ScrollStyle`]) are one field.
+/// Debug options are not part of a theme, and are not recorded.
+#[rustfmt::skip]
+const FIELDS: &[StyleField] = style_fields![
+    override_text_style,
+    override_font_id,
+    override_text_valign,
+    text_styles,
+    drag_value_text_style,
+    wrap_mode,
+    layout_direction,
+    animation_time,
+    reduced_motion,
+    explanation_tooltips,
+    url_in_tooltip,
+    always_scroll_the_only_direction,
+    scroll_animation,
+    compact_menu_style,
+
+    spacing.item_spacing,
+    spacing.window_margin,
+    spacing.button_padding,
+    spacing.menu_margin,
+    spacing.indent,
+    spacing.interact_size,
+    spacing.slider_width,
+    spacing.slider_rail_height,
+    spacing.combo_width,
+    spacing.text_edit_width,
+    spacing.icon_width,
+    spacing.icon_width_inner,
+    spacing.icon_spacing,
+    spacing.default_area_size,
+    spacing.tooltip_width,
+    spacing.menu_width,
+    spacing.menu_spacing,
+    spacing.indent_ends_with_horizontal_line,
+    spacing.combo_height,
+    spacing.scroll,
+
+    interaction.interact_radius,
+    interaction.resize_grab_radius_side,
+    interaction.resize_grab_radius_corner,
+    interaction.window_snap_distance,
+    interaction.layout_grid,
+    interaction.show_tooltips_only_when_still,
+    interaction.tooltip_delay,
+    interaction.tooltip_grace_time,
+    interaction.selectable_labels,
+    interaction.multi_widget_text_select,
+    interaction.long_press_duration,
+    interaction.double_tap_delay,
+    interaction.tap_max_distance,
+    interaction.swipe_min_velocity,
+
+    visuals.dark_mode,
+    visuals.text_alpha_from_coverage,
+    visuals.override_text_color,
+    visuals.weak_text_alpha,
+    visuals.weak_text_color,
+    visuals.widgets.noninteractive,
+    visuals.widgets.inactive,
+    visuals.widgets.hovered,
+    visuals.widgets.active,
+    visuals.widgets.open,
+    visuals.selection,
+    visuals.hyperlink_color,
+    visuals.faint_bg_color,
+    visuals.extreme_bg_color,
+    visuals.text_edit_bg_color,
+    visuals.code_bg_color,
+    visuals.warn_fg_color,
+    visuals.error_fg_color,
+    visuals.window_corner_radius,
+    visuals.window_shadow,
+    visuals.window_fill,
+    visuals.window_stroke,
+    visuals.window_highlight_topmost,
+    visuals.menu_corner_radius,
+    visuals.panel_fill,
+    visuals.popup_shadow,
+    visuals.resize_corner_size,
+    visuals.text_cursor,
+    visuals.gamepad_focus_stroke,
+    visuals.clip_rect_margin,
+    visuals.button_frame,
+    visuals.collapsing_header_frame,
+    visuals.indent_has_left_vline,
+    visuals.striped,
+    visuals.slider_trailing_fill,
+    visuals.handle_shape,
+    visuals.interact_cursor,
+    visuals.image_loading_spinners,
+    visuals.numeric_color_space,
+    visuals.disabled_alpha,
+];
+
+/// The changes a user made to a base theme, and only those.
+///
+/// If you ship a theme with your app, and let users tweak it (e.g. with [`Style::ui`]),
+/// store the user's tweaks as a [`StyleOverlay`] instead of storing the whole edited [`Style`].
+/// Then, when you ship an updated theme, the user keeps their tweaks,
+/// and gets the updates to everything they didn't touch.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # let app_theme = egui::Style::default();
+/// let mut overlay = egui::style::StyleOverlay::default();
+///
+/// // The user edits the style:
+/// let mut edited = app_theme.clone();
+/// edited.spacing.item_spacing = egui::vec2(12.0, 6.0);
+/// overlay.record(&app_theme, &edited);
+///
+/// // Later, the app ships a new theme, but the user's change is kept:
+/// let mut new_theme = app_theme.clone();
+/// new_theme.visuals.window_fill = egui::Color32::DARK_BLUE;
+/// let style = overlay.applied_to(&new_theme);
+/// assert_eq!(style.spacing.item_spacing, egui::vec2(12.0, 6.0));
+/// assert_eq!(style.visuals.window_fill, egui::Color32::DARK_BLUE);
+/// ctx.set_style(style);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StyleOverlay {
+    /// The names of the fields the user changed, e.g. `"spacing.item_spacing"`.
+    changed: BTreeSet<String>,
+
+    /// Where the values of the changed fields are taken from.
+    ///
+    /// The other fields are ignored.
+    values: Style,
+}
+
+impl StyleOverlay {
+    /// The overlay that turns `base` into `edited`.
+    pub fn from_diff(base: &Style, edited: &Style) -> Self {
+        let mut overlay = Self::default();
+        overlay.record(base, edited);
+        overlay
+    }
+
+    /// Record the fields of `edited` that differ from `base`.
+    ///
+    /// Fields that are back to their `base` value are no longer overridden.
+    pub fn record(&mut self, base: &Style, edited: &Style) {
+        for field in FIELDS {
+            if (field.differs)(base, edited) {
+                (field.copy)(&mut self.values, edited);
+                self.changed.insert(field.name.to_owned());
+            } else {
+                self.changed.remove(field.name);
+            }
+        }
+    }
+
+    /// Overwrite the changed fields of `style`.
+    pub fn apply(&self, style: &mut Style) {
+        for field in FIELDS {
+            if self.changed.contains(field.name) {
+                (field.copy)(style, &self.values);
+            }
+        }
+    }
+
+    /// The `base` style with the changed fields overwritten.
+    pub fn applied_to(&self, base: &Style) -> Style {
+        let mut style = base.clone();
+        self.apply(&mut style);
+        style
+    }
+
+    /// No fields are changed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+
+    /// The names of the changed fields, e.g. `"spacing.item_spacing"`.
+    pub fn changed_fields(&self) -> impl Iterator<Item = &str> {
+        self.changed.iter().map(String::as_str)
+    }
+
+    /// Stop overriding the given field, so that it follows the base theme again.
+    pub fn reset_field(&mut self, name: &str) {
+        self.changed.remove(name);
+    }
+
+    /// Stop overriding all fields.
+    pub fn clear(&mut self) {
+        self.changed.clear();
+    }
+
+    /// Show [`Style::ui`] for `base` with this overlay applied, and record the user's edits.
+    ///
+    /// Also lists the changed fields, with buttons to reset them to the base theme.
+    ///
+    /// Returns `true` if the overlay changed.
+    pub fn ui(&mut self, ui: &mut Ui, base: &Style) -> bool {
+        let before = self.changed.clone();
+
+        ui.collapsing("Changed fields", |ui| {
+            if self.is_empty() {
+                ui.weak("None");
+            }
+            let mut reset = None;
+            for name in &self.changed {
+                ui.horizontal(|ui| {
+                    if ui
+                        .small_button("⟲")
+                        .on_hover_text("Reset to the base theme")
+                        .clicked()
+                    {
+                        reset = Some(name.clone());
+                    }
+                    ui.monospace(name);
+                });
+            }
+            if let Some(name) = reset {
+                self.reset_field(&name);
+            }
+            if !self.is_empty() && ui.button("Reset all").clicked() {
+                self.clear();
+            }
+        });
+
+        let mut style = self.applied_to(base);
+        let unedited = style.clone();
+        style.ui(ui);
+
+        if style != unedited {
+            self.record(base, &style);
+        }
+
+        self.changed != before || style != unedited
+    }
+}
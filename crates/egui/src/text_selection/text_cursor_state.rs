@@ -65,13 +65,15 @@ impl TextCursorState {
     ) -> bool {
         let text = galley.text();
 
-        if response.double_clicked() {
+        // How close together the clicks must be is set in `InputOptions`.
+        let click_count = response.click_count().unwrap_or(0);
+        if click_count == 2 {
             // Select word:
             let ccursor_range = select_word_at(text, cursor_at_pointer);
             self.set_char_range(Some(ccursor_range));
             true
-        } else if response.triple_clicked() {
-            // Select line:
+        } else if 3 <= click_count {
+            // Select line, also on any further clicks:
             let ccursor_range = select_line_at(text, cursor_at_pointer);
             self.set_char_range(Some(ccursor_range));
             true
@@ -128,7 +128,7 @@ pub fn paint_text_cursor(
     primary_cursor_rect: Rect,
     time_since_last_interaction: f64,
 ) {
-    if ui.visuals().text_cursor.blink {
+    if ui.visuals().text_cursor.blink && !ui.ctx().is_low_power_idle() {
         let on_duration = ui.visuals().text_cursor.on_duration;
         let off_duration = ui.visuals().text_cursor.off_duration;
         let total_duration = on_duration + off_duration;
@@ -144,7 +144,7 @@ pub fn paint_text_cursor(
             total_duration - time_in_cycle
         };
 
-        ui.ctx().request_repaint_after_secs(wake_in);
+        ui.ctx().request_low_priority_repaint_after_secs(wake_in);
     } else {
         paint_cursor_end(painter, ui.visuals(), primary_cursor_rect);
     }
@@ -0,0 +1,473 @@
+//! A grid with explicitly sized tracks and spanning cells, see [`TrackGrid`].
+
+use crate::{
+    Align2, Context, Id, InnerResponse, Layout, NumExt as _, Rect, Sense, TextWrapMode, Ui,
+    UiBuilder, Vec2, pos2,
+};
+
+/// How wide a column, or how tall a row, of a [`TrackGrid`] is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Track {
+    /// Exactly this many points.
+    Fixed(f32),
+
+    /// A share of the space left over by the other tracks, proportional to the weight.
+    ///
+    /// If there is no limit to the available space (e.g. the height inside a vertical [`crate::ScrollArea`]),
+    /// this acts like [`Self::Auto`].
+    Weight(f32),
+
+    /// Just large enough to fit the contents of its cells.
+    Auto,
+}
+
+/// Refers to a column or row of a [`TrackGrid`], either by index or by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TrackRef {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for TrackRef {
+    #[inline]
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<&str> for TrackRef {
+    #[inline]
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+}
+
+impl From<String> for TrackRef {
+    #[inline]
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+/// Where to put a cell in a [`TrackGrid`], how many tracks it spans, and how to align its contents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridCell {
+    col: TrackRef,
+    row: TrackRef,
+    col_span: usize,
+    row_span: usize,
+    align: Option<Align2>,
+}
+
+impl GridCell {
+    /// The cell at this column and row.
+    pub fn new(col: impl Into<TrackRef>, row: impl Into<TrackRef>) -> Self {
+        Self {
+            col: col.into(),
+            row: row.into(),
+            col_span: 1,
+            row_span: 1,
+            align: None,
+        }
+    }
+
+    /// Let the cell cover this many columns and rows, starting at its own.
+    ///
+    /// Default: `(1, 1)`.
+    #[inline]
+    pub fn span(mut self, cols: usize, rows: usize) -> Self {
+        self.col_span = cols.at_least(1);
+        self.row_span = rows.at_least(1);
+        self
+    }
+
+    /// Align the contents within the cell.
+    ///
+    /// By default the contents fill the cell, starting at the top left.
+    #[inline]
+    pub fn align(mut self, align: Align2) -> Self {
+        self.align = Some(align);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MeasuredCell {
+    col: usize,
+    col_span: usize,
+    row: usize,
+    row_span: usize,
+    size: Vec2,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    cells: Vec<MeasuredCell>,
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_temp(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        // Like `Grid`, this is not persisted: the sizes are re-measured every frame.
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
+/// A grid where you decide the size of every column and row up front, and place cells explicitly.
+///
+/// Unlike [`crate::Grid`], which places widgets one after the other,
+/// each cell of a [`TrackGrid`] is placed at a column and row, and can span several of them.
+/// Columns and rows ("tracks") are [`Track::Fixed`], [`Track::Weight`]ed or [`Track::Auto`]-sized,
+/// and can be given names to refer to them by.
+///
+/// The grid fills the available width (and height, if it has weighted rows).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{GridCell, Track, TrackGrid};
+///
+/// TrackGrid::new("layout")
+///     .named_column("sidebar", Track::Fixed(120.0))
+///     .named_column("main", Track::Weight(1.0))
+///     .named_row("header", Track::Auto)
+///     .named_row("body", Track::Auto)
+///     .show(ui, |grid| {
+///         grid.cell(GridCell::new("sidebar", "header").span(2, 1), |ui| {
+///             ui.heading("Header spanning both columns");
+///         });
+///         grid.cell(GridCell::new("sidebar", "body"), |ui| {
+///             ui.label("Sidebar");
+///         });
+///         grid.cell(
+///             GridCell::new("main", "body").align(egui::Align2::CENTER_CENTER),
+///             |ui| {
+///                 ui.label("Centered");
+///             },
+///         );
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct TrackGrid {
+    id_salt: Id,
+    columns: Vec<(Option<String>, Track)>,
+    rows: Vec<(Option<String>, Track)>,
+    spacing: Option<Vec2>,
+}
+
+impl TrackGrid {
+    /// Create a new [`TrackGrid`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            spacing: None,
+        }
+    }
+
+    /// Add a column.
+    #[inline]
+    pub fn column(mut self, track: Track) -> Self {
+        self.columns.push((None, track));
+        self
+    }
+
+    /// Add a column that cells can refer to by name.
+    #[inline]
+    pub fn named_column(mut self, name: impl Into<String>, track: Track) -> Self {
+        self.columns.push((Some(name.into()), track));
+        self
+    }
+
+    /// Add several columns.
+    #[inline]
+    pub fn columns(mut self, tracks: impl IntoIterator<Item = Track>) -> Self {
+        self.columns
+            .extend(tracks.into_iter().map(|track| (None, track)));
+        self
+    }
+
+    /// Add a row.
+    #[inline]
+    pub fn row(mut self, track: Track) -> Self {
+        self.rows.push((None, track));
+        self
+    }
+
+    /// Add a row that cells can refer to by name.
+    #[inline]
+    pub fn named_row(mut self, name: impl Into<String>, track: Track) -> Self {
+        self.rows.push((Some(name.into()), track));
+        self
+    }
+
+    /// Add several rows.
+    #[inline]
+    pub fn rows(mut self, tracks: impl IntoIterator<Item = Track>) -> Self {
+        self.rows
+            .extend(tracks.into_iter().map(|track| (None, track)));
+        self
+    }
+
+    /// Set spacing between columns/rows.
+    /// Default: [`crate::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut TrackGridUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            columns,
+            rows,
+            spacing,
+        } = self;
+        let spacing = spacing.unwrap_or_else(|| ui.spacing().item_spacing);
+
+        let id = ui.make_persistent_id(id_salt);
+        let prev_state = State::load(ui.ctx(), id);
+        let measured = prev_state
+            .as_ref()
+            .map_or(&[][..], |state| &state.cells[..]);
+
+        let available = ui.available_size_before_wrap();
+        let col_sizes = resolve_tracks(
+            &columns,
+            measured.iter().map(|c| (c.col, c.col_span, c.size.x)),
+            available.x,
+            spacing.x,
+        );
+        let row_sizes = resolve_tracks(
+            &rows,
+            measured.iter().map(|c| (c.row, c.row_span, c.size.y)),
+            available.y,
+            spacing.y,
+        );
+
+        let origin = ui.cursor().min;
+        let total_size = Vec2::new(
+            track_offset(&col_sizes, col_sizes.len(), spacing.x) - spacing.x,
+            track_offset(&row_sizes, row_sizes.len(), spacing.y) - spacing.y,
+        )
+        .at_least(Vec2::ZERO);
+        let rect = Rect::from_min_size(origin, total_size);
+
+        let mut ui_builder = UiBuilder::new().max_rect(rect);
+        if prev_state.is_none() {
+            // The initial frame will be glitchy, because we don't know the sizes of the auto tracks yet.
+            if ui.is_visible() {
+                ui.ctx().request_discard("new TrackGrid");
+            }
+            ui_builder = ui_builder.sizing_pass().invisible();
+        }
+
+        let mut grid_ui = ui.new_child(ui_builder);
+        let mut grid = TrackGridUi {
+            ui: &mut grid_ui,
+            columns,
+            rows,
+            col_sizes,
+            row_sizes,
+            spacing,
+            origin,
+            prev_cells: prev_state.map(|state| state.cells).unwrap_or_default(),
+            cells: Vec::new(),
+        };
+        let inner = add_contents(&mut grid);
+        State { cells: grid.cells }.store(ui.ctx(), id);
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Places cells in a [`TrackGrid`], see [`TrackGrid::show`].
+pub struct TrackGridUi<'a> {
+    ui: &'a mut Ui,
+    columns: Vec<(Option<String>, Track)>,
+    rows: Vec<(Option<String>, Track)>,
+    col_sizes: Vec<f32>,
+    row_sizes: Vec<f32>,
+    spacing: Vec2,
+    origin: crate::Pos2,
+    prev_cells: Vec<MeasuredCell>,
+    cells: Vec<MeasuredCell>,
+}
+
+impl TrackGridUi<'_> {
+    /// The width of each column this frame.
+    pub fn col_widths(&self) -> &[f32] {
+        &self.col_sizes
+    }
+
+    /// The height of each row this frame.
+    pub fn row_heights(&self) -> &[f32] {
+        &self.row_sizes
+    }
+
+    /// Add contents to a cell.
+    ///
+    /// Cells may overlap; later cells are painted on top.
+    pub fn cell<R>(&mut self, cell: GridCell, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        let GridCell {
+            col,
+            row,
+            col_span,
+            row_span,
+            align,
+        } = cell;
+
+        let col = resolve_track_ref(&self.columns, &col);
+        let row = resolve_track_ref(&self.rows, &row);
+        let col_span = col_span.at_most(self.columns.len() - col);
+        let row_span = row_span.at_most(self.rows.len() - row);
+
+        let min = pos2(
+            track_offset(&self.col_sizes, col, self.spacing.x),
+            track_offset(&self.row_sizes, row, self.spacing.y),
+        );
+        let max = pos2(
+            track_offset(&self.col_sizes, col + col_span, self.spacing.x) - self.spacing.x,
+            track_offset(&self.row_sizes, row + row_span, self.spacing.y) - self.spacing.y,
+        );
+        let cell_rect = Rect::from_min_max(min, max.max(min)).translate(self.origin.to_vec2());
+
+        let index = self.cells.len();
+        let (max_rect, layout) = match align {
+            Some(align) => {
+                // Align using the size the contents had last frame:
+                let prev_size = self
+                    .prev_cells
+                    .get(index)
+                    .filter(|prev| (prev.col, prev.row) == (col, row))
+                    .map_or(cell_rect.size(), |prev| prev.size);
+                (
+                    align.align_size_within_rect(prev_size.min(cell_rect.size()), cell_rect),
+                    Layout::top_down(align.x()),
+                )
+            }
+            None => (cell_rect, Layout::top_down(crate::Align::Min)),
+        };
+
+        let mut cell_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(("cell", index))
+                .max_rect(max_rect)
+                .layout(layout),
+        );
+        let is_auto = |tracks: &[(Option<String>, Track)], start: usize, span: usize| {
+            tracks[start..start + span]
+                .iter()
+                .any(|(_, track)| *track == Track::Auto)
+        };
+        if is_auto(&self.columns, col, col_span) {
+            // Measure the natural width of the contents:
+            cell_ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+        }
+        let inner = add_contents(&mut cell_ui);
+
+        self.cells.push(MeasuredCell {
+            col,
+            col_span,
+            row,
+            row_span,
+            size: cell_ui.min_size(),
+        });
+        inner
+    }
+}
+
+fn resolve_track_ref(tracks: &[(Option<String>, Track)], track_ref: &TrackRef) -> usize {
+    let index = match track_ref {
+        TrackRef::Index(index) => Some(*index).filter(|&index| index < tracks.len()),
+        TrackRef::Name(name) => tracks
+            .iter()
+            .position(|(track_name, _)| track_name.as_deref() == Some(name.as_str())),
+    };
+    debug_assert!(
+        index.is_some(),
+        "TrackGrid has no track {track_ref:?}. Add it with `column`/`row` first."
+    );
+    index.unwrap_or_default()
+}
+
+/// Where the track at `index` starts, relative to the first track.
+fn track_offset(sizes: &[f32], index: usize, spacing: f32) -> f32 {
+    sizes[..index].iter().map(|size| size + spacing).sum()
+}
+
+/// Turn the tracks into sizes, given the measured size of each cell last frame
+/// as `(first track, number of tracks spanned, size)`.
+fn resolve_tracks(
+    tracks: &[(Option<String>, Track)],
+    measured: impl Iterator<Item = (usize, usize, f32)> + Clone,
+    available: f32,
+    spacing: f32,
+) -> Vec<f32> {
+    let is_auto = |track: Track| match track {
+        Track::Fixed(_) => false,
+        Track::Weight(_) => !available.is_finite(),
+        Track::Auto => true,
+    };
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|&(_, track)| match track {
+            Track::Fixed(size) => size.at_least(0.0),
+            Track::Weight(_) | Track::Auto => 0.0,
+        })
+        .collect();
+    let measured = measured.filter(|&(start, span, _)| start + span <= tracks.len());
+
+    // First make the auto tracks fit the cells that only span one track…
+    for (start, _, size) in measured.clone().filter(|&(_, span, _)| span == 1) {
+        if is_auto(tracks[start].1) {
+            sizes[start] = sizes[start].max(size);
+        }
+    }
+
+    // …then grow them to fit the spanning cells, if needed:
+    for (start, span, size) in measured.filter(|&(_, span, _)| 1 < span) {
+        let range = start..start + span;
+        let autos: Vec<usize> = range.clone().filter(|&i| is_auto(tracks[i].1)).collect();
+        let covered = sizes[range].iter().sum::<f32>() + spacing * (span - 1) as f32;
+        if !autos.is_empty() && covered < size {
+            let extra = (size - covered) / autos.len() as f32;
+            for i in autos {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    if available.is_finite() {
+        let total_weight: f32 = tracks
+            .iter()
+            .map(|&(_, track)| match track {
+                Track::Weight(weight) => weight.at_least(0.0),
+                Track::Fixed(_) | Track::Auto => 0.0,
+            })
+            .sum();
+        let used = sizes.iter().sum::<f32>() + spacing * (tracks.len().at_least(1) - 1) as f32;
+        let remaining = (available - used).at_least(0.0);
+        if 0.0 < total_weight {
+            for (size, &(_, track)) in sizes.iter_mut().zip(tracks) {
+                if let Track::Weight(weight) = track {
+                    *size = remaining * weight.at_least(0.0) / total_weight;
+                }
+            }
+        }
+    }
+
+    sizes
+}
@@ -2,7 +2,7 @@
 #![allow(clippy::use_self)]
 
 use emath::GuiRounding as _;
-use epaint::mutex::RwLock;
+use epaint::mutex::{Mutex, RwLock};
 use std::{any::Any, hash::Hash, sync::Arc};
 
 use crate::ClosableTag;
@@ -10,24 +10,27 @@ use crate::ClosableTag;
 use crate::Stroke;
 use crate::containers::menu;
 use crate::{
-    Align, Color32, Context, CursorIcon, DragAndDrop, Id, InnerResponse, InputState, IntoAtoms,
-    LayerId, Memory, Order, Painter, PlatformOutput, Pos2, Rangef, Rect, Response, Rgba, RichText,
-    Sense, Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack, UiStackInfo, Vec2,
-    WidgetRect, WidgetText,
+    Align, Color32, Context, CursorIcon, Id, InnerResponse, InputState, IntoAtoms, LayerId, Memory,
+    Order, Painter, PlatformOutput, Pos2, Rangef, Rect, Response, Rgba, RichText, Sense, Shape,
+    Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack, UiStackInfo, Vec2, WidgetRect,
+    WidgetText,
     containers::{CollapsingHeader, CollapsingResponse, Frame},
     ecolor::Hsva,
     emath, epaint,
     epaint::text::Fonts,
     grid,
     layout::{Direction, Layout},
+    memoize::Recording,
     pass_state,
     placer::Placer,
-    pos2, style,
+    pos2,
+    promise::{Promise, PromiseState},
+    style,
     util::IdTypeMap,
     vec2, widgets,
     widgets::{
-        Button, Checkbox, DragValue, Hyperlink, Image, ImageSource, Label, Link, RadioButton,
-        Separator, Spinner, TextEdit, Widget, color_picker,
+        BuiltinIcon, Button, Checkbox, DragValue, Hyperlink, Icon, Image, ImageSource, Label, Link,
+        RadioButton, Separator, Spinner, TextEdit, Widget, color_picker,
     },
 };
 // ----------------------------------------------------------------------------
@@ -93,6 +96,9 @@ pub struct Ui {
     /// and all widgets will assume a gray style.
     enabled: bool,
 
+    /// If true, widgets show their values but don't let the user edit them.
+    read_only: bool,
+
     /// Set to true in special cases where we do one frame
     /// where we size up the contents of the Ui, without actually showing it.
     sizing_pass: bool,
@@ -101,6 +107,9 @@ pub struct Ui {
     #[expect(deprecated)]
     menu_state: Option<Arc<RwLock<crate::menu::MenuState>>>,
 
+    /// Collects the responses of the widgets added inside a [`Self::group_response`].
+    response_group: Option<Arc<Mutex<Option<Response>>>>,
+
     /// The [`UiStack`] for this [`Ui`].
     stack: Arc<UiStack>,
 
@@ -130,10 +139,12 @@ impl Ui {
             max_rect,
             layout,
             disabled,
+            read_only,
             invisible,
             sizing_pass,
             style,
             sense,
+            layout_transition: _,
         } = ui_builder;
 
         let layer_id = layer_id.unwrap_or(LayerId::background());
@@ -145,9 +156,9 @@ impl Ui {
 
         let max_rect = max_rect.unwrap_or_else(|| ctx.screen_rect());
         let clip_rect = max_rect;
-        let layout = layout.unwrap_or_default();
         let disabled = disabled || invisible;
         let style = style.unwrap_or_else(|| ctx.style());
+        let layout = layout.unwrap_or_else(|| Layout::top_down(style.layout_direction.start()));
         let sense = sense.unwrap_or(Sense::hover());
 
         let placer = Placer::new(max_rect, layout);
@@ -167,8 +178,10 @@ impl Ui {
             style,
             placer,
             enabled: true,
+            read_only,
             sizing_pass,
             menu_state: None,
+            response_group: None,
             stack: Arc::new(ui_stack),
             sense,
             min_rect_already_remembered: false,
@@ -257,10 +270,12 @@ impl Ui {
             max_rect,
             layout,
             disabled,
+            read_only,
             invisible,
             sizing_pass,
             style,
             sense,
+            layout_transition: _, // handled by `scope_dyn`
         } = ui_builder;
 
         let mut painter = self.painter.clone();
@@ -269,6 +284,7 @@ impl Ui {
         let max_rect = max_rect.unwrap_or_else(|| self.available_rect_before_wrap());
         let mut layout = layout.unwrap_or(*self.layout());
         let enabled = self.enabled && !disabled && !invisible;
+        let read_only = self.read_only || read_only;
         if let Some(layer_id) = layer_id {
             painter.set_layer_id(layer_id);
         }
@@ -318,8 +334,10 @@ impl Ui {
             style,
             placer,
             enabled,
+            read_only,
             sizing_pass,
             menu_state: self.menu_state.clone(),
+            response_group: self.response_group.clone(),
             stack: Arc::new(ui_stack),
             sense,
             min_rect_already_remembered: false,
@@ -431,6 +449,22 @@ impl Ui {
         self.style = self.ctx().style();
     }
 
+    /// Left-to-right or right-to-left?
+    /// Short for `ui.style().layout_direction`.
+    #[inline]
+    pub fn layout_direction(&self) -> crate::LayoutDirection {
+        self.style.layout_direction
+    }
+
+    /// Switch between left-to-right and right-to-left.
+    ///
+    /// Changes apply to the children of this [`Ui`] that are added afterwards,
+    /// but not to the layout of this [`Ui`] itself.
+    /// To make the whole app right-to-left, set [`Style::layout_direction`] with [`Context::all_styles_mut`].
+    pub fn set_layout_direction(&mut self, layout_direction: crate::LayoutDirection) {
+        self.style_mut().layout_direction = layout_direction;
+    }
+
     /// The current spacing options for this [`Ui`].
     /// Short for `ui.style().spacing`.
     #[inline]
@@ -504,6 +538,24 @@ impl Ui {
         self.enabled
     }
 
+    /// If `true`, the widgets in this [`Ui`] show their values at full contrast
+    /// but don't let the user edit them.
+    ///
+    /// See [`Self::read_only`].
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// The [`Sense`] for a widget that edits a value: [`Sense::hover`] if the [`Ui`] is read-only.
+    pub(crate) fn edit_sense(&self, sense: Sense) -> Sense {
+        if self.read_only {
+            Sense::hover()
+        } else {
+            sense
+        }
+    }
+
     /// Calling `disable()` will cause the [`Ui`] to deny all future interaction
     /// and all the widgets will draw with a gray look.
     ///
@@ -764,6 +816,45 @@ impl Ui {
         self.painter.set_clip_rect(clip_rect);
     }
 
+    /// If set, everything painted in this ui is only visible inside of this shape.
+    ///
+    /// See [`Self::shrink_clip_shape`].
+    #[inline]
+    pub fn clip_shape(&self) -> Option<&Shape> {
+        self.painter.clip_shape()
+    }
+
+    /// Only paint inside of `clip_shape`, e.g. a circle or a rounded rectangle.
+    ///
+    /// Semi-transparent parts of the shape make whatever is painted there semi-transparent,
+    /// so it can be used as an alpha mask.
+    /// The clip rectangle is also shrunk to the bounds of the shape.
+    /// This only affects painting, not interaction.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = ui.available_rect_before_wrap();
+    /// ui.scope(|ui| {
+    ///     ui.shrink_clip_shape(egui::Shape::circle_filled(
+    ///         rect.center(),
+    ///         32.0,
+    ///         egui::Color32::WHITE,
+    ///     ));
+    ///     ui.label("Only the middle of this text is visible");
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See [`Painter::shrink_clip_shape`] for more.
+    pub fn shrink_clip_shape(&mut self, clip_shape: impl Into<Shape>) {
+        self.painter.shrink_clip_shape(clip_shape);
+    }
+
+    /// Set or remove the clip shape, see [`Self::shrink_clip_shape`].
+    pub fn set_clip_shape(&mut self, clip_shape: Option<Shape>) {
+        self.painter.set_clip_shape(clip_shape);
+    }
+
     /// Can be used for culling: if `false`, then no part of `rect` will be visible on screen.
     ///
     /// This is false if the whole `Ui` is invisible (see [`UiBuilder::invisible`])
@@ -1071,6 +1162,7 @@ impl Ui {
 /// # Interaction
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this [`Ui`].
+    #[track_caller]
     pub fn interact(&self, rect: Rect, id: Id, sense: Sense) -> Response {
         self.ctx().create_widget(
             WidgetRect {
@@ -1310,6 +1402,7 @@ impl Ui {
     /// ui.painter().rect_stroke(response.rect, 0.0, (1.0, egui::Color32::WHITE), egui::StrokeKind::Inside);
     /// # });
     /// ```
+    #[track_caller]
     pub fn allocate_response(&mut self, desired_size: Vec2, sense: Sense) -> Response {
         let (id, rect) = self.allocate_space(desired_size);
         let mut response = self.interact(rect, id, sense);
@@ -1322,6 +1415,7 @@ impl Ui {
     /// The response rect will be larger if this is part of a justified layout or similar.
     /// This means that if this is a narrow widget in a wide justified layout, then
     /// the widget will react to interactions outside the returned [`Rect`].
+    #[track_caller]
     pub fn allocate_exact_size(&mut self, desired_size: Vec2, sense: Sense) -> (Rect, Response) {
         let response = self.allocate_response(desired_size, sense);
         let rect = self
@@ -1333,6 +1427,7 @@ impl Ui {
     /// Allocate at least as much space as needed, and interact with that rect.
     ///
     /// The returned [`Rect`] will be the same size as `Response::rect`.
+    #[track_caller]
     pub fn allocate_at_least(&mut self, desired_size: Vec2, sense: Sense) -> (Rect, Response) {
         let response = self.allocate_response(desired_size, sense);
         (response.rect, response)
@@ -1428,6 +1523,7 @@ impl Ui {
     ///
     /// Ignore the layout of the [`Ui`]: just put my widget here!
     /// The layout cursor will advance to past this `rect`.
+    #[track_caller]
     pub fn allocate_rect(&mut self, rect: Rect, sense: Sense) -> Response {
         let rect = rect.round_ui();
         let id = self.advance_cursor_after_rect(rect);
@@ -1570,6 +1666,7 @@ impl Ui {
     /// painter.line_segment([c, c + r * Vec2::angled(TAU * 3.0 / 8.0)], stroke);
     /// # });
     /// ```
+    #[track_caller]
     pub fn allocate_painter(&mut self, desired_size: Vec2, sense: Sense) -> (Response, Painter) {
         let response = self.allocate_response(desired_size, sense);
         let clip_rect = self.clip_rect().intersect(response.rect); // Make sure we don't paint out of bounds
@@ -1720,8 +1817,11 @@ impl Ui {
     /// # });
     /// ```
     #[inline]
+    #[track_caller]
     pub fn add(&mut self, widget: impl Widget) -> Response {
-        widget.ui(self)
+        let response = widget.ui(self);
+        self.add_to_response_group(&response);
+        response
     }
 
     /// Add a [`Widget`] to this [`Ui`] with a given size.
@@ -1773,6 +1873,7 @@ impl Ui {
     /// ui.add_enabled(false, egui::Button::new("Can't click this"));
     /// # });
     /// ```
+    #[track_caller]
     pub fn add_enabled(&mut self, enabled: bool, widget: impl Widget) -> Response {
         if self.is_enabled() && !enabled {
             let old_painter = self.painter.clone();
@@ -1818,6 +1919,26 @@ impl Ui {
         })
     }
 
+    /// Add a read-only section, for "view mode" screens.
+    ///
+    /// Unlike [`Self::add_enabled_ui`], the widgets are not greyed out, and text can still be
+    /// selected and copied, but checkboxes, sliders, text edits etc. reject any edits.
+    /// Buttons can still be clicked.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut name = String::from("Ferris");
+    /// # let mut age = 42;
+    /// ui.read_only(|ui| {
+    ///     ui.text_edit_singleline(&mut name);
+    ///     ui.add(egui::Slider::new(&mut age, 0..=120));
+    /// });
+    /// # });
+    /// ```
+    pub fn read_only<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.scope_builder(UiBuilder::new().read_only(), add_contents)
+    }
+
     /// Add a single [`Widget`] that is possibly invisible.
     ///
     /// An invisible widget still takes up the same space as if it were visible.
@@ -1913,61 +2034,69 @@ impl Ui {
     /// # });
     /// ```
     #[inline]
+    #[track_caller]
     pub fn label(&mut self, text: impl Into<WidgetText>) -> Response {
-        Label::new(text).ui(self)
+        self.add(Label::new(text))
     }
 
     /// Show colored text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).color(color))`
+    #[track_caller]
     pub fn colored_label(
         &mut self,
         color: impl Into<Color32>,
         text: impl Into<RichText>,
     ) -> Response {
-        Label::new(text.into().color(color)).ui(self)
+        self.add(Label::new(text.into().color(color)))
     }
 
     /// Show large text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).heading())`
+    #[track_caller]
     pub fn heading(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().heading()).ui(self)
+        self.add(Label::new(text.into().heading()))
     }
 
     /// Show monospace (fixed width) text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).monospace())`
+    #[track_caller]
     pub fn monospace(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().monospace()).ui(self)
+        self.add(Label::new(text.into().monospace()))
     }
 
     /// Show text as monospace with a gray background.
     ///
     /// Shortcut for `ui.label(RichText::new(text).code())`
+    #[track_caller]
     pub fn code(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().code()).ui(self)
+        self.add(Label::new(text.into().code()))
     }
 
     /// Show small text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).small())`
+    #[track_caller]
     pub fn small(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().small()).ui(self)
+        self.add(Label::new(text.into().small()))
     }
 
     /// Show text that stand out a bit (e.g. slightly brighter).
     ///
     /// Shortcut for `ui.label(RichText::new(text).strong())`
+    #[track_caller]
     pub fn strong(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().strong()).ui(self)
+        self.add(Label::new(text.into().strong()))
     }
 
     /// Show text that is weaker (fainter color).
     ///
     /// Shortcut for `ui.label(RichText::new(text).weak())`
+    #[track_caller]
     pub fn weak(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().weak()).ui(self)
+        self.add(Label::new(text.into().weak()))
     }
 
     /// Looks like a hyperlink.
@@ -1984,8 +2113,9 @@ impl Ui {
     ///
     /// See also [`Link`].
     #[must_use = "You should check if the user clicked this with `if ui.link(…).clicked() { … } "]
+    #[track_caller]
     pub fn link(&mut self, text: impl Into<WidgetText>) -> Response {
-        Link::new(text).ui(self)
+        self.add(Link::new(text))
     }
 
     /// Link to a web page.
@@ -1999,8 +2129,9 @@ impl Ui {
     /// ```
     ///
     /// See also [`Hyperlink`].
+    #[track_caller]
     pub fn hyperlink(&mut self, url: impl ToString) -> Response {
-        Hyperlink::new(url).ui(self)
+        self.add(Hyperlink::new(url))
     }
 
     /// Shortcut for `add(Hyperlink::from_label_and_url(label, url))`.
@@ -2012,8 +2143,9 @@ impl Ui {
     /// ```
     ///
     /// See also [`Hyperlink`].
+    #[track_caller]
     pub fn hyperlink_to(&mut self, label: impl Into<WidgetText>, url: impl ToString) -> Response {
-        Hyperlink::from_label_and_url(label, url).ui(self)
+        self.add(Hyperlink::from_label_and_url(label, url))
     }
 
     /// No newlines (`\n`) allowed. Pressing enter key will result in the [`TextEdit`] losing focus (`response.lost_focus`).
@@ -2023,7 +2155,7 @@ impl Ui {
         &mut self,
         text: &mut S,
     ) -> Response {
-        TextEdit::singleline(text).ui(self)
+        self.add(TextEdit::singleline(text))
     }
 
     /// A [`TextEdit`] for multiple lines. Pressing enter key will create a new line.
@@ -2033,7 +2165,7 @@ impl Ui {
         &mut self,
         text: &mut S,
     ) -> Response {
-        TextEdit::multiline(text).ui(self)
+        self.add(TextEdit::multiline(text))
     }
 
     /// A [`TextEdit`] for code editing.
@@ -2065,8 +2197,9 @@ impl Ui {
     /// ```
     #[must_use = "You should check if the user clicked this with `if ui.button(…).clicked() { … } "]
     #[inline]
+    #[track_caller]
     pub fn button<'a>(&mut self, atoms: impl IntoAtoms<'a>) -> Response {
-        Button::new(atoms).ui(self)
+        self.add(Button::new(atoms))
     }
 
     /// A button as small as normal body text.
@@ -2075,16 +2208,18 @@ impl Ui {
     ///
     /// Shortcut for `add(Button::new(text).small())`
     #[must_use = "You should check if the user clicked this with `if ui.small_button(…).clicked() { … } "]
+    #[track_caller]
     pub fn small_button(&mut self, text: impl Into<WidgetText>) -> Response {
-        Button::new(text).small().ui(self)
+        self.add(Button::new(text).small())
     }
 
     /// Show a checkbox.
     ///
     /// See also [`Self::toggle_value`].
     #[inline]
+    #[track_caller]
     pub fn checkbox<'a>(&mut self, checked: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Response {
-        Checkbox::new(checked, atoms).ui(self)
+        self.add(Checkbox::new(checked, atoms))
     }
 
     /// Acts like a checkbox, but looks like a [`Button::selectable`].
@@ -2092,9 +2227,10 @@ impl Ui {
     /// Click to toggle to bool.
     ///
     /// See also [`Self::checkbox`].
+    #[track_caller]
     pub fn toggle_value<'a>(&mut self, selected: &mut bool, atoms: impl IntoAtoms<'a>) -> Response {
         let mut response = self.selectable_label(*selected, atoms);
-        if response.clicked() {
+        if response.clicked() && !self.read_only {
             *selected = !*selected;
             response.mark_changed();
         }
@@ -2105,13 +2241,17 @@ impl Ui {
     /// Often you want to use [`Self::radio_value`] instead.
     #[must_use = "You should check if the user clicked this with `if ui.radio(…).clicked() { … } "]
     #[inline]
+    #[track_caller]
     pub fn radio<'a>(&mut self, selected: bool, atoms: impl IntoAtoms<'a>) -> Response {
-        RadioButton::new(selected, atoms).ui(self)
+        self.add(RadioButton::new(selected, atoms))
     }
 
     /// Show a [`RadioButton`]. It is selected if `*current_value == selected_value`.
     /// If clicked, `selected_value` is assigned to `*current_value`.
     ///
+    /// The radio buttons of a group act as a single stop in the tab order, at the selected one,
+    /// and moving the focus to a radio button with the arrow keys selects it.
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     ///
@@ -2121,21 +2261,29 @@ impl Ui {
     ///
     /// ui.radio_value(&mut my_enum, Enum::First, "First");
     ///
-    /// // is equivalent to:
+    /// // is roughly equivalent to:
     ///
     /// if ui.add(egui::RadioButton::new(my_enum == Enum::First, "First")).clicked() {
     ///     my_enum = Enum::First
     /// }
     /// # });
     /// ```
+    #[track_caller]
     pub fn radio_value<'a, Value: PartialEq>(
         &mut self,
         current_value: &mut Value,
         alternative: Value,
         atoms: impl IntoAtoms<'a>,
     ) -> Response {
-        let mut response = self.radio(*current_value == alternative, atoms);
-        if response.clicked() && *current_value != alternative {
+        let selected = *current_value == alternative;
+        let mut response = self.radio(selected, atoms);
+        if !selected {
+            // Tab moves past the group, the arrow keys move within it:
+            response = response.tab_index(-1);
+        }
+        if (response.clicked() || self.gained_focus_from_arrow_keys(&response))
+            && *current_value != alternative
+        {
             *current_value = alternative;
             response.mark_changed();
         }
@@ -2146,16 +2294,21 @@ impl Ui {
     ///
     /// See also [`Button::selectable`] and [`Self::toggle_value`].
     #[must_use = "You should check if the user clicked this with `if ui.selectable_label(…).clicked() { … } "]
+    #[track_caller]
     pub fn selectable_label<'a>(&mut self, checked: bool, text: impl IntoAtoms<'a>) -> Response {
-        Button::selectable(checked, text).ui(self)
+        self.add(Button::selectable(checked, text))
     }
 
     /// Show selectable text. It is selected if `*current_value == selected_value`.
     /// If clicked, `selected_value` is assigned to `*current_value`.
     ///
+    /// Moving the focus to it with the arrow keys selects it too, so the arrow keys move
+    /// the selection in a list of these.
+    ///
     /// Example: `ui.selectable_value(&mut my_enum, Enum::Alternative, "Alternative")`.
     ///
     /// See also [`Button::selectable`] and [`Self::toggle_value`].
+    #[track_caller]
     pub fn selectable_value<'a, Value: PartialEq>(
         &mut self,
         current_value: &mut Value,
@@ -2163,19 +2316,27 @@ impl Ui {
         text: impl IntoAtoms<'a>,
     ) -> Response {
         let mut response = self.selectable_label(*current_value == selected_value, text);
-        if response.clicked() && *current_value != selected_value {
+        if (response.clicked() || self.gained_focus_from_arrow_keys(&response))
+            && !self.read_only
+            && *current_value != selected_value
+        {
             *current_value = selected_value;
             response.mark_changed();
         }
         response
     }
 
+    fn gained_focus_from_arrow_keys(&self, response: &Response) -> bool {
+        self.ctx()
+            .memory(|mem| mem.gained_focus_from_arrow_keys(response.id))
+    }
+
     /// Shortcut for `add(Separator::default())`
     ///
     /// See also [`Separator`].
     #[inline]
     pub fn separator(&mut self) -> Response {
-        Separator::default().ui(self)
+        self.add(Separator::default())
     }
 
     /// Shortcut for `add(Spinner::new())`
@@ -2183,7 +2344,15 @@ impl Ui {
     /// See also [`Spinner`].
     #[inline]
     pub fn spinner(&mut self) -> Response {
-        Spinner::new().ui(self)
+        self.add(Spinner::new())
+    }
+
+    /// Shortcut for `add(Icon::new(icon))`
+    ///
+    /// See also [`Icon`].
+    #[inline]
+    pub fn icon(&mut self, icon: BuiltinIcon) -> Response {
+        self.add(Icon::new(icon))
     }
 
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
@@ -2252,7 +2421,7 @@ impl Ui {
     /// See also [`crate::Image`], [`crate::ImageSource`].
     #[inline]
     pub fn image<'a>(&mut self, source: impl Into<ImageSource<'a>>) -> Response {
-        Image::new(source).ui(self)
+        self.add(Image::new(source))
     }
 }
 
@@ -2272,6 +2441,20 @@ impl Ui {
         color_picker::color_edit_button_hsva(self, hsva, color_picker::Alpha::BlendOrAdditive)
     }
 
+    /// Shows the full color picker inline, i.e. always open, e.g. for an inspector side panel.
+    ///
+    /// See also [`Self::color_edit_button_srgba`].
+    pub fn color_picker_inline(&mut self, srgba: &mut Color32) -> Response {
+        color_picker::color_picker_inline_srgba(self, srgba, color_picker::Alpha::BlendOrAdditive)
+    }
+
+    /// Shows the full color picker inline, i.e. always open, e.g. for an inspector side panel.
+    ///
+    /// See also [`Self::color_edit_button_hsva`].
+    pub fn color_picker_inline_hsva(&mut self, hsva: &mut Hsva) -> Response {
+        color_picker::color_picker_inline_hsva(self, hsva, color_picker::Alpha::BlendOrAdditive)
+    }
+
     /// Shows a button with the given color.
     ///
     /// If the user clicks the button, a full color picker is shown.
@@ -2368,6 +2551,67 @@ impl Ui {
         crate::Frame::group(self.style()).show(self, add_contents)
     }
 
+    /// Add some widgets, and get a single [`Response`] for all of them.
+    ///
+    /// The response covers the whole area of the contents,
+    /// is clicked if the background or any of the widgets is clicked,
+    /// changed if any of the widgets changed, hovered if any part of it is hovered, etc.
+    /// This is useful for e.g. a row or card that should react as one.
+    ///
+    /// Only widgets added with [`Self::add`] (which includes helpers like [`Self::button`])
+    /// are included.
+    /// Unlike [`Self::group`], this does not add a frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut enabled = true;
+    /// let response = ui
+    ///     .group_response(|ui| {
+    ///         ui.horizontal(|ui| {
+    ///             ui.checkbox(&mut enabled, "");
+    ///             ui.label("Auto-save");
+    ///         });
+    ///     })
+    ///     .response;
+    /// if response.changed() {
+    ///     // The checkbox was toggled.
+    /// }
+    /// if response.hovered() {
+    ///     response.on_hover_text("Save every minute");
+    /// }
+    /// # });
+    /// ```
+    pub fn group_response<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let group = Arc::new(Mutex::new(None));
+        let InnerResponse {
+            inner,
+            mut response,
+        } = self.scope_builder(UiBuilder::new().sense(Sense::click()), |ui| {
+            ui.response_group = Some(group.clone());
+            add_contents(ui)
+        });
+
+        let widgets = group.lock().take();
+        if let Some(widgets) = widgets {
+            response = union_on_same_layer(response, widgets);
+        }
+        self.add_to_response_group(&response);
+        InnerResponse::new(inner, response)
+    }
+
+    fn add_to_response_group(&self, response: &Response) {
+        if let Some(group) = &self.response_group {
+            let mut group = group.lock();
+            *group = Some(match group.take() {
+                Some(group) => union_on_same_layer(group, response.clone()),
+                None => response.clone(),
+            });
+        }
+    }
+
     /// Create a child Ui with an explicit [`Id`].
     ///
     /// ```
@@ -2435,15 +2679,363 @@ impl Ui {
         ui_builder: UiBuilder,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
+        let layout_transition = ui_builder.layout_transition;
         let next_auto_id_salt = self.next_auto_id_salt;
         let mut child_ui = self.new_child(ui_builder);
         self.next_auto_id_salt = next_auto_id_salt; // HACK: we want `scope` to only increment this once, so that `ui.scope` is equivalent to `ui.allocate_space`.
+        let first_shape = self.ctx().graphics(|gx| {
+            gx.get(child_ui.layer_id())
+                .map_or(crate::layers::ShapeIdx(0), |l| l.next_idx())
+        });
         let ret = add_contents(&mut child_ui);
         let response = child_ui.remember_min_rect();
-        self.advance_cursor_after_rect(child_ui.min_rect());
+        let rect = if let Some(tween) = layout_transition {
+            child_ui.paint_layout_transition(first_shape, tween)
+        } else {
+            child_ui.min_rect()
+        };
+        self.advance_cursor_after_rect(rect);
         InnerResponse::new(ret, response)
     }
 
+    /// Show the contents while `condition` is true, animating them in and out.
+    ///
+    /// When `condition` becomes `false`, the contents keep being shown (and so `add_contents`
+    /// keeps being called) until the exit animation is done, after which `None` is returned.
+    /// When `condition` becomes `true`, the contents are animated in.
+    /// The animation takes [`crate::style::Style::animation_time`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut show_details = false;
+    /// ui.checkbox(&mut show_details, "Show details");
+    /// ui.show_animated_if(show_details, egui::Transition::Collapse, |ui| {
+    ///     ui.label("Details");
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// The state of the animation is stored with the next auto-[`Id`],
+    /// so the widgets before this one should not change while it animates.
+    pub fn show_animated_if<R>(
+        &mut self,
+        condition: bool,
+        transition: crate::Transition,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        use crate::Transition;
+
+        let id = self.auto_id_with("show_animated_if");
+        let shown = self.ctx().animate_bool_responsive(id, condition);
+        if shown <= 0.0 {
+            self.skip_ahead_auto_ids(1); // Same as `scope`
+            return None;
+        }
+
+        Some(self.scope(|ui| {
+            if shown >= 1.0 {
+                let ret = add_contents(ui);
+                if transition == Transition::Collapse {
+                    let height = ui.min_rect().height();
+                    ui.data_mut(|d| d.insert_temp(id, height));
+                }
+                return ret;
+            }
+
+            if transition.fades() {
+                ui.multiply_opacity(shown);
+            }
+
+            let max_height = if transition == Transition::Collapse {
+                // Until we know the full height, just use a placeholder value that shows some movement:
+                let full_height = ui.data(|d| d.get_temp::<f32>(id)).unwrap_or(10.0 / shown);
+                let max_height = (shown * full_height).round_ui();
+                let mut clip_rect = ui.clip_rect();
+                clip_rect.max.y = clip_rect.max.y.min(ui.max_rect().top() + max_height);
+                ui.set_clip_rect(clip_rect);
+                Some(max_height)
+            } else {
+                None
+            };
+
+            let layer_id = ui.layer_id();
+            let first_shape = ui.ctx().graphics(|gx| {
+                gx.get(layer_id)
+                    .map_or(crate::layers::ShapeIdx(0), |l| l.next_idx())
+            });
+
+            let ret = add_contents(ui);
+
+            let mut min_rect = ui.min_rect();
+            let transform = match transition {
+                Transition::Fade | Transition::Collapse => None,
+                Transition::Slide | Transition::FadeSlide => {
+                    let distance = ui.spacing().interact_size.y;
+                    Some(emath::TSTransform::from_translation(vec2(
+                        0.0,
+                        -(1.0 - shown) * distance,
+                    )))
+                }
+                Transition::FadeScale => {
+                    let center = min_rect.center().to_vec2();
+                    Some(
+                        emath::TSTransform::from_translation(center)
+                            * emath::TSTransform::from_scaling(emath::lerp(0.9..=1.0, shown))
+                            * emath::TSTransform::from_translation(-center),
+                    )
+                }
+            };
+            if let Some(transform) = transform {
+                ui.ctx().graphics_mut(|g| {
+                    let list = g.entry(layer_id);
+                    let end_shape = list.next_idx();
+                    list.transform_range(first_shape, end_shape, transform);
+                });
+            }
+
+            if let Some(max_height) = max_height {
+                let height = min_rect.height();
+                ui.data_mut(|d| d.insert_temp(id, height));
+
+                // Pretend the contents took up at most `max_height` space:
+                min_rect.max.y = min_rect.max.y.min(min_rect.top() + max_height);
+                ui.force_set_min_rect(min_rect);
+            }
+
+            ret
+        }))
+    }
+
+    /// While `loading`, show shimmering placeholders in the shape of the contents.
+    ///
+    /// The contents are laid out as usual, so they take up realistic space,
+    /// but they are not interactive, and what they paint is replaced:
+    /// each row of text by a rounded block, and filled shapes and images by blocks.
+    /// So while loading, fill in your data with placeholder values of realistic length.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let user: Option<(String, String)> = None;
+    /// let (name, bio) = user.clone().unwrap_or_else(|| ("Firstname Lastname".to_owned(), "Some text ".repeat(10)));
+    /// ui.skeleton_while(user.is_none(), |ui| {
+    ///     ui.heading(name);
+    ///     ui.label(bio);
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`crate::Skeleton`].
+    pub fn skeleton_while<R>(
+        &mut self,
+        loading: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        if !loading {
+            return self.scope(add_contents);
+        }
+
+        self.scope_builder(UiBuilder::new().disabled(), |ui| {
+            let layer_id = ui.layer_id();
+            let first_shape = ui.ctx().graphics(|gx| {
+                gx.get(layer_id)
+                    .map_or(crate::layers::ShapeIdx(0), |l| l.next_idx())
+            });
+
+            let ret = add_contents(ui);
+
+            let shimmer = crate::widgets::skeleton::Shimmer::new(ui);
+            ui.ctx().graphics_mut(|g| {
+                let list = g.entry(layer_id);
+                for idx in first_shape.0..list.next_idx().0 {
+                    list.mutate_shape(crate::layers::ShapeIdx(idx), |clipped| {
+                        clipped.shape = shimmer.placeholder(&clipped.shape);
+                    });
+                }
+            });
+            ret
+        })
+    }
+
+    /// Show the value of a [`Promise`] once it has arrived, and a loading state until then.
+    ///
+    /// While pending, this shows a [`Spinner`],
+    /// or a [`widgets::ProgressBar`] if the sender reports progress with [`crate::PromiseSender::set_progress`].
+    /// If the sender was dropped without sending a value, an error is shown.
+    ///
+    /// You don't need to request repaints: sending the value wakes up the ui.
+    ///
+    /// Returns `None` until the value has arrived.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let ctx = ui.ctx();
+    /// let mut promise = egui::Promise::spawn_thread(ctx, "compute", |_| (1..=10).sum::<u32>());
+    /// ui.await_promise(&mut promise, |ui, sum| ui.label(format!("The sum is {sum}")));
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::await_promise_result`].
+    pub fn await_promise<T: Send + 'static, R>(
+        &mut self,
+        promise: &mut Promise<T>,
+        add_contents: impl FnOnce(&mut Ui, &T) -> R,
+    ) -> Option<R> {
+        match promise.poll() {
+            PromiseState::Pending { progress } => {
+                match progress {
+                    Some(progress) => {
+                        self.add(widgets::ProgressBar::new(progress).show_percentage());
+                    }
+                    None => {
+                        self.spinner();
+                    }
+                }
+                None
+            }
+            PromiseState::Ready(value) => Some(add_contents(self, value)),
+            PromiseState::Abandoned => {
+                self.colored_label(self.visuals().error_fg_color, "⚠ The task failed");
+                None
+            }
+        }
+    }
+
+    /// Like [`Self::await_promise`], but for fallible tasks: errors are shown as error labels.
+    ///
+    /// `add_contents` is only called with successful values.
+    pub fn await_promise_result<T: Send + 'static, E: std::fmt::Display + Send + 'static, R>(
+        &mut self,
+        promise: &mut Promise<Result<T, E>>,
+        add_contents: impl FnOnce(&mut Ui, &T) -> R,
+    ) -> Option<R> {
+        self.await_promise(promise, |ui, result| match result {
+            Ok(value) => Some(add_contents(ui, value)),
+            Err(err) => {
+                ui.colored_label(ui.visuals().error_fg_color, format!("⚠ {err}"));
+                None
+            }
+        })
+        .flatten()
+    }
+
+    /// Move the shapes painted since `first_shape` to where the [`Self::min_rect`] is shown,
+    /// see [`UiBuilder::layout_transition`].
+    ///
+    /// Returns the rectangle to allocate in the parent.
+    fn paint_layout_transition(
+        &self,
+        first_shape: crate::layers::ShapeIdx,
+        tween: crate::Tween,
+    ) -> Rect {
+        let target = self.min_rect();
+        let shown = self
+            .ctx()
+            .animate_tween(self.id.with("layout_transition"), target, tween);
+        if shown != target {
+            let offset = shown.min - target.min;
+            let clip_rect = (shown.size() != target.size())
+                .then(|| shown.expand(self.visuals().clip_rect_margin));
+            self.ctx().graphics_mut(|g| {
+                let list = g.entry(self.layer_id());
+                for idx in first_shape.0..list.next_idx().0 {
+                    list.mutate_shape(crate::layers::ShapeIdx(idx), |clipped| {
+                        clipped.shape.translate(offset);
+                        clipped.clip_rect = clipped.clip_rect.translate(offset);
+                        if let Some(clip_rect) = clip_rect {
+                            clipped.clip_rect = clipped.clip_rect.intersect(clip_rect);
+                        }
+                    });
+                }
+            });
+        }
+        Rect::from_min_size(target.min, shown.size())
+    }
+
+    /// Add a focus scope, which keeps tab and shift+tab navigation inside it:
+    /// when a widget in the scope has keyboard focus, tab cycles through the widgets in the scope only.
+    ///
+    /// Use it for e.g. a dialog or a panel. The [`Id`] of the scope is the one of the returned
+    /// [`Response`], which you can pass to [`Context::focus_next_in`] to move the focus into it.
+    ///
+    /// See also [`Response::tab_index`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let (mut name, mut email) = (String::new(), String::new());
+    /// let scope = ui.focus_scope(|ui| {
+    ///     ui.text_edit_singleline(&mut name);
+    ///     ui.text_edit_singleline(&mut email);
+    /// });
+    /// if ui.button("Edit").clicked() {
+    ///     ui.ctx().focus_next_in(scope.response.id);
+    /// }
+    /// # });
+    /// ```
+    pub fn focus_scope<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.scope(|ui| {
+            let scope = ui.unique_id();
+            ui.memory_mut(|mem| mem.begin_focus_scope(scope));
+            let inner = add_contents(ui);
+            ui.memory_mut(|mem| mem.end_focus_scope());
+            inner
+        })
+    }
+
+    /// Add a subtree that is only laid out again when its `inputs` change.
+    ///
+    /// Otherwise the shapes and size it produced last time are replayed,
+    /// which is much cheaper for static content such as big tables of labels.
+    ///
+    /// `inputs` must include everything the contents depend on.
+    /// Apart from that the subtree is laid out again when the available width (height for
+    /// horizontal layouts), the theme, the zoom, or whether the [`Ui`] is enabled changes,
+    /// and whenever the user interacts with it, i.e. hovers it, or focuses or drags a widget in it.
+    /// It is also never replayed while AccessKit is enabled.
+    ///
+    /// Since `add_contents` is not called when the subtree is replayed, any widgets in it
+    /// won't respond to anything but the pointer hovering them.
+    /// They are still registered though, so they keep their place in the tab order.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let readings = [1.0, 2.0, 3.0];
+    /// ui.memoize("readings", readings.map(f32::to_bits), |ui| {
+    ///     for reading in readings {
+    ///         ui.label(format!("{reading:.1} °C"));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn memoize(
+        &mut self,
+        id_salt: impl Hash,
+        inputs: impl Hash,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Response {
+        let next_auto_id_salt = self.next_auto_id_salt;
+        let mut child_ui = self.new_child(UiBuilder::new().id_salt(id_salt));
+        self.next_auto_id_salt = next_auto_id_salt;
+
+        let id = child_ui.id();
+        let key = Recording::key(&child_ui, inputs);
+        let recording = Recording::is_possible(&child_ui)
+            .then(|| self.ctx().data(|d| d.get_temp::<Recording>(id)))
+            .flatten();
+        if let Some(recording) = recording.filter(|r| r.can_replay(&child_ui, key)) {
+            recording.replay(&mut child_ui);
+        } else if let Some(recording) = Recording::record(&mut child_ui, key, add_contents)
+            .filter(|_| Recording::is_possible(&child_ui))
+        {
+            self.ctx().data_mut(|d| d.insert_temp(id, recording));
+        } else {
+            self.ctx().data_mut(|d| d.remove::<Recording>(id));
+        }
+
+        let response = child_ui.remember_min_rect();
+        self.advance_cursor_after_rect(child_ui.min_rect());
+        response
+    }
+
     /// Redirect shapes to another paint layer.
     ///
     /// ```
@@ -2476,6 +3068,36 @@ impl Ui {
         CollapsingHeader::new(heading).show(self, add_contents)
     }
 
+    /// Open or close all [`CollapsingHeader`]s in this [`Ui`], including those in child [`Ui`]s,
+    /// e.g. for "Expand all" and "Collapse all" buttons.
+    ///
+    /// This also affects the headers that have already been added this frame, from the next frame.
+    /// To open or close all headers everywhere, use [`Context::set_all_collapsing_open`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let expand = ui
+    ///     .horizontal(|ui| {
+    ///         if ui.button("Expand all").clicked() {
+    ///             Some(true)
+    ///         } else if ui.button("Collapse all").clicked() {
+    ///             Some(false)
+    ///         } else {
+    ///             None
+    ///         }
+    ///     })
+    ///     .inner;
+    /// if let Some(open) = expand {
+    ///     ui.set_all_collapsing_open(open);
+    /// }
+    /// ui.collapsing("Transform", |ui| ui.label("…"));
+    /// ui.collapsing("Material", |ui| ui.label("…"));
+    /// # });
+    /// ```
+    pub fn set_all_collapsing_open(&self, open: bool) {
+        crate::collapsing_header::request_all_open(self.ctx(), Some(self.unique_id), open);
+    }
+
     /// Create a child ui which is indented to the right.
     ///
     /// The `id_salt` here be anything at all.
@@ -2639,7 +3261,8 @@ impl Ui {
     }
 
     /// Start a ui with vertical layout.
-    /// Widgets will be left-justified.
+    /// Widgets will be left-justified, or right-justified in right-to-left mode
+    /// (see [`Self::layout_direction`]).
     ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
@@ -2653,10 +3276,8 @@ impl Ui {
     /// See also [`Self::with_layout`] for more options.
     #[inline]
     pub fn vertical<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
-        self.scope_builder(
-            UiBuilder::new().layout(Layout::top_down(Align::Min)),
-            add_contents,
-        )
+        let layout = Layout::top_down(self.layout_direction().start());
+        self.scope_builder(UiBuilder::new().layout(layout), add_contents)
     }
 
     /// Start a ui with vertical layout.
@@ -2762,6 +3383,68 @@ impl Ui {
         self.placer.set_row_height(height);
     }
 
+    /// Split the remaining space into a left and a right pane, with a draggable divider in between.
+    ///
+    /// `ratio` is the initial fraction of the width given to the left pane.
+    /// The user can drag the divider, or double-click it to collapse the left pane.
+    /// The ratio is persisted in [`crate::Memory`].
+    ///
+    /// For more options, or more panes, use [`crate::Split`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.split_horizontal(0.3, |left| {
+    ///     left.label("Tree");
+    /// }, |right| {
+    ///     right.split_vertical(0.5, |top| {
+    ///         top.label("Editor");
+    ///     }, |bottom| {
+    ///         bottom.label("Console");
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn split_horizontal<L, R>(
+        &mut self,
+        ratio: f32,
+        add_left: impl FnOnce(&mut Self) -> L,
+        add_right: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<(L, R)> {
+        let split = crate::Split::horizontal(self.next_auto_id());
+        self.split_two(split, ratio, add_left, add_right)
+    }
+
+    /// Split the remaining space into a top and a bottom pane, with a draggable divider in between.
+    ///
+    /// `ratio` is the initial fraction of the height given to the top pane.
+    ///
+    /// See [`Self::split_horizontal`] for more.
+    pub fn split_vertical<T, B>(
+        &mut self,
+        ratio: f32,
+        add_top: impl FnOnce(&mut Self) -> T,
+        add_bottom: impl FnOnce(&mut Self) -> B,
+    ) -> InnerResponse<(T, B)> {
+        let split = crate::Split::vertical(self.next_auto_id());
+        self.split_two(split, ratio, add_top, add_bottom)
+    }
+
+    fn split_two<A, B>(
+        &mut self,
+        split: crate::Split,
+        ratio: f32,
+        add_first: impl FnOnce(&mut Self) -> A,
+        add_second: impl FnOnce(&mut Self) -> B,
+    ) -> InnerResponse<(A, B)> {
+        let ratio = ratio.clamp(0.0, 1.0);
+        split
+            .default_ratios([ratio, 1.0 - ratio])
+            .show(self, 2, |panes| {
+                let (first, second) = panes.split_at_mut(1);
+                (add_first(&mut first[0]), add_second(&mut second[0]))
+            })
+    }
+
     /// Temporarily split a [`Ui`] into several columns.
     ///
     /// ```
@@ -2802,7 +3485,7 @@ impl Ui {
                 let mut column_ui = self.new_child(
                     UiBuilder::new()
                         .max_rect(child_rect)
-                        .layout(Layout::top_down_justified(Align::LEFT)),
+                        .layout(Layout::top_down_justified(self.layout_direction().start())),
                 );
                 column_ui.set_width(column_width);
                 column_ui
@@ -2859,7 +3542,7 @@ impl Ui {
             let mut column_ui = self.new_child(
                 UiBuilder::new()
                     .max_rect(child_rect)
-                    .layout(Layout::top_down_justified(Align::LEFT)),
+                    .layout(Layout::top_down_justified(self.layout_direction().start())),
             );
             column_ui.set_width(column_width);
             column_ui
@@ -2940,6 +3623,8 @@ impl Ui {
     /// Returns the dropped item, if it was released this frame.
     ///
     /// The given frame is used for its margins, but it color is ignored.
+    ///
+    /// This is a shorthand for a [`crate::DropTarget`] accepting a single payload type.
     #[doc(alias = "drag and drop")]
     pub fn dnd_drop_zone<Payload, R>(
         &mut self,
@@ -2949,40 +3634,14 @@ impl Ui {
     where
         Payload: Any + Send + Sync,
     {
-        let is_anything_being_dragged = DragAndDrop::has_any_payload(self.ctx());
-        let can_accept_what_is_being_dragged =
-            DragAndDrop::has_payload_of_type::<Payload>(self.ctx());
-
-        let mut frame = frame.begin(self);
-        let inner = add_contents(&mut frame.content_ui);
-        let response = frame.allocate_space(self);
-
-        // NOTE: we use `response.contains_pointer` here instead of `hovered`, because
-        // `hovered` is always false when another widget is being dragged.
-        let style = if is_anything_being_dragged
-            && can_accept_what_is_being_dragged
-            && response.contains_pointer()
-        {
-            self.visuals().widgets.active
-        } else {
-            self.visuals().widgets.inactive
-        };
-
-        let mut fill = style.bg_fill;
-        let mut stroke = style.bg_stroke;
-
-        if is_anything_being_dragged && !can_accept_what_is_being_dragged {
-            // When dragging something else, show that it can't be dropped here:
-            fill = self.visuals().disable(fill);
-            stroke.color = self.visuals().disable(stroke.color);
-        }
-
-        frame.frame.fill = fill;
-        frame.frame.stroke = stroke;
-
-        frame.paint(self);
-
-        let payload = response.dnd_release_payload::<Payload>();
+        let drop_target = crate::DropTarget::new()
+            .accept::<Payload>()
+            .frame(frame)
+            .show(self, add_contents);
+        let payload = drop_target.dropped::<Payload>();
+        let crate::DropTargetResponse {
+            inner, response, ..
+        } = drop_target;
 
         (InnerResponse { inner, response }, payload)
     }
@@ -3250,6 +3909,15 @@ fn register_rect(ui: &Ui, rect: Rect) {
 #[cfg(not(debug_assertions))]
 fn register_rect(_ui: &Ui, _rect: Rect) {}
 
+/// [`Response::union`], ignoring `b` if it is on another layer (e.g. in a popup).
+fn union_on_same_layer(a: Response, b: Response) -> Response {
+    if a.layer_id == b.layer_id {
+        a.union(b)
+    } else {
+        a
+    }
+}
+
 #[test]
 fn ui_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
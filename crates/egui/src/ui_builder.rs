@@ -3,7 +3,7 @@ use std::{hash::Hash, sync::Arc};
 use crate::ClosableTag;
 #[expect(unused_imports)] // Used for doclinks
 use crate::Ui;
-use crate::{Id, LayerId, Layout, Rect, Sense, Style, UiStackInfo};
+use crate::{Id, LayerId, Layout, Rect, Sense, Style, Tween, UiStackInfo};
 
 /// Build a [`Ui`] as the child of another [`Ui`].
 ///
@@ -20,10 +20,12 @@ pub struct UiBuilder {
     pub max_rect: Option<Rect>,
     pub layout: Option<Layout>,
     pub disabled: bool,
+    pub read_only: bool,
     pub invisible: bool,
     pub sizing_pass: bool,
     pub style: Option<Arc<Style>>,
     pub sense: Option<Sense>,
+    pub layout_transition: Option<Tween>,
 }
 
 impl UiBuilder {
@@ -121,6 +123,18 @@ impl UiBuilder {
         self
     }
 
+    /// Make the new `Ui` read-only.
+    ///
+    /// Unlike [`Self::disabled`], the widgets are shown at full contrast,
+    /// and text can still be selected and copied, but their values can't be edited.
+    ///
+    /// Note that if the parent `Ui` is read-only, the child will always be read-only.
+    #[inline]
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
     /// Make the contents invisible.
     ///
     /// Will also disable the `Ui` (see [`Self::disabled`]).
@@ -166,6 +180,41 @@ impl UiBuilder {
         self
     }
 
+    /// Animate the rectangle of the new [`Ui`] from where it was last frame
+    /// to where it is now, instead of snapping there.
+    ///
+    /// This only has an effect with [`Ui::scope_builder`].
+    /// The contents are laid out at their new place right away, and react to the pointer there,
+    /// but they are painted moving (and clipped while growing or shrinking) to it.
+    /// The parent [`Ui`] allocates the animated size,
+    /// so whatever comes after moves smoothly too.
+    ///
+    /// Give the [`Ui`] an [`Self::id_salt`] that follows it (e.g. the key of a list item),
+    /// so that it animates when reordered.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut expanded = false;
+    /// ui.scope_builder(
+    ///     egui::UiBuilder::new().layout_transition(egui::Tween::new(0.2)),
+    ///     |ui| {
+    ///         ui.checkbox(&mut expanded, "Show more");
+    ///         if expanded {
+    ///             ui.label("More");
+    ///         }
+    ///     },
+    /// );
+    /// ui.label("This slides down smoothly");
+    /// # });
+    /// ```
+    ///
+    /// See also [`crate::AnimatedLayout`].
+    #[inline]
+    pub fn layout_transition(mut self, tween: Tween) -> Self {
+        self.layout_transition = Some(tween);
+        self
+    }
+
     /// Make this [`Ui`] closable.
     ///
     /// Calling [`Ui::close`] in a child [`Ui`] will mark this [`Ui`] for closing.
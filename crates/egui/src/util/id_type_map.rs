@@ -3,7 +3,13 @@
 // For non-serializable types, these simply return `None`.
 // This will also allow users to pick their own serialization format per type.
 
-use std::{any::Any, sync::Arc};
+use std::{
+    any::Any,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering::Relaxed},
+    },
+};
 
 // -----------------------------------------------------------------------------------------------
 
@@ -88,6 +94,15 @@ enum Element {
         /// None if non-serializable type.
         #[cfg(feature = "persistence")]
         serialize_fn: Option<Serializer>,
+
+        /// The id the value was stored with.
+        id: Id,
+
+        /// From [`std::any::type_name`].
+        type_name: &'static str,
+
+        /// The [`IdTypeMap::pass_nr`] when the value was last read or written.
+        last_used: AtomicU64,
     },
 
     /// A serialized value
@@ -102,11 +117,17 @@ impl Clone for Element {
                 clone_fn,
                 #[cfg(feature = "persistence")]
                 serialize_fn,
+                id,
+                type_name,
+                last_used,
             } => Self::Value {
                 value: clone_fn(value),
                 clone_fn: *clone_fn,
                 #[cfg(feature = "persistence")]
                 serialize_fn: *serialize_fn,
+                id: *id,
+                type_name,
+                last_used: AtomicU64::new(last_used.load(Relaxed)),
             },
 
             Self::Serialized(element) => Self::Serialized(element.clone()),
@@ -117,9 +138,16 @@ impl Clone for Element {
 impl std::fmt::Debug for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
-            Self::Value { value, .. } => f
+            Self::Value {
+                value,
+                id,
+                type_name,
+                ..
+            } => f
                 .debug_struct("Element::Value")
                 .field("type_id", &(**value).type_id())
+                .field("id", id)
+                .field("type_name", type_name)
                 .finish_non_exhaustive(),
             Self::Serialized(SerializedElement {
                 type_id,
@@ -138,7 +166,11 @@ impl std::fmt::Debug for Element {
 impl Element {
     /// Create a value that won't be persisted.
     #[inline]
-    pub(crate) fn new_temp<T: 'static + Any + Clone + Send + Sync>(t: T) -> Self {
+    pub(crate) fn new_temp<T: 'static + Any + Clone + Send + Sync>(
+        id: Id,
+        pass_nr: u64,
+        t: T,
+    ) -> Self {
         Self::Value {
             value: Box::new(t),
             clone_fn: |x| {
@@ -147,12 +179,15 @@ impl Element {
             },
             #[cfg(feature = "persistence")]
             serialize_fn: None,
+            id,
+            type_name: std::any::type_name::<T>(),
+            last_used: AtomicU64::new(pass_nr),
         }
     }
 
     /// Create a value that will be persisted.
     #[inline]
-    pub(crate) fn new_persisted<T: SerializableAny>(t: T) -> Self {
+    pub(crate) fn new_persisted<T: SerializableAny>(id: Id, pass_nr: u64, t: T) -> Self {
         Self::Value {
             value: Box::new(t),
             clone_fn: |x| {
@@ -164,6 +199,9 @@ impl Element {
                 let x = x.downcast_ref::<T>().unwrap(); // This will never panic too, for same reason.
                 ron::to_string(x).ok()
             }),
+            id,
+            type_name: std::any::type_name::<T>(),
+            last_used: AtomicU64::new(pass_nr),
         }
     }
 
@@ -176,6 +214,50 @@ impl Element {
         }
     }
 
+    /// Mark the value as used in the given pass.
+    #[inline]
+    fn touch(&self, pass_nr: u64) {
+        if let Self::Value { last_used, .. } = self {
+            last_used.store(pass_nr, Relaxed);
+        }
+    }
+
+    fn entry(&self, key: u64, pass_nr: u64) -> IdTypeMapEntry {
+        match self {
+            Self::Value {
+                value,
+                #[cfg(feature = "persistence")]
+                serialize_fn,
+                id,
+                type_name,
+                last_used,
+                ..
+            } => IdTypeMapEntry {
+                id: Some(*id),
+                type_id: (**value).type_id().into(),
+                type_name: Some(type_name),
+                num_bytes: std::mem::size_of_val(&**value),
+                #[cfg(feature = "persistence")]
+                persisted: serialize_fn.is_some(),
+                #[cfg(not(feature = "persistence"))]
+                persisted: false,
+                serialized: false,
+                passes_since_used: pass_nr.saturating_sub(last_used.load(Relaxed)),
+                key,
+            },
+            Self::Serialized(SerializedElement { type_id, ron, .. }) => IdTypeMapEntry {
+                id: None,
+                type_id: *type_id,
+                type_name: None,
+                num_bytes: ron.len(),
+                persisted: true,
+                serialized: true,
+                passes_since_used: pass_nr,
+                key,
+            },
+        }
+    }
+
     #[inline]
     pub(crate) fn get_temp<T: 'static>(&self) -> Option<&T> {
         match self {
@@ -195,18 +277,21 @@ impl Element {
     #[inline]
     pub(crate) fn get_temp_mut_or_insert_with<T: 'static + Any + Clone + Send + Sync>(
         &mut self,
+        id: Id,
+        pass_nr: u64,
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         match self {
             Self::Value { value, .. } => {
                 if !value.is::<T>() {
-                    *self = Self::new_temp(insert_with());
+                    *self = Self::new_temp(id, pass_nr, insert_with());
                 }
             }
             Self::Serialized(_) => {
-                *self = Self::new_temp(insert_with());
+                *self = Self::new_temp(id, pass_nr, insert_with());
             }
         }
+        self.touch(pass_nr);
 
         match self {
             Self::Value { value, .. } => value.downcast_mut().unwrap(), // This unwrap will never panic because we already converted object to required type
@@ -217,25 +302,32 @@ impl Element {
     #[inline]
     pub(crate) fn get_persisted_mut_or_insert_with<T: SerializableAny>(
         &mut self,
+        id: Id,
+        pass_nr: u64,
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         match self {
             Self::Value { value, .. } => {
                 if !value.is::<T>() {
-                    *self = Self::new_persisted(insert_with());
+                    *self = Self::new_persisted(id, pass_nr, insert_with());
                 }
             }
 
             #[cfg(feature = "persistence")]
             Self::Serialized(SerializedElement { ron, .. }) => {
-                *self = Self::new_persisted(from_ron_str::<T>(ron).unwrap_or_else(insert_with));
+                *self = Self::new_persisted(
+                    id,
+                    pass_nr,
+                    from_ron_str::<T>(ron).unwrap_or_else(insert_with),
+                );
             }
 
             #[cfg(not(feature = "persistence"))]
             Self::Serialized(_) => {
-                *self = Self::new_persisted(insert_with());
+                *self = Self::new_persisted(id, pass_nr, insert_with());
             }
         }
+        self.touch(pass_nr);
 
         match self {
             Self::Value { value, .. } => value.downcast_mut().unwrap(), // This unwrap will never panic because we already converted object to required type
@@ -243,13 +335,18 @@ impl Element {
         }
     }
 
-    pub(crate) fn get_mut_persisted<T: SerializableAny>(&mut self) -> Option<&mut T> {
+    pub(crate) fn get_mut_persisted<T: SerializableAny>(
+        &mut self,
+        id: Id,
+        pass_nr: u64,
+    ) -> Option<&mut T> {
+        self.touch(pass_nr);
         match self {
             Self::Value { value, .. } => value.downcast_mut(),
 
             #[cfg(feature = "persistence")]
             Self::Serialized(SerializedElement { ron, .. }) => {
-                *self = Self::new_persisted(from_ron_str::<T>(ron)?);
+                *self = Self::new_persisted(id, pass_nr, from_ron_str::<T>(ron)?);
 
                 match self {
                     Self::Value { value, .. } => value.downcast_mut(),
@@ -258,7 +355,10 @@ impl Element {
             }
 
             #[cfg(not(feature = "persistence"))]
-            Self::Serialized(_) => None,
+            Self::Serialized(_) => {
+                let _ = id;
+                None
+            }
         }
     }
 
@@ -352,6 +452,9 @@ pub struct IdTypeMap {
     map: nohash_hasher::IntMap<u64, Element>,
 
     max_bytes_per_type: usize,
+
+    /// Increased by one each pass. Used to find values that are no longer used.
+    pass_nr: u64,
 }
 
 impl Default for IdTypeMap {
@@ -359,6 +462,7 @@ impl Default for IdTypeMap {
         Self {
             map: Default::default(),
             max_bytes_per_type: 256 * 1024,
+            pass_nr: 0,
         }
     }
 }
@@ -368,14 +472,16 @@ impl IdTypeMap {
     #[inline]
     pub fn insert_temp<T: 'static + Any + Clone + Send + Sync>(&mut self, id: Id, value: T) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.map.insert(hash, Element::new_temp(value));
+        self.map
+            .insert(hash, Element::new_temp(id, self.pass_nr, value));
     }
 
     /// Insert a value that will be persisted next time you start the app.
     #[inline]
     pub fn insert_persisted<T: SerializableAny>(&mut self, id: Id, value: T) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.map.insert(hash, Element::new_persisted(value));
+        self.map
+            .insert(hash, Element::new_persisted(id, self.pass_nr, value));
     }
 
     /// Read a value without trying to deserialize a persisted value.
@@ -384,7 +490,9 @@ impl IdTypeMap {
     #[inline]
     pub fn get_temp<T: 'static + Clone>(&self, id: Id) -> Option<T> {
         let hash = hash(TypeId::of::<T>(), id);
-        self.map.get(&hash).and_then(|x| x.get_temp()).cloned()
+        let element = self.map.get(&hash)?;
+        element.touch(self.pass_nr);
+        element.get_temp().cloned()
     }
 
     /// Read a value, optionally deserializing it if available.
@@ -396,9 +504,10 @@ impl IdTypeMap {
     #[inline]
     pub fn get_persisted<T: SerializableAny>(&mut self, id: Id) -> Option<T> {
         let hash = hash(TypeId::of::<T>(), id);
+        let pass_nr = self.pass_nr;
         self.map
             .get_mut(&hash)
-            .and_then(|x| x.get_mut_persisted())
+            .and_then(|x| x.get_mut_persisted(id, pass_nr))
             .cloned()
     }
 
@@ -435,14 +544,17 @@ impl IdTypeMap {
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         let hash = hash(TypeId::of::<T>(), id);
+        let pass_nr = self.pass_nr;
         use std::collections::hash_map::Entry;
         match self.map.entry(hash) {
             Entry::Vacant(vacant) => vacant
-                .insert(Element::new_temp(insert_with()))
+                .insert(Element::new_temp(id, pass_nr, insert_with()))
                 .get_mut_temp()
                 .unwrap(), // this unwrap will never panic, because we insert correct type right now
             Entry::Occupied(occupied) => {
-                occupied.into_mut().get_temp_mut_or_insert_with(insert_with)
+                occupied
+                    .into_mut()
+                    .get_temp_mut_or_insert_with(id, pass_nr, insert_with)
             }
         }
     }
@@ -453,15 +565,18 @@ impl IdTypeMap {
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         let hash = hash(TypeId::of::<T>(), id);
+        let pass_nr = self.pass_nr;
         use std::collections::hash_map::Entry;
         match self.map.entry(hash) {
             Entry::Vacant(vacant) => vacant
-                .insert(Element::new_persisted(insert_with()))
-                .get_mut_persisted()
+                .insert(Element::new_persisted(id, pass_nr, insert_with()))
+                .get_mut_persisted(id, pass_nr)
                 .unwrap(), // this unwrap will never panic, because we insert correct type right now
-            Entry::Occupied(occupied) => occupied
-                .into_mut()
-                .get_persisted_mut_or_insert_with(insert_with),
+            Entry::Occupied(occupied) => {
+                occupied
+                    .into_mut()
+                    .get_persisted_mut_or_insert_with(id, pass_nr, insert_with)
+            }
         }
     }
 
@@ -536,6 +651,32 @@ impl IdTypeMap {
             .count()
     }
 
+    /// Information about every stored value, in no particular order.
+    ///
+    /// Useful for finding out what is taking up memory, and for finding state that is no longer used.
+    pub fn entries(&self) -> impl Iterator<Item = IdTypeMapEntry> + '_ {
+        self.map
+            .iter()
+            .map(|(key, element)| element.entry(*key, self.pass_nr))
+    }
+
+    /// Only keep the values for which `keep` returns `true`.
+    pub fn retain(&mut self, mut keep: impl FnMut(&IdTypeMapEntry) -> bool) {
+        let pass_nr = self.pass_nr;
+        self.map
+            .retain(|key, element| keep(&element.entry(*key, pass_nr)));
+    }
+
+    /// Remove the value described by the entry, as returned by [`Self::entries`].
+    pub fn remove_entry(&mut self, entry: &IdTypeMapEntry) {
+        self.map.remove(&entry.key);
+    }
+
+    /// Called by [`crate::Memory`] at the start of each pass.
+    pub(crate) fn begin_pass(&mut self) {
+        self.pass_nr += 1;
+    }
+
     /// The maximum number of bytes that will be used to
     /// store the persisted state of a single widget type.
     ///
@@ -559,6 +700,51 @@ impl IdTypeMap {
     }
 }
 
+/// A value stored in an [`IdTypeMap`], as returned by [`IdTypeMap::entries`].
+#[derive(Clone, Copy, Debug)]
+pub struct IdTypeMapEntry {
+    /// The [`Id`] the value was stored with.
+    ///
+    /// `None` for values that were loaded from disk and have not been read since.
+    pub id: Option<Id>,
+
+    /// The type of the value.
+    pub type_id: TypeId,
+
+    /// The name of the type, from [`std::any::type_name`].
+    ///
+    /// `None` for values that were loaded from disk and have not been read since.
+    pub type_name: Option<&'static str>,
+
+    /// The size of the value, in bytes.
+    ///
+    /// This does not include any heap allocations the value owns.
+    /// For values that are still serialized, this is the size of the serialized data.
+    pub num_bytes: usize,
+
+    /// Will the value be persisted?
+    pub persisted: bool,
+
+    /// Was the value loaded from disk, and not read since?
+    pub serialized: bool,
+
+    /// How many passes ago the value was last read or written.
+    ///
+    /// A large value means the state is probably left over from a widget that is no longer shown.
+    pub passes_since_used: u64,
+
+    key: u64,
+}
+
+#[cfg(feature = "persistence")]
+impl IdTypeMapEntry {
+    /// The [`Id`] the value was stored with, also for values that have not been read since they were loaded.
+    pub(crate) fn stored_id(&self) -> Id {
+        self.id
+            .unwrap_or_else(|| Id::from_hash(self.key ^ self.type_id.value()))
+    }
+}
+
 #[inline(always)]
 fn hash(type_id: TypeId, id: Id) -> u64 {
     type_id.value() ^ id.value()
@@ -984,3 +1170,37 @@ fn test_serialize_gc() {
         Some(B(2_000_000))
     );
 }
+
+#[test]
+fn test_entries_and_unused_values() {
+    let a = Id::new("a");
+    let b = Id::new("b");
+
+    let mut map: IdTypeMap = Default::default();
+    map.insert_temp(a, 42_i32);
+    map.insert_persisted(b, 13.37_f64);
+
+    for _ in 0..10 {
+        map.begin_pass();
+        assert_eq!(map.get_temp::<i32>(a), Some(42));
+    }
+
+    let mut entries: Vec<_> = map.entries().collect();
+    entries.sort_by_key(|e| e.passes_since_used);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].id, Some(a));
+    assert_eq!(entries[0].type_name, Some("i32"));
+    assert_eq!(entries[0].num_bytes, 4);
+    assert_eq!(entries[0].passes_since_used, 0);
+    assert_eq!(entries[1].id, Some(b));
+    assert_eq!(entries[1].type_name, Some("f64"));
+    assert_eq!(entries[1].passes_since_used, 10);
+
+    map.retain(|e| e.passes_since_used < 5);
+    assert_eq!(map.get_temp::<i32>(a), Some(42));
+    assert_eq!(map.get_temp::<f64>(b), None);
+
+    let entry = map.entries().next().unwrap();
+    map.remove_entry(&entry);
+    assert!(map.is_empty());
+}
@@ -4,7 +4,7 @@ pub(crate) mod fixed_cache;
 pub mod id_type_map;
 pub mod undoer;
 
-pub use id_type_map::IdTypeMap;
+pub use id_type_map::{IdTypeMap, IdTypeMapEntry};
 
 pub use epaint::emath::History;
 pub use epaint::util::{hash, hash_with};
@@ -240,6 +240,12 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Change the texts of the button, e.g. to parse the mnemonics of a menu button.
+    pub(crate) fn map_texts(mut self, f: impl FnMut(WidgetText) -> WidgetText) -> Self {
+        self.layout.atoms.map_texts(f);
+        self
+    }
+
     /// If `true`, mark this button as "selected".
     #[inline]
     pub fn selected(mut self, selected: bool) -> Self {
@@ -248,6 +254,7 @@ impl<'a> Button<'a> {
     }
 
     /// Show the button and return a [`AtomLayoutResponse`] for painting custom contents.
+    #[track_caller]
     pub fn atom_ui(self, ui: &mut Ui) -> AtomLayoutResponse {
         let Button {
             mut layout,
@@ -345,6 +352,7 @@ impl<'a> Button<'a> {
 }
 
 impl Widget for Button<'_> {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         self.atom_ui(ui).response
     }
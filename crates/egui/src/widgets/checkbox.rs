@@ -48,6 +48,7 @@ impl<'a> Checkbox<'a> {
 }
 
 impl Widget for Checkbox<'_> {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         let Checkbox {
             checked,
@@ -70,7 +71,7 @@ impl Widget for Checkbox<'_> {
         let text = atoms.text().map(String::from);
 
         let mut prepared = AtomLayout::new(atoms)
-            .sense(Sense::click())
+            .sense(ui.edit_sense(Sense::click()))
             .min_size(min_size)
             .allocate(ui);
 
@@ -1,13 +1,15 @@
 //! Color picker widgets.
 
+use std::sync::Arc;
+
 use crate::util::fixed_cache::FixedCache;
 use crate::{
-    Context, DragValue, Id, Painter, Popup, PopupCloseBehavior, Response, Sense, Ui, Widget as _,
-    WidgetInfo, WidgetType, epaint, lerp, remap_clamp,
+    Context, DragValue, Id, Painter, Popup, PopupCloseBehavior, Response, Sense, TextEdit, Ui,
+    Widget as _, WidgetInfo, WidgetType, epaint, lerp, remap_clamp,
 };
 use epaint::{
     Mesh, Rect, Shape, Stroke, StrokeKind, Vec2,
-    ecolor::{Color32, Hsva, HsvaGamma, Rgba},
+    ecolor::{Color32, HexColor, Hsla, Hsva, HsvaGamma, Oklab, Oklch, Rgba},
     pos2, vec2,
 };
 
@@ -86,8 +88,11 @@ pub fn show_color_at(painter: &Painter, color: Color32, rect: Rect) {
 }
 
 fn color_button(ui: &mut Ui, color: Color32, open: bool) -> Response {
-    let size = ui.spacing().interact_size;
-    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    color_button_sized(ui, color, ui.spacing().interact_size, open)
+}
+
+fn color_button_sized(ui: &mut Ui, color: Color32, size: Vec2, open: bool) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, ui.edit_sense(Sense::click()));
     response.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
 
     if ui.is_rect_visible(rect) {
@@ -253,6 +258,178 @@ pub enum Alpha {
     BlendOrAdditive,
 }
 
+/// The color model of the extra sliders in the color picker.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorModel {
+    /// Hue, saturation, value. This is shown by the 2D slider, so there are no extra sliders.
+    #[default]
+    Hsv,
+
+    /// Hue, saturation, lightness, see [`Hsla`].
+    Hsl,
+
+    /// Perceptual lightness, chroma, hue, see [`Oklch`].
+    Oklch,
+
+    /// Perceptual lightness and two opponent color axes, see [`Oklab`].
+    Oklab,
+}
+
+impl ColorModel {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Hsv => "HSV",
+            Self::Hsl => "HSL",
+            Self::Oklch => "OKLCH",
+            Self::Oklab => "Oklab",
+        }
+    }
+
+    fn slider_names(self) -> [&'static str; 3] {
+        match self {
+            Self::Hsv => ["Hue", "Saturation", "Value"],
+            Self::Hsl => ["Hue", "Saturation", "Lightness"],
+            Self::Oklch => ["Lightness", "Chroma", "Hue"],
+            Self::Oklab => ["Lightness", "Green-red", "Blue-yellow"],
+        }
+    }
+
+    /// The components of the color in this model, each mapped to 0-1 for the sliders.
+    fn sliders_from_color(self, hsva: Hsva) -> [f32; 3] {
+        let ab_range = -OKLAB_CHROMA_MAX..=OKLAB_CHROMA_MAX;
+        match self {
+            Self::Hsv => [hsva.h, hsva.s, hsva.v],
+            Self::Hsl => {
+                let Hsla { h, s, l, .. } = hsva.into();
+                [h, s, l]
+            }
+            Self::Oklch => {
+                let Oklch { l, c, h, .. } = hsva.into();
+                [l, c / OKLAB_CHROMA_MAX, h]
+            }
+            Self::Oklab => {
+                let Oklab { l, a, b, .. } = hsva.into();
+                [
+                    l,
+                    remap_clamp(a, ab_range.clone(), 0.0..=1.0),
+                    remap_clamp(b, ab_range, 0.0..=1.0),
+                ]
+            }
+        }
+    }
+
+    /// The inverse of [`Self::sliders_from_color`]. The returned color is opaque.
+    fn color_from_sliders(self, [x, y, z]: [f32; 3]) -> Hsva {
+        let ab_range = -OKLAB_CHROMA_MAX..=OKLAB_CHROMA_MAX;
+        match self {
+            Self::Hsv => Hsva::new(x, y, z, 1.0),
+            Self::Hsl => Hsla::new(x, y, z, 1.0).into(),
+            Self::Oklch => Oklch::new(x, y * OKLAB_CHROMA_MAX, z, 1.0).into(),
+            Self::Oklab => {
+                let a = lerp(ab_range.clone(), y);
+                let b = lerp(ab_range, z);
+                Oklab::new(x, a, b, 1.0).into()
+            }
+        }
+    }
+}
+
+/// The largest chroma (and `a`/`b` magnitude) of the [`Oklab`] and [`Oklch`] sliders.
+const OKLAB_CHROMA_MAX: f32 = 0.4;
+
+/// The state shared by all color pickers: the selected [`ColorModel`], and the user palette.
+///
+/// This is persisted in [`crate::Memory`], so the palette survives restarts of the app
+/// if the memory is persisted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ColorPickerMemory {
+    /// The color model of the extra sliders.
+    pub model: ColorModel,
+
+    /// Colors the user saved, in the order they were saved.
+    pub saved: Vec<Color32>,
+
+    /// The most recently picked colors, the latest first.
+    pub recent: Vec<Color32>,
+}
+
+impl ColorPickerMemory {
+    /// How many recent colors are remembered.
+    pub const MAX_RECENT: usize = 10;
+
+    fn id() -> Id {
+        Id::new("color_picker_memory")
+    }
+
+    pub fn load(ctx: &Context) -> Self {
+        ctx.data_mut(|d| d.get_persisted(Self::id()))
+            .unwrap_or_default()
+    }
+
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_persisted(Self::id(), self));
+    }
+
+    /// Add a color to the saved colors, unless it is already saved.
+    pub fn save(&mut self, color: Color32) {
+        if !self.saved.contains(&color) {
+            self.saved.push(color);
+        }
+    }
+
+    /// Put a color first in the recent colors.
+    pub fn add_recent(&mut self, color: Color32) {
+        self.recent.retain(|&c| c != color);
+        self.recent.insert(0, color);
+        self.recent.truncate(Self::MAX_RECENT);
+    }
+}
+
+/// A function that lets the user pick a color from anywhere on the screen.
+///
+/// Returns `None` if the user cancelled.
+pub type EyedropperFn = Arc<dyn Fn() -> Option<Color32> + Send + Sync>;
+
+#[derive(Clone)]
+struct Eyedropper(EyedropperFn);
+
+/// Install an eyedropper, provided by the integration (or app),
+/// which is then offered as a button in all color pickers.
+///
+/// egui itself can't read pixels from the screen, so there is no eyedropper by default.
+/// Pass `None` to remove it.
+pub fn set_eyedropper(ctx: &Context, eyedropper: Option<EyedropperFn>) {
+    ctx.data_mut(|d| match eyedropper {
+        Some(eyedropper) => d.insert_temp(Id::NULL, Eyedropper(eyedropper)),
+        None => {
+            d.remove::<Eyedropper>(Id::NULL);
+        }
+    });
+}
+
+fn eyedropper(ctx: &Context) -> Option<Eyedropper> {
+    ctx.data(|d| d.get_temp::<Eyedropper>(Id::NULL))
+}
+
+#[derive(Clone, Copy)]
+struct ExtendedControls(bool);
+
+/// Show a hex field, sliders for other [`ColorModel`]s, and the saved and recent colors
+/// of [`ColorPickerMemory`] in all color pickers.
+///
+/// Off by default, which keeps the color picker compact.
+pub fn set_extended_controls(ctx: &Context, enabled: bool) {
+    ctx.data_mut(|d| d.insert_temp(Id::NULL, ExtendedControls(enabled)));
+}
+
+fn extended_controls(ctx: &Context) -> bool {
+    ctx.data(|d| d.get_temp::<ExtendedControls>(Id::NULL))
+        .is_some_and(|ExtendedControls(enabled)| enabled)
+}
+
 fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     use crate::style::NumericColorSpace;
 
@@ -311,6 +488,16 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
                 }
             }
         }
+
+        NumericColorSpace::Hex => {
+            hex_color_space_edit_ui(ui, hsvag, alpha_control);
+        }
+    }
+
+    let extended = extended_controls(ui.ctx());
+
+    if extended && ui.style().visuals.numeric_color_space != NumericColorSpace::Hex {
+        hex_edit_ui(ui, hsvag, alpha_control);
     }
 
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
@@ -375,6 +562,199 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
             color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
         }
     }
+
+    if !extended {
+        if let Some(eyedropper) = eyedropper(ui.ctx()) {
+            eyedropper_button_ui(ui, hsvag, &eyedropper);
+        }
+        return;
+    }
+
+    let mut memory = ColorPickerMemory::load(ui.ctx());
+    let memory_before = memory.clone();
+
+    ui.horizontal(|ui| {
+        for model in [
+            ColorModel::Hsv,
+            ColorModel::Hsl,
+            ColorModel::Oklch,
+            ColorModel::Oklab,
+        ] {
+            ui.selectable_value(&mut memory.model, model, model.name());
+        }
+    });
+    color_model_sliders_ui(ui, hsvag, memory.model);
+
+    palette_ui(ui, hsvag, &mut memory);
+
+    if memory != memory_before {
+        memory.store(ui.ctx());
+    }
+}
+
+/// Shows three 1D sliders, one for each component of the color in the given model.
+fn color_model_sliders_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, model: ColorModel) {
+    if model == ColorModel::Hsv {
+        return; // The 2D slider and the hue slider already cover this.
+    }
+
+    let hsva = Hsva::from(*hsvag);
+    let components = model.sliders_from_color(hsva);
+    let mut edited = components;
+    for (i, name) in model.slider_names().into_iter().enumerate() {
+        color_slider_1d(ui, &mut edited[i], |t| {
+            let mut components = components;
+            components[i] = t;
+            model.color_from_sliders(components).into()
+        })
+        .on_hover_text(name);
+    }
+
+    // Only update if changed to avoid rounding issues.
+    if edited != components {
+        *hsvag = HsvaGamma::from(Hsva {
+            a: hsva.a,
+            ..model.color_from_sliders(edited)
+        });
+    }
+}
+
+/// Shows the saved and recent colors of [`ColorPickerMemory`], and the eyedropper (if any).
+fn palette_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, memory: &mut ColorPickerMemory) {
+    let swatch_size = Vec2::splat(ui.spacing().interact_size.y);
+    let mut picked = None;
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label("Saved:");
+        let mut remove = None;
+        for (i, &color) in memory.saved.iter().enumerate() {
+            let response = color_button_sized(ui, color, swatch_size, false)
+                .on_hover_text("Click to use, right-click to remove");
+            if response.clicked() {
+                picked = Some(color);
+            } else if response.secondary_clicked() {
+                remove = Some(i);
+            }
+        }
+        if let Some(i) = remove {
+            memory.saved.remove(i);
+        }
+        if ui
+            .small_button("+")
+            .on_hover_text("Save the selected color")
+            .clicked()
+        {
+            memory.save(Color32::from(*hsvag));
+        }
+
+        if let Some(eyedropper) = eyedropper(ui.ctx()) {
+            eyedropper_button_ui(ui, hsvag, &eyedropper);
+        }
+    });
+
+    if !memory.recent.is_empty() {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Recent:");
+            for &color in &memory.recent {
+                let response =
+                    color_button_sized(ui, color, swatch_size, false).on_hover_text("Click to use");
+                if response.clicked() {
+                    picked = Some(color);
+                }
+            }
+        });
+    }
+
+    if let Some(color) = picked {
+        *hsvag = HsvaGamma::from(color);
+    }
+}
+
+/// A button for the eyedropper installed with [`set_eyedropper`].
+fn eyedropper_button_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, eyedropper: &Eyedropper) {
+    if ui
+        .small_button("💉")
+        .on_hover_text("Pick a color from the screen")
+        .clicked()
+    {
+        if let Some(color) = (eyedropper.0)() {
+            *hsvag = HsvaGamma::from(color);
+        }
+    }
+}
+
+/// Shows a text field for the color as a hex string, e.g. `#ff8000`.
+fn hex_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
+    ui.horizontal(|ui| {
+        ui.label("Hex:");
+        hex_text_edit_ui(ui, hsvag, alpha);
+    });
+}
+
+/// Used for [`crate::style::NumericColorSpace::Hex`].
+///
+/// Returns `true` on change.
+fn hex_color_space_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) -> bool {
+    ui.horizontal(|ui| {
+        input_type_button_ui(ui);
+
+        if ui
+            .button("📋")
+            .on_hover_text("Click to copy color as hex")
+            .clicked()
+        {
+            ui.ctx().copy_text(hex_string(Color32::from(*hsvag), alpha));
+        }
+
+        hex_text_edit_ui(ui, hsvag, alpha)
+    })
+    .inner
+}
+
+fn hex_string(color: Color32, alpha: Alpha) -> String {
+    if alpha == Alpha::Opaque {
+        HexColor::Hex6(color).to_string()
+    } else {
+        HexColor::Hex8(color).to_string()
+    }
+}
+
+/// A [`TextEdit`] for a hex color, which also accepts CSS colors like `rgb(255, 0, 0)`.
+///
+/// Returns `true` on change.
+fn hex_text_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) -> bool {
+    let color = Color32::from(*hsvag);
+
+    // While editing, we keep the text the user is typing, even if it is not a valid color (yet).
+    let id = ui.id().with("hex_edit");
+    let mut text = ui
+        .data_mut(|d| d.get_temp::<String>(id))
+        .unwrap_or_else(|| hex_string(color, alpha));
+
+    let mut edited = false;
+    let response = ui.add(
+        TextEdit::singleline(&mut text)
+            .desired_width(ui.spacing().interact_size.x * 1.5)
+            .font(crate::TextStyle::Monospace),
+    );
+    if response.has_focus() {
+        if response.changed() {
+            if let Some(parsed) = Color32::from_css(&text) {
+                if parsed != color {
+                    let a = hsvag.a;
+                    *hsvag = HsvaGamma::from(parsed);
+                    if alpha == Alpha::Opaque || is_additive_alpha(a) {
+                        hsvag.a = a; // Keep the alpha we can't edit here
+                    }
+                    edited = true;
+                }
+            }
+        }
+        ui.data_mut(|d| d.insert_temp(id, text));
+    } else {
+        ui.data_mut(|d| d.remove_temp::<String>(id));
+    }
+    edited
 }
 
 fn input_type_button_ui(ui: &mut Ui) {
@@ -489,6 +869,47 @@ pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> b
     changed
 }
 
+/// Shows the full color picker inline, i.e. always open, instead of behind a button like
+/// [`color_edit_button_hsva`]. Useful for e.g. an inspector side panel.
+///
+/// The color is added to the recent colors of [`ColorPickerMemory`] when the user is done
+/// editing (stops dragging or typing).
+pub fn color_picker_inline_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
+    let mut changed = false;
+    let mut response = ui
+        .vertical(|ui| {
+            changed = color_picker_hsva_2d(ui, hsva, alpha);
+        })
+        .response;
+    if changed {
+        response.mark_changed();
+    }
+
+    let editing_id = response.id.with("editing");
+    let was_editing = ui.data(|d| d.get_temp::<bool>(editing_id)).unwrap_or(false);
+    let still_editing = ui.input(|i| i.pointer.any_down()) || ui.ctx().wants_keyboard_input();
+    if (changed || was_editing) && !still_editing {
+        // The user is done editing:
+        let mut memory = ColorPickerMemory::load(ui.ctx());
+        memory.add_recent(Color32::from(*hsva));
+        memory.store(ui.ctx());
+    }
+    ui.data_mut(|d| d.insert_temp(editing_id, (changed || was_editing) && still_editing));
+
+    response
+}
+
+/// Shows the full color picker inline, i.e. always open.
+///
+/// See [`color_picker_inline_hsva`].
+pub fn color_picker_inline_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> Response {
+    let mut hsva = color_cache_get(ui.ctx(), *srgba);
+    let response = color_picker_inline_hsva(ui, &mut hsva, alpha);
+    *srgba = Color32::from(hsva);
+    color_cache_set(ui.ctx(), *srgba, hsva);
+    response
+}
+
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let popup_id = ui.auto_id_with("popup");
     let open = Popup::is_id_open(ui.ctx(), popup_id);
@@ -509,6 +930,13 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
             }
         });
 
+    if open && !Popup::is_id_open(ui.ctx(), popup_id) {
+        // The user is done editing:
+        let mut memory = ColorPickerMemory::load(ui.ctx());
+        memory.add_recent(Color32::from(*hsva));
+        memory.store(ui.ctx());
+    }
+
     button_response
 }
 
@@ -597,7 +597,7 @@ impl Widget for DragValue<'_> {
                     .text_style(text_style),
             )
             .wrap_mode(TextWrapMode::Extend)
-            .sense(Sense::click_and_drag())
+            .sense(ui.edit_sense(Sense::click_and_drag()))
             .min_size(ui.spacing().interact_size); // TODO(emilk): find some more generic solution to `min_size`
 
             let cursor_icon = if value <= *range.start() {
@@ -35,6 +35,7 @@ impl Link {
 }
 
 impl Widget for Link {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         let Self { text } = self;
         let label = Label::new(text).sense(Sense::click());
@@ -124,6 +125,7 @@ impl Hyperlink {
 }
 
 impl Widget for Hyperlink {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         let Self { url, text, new_tab } = self;
 
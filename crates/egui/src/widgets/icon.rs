@@ -0,0 +1,383 @@
+use epaint::{
+    CircleShape, Color32, LineCap, LineJoin, PathBuilder, PathShape, PathStroke, Pos2, Rect,
+    RectShape, Shape, StrokeKind, pos2, vec2,
+};
+
+use crate::{Response, Sense, TextStyle, Ui, Widget, WidgetInfo, WidgetType};
+
+/// A vector icon that comes with egui, see [`Icon`].
+///
+/// The icons are painted with shapes, so they are crisp at any size and zoom level,
+/// and can be painted in any color.
+///
+/// Each icon has a name (e.g. `"chevron_left"`), so that they can be picked in data,
+/// see [`Self::name`] and [`Self::from_name`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BuiltinIcon {
+    ChevronLeft,
+    ChevronRight,
+    ChevronUp,
+    ChevronDown,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    ArrowDown,
+    Close,
+    Check,
+    Plus,
+    Minus,
+    Menu,
+    More,
+    Search,
+    Info,
+    Warning,
+    Error,
+    Refresh,
+    Copy,
+    Trash,
+    Home,
+    Star,
+}
+
+impl BuiltinIcon {
+    /// All the icons, in a sensible order for showing them in a grid.
+    pub const ALL: [Self; 23] = [
+        Self::ChevronLeft,
+        Self::ChevronRight,
+        Self::ChevronUp,
+        Self::ChevronDown,
+        Self::ArrowLeft,
+        Self::ArrowRight,
+        Self::ArrowUp,
+        Self::ArrowDown,
+        Self::Close,
+        Self::Check,
+        Self::Plus,
+        Self::Minus,
+        Self::Menu,
+        Self::More,
+        Self::Search,
+        Self::Info,
+        Self::Warning,
+        Self::Error,
+        Self::Refresh,
+        Self::Copy,
+        Self::Trash,
+        Self::Home,
+        Self::Star,
+    ];
+
+    /// The name of the icon, in `snake_case`, e.g. `"chevron_left"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ChevronLeft => "chevron_left",
+            Self::ChevronRight => "chevron_right",
+            Self::ChevronUp => "chevron_up",
+            Self::ChevronDown => "chevron_down",
+            Self::ArrowLeft => "arrow_left",
+            Self::ArrowRight => "arrow_right",
+            Self::ArrowUp => "arrow_up",
+            Self::ArrowDown => "arrow_down",
+            Self::Close => "close",
+            Self::Check => "check",
+            Self::Plus => "plus",
+            Self::Minus => "minus",
+            Self::Menu => "menu",
+            Self::More => "more",
+            Self::Search => "search",
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Refresh => "refresh",
+            Self::Copy => "copy",
+            Self::Trash => "trash",
+            Self::Home => "home",
+            Self::Star => "star",
+        }
+    }
+
+    /// Look up an icon by its [`Self::name`].
+    ///
+    /// ```
+    /// assert_eq!(egui::BuiltinIcon::from_name("search"), Some(egui::BuiltinIcon::Search));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|icon| icon.name() == name)
+    }
+
+    /// The shapes of the icon, fit into the largest square inside `rect`.
+    pub fn to_shape(self, rect: Rect, color: Color32) -> Shape {
+        // The icons are designed on a 24×24 grid, with lines 2 units wide:
+        let scale = rect.size().min_elem() / 24.0;
+        let origin = rect.center() - vec2(12.0, 12.0) * scale;
+        let p = |x: f32, y: f32| origin + vec2(x, y) * scale;
+        let stroke = PathStroke::new(2.0 * scale, color)
+            .with_cap(LineCap::Round)
+            .with_join(LineJoin::Round);
+
+        let line = |points: &[[f32; 2]]| -> Shape {
+            PathShape::line(
+                points.iter().map(|&[x, y]| p(x, y)).collect(),
+                stroke.clone(),
+            )
+            .into()
+        };
+        let circle = |x: f32, y: f32, radius: f32| -> Shape {
+            CircleShape {
+                center: p(x, y),
+                radius: radius * scale,
+                fill: Color32::TRANSPARENT,
+                stroke: epaint::Stroke::new(2.0 * scale, color),
+            }
+            .into()
+        };
+        let dot =
+            |x: f32, y: f32| -> Shape { CircleShape::filled(p(x, y), 1.5 * scale, color).into() };
+
+        let shapes = match self {
+            Self::ChevronLeft => vec![line(&[[15.0, 6.0], [9.0, 12.0], [15.0, 18.0]])],
+            Self::ChevronRight => vec![line(&[[9.0, 6.0], [15.0, 12.0], [9.0, 18.0]])],
+            Self::ChevronUp => vec![line(&[[6.0, 15.0], [12.0, 9.0], [18.0, 15.0]])],
+            Self::ChevronDown => vec![line(&[[6.0, 9.0], [12.0, 15.0], [18.0, 9.0]])],
+            Self::ArrowLeft => vec![
+                line(&[[19.0, 12.0], [5.0, 12.0]]),
+                line(&[[12.0, 5.0], [5.0, 12.0], [12.0, 19.0]]),
+            ],
+            Self::ArrowRight => vec![
+                line(&[[5.0, 12.0], [19.0, 12.0]]),
+                line(&[[12.0, 5.0], [19.0, 12.0], [12.0, 19.0]]),
+            ],
+            Self::ArrowUp => vec![
+                line(&[[12.0, 19.0], [12.0, 5.0]]),
+                line(&[[5.0, 12.0], [12.0, 5.0], [19.0, 12.0]]),
+            ],
+            Self::ArrowDown => vec![
+                line(&[[12.0, 5.0], [12.0, 19.0]]),
+                line(&[[5.0, 12.0], [12.0, 19.0], [19.0, 12.0]]),
+            ],
+            Self::Close => vec![
+                line(&[[6.0, 6.0], [18.0, 18.0]]),
+                line(&[[18.0, 6.0], [6.0, 18.0]]),
+            ],
+            Self::Check => vec![line(&[[4.0, 12.0], [9.0, 17.0], [20.0, 6.0]])],
+            Self::Plus => vec![
+                line(&[[12.0, 5.0], [12.0, 19.0]]),
+                line(&[[5.0, 12.0], [19.0, 12.0]]),
+            ],
+            Self::Minus => vec![line(&[[5.0, 12.0], [19.0, 12.0]])],
+            Self::Menu => vec![
+                line(&[[4.0, 6.0], [20.0, 6.0]]),
+                line(&[[4.0, 12.0], [20.0, 12.0]]),
+                line(&[[4.0, 18.0], [20.0, 18.0]]),
+            ],
+            Self::More => vec![dot(5.0, 12.0), dot(12.0, 12.0), dot(19.0, 12.0)],
+            Self::Search => vec![circle(10.5, 10.5, 6.5), line(&[[15.5, 15.5], [20.0, 20.0]])],
+            Self::Info => vec![
+                circle(12.0, 12.0, 10.0),
+                line(&[[12.0, 11.0], [12.0, 17.0]]),
+                dot(12.0, 7.5),
+            ],
+            Self::Warning => vec![
+                PathShape::closed_line(
+                    vec![p(12.0, 3.0), p(22.0, 20.0), p(2.0, 20.0)],
+                    stroke.clone(),
+                )
+                .into(),
+                line(&[[12.0, 9.0], [12.0, 13.0]]),
+                dot(12.0, 16.5),
+            ],
+            Self::Error => vec![
+                circle(12.0, 12.0, 10.0),
+                line(&[[9.0, 9.0], [15.0, 15.0]]),
+                line(&[[15.0, 9.0], [9.0, 15.0]]),
+            ],
+            Self::Refresh => {
+                // Clockwise around most of a circle, ending in an arrow head:
+                let (center, radius) = (p(12.0, 12.0), 8.0 * scale);
+                let end_angle = (-60_f32).to_radians();
+                let end = center + radius * epaint::Vec2::angled(end_angle);
+                let forward = epaint::Vec2::angled(end_angle).rot90();
+                let side = 4.0 * scale * forward.rot90();
+                let back = -4.0 * scale * forward;
+                vec![
+                    PathBuilder::new()
+                        .arc(center, radius, 0.0, 300_f32.to_radians())
+                        .to_shape(Color32::TRANSPARENT, stroke.clone()),
+                    PathShape::line(
+                        vec![end + back + side, end, end + back - side],
+                        stroke.clone(),
+                    )
+                    .into(),
+                ]
+            }
+            Self::Copy => vec![
+                RectShape::stroke(
+                    Rect::from_min_max(p(9.0, 9.0), p(20.0, 20.0)),
+                    2.0 * scale,
+                    epaint::Stroke::new(2.0 * scale, color),
+                    StrokeKind::Middle,
+                )
+                .into(),
+                line(&[
+                    [9.0, 15.0],
+                    [4.0, 15.0],
+                    [4.0, 4.0],
+                    [15.0, 4.0],
+                    [15.0, 9.0],
+                ]),
+            ],
+            Self::Trash => vec![
+                line(&[[4.0, 6.0], [20.0, 6.0]]),
+                line(&[[9.0, 6.0], [9.0, 3.5], [15.0, 3.5], [15.0, 6.0]]),
+                line(&[[6.0, 6.0], [7.0, 20.0], [17.0, 20.0], [18.0, 6.0]]),
+            ],
+            Self::Home => vec![
+                line(&[[3.0, 11.0], [12.0, 3.0], [21.0, 11.0]]),
+                line(&[
+                    [5.5, 9.0],
+                    [5.5, 20.0],
+                    [10.0, 20.0],
+                    [10.0, 14.0],
+                    [14.0, 14.0],
+                    [14.0, 20.0],
+                    [18.5, 20.0],
+                    [18.5, 9.0],
+                ]),
+            ],
+            Self::Star => {
+                let points: Vec<Pos2> = (0..10)
+                    .map(|i| {
+                        let radius = if i % 2 == 0 { 9.5 } else { 4.0 };
+                        let angle = (i as f32 * 36.0 - 90.0).to_radians();
+                        pos2(12.0, 12.5) + radius * epaint::Vec2::angled(angle)
+                    })
+                    .map(|pos| p(pos.x, pos.y))
+                    .collect();
+                vec![PathShape::closed_line(points, stroke.clone()).into()]
+            }
+        };
+        Shape::Vec(shapes)
+    }
+}
+
+/// Show one of the [`BuiltinIcon`]s.
+///
+/// By default the icon is as tall as the body text,
+/// and uses the text color of the [`crate::style::WidgetVisuals`],
+/// so it looks right next to a label or inside a button,
+/// and follows the hover/click state if you give it a [`Sense`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.horizontal(|ui| {
+///     ui.add(egui::Icon::new(egui::BuiltinIcon::Warning).color(egui::Color32::YELLOW));
+///     ui.label("Careful!");
+/// });
+///
+/// if ui.add(egui::Icon::new(egui::BuiltinIcon::Close).size(24.0).sense(egui::Sense::click())).clicked() {
+///     // …
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+#[derive(Clone, Copy, Debug)]
+pub struct Icon {
+    icon: BuiltinIcon,
+
+    /// Uses the height of [`TextStyle::Body`] if `None`.
+    size: Option<f32>,
+
+    color: Option<Color32>,
+    sense: Sense,
+}
+
+impl Icon {
+    pub fn new(icon: BuiltinIcon) -> Self {
+        Self {
+            icon,
+            size: None,
+            color: None,
+            sense: Sense::hover(),
+        }
+    }
+
+    /// Look up the icon by its [`BuiltinIcon::name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        BuiltinIcon::from_name(name).map(Self::new)
+    }
+
+    /// The width and height of the icon.
+    ///
+    /// By default, this is the height of [`TextStyle::Body`].
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Override the color, which otherwise comes from the [`crate::style::WidgetVisuals`].
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// By default, the icon only senses hover.
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+
+    /// Paint the icon in the given rectangle.
+    pub fn paint_at(&self, ui: &Ui, rect: Rect, response: &Response) {
+        if ui.is_rect_visible(rect) {
+            let color = self
+                .color
+                .unwrap_or_else(|| ui.style().interact(response).text_color());
+            ui.painter().add(self.icon.to_shape(rect, color));
+        }
+    }
+}
+
+impl From<BuiltinIcon> for Icon {
+    #[inline]
+    fn from(icon: BuiltinIcon) -> Self {
+        Self::new(icon)
+    }
+}
+
+impl Widget for Icon {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = self
+            .size
+            .unwrap_or_else(|| ui.text_style_height(&TextStyle::Body));
+        let (rect, response) = ui.allocate_exact_size(vec2(size, size), self.sense);
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Image, ui.is_enabled(), self.icon.name())
+        });
+        self.paint_at(ui, rect, &response);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_names() {
+        for icon in BuiltinIcon::ALL {
+            assert_eq!(BuiltinIcon::from_name(icon.name()), Some(icon));
+
+            let rect = Rect::from_min_max(pos2(10.0, 10.0), pos2(58.0, 34.0));
+            let bounds = icon.to_shape(rect, Color32::WHITE).visual_bounding_rect();
+            assert!(
+                rect.expand(1.0).contains_rect(bounds),
+                "{icon:?} is outside of {rect:?}: {bounds:?}"
+            );
+        }
+        assert_eq!(BuiltinIcon::from_name("nonexistent"), None);
+    }
+}
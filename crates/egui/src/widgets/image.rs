@@ -2,7 +2,7 @@ use std::{borrow::Cow, slice::Iter, sync::Arc, time::Duration};
 
 use emath::{Align, Float as _, GuiRounding as _, NumExt as _, Rot2};
 use epaint::{
-    RectShape,
+    NineSlice, RectShape,
     text::{LayoutJob, TextFormat, TextWrapping},
 };
 
@@ -56,6 +56,8 @@ pub struct Image<'a> {
     size: ImageSize,
     pub(crate) show_loading_spinner: Option<bool>,
     pub(crate) alt_text: Option<String>,
+    animation_paused: bool,
+    placeholder: Option<Box<ImageSource<'a>>>,
 }
 
 impl<'a> Image<'a> {
@@ -82,6 +84,8 @@ impl<'a> Image<'a> {
                 size,
                 show_loading_spinner: None,
                 alt_text: None,
+                animation_paused: false,
+                placeholder: None,
             }
         }
 
@@ -241,6 +245,30 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Stretch the image so that its borders keep their size (nine-patch scaling).
+    ///
+    /// Useful for skinning frames, speech bubbles, and button backgrounds.
+    /// The insets are in the same unit as the size of the image (usually texels).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(
+    ///     egui::Image::new(egui::include_image!("../../assets/ferris.png"))
+    ///         .nine_slice(egui::NineSlice::new(16.0))
+    ///         .fit_to_exact_size(egui::vec2(300.0, 80.0)),
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// This turns off rotation and rounding of the image.
+    #[inline]
+    pub fn nine_slice(mut self, nine_slice: NineSlice) -> Self {
+        self.image_options.nine_slice = Some(nine_slice);
+        self.image_options.rotation = None; // incompatible with nine-slicing
+        self.image_options.corner_radius = CornerRadius::ZERO; // incompatible with nine-slicing
+        self
+    }
+
     /// Round the corners of the image.
     ///
     /// The default is no rounding ([`CornerRadius::ZERO`]).
@@ -285,6 +313,46 @@ impl<'a> Image<'a> {
         self.alt_text = Some(label.into());
         self
     }
+
+    /// Pause an animated image (GIF, APNG or WebP) on its current frame.
+    ///
+    /// When unpaused, it continues from that frame.
+    /// While paused, no repaints are requested for the animation.
+    ///
+    /// The playback state is shared by all images with the same URI.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut paused = false;
+    /// ui.checkbox(&mut paused, "Paused");
+    /// ui.add(egui::Image::new("https://example.com/cat.gif").animation_paused(paused));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn animation_paused(mut self, paused: bool) -> Self {
+        self.animation_paused = paused;
+        self
+    }
+
+    /// Show this image, stretched to fit, while the actual image is loading.
+    ///
+    /// This is usually a small thumbnail, or a `blurhash://` URI
+    /// if the `blurhash` feature of `egui_extras` is enabled.
+    /// If the placeholder isn't loaded either, a spinner is shown as usual.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(
+    ///     egui::Image::new("https://example.com/photo.jpg")
+    ///         .placeholder("blurhash://LEHV6nWB2yk8pyo0adR*.7kCMdnj"),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn placeholder(mut self, placeholder: impl Into<ImageSource<'a>>) -> Self {
+        self.placeholder = Some(Box::new(placeholder.into()));
+        self
+    }
 }
 
 impl<'a, T: Into<ImageSource<'a>>> From<T> for Image<'a> {
@@ -337,14 +405,14 @@ impl<'a> Image<'a> {
     pub fn source(&'a self, ctx: &Context) -> ImageSource<'a> {
         match &self.source {
             ImageSource::Uri(uri) if is_animated_image_uri(uri) => {
-                let frame_uri =
-                    encode_animated_image_uri(uri, animated_image_frame_index(ctx, uri));
+                let frame_index = animated_image_frame_index(ctx, uri, self.animation_paused);
+                let frame_uri = encode_animated_image_uri(uri, frame_index);
                 ImageSource::Uri(Cow::Owned(frame_uri))
             }
 
             ImageSource::Bytes { uri, bytes } if are_animated_image_bytes(bytes) => {
-                let frame_uri =
-                    encode_animated_image_uri(uri, animated_image_frame_index(ctx, uri));
+                let frame_index = animated_image_frame_index(ctx, uri, self.animation_paused);
+                let frame_uri = encode_animated_image_uri(uri, frame_index);
                 ctx.include_bytes(uri.clone(), bytes.clone());
                 ImageSource::Uri(Cow::Owned(frame_uri))
             }
@@ -396,9 +464,25 @@ impl<'a> Image<'a> {
             },
         );
 
+        self.paint_load_result(ui, &texture, rect);
+    }
+
+    /// Like [`paint_texture_load_result`], but shows the [`Self::placeholder`] while loading.
+    fn paint_load_result(&self, ui: &Ui, tlr: &TextureLoadResult, rect: Rect) {
+        if let (Ok(TexturePoll::Pending { .. }), Some(placeholder)) = (tlr, &self.placeholder) {
+            if let Ok(TexturePoll::Ready { texture }) =
+                placeholder
+                    .clone()
+                    .load(ui.ctx(), self.texture_options, SizeHint::default())
+            {
+                paint_texture_at(ui.painter(), rect, &self.image_options, &texture);
+                return;
+            }
+        }
+
         paint_texture_load_result(
             ui,
-            &texture,
+            tlr,
             rect,
             self.show_loading_spinner,
             &self.image_options,
@@ -420,14 +504,7 @@ impl Widget for Image<'_> {
             info
         });
         if ui.is_rect_visible(rect) {
-            paint_texture_load_result(
-                ui,
-                &tlr,
-                rect,
-                self.show_loading_spinner,
-                &self.image_options,
-                self.alt_text.as_deref(),
-            );
+            self.paint_load_result(ui, &tlr, rect);
         }
         texture_load_result_response(&self.source(ui.ctx()), &tlr, response)
     }
@@ -834,6 +911,11 @@ pub struct ImageOptions {
     /// Due to limitations in the current implementation,
     /// this will turn off any rotation of the image.
     pub corner_radius: CornerRadius,
+
+    /// Stretch the image so that its borders keep their size, see [`Image::nine_slice`].
+    ///
+    /// If set, [`Self::rotation`] and [`Self::corner_radius`] are ignored.
+    pub nine_slice: Option<NineSlice>,
 }
 
 impl Default for ImageOptions {
@@ -844,6 +926,7 @@ impl Default for ImageOptions {
             tint: Color32::WHITE,
             rotation: None,
             corner_radius: CornerRadius::ZERO,
+            nine_slice: None,
         }
     }
 }
@@ -862,6 +945,17 @@ pub fn paint_texture_at(
         ));
     }
 
+    if let Some(nine_slice) = options.nine_slice {
+        painter.add(Shape::mesh(nine_slice.mesh(
+            texture.id,
+            rect,
+            options.uv,
+            texture.size,
+            options.tint,
+        )));
+        return;
+    }
+
     match options.rotation {
         Some((rot, origin)) => {
             // TODO(emilk): implement this using `PathShape` (add texture support to it).
@@ -890,8 +984,26 @@ pub fn paint_texture_at(
 pub struct FrameDurations(Arc<Vec<Duration>>);
 
 impl FrameDurations {
+    /// Like browsers do, frames with a delay of 10 ms or less are shown for 100 ms,
+    /// since many GIFs rely on this.
+    pub const MIN_DELAY: Duration = Duration::from_millis(10);
+
+    /// Shown instead of delays shorter than or equal to [`Self::MIN_DELAY`].
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
     pub fn new(durations: Vec<Duration>) -> Self {
-        Self(Arc::new(durations))
+        Self(Arc::new(
+            durations
+                .into_iter()
+                .map(|duration| {
+                    if duration <= Self::MIN_DELAY {
+                        Self::DEFAULT_DELAY
+                    } else {
+                        duration
+                    }
+                })
+                .collect(),
+        ))
     }
 
     pub fn all(&self) -> Iter<'_, Duration> {
@@ -899,6 +1011,33 @@ impl FrameDurations {
     }
 }
 
+/// Where in its animation an animated image is.
+///
+/// Stored per URI, so that animations start from the first frame when first shown.
+#[derive(Clone, Copy, Debug)]
+struct AnimationPlayback {
+    /// When the animation was at its first frame, in [`crate::InputState::time`].
+    start_time: f64,
+
+    /// When the animation was paused, if it is.
+    paused_at: Option<f64>,
+}
+
+impl AnimationPlayback {
+    /// Seconds since the start of the animation, not counting time spent paused.
+    fn time(&mut self, now: f64, paused: bool) -> f64 {
+        match (self.paused_at, paused) {
+            (None, true) => self.paused_at = Some(now),
+            (Some(paused_at), false) => {
+                self.start_time += now - paused_at;
+                self.paused_at = None;
+            }
+            _ => {}
+        }
+        self.paused_at.unwrap_or(now) - self.start_time
+    }
+}
+
 /// Animated image uris contain the uri & the frame that will be displayed
 fn encode_animated_image_uri(uri: &str, frame_index: usize) -> String {
     format!("{uri}#{frame_index}")
@@ -917,26 +1056,37 @@ pub fn decode_animated_image_uri(uri: &str) -> Result<(&str, usize), String> {
     Ok((uri, index))
 }
 
-/// Calculates at which frame the animated image is
-fn animated_image_frame_index(ctx: &Context, uri: &str) -> usize {
-    let now = ctx.input(|input| Duration::from_secs_f64(input.time));
-
+/// Calculates at which frame the animated image is,
+/// and requests a repaint for when the next frame is due.
+fn animated_image_frame_index(ctx: &Context, uri: &str, paused: bool) -> usize {
     let durations: Option<FrameDurations> = ctx.data(|data| data.get_temp(Id::new(uri)));
+    let Some(durations) = durations else {
+        return 0; // Not loaded yet
+    };
 
-    if let Some(durations) = durations {
-        let frames: Duration = durations.all().sum();
-        let pos_ms = now.as_millis() % frames.as_millis().max(1);
-
-        let mut cumulative_ms = 0;
-
-        for (index, duration) in durations.all().enumerate() {
-            cumulative_ms += duration.as_millis();
+    let total: f64 = durations.all().map(Duration::as_secs_f64).sum();
+    if total <= 0.0 {
+        return 0;
+    }
 
-            if pos_ms < cumulative_ms {
-                let ms_until_next_frame = cumulative_ms - pos_ms;
-                ctx.request_repaint_after(Duration::from_millis(ms_until_next_frame as u64));
-                return index;
+    let now = ctx.input(|input| input.time);
+    let time = ctx.data_mut(|data| {
+        data.get_temp_mut_or_insert_with(Id::new(uri).with("playback"), || AnimationPlayback {
+            start_time: now,
+            paused_at: None,
+        })
+        .time(now, paused)
+    });
+    let time = time.rem_euclid(total);
+
+    let mut cumulative = 0.0;
+    for (index, duration) in durations.all().enumerate() {
+        cumulative += duration.as_secs_f64();
+        if time < cumulative {
+            if !paused {
+                ctx.request_repaint_after_secs((cumulative - time) as f32);
             }
+            return index;
         }
     }
 
@@ -963,10 +1113,75 @@ pub fn has_webp_header(bytes: &[u8]) -> bool {
     bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP"
 }
 
+/// Checks if uri is an animated png file
+fn is_apng_uri(uri: &str) -> bool {
+    uri.ends_with(".apng") || uri.contains(".apng#")
+}
+
+/// Checks if bytes are an animated png.
+///
+/// Unlike a normal png, these have an `acTL` chunk before the image data.
+pub fn has_apng_header(bytes: &[u8]) -> bool {
+    let Some(mut chunks) = bytes.strip_prefix(b"\x89PNG\r\n\x1a\n") else {
+        return false;
+    };
+    while let [l0, l1, l2, l3, t0, t1, t2, t3, rest @ ..] = chunks {
+        match &[*t0, *t1, *t2, *t3] {
+            b"acTL" => return true,
+            b"IDAT" => return false,
+            _ => {}
+        }
+        let len = u32::from_be_bytes([*l0, *l1, *l2, *l3]) as usize;
+        // Skip the chunk data and its checksum:
+        chunks = rest.get(len.saturating_add(4)..).unwrap_or_default();
+    }
+    false
+}
+
 fn is_animated_image_uri(uri: &str) -> bool {
-    is_gif_uri(uri) || is_webp_uri(uri)
+    is_gif_uri(uri) || is_webp_uri(uri) || is_apng_uri(uri)
 }
 
 fn are_animated_image_bytes(bytes: &[u8]) -> bool {
-    has_gif_magic_header(bytes) || has_webp_header(bytes)
+    has_gif_magic_header(bytes) || has_webp_header(bytes) || has_apng_header(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_apng_header() {
+        fn png(chunks: &[&[u8; 4]]) -> Vec<u8> {
+            let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+            for chunk_type in chunks {
+                bytes.extend_from_slice(&2_u32.to_be_bytes());
+                bytes.extend_from_slice(*chunk_type);
+                bytes.extend_from_slice(&[0; 2 + 4]); // data and checksum
+            }
+            bytes
+        }
+
+        assert!(has_apng_header(&png(&[b"IHDR", b"acTL", b"IDAT"])));
+        assert!(!has_apng_header(&png(&[b"IHDR", b"IDAT", b"acTL"])));
+        assert!(!has_apng_header(&png(&[b"IHDR"])));
+        assert!(!has_apng_header(b"GIF89a"));
+    }
+
+    #[test]
+    fn test_animation_playback() {
+        let mut playback = AnimationPlayback {
+            start_time: 10.0,
+            paused_at: None,
+        };
+        assert_eq!(playback.time(11.0, false), 1.0);
+        assert_eq!(playback.time(12.0, true), 2.0, "Paused");
+        assert_eq!(playback.time(15.0, true), 2.0, "Stays paused");
+        assert_eq!(
+            playback.time(15.0, false),
+            2.0,
+            "Continues where it was paused"
+        );
+        assert_eq!(playback.time(16.0, false), 3.0);
+    }
 }
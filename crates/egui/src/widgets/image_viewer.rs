@@ -0,0 +1,416 @@
+use crate::{
+    Align, Color32, ColorImage, Context, CornerRadius, Id, Image, Layout, Pos2, Rangef, Rect,
+    Response, Sense, Shape, Stroke, TextStyle, TextureFilter, TextureOptions, Ui, UiBuilder, Vec2,
+    WidgetInfo, WidgetType, epaint::RectShape, load::TexturePoll, pos2, vec2,
+};
+
+/// The persisted state of an [`ImageViewer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ImageViewerState {
+    /// Physical pixels per image pixel, so `1.0` shows the image at its actual size.
+    ///
+    /// `None` fits the whole image into the view, even as the view changes size.
+    pub zoom: Option<f32>,
+
+    /// The image pixel coordinate at the center of the view.
+    ///
+    /// Ignored while fitting the image to the view.
+    pub center: Pos2,
+}
+
+impl ImageViewerState {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Fit the whole image into the view.
+    pub fn zoom_to_fit(&mut self) {
+        self.zoom = None;
+    }
+
+    /// Show one image pixel per physical pixel, keeping the center of the view in place.
+    pub fn zoom_to_actual_size(&mut self, image_size: Vec2) {
+        if self.zoom.is_none() {
+            self.center = (image_size / 2.0).to_pos2();
+        }
+        self.zoom = Some(1.0);
+    }
+}
+
+/// Shows an image that can be zoomed and panned, for inspecting it pixel by pixel.
+///
+/// * Scrolling (or pinching) zooms around the pointer
+/// * Dragging pans
+/// * Double-clicking fits the image to the view
+/// * The "Fit" and "1:1" buttons in the corner fit the image, or show it at its actual size
+/// * When zoomed in far enough, a grid shows the pixel boundaries
+/// * Hovering shows the coordinates of the pixel, and its color if you provide the [`Self::pixels`]
+///
+/// Pixels are shown sharp when zoomed in, and smoothed when zoomed out.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = egui::ImageViewer::new("viewer", egui::include_image!("../../assets/ferris.png"))
+///     .desired_size(egui::vec2(300.0, 200.0))
+///     .show(ui);
+/// if let Some([x, y]) = response.hovered_pixel {
+///     ui.label(format!("Hovering pixel {x}, {y}"));
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct ImageViewer<'a> {
+    id_salt: Id,
+    image: Image<'a>,
+    pixels: Option<&'a ColorImage>,
+    desired_size: Option<Vec2>,
+    zoom_range: Rangef,
+    zoom_speed: f32,
+    pixel_grid: bool,
+    show_controls: bool,
+}
+
+impl<'a> ImageViewer<'a> {
+    /// Zoomed in at least this far (in points per image pixel), the pixel grid is shown.
+    pub const PIXEL_GRID_MIN_ZOOM: f32 = 8.0;
+
+    /// The `id_salt` must be locally unique, as the zoom and pan are stored in [`crate::Memory`].
+    pub fn new(id_salt: impl std::hash::Hash, image: impl Into<Image<'a>>) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            image: image.into(),
+            pixels: None,
+            desired_size: None,
+            zoom_range: Rangef::new(1.0 / 32.0, 128.0),
+            zoom_speed: 0.002,
+            pixel_grid: true,
+            show_controls: true,
+        }
+    }
+
+    /// The pixels of the image, to show the color of the hovered pixel.
+    ///
+    /// These should be the same size as the image.
+    #[inline]
+    pub fn pixels(mut self, pixels: &'a ColorImage) -> Self {
+        self.pixels = Some(pixels);
+        self
+    }
+
+    /// The size of the view.
+    ///
+    /// Default: all available width and height.
+    #[inline]
+    pub fn desired_size(mut self, desired_size: impl Into<Vec2>) -> Self {
+        self.desired_size = Some(desired_size.into());
+        self
+    }
+
+    /// The allowed zoom, in physical pixels per image pixel.
+    ///
+    /// Default: `1/32..=128`.
+    #[inline]
+    pub fn zoom_range(mut self, zoom_range: impl Into<Rangef>) -> Self {
+        self.zoom_range = zoom_range.into();
+        self
+    }
+
+    /// How fast scrolling zooms.
+    ///
+    /// Default: `0.002`.
+    #[inline]
+    pub fn zoom_speed(mut self, zoom_speed: f32) -> Self {
+        self.zoom_speed = zoom_speed;
+        self
+    }
+
+    /// Show the pixel boundaries when zoomed in at least [`Self::PIXEL_GRID_MIN_ZOOM`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn pixel_grid(mut self, pixel_grid: bool) -> Self {
+        self.pixel_grid = pixel_grid;
+        self
+    }
+
+    /// Show the zoom, and the "Fit" and "1:1" buttons, in the top right corner.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_controls(mut self, show_controls: bool) -> Self {
+        self.show_controls = show_controls;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> ImageViewerResponse {
+        let Self {
+            id_salt,
+            image,
+            pixels,
+            desired_size,
+            zoom_range,
+            zoom_speed,
+            pixel_grid,
+            show_controls,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state = ImageViewerState::load(ui.ctx(), id).unwrap_or_default();
+
+        let size = desired_size.unwrap_or_else(|| ui.available_size());
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        response.widget_info(|| {
+            let mut info = WidgetInfo::new(WidgetType::Image);
+            info.label = image.alt_text.clone();
+            info
+        });
+
+        // Painted before the controls, which we add next:
+        let background = ui.painter().add(Shape::Noop);
+
+        let image = image.texture_options(TextureOptions {
+            magnification: TextureFilter::Nearest,
+            ..TextureOptions::LINEAR
+        });
+        let texture = match image.load_for_size(ui.ctx(), rect.size()) {
+            Ok(TexturePoll::Ready { texture }) => texture,
+            tlr => {
+                super::image::paint_texture_load_result(
+                    ui,
+                    &tlr,
+                    rect,
+                    None,
+                    image.image_options(),
+                    image.alt_text.as_deref(),
+                );
+                return ImageViewerResponse {
+                    response,
+                    state,
+                    hovered_pixel: None,
+                    hovered_color: None,
+                };
+            }
+        };
+        let image_size = texture.size;
+        let pixels_per_point = ui.pixels_per_point();
+        let fit_zoom = pixels_per_point * (rect.size() / image_size).min_elem();
+
+        if show_controls {
+            let zoom = state.zoom.unwrap_or(fit_zoom);
+            let mut controls = ui.new_child(
+                UiBuilder::new()
+                    .max_rect(rect.shrink(ui.spacing().item_spacing.x))
+                    .layout(Layout::right_to_left(Align::Min)),
+            );
+            if controls.small_button("1:1").clicked() {
+                state.zoom_to_actual_size(image_size);
+            }
+            if controls.small_button("Fit").clicked() {
+                state.zoom_to_fit();
+            }
+            controls.label(format!("{:.0}%", 100.0 * zoom));
+        }
+
+        if response.double_clicked() {
+            state.zoom_to_fit();
+        }
+
+        // From image pixels to points:
+        let scale = |state: &ImageViewerState| state.zoom.unwrap_or(fit_zoom) / pixels_per_point;
+        let center = |state: &ImageViewerState| {
+            if state.zoom.is_some() {
+                state.center
+            } else {
+                (image_size / 2.0).to_pos2()
+            }
+        };
+
+        if response.contains_pointer() {
+            // Capture the scroll so that a parent `ScrollArea` doesn't scroll:
+            let (zoom_delta, scroll_delta) = ui.input_mut(|i| {
+                let scroll_delta = std::mem::take(&mut i.smooth_scroll_delta);
+                (i.zoom_delta(), scroll_delta)
+            });
+            let factor = zoom_delta * (zoom_speed * scroll_delta.y).exp();
+            if let (Some(pointer), true) = (response.hover_pos(), factor != 1.0) {
+                // Keep the pixel under the pointer in place:
+                let offset = pointer - rect.center();
+                let under_pointer = center(&state) + offset / scale(&state);
+                let zoom = zoom_range.clamp(state.zoom.unwrap_or(fit_zoom) * factor);
+                state.zoom = Some(zoom);
+                state.center = under_pointer - offset / scale(&state);
+            }
+        }
+
+        let drag_delta = response.drag_delta();
+        if drag_delta != Vec2::ZERO {
+            state.center = center(&state) - drag_delta / scale(&state);
+            state.zoom = Some(state.zoom.unwrap_or(fit_zoom));
+        }
+
+        // Don't lose the image:
+        state.center = center(&state).clamp(Pos2::ZERO, image_size.to_pos2());
+
+        let scale = scale(&state);
+        let center = center(&state);
+        let to_screen = |pixel: Pos2| rect.center() + (pixel - center) * scale;
+        let from_screen = |pos: Pos2| center + (pos - rect.center()) / scale;
+        let image_rect = Rect::from_min_max(to_screen(Pos2::ZERO), to_screen(image_size.to_pos2()));
+
+        let hovered_pixel = response
+            .hover_pos()
+            .map(from_screen)
+            .filter(|pixel| Rect::from_min_size(Pos2::ZERO, image_size).contains(*pixel))
+            .map(|pixel| [pixel.x as usize, pixel.y as usize]);
+        let hovered_color = hovered_pixel.and_then(|pixel| {
+            let pixels = pixels?;
+            (pixel[0] < pixels.width() && pixel[1] < pixels.height()).then(|| pixels[pixel.into()])
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let options = image.image_options();
+            let mut shapes = vec![
+                RectShape::filled(rect, 0, visuals.extreme_bg_color).into(),
+                RectShape::filled(image_rect, 0, options.tint)
+                    .with_texture(texture.id, options.uv)
+                    .into(),
+            ];
+
+            let grid_stroke = Stroke::new(
+                1.0 / pixels_per_point,
+                visuals.widgets.noninteractive.bg_stroke.color,
+            );
+            if pixel_grid && Self::PIXEL_GRID_MIN_ZOOM <= scale {
+                let visible = from_screen(rect.min).max(Pos2::ZERO).floor()
+                    ..=from_screen(rect.max).min(image_size.to_pos2()).ceil();
+                let (min, max) = (*visible.start(), *visible.end());
+                for x in (min.x as usize)..=(max.x as usize) {
+                    let x = to_screen(pos2(x as f32, 0.0)).x;
+                    shapes.push(Shape::vline(x, image_rect.y_range(), grid_stroke));
+                }
+                for y in (min.y as usize)..=(max.y as usize) {
+                    let y = to_screen(pos2(0.0, y as f32)).y;
+                    shapes.push(Shape::hline(image_rect.x_range(), y, grid_stroke));
+                }
+            }
+
+            if let Some([x, y]) = hovered_pixel {
+                let pixel_rect = Rect::from_min_max(
+                    to_screen(pos2(x as f32, y as f32)),
+                    to_screen(pos2(x as f32 + 1.0, y as f32 + 1.0)),
+                );
+                if Self::PIXEL_GRID_MIN_ZOOM <= scale {
+                    shapes.push(
+                        RectShape::stroke(
+                            pixel_rect,
+                            0,
+                            visuals.selection.stroke,
+                            crate::StrokeKind::Outside,
+                        )
+                        .into(),
+                    );
+                }
+                shapes.push(readout_shape(ui, rect, [x, y], hovered_color));
+            }
+
+            ui.painter()
+                .with_clip_rect(rect)
+                .set(background, Shape::Vec(shapes));
+        }
+
+        state.store(ui.ctx(), id);
+
+        ImageViewerResponse {
+            response,
+            state,
+            hovered_pixel,
+            hovered_color,
+        }
+    }
+}
+
+impl crate::Widget for ImageViewer<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// The coordinates and color of the hovered pixel, in the bottom left corner of the view.
+fn readout_shape(ui: &Ui, rect: Rect, [x, y]: [usize; 2], color: Option<Color32>) -> Shape {
+    let visuals = ui.visuals();
+    let mut text = format!("{x}, {y}");
+    if let Some(color) = color {
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        text += &format!("  #{r:02X}{g:02X}{b:02X}{a:02X}");
+    }
+    let font_id = TextStyle::Monospace.resolve(ui.style());
+    let galley = ui
+        .painter()
+        .layout_no_wrap(text, font_id, visuals.text_color());
+
+    let margin = ui.spacing().item_spacing.x;
+    let swatch_size = galley.size().y;
+    // The swatch and the space after it:
+    let swatch_width = if color.is_some() {
+        swatch_size + margin
+    } else {
+        0.0
+    };
+    let size = galley.size() + vec2(swatch_width, 0.0) + Vec2::splat(margin);
+    let background = Rect::from_min_size(
+        pos2(rect.left() + margin, rect.bottom() - margin - size.y),
+        size,
+    );
+    let text_pos = background.min + Vec2::splat(margin / 2.0);
+
+    let mut shapes = vec![
+        RectShape::filled(
+            background,
+            visuals.widgets.noninteractive.corner_radius,
+            visuals.window_fill.gamma_multiply(0.9),
+        )
+        .into(),
+    ];
+    if let Some(color) = color {
+        let swatch = Rect::from_min_size(text_pos, Vec2::splat(swatch_size));
+        shapes.push(RectShape::filled(swatch, CornerRadius::same(2), color).into());
+        shapes.push(
+            RectShape::stroke(
+                swatch,
+                CornerRadius::same(2),
+                visuals.widgets.noninteractive.bg_stroke,
+                crate::StrokeKind::Inside,
+            )
+            .into(),
+        );
+    }
+    shapes.push(Shape::galley(
+        text_pos + vec2(swatch_width, 0.0),
+        galley,
+        visuals.text_color(),
+    ));
+    Shape::Vec(shapes)
+}
+
+/// Returned by [`ImageViewer::show`].
+#[derive(Clone, Debug)]
+pub struct ImageViewerResponse {
+    /// The response of the view.
+    pub response: Response,
+
+    /// The zoom and pan at the end of the frame.
+    pub state: ImageViewerState,
+
+    /// The image pixel under the pointer, as `[x, y]`.
+    pub hovered_pixel: Option<[usize; 2]>,
+
+    /// The color of [`Self::hovered_pixel`], if [`ImageViewer::pixels`] was set.
+    pub hovered_color: Option<Color32>,
+}
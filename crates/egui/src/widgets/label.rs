@@ -137,6 +137,7 @@ impl Label {
 
 impl Label {
     /// Do layout and position the galley in the ui, without painting it or adding widget info.
+    #[track_caller]
     pub fn layout_in_ui(self, ui: &mut Ui) -> (Pos2, Arc<Galley>, Response) {
         let selectable = self
             .selectable
@@ -266,6 +267,7 @@ impl Label {
 }
 
 impl Widget for Label {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         // Interactive = the uses asked to sense interaction.
         // We DON'T want to have the color respond just because the text is selectable;
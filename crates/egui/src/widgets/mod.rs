@@ -11,16 +11,20 @@ mod checkbox;
 pub mod color_picker;
 pub(crate) mod drag_value;
 mod hyperlink;
+mod icon;
 mod image;
 mod image_button;
+mod image_viewer;
 mod label;
 mod progress_bar;
 mod radio_button;
 mod selected_label;
 mod separator;
+pub(crate) mod skeleton;
 mod slider;
 mod spinner;
 pub mod text_edit;
+mod view_3d;
 
 #[expect(deprecated)]
 pub use self::selected_label::SelectableLabel;
@@ -29,18 +33,23 @@ pub use self::{
     checkbox::Checkbox,
     drag_value::DragValue,
     hyperlink::{Hyperlink, Link},
+    icon::{BuiltinIcon, Icon},
     image::{
         FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
-        decode_animated_image_uri, has_gif_magic_header, has_webp_header, paint_texture_at,
+        decode_animated_image_uri, has_apng_header, has_gif_magic_header, has_webp_header,
+        paint_texture_at,
     },
     image_button::ImageButton,
+    image_viewer::{ImageViewer, ImageViewerResponse, ImageViewerState},
     label::Label,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
     separator::Separator,
+    skeleton::Skeleton,
     slider::{Slider, SliderClamping, SliderOrientation},
     spinner::Spinner,
-    text_edit::{TextBuffer, TextEdit},
+    text_edit::{ClipboardHistory, TextBuffer, TextEdit},
+    view_3d::{CameraEvent, View3D, View3DInput},
 };
 
 // ----------------------------------------------------------------------------
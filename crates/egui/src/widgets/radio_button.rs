@@ -38,6 +38,7 @@ impl<'a> RadioButton<'a> {
 }
 
 impl Widget for RadioButton<'_> {
+    #[track_caller]
     fn ui(self, ui: &mut Ui) -> Response {
         let Self { checked, mut atoms } = self;
 
@@ -56,7 +57,7 @@ impl Widget for RadioButton<'_> {
         let text = atoms.text().map(String::from);
 
         let mut prepared = AtomLayout::new(atoms)
-            .sense(Sense::click())
+            .sense(ui.edit_sense(Sense::click()))
             .min_size(min_size)
             .allocate(ui);
 
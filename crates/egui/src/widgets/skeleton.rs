@@ -0,0 +1,245 @@
+use epaint::{
+    Brush, Color32, CornerRadius, Gradient, Pos2, Rect, RectShape, Shape, Vec2, emath::lerp, pos2,
+    vec2,
+};
+
+use crate::{Response, Sense, TextStyle, Ui, Widget, WidgetInfo, WidgetType};
+
+/// A placeholder for content that is still loading, with a shimmer moving over it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.horizontal(|ui| {
+///     ui.add(egui::Skeleton::circle(32.0)); // avatar
+///     ui.vertical(|ui| {
+///         ui.add(egui::Skeleton::text(2));
+///     });
+/// });
+/// ui.add(egui::Skeleton::rect(egui::vec2(200.0, 100.0))); // image
+/// # });
+/// ```
+///
+/// See also [`Ui::skeleton_while`], which shows placeholders in the shape of the actual contents,
+/// and [`crate::Spinner`].
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+#[derive(Clone, Copy, Debug)]
+pub struct Skeleton {
+    kind: SkeletonKind,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum SkeletonKind {
+    Text { lines: usize },
+    Circle { diameter: f32 },
+    Rect { size: Vec2 },
+}
+
+impl Skeleton {
+    /// Lines of [`TextStyle::Body`] text, filling the available width.
+    ///
+    /// If there is more than one line, the last line is shorter, like the end of a paragraph.
+    pub fn text(lines: usize) -> Self {
+        Self {
+            kind: SkeletonKind::Text { lines },
+        }
+    }
+
+    /// A circle, e.g. for an avatar.
+    pub fn circle(diameter: f32) -> Self {
+        Self {
+            kind: SkeletonKind::Circle { diameter },
+        }
+    }
+
+    /// A rectangle, e.g. for an image.
+    pub fn rect(size: impl Into<Vec2>) -> Self {
+        Self {
+            kind: SkeletonKind::Rect { size: size.into() },
+        }
+    }
+
+    /// Paint the skeleton in the given rectangle.
+    pub fn paint_at(&self, ui: &Ui, rect: Rect) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+        let shimmer = Shimmer::new(ui);
+        let shape = match self.kind {
+            SkeletonKind::Text { lines } => {
+                let row_height = rect.height() / lines.max(1) as f32;
+                Shape::Vec(
+                    (0..lines)
+                        .map(|i| {
+                            let width = if 1 < lines && i + 1 == lines {
+                                0.6 * rect.width()
+                            } else {
+                                rect.width()
+                            };
+                            let row = Rect::from_min_size(
+                                rect.min + vec2(0.0, i as f32 * row_height),
+                                vec2(width, row_height),
+                            );
+                            shimmer.text_row(row)
+                        })
+                        .collect(),
+                )
+            }
+            SkeletonKind::Circle { .. } => {
+                shimmer.block(rect, CornerRadius::from(rect.height() / 2.0))
+            }
+            SkeletonKind::Rect { .. } => {
+                shimmer.block(rect, ui.visuals().widgets.noninteractive.corner_radius)
+            }
+        };
+        ui.painter().add(shape);
+    }
+}
+
+impl Widget for Skeleton {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = match self.kind {
+            SkeletonKind::Text { lines } => vec2(
+                ui.available_width(),
+                lines as f32 * ui.text_style_height(&TextStyle::Body),
+            ),
+            SkeletonKind::Circle { diameter } => Vec2::splat(diameter),
+            SkeletonKind::Rect { size } => size,
+        };
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::ProgressIndicator, ui.is_enabled(), "Loading")
+        });
+        self.paint_at(ui, rect);
+        response
+    }
+}
+
+/// Paints placeholder blocks with a shimmer that moves across the screen,
+/// so that all blocks shimmer together.
+pub(crate) struct Shimmer {
+    base: Color32,
+    highlight: Color32,
+
+    /// Where the highlight is, in points.
+    center_x: f32,
+
+    /// How far the highlight fades out to each side, in points.
+    half_width: f32,
+}
+
+impl Shimmer {
+    /// How long it takes the shimmer to cross the screen, in seconds.
+    const PERIOD: f64 = 1.5;
+
+    /// Also requests a repaint, as the shimmer is animated.
+    pub fn new(ui: &Ui) -> Self {
+        ui.ctx().request_repaint();
+
+        let visuals = ui.visuals();
+        let screen_rect = ui.ctx().screen_rect();
+        let half_width = (0.15 * screen_rect.width()).max(60.0);
+        let phase = (ui.input(|i| i.time) / Self::PERIOD).fract() as f32;
+        Self {
+            base: visuals.widgets.inactive.weak_bg_fill,
+            highlight: visuals.widgets.hovered.weak_bg_fill,
+            center_x: lerp(
+                (screen_rect.left() - half_width)..=(screen_rect.right() + half_width),
+                phase,
+            ),
+            half_width,
+        }
+    }
+
+    pub fn block(&self, rect: Rect, corner_radius: impl Into<CornerRadius>) -> Shape {
+        let width = rect.width().max(f32::EPSILON);
+        let relative = |x: f32| (x - rect.min.x) / width;
+        let gradient = Gradient::from_stops([
+            (relative(self.center_x - self.half_width), self.base),
+            (relative(self.center_x), self.highlight),
+            (relative(self.center_x + self.half_width), self.base),
+        ]);
+        RectShape::filled(rect, corner_radius, Color32::WHITE)
+            .with_brush(Brush::LinearGradient {
+                start: Pos2::ZERO,
+                end: pos2(1.0, 0.0),
+                gradient,
+            })
+            .into()
+    }
+
+    /// A rounded block a bit lower than the row of text.
+    pub fn text_row(&self, row: Rect) -> Shape {
+        let height = 0.7 * row.height();
+        let rect = Rect::from_center_size(row.center(), vec2(row.width(), height));
+        self.block(rect, CornerRadius::from(height / 2.0))
+    }
+
+    /// A placeholder in the shape of what `shape` covers, see [`Ui::skeleton_while`].
+    ///
+    /// Text is replaced by one block per row, and filled shapes by blocks.
+    /// Strokes and lines are removed.
+    pub fn placeholder(&self, shape: &Shape) -> Shape {
+        match shape {
+            Shape::Vec(shapes) => {
+                Shape::Vec(shapes.iter().map(|shape| self.placeholder(shape)).collect())
+            }
+            Shape::Text(text) => Shape::Vec(
+                text.galley
+                    .rows
+                    .iter()
+                    .map(|row| row.rect().translate(text.pos.to_vec2()))
+                    .filter(|rect| 1.0 <= rect.width())
+                    .map(|rect| self.text_row(rect))
+                    .collect(),
+            ),
+            Shape::Rect(rect) if rect.fill != Color32::TRANSPARENT || rect.brush.is_some() => {
+                self.block(rect.rect, rect.corner_radius)
+            }
+            Shape::Circle(circle) if circle.fill != Color32::TRANSPARENT => self.block(
+                Rect::from_center_size(circle.center, Vec2::splat(2.0 * circle.radius)),
+                CornerRadius::from(circle.radius),
+            ),
+            Shape::Mesh(mesh) => self.block(mesh.calc_bounds(), 0),
+            _ => Shape::Noop,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CentralPanel, Context, RawInput};
+
+    use super::*;
+
+    /// Count the texts and the shimmering blocks.
+    fn count(shape: &Shape, counts: &mut (usize, usize)) {
+        match shape {
+            Shape::Vec(shapes) => shapes.iter().for_each(|shape| count(shape, counts)),
+            Shape::Text(_) => counts.0 += 1,
+            Shape::Rect(rect) if rect.brush.is_some() => counts.1 += 1,
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn skeleton_while_replaces_text() {
+        let ctx = Context::default();
+        let run = |loading: bool| {
+            let output = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.skeleton_while(loading, |ui| {
+                        ui.label("First line\nSecond line");
+                    });
+                });
+            });
+            let mut counts = (0, 0);
+            for clipped in &output.shapes {
+                count(&clipped.shape, &mut counts);
+            }
+            counts
+        };
+
+        assert_eq!(run(false), (1, 0));
+        assert_eq!(run(true), (0, 2), "One block per row of text");
+    }
+}
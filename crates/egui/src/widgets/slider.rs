@@ -3,9 +3,9 @@
 use std::ops::RangeInclusive;
 
 use crate::{
-    Color32, DragValue, EventFilter, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef, Rect,
-    Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, emath,
-    epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
+    Color32, DragValue, EventFilter, Key, Label, MINUS_CHAR_STR, Modifiers, NumExt as _, Pos2,
+    Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo,
+    WidgetText, emath, epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
 };
 
 use super::drag_value::clamp_value_to_range;
@@ -86,6 +86,9 @@ pub enum SliderClamping {
 /// The slider consists of three parts: a slider, a value display, and an optional text.
 /// The user can click the value display to edit its value. It can be turned off with `.show_value(false)`.
 ///
+/// When focused, the arrow keys move the slider a step at a time, `PageUp`/`PageDown` a tenth of the slider,
+/// and `Home`/`End` to the start and end of the range.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # let mut my_f32: f32 = 0.0;
@@ -658,7 +661,7 @@ impl Slider<'_> {
             SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
             SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
         };
-        ui.allocate_response(desired_size, Sense::drag())
+        ui.allocate_response(desired_size, ui.edit_sense(Sense::drag()))
     }
 
     /// Just the slider, no text
@@ -714,6 +717,30 @@ impl Slider<'_> {
                 decrement += input.num_presses(dec_key);
                 increment += input.num_presses(inc_key);
             });
+
+            // Jump to either end, or a tenth of the slider at a time.
+            // The keys are consumed so that a surrounding `ScrollArea` doesn't scroll too.
+            let (home, end, page_up, page_down) = ui.input_mut(|input| {
+                (
+                    input.consume_key(Modifiers::NONE, Key::Home),
+                    input.consume_key(Modifiers::NONE, Key::End),
+                    input.consume_key(Modifiers::NONE, Key::PageUp),
+                    input.consume_key(Modifiers::NONE, Key::PageDown),
+                )
+            });
+            if home {
+                self.set_value(*self.range.start());
+            } else if end {
+                self.set_value(*self.range.end());
+            } else if page_up != page_down {
+                let page = if page_up { 0.1 } else { -0.1 };
+                let normalized = normalized_from_value(self.get_value(), self.range(), &self.spec);
+                self.set_value(value_from_normalized(
+                    (normalized + page).clamp(0.0, 1.0),
+                    self.range(),
+                    &self.spec,
+                ));
+            }
         }
 
         #[cfg(feature = "accesskit")]
@@ -897,6 +924,21 @@ impl Slider<'_> {
         limit / 2.5
     }
 
+    /// The value as shown to the user, with prefix and suffix, e.g. `"x: 42 m"`.
+    #[cfg(feature = "accesskit")]
+    fn value_text(&self, ui: &Ui, value: f64) -> String {
+        let max_decimals = self
+            .max_decimals
+            .unwrap_or(self.min_decimals + 3)
+            .at_least(self.min_decimals);
+        let decimals = self.min_decimals..=max_decimals;
+        let number = match &self.custom_formatter {
+            Some(custom_formatter) => custom_formatter(value, decimals),
+            None => ui.style().number_formatter.format(value, decimals),
+        };
+        format!("{}{number}{}", self.prefix, self.suffix)
+    }
+
     fn value_ui(&mut self, ui: &mut Ui, position_range: Rangef) -> Response {
         // If [`DragValue`] is controlled from the keyboard and `step` is defined, set speed to `step`
         let change = ui.input(|input| {
@@ -978,9 +1020,12 @@ impl Slider<'_> {
         }
         response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, self.text.text()));
 
+        #[cfg(feature = "accesskit")]
+        let value_text = self.value_text(ui, value);
         #[cfg(feature = "accesskit")]
         ui.ctx().accesskit_node_builder(response.id, |builder| {
             use accesskit::Action;
+            builder.set_value(value_text);
             builder.set_min_numeric_value(*self.range.start());
             builder.set_max_numeric_value(*self.range.end());
             if let Some(step) = self.step {
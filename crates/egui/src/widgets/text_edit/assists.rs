@@ -0,0 +1,252 @@
+//! Optional editing assists for code editing: bracket matching and auto-indentation.
+
+use epaint::text::cursor::CCursor;
+
+use crate::{TextBuffer, text_selection::CCursorRange};
+
+/// The opening and closing characters that are auto-closed and matched.
+const PAIRS: [(char, char); 5] = [('(', ')'), ('[', ']'), ('{', '}'), ('"', '"'), ('\'', '\'')];
+
+/// The brackets that are matched by [`matching_bracket`].
+///
+/// Quotes are not included, since you can't tell an opening quote from a closing one.
+const BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+fn closing_char(opening: char) -> Option<char> {
+    PAIRS
+        .iter()
+        .find(|(open, _)| *open == opening)
+        .map(|(_, close)| *close)
+}
+
+fn is_closing_char(c: char) -> bool {
+    PAIRS.iter().any(|(_, close)| *close == c)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn char_at(text: &str, char_index: usize) -> Option<char> {
+    text.chars().nth(char_index)
+}
+
+/// Insert typed text, auto-closing brackets and quotes.
+///
+/// * Typing an opening bracket or quote also inserts the closing one, with the cursor in between.
+/// * Typing an opening bracket or quote with a selection wraps the selection.
+/// * Typing a closing bracket or quote in front of the same character types over it.
+///
+/// Returns `None` if the text should be inserted as usual.
+pub(super) fn insert_with_auto_close(
+    text: &mut dyn TextBuffer,
+    cursor_range: &CCursorRange,
+    text_to_insert: &str,
+    char_limit: usize,
+) -> Option<CCursorRange> {
+    let mut chars = text_to_insert.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+
+    let [min, max] = cursor_range.sorted_cursors();
+    let prev_char = min
+        .index
+        .checked_sub(1)
+        .and_then(|i| char_at(text.as_str(), i));
+    let next_char = char_at(text.as_str(), max.index);
+
+    if cursor_range.is_empty() && is_closing_char(c) && next_char == Some(c) {
+        // Type over the closing character:
+        return Some(CCursorRange::one(CCursor::new(max.index + 1)));
+    }
+
+    let closing = closing_char(c)?;
+
+    if cursor_range.is_empty() {
+        let is_quote = c == closing;
+        if next_char.is_some_and(is_word_char) || (is_quote && prev_char.is_some_and(is_word_char))
+        {
+            // E.g. typing `(` in front of a word, or the `'` in `don't`.
+            return None;
+        }
+
+        let mut ccursor = min;
+        text.insert_text_at(&mut ccursor, &c.to_string(), char_limit);
+        let inside = ccursor;
+        text.insert_text_at(&mut ccursor, &closing.to_string(), char_limit);
+        Some(CCursorRange::one(inside))
+    } else {
+        let selected = cursor_range.slice_str(text.as_str()).to_owned();
+        let mut ccursor = text.delete_selected(cursor_range);
+        text.insert_text_at(&mut ccursor, &c.to_string(), char_limit);
+        let start = ccursor;
+        text.insert_text_at(&mut ccursor, &selected, char_limit);
+        let end = ccursor;
+        text.insert_text_at(&mut ccursor, &closing.to_string(), char_limit);
+        Some(CCursorRange::two(start, end))
+    }
+}
+
+/// If the cursor is between an empty pair of brackets or quotes, delete both.
+///
+/// Returns `None` if backspace should be handled as usual.
+pub(super) fn delete_empty_pair(
+    text: &mut dyn TextBuffer,
+    cursor_range: &CCursorRange,
+) -> Option<CCursorRange> {
+    let ccursor = cursor_range.single()?;
+    let prev_char = char_at(text.as_str(), ccursor.index.checked_sub(1)?)?;
+    let next_char = char_at(text.as_str(), ccursor.index)?;
+    if closing_char(prev_char) != Some(next_char) {
+        return None;
+    }
+    text.delete_char_range(ccursor.index - 1..ccursor.index + 1);
+    Some(CCursorRange::one(CCursor::new(ccursor.index - 1)))
+}
+
+/// Insert a newline, indented by `indenter`.
+///
+/// If the cursor is between a pair of brackets, the closing bracket is moved to its own line,
+/// with the same indentation as the line of the opening bracket.
+pub(super) fn insert_newline_with_indent(
+    text: &mut dyn TextBuffer,
+    cursor_range: &CCursorRange,
+    char_limit: usize,
+    indenter: &mut dyn FnMut(&str) -> String,
+) -> CCursorRange {
+    let mut ccursor = text.delete_selected(cursor_range);
+
+    let line_start = text
+        .char_range(0..ccursor.index)
+        .rfind('\n')
+        .map_or(0, |byte_index| {
+            text.char_index_from_byte_index(byte_index) + 1
+        });
+    let line_before_cursor = text.char_range(line_start..ccursor.index).to_owned();
+    let indent = indenter(&line_before_cursor);
+
+    let prev_char = line_before_cursor.trim_end().chars().last();
+    let next_char = char_at(text.as_str(), ccursor.index);
+    let is_between_brackets = BRACKETS
+        .iter()
+        .any(|&(open, close)| prev_char == Some(open) && next_char == Some(close));
+
+    text.insert_text_at(&mut ccursor, &format!("\n{indent}"), char_limit);
+    if is_between_brackets {
+        let mut after = ccursor;
+        let outer_indent = leading_whitespace(&line_before_cursor);
+        text.insert_text_at(&mut after, &format!("\n{outer_indent}"), char_limit);
+    }
+    CCursorRange::one(ccursor)
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    let end = line.len() - line.trim_start().len();
+    &line[..end]
+}
+
+/// The default indentation of a new line, given the line before the cursor.
+///
+/// Keeps the indentation of the current line,
+/// and indents one more tab if the line ends with an opening bracket.
+pub(super) fn default_indentation(line_before_cursor: &str) -> String {
+    let mut indent = leading_whitespace(line_before_cursor).to_owned();
+    let last_char = line_before_cursor.trim_end().chars().last();
+    if BRACKETS.iter().any(|&(open, _)| last_char == Some(open)) {
+        indent.push('\t');
+    }
+    indent
+}
+
+/// If there is a bracket right before or after the cursor,
+/// returns the char indices of it and its matching bracket.
+pub(super) fn matching_bracket(text: &str, cursor: CCursor) -> Option<[usize; 2]> {
+    let before = cursor
+        .index
+        .checked_sub(1)
+        .and_then(|i| Some((i, char_at(text, i)?)));
+    let after = char_at(text, cursor.index).map(|c| (cursor.index, c));
+
+    [before, after]
+        .into_iter()
+        .flatten()
+        .find_map(|(index, c)| Some([index, find_match(text, index, c)?]))
+}
+
+fn find_match(text: &str, index: usize, c: char) -> Option<usize> {
+    let (target, forward) = BRACKETS.iter().find_map(|&(open, close)| {
+        if c == open {
+            Some((close, true))
+        } else if c == close {
+            Some((open, false))
+        } else {
+            None
+        }
+    })?;
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut depth = 0_usize;
+    let mut i = index;
+    loop {
+        if chars[i] == c {
+            depth += 1;
+        } else if chars[i] == target {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+
+        if forward {
+            i += 1;
+            if chars.len() <= i {
+                return None;
+            }
+        } else {
+            i = i.checked_sub(1)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_bracket() {
+        let text = "f(a[0], {b})";
+        assert_eq!(matching_bracket(text, CCursor::new(2)), Some([1, 11]));
+        assert_eq!(matching_bracket(text, CCursor::new(12)), Some([11, 1]));
+        assert_eq!(matching_bracket(text, CCursor::new(4)), Some([3, 5]));
+        assert_eq!(matching_bracket(text, CCursor::new(9)), Some([8, 10]));
+        assert_eq!(matching_bracket("a)", CCursor::new(2)), None);
+    }
+
+    #[test]
+    fn test_auto_close_and_indent() {
+        let mut text = String::from("fn f() ");
+        let range = CCursorRange::one(CCursor::new(7));
+        let range = insert_with_auto_close(&mut text, &range, "{", usize::MAX).unwrap();
+        assert_eq!(text, "fn f() {}");
+        assert_eq!(range, CCursorRange::one(CCursor::new(8)));
+
+        let range = insert_newline_with_indent(&mut text, &range, usize::MAX, &mut |line| {
+            default_indentation(line)
+        });
+        assert_eq!(text, "fn f() {\n\t\n}");
+        assert_eq!(range, CCursorRange::one(CCursor::new(10)));
+
+        // Type over the closing bracket:
+        let mut text = String::from("()");
+        let range = CCursorRange::one(CCursor::new(1));
+        let range = insert_with_auto_close(&mut text, &range, ")", usize::MAX).unwrap();
+        assert_eq!(text, "()");
+        assert_eq!(range, CCursorRange::one(CCursor::new(2)));
+
+        // Backspace removes the empty pair:
+        let range = delete_empty_pair(&mut text, &CCursorRange::one(CCursor::new(1))).unwrap();
+        assert_eq!(text, "");
+        assert_eq!(range, CCursorRange::one(CCursor::new(0)));
+    }
+}
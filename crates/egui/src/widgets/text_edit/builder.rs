@@ -17,9 +17,10 @@ use crate::{
     vec2,
 };
 
-use super::{TextEditOutput, TextEditState};
+use super::{TextEditOutput, TextEditState, assists, paste_menu};
 
 type LayouterFn<'t> = &'t mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley>;
+type IndenterFn<'t> = &'t mut dyn FnMut(&str) -> String;
 
 /// A text region that the user can edit the contents of.
 ///
@@ -84,11 +85,17 @@ pub struct TextEdit<'t> {
     event_filter: EventFilter,
     cursor_at_end: bool,
     min_size: Vec2,
-    align: Align2,
+    horizontal_align: Option<Align>,
+    vertical_align: Align,
     clip_text: bool,
     char_limit: usize,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
+    auto_close_brackets: bool,
+    highlight_matching_brackets: bool,
+    auto_indent: bool,
+    indenter: Option<IndenterFn<'t>>,
+    paste_menu: bool,
 }
 
 impl WidgetWithState for TextEdit<'_> {
@@ -143,11 +150,17 @@ impl<'t> TextEdit<'t> {
             },
             cursor_at_end: true,
             min_size: Vec2::ZERO,
-            align: Align2::LEFT_TOP,
+            horizontal_align: None,
+            vertical_align: Align::TOP,
             clip_text: false,
             char_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
+            auto_close_brackets: false,
+            highlight_matching_brackets: false,
+            auto_indent: false,
+            indenter: None,
+            paste_menu: false,
         }
     }
 
@@ -365,16 +378,19 @@ impl<'t> TextEdit<'t> {
     }
 
     /// Set the horizontal align of the inner text.
+    ///
+    /// Default: the start of the line, i.e. [`Align::LEFT`],
+    /// or [`Align::RIGHT`] in right-to-left mode (see [`Ui::layout_direction`]).
     #[inline]
     pub fn horizontal_align(mut self, align: Align) -> Self {
-        self.align.0[0] = align;
+        self.horizontal_align = Some(align);
         self
     }
 
     /// Set the vertical align of the inner text.
     #[inline]
     pub fn vertical_align(mut self, align: Align) -> Self {
-        self.align.0[1] = align;
+        self.vertical_align = align;
         self
     }
 
@@ -396,6 +412,81 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// When typing an opening bracket or quote, also insert the closing one.
+    ///
+    /// Typing the closing character right in front of it then types over it,
+    /// and backspace between an empty pair deletes both.
+    /// Typing an opening bracket or quote with a selection wraps the selection.
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn auto_close_brackets(mut self, auto_close_brackets: bool) -> Self {
+        self.auto_close_brackets = auto_close_brackets;
+        self
+    }
+
+    /// Highlight the bracket matching the one next to the cursor.
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn highlight_matching_brackets(mut self, highlight_matching_brackets: bool) -> Self {
+        self.highlight_matching_brackets = highlight_matching_brackets;
+        self
+    }
+
+    /// When pressing enter in a multiline [`TextEdit`], indent the new line.
+    ///
+    /// By default the new line keeps the indentation of the current line,
+    /// plus one tab if the line ends with an opening bracket.
+    /// Use [`Self::indenter`] to customize this.
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn auto_indent(mut self, auto_indent: bool) -> Self {
+        self.auto_indent = auto_indent;
+        self
+    }
+
+    /// Decide the indentation of a new line when pressing enter.
+    ///
+    /// The argument is the text of the current line up to the cursor,
+    /// and the returned string is inserted after the newline.
+    /// This also turns on [`Self::auto_indent`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_code = String::new();
+    /// let mut indenter = |line: &str| {
+    ///     let indent = &line[..line.len() - line.trim_start().len()];
+    ///     if line.trim_end().ends_with(':') {
+    ///         format!("{indent}    ")
+    ///     } else {
+    ///         indent.to_owned()
+    ///     }
+    /// };
+    /// ui.add(egui::TextEdit::multiline(&mut my_code).indenter(&mut indenter));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn indenter(mut self, indenter: &'t mut dyn FnMut(&str) -> String) -> Self {
+        self.indenter = Some(indenter);
+        self.auto_indent = true;
+        self
+    }
+
+    /// Show a small arrow at the right side, which opens a menu of the texts
+    /// recently copied in this app. Picking one pastes it.
+    ///
+    /// The menu can also be opened with Ctrl+Shift+V (Cmd+Shift+V on Mac) while editing.
+    /// See also [`crate::ClipboardHistory`].
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn paste_menu(mut self, paste_menu: bool) -> Self {
+        self.paste_menu = paste_menu;
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -422,8 +513,18 @@ impl TextEdit<'_> {
     /// }
     /// # });
     /// ```
-    pub fn show(self, ui: &mut Ui) -> TextEditOutput {
+    pub fn show(mut self, ui: &mut Ui) -> TextEditOutput {
         let is_mutable = self.text.is_mutable();
+        self.paste_menu &= is_mutable && self.interactive && ui.is_enabled() && !ui.is_read_only();
+        let paste_menu = self.paste_menu;
+        let margin_right = f32::from(self.margin.right);
+        if paste_menu {
+            // Make room for the arrow:
+            self.margin.right = self
+                .margin
+                .right
+                .saturating_add(ui.spacing().icon_width as i8);
+        }
         let frame = self.frame;
         let where_to_put_background = ui.painter().add(Shape::Noop);
         let background_color = self
@@ -431,6 +532,11 @@ impl TextEdit<'_> {
             .unwrap_or_else(|| ui.visuals().text_edit_bg_color());
         let output = self.show_content(ui);
 
+        if paste_menu {
+            let button_rect = paste_menu::button_rect(ui, output.response.rect, margin_right);
+            paste_menu::paste_menu_ui(ui, &output.response, button_rect);
+        }
+
         if frame {
             let visuals = ui.style().interact(&output.response);
             let frame_rect = output.response.rect.expand(visuals.expansion);
@@ -488,13 +594,33 @@ impl TextEdit<'_> {
             event_filter,
             cursor_at_end,
             min_size,
-            align,
+            horizontal_align,
+            vertical_align,
             clip_text,
             char_limit,
             return_key,
             background_color: _,
+            auto_close_brackets,
+            highlight_matching_brackets,
+            auto_indent,
+            indenter,
+            paste_menu,
         } = self;
 
+        let align = Align2([
+            horizontal_align.unwrap_or_else(|| ui.layout_direction().start()),
+            vertical_align,
+        ]);
+
+        // In a read-only `Ui` the text can still be selected and copied, but not edited:
+        let mut read_only_text: &str;
+        let text: &mut dyn TextBuffer = if ui.is_read_only() {
+            read_only_text = text.as_str();
+            &mut read_only_text
+        } else {
+            text
+        };
+
         let text_color = text_color
             .or(ui.visuals().override_text_color)
             // .unwrap_or_else(|| ui.style().interact(&response).text_color()); // too bright
@@ -527,6 +653,17 @@ impl TextEdit<'_> {
 
         let layouter = layouter.unwrap_or(&mut default_layouter);
 
+        let mut default_indenter = |line: &str| assists::default_indentation(line);
+        let indenter: Option<&mut dyn FnMut(&str) -> String> = if auto_indent && !password {
+            match indenter {
+                Some(indenter) => Some(indenter),
+                None => Some(&mut default_indenter),
+            }
+        } else {
+            None
+        };
+        let auto_close_brackets = auto_close_brackets && !password;
+
         let mut galley = layouter(ui, text, wrap_width);
 
         let desired_inner_width = if clip_text {
@@ -621,6 +758,17 @@ impl TextEdit<'_> {
         if interactive && ui.memory(|mem| mem.has_focus(id)) {
             ui.memory_mut(|mem| mem.set_focus_lock_filter(id, event_filter));
 
+            if paste_menu {
+                if ui.input_mut(|i| i.consume_shortcut(&paste_menu::SHORTCUT)) {
+                    // Some integrations also turn this shortcut into a paste:
+                    ui.input_mut(|i| i.events.retain(|event| !matches!(event, Event::Paste(_))));
+                    paste_menu::open(ui.ctx(), id);
+                }
+                if let Some(text) = paste_menu::take_pending_paste(ui.ctx(), id) {
+                    ui.input_mut(|i| i.events.push(Event::Paste(text)));
+                }
+            }
+
             let default_cursor_range = if cursor_at_end {
                 CCursorRange::one(galley.end())
             } else {
@@ -641,6 +789,8 @@ impl TextEdit<'_> {
                 char_limit,
                 event_filter,
                 return_key,
+                auto_close_brackets,
+                indenter,
             );
 
             if changed {
@@ -723,6 +873,17 @@ impl TextEdit<'_> {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
                     // Add text selection rectangles to the galley:
                     paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
+
+                    if highlight_matching_brackets && !password {
+                        paint_matching_brackets(
+                            &painter,
+                            ui.visuals(),
+                            &galley,
+                            galley_pos,
+                            text.as_str(),
+                            &cursor_range,
+                        );
+                    }
                 }
             }
 
@@ -741,6 +902,25 @@ impl TextEdit<'_> {
 
             painter.galley(galley_pos, galley.clone(), text_color);
 
+            if has_focus {
+                if let Some(preedit) = ime_preedit_range(&state, text) {
+                    paint_ime_preedit(&painter, &galley, galley_pos, &preedit, text_color);
+                }
+            }
+
+            // Scroll into view above the on-screen keyboard when it shows up or changes size:
+            let keyboard_rect = if has_focus {
+                ui.input(|i| i.virtual_keyboard_rect)
+            } else {
+                None
+            };
+            if state.revealed_above_keyboard != keyboard_rect {
+                state.revealed_above_keyboard = keyboard_rect;
+                if keyboard_rect.is_some() {
+                    ui.scroll_to_rect(outer_rect, None);
+                }
+            }
+
             if has_focus {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
                     let primary_cursor_rect =
@@ -790,8 +970,12 @@ impl TextEdit<'_> {
         }
 
         // Ensures correct IME behavior when the text input area gains or loses focus.
-        if state.ime_enabled && (response.gained_focus() || response.lost_focus()) {
+        // Any composition is kept as is.
+        if (state.ime_enabled || state.ime_preedit.is_some())
+            && (response.gained_focus() || response.lost_focus())
+        {
             state.ime_enabled = false;
+            state.ime_preedit = None;
             if let Some(mut ccursor_range) = state.cursor.char_range() {
                 ccursor_range.secondary.index = ccursor_range.primary.index;
                 state.cursor.set_char_range(Some(ccursor_range));
@@ -880,7 +1064,7 @@ fn mask_if_password(is_password: bool, text: &str) -> String {
 // ----------------------------------------------------------------------------
 
 /// Check for (keyboard) events to edit the cursor and/or text.
-#[expect(clippy::too_many_arguments)]
+#[expect(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 fn events(
     ui: &crate::Ui,
     state: &mut TextEditState,
@@ -895,6 +1079,8 @@ fn events(
     char_limit: usize,
     event_filter: EventFilter,
     return_key: Option<KeyboardShortcut>,
+    auto_close_brackets: bool,
+    mut indenter: Option<&mut dyn FnMut(&str) -> String>,
 ) -> (bool, CCursorRange) {
     let os = ui.ctx().os();
 
@@ -923,6 +1109,9 @@ fn events(
         events.sort_by_key(|e| !matches!(e, Event::Ime(_)));
     }
 
+    // Pasted files are inserted as their paths, unless the integration also pasted them as text:
+    let has_text_paste = events.iter().any(|event| matches!(event, Event::Paste(_)));
+
     for event in &events {
         let did_mutate_text = match event {
             // First handle events that only changes the selection cursor, not the text:
@@ -953,14 +1142,37 @@ fn events(
                     None
                 }
             }
+            Event::PasteContent(content @ crate::ClipboardContent::Files(_)) if !has_text_paste => {
+                let text_to_insert = content.to_text().unwrap_or_default();
+                if !text_to_insert.is_empty() {
+                    let mut ccursor = text.delete_selected(&cursor_range);
+                    text.insert_text_at(&mut ccursor, &text_to_insert, char_limit);
+                    Some(CCursorRange::one(ccursor))
+                } else {
+                    None
+                }
+            }
             Event::Text(text_to_insert) => {
                 // Newlines are handled by `Key::Enter`.
                 if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
-                    let mut ccursor = text.delete_selected(&cursor_range);
+                    let auto_closed = if auto_close_brackets {
+                        assists::insert_with_auto_close(
+                            text,
+                            &cursor_range,
+                            text_to_insert,
+                            char_limit,
+                        )
+                    } else {
+                        None
+                    };
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    auto_closed.or_else(|| {
+                        let mut ccursor = text.delete_selected(&cursor_range);
 
-                    Some(CCursorRange::one(ccursor))
+                        text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+
+                        Some(CCursorRange::one(ccursor))
+                    })
                 } else {
                     None
                 }
@@ -990,10 +1202,18 @@ fn events(
             }) =>
             {
                 if multiline {
-                    let mut ccursor = text.delete_selected(&cursor_range);
-                    text.insert_text_at(&mut ccursor, "\n", char_limit);
-                    // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
-                    Some(CCursorRange::one(ccursor))
+                    if let Some(indenter) = &mut indenter {
+                        Some(assists::insert_newline_with_indent(
+                            text,
+                            &cursor_range,
+                            char_limit,
+                            indenter,
+                        ))
+                    } else {
+                        let mut ccursor = text.delete_selected(&cursor_range);
+                        text.insert_text_at(&mut ccursor, "\n", char_limit);
+                        Some(CCursorRange::one(ccursor))
+                    }
                 } else {
                     ui.memory_mut(|mem| mem.surrender_focus(id)); // End input with enter
                     break;
@@ -1039,6 +1259,24 @@ fn events(
                 }
             }
 
+            Event::Key {
+                key: Key::Backspace,
+                pressed: true,
+                modifiers,
+                ..
+            } if auto_close_brackets && modifiers.is_none() => {
+                assists::delete_empty_pair(text, &cursor_range).or_else(|| {
+                    check_for_mutating_key_press(
+                        os,
+                        &cursor_range,
+                        text,
+                        galley,
+                        modifiers,
+                        Key::Backspace,
+                    )
+                })
+            }
+
             Event::Key {
                 modifiers,
                 key,
@@ -1049,46 +1287,48 @@ fn events(
             Event::Ime(ime_event) => match ime_event {
                 ImeEvent::Enabled => {
                     state.ime_enabled = true;
-                    state.ime_cursor_range = cursor_range;
                     None
                 }
                 ImeEvent::Preedit(text_mark) => {
                     if text_mark == "\n" || text_mark == "\r" {
                         None
                     } else {
-                        // Empty prediction can be produced when user press backspace
-                        // or escape during IME, so we clear current text.
-                        let mut ccursor = text.delete_selected(&cursor_range);
+                        // Replace the previous composition (or the selection) with the new one.
+                        // An empty composition is produced when the user presses backspace
+                        // or escape during IME, so we just clear the current one.
+                        let replaced = ime_preedit_range(state, text).unwrap_or(cursor_range);
+                        let mut ccursor = text.delete_selected(&replaced);
                         let start_cursor = ccursor;
                         if !text_mark.is_empty() {
                             text.insert_text_at(&mut ccursor, text_mark, char_limit);
                         }
-                        state.ime_cursor_range = cursor_range;
-                        Some(CCursorRange::two(start_cursor, ccursor))
+                        state.ime_preedit = (ccursor.index != start_cursor.index)
+                            .then(|| CCursorRange::two(start_cursor, ccursor));
+                        Some(CCursorRange::one(ccursor))
                     }
                 }
                 ImeEvent::Commit(prediction) => {
                     if prediction == "\n" || prediction == "\r" {
                         None
                     } else {
+                        // The committed text replaces the composition.
+                        // An empty commit cancels the composition.
                         state.ime_enabled = false;
-
-                        if !prediction.is_empty()
-                            && cursor_range.secondary.index
-                                == state.ime_cursor_range.secondary.index
-                        {
-                            let mut ccursor = text.delete_selected(&cursor_range);
+                        let replaced = ime_preedit_range(state, text).unwrap_or(cursor_range);
+                        state.ime_preedit = None;
+                        let mut ccursor = text.delete_selected(&replaced);
+                        if !prediction.is_empty() {
                             text.insert_text_at(&mut ccursor, prediction, char_limit);
-                            Some(CCursorRange::one(ccursor))
-                        } else {
-                            let ccursor = cursor_range.primary;
-                            Some(CCursorRange::one(ccursor))
                         }
+                        Some(CCursorRange::one(ccursor))
                     }
                 }
                 ImeEvent::Disabled => {
+                    // Disabling the IME without a commit cancels the composition:
                     state.ime_enabled = false;
-                    None
+                    let preedit = ime_preedit_range(state, text);
+                    state.ime_preedit = None;
+                    preedit.map(|preedit| CCursorRange::one(text.delete_selected(&preedit)))
                 }
             },
 
@@ -1098,6 +1338,11 @@ fn events(
         if let Some(new_ccursor_range) = did_mutate_text {
             any_change = true;
 
+            if !matches!(event, Event::Ime(_)) {
+                // Editing the text some other way ends the composition where it is:
+                state.ime_preedit = None;
+            }
+
             // Layout again to avoid frame delay, and to keep `text` and `galley` in sync.
             *galley = layouter(ui, text, wrap_width);
 
@@ -1118,6 +1363,75 @@ fn events(
 
 // ----------------------------------------------------------------------------
 
+/// Outline the bracket next to the cursor, and its matching bracket.
+fn paint_matching_brackets(
+    painter: &crate::Painter,
+    visuals: &crate::Visuals,
+    galley: &Galley,
+    galley_pos: emath::Pos2,
+    text: &str,
+    cursor_range: &CCursorRange,
+) {
+    let Some(cursor) = cursor_range.single() else {
+        return;
+    };
+    let Some(brackets) = assists::matching_bracket(text, cursor) else {
+        return;
+    };
+    for index in brackets {
+        let min = galley.pos_from_cursor(CCursor::new(index));
+        let max = galley.pos_from_cursor(CCursor::new(index + 1));
+        let rect = Rect::from_x_y_ranges(min.min.x..=max.min.x.max(min.min.x), min.y_range());
+        painter.rect_stroke(
+            rect.translate(galley_pos.to_vec2()),
+            2.0,
+            visuals.selection.stroke,
+            StrokeKind::Inside,
+        );
+    }
+}
+
+/// The range of the IME composition, if any, and if it is still within the text.
+fn ime_preedit_range(state: &TextEditState, text: &dyn TextBuffer) -> Option<CCursorRange> {
+    let preedit = state.ime_preedit?;
+    let [_, max] = preedit.sorted_cursors();
+    (max.index <= text.as_str().chars().count()).then_some(preedit)
+}
+
+/// Underline the text being composed with the IME, like most platforms do.
+fn paint_ime_preedit(
+    painter: &crate::Painter,
+    galley: &Galley,
+    galley_pos: emath::Pos2,
+    preedit: &CCursorRange,
+    color: Color32,
+) {
+    let [min, max] = preedit.sorted_cursors();
+    let min = galley.layout_from_cursor(min);
+    let max = galley.layout_from_cursor(max);
+    let stroke = crate::Stroke::new(1.0, color);
+
+    for ri in min.row..=max.row {
+        let placed_row = &galley.rows[ri];
+        let row = &placed_row.row;
+        let left = if ri == min.row {
+            row.x_offset(min.column)
+        } else {
+            0.0
+        };
+        let right = if ri == max.row {
+            row.x_offset(max.column)
+        } else {
+            row.size.x
+        };
+        let rect = Rect::from_x_y_ranges(left..=right, 0.0..=row.size.y)
+            .translate(placed_row.pos.to_vec2() + galley_pos.to_vec2());
+        painter.hline(rect.x_range(), rect.bottom() - 1.0, stroke);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 fn remove_ime_incompatible_events(events: &mut Vec<Event>) {
     // Remove key events which cause problems while 'IME' is being used.
     // See https://github.com/emilk/egui/pull/4509
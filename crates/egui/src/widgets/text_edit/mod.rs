@@ -1,9 +1,11 @@
+mod assists;
 mod builder;
 mod output;
+mod paste_menu;
 mod state;
 mod text_buffer;
 
 pub use {
     crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
-    state::TextEditState, text_buffer::TextBuffer,
+    paste_menu::ClipboardHistory, state::TextEditState, text_buffer::TextBuffer,
 };
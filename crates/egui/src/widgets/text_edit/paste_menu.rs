@@ -0,0 +1,138 @@
+//! The paste menu of a [`TextEdit`](super::TextEdit), see [`TextEdit::paste_menu`](super::TextEdit::paste_menu).
+
+use crate::{
+    Context, Id, Key, KeyboardShortcut, Modifiers, Popup, Rect, Response, Sense, SetOpenCommand,
+    Ui, WidgetInfo, WidgetType, containers::menu, pos2,
+};
+
+/// The texts copied with [`Context::copy_text`] during this session, most recent first.
+///
+/// This is what the paste menu of a [`TextEdit`](super::TextEdit) lists.
+/// It is only kept in memory, and never persisted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClipboardHistory {
+    /// Most recent first.
+    pub entries: Vec<String>,
+}
+
+impl ClipboardHistory {
+    /// The maximum number of entries that are kept.
+    pub const MAX_ENTRIES: usize = 10;
+
+    fn id() -> Id {
+        Id::new("clipboard_history")
+    }
+
+    pub fn load(ctx: &Context) -> Self {
+        ctx.data(|d| d.get_temp(Self::id())).unwrap_or_default()
+    }
+
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+
+    /// Add a copied text, moving it to the front if it is already in the history.
+    pub(crate) fn add(ctx: &Context, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let mut history = Self::load(ctx);
+        history.entries.retain(|entry| entry != text);
+        history.entries.insert(0, text.to_owned());
+        history.entries.truncate(Self::MAX_ENTRIES);
+        history.store(ctx);
+    }
+}
+
+/// Opens the paste menu of a focused [`TextEdit`](super::TextEdit).
+pub(crate) const SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::V);
+
+pub(crate) fn popup_id(text_edit_id: Id) -> Id {
+    text_edit_id.with("paste_menu")
+}
+
+/// Open the paste menu from the keyboard, with the first entry focused.
+pub(crate) fn open(ctx: &Context, text_edit_id: Id) {
+    let popup_id = popup_id(text_edit_id);
+    Popup::open_id(ctx, popup_id);
+    menu::request_focus_first_item(ctx, popup_id);
+}
+
+/// The entry picked in the menu, to be pasted into the [`TextEdit`](super::TextEdit)
+/// once it has focus again.
+pub(crate) fn take_pending_paste(ctx: &Context, text_edit_id: Id) -> Option<String> {
+    ctx.data_mut(|d| d.remove_temp(text_edit_id.with("pending_paste")))
+}
+
+/// The arrow button at the right side of the [`TextEdit`](super::TextEdit), and the menu it opens.
+///
+/// `rect` is where the arrow goes.
+pub(crate) fn paste_menu_ui(ui: &Ui, text_edit: &Response, rect: Rect) {
+    let text_edit_id = text_edit.id;
+    let popup_id = popup_id(text_edit_id);
+
+    let button = ui
+        .interact(rect, text_edit_id.with("paste_menu_button"), Sense::click())
+        .on_hover_text(format!(
+            "Paste from history ({})",
+            ui.ctx().format_shortcut(&SHORTCUT)
+        ));
+    button.widget_info(|| {
+        WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Paste from history")
+    });
+
+    let open = Popup::is_id_open(ui.ctx(), popup_id);
+    let visuals = ui.style().interact_selectable(&button, open);
+    ui.painter().text(
+        rect.center(),
+        crate::Align2::CENTER_CENTER,
+        "⏷",
+        crate::TextStyle::Small.resolve(ui.style()),
+        visuals.text_color(),
+    );
+
+    Popup::menu(&button)
+        .id(popup_id)
+        .open_memory(button.clicked().then_some(SetOpenCommand::Toggle))
+        .anchor(text_edit.rect)
+        .align(crate::RectAlign::BOTTOM_END)
+        .show(|ui| {
+            let history = ClipboardHistory::load(ui.ctx());
+            if history.entries.is_empty() {
+                ui.weak("Nothing copied yet");
+            }
+            for entry in history.entries {
+                if ui.button(preview(&entry)).clicked() {
+                    ui.data_mut(|d| d.insert_temp(text_edit_id.with("pending_paste"), entry));
+                    ui.memory_mut(|mem| mem.request_focus(text_edit_id));
+                    // The focus moves to the `TextEdit`, so picking with the keyboard doesn't close it:
+                    ui.close();
+                }
+            }
+            menu::menu_keyboard_ui(ui, popup_id, crate::PopupCloseBehavior::CloseOnClick);
+        });
+}
+
+/// The first line of the text, shortened to fit in a menu.
+fn preview(text: &str) -> String {
+    const MAX_CHARS: usize = 40;
+    let first_line = text.lines().next().unwrap_or_default();
+    if first_line.chars().count() <= MAX_CHARS && first_line.len() == text.trim_end().len() {
+        first_line.to_owned()
+    } else {
+        let mut preview: String = first_line.chars().take(MAX_CHARS).collect();
+        preview.push('…');
+        preview
+    }
+}
+
+/// Where the arrow of the paste menu goes, in a [`TextEdit`](super::TextEdit)
+/// with the given outer rectangle and right margin.
+pub(crate) fn button_rect(ui: &Ui, outer_rect: Rect, margin_right: f32) -> Rect {
+    let right = outer_rect.max.x - margin_right;
+    Rect::from_min_max(
+        pos2(right - ui.spacing().icon_width, outer_rect.min.y),
+        pos2(right, outer_rect.max.y),
+    )
+}
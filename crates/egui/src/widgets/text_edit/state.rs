@@ -45,9 +45,10 @@ pub struct TextEditState {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) ime_enabled: bool,
 
-    // cursor range for IME candidate.
+    // The text being composed with the IME, not yet committed.
+    // It is shown inline, with an underline.
     #[cfg_attr(feature = "serde", serde(skip))]
-    pub(crate) ime_cursor_range: CCursorRange,
+    pub(crate) ime_preedit: Option<CCursorRange>,
 
     // Visual offset when editing singleline text bigger than the width.
     #[cfg_attr(feature = "serde", serde(skip))]
@@ -57,6 +58,10 @@ pub struct TextEditState {
     /// Used to pause the cursor animation when typing.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) last_interaction_time: f64,
+
+    /// The on-screen keyboard we last scrolled this `TextEdit` above, while it had focus.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) revealed_above_keyboard: Option<crate::Rect>,
 }
 
 impl TextEditState {
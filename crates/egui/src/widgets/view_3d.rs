@@ -0,0 +1,208 @@
+use std::{any::Any, sync::Arc};
+
+use crate::{
+    PaintCallback, PointerButton, Rect, Response, Sense, Ui, Vec2, emath::NumExt as _, vec2,
+};
+
+/// A camera interaction produced by a [`View3D`].
+///
+/// Apply these to your own camera.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraEvent {
+    /// Rotate the camera around its target.
+    ///
+    /// `x` is the change in yaw and `y` the change in pitch, in radians.
+    /// Produced by dragging with the primary mouse button.
+    Orbit(Vec2),
+
+    /// Move the camera sideways, in points.
+    ///
+    /// Produced by dragging with the secondary or middle mouse button,
+    /// or with the primary mouse button while holding shift.
+    Pan(Vec2),
+
+    /// Move the camera closer to (> 1) or further away from (< 1) its target, by this factor.
+    ///
+    /// Produced by the scroll wheel or by pinch-to-zoom.
+    Zoom(f32),
+}
+
+/// What a [`View3D`] gives to the closure producing the paint callback.
+#[derive(Clone, Debug)]
+pub struct View3DInput {
+    /// Where the view is painted, in points.
+    pub rect: Rect,
+
+    /// Width divided by height of [`Self::rect`].
+    ///
+    /// Use this for your projection matrix.
+    pub aspect_ratio: f32,
+
+    /// The camera interactions of this frame, in the order they happened.
+    pub camera_events: Vec<CameraEvent>,
+}
+
+/// Embed a custom 3D scene in the ui.
+///
+/// This allocates space for the view, turns mouse and touch input into [`CameraEvent`]s,
+/// and paints a [`PaintCallback`] with a depth buffer.
+/// The callback itself is backend specific,
+/// e.g. an `egui_glow::CallbackFn` or an `egui_wgpu::Callback`.
+///
+/// The depth buffer and multisampling of the render target are reported to the callback in
+/// [`crate::PaintCallbackInfo::has_depth_buffer`] and [`crate::PaintCallbackInfo::msaa_samples`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut yaw = 0.0;
+/// # fn my_backend_callback(yaw: f32, aspect_ratio: f32) -> std::sync::Arc<dyn std::any::Any + Send + Sync> { std::sync::Arc::new(()) }
+/// egui::View3D::new().show(ui, |input| {
+///     for event in &input.camera_events {
+///         if let egui::CameraEvent::Orbit(delta) = event {
+///             yaw += delta.x;
+///         }
+///     }
+///     my_backend_callback(yaw, input.aspect_ratio)
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct View3D {
+    desired_size: Option<Vec2>,
+    aspect_ratio: Option<f32>,
+    orbit_speed: f32,
+    zoom_speed: f32,
+    depth_buffer: bool,
+}
+
+impl Default for View3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View3D {
+    pub fn new() -> Self {
+        Self {
+            desired_size: None,
+            aspect_ratio: None,
+            orbit_speed: 0.01,
+            zoom_speed: 0.005,
+            depth_buffer: true,
+        }
+    }
+
+    /// The size of the view.
+    ///
+    /// Default: all available width and height.
+    #[inline]
+    pub fn desired_size(mut self, desired_size: impl Into<Vec2>) -> Self {
+        self.desired_size = Some(desired_size.into());
+        self
+    }
+
+    /// Keep the view at this width/height ratio, shrinking it to fit if needed.
+    ///
+    /// Default: none, i.e. fill the desired size.
+    #[inline]
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    /// Radians of [`CameraEvent::Orbit`] per point dragged.
+    ///
+    /// Default: `0.01`.
+    #[inline]
+    pub fn orbit_speed(mut self, orbit_speed: f32) -> Self {
+        self.orbit_speed = orbit_speed;
+        self
+    }
+
+    /// How fast the scroll wheel produces [`CameraEvent::Zoom`].
+    ///
+    /// Default: `0.005`.
+    #[inline]
+    pub fn zoom_speed(mut self, zoom_speed: f32) -> Self {
+        self.zoom_speed = zoom_speed;
+        self
+    }
+
+    /// Request a depth buffer for the callback.
+    ///
+    /// See [`PaintCallback::depth_buffer`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn depth_buffer(mut self, depth_buffer: bool) -> Self {
+        self.depth_buffer = depth_buffer;
+        self
+    }
+
+    /// Show the view.
+    ///
+    /// The closure is given the input of this frame, and should return the backend-specific
+    /// callback to paint with, i.e. what goes into [`PaintCallback::callback`].
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        callback: impl FnOnce(&View3DInput) -> Arc<dyn Any + Send + Sync>,
+    ) -> Response {
+        let Self {
+            desired_size,
+            aspect_ratio,
+            orbit_speed,
+            zoom_speed,
+            depth_buffer,
+        } = self;
+
+        let mut size = desired_size.unwrap_or_else(|| ui.available_size());
+        if let Some(aspect_ratio) = aspect_ratio {
+            let width = size.x.at_most(size.y * aspect_ratio);
+            size = vec2(width, width / aspect_ratio.at_least(f32::EPSILON));
+        }
+
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+        let mut camera_events = vec![];
+
+        let delta = response.drag_delta();
+        if delta != Vec2::ZERO {
+            let shift = ui.input(|i| i.modifiers.shift);
+            if response.dragged_by(PointerButton::Secondary)
+                || response.dragged_by(PointerButton::Middle)
+                || (shift && response.dragged_by(PointerButton::Primary))
+            {
+                camera_events.push(CameraEvent::Pan(delta));
+            } else if response.dragged_by(PointerButton::Primary) {
+                camera_events.push(CameraEvent::Orbit(orbit_speed * delta));
+            }
+        }
+
+        if response.contains_pointer() {
+            // Capture the scroll so that a parent `ScrollArea` doesn't scroll:
+            let (zoom_delta, scroll_delta) = ui.input_mut(|i| {
+                let scroll_delta = std::mem::take(&mut i.smooth_scroll_delta);
+                (i.zoom_delta(), scroll_delta)
+            });
+            let zoom = zoom_delta * (zoom_speed * scroll_delta.y).exp();
+            if zoom != 1.0 {
+                camera_events.push(CameraEvent::Zoom(zoom));
+            }
+        }
+
+        let input = View3DInput {
+            rect,
+            aspect_ratio: rect.aspect_ratio(),
+            camera_events,
+        };
+
+        let callback = PaintCallback::new(rect, callback(&input)).with_depth_buffer(depth_buffer);
+        if ui.is_rect_visible(rect) {
+            ui.painter().add(callback);
+        }
+
+        response
+    }
+}
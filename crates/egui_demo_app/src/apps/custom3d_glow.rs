@@ -67,10 +67,7 @@ impl Custom3d {
             rotating_triangle.lock().paint(painter.gl(), angle);
         });
 
-        let callback = egui::PaintCallback {
-            rect,
-            callback: Arc::new(cb),
-        };
+        let callback = egui::PaintCallback::new(rect, Arc::new(cb));
         ui.painter().add(callback);
     }
 }
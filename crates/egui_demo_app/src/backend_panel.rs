@@ -351,6 +351,7 @@ struct EguiWindows {
     settings: bool,
     inspection: bool,
     memory: bool,
+    data_inspector: bool,
     output_events: bool,
 
     #[cfg_attr(feature = "serde", serde(skip))]
@@ -369,6 +370,7 @@ impl EguiWindows {
             settings: false,
             inspection: false,
             memory: false,
+            data_inspector: false,
             output_events: false,
             output_event_history: Default::default(),
         }
@@ -379,6 +381,7 @@ impl EguiWindows {
             settings,
             inspection,
             memory,
+            data_inspector,
             output_events,
             output_event_history: _,
         } = self;
@@ -386,6 +389,7 @@ impl EguiWindows {
         ui.checkbox(settings, "🔧 Settings");
         ui.checkbox(inspection, "🔍 Inspection");
         ui.checkbox(memory, "📝 Memory");
+        ui.checkbox(data_inspector, "🗄 Data Inspector");
         ui.checkbox(output_events, "📤 Output Events");
     }
 
@@ -394,6 +398,7 @@ impl EguiWindows {
             settings,
             inspection,
             memory,
+            data_inspector,
             output_events,
             output_event_history,
         } = self;
@@ -428,6 +433,13 @@ impl EguiWindows {
                 ctx.memory_ui(ui);
             });
 
+        egui::Window::new("🗄 Data Inspector")
+            .open(data_inspector)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ctx.data_inspector_ui(ui);
+            });
+
         egui::Window::new("📤 Output Events")
             .open(output_events)
             .resizable(true)
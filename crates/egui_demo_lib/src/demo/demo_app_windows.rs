@@ -119,6 +119,17 @@ impl Default for DemoGroups {
 }
 
 impl DemoGroups {
+    /// All demos and tests, except the about window.
+    #[cfg(any(test, feature = "snapshot"))]
+    pub fn into_demos(self) -> Vec<Box<dyn Demo>> {
+        let Self {
+            about: _,
+            demos,
+            tests,
+        } = self;
+        (demos + tests).demos
+    }
+
     pub fn checkboxes(&mut self, ui: &mut Ui, open: &mut BTreeSet<String>) {
         let Self {
             about,
@@ -370,62 +381,24 @@ fn file_menu_button(ui: &mut Ui) {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Demo as _, demo::demo_app_windows::DemoGroups};
+    use crate::Demo as _;
 
-    use egui_kittest::kittest::{NodeT as _, Queryable as _};
-    use egui_kittest::{Harness, OsThreshold, SnapshotOptions, SnapshotResults};
+    use egui_kittest::SnapshotResults;
 
     #[test]
     fn demos_should_match_snapshot() {
-        let DemoGroups {
-            demos,
-            tests,
-            about: _,
-        } = DemoGroups::default();
-        let demos = demos + tests;
-
         let mut results = SnapshotResults::new();
 
-        for mut demo in demos.demos {
-            // Widget Gallery needs to be customized (to set a specific date) and has its own test
+        for mut demo in crate::snapshot::demos() {
+            // Widget Gallery has its own test, in more themes and scales
             if demo.name() == crate::WidgetGallery::default().name() {
                 continue;
             }
 
-            let name = remove_leading_emoji(demo.name());
-
-            let mut harness = Harness::new(|ctx| {
-                egui_extras::install_image_loaders(ctx);
-                demo.show(ctx, &mut true);
-            });
-
-            let window = harness.queryable_node().children().next().unwrap();
-            // TODO(lucasmerlin): Windows should probably have a label?
-            //let window = harness.get_by_label(name);
-
-            let size = window.rect().size();
-            harness.set_size(size);
-
-            // Run the app for some more frames...
-            harness.run_ok();
-
-            let mut options = SnapshotOptions::default();
-
-            if name == "Bézier Curve" {
-                // The Bézier Curve demo needs a threshold of 2.1 to pass on linux:
-                options = options.threshold(OsThreshold::new(0.0).linux(2.1));
-            }
-
-            results.add(harness.try_snapshot_options(format!("demos/{name}"), &options));
-        }
-    }
-
-    fn remove_leading_emoji(full_name: &str) -> &str {
-        if let Some((start, name)) = full_name.split_once(' ') {
-            if start.len() <= 4 && start.bytes().next().is_some_and(|byte| byte >= 128) {
-                return name;
-            }
+            results.add(crate::snapshot::snapshot_demo(
+                demo.as_mut(),
+                "tests/snapshots",
+            ));
         }
-        full_name
     }
 }
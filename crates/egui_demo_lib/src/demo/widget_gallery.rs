@@ -57,6 +57,14 @@ impl WidgetGallery {
         }
         self
     }
+
+    /// Set the date of the date picker, e.g. so that it looks the same in every screenshot.
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn with_date(mut self, date: chrono::NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
 }
 
 impl crate::Demo for WidgetGallery {
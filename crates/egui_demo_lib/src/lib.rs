@@ -14,6 +14,8 @@
 mod demo;
 pub mod easy_mark;
 mod rendering_test;
+#[cfg(any(test, feature = "snapshot"))]
+pub mod snapshot;
 
 pub use demo::{Demo, DemoWindows, View, WidgetGallery};
 pub use rendering_test::ColorTest;
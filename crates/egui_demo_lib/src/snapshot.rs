@@ -0,0 +1,106 @@
+//! Render every demo to an image, to check rendering changes across all of egui at once.
+//!
+//! This is what the snapshot tests of `egui_demo_lib` use,
+//! but it is also useful if you maintain a fork of egui, a custom theme, or an integration:
+//!
+//! ```no_run
+//! // In a test of your crate:
+//! egui_demo_lib::snapshot::snapshot_all_demos("tests/snapshots").unwrap();
+//! ```
+//!
+//! Each demo is saved as `demos/<name>.png` in the output directory.
+//! Run with `UPDATE_SNAPSHOTS=1` to create or update the images, see [`egui_kittest`].
+
+use std::path::Path;
+
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+use egui_kittest::{Harness, OsThreshold, SnapshotOptions, SnapshotResult, SnapshotResults};
+
+use crate::Demo;
+use crate::demo::demo_app_windows::DemoGroups;
+
+/// Every demo and test window of the demo app, with state that renders the same every time.
+pub fn demos() -> Vec<Box<dyn Demo>> {
+    DemoGroups::default()
+        .into_demos()
+        .into_iter()
+        .map(|demo| -> Box<dyn Demo> {
+            if demo.name() == crate::WidgetGallery::default().name() {
+                Box::new(widget_gallery())
+            } else {
+                demo
+            }
+        })
+        .collect()
+}
+
+fn widget_gallery() -> crate::WidgetGallery {
+    let gallery = crate::WidgetGallery::default();
+
+    // Otherwise the date picker shows today's date:
+    #[cfg(feature = "chrono")]
+    let gallery = gallery.with_date(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+
+    gallery
+}
+
+/// The name of the snapshot of a demo, e.g. `"demos/Code Editor"`.
+pub fn snapshot_name(demo: &dyn Demo) -> String {
+    format!("demos/{}", remove_leading_emoji(demo.name()))
+}
+
+/// A [`Harness`] showing the window of the demo, sized to fit it.
+pub fn demo_harness(demo: &mut dyn Demo) -> Harness<'_> {
+    let mut harness = Harness::new(move |ctx| {
+        egui_extras::install_image_loaders(ctx);
+        demo.show(ctx, &mut true);
+    });
+
+    let window = harness.queryable_node().children().next().unwrap();
+    // TODO(lucasmerlin): Windows should probably have a label?
+    //let window = harness.get_by_label(name);
+
+    let size = window.rect().size();
+    harness.set_size(size);
+
+    // Run the app for some more frames...
+    harness.run_ok();
+
+    harness
+}
+
+/// Render a demo and compare it to its snapshot in `output_path`.
+///
+/// # Errors
+/// If the rendering differs from the snapshot, or the snapshot could not be read or written.
+pub fn snapshot_demo(demo: &mut dyn Demo, output_path: impl AsRef<Path>) -> SnapshotResult {
+    let name = snapshot_name(demo);
+    let mut options = SnapshotOptions::default().output_path(output_path.as_ref());
+
+    if name == "demos/Bézier Curve" {
+        // The Bézier Curve demo needs a threshold of 2.1 to pass on linux:
+        options = options.threshold(OsThreshold::new(0.0).linux(2.1));
+    }
+
+    demo_harness(demo).try_snapshot_options(name, &options)
+}
+
+/// Render every demo in [`demos`] and compare them to their snapshots in `output_path`.
+///
+/// The returned [`SnapshotResults`] panics on drop if any snapshot failed.
+pub fn snapshot_all_demos(output_path: impl AsRef<Path>) -> SnapshotResults {
+    let mut results = SnapshotResults::new();
+    for mut demo in demos() {
+        results.add(snapshot_demo(demo.as_mut(), output_path.as_ref()));
+    }
+    results
+}
+
+fn remove_leading_emoji(full_name: &str) -> &str {
+    if let Some((start, name)) = full_name.split_once(' ') {
+        if start.len() <= 4 && start.bytes().next().is_some_and(|byte| byte >= 128) {
+            return name;
+        }
+    }
+    full_name
+}
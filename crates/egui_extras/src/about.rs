@@ -0,0 +1,201 @@
+use egui::{Context, Grid, Ui, Window};
+
+/// Information about an app, shown by [`AboutWindow`].
+///
+/// Most of it can be filled in from `Cargo.toml`, see [`AboutWindow`].
+#[derive(Clone, Debug, Default)]
+pub struct AppInfo {
+    /// The name of the app.
+    pub name: String,
+
+    /// The version of the app, e.g. `"1.2.3"`.
+    pub version: String,
+
+    /// A short description of the app.
+    pub description: Option<String>,
+
+    /// Where to find out more about the app.
+    pub homepage: Option<String>,
+
+    pub authors: Vec<String>,
+
+    /// The license of the app itself, e.g. `"MIT OR Apache-2.0"`.
+    pub license: Option<String>,
+
+    /// What the app is built with, and under which licenses.
+    pub credits: Vec<Credit>,
+
+    /// The renderer in use, as reported by the backend, e.g. `"wgpu (Vulkan)"`.
+    ///
+    /// With `eframe` you can get this from `eframe::Frame::wgpu_render_state`
+    /// or `eframe::Frame::gl`.
+    pub renderer: Option<String>,
+}
+
+/// A dependency or other third-party work credited in an [`AboutWindow`].
+#[derive(Clone, Debug, Default)]
+pub struct Credit {
+    pub name: String,
+
+    /// E.g. `"MIT"`.
+    pub license: String,
+
+    /// Where the work can be found.
+    pub url: Option<String>,
+}
+
+impl Credit {
+    pub fn new(name: impl Into<String>, license: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            license: license.into(),
+            url: None,
+        }
+    }
+
+    #[inline]
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+}
+
+/// A standard "About" window, showing the name and version of the app,
+/// what it is built with, and the licenses of its dependencies.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut about_open = true;
+/// let info = egui_extras::AppInfo {
+///     name: env!("CARGO_PKG_NAME").to_owned(),
+///     version: env!("CARGO_PKG_VERSION").to_owned(),
+///     homepage: Some("https://github.com/emilk/egui".to_owned()),
+///     license: Some("MIT OR Apache-2.0".to_owned()),
+///     credits: vec![
+///         egui_extras::Credit::new("egui", "MIT OR Apache-2.0")
+///             .url("https://github.com/emilk/egui"),
+///     ],
+///     ..Default::default()
+/// };
+/// egui_extras::AboutWindow::new(&info).show(ctx, &mut about_open);
+/// # });
+/// ```
+pub struct AboutWindow<'a> {
+    info: &'a AppInfo,
+}
+
+impl<'a> AboutWindow<'a> {
+    pub fn new(info: &'a AppInfo) -> Self {
+        Self { info }
+    }
+
+    /// Show the window, if `open` is `true`.
+    ///
+    /// `open` is set to `false` when the user closes the window.
+    pub fn show(self, ctx: &Context, open: &mut bool) {
+        Window::new(format!("About {}", self.info.name))
+            .id(egui::Id::new("egui_extras::AboutWindow"))
+            .open(open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| self.ui(ui));
+    }
+
+    /// Show the contents of the window, e.g. to put them somewhere else.
+    pub fn ui(self, ui: &mut Ui) {
+        let AppInfo {
+            name,
+            version,
+            description,
+            homepage,
+            authors,
+            license,
+            credits,
+            renderer,
+        } = self.info;
+
+        ui.horizontal(|ui| {
+            ui.heading(name);
+            ui.weak(version);
+        });
+        if let Some(description) = description {
+            ui.label(description);
+        }
+        if let Some(homepage) = homepage {
+            ui.hyperlink(homepage);
+        }
+        if !authors.is_empty() {
+            ui.label(format!("By {}", authors.join(", ")));
+        }
+        if let Some(license) = license {
+            ui.label(format!("License: {license}"));
+        }
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 0.0;
+            ui.label("Built with ");
+            ui.hyperlink_to(
+                format!("egui {}", egui_version()),
+                "https://github.com/emilk/egui",
+            );
+        });
+        if let Some(renderer) = renderer {
+            ui.label(format!("Renderer: {renderer}"));
+        }
+        if ui
+            .button("📋 Copy version info")
+            .on_hover_text("Useful for bug reports")
+            .clicked()
+        {
+            ui.ctx().copy_text(self.version_info());
+        }
+
+        if !credits.is_empty() {
+            ui.separator();
+            egui::CollapsingHeader::new("Third-party licenses")
+                .id_salt("egui_extras::AboutWindow::credits")
+                .show(ui, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            Grid::new("egui_extras::AboutWindow::credits_grid")
+                                .num_columns(2)
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for Credit { name, license, url } in credits {
+                                        if let Some(url) = url {
+                                            ui.hyperlink_to(name, url);
+                                        } else {
+                                            ui.label(name);
+                                        }
+                                        ui.label(license);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                });
+        }
+    }
+
+    /// The app and egui versions and the renderer, as plain text.
+    pub fn version_info(&self) -> String {
+        let AppInfo {
+            name,
+            version,
+            renderer,
+            ..
+        } = self.info;
+        let mut text = format!("{name} {version}\negui {}", egui_version());
+        if let Some(renderer) = renderer {
+            text += &format!("\nRenderer: {renderer}");
+        }
+        text
+    }
+}
+
+/// `egui_extras` is always released together with `egui`, with the same version.
+fn egui_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
@@ -21,6 +21,7 @@ pub struct DatePickerButton<'a> {
     format: String,
     highlight_weekends: bool,
     start_end_years: Option<RangeInclusive<i32>>,
+    date_range: Option<RangeInclusive<NaiveDate>>,
 }
 
 impl<'a> DatePickerButton<'a> {
@@ -36,6 +37,7 @@ impl<'a> DatePickerButton<'a> {
             format: "%Y-%m-%d".to_owned(),
             highlight_weekends: true,
             start_end_years: None,
+            date_range: None,
         }
     }
 
@@ -115,6 +117,15 @@ impl<'a> DatePickerButton<'a> {
         self.start_end_years = Some(start_end_years);
         self
     }
+
+    /// Only allow picking dates in this range. (Default: no limit)
+    ///
+    /// Days outside the range are shown disabled in the calendar.
+    #[inline]
+    pub fn date_range(mut self, date_range: RangeInclusive<NaiveDate>) -> Self {
+        self.date_range = Some(date_range);
+        self
+    }
 }
 
 impl Widget for DatePickerButton<'_> {
@@ -182,6 +193,7 @@ impl Widget for DatePickerButton<'_> {
                                 calendar_week: self.calendar_week,
                                 highlight_weekends: self.highlight_weekends,
                                 start_end_years: self.start_end_years,
+                                date_range: self.date_range,
                             }
                             .draw(ui)
                         })
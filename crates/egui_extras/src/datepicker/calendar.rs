@@ -0,0 +1,296 @@
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike as _, Days, Months, NaiveDate, Weekday};
+use egui::{
+    Button, Color32, EventFilter, Grid, Id, Key, Response, RichText, Sense, Ui, Widget, vec2,
+};
+
+use super::{month_data, popup::month_name};
+
+#[derive(Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct CalendarState {
+    /// The month being shown.
+    year: i32,
+    month: u32,
+
+    /// The selection last frame, as days since the common era.
+    ///
+    /// Used to jump to the selected month if the selection is changed from the outside.
+    last_selection: i32,
+    setup: bool,
+}
+
+/// A month calendar grid for picking a date, shown inline.
+///
+/// Unlike [`crate::DatePickerButton`], this does not open a popup,
+/// and the selection changes as soon as the user clicks a day.
+///
+/// When the calendar has keyboard focus, the selection can be moved with the arrow keys
+/// (by day or week), `PageUp`/`PageDown` (by month) and `Home`/`End` (to the start/end of the month).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut date = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let first = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let last = chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+/// ui.add(egui_extras::Calendar::new(&mut date).date_range(first..=last));
+/// # });
+/// ```
+pub struct Calendar<'a> {
+    selection: &'a mut NaiveDate,
+    id_salt: Option<Id>,
+    calendar_week: bool,
+    highlight_weekends: bool,
+    date_range: Option<RangeInclusive<NaiveDate>>,
+}
+
+impl<'a> Calendar<'a> {
+    pub fn new(selection: &'a mut NaiveDate) -> Self {
+        Self {
+            selection,
+            id_salt: None,
+            calendar_week: true,
+            highlight_weekends: true,
+            date_range: None,
+        }
+    }
+
+    /// Add id source.
+    /// Must be set if multiple calendars are in the same Ui.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Show the ISO week numbers. (Default: true)
+    #[inline]
+    pub fn calendar_week(mut self, week: bool) -> Self {
+        self.calendar_week = week;
+        self
+    }
+
+    /// Highlight weekend days. (Default: true)
+    #[inline]
+    pub fn highlight_weekends(mut self, highlight_weekends: bool) -> Self {
+        self.highlight_weekends = highlight_weekends;
+        self
+    }
+
+    /// Only allow picking dates in this range. (Default: no limit)
+    ///
+    /// Days outside the range are shown disabled.
+    /// If the end is before the start, they are swapped.
+    #[inline]
+    pub fn date_range(mut self, date_range: RangeInclusive<NaiveDate>) -> Self {
+        let (start, end) = date_range.into_inner();
+        self.date_range = Some(start.min(end)..=start.max(end));
+        self
+    }
+
+    fn clamp(&self, date: NaiveDate) -> NaiveDate {
+        match &self.date_range {
+            Some(range) => date.clamp(*range.start(), *range.end()),
+            None => date,
+        }
+    }
+
+    fn contains(&self, date: NaiveDate) -> bool {
+        self.date_range
+            .as_ref()
+            .is_none_or(|range| range.contains(&date))
+    }
+}
+
+impl Widget for Calendar<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new("calendar")));
+        let mut state = ui
+            .data_mut(|data| data.get_persisted::<CalendarState>(id))
+            .unwrap_or_default();
+        let mut selection = *self.selection;
+        let mut month = NaiveDate::from_ymd_opt(state.year, state.month, 1)
+            .filter(|_| state.setup && state.last_selection == selection.num_days_from_ce())
+            .unwrap_or_else(|| first_of_month(selection));
+
+        let has_focus = ui.memory(|mem| mem.has_focus(id));
+        if has_focus {
+            ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            let moved = ui.input(|i| {
+                if i.key_pressed(Key::ArrowLeft) {
+                    selection.checked_sub_days(Days::new(1))
+                } else if i.key_pressed(Key::ArrowRight) {
+                    selection.checked_add_days(Days::new(1))
+                } else if i.key_pressed(Key::ArrowUp) {
+                    selection.checked_sub_days(Days::new(7))
+                } else if i.key_pressed(Key::ArrowDown) {
+                    selection.checked_add_days(Days::new(7))
+                } else if i.key_pressed(Key::PageUp) {
+                    selection.checked_sub_months(Months::new(1))
+                } else if i.key_pressed(Key::PageDown) {
+                    selection.checked_add_months(Months::new(1))
+                } else if i.key_pressed(Key::Home) {
+                    Some(first_of_month(selection))
+                } else if i.key_pressed(Key::End) {
+                    Some(last_of_month(selection))
+                } else {
+                    None
+                }
+            });
+            if let Some(moved) = moved {
+                selection = self.clamp(moved);
+                month = first_of_month(selection);
+            }
+        }
+
+        let today = chrono::offset::Utc::now().date_naive();
+        let (min_month, max_month) = match &self.date_range {
+            Some(range) => (first_of_month(*range.start()), first_of_month(*range.end())),
+            None => (NaiveDate::MIN, NaiveDate::MAX),
+        };
+
+        let inner = ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                let prev_month = month.checked_sub_months(Months::new(1));
+                if ui
+                    .add_enabled(min_month < month, Button::new("⏴"))
+                    .on_hover_text("Previous month")
+                    .clicked()
+                {
+                    month = prev_month.unwrap_or(month);
+                }
+                let next_month = month.checked_add_months(Months::new(1));
+                if ui
+                    .add_enabled(month < max_month, Button::new("⏵"))
+                    .on_hover_text("Next month")
+                    .clicked()
+                {
+                    month = next_month.unwrap_or(month);
+                }
+                ui.strong(format!("{} {}", month_name(month.month()), month.year()));
+            });
+
+            let mut clicked = None;
+            Grid::new(id.with("grid"))
+                .spacing(vec2(2.0, 2.0))
+                .show(ui, |ui| {
+                    if self.calendar_week {
+                        ui.weak("Wk");
+                    }
+                    //TODO(elwerene): Locale
+                    for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                        ui.label(name);
+                    }
+                    ui.end_row();
+
+                    for week in month_data(month.year(), month.month()) {
+                        if self.calendar_week {
+                            ui.weak(week.number.to_string());
+                        }
+                        for day in week.days {
+                            let mut text = RichText::new(day.day().to_string());
+                            if day.month() != month.month() {
+                                text = text.weak();
+                            }
+                            let is_weekend = matches!(day.weekday(), Weekday::Sat | Weekday::Sun);
+                            let mut button = Button::new(text)
+                                .min_size(vec2(24.0, 20.0))
+                                .selected(day == selection);
+                            if is_weekend && self.highlight_weekends && day != selection {
+                                button = button.fill(if ui.visuals().dark_mode {
+                                    Color32::DARK_RED
+                                } else {
+                                    Color32::LIGHT_RED
+                                });
+                            }
+                            let response = ui.add_enabled(self.contains(day), button);
+
+                            if day == today {
+                                // Encircle today's date
+                                let stroke = ui.visuals().widgets.inactive.fg_stroke;
+                                ui.painter()
+                                    .circle_stroke(response.rect.center(), 8.0, stroke);
+                            }
+                            if has_focus && day == selection {
+                                let stroke = ui.visuals().selection.stroke;
+                                ui.painter().rect_stroke(
+                                    response.rect,
+                                    ui.visuals().widgets.active.corner_radius,
+                                    stroke,
+                                    egui::StrokeKind::Outside,
+                                );
+                            }
+                            if response.clicked() {
+                                clicked = Some(day);
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+            clicked
+        });
+
+        let mut response = ui.interact(inner.response.rect, id, Sense::focusable_noninteractive());
+
+        if let Some(clicked) = inner.inner {
+            selection = clicked;
+            month = first_of_month(clicked);
+            response.request_focus();
+        }
+
+        if selection != *self.selection {
+            *self.selection = selection;
+            response.mark_changed();
+        }
+
+        state = CalendarState {
+            year: month.year(),
+            month: month.month(),
+            last_selection: selection.num_days_from_ce(),
+            setup: true,
+        };
+        ui.data_mut(|data| data.insert_persisted(id, state));
+
+        response
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn last_of_month(date: NaiveDate) -> NaiveDate {
+    first_of_month(date)
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverted_date_range() {
+        let date = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let mut selection = date(1);
+        let calendar = Calendar::new(&mut selection).date_range(date(20)..=date(10));
+
+        assert_eq!(calendar.clamp(date(1)), date(10));
+        assert_eq!(calendar.clamp(date(15)), date(15));
+        assert_eq!(calendar.clamp(date(31)), date(20));
+        assert!(calendar.contains(date(15)));
+        assert!(!calendar.contains(date(21)));
+    }
+}
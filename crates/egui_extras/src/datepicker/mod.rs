@@ -1,7 +1,9 @@
 mod button;
+mod calendar;
 mod popup;
 
 pub use button::DatePickerButton;
+pub use calendar::Calendar;
 use chrono::{Datelike as _, Duration, NaiveDate, Weekday};
 
 #[derive(Debug)]
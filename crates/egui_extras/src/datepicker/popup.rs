@@ -36,6 +36,7 @@ pub(crate) struct DatePickerPopup<'a> {
     pub calendar_week: bool,
     pub highlight_weekends: bool,
     pub start_end_years: Option<std::ops::RangeInclusive<i32>>,
+    pub date_range: Option<std::ops::RangeInclusive<NaiveDate>>,
 }
 
 impl DatePickerPopup<'_> {
@@ -338,7 +339,12 @@ impl DatePickerPopup<'_> {
                                                                 text_color.linear_multiply(0.5);
                                                         };
 
-                                                        let button_response = ui.add(
+                                                        let in_range =
+                                                            self.date_range.as_ref().is_none_or(
+                                                                |range| range.contains(&day),
+                                                            );
+                                                        let button_response = ui.add_enabled(
+                                                            in_range,
                                                             Button::new(
                                                                 RichText::new(
                                                                     day.day().to_string(),
@@ -396,12 +402,16 @@ impl DatePickerPopup<'_> {
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                 if ui.button("Save").clicked() {
-                                    *self.selection = NaiveDate::from_ymd_opt(
+                                    let mut date = NaiveDate::from_ymd_opt(
                                         popup_state.year,
                                         popup_state.month,
                                         popup_state.day,
                                     )
                                     .expect("Could not create NaiveDate");
+                                    if let Some(range) = &self.date_range {
+                                        date = date.clamp(*range.start(), *range.end());
+                                    }
+                                    *self.selection = date;
                                     saved = true;
                                     close = true;
                                 }
@@ -424,7 +434,7 @@ impl DatePickerPopup<'_> {
     }
 }
 
-fn month_name(i: u32) -> &'static str {
+pub(super) fn month_name(i: u32) -> &'static str {
     match i {
         1 => "January",
         2 => "February",
@@ -9,6 +9,7 @@
 #![allow(clippy::float_cmp)]
 #![allow(clippy::manual_range_contains)]
 
+mod about;
 #[cfg(feature = "chrono")]
 mod datepicker;
 
@@ -22,8 +23,9 @@ mod sizing;
 mod strip;
 mod table;
 
+pub use crate::about::{AboutWindow, AppInfo, Credit};
 #[cfg(feature = "chrono")]
-pub use crate::datepicker::DatePickerButton;
+pub use crate::datepicker::{Calendar, DatePickerButton};
 
 pub(crate) use crate::layout::StripLayout;
 pub use crate::sizing::Size;
@@ -32,6 +34,9 @@ pub use crate::table::*;
 
 pub use loaders::install_image_loaders;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "http"))]
+pub use loaders::install_image_loaders_with_disk_cache;
+
 // ---------------------------------------------------------------------------
 
 /// Panic in debug builds, log otherwise.
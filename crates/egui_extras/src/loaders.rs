@@ -1,5 +1,3 @@
-// TODO(jprochazk): automatic cache eviction
-
 /// Installs a set of image loaders.
 ///
 /// Calling this enables the use of [`egui::Image`] and [`egui::Ui::image`].
@@ -10,6 +8,8 @@
 /// - `http` feature: `http(s)://` loader
 /// - `image` feature: Loader of png, jpeg etc using the [`image`] crate
 /// - `svg` feature: `.svg` loader
+/// - `gif`, `webp` and `apng` features: animated images, see [`egui::Image::animation_paused`]
+/// - `blurhash` feature: `blurhash://` placeholders, see [`egui::Image::placeholder`]
 ///
 /// Calling this multiple times on the same [`egui::Context`] is safe.
 /// It will never install duplicate loaders.
@@ -49,11 +49,18 @@
 /// not one of the supported and enabled image formats, the loader will return [`LoadError::NotSupported`][`egui::load::LoadError::NotSupported`],
 /// allowing a different loader to attempt to load the image.
 ///
+/// The `image`, `gif`, `webp` and `apng` loaders decode on a few background threads.
+/// Images that are no longer shown are skipped if they haven't been decoded yet.
+/// Use [`egui::Context::image_load_state`] to see how far along an image is.
+///
 /// The `svg` loader is an [`ImageLoader`][`egui::load::ImageLoader`].
 /// It will attempt to load any URI with an `svg` extension. It will _not_ attempt to load a URI without an extension.
 /// The content type specified by [`BytesPoll::Ready::mime`][`egui::load::BytesPoll::Ready::mime`] always takes precedence,
 /// and must include `svg` for it to be considered supported. For example, `image/svg+xml` would be loaded by the `svg` loader.
 ///
+/// The `blurhash` loader is an [`ImageLoader`][`egui::load::ImageLoader`].
+/// It will decode `blurhash://<hash>` URIs into small blurry images, to show using [`egui::Image::placeholder`].
+///
 /// See [`egui::load`] for more information about how loaders work.
 pub fn install_image_loaders(ctx: &egui::Context) {
     #[cfg(all(not(target_arch = "wasm32"), feature = "file"))]
@@ -90,12 +97,26 @@ pub fn install_image_loaders(ctx: &egui::Context) {
         log::trace!("installed WebPLoader");
     }
 
+    #[cfg(feature = "apng")]
+    if !ctx.is_loader_installed(self::apng_loader::ApngLoader::ID) {
+        ctx.add_image_loader(std::sync::Arc::new(self::apng_loader::ApngLoader::default()));
+        log::trace!("installed ApngLoader");
+    }
+
     #[cfg(feature = "svg")]
     if !ctx.is_loader_installed(self::svg_loader::SvgLoader::ID) {
         ctx.add_image_loader(std::sync::Arc::new(self::svg_loader::SvgLoader::default()));
         log::trace!("installed SvgLoader");
     }
 
+    #[cfg(feature = "blurhash")]
+    if !ctx.is_loader_installed(self::blurhash_loader::BlurhashLoader::ID) {
+        ctx.add_image_loader(std::sync::Arc::new(
+            self::blurhash_loader::BlurhashLoader::default(),
+        ));
+        log::trace!("installed BlurhashLoader");
+    }
+
     #[cfg(all(
         any(target_arch = "wasm32", not(feature = "file")),
         not(feature = "http"),
@@ -107,6 +128,41 @@ pub fn install_image_loaders(ctx: &egui::Context) {
     let _ = ctx;
 }
 
+/// Like [`install_image_loaders`], but images downloaded over HTTP are also stored in `cache_dir`,
+/// so that they survive restarts.
+///
+/// Each file is stored together with its `ETag`, which is sent to the server when the image is loaded again.
+/// If the server responds that the image is unchanged, or can't be reached, the stored file is used instead.
+/// Files without an `ETag` are not stored.
+///
+/// This first removes the least recently used files until `cache_dir` takes at most `max_disk_bytes`.
+///
+/// Call this before [`install_image_loaders`], or the `http` loader is already installed without the disk cache.
+/// To limit how much memory the loaded images take, see [`egui::Options::image_memory_budget`].
+///
+/// Not available on the web, where the browser caches downloads.
+#[cfg(all(not(target_arch = "wasm32"), feature = "http"))]
+pub fn install_image_loaders_with_disk_cache(
+    ctx: &egui::Context,
+    cache_dir: impl Into<std::path::PathBuf>,
+    max_disk_bytes: u64,
+) {
+    if !ctx.is_loader_installed(self::ehttp_loader::EhttpLoader::ID) {
+        ctx.add_bytes_loader(std::sync::Arc::new(
+            self::ehttp_loader::EhttpLoader::with_disk_cache(cache_dir.into(), max_disk_bytes),
+        ));
+        log::trace!("installed EhttpLoader with disk cache");
+    }
+
+    install_image_loaders(ctx);
+}
+
+#[cfg(feature = "apng")]
+mod apng_loader;
+#[cfg(feature = "blurhash")]
+mod blurhash_loader;
+#[cfg(feature = "image")]
+mod decode_pool;
 #[cfg(not(target_arch = "wasm32"))]
 mod file_loader;
 
@@ -0,0 +1,205 @@
+use ahash::HashMap;
+use egui::{
+    ColorImage, FrameDurations, Id, decode_animated_image_uri, has_apng_header,
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, LoadState, SizeHint},
+    mutex::Mutex,
+};
+use image::{AnimationDecoder as _, codecs::png::PngDecoder};
+use std::{
+    io::Cursor,
+    mem::size_of,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering::Relaxed},
+    },
+    task::Poll,
+    time::Duration,
+};
+
+/// Array of Frames and the duration for how long each frame should be shown
+#[derive(Debug, Clone)]
+pub struct AnimatedImage {
+    frames: Vec<Arc<ColorImage>>,
+    frame_durations: FrameDurations,
+}
+
+impl AnimatedImage {
+    fn load_apng(data: &[u8]) -> Result<Self, String> {
+        let decoder = PngDecoder::new(Cursor::new(data))
+            .and_then(|decoder| decoder.apng())
+            .map_err(|err| format!("Failed to decode apng: {err}"))?;
+        let mut images = vec![];
+        let mut durations = vec![];
+        for frame in decoder.into_frames() {
+            let frame = frame.map_err(|err| format!("Failed to decode apng: {err}"))?;
+            let img = frame.buffer();
+            let pixels = img.as_flat_samples();
+
+            let delay: Duration = frame.delay().into();
+            images.push(Arc::new(ColorImage::from_rgba_unmultiplied(
+                [img.width() as usize, img.height() as usize],
+                pixels.as_slice(),
+            )));
+            durations.push(delay);
+        }
+        if images.is_empty() {
+            return Err("Failed to decode apng: no frames".to_owned());
+        }
+        Ok(Self {
+            frames: images,
+            frame_durations: FrameDurations::new(durations),
+        })
+    }
+
+    pub fn byte_len(&self) -> usize {
+        size_of::<Self>()
+            + self
+                .frames
+                .iter()
+                .map(|image| {
+                    image.pixels.len() * size_of::<egui::Color32>() + size_of::<Duration>()
+                })
+                .sum::<usize>()
+    }
+
+    /// Gets image at index
+    pub fn get_image(&self, index: usize) -> Arc<ColorImage> {
+        self.frames[index % self.frames.len()].clone()
+    }
+}
+
+struct Entry {
+    /// The last pass this image was requested in.
+    ///
+    /// Images that are still decoding are canceled if they are no longer requested.
+    last_used: u64,
+
+    result: Poll<Result<Arc<AnimatedImage>, String>>,
+}
+
+#[derive(Default)]
+pub struct ApngLoader {
+    pass_index: AtomicU64,
+    cache: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+impl ApngLoader {
+    pub const ID: &'static str = egui::generate_loader_id!(ApngLoader);
+}
+
+impl ImageLoader for ApngLoader {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn load(&self, ctx: &egui::Context, frame_uri: &str, _: SizeHint) -> ImageLoadResult {
+        let (image_uri, frame_index) =
+            decode_animated_image_uri(frame_uri).map_err(|_err| LoadError::NotSupported)?;
+        let mut cache = self.cache.lock();
+        if let Some(entry) = cache.get_mut(image_uri) {
+            entry.last_used = self.pass_index.load(Relaxed);
+            match &entry.result {
+                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready {
+                    image: image.get_image(frame_index),
+                }),
+                Poll::Ready(Err(err)) => Err(LoadError::Loading(err.clone())),
+                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
+            }
+        } else {
+            match ctx.try_load_bytes(image_uri) {
+                Ok(BytesPoll::Ready { bytes, .. }) => {
+                    if !has_apng_header(&bytes) {
+                        return Err(LoadError::NotSupported);
+                    }
+                    cache.insert(
+                        image_uri.to_owned(),
+                        Entry {
+                            last_used: self.pass_index.load(Relaxed),
+                            result: Poll::Pending,
+                        },
+                    );
+                    drop(cache);
+
+                    super::decode_pool::spawn({
+                        let ctx = ctx.clone();
+                        let cache = self.cache.clone();
+                        let image_uri = image_uri.to_owned();
+                        move || {
+                            if !cache.lock().contains_key(&image_uri) {
+                                log::trace!("canceled loading {image_uri:?}");
+                                return;
+                            }
+                            log::trace!("started loading {image_uri:?}");
+                            let result = AnimatedImage::load_apng(&bytes).map(Arc::new);
+                            if let Ok(image) = &result {
+                                ctx.data_mut(|data| {
+                                    *data.get_temp_mut_or_default(Id::new(&image_uri)) =
+                                        image.frame_durations.clone();
+                                });
+                            }
+                            log::trace!("finished loading {image_uri:?}");
+                            if let Some(entry) = cache.lock().get_mut(&image_uri) {
+                                entry.result = Poll::Ready(result);
+                                ctx.request_repaint();
+                            }
+                        }
+                    });
+
+                    // On the web, the image is decoded right away:
+                    self.load(ctx, frame_uri, SizeHint::default())
+                }
+                Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    fn forget(&self, uri: &str) {
+        let _ = self.cache.lock().remove(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn end_pass(&self, pass_index: u64) {
+        self.pass_index.store(pass_index, Relaxed);
+
+        // Cancel decoding the images that are no longer shown:
+        self.cache
+            .lock()
+            .retain(|_uri, entry| entry.result.is_ready() || pass_index <= entry.last_used + 1);
+    }
+
+    fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(entry_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, entry_byte_size))
+    }
+
+    fn has_pending(&self) -> bool {
+        self.cache
+            .lock()
+            .values()
+            .any(|entry| entry.result.is_pending())
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let uri = decode_animated_image_uri(uri).map_or(uri, |(uri, _frame_index)| uri);
+        self.cache.lock().get(uri).map(|entry| match &entry.result {
+            Poll::Ready(Ok(_)) => LoadState::Ready,
+            Poll::Ready(Err(err)) => LoadState::Failed(err.clone()),
+            Poll::Pending => LoadState::Decoding,
+        })
+    }
+}
+
+fn entry_byte_size(entry: &Entry) -> usize {
+    match &entry.result {
+        Poll::Ready(Ok(image)) => image.byte_len(),
+        Poll::Ready(Err(err)) => err.len(),
+        Poll::Pending => 0,
+    }
+}
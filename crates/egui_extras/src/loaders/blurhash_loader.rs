@@ -0,0 +1,173 @@
+use ahash::HashMap;
+use egui::{
+    Color32, ColorImage,
+    ecolor::{gamma_u8_from_linear_f32, linear_f32_from_gamma_u8},
+    load::{ImageLoadResult, ImageLoader, ImagePoll, LoadError, LoadState, SizeHint},
+    mutex::Mutex,
+};
+use std::{f32::consts::PI, mem::size_of, sync::Arc};
+
+const PROTOCOL: &str = "blurhash://";
+
+/// Decodes [blurhash](https://blurha.sh) placeholders, e.g. `blurhash://LEHV6nWB2yk8pyo0adR*.7kCMdnj`.
+///
+/// The hash is tiny, so it can be sent along with the URL of the actual image,
+/// and shown using [`egui::Image::placeholder`] while that is loading.
+#[derive(Default)]
+pub struct BlurhashLoader {
+    cache: Mutex<HashMap<String, Result<Arc<ColorImage>, String>>>,
+}
+
+impl BlurhashLoader {
+    pub const ID: &'static str = egui::generate_loader_id!(BlurhashLoader);
+
+    /// The size of the decoded image. It is blurry anyway, so it is stretched to fit.
+    const SIZE: usize = 32;
+}
+
+impl ImageLoader for BlurhashLoader {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn load(&self, _ctx: &egui::Context, uri: &str, _: SizeHint) -> ImageLoadResult {
+        let Some(hash) = uri.strip_prefix(PROTOCOL) else {
+            return Err(LoadError::NotSupported);
+        };
+        let result = self
+            .cache
+            .lock()
+            .entry(uri.to_owned())
+            .or_insert_with(|| decode(hash, Self::SIZE, Self::SIZE).map(Arc::new))
+            .clone();
+        match result {
+            Ok(image) => Ok(ImagePoll::Ready { image }),
+            Err(err) => Err(LoadError::Loading(err)),
+        }
+    }
+
+    fn forget(&self, uri: &str) {
+        let _ = self.cache.lock().remove(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(result_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, result_byte_size))
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        self.cache.lock().get(uri).map(|result| match result {
+            Ok(_) => LoadState::Ready,
+            Err(err) => LoadState::Failed(err.clone()),
+        })
+    }
+}
+
+const BASE83: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+fn decode_base83(chars: &[u8]) -> Result<u32, String> {
+    chars.iter().try_fold(0, |value, c| {
+        let digit = BASE83
+            .iter()
+            .position(|b| b == c)
+            .ok_or_else(|| format!("Invalid blurhash character {:?}", char::from(*c)))?;
+        Ok(value * 83 + digit as u32)
+    })
+}
+
+/// Decode a blurhash into an image of the given size.
+fn decode(hash: &str, width: usize, height: usize) -> Result<ColorImage, String> {
+    let hash = hash.as_bytes();
+    if hash.len() < 6 {
+        return Err("The blurhash is too short".to_owned());
+    }
+
+    let size_flag = decode_base83(&hash[0..1])? as usize;
+    let (num_x, num_y) = (size_flag % 9 + 1, size_flag / 9 + 1);
+    if hash.len() != 4 + 2 * num_x * num_y {
+        return Err(format!(
+            "The blurhash should be {} characters long, but is {}",
+            4 + 2 * num_x * num_y,
+            hash.len()
+        ));
+    }
+    let max_value = (decode_base83(&hash[1..2])? + 1) as f32 / 166.0;
+
+    let mut colors = Vec::with_capacity(num_x * num_y);
+    let dc = decode_base83(&hash[2..6])?;
+    colors.push([
+        linear_f32_from_gamma_u8((dc >> 16) as u8),
+        linear_f32_from_gamma_u8((dc >> 8) as u8),
+        linear_f32_from_gamma_u8(dc as u8),
+    ]);
+    for ac in hash[6..].chunks_exact(2) {
+        let ac = decode_base83(ac)?;
+        let component = |quantized: u32| {
+            let value = (quantized as f32 - 9.0) / 9.0;
+            value.signum() * value * value * max_value
+        };
+        colors.push([
+            component(ac / (19 * 19)),
+            component((ac / 19) % 19),
+            component(ac % 19),
+        ]);
+    }
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let mut rgb = [0.0; 3];
+            for j in 0..num_y {
+                for i in 0..num_x {
+                    let basis = (PI * x as f32 * i as f32 / width as f32).cos()
+                        * (PI * y as f32 * j as f32 / height as f32).cos();
+                    let color = colors[i + j * num_x];
+                    for c in 0..3 {
+                        rgb[c] += color[c] * basis;
+                    }
+                }
+            }
+            pixels.push(Color32::from_rgb(
+                gamma_u8_from_linear_f32(rgb[0]),
+                gamma_u8_from_linear_f32(rgb[1]),
+                gamma_u8_from_linear_f32(rgb[2]),
+            ));
+        }
+    }
+    Ok(ColorImage::new([width, height], pixels))
+}
+
+fn result_byte_size(result: &Result<Arc<ColorImage>, String>) -> usize {
+    match result {
+        Ok(image) => image.pixels.len() * size_of::<Color32>(),
+        Err(err) => err.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        // A single gray color, with no variation:
+        let image = decode("00Q]+@", 4, 3).unwrap();
+        assert_eq!(image.size, [4, 3]);
+        assert!(image.pixels.iter().all(|&pixel| pixel == image.pixels[0]));
+
+        let image = decode("LEHV6nWB2yk8pyo0adR*.7kCMdnj", 32, 32).unwrap();
+        assert_eq!(image.size, [32, 32]);
+        assert_ne!(image.pixels[0], image.pixels[32 * 32 - 1]);
+
+        assert!(decode("LEHV6nWB2yk8", 32, 32).is_err(), "Wrong length");
+        assert!(decode("00Q]+\"", 32, 32).is_err(), "Invalid character");
+    }
+}
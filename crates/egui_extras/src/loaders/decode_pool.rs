@@ -0,0 +1,52 @@
+//! A few background threads for decoding images, so that big images don't make the ui stutter.
+
+/// Decode on one of the background threads, or right away on the web, where there are no threads.
+///
+/// The jobs are run in the order they were spawned.
+/// Each job should check that its result is still wanted before starting to decode,
+/// so that images that are no longer shown are skipped.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn(job: impl FnOnce() + Send + 'static) {
+    use std::sync::{Arc, OnceLock, mpsc};
+
+    type Job = Box<dyn FnOnce() + Send + 'static>;
+
+    static SENDER: OnceLock<mpsc::Sender<Job>> = OnceLock::new();
+
+    let sender = SENDER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(egui::mutex::Mutex::new(receiver));
+
+        // Leave some cores for the ui and the rest of the app:
+        let num_threads = std::thread::available_parallelism()
+            .map_or(2, |n| n.get() / 2)
+            .clamp(1, 4);
+        for i in 0..num_threads {
+            let receiver = receiver.clone();
+            std::thread::Builder::new()
+                .name(format!("egui_extras::decode_pool[{i}]"))
+                .spawn(move || {
+                    loop {
+                        let job = receiver.lock().recv();
+                        match job {
+                            Ok(job) => job(),
+                            Err(mpsc::RecvError) => break,
+                        }
+                    }
+                })
+                .expect("failed to spawn thread");
+        }
+        sender
+    });
+
+    let job: Job = Box::new(job);
+    if let Err(mpsc::SendError(job)) = sender.send(job) {
+        job(); // Can't happen, since the workers never stop
+    }
+}
+
+/// Decode on one of the background threads, or right away on the web, where there are no threads.
+#[cfg(target_arch = "wasm32")]
+pub fn spawn(job: impl FnOnce() + Send + 'static) {
+    job();
+}
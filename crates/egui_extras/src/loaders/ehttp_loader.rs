@@ -1,6 +1,6 @@
 use ahash::HashMap;
 use egui::{
-    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError},
+    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError, LoadState},
     mutex::Mutex,
 };
 use std::{sync::Arc, task::Poll};
@@ -42,10 +42,183 @@ type Entry = Poll<Result<File, String>>;
 #[derive(Default)]
 pub struct EhttpLoader {
     cache: Arc<Mutex<HashMap<String, Entry>>>,
+
+    /// Where downloaded files are kept between runs, if anywhere.
+    #[cfg(not(target_arch = "wasm32"))]
+    disk_cache: Option<Arc<DiskCache>>,
 }
 
 impl EhttpLoader {
     pub const ID: &'static str = egui::generate_loader_id!(EhttpLoader);
+
+    /// Also store downloaded files in `dir`, see [`crate::install_image_loaders_with_disk_cache`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_disk_cache(dir: std::path::PathBuf, max_disk_bytes: u64) -> Self {
+        let disk_cache = Arc::new(DiskCache { dir });
+        std::thread::Builder::new()
+            .name("egui_extras::EhttpLoader::trim_disk_cache".to_owned())
+            .spawn({
+                let disk_cache = disk_cache.clone();
+                move || disk_cache.trim(max_disk_bytes)
+            })
+            .expect("failed to spawn thread");
+        Self {
+            cache: Default::default(),
+            disk_cache: Some(disk_cache),
+        }
+    }
+}
+
+/// Stores downloaded files on disk together with their `ETag`, so that they survive restarts.
+///
+/// Each URI is stored in two files, named after the hash of the URI:
+/// `<hash>.bytes` with the contents, and `<hash>.meta` with the URI, the `ETag`, and the mime type,
+/// one per line.
+#[cfg(not(target_arch = "wasm32"))]
+struct DiskCache {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DiskCache {
+    fn path(&self, uri: &str, extension: &str) -> std::path::PathBuf {
+        self.dir
+            .join(format!("{:016x}.{extension}", egui::util::hash(uri)))
+    }
+
+    /// The stored `ETag` and file, if any.
+    fn read(&self, uri: &str) -> Option<(String, File)> {
+        let meta = std::fs::read_to_string(self.path(uri, "meta")).ok()?;
+        let mut lines = meta.lines();
+        if lines.next()? != uri {
+            return None; // hash collision
+        }
+        let etag = lines.next()?.to_owned();
+        let mime = lines
+            .next()
+            .filter(|mime| !mime.is_empty())
+            .map(ToOwned::to_owned);
+        let bytes = std::fs::read(self.path(uri, "bytes")).ok()?;
+        Some((
+            etag,
+            File {
+                bytes: bytes.into(),
+                mime,
+            },
+        ))
+    }
+
+    fn write(&self, uri: &str, etag: &str, file: &File) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        // The meta file is written last, so that it is only there if the contents are complete.
+        let meta_path = self.path(uri, "meta");
+        let _ = std::fs::remove_file(&meta_path);
+        std::fs::write(self.path(uri, "bytes"), &file.bytes)?;
+        let mime = file.mime.as_deref().unwrap_or_default();
+        std::fs::write(meta_path, format!("{uri}\n{etag}\n{mime}\n"))
+    }
+
+    /// Mark the stored file as recently used, see [`Self::trim`].
+    fn touch(&self, uri: &str) {
+        let _ = std::fs::File::options()
+            .write(true)
+            .open(self.path(uri, "bytes"))
+            .and_then(|file| file.set_modified(std::time::SystemTime::now()));
+    }
+
+    /// Download `uri`, unless the stored version is still up to date.
+    ///
+    /// If the server can't be reached, the stored version is used.
+    fn fetch(&self, uri: &str) -> Result<File, String> {
+        let cached = self.read(uri);
+
+        let mut request = ehttp::Request::get(uri);
+        if let Some((etag, _)) = &cached {
+            request.headers.insert("If-None-Match", etag);
+        }
+
+        let response = match ehttp::fetch_blocking(&request) {
+            Ok(response) => response,
+            Err(err) => {
+                // Log details; return summary
+                log::error!("Failed to load {uri:?}: {err}");
+                return match cached {
+                    Some((_, file)) => {
+                        log::warn!("Using the version of {uri:?} stored on disk");
+                        self.touch(uri);
+                        Ok(file)
+                    }
+                    None => Err(format!("Failed to load {uri:?}")),
+                };
+            }
+        };
+
+        if response.status == 304 {
+            if let Some((_, file)) = cached {
+                log::trace!("{uri:?} is not modified, using the version stored on disk");
+                self.touch(uri);
+                return Ok(file);
+            }
+        }
+
+        let etag = response.headers.get("etag").map(ToOwned::to_owned);
+        let file = File::from_response(uri, response)?;
+        if let Some(etag) = etag {
+            if let Err(err) = self.write(uri, &etag, &file) {
+                log::warn!("Failed to store {uri:?} on disk: {err}");
+            }
+        }
+        Ok(file)
+    }
+
+    /// Remove the least recently used files until all files take at most `max_bytes`.
+    fn trim(&self, max_bytes: u64) {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        let mut files: Vec<(std::time::SystemTime, u64, std::path::PathBuf)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension()? != "bytes" {
+                    return None;
+                }
+                let metadata = entry.metadata().ok()?;
+                Some((metadata.modified().ok()?, metadata.len(), path))
+            })
+            .collect();
+        files.sort();
+
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        for (_, len, path) in files {
+            if total <= max_bytes {
+                break;
+            }
+            log::trace!("Removing {path:?} from the disk cache");
+            let _ = std::fs::remove_file(path.with_extension("meta"));
+            let _ = std::fs::remove_file(&path);
+            total -= len;
+        }
+    }
+}
+
+/// Store the result of loading `uri`, unless it was forgotten while loading.
+fn finish_loading(
+    ctx: &egui::Context,
+    cache: &Mutex<HashMap<String, Entry>>,
+    uri: &str,
+    result: Result<File, String>,
+) {
+    let mut cache = cache.lock();
+    if let Some(entry) = cache.get_mut(uri) {
+        *entry = Poll::Ready(result);
+        ctx.request_repaint();
+        log::trace!("Finished loading {uri:?}");
+    } else {
+        log::trace!(
+            "Canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading."
+        );
+    }
 }
 
 const PROTOCOLS: &[&str] = &["http://", "https://"];
@@ -82,32 +255,31 @@ impl BytesLoader for EhttpLoader {
             cache.insert(uri.clone(), Poll::Pending);
             drop(cache);
 
-            ehttp::fetch(ehttp::Request::get(uri.clone()), {
-                let ctx = ctx.clone();
-                let cache = self.cache.clone();
-                move |response| {
-                    let result = match response {
-                        Ok(response) => File::from_response(&uri, response),
-                        Err(err) => {
-                            // Log details; return summary
-                            log::error!("Failed to load {uri:?}: {err}");
-                            Err(format!("Failed to load {uri:?}"))
-                        }
-                    };
-                    let mut cache = cache.lock();
-                    if let std::collections::hash_map::Entry::Occupied(mut entry) =
-                        cache.entry(uri.clone())
-                    {
-                        let entry = entry.get_mut();
-                        *entry = Poll::Ready(result);
-                        ctx.request_repaint();
-                        log::trace!("Finished loading {uri:?}");
-                    } else {
-                        log::trace!(
-                            "Canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading."
-                        );
+            let ctx = ctx.clone();
+            let cache = self.cache.clone();
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(disk_cache) = self.disk_cache.clone() {
+                std::thread::Builder::new()
+                    .name(format!("egui_extras::EhttpLoader::load({uri:?})"))
+                    .spawn(move || {
+                        let result = disk_cache.fetch(&uri);
+                        finish_loading(&ctx, &cache, &uri, result);
+                    })
+                    .expect("failed to spawn thread");
+                return Ok(BytesPoll::Pending { size: None });
+            }
+
+            ehttp::fetch(ehttp::Request::get(uri.clone()), move |response| {
+                let result = match response {
+                    Ok(response) => File::from_response(&uri, response),
+                    Err(err) => {
+                        // Log details; return summary
+                        log::error!("Failed to load {uri:?}: {err}");
+                        Err(format!("Failed to load {uri:?}"))
                     }
-                }
+                };
+                finish_loading(&ctx, &cache, &uri, result);
             });
 
             Ok(BytesPoll::Pending { size: None })
@@ -123,20 +295,62 @@ impl BytesLoader for EhttpLoader {
     }
 
     fn byte_size(&self) -> usize {
-        self.cache
-            .lock()
-            .values()
-            .map(|entry| match entry {
-                Poll::Ready(Ok(file)) => {
-                    file.bytes.len() + file.mime.as_ref().map_or(0, |m| m.len())
-                }
-                Poll::Ready(Err(err)) => err.len(),
-                _ => 0,
-            })
-            .sum()
+        self.cache.lock().values().map(entry_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, entry_byte_size))
     }
 
     fn has_pending(&self) -> bool {
         self.cache.lock().values().any(|entry| entry.is_pending())
     }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        self.cache.lock().get(uri).map(|entry| match entry {
+            Poll::Ready(Ok(_)) => LoadState::Ready,
+            Poll::Ready(Err(err)) => LoadState::Failed(err.clone()),
+            Poll::Pending => LoadState::Fetching,
+        })
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[expect(clippy::disallowed_methods)] // We don't want a dependency on `tempdir` just for this
+    fn test_disk_cache() {
+        let dir = std::env::temp_dir().join(format!("egui_disk_cache_{}", std::process::id()));
+        let disk_cache = DiskCache { dir: dir.clone() };
+        let uri = "https://example.com/image.png";
+        assert!(disk_cache.read(uri).is_none());
+
+        let file = File {
+            bytes: vec![1, 2, 3].into(),
+            mime: Some("image/png".to_owned()),
+        };
+        disk_cache.write(uri, "\"abc\"", &file).unwrap();
+        let (etag, cached) = disk_cache.read(uri).unwrap();
+        assert_eq!(etag, "\"abc\"");
+        assert_eq!(&*cached.bytes, &[1, 2, 3]);
+        assert_eq!(cached.mime.as_deref(), Some("image/png"));
+        assert!(disk_cache.read("https://example.com/other.png").is_none());
+
+        disk_cache.trim(3);
+        assert!(disk_cache.read(uri).is_some(), "Within budget");
+        disk_cache.trim(2);
+        assert!(disk_cache.read(uri).is_none(), "Over budget");
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
+
+fn entry_byte_size(entry: &Entry) -> usize {
+    match entry {
+        Poll::Ready(Ok(file)) => file.bytes.len() + file.mime.as_ref().map_or(0, |m| m.len()),
+        Poll::Ready(Err(err)) => err.len(),
+        Poll::Pending => 0,
+    }
 }
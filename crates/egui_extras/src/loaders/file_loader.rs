@@ -1,6 +1,6 @@
 use ahash::HashMap;
 use egui::{
-    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError},
+    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError, LoadState},
     mutex::Mutex,
 };
 use std::{sync::Arc, task::Poll, thread};
@@ -121,20 +121,30 @@ impl BytesLoader for FileLoader {
     }
 
     fn byte_size(&self) -> usize {
-        self.cache
-            .lock()
-            .values()
-            .map(|entry| match entry {
-                Poll::Ready(Ok(file)) => {
-                    file.bytes.len() + file.mime.as_ref().map_or(0, |m| m.len())
-                }
-                Poll::Ready(Err(err)) => err.len(),
-                _ => 0,
-            })
-            .sum()
+        self.cache.lock().values().map(entry_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, entry_byte_size))
     }
 
     fn has_pending(&self) -> bool {
         self.cache.lock().values().any(|entry| entry.is_pending())
     }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        self.cache.lock().get(uri).map(|entry| match entry {
+            Poll::Ready(Ok(_)) => LoadState::Ready,
+            Poll::Ready(Err(err)) => LoadState::Failed(err.clone()),
+            Poll::Pending => LoadState::Fetching,
+        })
+    }
+}
+
+fn entry_byte_size(entry: &Entry) -> usize {
+    match entry {
+        Poll::Ready(Ok(file)) => file.bytes.len() + file.mime.as_ref().map_or(0, |m| m.len()),
+        Poll::Ready(Err(err)) => err.len(),
+        Poll::Pending => 0,
+    }
 }
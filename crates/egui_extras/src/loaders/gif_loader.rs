@@ -1,11 +1,20 @@
 use ahash::HashMap;
 use egui::{
     ColorImage, FrameDurations, Id, decode_animated_image_uri, has_gif_magic_header,
-    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, LoadState, SizeHint},
     mutex::Mutex,
 };
 use image::AnimationDecoder as _;
-use std::{io::Cursor, mem::size_of, sync::Arc, time::Duration};
+use std::{
+    io::Cursor,
+    mem::size_of,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering::Relaxed},
+    },
+    task::Poll,
+    time::Duration,
+};
 
 /// Array of Frames and the duration for how long each frame should be shown
 #[derive(Debug, Clone)]
@@ -56,11 +65,19 @@ impl AnimatedImage {
         self.frames[index % self.frames.len()].clone()
     }
 }
-type Entry = Result<Arc<AnimatedImage>, String>;
+struct Entry {
+    /// The last pass this image was requested in.
+    ///
+    /// Images that are still decoding are canceled if they are no longer requested.
+    last_used: u64,
+
+    result: Poll<Result<Arc<AnimatedImage>, String>>,
+}
 
 #[derive(Default)]
 pub struct GifLoader {
-    cache: Mutex<HashMap<String, Entry>>,
+    pass_index: AtomicU64,
+    cache: Arc<Mutex<HashMap<String, Entry>>>,
 }
 
 impl GifLoader {
@@ -76,12 +93,14 @@ impl ImageLoader for GifLoader {
         let (image_uri, frame_index) =
             decode_animated_image_uri(frame_uri).map_err(|_err| LoadError::NotSupported)?;
         let mut cache = self.cache.lock();
-        if let Some(entry) = cache.get(image_uri).cloned() {
-            match entry {
-                Ok(image) => Ok(ImagePoll::Ready {
+        if let Some(entry) = cache.get_mut(image_uri) {
+            entry.last_used = self.pass_index.load(Relaxed);
+            match &entry.result {
+                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready {
                     image: image.get_image(frame_index),
                 }),
-                Err(err) => Err(LoadError::Loading(err)),
+                Poll::Ready(Err(err)) => Err(LoadError::Loading(err.clone())),
+                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
             }
         } else {
             match ctx.try_load_bytes(image_uri) {
@@ -89,22 +108,42 @@ impl ImageLoader for GifLoader {
                     if !has_gif_magic_header(&bytes) {
                         return Err(LoadError::NotSupported);
                     }
-                    log::trace!("started loading {image_uri:?}");
-                    let result = AnimatedImage::load_gif(&bytes).map(Arc::new);
-                    if let Ok(v) = &result {
-                        ctx.data_mut(|data| {
-                            *data.get_temp_mut_or_default(Id::new(image_uri)) =
-                                v.frame_durations.clone();
-                        });
-                    }
-                    log::trace!("finished loading {image_uri:?}");
-                    cache.insert(image_uri.into(), result.clone());
-                    match result {
-                        Ok(image) => Ok(ImagePoll::Ready {
-                            image: image.get_image(frame_index),
-                        }),
-                        Err(err) => Err(LoadError::Loading(err)),
-                    }
+                    cache.insert(
+                        image_uri.to_owned(),
+                        Entry {
+                            last_used: self.pass_index.load(Relaxed),
+                            result: Poll::Pending,
+                        },
+                    );
+                    drop(cache);
+
+                    super::decode_pool::spawn({
+                        let ctx = ctx.clone();
+                        let cache = self.cache.clone();
+                        let image_uri = image_uri.to_owned();
+                        move || {
+                            if !cache.lock().contains_key(&image_uri) {
+                                log::trace!("canceled loading {image_uri:?}");
+                                return;
+                            }
+                            log::trace!("started loading {image_uri:?}");
+                            let result = AnimatedImage::load_gif(&bytes).map(Arc::new);
+                            if let Ok(image) = &result {
+                                ctx.data_mut(|data| {
+                                    *data.get_temp_mut_or_default(Id::new(&image_uri)) =
+                                        image.frame_durations.clone();
+                                });
+                            }
+                            log::trace!("finished loading {image_uri:?}");
+                            if let Some(entry) = cache.lock().get_mut(&image_uri) {
+                                entry.result = Poll::Ready(result);
+                                ctx.request_repaint();
+                            }
+                        }
+                    });
+
+                    // On the web, the image is decoded right away:
+                    self.load(ctx, frame_uri, SizeHint::default())
                 }
                 Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
                 Err(err) => Err(err),
@@ -120,14 +159,44 @@ impl ImageLoader for GifLoader {
         self.cache.lock().clear();
     }
 
+    fn end_pass(&self, pass_index: u64) {
+        self.pass_index.store(pass_index, Relaxed);
+
+        // Cancel decoding the images that are no longer shown:
+        self.cache
+            .lock()
+            .retain(|_uri, entry| entry.result.is_ready() || pass_index <= entry.last_used + 1);
+    }
+
     fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(entry_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, entry_byte_size))
+    }
+
+    fn has_pending(&self) -> bool {
         self.cache
             .lock()
             .values()
-            .map(|v| match v {
-                Ok(v) => v.byte_len(),
-                Err(e) => e.len(),
-            })
-            .sum()
+            .any(|entry| entry.result.is_pending())
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let uri = decode_animated_image_uri(uri).map_or(uri, |(uri, _frame_index)| uri);
+        self.cache.lock().get(uri).map(|entry| match &entry.result {
+            Poll::Ready(Ok(_)) => LoadState::Ready,
+            Poll::Ready(Err(err)) => LoadState::Failed(err.clone()),
+            Poll::Pending => LoadState::Decoding,
+        })
+    }
+}
+
+fn entry_byte_size(entry: &Entry) -> usize {
+    match &entry.result {
+        Poll::Ready(Ok(image)) => image.byte_len(),
+        Poll::Ready(Err(err)) => err.len(),
+        Poll::Pending => 0,
     }
 }
@@ -1,19 +1,32 @@
 use ahash::HashMap;
 use egui::{
     ColorImage, decode_animated_image_uri,
-    load::{Bytes, BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, LoadState, SizeHint},
     mutex::Mutex,
 };
 use image::ImageFormat;
-use std::{mem::size_of, path::Path, sync::Arc, task::Poll};
+use std::{
+    mem::size_of,
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering::Relaxed},
+    },
+    task::Poll,
+};
 
-#[cfg(not(target_arch = "wasm32"))]
-use std::thread;
+struct Entry {
+    /// The last pass this image was requested in.
+    ///
+    /// Images that are still decoding are canceled if they are no longer requested.
+    last_used: u64,
 
-type Entry = Poll<Result<Arc<ColorImage>, String>>;
+    result: Poll<Result<Arc<ColorImage>, String>>,
+}
 
 #[derive(Default)]
 pub struct ImageCrateLoader {
+    pass_index: AtomicU64,
     cache: Arc<Mutex<HashMap<String, Entry>>>,
 }
 
@@ -75,91 +88,72 @@ impl ImageLoader for ImageCrateLoader {
             return Err(LoadError::NotSupported);
         }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        #[expect(clippy::unnecessary_wraps)] // needed here to match other return types
-        fn load_image(
-            ctx: &egui::Context,
-            uri: &str,
-            cache: &Arc<Mutex<HashMap<String, Entry>>>,
-            bytes: &Bytes,
-        ) -> ImageLoadResult {
-            let uri = uri.to_owned();
-            cache.lock().insert(uri.clone(), Poll::Pending);
-
-            // Do the image parsing on a bg thread
-            thread::Builder::new()
-                .name(format!("egui_extras::ImageLoader::load({uri:?})"))
-                .spawn({
-                    let ctx = ctx.clone();
-                    let cache = cache.clone();
+        let mut cache = self.cache.lock();
+        if let Some(entry) = cache.get_mut(uri) {
+            entry.last_used = self.pass_index.load(Relaxed);
+            return match entry.result.clone() {
+                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
+                Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
+                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
+            };
+        }
+        drop(cache);
+
+        match ctx.try_load_bytes(uri) {
+            Ok(BytesPoll::Ready { bytes, mime, .. }) => {
+                // (2)
+                if let Some(mime) = mime {
+                    if !is_supported_mime(&mime) {
+                        return Err(LoadError::FormatNotSupported {
+                            detected_format: Some(mime),
+                        });
+                    }
+                }
+
+                self.cache.lock().insert(
+                    uri.to_owned(),
+                    Entry {
+                        last_used: self.pass_index.load(Relaxed),
+                        result: Poll::Pending,
+                    },
+                );
 
-                    let uri = uri.clone();
-                    let bytes = bytes.clone();
+                super::decode_pool::spawn({
+                    let ctx = ctx.clone();
+                    let cache = self.cache.clone();
+                    let uri = uri.to_owned();
                     move || {
+                        if !cache.lock().contains_key(&uri) {
+                            log::trace!("ImageLoader - canceled loading {uri:?}");
+                            return;
+                        }
+
                         log::trace!("ImageLoader - started loading {uri:?}");
                         let result = crate::image::load_image_bytes(&bytes)
                             .map(Arc::new)
                             .map_err(|err| err.to_string());
-                        let mut cache = cache.lock();
 
-                        if let std::collections::hash_map::Entry::Occupied(mut entry) = cache.entry(uri.clone()) {
-                            let entry = entry.get_mut();
-                            *entry = Poll::Ready(result);
+                        if let Some(entry) = cache.lock().get_mut(&uri) {
+                            entry.result = Poll::Ready(result);
                             ctx.request_repaint();
                             log::trace!("ImageLoader - finished loading {uri:?}");
                         } else {
-                            log::trace!("ImageLoader - canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading.");
+                            log::trace!(
+                                "ImageLoader - canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading."
+                            );
                         }
                     }
-                })
-                .expect("failed to spawn thread");
-
-            Ok(ImagePoll::Pending { size: None })
-        }
+                });
 
-        #[cfg(target_arch = "wasm32")]
-        fn load_image(
-            _ctx: &egui::Context,
-            uri: &str,
-            cache: &Arc<Mutex<HashMap<String, Entry>>>,
-            bytes: &Bytes,
-        ) -> ImageLoadResult {
-            let mut cache_lock = cache.lock();
-            log::trace!("started loading {uri:?}");
-            let result = crate::image::load_image_bytes(bytes)
-                .map(Arc::new)
-                .map_err(|err| err.to_string());
-            log::trace!("finished loading {uri:?}");
-            cache_lock.insert(uri.into(), std::task::Poll::Ready(result.clone()));
-            match result {
-                Ok(image) => Ok(ImagePoll::Ready { image }),
-                Err(err) => Err(LoadError::Loading(err)),
-            }
-        }
-
-        let entry = self.cache.lock().get(uri).cloned();
-        if let Some(entry) = entry {
-            match entry {
-                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
-                Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
-                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
-            }
-        } else {
-            match ctx.try_load_bytes(uri) {
-                Ok(BytesPoll::Ready { bytes, mime, .. }) => {
-                    // (2)
-                    if let Some(mime) = mime {
-                        if !is_supported_mime(&mime) {
-                            return Err(LoadError::FormatNotSupported {
-                                detected_format: Some(mime),
-                            });
-                        }
-                    }
-                    load_image(ctx, uri, &self.cache, &bytes)
+                // On the web, the image is decoded right away:
+                match self.cache.lock().get(uri).map(|entry| entry.result.clone()) {
+                    Some(Poll::Ready(Ok(image))) => Ok(ImagePoll::Ready { image }),
+                    Some(Poll::Ready(Err(err))) => Err(LoadError::Loading(err)),
+                    Some(Poll::Pending) | None => Ok(ImagePoll::Pending { size: None }),
                 }
-                Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
-                Err(err) => Err(err),
             }
+            Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
+            Err(err) => Err(err),
         }
     }
 
@@ -171,20 +165,45 @@ impl ImageLoader for ImageCrateLoader {
         self.cache.lock().clear();
     }
 
+    fn end_pass(&self, pass_index: u64) {
+        self.pass_index.store(pass_index, Relaxed);
+
+        // Cancel decoding the images that are no longer shown:
+        self.cache
+            .lock()
+            .retain(|_uri, entry| entry.result.is_ready() || pass_index <= entry.last_used + 1);
+    }
+
     fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(entry_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, entry_byte_size))
+    }
+
+    fn has_pending(&self) -> bool {
         self.cache
             .lock()
             .values()
-            .map(|result| match result {
-                Poll::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
-                Poll::Ready(Err(err)) => err.len(),
-                Poll::Pending => 0,
-            })
-            .sum()
+            .any(|entry| entry.result.is_pending())
     }
 
-    fn has_pending(&self) -> bool {
-        self.cache.lock().values().any(|result| result.is_pending())
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let uri = decode_animated_image_uri(uri).map_or(uri, |(uri, _frame_index)| uri);
+        self.cache.lock().get(uri).map(|entry| match &entry.result {
+            Poll::Ready(Ok(_)) => LoadState::Ready,
+            Poll::Ready(Err(err)) => LoadState::Failed(err.clone()),
+            Poll::Pending => LoadState::Decoding,
+        })
+    }
+}
+
+fn entry_byte_size(entry: &Entry) -> usize {
+    match &entry.result {
+        Poll::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
+        Poll::Ready(Err(err)) => err.len(),
+        Poll::Pending => 0,
     }
 }
 
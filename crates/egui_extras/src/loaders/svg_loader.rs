@@ -10,7 +10,7 @@ use ahash::HashMap;
 
 use egui::{
     ColorImage,
-    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, LoadState, SizeHint},
     mutex::Mutex,
 };
 
@@ -107,15 +107,20 @@ impl ImageLoader for SvgLoader {
     }
 
     fn byte_size(&self) -> usize {
-        self.cache
-            .lock()
-            .values()
-            .flat_map(|bucket| bucket.values())
-            .map(|entry| match &entry.result {
-                Ok(image) => image.pixels.len() * size_of::<egui::Color32>(),
-                Err(err) => err.len(),
-            })
-            .sum()
+        self.cache.lock().values().map(bucket_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, bucket_byte_size))
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let cache = self.cache.lock();
+        let entry = cache.get(uri)?.values().next()?;
+        Some(match &entry.result {
+            Ok(_) => LoadState::Ready,
+            Err(err) => LoadState::Failed(err.clone()),
+        })
     }
 
     fn end_pass(&self, pass_index: u64) {
@@ -134,6 +139,16 @@ impl ImageLoader for SvgLoader {
     }
 }
 
+fn bucket_byte_size(bucket: &HashMap<SizeHint, Entry>) -> usize {
+    bucket
+        .values()
+        .map(|entry| match &entry.result {
+            Ok(image) => image.pixels.len() * size_of::<egui::Color32>(),
+            Err(err) => err.len(),
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,11 +1,20 @@
 use ahash::HashMap;
 use egui::{
     ColorImage, FrameDurations, Id, decode_animated_image_uri, has_webp_header,
-    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, LoadState, SizeHint},
     mutex::Mutex,
 };
 use image::{AnimationDecoder as _, ColorType, ImageDecoder as _, Rgba, codecs::webp::WebPDecoder};
-use std::{io::Cursor, mem::size_of, sync::Arc, time::Duration};
+use std::{
+    io::Cursor,
+    mem::size_of,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering::Relaxed},
+    },
+    task::Poll,
+    time::Duration,
+};
 
 #[derive(Clone)]
 enum WebP {
@@ -112,11 +121,19 @@ impl AnimatedImage {
     }
 }
 
-type Entry = Result<WebP, String>;
+struct Entry {
+    /// The last pass this image was requested in.
+    ///
+    /// Images that are still decoding are canceled if they are no longer requested.
+    last_used: u64,
+
+    result: Poll<Result<WebP, String>>,
+}
 
 #[derive(Default)]
 pub struct WebPLoader {
-    cache: Mutex<HashMap<String, Entry>>,
+    pass_index: AtomicU64,
+    cache: Arc<Mutex<HashMap<String, Entry>>>,
 }
 
 impl WebPLoader {
@@ -130,15 +147,16 @@ impl ImageLoader for WebPLoader {
 
     fn load(&self, ctx: &egui::Context, frame_uri: &str, _: SizeHint) -> ImageLoadResult {
         let (image_uri, frame_index) =
-            decode_animated_image_uri(frame_uri).map_err(|_error| LoadError::NotSupported)?;
-
+            decode_animated_image_uri(frame_uri).map_err(|_err| LoadError::NotSupported)?;
         let mut cache = self.cache.lock();
-        if let Some(entry) = cache.get(image_uri).cloned() {
-            match entry {
-                Ok(image) => Ok(ImagePoll::Ready {
+        if let Some(entry) = cache.get_mut(image_uri) {
+            entry.last_used = self.pass_index.load(Relaxed);
+            match &entry.result {
+                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready {
                     image: image.get_image(frame_index),
                 }),
-                Err(error) => Err(LoadError::Loading(error)),
+                Poll::Ready(Err(err)) => Err(LoadError::Loading(err.clone())),
+                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
             }
         } else {
             match ctx.try_load_bytes(image_uri) {
@@ -146,31 +164,45 @@ impl ImageLoader for WebPLoader {
                     if !has_webp_header(&bytes) {
                         return Err(LoadError::NotSupported);
                     }
+                    cache.insert(
+                        image_uri.to_owned(),
+                        Entry {
+                            last_used: self.pass_index.load(Relaxed),
+                            result: Poll::Pending,
+                        },
+                    );
+                    drop(cache);
 
-                    log::trace!("started loading {image_uri:?}");
-
-                    let result = WebP::load(&bytes);
-
-                    if let Ok(WebP::Animated(animated_image)) = &result {
-                        ctx.data_mut(|data| {
-                            *data.get_temp_mut_or_default(Id::new(image_uri)) =
-                                animated_image.frame_durations.clone();
-                        });
-                    }
-
-                    log::trace!("finished loading {image_uri:?}");
-
-                    cache.insert(image_uri.into(), result.clone());
+                    super::decode_pool::spawn({
+                        let ctx = ctx.clone();
+                        let cache = self.cache.clone();
+                        let image_uri = image_uri.to_owned();
+                        move || {
+                            if !cache.lock().contains_key(&image_uri) {
+                                log::trace!("canceled loading {image_uri:?}");
+                                return;
+                            }
+                            log::trace!("started loading {image_uri:?}");
+                            let result = WebP::load(&bytes);
+                            if let Ok(WebP::Animated(image)) = &result {
+                                ctx.data_mut(|data| {
+                                    *data.get_temp_mut_or_default(Id::new(&image_uri)) =
+                                        image.frame_durations.clone();
+                                });
+                            }
+                            log::trace!("finished loading {image_uri:?}");
+                            if let Some(entry) = cache.lock().get_mut(&image_uri) {
+                                entry.result = Poll::Ready(result);
+                                ctx.request_repaint();
+                            }
+                        }
+                    });
 
-                    match result {
-                        Ok(image) => Ok(ImagePoll::Ready {
-                            image: image.get_image(frame_index),
-                        }),
-                        Err(error) => Err(LoadError::Loading(error)),
-                    }
+                    // On the web, the image is decoded right away:
+                    self.load(ctx, frame_uri, SizeHint::default())
                 }
                 Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
-                Err(error) => Err(error),
+                Err(err) => Err(err),
             }
         }
     }
@@ -183,14 +215,44 @@ impl ImageLoader for WebPLoader {
         self.cache.lock().clear();
     }
 
+    fn end_pass(&self, pass_index: u64) {
+        self.pass_index.store(pass_index, Relaxed);
+
+        // Cancel decoding the images that are no longer shown:
+        self.cache
+            .lock()
+            .retain(|_uri, entry| entry.result.is_ready() || pass_index <= entry.last_used + 1);
+    }
+
     fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(entry_byte_size).sum()
+    }
+
+    fn byte_size_of(&self, uri: &str) -> Option<usize> {
+        Some(self.cache.lock().get(uri).map_or(0, entry_byte_size))
+    }
+
+    fn has_pending(&self) -> bool {
         self.cache
             .lock()
             .values()
-            .map(|entry| match entry {
-                Ok(entry_value) => entry_value.byte_len(),
-                Err(error) => error.len(),
-            })
-            .sum()
+            .any(|entry| entry.result.is_pending())
+    }
+
+    fn load_state(&self, uri: &str) -> Option<LoadState> {
+        let uri = decode_animated_image_uri(uri).map_or(uri, |(uri, _frame_index)| uri);
+        self.cache.lock().get(uri).map(|entry| match &entry.result {
+            Poll::Ready(Ok(_)) => LoadState::Ready,
+            Poll::Ready(Err(err)) => LoadState::Failed(err.clone()),
+            Poll::Pending => LoadState::Decoding,
+        })
+    }
+}
+
+fn entry_byte_size(entry: &Entry) -> usize {
+    match &entry.result {
+        Poll::Ready(Ok(image)) => image.byte_len(),
+        Poll::Ready(Err(err)) => err.len(),
+        Poll::Pending => 0,
     }
 }
@@ -253,6 +253,13 @@ pub struct TableBuilder<'a> {
     cell_layout: egui::Layout,
     scroll_options: TableScrollOptions,
     sense: egui::Sense,
+    footer: Option<Footer<'a>>,
+}
+
+/// A row that is added below the scrolling body, see [`TableBuilder::footer`].
+struct Footer<'a> {
+    height: f32,
+    add_footer_row: Box<dyn FnOnce(TableRow<'_, '_>) + 'a>,
 }
 
 impl<'a> TableBuilder<'a> {
@@ -267,6 +274,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options: Default::default(),
             sense: egui::Sense::hover(),
+            footer: None,
         }
     }
 
@@ -449,6 +457,50 @@ impl<'a> TableBuilder<'a> {
                 * self.ui.spacing().scroll.allocated_width()
     }
 
+    /// Add a footer row which always stays visible and at the bottom, e.g. for totals.
+    ///
+    /// The footer is aligned with the columns of the body, and takes part in sizing them.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let prices = [1.5, 2.25, 10.0];
+    /// TableBuilder::new(ui)
+    ///     .columns(Column::auto(), 2)
+    ///     .footer(20.0, |mut footer| {
+    ///         footer.col(|ui| {
+    ///             ui.strong("Total");
+    ///         });
+    ///         footer.col(|ui| {
+    ///             ui.strong(format!("{:.2}", prices.iter().sum::<f64>()));
+    ///         });
+    ///     })
+    ///     .body(|body| {
+    ///         body.rows(20.0, prices.len(), |mut row| {
+    ///             let index = row.index();
+    ///             row.col(|ui| {
+    ///                 ui.label(format!("Item {index}"));
+    ///             });
+    ///             row.col(|ui| {
+    ///                 ui.label(format!("{:.2}", prices[index]));
+    ///             });
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn footer(
+        mut self,
+        height: f32,
+        add_footer_row: impl FnOnce(TableRow<'_, '_>) + 'a,
+    ) -> Self {
+        self.footer = Some(Footer {
+            height,
+            add_footer_row: Box::new(add_footer_row),
+        });
+        self
+    }
+
     /// Reset all column widths.
     pub fn reset(&self) {
         let state_id = self.ui.id().with(self.id_salt);
@@ -468,6 +520,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            footer,
         } = self;
 
         for (i, column) in columns.iter_mut().enumerate() {
@@ -503,6 +556,7 @@ impl<'a> TableBuilder<'a> {
                 max_used_widths: &mut max_used_widths,
                 row_index: 0,
                 col_index: 0,
+                kind: RowKind::Header,
                 height,
                 striped: false,
                 hovered: false,
@@ -527,6 +581,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            footer,
         }
     }
 
@@ -546,6 +601,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            footer,
         } = self;
 
         let striped = striped.unwrap_or(ui.visuals().striped);
@@ -572,6 +628,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            footer,
         }
         .body(add_body_contents)
     }
@@ -697,9 +754,11 @@ pub struct Table<'a> {
     scroll_options: TableScrollOptions,
 
     sense: egui::Sense,
+
+    footer: Option<Footer<'a>>,
 }
 
-impl Table<'_> {
+impl<'a> Table<'a> {
     /// Access the contained [`egui::Ui`].
     ///
     /// You can use this to e.g. modify the [`egui::Style`] with [`egui::Ui::style_mut`].
@@ -707,6 +766,20 @@ impl Table<'_> {
         self.ui
     }
 
+    /// Add a footer row which always stays visible and at the bottom, see [`TableBuilder::footer`].
+    #[inline]
+    pub fn footer(
+        mut self,
+        height: f32,
+        add_footer_row: impl FnOnce(TableRow<'_, '_>) + 'a,
+    ) -> Self {
+        self.footer = Some(Footer {
+            height,
+            add_footer_row: Box::new(add_footer_row),
+        });
+        self
+    }
+
     /// Create table body after adding a header row
     pub fn body<F>(self, add_body_contents: F) -> ScrollAreaOutput<()>
     where
@@ -726,6 +799,7 @@ impl Table<'_> {
             cell_layout,
             scroll_options,
             sense,
+            footer,
         } = self;
 
         let TableScrollOptions {
@@ -743,6 +817,19 @@ impl Table<'_> {
 
         let cursor_position = ui.cursor().min;
 
+        // Leave room for the footer below the body:
+        let (min_scrolled_height, max_scroll_height) = match &footer {
+            Some(footer) => {
+                let room = ui.available_height() - footer.height - ui.spacing().item_spacing.y;
+                let max_scroll_height = max_scroll_height.min(room);
+                (
+                    min_scrolled_height.min(max_scroll_height),
+                    max_scroll_height,
+                )
+            }
+            None => (min_scrolled_height, max_scroll_height),
+        };
+
         let mut scroll_area = ScrollArea::new([false, vscroll])
             .id_salt(state_id.with("__scroll_area"))
             .scroll_source(ScrollSource {
@@ -808,6 +895,38 @@ impl Table<'_> {
             }
         });
 
+        if let Some(Footer {
+            height,
+            add_footer_row,
+        }) = footer
+        {
+            let mut ui_builder = egui::UiBuilder::new();
+            if is_sizing_pass {
+                ui_builder = ui_builder.sizing_pass();
+            }
+            ui.scope_builder(ui_builder, |ui| {
+                let mut layout =
+                    StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                let mut response: Option<Response> = None;
+                add_footer_row(TableRow {
+                    layout: &mut layout,
+                    columns: &columns,
+                    widths: &state.column_widths,
+                    max_used_widths: &mut max_used_widths,
+                    row_index: 0,
+                    col_index: 0,
+                    kind: RowKind::Footer,
+                    height,
+                    striped: false,
+                    hovered: false,
+                    selected: false,
+                    overline: false,
+                    response: &mut response,
+                });
+                layout.allocate_rect();
+            });
+        }
+
         let bottom = ui.min_rect().bottom();
 
         let spacing_x = ui.spacing().item_spacing.x;
@@ -990,6 +1109,7 @@ impl<'a> TableBody<'a> {
             max_used_widths: self.max_used_widths,
             row_index: self.row_index,
             col_index: 0,
+            kind: RowKind::Body,
             height,
             striped: self.striped && self.row_index % 2 == 0,
             hovered: self.hovered_row_index == Some(self.row_index),
@@ -1036,6 +1156,135 @@ impl<'a> TableBody<'a> {
         row_height_sans_spacing: f32,
         total_rows: usize,
         mut add_row_content: impl FnMut(TableRow<'_, '_>),
+    ) {
+        self.add_rows(
+            row_height_sans_spacing,
+            total_rows,
+            None,
+            &mut add_row_content,
+        );
+    }
+
+    /// Like [`Self::rows`], but with group headers that stick to the top of the table.
+    ///
+    /// The rows for which `is_group_header` returns `true` start a new group.
+    /// When a group header is scrolled past, it stays at the top of the table
+    /// while the rest of its group scrolls underneath it, until it is pushed out by the next group header.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder().at_least(100.0))
+    ///     .body(|body| {
+    ///         let is_group_header = |row_index: usize| row_index % 50 == 0;
+    ///         body.rows_with_sticky_headers(18.0, 1_000, is_group_header, |mut row| {
+    ///             let row_index = row.index();
+    ///             row.col(|ui| {
+    ///                 if is_group_header(row_index) {
+    ///                     ui.strong(format!("Group {}", row_index / 50));
+    ///                 } else {
+    ///                     ui.label(format!("Row {row_index}"));
+    ///                 }
+    ///             });
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    pub fn rows_with_sticky_headers(
+        mut self,
+        row_height_sans_spacing: f32,
+        total_rows: usize,
+        is_group_header: impl Fn(usize) -> bool,
+        mut add_row_content: impl FnMut(TableRow<'_, '_>),
+    ) {
+        let spacing = self.layout.ui.spacing().item_spacing;
+        let row_height_with_spacing = row_height_sans_spacing + spacing.y;
+        let top_y = self.layout.cursor.y;
+        let row_top = |row_index: usize| top_y + row_index as f32 * row_height_with_spacing;
+
+        let first_visible_row = (self.scroll_offset_y() / row_height_with_spacing)
+            .floor()
+            .max(0.0) as usize;
+        let last_visible_row =
+            first_visible_row + (self.y_range.span() / row_height_with_spacing).ceil() as usize + 1;
+
+        // The header of the first visible row, if it has been scrolled past:
+        let sticky_header = (0..total_rows.min(first_visible_row + 1))
+            .rev()
+            .find(|&row_index| is_group_header(row_index))
+            .filter(|&row_index| row_top(row_index) < self.y_range.min)
+            .map(|row_index| {
+                // Pushed out by the next header:
+                let next_header_top = (row_index + 1..total_rows.min(last_visible_row + 1))
+                    .find(|&row_index| is_group_header(row_index))
+                    .map_or(f32::INFINITY, row_top);
+                let y = self
+                    .y_range
+                    .min
+                    .min(next_header_top - row_height_with_spacing);
+                (row_index, y)
+            });
+
+        self.add_rows(
+            row_height_sans_spacing,
+            total_rows,
+            sticky_header.map(|(row_index, _)| row_index),
+            &mut add_row_content,
+        );
+
+        if let Some((row_index, y)) = sticky_header {
+            let cursor = self.layout.cursor;
+            self.layout.cursor = egui::pos2(self.layout.rect.left(), y);
+
+            // Hide the rows scrolling underneath:
+            let width = self.widths.iter().sum::<f32>() + spacing.x * self.widths.len() as f32;
+            let background = Rect::from_min_size(
+                egui::pos2(
+                    self.layout.rect.left() - 0.5 * spacing.x,
+                    y - 0.5 * spacing.y,
+                ),
+                egui::vec2(width, row_height_with_spacing),
+            );
+            let fill = if self.layout.ui.stack().contained_in(egui::UiKind::Window) {
+                self.layout.ui.visuals().window_fill()
+            } else {
+                self.layout.ui.visuals().panel_fill
+            };
+            self.layout
+                .ui
+                .painter()
+                .rect_filled(background, egui::CornerRadius::ZERO, fill);
+
+            let mut response: Option<Response> = None;
+            add_row_content(TableRow {
+                layout: &mut self.layout,
+                columns: self.columns,
+                widths: self.widths,
+                max_used_widths: self.max_used_widths,
+                row_index,
+                col_index: 0,
+                kind: RowKind::Body,
+                height: row_height_sans_spacing,
+                striped: false,
+                hovered: self.hovered_row_index == Some(row_index),
+                selected: false,
+                overline: false,
+                response: &mut response,
+            });
+            self.capture_hover_state(&response, row_index);
+            self.layout.cursor = cursor;
+        }
+    }
+
+    /// Add the visible rows, leaving out `hidden_row`.
+    fn add_rows(
+        &mut self,
+        row_height_sans_spacing: f32,
+        total_rows: usize,
+        hidden_row: Option<usize>,
+        add_row_content: &mut impl FnMut(TableRow<'_, '_>),
     ) {
         let spacing = self.layout.ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
@@ -1064,6 +1313,10 @@ impl<'a> TableBody<'a> {
         let max_row = max_row.min(total_rows);
 
         for row_index in min_row..max_row {
+            if Some(row_index) == hidden_row {
+                self.add_buffer(row_height_with_spacing);
+                continue;
+            }
             let mut response: Option<Response> = None;
             add_row_content(TableRow {
                 layout: &mut self.layout,
@@ -1072,6 +1325,7 @@ impl<'a> TableBody<'a> {
                 max_used_widths: self.max_used_widths,
                 row_index,
                 col_index: 0,
+                kind: RowKind::Body,
                 height: row_height_sans_spacing,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
@@ -1154,6 +1408,7 @@ impl<'a> TableBody<'a> {
                     max_used_widths: self.max_used_widths,
                     row_index,
                     col_index: 0,
+                    kind: RowKind::Body,
                     height: row_height,
                     striped: self.striped && (row_index + self.row_index) % 2 == 0,
                     hovered: self.hovered_row_index == Some(row_index),
@@ -1177,6 +1432,7 @@ impl<'a> TableBody<'a> {
                 max_used_widths: self.max_used_widths,
                 row_index,
                 col_index: 0,
+                kind: RowKind::Body,
                 height: row_height,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
@@ -1251,6 +1507,14 @@ impl Drop for TableBody<'_> {
     }
 }
 
+/// Where a [`TableRow`] is, for accessibility.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RowKind {
+    Header,
+    Body,
+    Footer,
+}
+
 /// The row of a table.
 /// Is created by [`TableRow`] for each created [`TableBody::row`] or each visible row in rows created by calling [`TableBody::rows`].
 pub struct TableRow<'a, 'b> {
@@ -1263,6 +1527,7 @@ pub struct TableRow<'a, 'b> {
 
     row_index: usize,
     col_index: usize,
+    kind: RowKind,
     height: f32,
 
     striped: bool,
@@ -1322,6 +1587,16 @@ impl TableRow<'_, '_> {
             *max_w = max_w.max(used_rect.width());
         }
 
+        let (kind, row_index) = (self.kind, self.row_index);
+        response.accessibility(|node| {
+            match kind {
+                RowKind::Header => node.role(egui::WidgetType::ColumnHeader),
+                RowKind::Body => node.role(egui::WidgetType::TableCell).row_index(row_index),
+                RowKind::Footer => node.role(egui::WidgetType::TableCell),
+            }
+            .column_index(col_index);
+        });
+
         *self.response = Some(
             self.response
                 .as_ref()
@@ -91,6 +91,8 @@ pub struct Painter {
     vao: crate::vao::VertexArrayObject,
     srgb_textures: bool,
     supports_srgb_framebuffer: bool,
+    has_depth_buffer: bool,
+    msaa_samples: u32,
     vbo: glow::Buffer,
     element_array_buffer: glow::Buffer,
 
@@ -181,6 +183,10 @@ impl Painter {
             });
         log::debug!("SRGB framebuffer Support: {:?}", supports_srgb_framebuffer);
 
+        let has_depth_buffer = unsafe { depth_buffer_bits(&gl, shader_version) } > 0;
+        let msaa_samples = unsafe { gl.get_parameter_i32(glow::SAMPLES) }.max(1) as u32;
+        log::debug!("Depth buffer: {has_depth_buffer:?}, MSAA samples: {msaa_samples}");
+
         unsafe {
             let vert = compile_shader(
                 &gl,
@@ -262,6 +268,8 @@ impl Painter {
                 vao,
                 srgb_textures,
                 supports_srgb_framebuffer,
+                has_depth_buffer,
+                msaa_samples,
                 vbo,
                 element_array_buffer,
                 textures: Default::default(),
@@ -415,6 +423,18 @@ impl Painter {
                 Primitive::Mesh(mesh) => {
                     self.paint_mesh(mesh);
                 }
+                Primitive::Instanced(instanced) => {
+                    // We have no instanced draw path, so copy the instances into one mesh:
+                    self.paint_mesh(&instanced.to_mesh());
+                }
+                Primitive::SdfRect(sdf_rect) => {
+                    // We have no SDF shader, so tessellate it:
+                    self.paint_mesh(&sdf_rect.to_mesh(pixels_per_point));
+                }
+                Primitive::BackdropBlur(blur) => {
+                    // We have no blur pass, so fall back to translucency:
+                    self.paint_mesh(&blur.to_fallback_mesh(pixels_per_point));
+                }
                 Primitive::Callback(callback) => {
                     if callback.rect.is_positive() {
                         profiling::scope!("callback");
@@ -424,6 +444,9 @@ impl Painter {
                             clip_rect: *clip_rect,
                             pixels_per_point,
                             screen_size_px,
+                            transform: callback.transform,
+                            has_depth_buffer: self.has_depth_buffer,
+                            msaa_samples: self.msaa_samples,
                         };
 
                         let viewport_px = info.viewport_in_pixels();
@@ -434,6 +457,13 @@ impl Painter {
                                 viewport_px.width_px,
                                 viewport_px.height_px,
                             );
+
+                            if callback.depth_buffer && self.has_depth_buffer {
+                                // The scissor test is enabled, so this only clears inside the clip rect:
+                                self.gl.depth_mask(true);
+                                self.gl.clear_depth_f32(1.0);
+                                self.gl.clear(glow::DEPTH_BUFFER_BIT);
+                            }
                         }
 
                         if let Some(callback) = callback.callback.downcast_ref::<CallbackFn>() {
@@ -756,6 +786,21 @@ impl Drop for Painter {
     }
 }
 
+/// The number of bits in the depth buffer of the currently bound framebuffer.
+unsafe fn depth_buffer_bits(gl: &glow::Context, shader_version: ShaderVersion) -> i32 {
+    unsafe {
+        if shader_version.is_embedded() {
+            gl.get_parameter_i32(glow::DEPTH_BITS)
+        } else {
+            gl.get_framebuffer_attachment_parameter_i32(
+                glow::FRAMEBUFFER,
+                glow::DEPTH,
+                glow::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE,
+            )
+        }
+    }
+}
+
 fn set_clip_rect(
     gl: &glow::Context,
     [width_px, height_px]: [u32; 2],
@@ -15,6 +15,7 @@ use std::time::Duration;
 mod app_kind;
 mod node;
 mod renderer;
+mod software;
 #[cfg(feature = "wgpu")]
 mod texture_to_image;
 #[cfg(feature = "wgpu")]
@@ -27,6 +28,7 @@ use crate::app_kind::AppKind;
 pub use builder::*;
 pub use node::*;
 pub use renderer::*;
+pub use software::SoftwareTestRenderer;
 
 use egui::style::ScrollAnimation;
 use egui::{Key, Modifiers, Pos2, Rect, RepaintCause, Vec2, ViewportId};
@@ -73,6 +75,7 @@ pub struct Harness<'a, State = ()> {
     step_dt: f32,
     wait_for_pending_images: bool,
     queued_events: EventQueue,
+    id_clashes: Vec<egui::IdClash>,
 }
 
 impl<State> Debug for Harness<'_, State> {
@@ -143,6 +146,7 @@ impl<'a, State> Harness<'a, State> {
             step_dt,
             wait_for_pending_images,
             queued_events: Default::default(),
+            id_clashes: Vec::new(),
         };
         // Run the harness until it is stable, ensuring that all Areas are shown and animations are done
         harness.run_ok();
@@ -271,6 +275,12 @@ impl<'a, State> Harness<'a, State> {
         );
         self.renderer.handle_delta(&output.textures_delta);
         self.output = output;
+
+        for clash in self.ctx.id_clashes() {
+            if !self.id_clashes.contains(&clash) {
+                self.id_clashes.push(clash);
+            }
+        }
     }
 
     /// Resize the test harness to fit the contents. This only works when creating the Harness via
@@ -417,6 +427,22 @@ impl<'a, State> Harness<'a, State> {
         &self.output
     }
 
+    /// All [`egui::Id`] clashes found so far, see [`egui::Context::id_clashes`].
+    pub fn id_clashes(&self) -> &[egui::IdClash] {
+        &self.id_clashes
+    }
+
+    /// Panics if any two widgets have used the same [`egui::Id`] since the harness was created.
+    ///
+    /// The panic message includes the source location of both widgets.
+    #[track_caller]
+    pub fn assert_no_id_clashes(&self) {
+        if !self.id_clashes.is_empty() {
+            let clashes: Vec<String> = self.id_clashes.iter().map(ToString::to_string).collect();
+            panic!("Found Id clashes:\n{}", clashes.join("\n"));
+        }
+    }
+
     /// Access the [`kittest::State`].
     pub fn kittest_state(&self) -> &kittest::State {
         &self.kittest
@@ -556,6 +582,24 @@ impl<'a, State> Harness<'a, State> {
         self.key_combination_modifiers(modifiers, &[key]);
     }
 
+    /// Compose text with an IME, showing each of the `preedits` before committing `commit`.
+    ///
+    /// See [`egui::Event::ime_composition`].
+    pub fn ime_composition(&self, preedits: &[&str], commit: &str) {
+        for event in egui::Event::ime_composition(preedits, commit) {
+            self.event(event);
+        }
+    }
+
+    /// Type `text` by pressing the `keys` in a row, like with a dead key or a compose key.
+    ///
+    /// See [`egui::Event::composed_keys`].
+    pub fn composed_keys(&self, keys: &[Key], text: &str) {
+        for event in egui::Event::composed_keys(keys, text) {
+            self.event(event);
+        }
+    }
+
     /// Render the last output to an image.
     ///
     /// # Errors
@@ -22,7 +22,8 @@ pub trait TestRenderer {
 
 /// A lazy renderer that initializes the renderer on the first render call.
 ///
-/// By default, this will create a wgpu renderer if the wgpu feature is enabled.
+/// By default, this will create a wgpu renderer if the wgpu feature is enabled,
+/// and a [`crate::SoftwareTestRenderer`] otherwise.
 pub enum LazyRenderer {
     Uninitialized {
         texture_ops: Vec<egui::TexturesDelta>,
@@ -38,10 +39,7 @@ impl Default for LazyRenderer {
         #[cfg(feature = "wgpu")]
         return Self::new(crate::wgpu::WgpuTestRenderer::new);
         #[cfg(not(feature = "wgpu"))]
-        return Self::Uninitialized {
-            texture_ops: Vec::new(),
-            builder: None,
-        };
+        return Self::new(crate::SoftwareTestRenderer::new);
     }
 }
 
@@ -0,0 +1,287 @@
+//! A renderer that runs on the CPU, for snapshot tests without a GPU, e.g. in CI.
+
+use std::collections::HashMap;
+
+use egui::epaint::{ClippedPrimitive, ImageDelta, Mesh, Primitive, Vertex};
+use egui::{
+    Color32, ColorImage, ImageData, Pos2, Rect, TextureFilter, TextureId, TextureOptions,
+    TextureWrapMode, TexturesDelta, Vec2,
+};
+
+use crate::TestRenderer;
+
+/// Rasterizes the tessellated shapes of a [`egui::FullOutput`] on the CPU.
+///
+/// This needs no GPU, so snapshot tests can run headless anywhere.
+/// It is the default renderer when the `wgpu` feature is off.
+///
+/// The result is close to, but not exactly the same as what a GPU renders,
+/// so snapshots should be made with the same renderer they are compared with.
+///
+/// Paint callbacks are not supported, and are skipped.
+#[derive(Default)]
+pub struct SoftwareTestRenderer {
+    textures: HashMap<TextureId, Texture>,
+}
+
+struct Texture {
+    image: ColorImage,
+    options: TextureOptions,
+}
+
+impl SoftwareTestRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the shapes of `output` to an image with premultiplied alpha, on a transparent background.
+    ///
+    /// Textures must be up to date, see [`TestRenderer::handle_delta`].
+    pub fn render_color_image(&self, ctx: &egui::Context, output: &egui::FullOutput) -> ColorImage {
+        let pixels_per_point = ctx.pixels_per_point();
+        let size = (ctx.screen_rect().size() * pixels_per_point).round();
+        let mut target =
+            ColorImage::filled([size.x as usize, size.y as usize], Color32::TRANSPARENT);
+
+        let primitives = ctx.tessellate(output.shapes.clone(), pixels_per_point);
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in &primitives
+        {
+            let mesh = match primitive {
+                Primitive::Mesh(mesh) => std::borrow::Cow::Borrowed(mesh),
+                Primitive::Instanced(instanced) => std::borrow::Cow::Owned(instanced.to_mesh()),
+                Primitive::SdfRect(sdf_rect) => {
+                    std::borrow::Cow::Owned(sdf_rect.to_mesh(pixels_per_point))
+                }
+                Primitive::BackdropBlur(blur) => {
+                    std::borrow::Cow::Owned(blur.to_fallback_mesh(pixels_per_point))
+                }
+                Primitive::Callback(_) => continue,
+            };
+            let clip_rect = Rect::from_min_max(
+                (clip_rect.min.to_vec2() * pixels_per_point)
+                    .round()
+                    .to_pos2(),
+                (clip_rect.max.to_vec2() * pixels_per_point)
+                    .round()
+                    .to_pos2(),
+            );
+            self.paint_mesh(&mut target, clip_rect, &mesh, pixels_per_point);
+        }
+
+        target
+    }
+
+    fn paint_mesh(&self, target: &mut ColorImage, clip_rect: Rect, mesh: &Mesh, scale: f32) {
+        let Some(texture) = self.textures.get(&mesh.texture_id) else {
+            return;
+        };
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+            paint_triangle(target, clip_rect, texture, [a, b, c], scale);
+        }
+    }
+}
+
+impl TestRenderer for SoftwareTestRenderer {
+    fn handle_delta(&mut self, delta: &TexturesDelta) {
+        for (
+            id,
+            ImageDelta {
+                image,
+                options,
+                pos,
+            },
+        ) in &delta.set
+        {
+            let ImageData::Color(patch) = image;
+            match pos {
+                None => {
+                    self.textures.insert(
+                        *id,
+                        Texture {
+                            image: (**patch).clone(),
+                            options: *options,
+                        },
+                    );
+                }
+                Some([x, y]) => {
+                    let Some(texture) = self.textures.get_mut(id) else {
+                        continue;
+                    };
+                    texture.options = *options;
+                    let width = texture.image.width();
+                    for row in 0..patch.height() {
+                        let start = (y + row) * width + x;
+                        let patch_row = &patch.pixels[row * patch.width()..][..patch.width()];
+                        texture.image.pixels[start..][..patch.width()].copy_from_slice(patch_row);
+                    }
+                }
+            }
+        }
+
+        // Freeing right away is fine, as we only render after handling the delta of the next frame:
+        for id in &delta.free {
+            self.textures.remove(id);
+        }
+    }
+
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+    ) -> Result<image::RgbaImage, String> {
+        let image = self.render_color_image(ctx, output);
+        let [width, height] = image.size;
+        image::RgbaImage::from_raw(width as u32, height as u32, image.as_raw().to_vec())
+            .ok_or_else(|| "Failed to create the image".to_owned())
+    }
+}
+
+/// Paint a triangle with the top-left fill rule, so triangles sharing an edge don't paint it twice.
+fn paint_triangle(
+    target: &mut ColorImage,
+    clip_rect: Rect,
+    texture: &Texture,
+    vertices: [&Vertex; 3],
+    scale: f32,
+) {
+    let [mut a, b, mut c] = vertices;
+    let pos = |v: &Vertex| (v.pos.to_vec2() * scale).to_pos2();
+    let area = edge(pos(a), pos(b), pos(c));
+    if area == 0.0 {
+        return;
+    }
+    if area < 0.0 {
+        // Make the winding consistent, so all edge functions are positive inside:
+        std::mem::swap(&mut a, &mut c);
+    }
+    let area = area.abs();
+    let [pa, pb, pc] = [pos(a), pos(b), pos(c)];
+
+    let bounds = Rect::from_points(&[pa, pb, pc]).intersect(clip_rect);
+    if !bounds.is_positive() {
+        return;
+    }
+    let [width, height] = target.size;
+    let x_range =
+        (bounds.min.x.floor().max(0.0) as usize)..(bounds.max.x.ceil() as usize).min(width);
+    let y_range =
+        (bounds.min.y.floor().max(0.0) as usize)..(bounds.max.y.ceil() as usize).min(height);
+
+    for y in y_range {
+        for x in x_range.clone() {
+            let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+            if !clip_rect.contains(p) {
+                continue;
+            }
+            let weights = [(pb, pc), (pc, pa), (pa, pb)].map(|(from, to)| edge(from, to, p));
+            let inside = weights
+                .iter()
+                .zip([(pb, pc), (pc, pa), (pa, pb)])
+                .all(|(&w, (from, to))| 0.0 < w || (w == 0.0 && is_top_left(from, to)));
+            if !inside {
+                continue;
+            }
+            let [wa, wb, wc] = weights.map(|w| w / area);
+
+            let uv = (wa * a.uv.to_vec2() + wb * b.uv.to_vec2() + wc * c.uv.to_vec2()).to_pos2();
+            let color = mix([a.color, b.color, c.color], [wa, wb, wc]);
+            let texel = sample(texture, uv);
+            let src = multiply(texel, color);
+
+            let dst = &mut target.pixels[y * width + x];
+            *dst = blend(src, *dst);
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a b p`.
+fn edge(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b - a).x * (p - a).y - (b - a).y * (p - a).x
+}
+
+/// Is this a top or left edge of a triangle wound so that [`edge`] is positive inside?
+fn is_top_left(from: Pos2, to: Pos2) -> bool {
+    let d: Vec2 = to - from;
+    (d.y == 0.0 && d.x > 0.0) || d.y < 0.0
+}
+
+fn mix(colors: [Color32; 3], weights: [f32; 3]) -> [f32; 4] {
+    let mut out = [0.0; 4];
+    for (color, weight) in colors.iter().zip(weights) {
+        for (o, c) in out.iter_mut().zip(color.to_array()) {
+            *o += weight * c as f32 / 255.0;
+        }
+    }
+    out
+}
+
+fn multiply(texel: [f32; 4], color: [f32; 4]) -> [f32; 4] {
+    [0, 1, 2, 3].map(|i| texel[i] * color[i])
+}
+
+/// Premultiplied "over" blending, in gamma space like egui's GPU renderers.
+fn blend(src: [f32; 4], dst: Color32) -> Color32 {
+    let dst = dst.to_array();
+    let [r, g, b, a] =
+        [0, 1, 2, 3].map(|i| src[i] * 255.0 + dst[i] as f32 * (1.0 - src[3].clamp(0.0, 1.0)));
+    let to_u8 = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+    Color32::from_rgba_premultiplied(to_u8(r), to_u8(g), to_u8(b), to_u8(a))
+}
+
+/// The premultiplied color of the texture at `uv`, in `0..=1`.
+fn sample(texture: &Texture, uv: Pos2) -> [f32; 4] {
+    let image = &texture.image;
+    let [width, height] = image.size;
+    if width == 0 || height == 0 {
+        return [0.0; 4];
+    }
+    let wrap_mode = texture.options.wrap_mode;
+    let texel = |x: i64, y: i64| -> [f32; 4] {
+        let x = wrap(x, width, wrap_mode);
+        let y = wrap(y, height, wrap_mode);
+        image.pixels[y * width + x]
+            .to_array()
+            .map(|c| c as f32 / 255.0)
+    };
+
+    let x = uv.x * width as f32;
+    let y = uv.y * height as f32;
+    match texture.options.magnification {
+        TextureFilter::Nearest => texel(x.floor() as i64, y.floor() as i64),
+        TextureFilter::Linear => {
+            let (x, y) = (x - 0.5, y - 0.5);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (tx, ty) = (x - x0, y - y0);
+            let (x0, y0) = (x0 as i64, y0 as i64);
+            let [c00, c10, c01, c11] = [
+                texel(x0, y0),
+                texel(x0 + 1, y0),
+                texel(x0, y0 + 1),
+                texel(x0 + 1, y0 + 1),
+            ];
+            [0, 1, 2, 3].map(|i| {
+                let top = c00[i] + (c10[i] - c00[i]) * tx;
+                let bottom = c01[i] + (c11[i] - c01[i]) * tx;
+                top + (bottom - top) * ty
+            })
+        }
+    }
+}
+
+fn wrap(i: i64, size: usize, wrap_mode: TextureWrapMode) -> usize {
+    let size = size as i64;
+    let i = match wrap_mode {
+        TextureWrapMode::ClampToEdge => i.clamp(0, size - 1),
+        TextureWrapMode::Repeat => i.rem_euclid(size),
+        TextureWrapMode::MirroredRepeat => {
+            let i = i.rem_euclid(2 * size);
+            if i < size { i } else { 2 * size - 1 - i }
+        }
+    };
+    i as usize
+}
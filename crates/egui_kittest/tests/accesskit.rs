@@ -1,9 +1,11 @@
 //! Tests the accesskit accessibility output of egui.
 
 use egui::{
-    CentralPanel, Context, RawInput, Window,
-    accesskit::{NodeId, Role, TreeUpdate},
+    AccessibilityAction, CentralPanel, Context, RawInput, Window,
+    accesskit::{Action, Live, NodeId, Role, Toggled, TreeUpdate},
 };
+use egui_kittest::Harness;
+use kittest::{NodeT as _, Queryable as _};
 
 /// Baseline test that asserts there are no spurious nodes in the
 /// accesskit output when the ui is empty.
@@ -151,6 +153,194 @@ fn window_children() {
     assert_button_exists(&output, "Hide", window_id);
 }
 
+#[test]
+fn announcements_are_live_regions() {
+    let ctx = Context::default();
+    ctx.enable_accesskit();
+
+    let run = |ctx: &Context, announce: bool| {
+        ctx.run(RawInput::default(), |ctx| {
+            if announce {
+                ctx.announce("Upload complete", egui::Priority::Polite);
+                ctx.announce("Connection lost", egui::Priority::Assertive);
+            }
+        })
+    };
+
+    let output = run(&ctx, true);
+    assert_eq!(
+        output.platform_output.events_description(),
+        "Connection lost. Upload complete"
+    );
+    let tree = output.platform_output.accesskit_update.unwrap();
+    let root = tree.tree.as_ref().unwrap().root;
+    for (label, role, live) in [
+        ("Upload complete", Role::Status, Live::Polite),
+        ("Connection lost", Role::Alert, Live::Assertive),
+    ] {
+        let (id, node) = tree
+            .nodes
+            .iter()
+            .find(|(_, node)| node.label() == Some(label))
+            .expect("Missing live region");
+        assert_eq!(node.role(), role);
+        assert_eq!(node.live(), Some(live));
+        assert_parent_child(&tree, root, *id);
+    }
+
+    // The live regions stay in the tree, so screen readers see later changes to them:
+    let output = run(&ctx, false);
+    assert!(output.platform_output.announcements.is_empty());
+    let tree = output.platform_output.accesskit_update.unwrap();
+    assert!(
+        tree.nodes
+            .iter()
+            .any(|(_, node)| node.label() == Some("Upload complete"))
+    );
+}
+
+#[test]
+fn slider_node() {
+    let output = accesskit_output_single_egui_frame(|ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let mut value = 25.0;
+            ui.add(
+                egui::Slider::new(&mut value, 0.0..=50.0)
+                    .step_by(5.0)
+                    .suffix(" m")
+                    .text("Distance"),
+            );
+        });
+    });
+
+    let (_, slider) = output
+        .nodes
+        .iter()
+        .find(|(_, node)| node.role() == Role::Slider)
+        .expect("Slider should exist in the accesskit output");
+
+    assert_eq!(slider.label(), Some("Distance"));
+    assert_eq!(slider.numeric_value(), Some(25.0));
+    assert_eq!(slider.min_numeric_value(), Some(0.0));
+    assert_eq!(slider.max_numeric_value(), Some(50.0));
+    assert_eq!(slider.numeric_value_step(), Some(5.0));
+    assert_eq!(slider.value(), Some("25 m"));
+}
+
+#[test]
+fn custom_widget_node() {
+    let output = accesskit_output_single_egui_frame(|ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let response = ui.label("★★★☆☆");
+            response.accessibility(|node| {
+                node.role(egui::WidgetType::Slider)
+                    .label("Rating")
+                    .value("3 of 5 stars")
+                    .numeric_value(3.0)
+                    .numeric_range(0.0..=5.0)
+                    .action(AccessibilityAction::Increment);
+            });
+        });
+    });
+
+    let (_, rating) = output
+        .nodes
+        .iter()
+        .find(|(_, node)| node.label() == Some("Rating"))
+        .expect("Custom widget should exist in the accesskit output");
+
+    assert_eq!(rating.role(), Role::Slider);
+    assert_eq!(rating.value(), Some("3 of 5 stars"));
+    assert_eq!(rating.numeric_value(), Some(3.0));
+    assert_eq!(rating.max_numeric_value(), Some(5.0));
+    assert!(rating.supports_action(Action::Increment));
+    assert!(!rating.supports_action(Action::Decrement));
+}
+
+#[test]
+fn table_cells_report_row_and_column() {
+    let output = accesskit_output_single_egui_frame(|ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            egui_extras::TableBuilder::new(ui)
+                .columns(egui_extras::Column::auto(), 2)
+                .header(20.0, |mut header| {
+                    header.col(|ui| {
+                        ui.label("Name");
+                    });
+                    header.col(|ui| {
+                        ui.label("Age");
+                    });
+                })
+                .body(|mut body| {
+                    for row_index in 0..3 {
+                        body.row(20.0, |mut row| {
+                            row.col(|ui| {
+                                ui.label(format!("Name {row_index}"));
+                            });
+                            row.col(|ui| {
+                                ui.label(format!("Age {row_index}"));
+                            });
+                        });
+                    }
+                });
+        });
+    });
+
+    let headers = output
+        .nodes
+        .iter()
+        .filter(|(_, node)| node.role() == Role::ColumnHeader)
+        .count();
+    assert_eq!(headers, 2);
+
+    let cells: Vec<_> = output
+        .nodes
+        .iter()
+        .filter(|(_, node)| node.role() == Role::Cell)
+        .map(|(_, node)| (node.row_index(), node.column_index()))
+        .collect();
+    assert_eq!(cells.len(), 6);
+    assert!(cells.contains(&(Some(0), Some(0))));
+    assert!(cells.contains(&(Some(2), Some(1))));
+}
+
+#[test]
+fn menu_items_report_position_and_expanded_state() {
+    let mut checked = false;
+    let mut harness = Harness::new_ui(|ui| {
+        egui::MenuBar::new().ui(ui, |ui| {
+            ui.menu_button("File", |ui| {
+                _ = ui.button("Open");
+                ui.checkbox(&mut checked, "Autosave");
+                ui.separator();
+                _ = ui.button("Quit");
+            });
+        });
+    });
+
+    let file = harness.get_by_label("File");
+    assert_eq!(file.accesskit_node().data().is_expanded(), Some(false));
+
+    file.click();
+    harness.run();
+
+    let file = harness.get_by_label("File");
+    assert_eq!(file.accesskit_node().data().is_expanded(), Some(true));
+
+    let open = harness.get_by_label("Open").accesskit_node();
+    assert_eq!(open.role(), Role::MenuItem);
+    assert_eq!(open.data().position_in_set(), Some(1));
+    assert_eq!(open.data().size_of_set(), Some(3));
+
+    let autosave = harness.get_by_label("Autosave").accesskit_node();
+    assert_eq!(autosave.role(), Role::MenuItemCheckBox);
+    assert_eq!(autosave.data().toggled(), Some(Toggled::False));
+    assert_eq!(autosave.data().position_in_set(), Some(2));
+
+    let quit = harness.get_by_label("Quit").accesskit_node();
+    assert_eq!(quit.data().position_in_set(), Some(3));
+}
+
 fn accesskit_output_single_egui_frame(run_ui: impl FnMut(&Context)) -> TreeUpdate {
     let ctx = Context::default();
     // Disable animations, so we do not need to wait for animations to end to see the result.
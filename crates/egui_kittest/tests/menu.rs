@@ -1,5 +1,5 @@
 use egui::containers::menu::{MenuBar, MenuConfig, SubMenuButton};
-use egui::{PopupCloseBehavior, Ui, include_image};
+use egui::{Key, Modifiers, PopupCloseBehavior, Ui, Vec2, include_image};
 use egui_kittest::{Harness, SnapshotResults};
 use kittest::Queryable as _;
 
@@ -184,3 +184,103 @@ fn menu_snapshots() {
     harness.run();
     results.add(harness.try_snapshot("menu/subsubmenu"));
 }
+
+#[test]
+fn menu_bar_keyboard_navigation() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui(|ui| {
+            MenuBar::new()
+                .config(MenuConfig::new().mnemonics(true))
+                .ui(ui, |ui| {
+                    ui.menu_button("&File", |ui| {
+                        _ = ui.button("Open");
+                        ui.menu_button("&Recent", |ui| {
+                            _ = ui.button("a.txt");
+                        });
+                    });
+                    ui.menu_button("&Edit", |ui| {
+                        _ = ui.button("Undo");
+                    });
+                });
+        });
+    harness.run();
+
+    // The mnemonic marker is not shown:
+    assert!(harness.query_by_label("&File").is_none());
+    harness.get_by_label("File");
+
+    // Alt + mnemonic opens the menu and focuses the first item:
+    harness.key_press_modifiers(Modifiers::ALT, Key::F);
+    harness.run();
+    assert!(harness.get_by_label("Open").is_focused());
+
+    // The mnemonic of a submenu opens it:
+    harness.key_press(Key::R);
+    harness.run();
+    assert!(harness.get_by_label("a.txt").is_focused());
+
+    // Left closes the submenu again:
+    harness.key_press(Key::ArrowLeft);
+    harness.run();
+    assert!(harness.query_by_label("a.txt").is_none());
+    assert!(harness.get_by_label_contains("Recent").is_focused());
+
+    // Right moves to the next menu (unless a submenu button is focused):
+    harness.key_press(Key::ArrowUp);
+    harness.run();
+    assert!(harness.get_by_label("Open").is_focused());
+    harness.key_press(Key::ArrowRight);
+    harness.run();
+    assert!(harness.query_by_label("Open").is_none());
+    assert!(harness.get_by_label("Undo").is_focused());
+
+    // Escape closes the menu and puts the focus back on the menu button:
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Undo").is_none());
+    assert!(harness.get_by_label("Edit").is_focused());
+
+    // Enter opens the menu again:
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(harness.get_by_label("Undo").is_focused());
+
+    // Enter activates the item, which closes the menu and gives the focus back to its button:
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(harness.query_by_label("Undo").is_none());
+    assert!(harness.get_by_label("Edit").is_focused());
+
+    // Pressing and releasing alt leaves the menu bar, and enters it again:
+    let tap_alt = |harness: &mut Harness<'_>| {
+        harness.input_mut().modifiers = Modifiers::ALT;
+        harness.run();
+        harness.input_mut().modifiers = Modifiers::NONE;
+        harness.run();
+    };
+    tap_alt(&mut harness);
+    assert!(!harness.get_by_label("Edit").is_focused());
+    tap_alt(&mut harness);
+    assert!(harness.get_by_label("File").is_focused());
+}
+
+#[test]
+fn menu_button_ampersand_is_plain_text_by_default() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui(|ui| {
+            MenuBar::new().ui(ui, |ui| {
+                ui.menu_button("Find & Replace", |ui| {
+                    _ = ui.button("Next");
+                });
+            });
+        });
+    harness.run();
+
+    // Without `MenuConfig::mnemonics` the `&` is kept, and no mnemonic is registered:
+    harness.get_by_label("Find & Replace");
+    harness.key_press_modifiers(Modifiers::ALT, Key::R);
+    harness.run();
+    assert!(harness.query_by_label("Next").is_none());
+}
@@ -138,3 +138,101 @@ fn test_scroll_down() {
         "The button was not clicked after scrolling down. (Probably not scrolled enough / at all)"
     );
 }
+
+#[test]
+fn test_text_edit_composed_input() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text: &mut String| {
+            ui.text_edit_singleline(text).request_focus();
+        },
+        String::new(),
+    );
+    harness.run();
+
+    harness.ime_composition(&["k", "か", "かn", "かな"], "かな");
+    harness.run();
+    assert_eq!(harness.state(), "かな");
+
+    // A dead key, e.g. ´ followed by e:
+    harness.composed_keys(&[egui::Key::Quote, egui::Key::E], "é");
+    harness.run();
+    assert_eq!(harness.state(), "かなé");
+
+    // Cancelling a composition leaves no text behind:
+    harness.ime_composition(&["k", "か", ""], "");
+    harness.run();
+    assert_eq!(harness.state(), "かなé");
+}
+
+#[test]
+fn test_software_renderer() {
+    use egui_kittest::{SoftwareTestRenderer, TestRenderer as _};
+
+    let ctx = egui::Context::default();
+    let mut input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            Vec2::new(100.0, 50.0),
+        )),
+        ..Default::default()
+    };
+    input
+        .viewports
+        .entry(egui::ViewportId::ROOT)
+        .or_default()
+        .native_pixels_per_point = Some(2.0);
+
+    let mut renderer = SoftwareTestRenderer::new();
+    let mut image = None;
+    for _ in 0..2 {
+        let output = ctx.run(input.clone(), |ctx| {
+            let painter = ctx.layer_painter(egui::LayerId::background());
+            painter.rect_filled(
+                egui::Rect::from_min_size(egui::Pos2::ZERO, Vec2::new(50.0, 50.0)),
+                0.0,
+                egui::Color32::RED,
+            );
+            painter.text(
+                egui::pos2(75.0, 25.0),
+                egui::Align2::CENTER_CENTER,
+                "Hi",
+                egui::FontId::proportional(20.0),
+                egui::Color32::WHITE,
+            );
+        });
+        renderer.handle_delta(&output.textures_delta);
+        image = Some(renderer.render_color_image(&ctx, &output));
+    }
+    let image = image.unwrap();
+
+    assert_eq!(image.size, [200, 100]);
+    assert_eq!(image[(10, 10)], egui::Color32::RED);
+    assert_eq!(image[(99, 99)], egui::Color32::RED);
+    assert_eq!(image[(100, 10)], egui::Color32::TRANSPARENT);
+    assert!(
+        image.pixels[100..]
+            .iter()
+            .any(|&pixel| pixel == egui::Color32::WHITE),
+        "the text should be painted"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Found Id clashes")]
+fn test_id_clashes_are_collected() {
+    let harness = Harness::new_ui(|ui| {
+        for _ in 0..2 {
+            // Two uis with the same id give their children the same ids:
+            ui.scope_builder(egui::UiBuilder::new().id("same"), |ui| {
+                let _ = ui.button("Click me");
+            });
+        }
+    });
+
+    let clashes = harness.id_clashes();
+    assert_eq!(clashes.len(), 1, "{clashes:#?}");
+    assert_eq!(clashes[0].first_location.file(), file!());
+    assert_eq!(clashes[0].second_location.file(), file!());
+
+    harness.assert_no_id_clashes();
+}
@@ -224,3 +224,11 @@ pub fn bounce_in_out(t: f32) -> f32 {
         0.5 * bounce_out(t * 2. - 1.) + 0.5
     }
 }
+
+/// Overshoots the target and settles, like a damped spring.
+///
+/// Unlike a physical spring, this always ends at exactly `1` when `t = 1`.
+#[inline]
+pub fn spring(t: f32) -> f32 {
+    1. - (-6. * t).exp() * (3. * PI * t).cos() * (1. - t)
+}
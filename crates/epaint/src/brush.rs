@@ -1,19 +1,263 @@
-use crate::{Rect, TextureId};
+use emath::{Pos2, Rect, Vec2};
 
-/// Controls texturing of a [`crate::RectShape`].
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+use crate::{Color32, Rgba, TextureId};
+
+/// Controls texturing of a [`crate::RectShape`], with either a texture or a gradient.
+///
+/// The brush color is multiplied with [`crate::RectShape::fill`],
+/// so usually you want to set that to [`Color32::WHITE`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Brush {
+    /// Fill the rectangle with a texture.
+    Texture {
+        /// Which texture to use.
+        fill_texture_id: TextureId,
+
+        /// What UV coordinates to use for the texture?
+        ///
+        /// To display the whole texture, set this to `Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0))`.
+        uv: Rect,
+    },
+
+    /// The color changes along the line from `start` to `end`.
+    ///
+    /// The positions are relative to the filled rectangle,
+    /// so that `(0, 0)` is the left top corner and `(1, 1)` the right bottom corner.
+    LinearGradient {
+        start: Pos2,
+        end: Pos2,
+        gradient: Gradient,
+    },
+
+    /// The color changes with the distance from `center`.
+    ///
+    /// The center and radius are relative to the filled rectangle (see [`Self::LinearGradient`]),
+    /// so a `radius` of `(0.5, 0.5)` around the center reaches the middle of each side.
+    RadialGradient {
+        center: Pos2,
+        radius: Vec2,
+        gradient: Gradient,
+    },
+
+    /// The color changes with the angle around `center`, clockwise from `start_angle`.
+    ///
+    /// The center is relative to the filled rectangle (see [`Self::LinearGradient`]).
+    /// The angles are in radians, with zero pointing to the right.
+    ConicGradient {
+        center: Pos2,
+        start_angle: f32,
+        gradient: Gradient,
+    },
+}
+
+impl Brush {
+    /// Left to right, from one color to another.
+    pub fn horizontal_gradient(left: Color32, right: Color32) -> Self {
+        Self::LinearGradient {
+            start: Pos2::new(0.0, 0.0),
+            end: Pos2::new(1.0, 0.0),
+            gradient: Gradient::new(left, right),
+        }
+    }
+
+    /// Top to bottom, from one color to another.
+    pub fn vertical_gradient(top: Color32, bottom: Color32) -> Self {
+        Self::LinearGradient {
+            start: Pos2::new(0.0, 0.0),
+            end: Pos2::new(0.0, 1.0),
+            gradient: Gradient::new(top, bottom),
+        }
+    }
+
+    /// From the center out to the middle of each side.
+    pub fn radial_gradient(inner: Color32, outer: Color32) -> Self {
+        Self::RadialGradient {
+            center: Pos2::new(0.5, 0.5),
+            radius: Vec2::splat(0.5),
+            gradient: Gradient::new(inner, outer),
+        }
+    }
+
+    /// The texture of this brush, or [`TextureId::default`] for gradients.
+    pub fn texture_id(&self) -> TextureId {
+        match self {
+            Self::Texture {
+                fill_texture_id, ..
+            } => *fill_texture_id,
+            Self::LinearGradient { .. }
+            | Self::RadialGradient { .. }
+            | Self::ConicGradient { .. } => TextureId::default(),
+        }
+    }
+
+    /// The gradient color at `pos`, when filling `rect`.
+    ///
+    /// Returns `None` for [`Self::Texture`].
+    pub fn gradient_color_at(&self, rect: Rect, pos: Pos2) -> Option<Color32> {
+        let relative = |pos: Pos2| {
+            let size = rect.size().max(Vec2::splat(f32::EPSILON));
+            ((pos - rect.min) / size).to_pos2()
+        };
+
+        match self {
+            Self::Texture { .. } => None,
+            Self::LinearGradient {
+                start,
+                end,
+                gradient,
+            } => {
+                let dir = *end - *start;
+                let t = (relative(pos) - *start).dot(dir) / dir.length_sq();
+                Some(gradient.color_at(t))
+            }
+            Self::RadialGradient {
+                center,
+                radius,
+                gradient,
+            } => {
+                let t = ((relative(pos) - *center) / *radius).length();
+                Some(gradient.color_at(t))
+            }
+            Self::ConicGradient {
+                center,
+                start_angle,
+                gradient,
+            } => {
+                let center = rect.min + center.to_vec2() * rect.size();
+                let angle = (pos - center).angle() - start_angle;
+                let t = angle.rem_euclid(std::f32::consts::TAU) / std::f32::consts::TAU;
+                Some(gradient.color_at(t))
+            }
+        }
+    }
+}
+
+/// How to blend between the colors of a [`Gradient`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct Brush {
-    /// If the rect should be filled with a texture, which one?
+pub enum GradientInterpolation {
+    /// Blend in `sRGB` gamma space.
     ///
-    /// The texture is multiplied with [`crate::RectShape::fill`].
-    pub fill_texture_id: TextureId,
+    /// This is what most image editors and web browsers do.
+    #[default]
+    Gamma,
 
-    /// What UV coordinates to use for the texture?
+    /// Blend in linear space.
     ///
-    /// To display a texture, set [`Self::fill_texture_id`],
-    /// and set this to `Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0))`.
+    /// This is physically correct, and avoids dark bands between saturated colors.
+    Linear,
+}
+
+/// Colors at positions along a gradient, used by [`Brush`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gradient {
+    /// Positions (usually in `0..=1`) and their colors, in increasing order of position.
     ///
-    /// Use [`Rect::ZERO`] to turn off texturing.
-    pub uv: Rect,
+    /// Before the first stop the first color is used, and after the last stop the last color.
+    pub stops: Vec<(f32, Color32)>,
+
+    pub interpolation: GradientInterpolation,
+}
+
+impl Gradient {
+    /// From one color to another.
+    pub fn new(from: Color32, to: Color32) -> Self {
+        Self::from_stops([(0.0, from), (1.0, to)])
+    }
+
+    pub fn from_stops(stops: impl Into<Vec<(f32, Color32)>>) -> Self {
+        Self {
+            stops: stops.into(),
+            interpolation: GradientInterpolation::default(),
+        }
+    }
+
+    #[inline]
+    pub fn with_interpolation(mut self, interpolation: GradientInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// The color at position `t`.
+    pub fn color_at(&self, t: f32) -> Color32 {
+        let Some(&(first_t, first_color)) = self.stops.first() else {
+            return Color32::TRANSPARENT;
+        };
+        if t.is_nan() || t <= first_t {
+            return first_color;
+        }
+
+        for window in self.stops.windows(2) {
+            let [(t0, c0), (t1, c1)] = [window[0], window[1]];
+            if t < t1 {
+                let f = if t0 < t1 { (t - t0) / (t1 - t0) } else { 1.0 };
+                return match self.interpolation {
+                    GradientInterpolation::Gamma => c0.lerp_to_gamma(c1, f),
+                    GradientInterpolation::Linear => {
+                        (Rgba::from(c0) * (1.0 - f) + Rgba::from(c1) * f).into()
+                    }
+                };
+            }
+        }
+
+        self.stops.last().map_or(first_color, |&(_, color)| color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        let gradient = Gradient::from_stops([
+            (0.0, Color32::BLACK),
+            (0.5, Color32::WHITE),
+            (1.0, Color32::RED),
+        ]);
+        assert_eq!(gradient.color_at(-1.0), Color32::BLACK);
+        assert_eq!(gradient.color_at(0.25), Color32::from_gray(128));
+        assert_eq!(gradient.color_at(0.5), Color32::WHITE);
+        assert_eq!(gradient.color_at(2.0), Color32::RED);
+        assert_eq!(gradient.color_at(f32::NAN), Color32::BLACK);
+
+        let linear = gradient.with_interpolation(GradientInterpolation::Linear);
+        assert_eq!(
+            linear.color_at(0.25),
+            Color32::from_gray(188),
+            "Half as much light"
+        );
+
+        let rect = Rect::from_min_max(pos2(100.0, 100.0), pos2(300.0, 200.0));
+        let brush = Brush::horizontal_gradient(Color32::BLACK, Color32::WHITE);
+        assert_eq!(
+            brush.gradient_color_at(rect, pos2(150.0, 0.0)),
+            Some(Color32::from_gray(64))
+        );
+
+        let brush = Brush::radial_gradient(Color32::BLACK, Color32::WHITE);
+        assert_eq!(
+            brush.gradient_color_at(rect, rect.center()),
+            Some(Color32::BLACK)
+        );
+        assert_eq!(
+            brush.gradient_color_at(rect, rect.right_center()),
+            Some(Color32::WHITE)
+        );
+
+        let brush = Brush::ConicGradient {
+            center: pos2(0.5, 0.5),
+            start_angle: 0.0,
+            gradient: Gradient::new(Color32::BLACK, Color32::WHITE),
+        };
+        assert_eq!(
+            brush.gradient_color_at(rect, rect.center_bottom()),
+            Some(Color32::from_gray(64)),
+            "A quarter turn clockwise"
+        );
+    }
 }
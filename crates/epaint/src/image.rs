@@ -1,6 +1,6 @@
 use emath::Vec2;
 
-use crate::{Color32, textures::TextureOptions};
+use crate::{Color32, Rgba, textures::TextureOptions};
 use std::sync::Arc;
 
 /// An image stored in RAM.
@@ -298,6 +298,35 @@ impl ColorImage {
         );
         Self::new([w, h], pixels)
     }
+
+    /// Half the width and height (rounded up), averaging each 2x2 block of pixels in linear space.
+    ///
+    /// This is how mipmaps are generated, see [`crate::textures::TextureOptions::mipmap_mode`].
+    pub fn downscale_half(&self) -> Self {
+        let [w, h] = self.size;
+        let size = [w.div_ceil(2).max(1), h.div_ceil(2).max(1)];
+        let mut pixels = Vec::with_capacity(size[0] * size[1]);
+        for y in 0..size[1] {
+            let rows = (2 * y)..(2 * y + 2).min(h);
+            for x in 0..size[0] {
+                let columns = (2 * x)..(2 * x + 2).min(w);
+                let mut sum = Rgba::TRANSPARENT;
+                let mut count = 0.0;
+                for row in rows.clone() {
+                    for column in columns.clone() {
+                        sum = sum + Rgba::from(self.pixels[row * w + column]);
+                        count += 1.0;
+                    }
+                }
+                pixels.push(if count == 0.0 {
+                    Color32::TRANSPARENT
+                } else {
+                    (sum * (1.0 / count)).into()
+                });
+            }
+        }
+        Self::new(size, pixels)
+    }
 }
 
 impl std::ops::Index<(usize, usize)> for ColorImage {
@@ -32,21 +32,28 @@ mod margin;
 mod margin_f32;
 mod mesh;
 pub mod mutex;
+mod nine_slice;
+mod path_builder;
+pub mod path_ops;
+mod sdf_rect;
 mod shadow;
 pub mod shape_transform;
 mod shapes;
 pub mod stats;
 mod stroke;
+#[cfg(feature = "svg")]
+pub mod svg;
 pub mod tessellator;
 pub mod text;
 mod texture_atlas;
 mod texture_handle;
 pub mod textures;
+mod tiled_texture;
 pub mod util;
 mod viewport;
 
 pub use self::{
-    brush::Brush,
+    brush::{Brush, Gradient, GradientInterpolation},
     color::ColorMode,
     corner_radius::CornerRadius,
     corner_radius_f32::CornerRadiusF32,
@@ -54,18 +61,23 @@ pub use self::{
     margin::Margin,
     margin_f32::*,
     mesh::{Mesh, Mesh16, Vertex},
+    nine_slice::NineSlice,
+    path_builder::{FillRule, PathBuilder, SubPath},
+    sdf_rect::SdfRect,
     shadow::Shadow,
     shapes::{
-        CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
+        BackdropBlurShape, CircleShape, CubicBezierShape, EllipseShape, Instance, InstancedMesh,
+        InstancedShape, MaskedShape, PaintCallback, PaintCallbackInfo, PathShape,
         QuadraticBezierShape, RectShape, Shape, TextShape,
     },
     stats::PaintStats,
-    stroke::{PathStroke, Stroke, StrokeKind},
+    stroke::{DashPattern, LineCap, LineJoin, PathStroke, Stroke, StrokeKind},
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
     texture_atlas::TextureAtlas,
     texture_handle::TextureHandle,
     textures::TextureManager,
+    tiled_texture::TiledTexture,
     viewport::ViewportInPixels,
 };
 
@@ -140,10 +152,26 @@ pub struct ClippedPrimitive {
     pub primitive: Primitive,
 }
 
-/// A rendering primitive - either a [`Mesh`] or a [`PaintCallback`].
+/// A rendering primitive - usually a [`Mesh`] or a [`PaintCallback`].
 #[derive(Clone, Debug)]
 pub enum Primitive {
     Mesh(Mesh),
+
+    /// The same mesh painted many times.
+    ///
+    /// Only produced when [`TessellationOptions::instanced_primitives`] is set.
+    Instanced(InstancedMesh),
+
+    /// A rounded rectangle to be painted with a signed distance field.
+    ///
+    /// Only produced when [`TessellationOptions::sdf_rects`] is set.
+    SdfRect(SdfRect),
+
+    /// Blur what has been painted so far, within a rounded rectangle.
+    ///
+    /// Only produced when [`TessellationOptions::backdrop_blur`] is set.
+    BackdropBlur(BackdropBlurShape),
+
     Callback(PaintCallback),
 }
 
@@ -0,0 +1,130 @@
+use emath::{Rect, Vec2, pos2};
+
+use crate::{Color32, MarginF32, Mesh, TextureId};
+
+/// How to stretch an image so that its borders keep their size,
+/// also known as nine-patch or 9-slice scaling.
+///
+/// The image is cut into a 3×3 grid by the [`Self::insets`]:
+/// the corners keep their size, the edges are stretched along their length,
+/// and the middle is stretched in both directions.
+/// This is useful for skinning frames, speech bubbles, and buttons.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NineSlice {
+    /// The size of the borders, in the same unit as the size of the image (usually texels).
+    pub insets: MarginF32,
+
+    /// The on-screen size of the borders is [`Self::insets`] times this.
+    ///
+    /// The default is `1.0`.
+    pub scale: f32,
+}
+
+impl NineSlice {
+    pub fn new(insets: impl Into<MarginF32>) -> Self {
+        Self {
+            insets: insets.into(),
+            scale: 1.0,
+        }
+    }
+
+    /// Scale the borders on screen, e.g. for a pixel-art skin.
+    #[inline]
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Stretch the part `uv` of a texture of `image_size` over `rect`.
+    ///
+    /// If `rect` is too small for the borders, they are shrunk to fit.
+    pub fn mesh(
+        &self,
+        texture_id: TextureId,
+        rect: Rect,
+        uv: Rect,
+        image_size: Vec2,
+        tint: Color32,
+    ) -> Mesh {
+        let Self { insets, scale } = *self;
+
+        // Where to cut, from the start, as a fraction of the whole:
+        let uv_cuts = |start: f32, end: f32, image_size: f32, uv_min: f32, uv_max: f32| {
+            let size = image_size * (uv_max - uv_min);
+            if size <= 0.0 {
+                return [uv_min, uv_min, uv_max, uv_max];
+            }
+            let start_uv = (uv_max - uv_min) * (start / size);
+            let end_uv = (uv_max - uv_min) * (end / size);
+            [uv_min, uv_min + start_uv, uv_max - end_uv, uv_max]
+        };
+        let pos_cuts = |start: f32, end: f32, min: f32, max: f32| {
+            let (start, end) = (start * scale, end * scale);
+            let shrink = if start + end > max - min && start + end > 0.0 {
+                (max - min) / (start + end)
+            } else {
+                1.0
+            };
+            [min, min + start * shrink, max - end * shrink, max]
+        };
+
+        let xs = pos_cuts(insets.left, insets.right, rect.min.x, rect.max.x);
+        let ys = pos_cuts(insets.top, insets.bottom, rect.min.y, rect.max.y);
+        let us = uv_cuts(insets.left, insets.right, image_size.x, uv.min.x, uv.max.x);
+        let vs = uv_cuts(insets.top, insets.bottom, image_size.y, uv.min.y, uv.max.y);
+
+        let mut mesh = Mesh::with_texture(texture_id);
+        for row in 0..3 {
+            for col in 0..3 {
+                if xs[col] < xs[col + 1] && ys[row] < ys[row + 1] {
+                    mesh.add_rect_with_uv(
+                        Rect::from_min_max(pos2(xs[col], ys[row]), pos2(xs[col + 1], ys[row + 1])),
+                        Rect::from_min_max(pos2(us[col], vs[row]), pos2(us[col + 1], vs[row + 1])),
+                        tint,
+                    );
+                }
+            }
+        }
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nine_slice() {
+        let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+        let image_size = Vec2::splat(30.0);
+        let nine_slice = NineSlice::new(MarginF32::same(10.0));
+
+        let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+        let mesh = nine_slice.mesh(TextureId::default(), rect, uv, image_size, Color32::WHITE);
+        assert_eq!(mesh.vertices.len(), 9 * 4);
+        assert_eq!(mesh.calc_bounds(), rect);
+
+        // The top left corner keeps its size:
+        let corner = &mesh.vertices[..4];
+        assert_eq!(corner[3].pos, pos2(10.0, 10.0));
+        assert_eq!(corner[3].uv, pos2(1.0 / 3.0, 1.0 / 3.0));
+
+        // The bottom right corner is also not stretched:
+        let corner = &mesh.vertices[8 * 4..];
+        assert_eq!(corner[0].pos, pos2(90.0, 40.0));
+        assert!((corner[0].uv - pos2(2.0 / 3.0, 2.0 / 3.0)).length() < 1e-6);
+
+        // Too small for the borders, so they shrink and the middle disappears:
+        let small = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 40.0));
+        let mesh = nine_slice.with_scale(2.0).mesh(
+            TextureId::default(),
+            small,
+            uv,
+            image_size,
+            Color32::WHITE,
+        );
+        assert_eq!(mesh.vertices.len(), 4 * 4, "Only the corners are left");
+        assert_eq!(mesh.vertices[3].pos, pos2(5.0, 20.0));
+    }
+}
@@ -0,0 +1,603 @@
+//! Build paths out of lines, arcs, and curves, see [`PathBuilder`].
+
+use emath::{Pos2, Rot2, Vec2, vec2};
+
+use crate::{
+    Color32, CubicBezierShape, Mesh, PathShape, PathStroke, QuadraticBezierShape, Shape, Stroke,
+};
+
+/// How to decide what is inside a path that overlaps itself, or consists of several sub-paths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FillRule {
+    /// A point is inside if the path winds around it a non-zero number of times.
+    ///
+    /// A sub-path inside of another one is only a hole if it goes the other way around.
+    /// This is `nonzero` in SVG.
+    #[default]
+    NonZero,
+
+    /// A point is inside if a ray from it crosses the path an odd number of times.
+    ///
+    /// A sub-path inside of another one is always a hole.
+    /// This is `evenodd` in SVG.
+    EvenOdd,
+}
+
+/// One continuous part of a [`PathBuilder`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubPath {
+    /// The curves and arcs are flattened into line segments between these points.
+    pub points: Vec<Pos2>,
+
+    /// Is there a line from the last point back to the first one?
+    pub closed: bool,
+}
+
+impl SubPath {
+    /// The last point, or `start` (which is then added) if the sub-path is empty.
+    fn start_or(&mut self, start: Pos2) -> Pos2 {
+        if let Some(&last) = self.points.last() {
+            last
+        } else {
+            self.points.push(start);
+            start
+        }
+    }
+}
+
+/// Build a path out of lines, arcs, and curves, for instance for a diagram or a gauge.
+///
+/// The curves and arcs are flattened into lines as they are added (see [`Self::with_tolerance`]).
+/// Unlike [`PathShape`], the path can consist of several sub-paths,
+/// and the fill can be concave and have holes (see [`FillRule`]).
+///
+/// ```
+/// # use epaint::{pos2, Color32, PathBuilder, Stroke};
+/// let gauge = PathBuilder::new()
+///     .arc(pos2(50.0, 50.0), 40.0, 0.75 * std::f32::consts::PI, 1.5 * std::f32::consts::PI)
+///     .to_shape(Color32::TRANSPARENT, Stroke::new(8.0, Color32::RED));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathBuilder {
+    subpaths: Vec<SubPath>,
+    tolerance: f32,
+    fill_rule: FillRule,
+}
+
+impl Default for PathBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PathBuilder {
+    pub fn new() -> Self {
+        Self {
+            subpaths: vec![],
+            tolerance: 0.1,
+            fill_rule: FillRule::default(),
+        }
+    }
+
+    /// The maximum distance between a curve or arc and the lines it is flattened into, in points.
+    ///
+    /// The default is `0.1`.
+    /// This must be set before adding any curves.
+    #[inline]
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// How the fill handles overlaps and holes, see [`FillRule`].
+    #[inline]
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// The sub-paths added so far.
+    #[inline]
+    pub fn subpaths(&self) -> &[SubPath] {
+        &self.subpaths
+    }
+
+    #[inline]
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Where the next line or curve will start from, if anywhere.
+    ///
+    /// After [`Self::close`], this is the start of the closed sub-path.
+    pub fn current_point(&self) -> Option<Pos2> {
+        let subpath = self.subpaths.last()?;
+        if subpath.closed {
+            subpath.points.first().copied()
+        } else {
+            subpath.points.last().copied()
+        }
+    }
+
+    /// Start a new sub-path at `pos`.
+    pub fn move_to(mut self, pos: Pos2) -> Self {
+        self.subpaths.push(SubPath {
+            points: vec![pos],
+            closed: false,
+        });
+        self
+    }
+
+    /// A straight line from the current point to `pos`.
+    ///
+    /// If there is no current point, this starts a new sub-path at `pos`.
+    pub fn line_to(mut self, pos: Pos2) -> Self {
+        let subpath = self.current_subpath();
+        if subpath.points.last() != Some(&pos) {
+            subpath.points.push(pos);
+        }
+        self
+    }
+
+    /// A quadratic Bézier curve from the current point to `to`.
+    pub fn quadratic_to(mut self, control: Pos2, to: Pos2) -> Self {
+        let tolerance = self.tolerance;
+        let subpath = self.current_subpath();
+        let from = subpath.start_or(control);
+        let curve = QuadraticBezierShape::from_points_stroke(
+            [from, control, to],
+            false,
+            Color32::TRANSPARENT,
+            Stroke::NONE,
+        );
+        subpath
+            .points
+            .extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+        self
+    }
+
+    /// A cubic Bézier curve from the current point to `to`.
+    pub fn cubic_to(mut self, control1: Pos2, control2: Pos2, to: Pos2) -> Self {
+        let tolerance = self.tolerance;
+        let subpath = self.current_subpath();
+        let from = subpath.start_or(control1);
+        let curve = CubicBezierShape::from_points_stroke(
+            [from, control1, control2, to],
+            false,
+            Color32::TRANSPARENT,
+            Stroke::NONE,
+        );
+        subpath
+            .points
+            .extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+        self
+    }
+
+    /// A circular arc around `center`.
+    ///
+    /// The angles are in radians, with zero pointing to the right,
+    /// and a positive `sweep_angle` going clockwise (since y points down).
+    ///
+    /// A straight line connects the current point (if any) to the start of the arc.
+    pub fn arc(self, center: Pos2, radius: f32, start_angle: f32, sweep_angle: f32) -> Self {
+        self.ellipse_arc(center, Vec2::splat(radius), 0.0, start_angle, sweep_angle)
+    }
+
+    /// An elliptical arc around `center`, with the ellipse rotated by `rotation` radians.
+    ///
+    /// See [`Self::arc`] for how the angles work.
+    pub fn ellipse_arc(
+        mut self,
+        center: Pos2,
+        radii: Vec2,
+        rotation: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+    ) -> Self {
+        let rot = Rot2::from_angle(rotation);
+        let point_at =
+            |angle: f32| center + rot * vec2(radii.x * angle.cos(), radii.y * angle.sin());
+
+        // How many segments do we need to stay within the tolerance?
+        let radius = radii.max_elem();
+        let max_step = if self.tolerance < radius {
+            2.0 * (1.0 - self.tolerance / radius).acos()
+        } else {
+            std::f32::consts::FRAC_PI_2
+        };
+        let segments = ((sweep_angle.abs() / max_step).ceil() as usize).clamp(1, 1024);
+
+        let start = point_at(start_angle);
+        let subpath = self.current_subpath();
+        if subpath.points.last() != Some(&start) {
+            subpath.points.push(start);
+        }
+        subpath.points.extend(
+            (1..=segments)
+                .map(|i| point_at(start_angle + sweep_angle * i as f32 / segments as f32)),
+        );
+        self
+    }
+
+    /// An elliptical arc from the current point to `to`, like the `A` command in SVG.
+    ///
+    /// * `radii`: the radii of the ellipse, which are scaled up if needed to reach `to`.
+    /// * `rotation`: the rotation of the ellipse, in radians.
+    /// * `large_arc`: go the long way around the ellipse?
+    /// * `sweep`: go clockwise (since y points down)?
+    pub fn arc_to(
+        self,
+        radii: Vec2,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Pos2,
+    ) -> Self {
+        let Some(from) = self.current_point() else {
+            return self.move_to(to);
+        };
+        let mut radii = radii.abs();
+        if from == to {
+            return self;
+        }
+        if radii.x == 0.0 || radii.y == 0.0 {
+            return self.line_to(to);
+        }
+
+        // See https://www.w3.org/TR/SVG11/implnote.html#ArcConversionEndpointToCenter
+        let rot = Rot2::from_angle(rotation);
+        let p = rot.inverse() * (0.5 * (from - to));
+
+        // Scale up the radii if the ellipse is too small to reach:
+        let lambda = (p.x / radii.x).powi(2) + (p.y / radii.y).powi(2);
+        if 1.0 < lambda {
+            radii *= lambda.sqrt();
+        }
+
+        let (rx2, ry2) = (radii.x * radii.x, radii.y * radii.y);
+        let numerator = (rx2 * ry2 - rx2 * p.y * p.y - ry2 * p.x * p.x).max(0.0);
+        let denominator = rx2 * p.y * p.y + ry2 * p.x * p.x;
+        let mut factor = (numerator / denominator).sqrt();
+        if large_arc == sweep {
+            factor = -factor;
+        }
+        let center_local = factor * vec2(radii.x * p.y / radii.y, -radii.y * p.x / radii.x);
+        let center = rot * center_local + 0.5 * (from.to_vec2() + to.to_vec2());
+
+        let angle_of = |v: Vec2| v.y.atan2(v.x);
+        let start = vec2(
+            (p.x - center_local.x) / radii.x,
+            (p.y - center_local.y) / radii.y,
+        );
+        let end = vec2(
+            (-p.x - center_local.x) / radii.x,
+            (-p.y - center_local.y) / radii.y,
+        );
+        let start_angle = angle_of(start);
+        let mut sweep_angle = angle_of(end) - start_angle;
+        if sweep && sweep_angle < 0.0 {
+            sweep_angle += std::f32::consts::TAU;
+        } else if !sweep && 0.0 < sweep_angle {
+            sweep_angle -= std::f32::consts::TAU;
+        }
+
+        let mut builder =
+            self.ellipse_arc(center.to_pos2(), radii, rotation, start_angle, sweep_angle);
+        if let Some(last) = builder
+            .subpaths
+            .last_mut()
+            .and_then(|s| s.points.last_mut())
+        {
+            *last = to; // avoid rounding errors
+        }
+        builder
+    }
+
+    /// Connect the current sub-path back to where it started.
+    ///
+    /// The next line or curve will start a new sub-path from there.
+    pub fn close(mut self) -> Self {
+        if let Some(subpath) = self.subpaths.last_mut() {
+            if 2 <= subpath.points.len() && subpath.points.first() == subpath.points.last() {
+                subpath.points.pop();
+            }
+            subpath.closed = true;
+        }
+        self
+    }
+
+    /// The sub-path to add to, starting a new one if needed.
+    ///
+    /// If there is no current point, the new sub-path is empty, and starts at whatever is added next.
+    fn current_subpath(&mut self) -> &mut SubPath {
+        let needs_new = self.subpaths.last().is_none_or(|subpath| subpath.closed);
+        if needs_new {
+            let points = self.current_point().into_iter().collect();
+            self.subpaths.push(SubPath {
+                points,
+                closed: false,
+            });
+        }
+        self.subpaths.last_mut().expect("just pushed")
+    }
+
+    /// Fill the inside of the path (according to [`Self::fill_rule`]),
+    /// as if all sub-paths were closed.
+    ///
+    /// Unlike the fill of a [`PathShape`], the edges of this fill are not anti-aliased,
+    /// so you usually want to also paint a stroke along the path.
+    pub fn fill_mesh(&self, color: Color32) -> Mesh {
+        let mut mesh = Mesh::default();
+        fill_trapezoids(&self.subpaths, self.fill_rule, |[a, b, c, d]| {
+            let idx = mesh.vertices.len() as u32;
+            for pos in [a, b, c, d] {
+                mesh.colored_vertex(pos, color);
+            }
+            mesh.add_triangle(idx, idx + 1, idx + 2);
+            mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+        });
+        mesh
+    }
+
+    /// Fill and stroke the path.
+    ///
+    /// A single convex closed sub-path is filled with a [`PathShape`], which is anti-aliased.
+    /// Anything else is filled with [`Self::fill_mesh`].
+    pub fn to_shape(&self, fill: Color32, stroke: impl Into<PathStroke>) -> Shape {
+        let stroke = stroke.into();
+        let mut shapes = vec![];
+
+        let convex = match self.subpaths.as_slice() {
+            [subpath] if subpath.closed && is_convex(&subpath.points) => Some(subpath),
+            _ => None,
+        };
+
+        if let Some(subpath) = convex {
+            shapes.push(PathShape::convex_polygon(subpath.points.clone(), fill, stroke).into());
+        } else {
+            if fill != Color32::TRANSPARENT {
+                let mesh = self.fill_mesh(fill);
+                if !mesh.is_empty() {
+                    shapes.push(Shape::mesh(mesh));
+                }
+            }
+            if !stroke.is_empty() {
+                for subpath in &self.subpaths {
+                    shapes.push(
+                        PathShape {
+                            points: subpath.points.clone(),
+                            closed: subpath.closed,
+                            fill: Color32::TRANSPARENT,
+                            stroke: stroke.clone(),
+                        }
+                        .into(),
+                    );
+                }
+            }
+        }
+
+        Shape::Vec(shapes)
+    }
+}
+
+/// Is this polygon convex (in either winding order)?
+fn is_convex(points: &[Pos2]) -> bool {
+    if points.len() < 3 {
+        return false;
+    }
+    let n = points.len();
+    let mut sign = 0.0;
+    for i in 0..n {
+        let a = points[(i + 1) % n] - points[i];
+        let b = points[(i + 2) % n] - points[(i + 1) % n];
+        let cross = a.x * b.y - a.y * b.x;
+        if cross != 0.0 {
+            if sign * cross < 0.0 {
+                return false;
+            }
+            sign = cross;
+        }
+    }
+    true
+}
+
+/// Split the inside of the sub-paths into horizontal bands, and each band into trapezoids.
+///
+/// Each trapezoid is given as `[top left, top right, bottom left, bottom right]`.
+fn fill_trapezoids(
+    subpaths: &[SubPath],
+    fill_rule: FillRule,
+    mut add_trapezoid: impl FnMut([Pos2; 4]),
+) {
+    struct Edge {
+        top: Pos2,
+        bottom: Pos2,
+
+        /// +1 if the path goes down along this edge, -1 if up.
+        winding: i32,
+    }
+
+    impl Edge {
+        fn x_at(&self, y: f32) -> f32 {
+            let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+            self.top.x + t * (self.bottom.x - self.top.x)
+        }
+    }
+
+    let mut edges = vec![];
+    for subpath in subpaths {
+        let n = subpath.points.len();
+        for i in 0..n {
+            let (a, b) = (subpath.points[i], subpath.points[(i + 1) % n]);
+            if a.y < b.y {
+                edges.push(Edge {
+                    top: a,
+                    bottom: b,
+                    winding: 1,
+                });
+            } else if b.y < a.y {
+                edges.push(Edge {
+                    top: b,
+                    bottom: a,
+                    winding: -1,
+                });
+            }
+        }
+    }
+
+    // The bands go between all the corners and crossings:
+    let mut ys: Vec<f32> = edges.iter().flat_map(|e| [e.top.y, e.bottom.y]).collect();
+    for (i, e0) in edges.iter().enumerate() {
+        for e1 in &edges[i + 1..] {
+            let (r, s) = (e0.bottom - e0.top, e1.bottom - e1.top);
+            let denom = r.x * s.y - r.y * s.x;
+            if denom == 0.0 {
+                continue;
+            }
+            let q = e1.top - e0.top;
+            let t = (q.x * s.y - q.y * s.x) / denom;
+            let u = (q.x * r.y - q.y * r.x) / denom;
+            if 0.0 < t && t < 1.0 && 0.0 < u && u < 1.0 {
+                ys.push(e0.top.y + t * r.y);
+            }
+        }
+    }
+    ys.sort_by(f32::total_cmp);
+    ys.dedup();
+
+    let mut crossings = vec![];
+    for band in ys.windows(2) {
+        let (y0, y1) = (band[0], band[1]);
+        let y_mid = 0.5 * (y0 + y1);
+        if y1 - y0 < 1e-4 {
+            continue;
+        }
+
+        crossings.clear();
+        crossings.extend(
+            edges
+                .iter()
+                .filter(|e| e.top.y <= y_mid && y_mid < e.bottom.y)
+                .map(|e| (e.x_at(y_mid), e.x_at(y0), e.x_at(y1), e.winding)),
+        );
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        let mut left = None;
+        for &(_, x0, x1, edge_winding) in &crossings {
+            winding += edge_winding;
+            let inside = match fill_rule {
+                FillRule::NonZero => winding != 0,
+                FillRule::EvenOdd => winding % 2 != 0,
+            };
+            match (left, inside) {
+                (None, true) => left = Some((x0, x1)),
+                (Some((left0, left1)), false) => {
+                    add_trapezoid([
+                        Pos2::new(left0, y0),
+                        Pos2::new(x0, y0),
+                        Pos2::new(left1, y1),
+                        Pos2::new(x1, y1),
+                    ]);
+                    left = None;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    fn fill_area(path: &PathBuilder) -> f32 {
+        let mesh = path.fill_mesh(Color32::WHITE);
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+            })
+            .sum()
+    }
+
+    fn square(path: PathBuilder, min: Pos2, size: f32, clockwise: bool) -> PathBuilder {
+        let corners = [
+            min,
+            min + vec2(size, 0.0),
+            min + vec2(size, size),
+            min + vec2(0.0, size),
+        ];
+        let mut path = path.move_to(corners[0]);
+        if clockwise {
+            for &corner in &corners[1..] {
+                path = path.line_to(corner);
+            }
+        } else {
+            for &corner in corners[1..].iter().rev() {
+                path = path.line_to(corner);
+            }
+        }
+        path.close()
+    }
+
+    #[test]
+    fn test_fill_rules() {
+        let outer_and_inner = |clockwise, fill_rule| {
+            let path = square(PathBuilder::new(), pos2(0.0, 0.0), 4.0, true);
+            square(path, pos2(1.0, 1.0), 2.0, clockwise).with_fill_rule(fill_rule)
+        };
+
+        assert_eq!(fill_area(&outer_and_inner(true, FillRule::NonZero)), 16.0);
+        assert_eq!(fill_area(&outer_and_inner(false, FillRule::NonZero)), 12.0);
+        assert_eq!(fill_area(&outer_and_inner(true, FillRule::EvenOdd)), 12.0);
+        assert_eq!(fill_area(&outer_and_inner(false, FillRule::EvenOdd)), 12.0);
+
+        // A self-intersecting bow tie:
+        let bow_tie = PathBuilder::new()
+            .move_to(pos2(0.0, 0.0))
+            .line_to(pos2(2.0, 2.0))
+            .line_to(pos2(2.0, 0.0))
+            .line_to(pos2(0.0, 2.0))
+            .close();
+        assert_eq!(fill_area(&bow_tie), 2.0);
+    }
+
+    #[test]
+    fn test_arcs() {
+        let center = pos2(10.0, 10.0);
+        let circle = PathBuilder::new()
+            .arc(center, 5.0, 0.0, std::f32::consts::TAU)
+            .close();
+        let points = &circle.subpaths()[0].points;
+        assert!(
+            points
+                .iter()
+                .all(|p| (p.distance(center) - 5.0).abs() < 1e-4)
+        );
+        let area = fill_area(&circle);
+        let expected = std::f32::consts::PI * 25.0;
+        let tolerance = std::f32::consts::TAU * 5.0 * 0.1; // circumference × tolerance
+        assert!((area - expected).abs() < tolerance, "{area}");
+
+        // A half circle from the left to the right, going over the top:
+        let half = PathBuilder::new().move_to(pos2(0.0, 10.0)).arc_to(
+            Vec2::splat(10.0),
+            0.0,
+            false,
+            true,
+            pos2(20.0, 10.0),
+        );
+        let points = &half.subpaths()[0].points;
+        assert_eq!(*points.last().unwrap(), pos2(20.0, 10.0));
+        let top = points.iter().min_by(|a, b| a.y.total_cmp(&b.y)).unwrap();
+        assert!(
+            (top.y - 0.0).abs() < 0.1 && (top.x - 10.0).abs() < 1.0,
+            "{top:?}"
+        );
+    }
+}
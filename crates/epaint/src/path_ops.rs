@@ -0,0 +1,347 @@
+//! Boolean operations on closed paths (polygons).
+//!
+//! The polygons must be simple (not self-intersecting), but may be concave and use either winding order.
+//! Degenerate cases, like a corner of one polygon lying exactly on an edge of the other,
+//! may give wrong results.
+//!
+//! ```
+//! # use epaint::{path_ops, pos2};
+//! let a = [pos2(0.0, 0.0), pos2(2.0, 0.0), pos2(2.0, 2.0), pos2(0.0, 2.0)];
+//! let b = [pos2(1.0, 1.0), pos2(3.0, 1.0), pos2(3.0, 3.0), pos2(1.0, 3.0)];
+//! let overlap = path_ops::intersection(&a, &b);
+//! assert_eq!(overlap.len(), 1);
+//! assert_eq!(path_ops::area(&overlap[0]), 1.0);
+//! ```
+//!
+//! Note that [`crate::PathShape`] can only fill convex polygons.
+
+use emath::{Pos2, Vec2};
+
+/// The outline of the area covered by `a`, `b`, or both.
+///
+/// Usually one polygon, but two if `a` and `b` don't touch.
+/// If the union has holes, they are returned as separate polygons.
+pub fn union(a: &[Pos2], b: &[Pos2]) -> Vec<Vec<Pos2>> {
+    boolean_op(a, b, Op::Union)
+}
+
+/// The outline of the area covered by both `a` and `b`.
+///
+/// This can be zero, one, or (if a polygon is concave) several polygons.
+pub fn intersection(a: &[Pos2], b: &[Pos2]) -> Vec<Vec<Pos2>> {
+    boolean_op(a, b, Op::Intersection)
+}
+
+/// The area enclosed by a polygon, regardless of winding order.
+pub fn area(polygon: &[Pos2]) -> f32 {
+    let n = polygon.len();
+    let twice_signed_area: f32 = (0..n)
+        .map(|i| cross(polygon[i].to_vec2(), polygon[(i + 1) % n].to_vec2()))
+        .sum();
+    0.5 * twice_signed_area.abs()
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Is `point` inside the polygon (using the even-odd rule)?
+pub fn contains(polygon: &[Pos2], point: Pos2) -> bool {
+    let n = polygon.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// ----------------------------------------------------------------------------
+// Greiner-Hormann polygon clipping.
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Union,
+    Intersection,
+}
+
+#[derive(Clone, Copy)]
+struct Vertex {
+    pos: Pos2,
+
+    /// For intersection points: the index of the same point in the other polygon.
+    neighbor: Option<usize>,
+
+    /// For intersection points: does the polygon enter the other polygon here?
+    entry: bool,
+
+    visited: bool,
+}
+
+/// Where two edges cross.
+struct Crossing {
+    pos: Pos2,
+
+    /// Edge index and position along the edge (0-1) in each polygon.
+    edges: [(usize, f32); 2],
+}
+
+fn boolean_op(a: &[Pos2], b: &[Pos2], op: Op) -> Vec<Vec<Pos2>> {
+    if a.len() < 3 || b.len() < 3 {
+        return match op {
+            Op::Union => [a, b]
+                .into_iter()
+                .filter(|p| 3 <= p.len())
+                .map(|p| p.to_vec())
+                .collect(),
+            Op::Intersection => vec![],
+        };
+    }
+
+    let crossings = find_crossings(a, b);
+
+    if crossings.is_empty() {
+        // Either one contains the other, or they are disjoint:
+        let a_in_b = contains(b, a[0]);
+        let b_in_a = contains(a, b[0]);
+        return match (op, a_in_b, b_in_a) {
+            (Op::Union, true, _) | (Op::Intersection, _, true) => vec![b.to_vec()],
+            (Op::Union, _, true) | (Op::Intersection, true, _) => vec![a.to_vec()],
+            (Op::Union, false, false) => vec![a.to_vec(), b.to_vec()],
+            (Op::Intersection, false, false) => vec![],
+        };
+    }
+
+    let mut polygons = [
+        with_crossings(a, b, &crossings, 0),
+        with_crossings(b, a, &crossings, 1),
+    ];
+    link_neighbors(&mut polygons, crossings.len());
+
+    if op == Op::Union {
+        // Walk along the outside of the other polygon instead of the inside:
+        for vertex in polygons.iter_mut().flatten() {
+            vertex.entry = !vertex.entry;
+        }
+    }
+
+    trace(&mut polygons)
+}
+
+fn find_crossings(a: &[Pos2], b: &[Pos2]) -> Vec<Crossing> {
+    let mut crossings = vec![];
+    for i in 0..a.len() {
+        let (p, r) = (a[i], a[(i + 1) % a.len()] - a[i]);
+        for j in 0..b.len() {
+            let (q, s) = (b[j], b[(j + 1) % b.len()] - b[j]);
+            let denom = cross(r, s);
+            if denom.abs() <= f32::EPSILON * r.length() * s.length() {
+                continue; // parallel
+            }
+            let t = cross(q - p, s) / denom;
+            let u = cross(q - p, r) / denom;
+            if (0.0..1.0).contains(&t) && (0.0..1.0).contains(&u) {
+                crossings.push(Crossing {
+                    pos: p + t * r,
+                    edges: [(i, t), (j, u)],
+                });
+            }
+        }
+    }
+    crossings
+}
+
+/// The vertices of `polygon` with the crossings inserted,
+/// and the crossings marked as entering or exiting `other`.
+///
+/// The `neighbor` of each crossing is temporarily set to its index in `crossings`.
+fn with_crossings(
+    polygon: &[Pos2],
+    other: &[Pos2],
+    crossings: &[Crossing],
+    which: usize,
+) -> Vec<Vertex> {
+    let mut on_edge: Vec<Vec<(f32, usize)>> = vec![vec![]; polygon.len()];
+    for (index, crossing) in crossings.iter().enumerate() {
+        let (edge, t) = crossing.edges[which];
+        on_edge[edge].push((t, index));
+    }
+
+    let mut inside = contains(other, polygon[0]);
+    let mut vertices = Vec::with_capacity(polygon.len() + crossings.len());
+    for (&pos, mut crossings_on_edge) in polygon.iter().zip(on_edge) {
+        vertices.push(Vertex {
+            pos,
+            neighbor: None,
+            entry: false,
+            visited: false,
+        });
+        crossings_on_edge.sort_by(|(t0, _), (t1, _)| t0.total_cmp(t1));
+        for (_, index) in crossings_on_edge {
+            inside = !inside;
+            vertices.push(Vertex {
+                pos: crossings[index].pos,
+                neighbor: Some(index),
+                entry: inside,
+                visited: false,
+            });
+        }
+    }
+    vertices
+}
+
+/// Replace the crossing indices in `neighbor` with indices into the other polygon.
+fn link_neighbors(polygons: &mut [Vec<Vertex>; 2], num_crossings: usize) {
+    let index_in = |polygon: &[Vertex]| {
+        let mut index_of_crossing = vec![0; num_crossings];
+        for (i, vertex) in polygon.iter().enumerate() {
+            if let Some(crossing) = vertex.neighbor {
+                index_of_crossing[crossing] = i;
+            }
+        }
+        index_of_crossing
+    };
+    let index_of_crossing = [index_in(&polygons[0]), index_in(&polygons[1])];
+    for (which, polygon) in polygons.iter_mut().enumerate() {
+        for vertex in polygon {
+            if let Some(crossing) = &mut vertex.neighbor {
+                *crossing = index_of_crossing[1 - which][*crossing];
+            }
+        }
+    }
+}
+
+/// Walk along the polygons, switching polygon at each crossing.
+fn trace(polygons: &mut [Vec<Vertex>; 2]) -> Vec<Vec<Pos2>> {
+    let mut result = vec![];
+
+    while let Some(start) = polygons[0]
+        .iter()
+        .position(|v| v.neighbor.is_some() && !v.visited)
+    {
+        let mut outline = vec![];
+        let (mut which, mut index) = (0, start);
+        loop {
+            let vertex = &mut polygons[which][index];
+            vertex.visited = true;
+            outline.push(vertex.pos);
+            let forward = vertex.entry;
+
+            // Follow the polygon to the next crossing:
+            let n = polygons[which].len();
+            loop {
+                index = if forward {
+                    (index + 1) % n
+                } else {
+                    (index + n - 1) % n
+                };
+                let vertex = &mut polygons[which][index];
+                if vertex.neighbor.is_some() {
+                    break;
+                }
+                outline.push(vertex.pos);
+            }
+
+            // Switch to the other polygon:
+            polygons[which][index].visited = true;
+            let Some(neighbor) = polygons[which][index].neighbor else {
+                unreachable!()
+            };
+            which = 1 - which;
+            index = neighbor;
+
+            if polygons[which][index].visited {
+                break; // back at the start
+            }
+        }
+
+        if 3 <= outline.len() {
+            result.push(outline);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    fn square(min: Pos2, size: f32) -> Vec<Pos2> {
+        vec![
+            min,
+            min + Vec2::new(size, 0.0),
+            min + Vec2::new(size, size),
+            min + Vec2::new(0.0, size),
+        ]
+    }
+
+    #[test]
+    fn test_boolean_ops() {
+        let a = square(pos2(0.0, 0.0), 2.0);
+        let b = square(pos2(1.0, 1.0), 2.0);
+
+        let overlap = intersection(&a, &b);
+        assert_eq!(overlap.len(), 1);
+        assert_eq!(area(&overlap[0]), 1.0);
+        assert!(contains(&overlap[0], pos2(1.5, 1.5)));
+
+        let outline = union(&a, &b);
+        assert_eq!(outline.len(), 1);
+        assert_eq!(area(&outline[0]), 7.0);
+        assert!(contains(&outline[0], pos2(0.5, 0.5)));
+        assert!(contains(&outline[0], pos2(2.5, 2.5)));
+        assert!(!contains(&outline[0], pos2(2.5, 0.5)));
+
+        // Opposite winding order:
+        let reversed: Vec<Pos2> = b.iter().rev().copied().collect();
+        assert_eq!(area(&intersection(&a, &reversed)[0]), 1.0);
+        assert_eq!(area(&union(&a, &reversed)[0]), 7.0);
+
+        // Disjoint and contained:
+        let far = square(pos2(10.0, 10.0), 1.0);
+        let inner = square(pos2(0.5, 0.5), 1.0);
+        assert!(intersection(&a, &far).is_empty());
+        assert_eq!(union(&a, &far).len(), 2);
+        assert_eq!(intersection(&a, &inner), vec![inner.clone()]);
+        assert_eq!(union(&a, &inner), vec![a.clone()]);
+    }
+
+    #[test]
+    fn test_boolean_ops_concave() {
+        // A U-shape, with a bar across both arms:
+        let u = vec![
+            pos2(0.0, 0.0),
+            pos2(1.0, 0.0),
+            pos2(1.0, 2.0),
+            pos2(2.0, 2.0),
+            pos2(2.0, 0.0),
+            pos2(3.0, 0.0),
+            pos2(3.0, 3.0),
+            pos2(0.0, 3.0),
+        ];
+        let bar = vec![
+            pos2(-1.0, 0.5),
+            pos2(4.0, 0.5),
+            pos2(4.0, 1.5),
+            pos2(-1.0, 1.5),
+        ];
+
+        let overlap = intersection(&u, &bar);
+        assert_eq!(overlap.len(), 2, "One piece per arm");
+        for piece in &overlap {
+            assert_eq!(area(piece), 1.0);
+        }
+
+        let outline = union(&u, &bar);
+        assert_eq!(outline.len(), 2, "Outline and a hole");
+        let areas: f32 = outline.iter().map(|p| area(p)).sum();
+        assert_eq!(areas, area(&u) + area(&bar) - 2.0 + 2.0 * 0.5);
+    }
+}
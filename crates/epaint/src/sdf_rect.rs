@@ -0,0 +1,156 @@
+use emath::{Pos2, Rect, Vec2, vec2};
+
+use crate::{
+    Color32, CornerRadiusF32, Mesh, RectShape, Rgba, Stroke, StrokeKind, TessellationOptions,
+    Tessellator,
+};
+
+/// A rounded rectangle, with an optional outline and blur,
+/// to be painted by evaluating its signed distance field (SDF) per pixel.
+///
+/// This is produced instead of a tessellated [`Mesh`] when
+/// [`TessellationOptions::sdf_rects`] is set.
+/// Compared to tessellation it needs only one quad, no matter how round or blurry the rectangle is,
+/// which makes large blurred shadows cheap.
+///
+/// See [`Self::color_at`] for how it should be shaded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SdfRect {
+    /// The outer edge of the rectangle (including the stroke), in points.
+    pub rect: Rect,
+
+    /// How rounded the corners of the outer edge are, in points.
+    pub corner_radius: CornerRadiusF32,
+
+    /// The color inside of the stroke.
+    pub fill: Color32,
+
+    /// Painted along the inside of [`Self::rect`].
+    pub stroke: Stroke,
+
+    /// The width of the soft edge, in points.
+    ///
+    /// Even if zero, the edge should be anti-aliased over about one physical pixel.
+    pub blur_width: f32,
+}
+
+impl SdfRect {
+    /// How much needs to be painted, e.g. for the quad to run the shader on.
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.rect.expand(self.blur_width / 2.0)
+    }
+
+    /// Signed distance from the outer edge to `pos`, in points.
+    ///
+    /// Negative inside the rectangle.
+    pub fn distance(&self, pos: Pos2) -> f32 {
+        let half_size = 0.5 * self.rect.size();
+        let p = pos - self.rect.center();
+
+        let CornerRadiusF32 { nw, ne, sw, se } = self.corner_radius;
+        let radius = match (0.0 < p.x, 0.0 < p.y) {
+            (false, false) => nw,
+            (true, false) => ne,
+            (false, true) => sw,
+            (true, true) => se,
+        };
+        let radius = radius.clamp(0.0, half_size.min_elem());
+
+        let q = p.abs() - half_size + Vec2::splat(radius);
+        q.max(Vec2::ZERO).length() + q.x.max(q.y).min(0.0) - radius
+    }
+
+    /// The color to paint at `pos`, with premultiplied alpha.
+    ///
+    /// This is a reference implementation of what a backend shader should do.
+    pub fn color_at(&self, pos: Pos2, pixels_per_point: f32) -> Color32 {
+        let softness = self.blur_width.max(1.0 / pixels_per_point);
+        let coverage = |distance: f32| (0.5 - distance / softness).clamp(0.0, 1.0);
+
+        let distance = self.distance(pos);
+        let outer = coverage(distance);
+        let inner = coverage(distance + self.stroke.width);
+
+        let color = Rgba::from(self.fill) * inner + Rgba::from(self.stroke.color) * (outer - inner);
+        color.into()
+    }
+
+    /// The equivalent [`RectShape`].
+    pub fn to_rect_shape(self) -> RectShape {
+        RectShape::new(
+            self.rect,
+            self.corner_radius,
+            self.fill,
+            self.stroke,
+            StrokeKind::Inside,
+        )
+        .with_blur_width(self.blur_width)
+        .with_round_to_pixels(false)
+    }
+
+    /// Tessellate into a [`Mesh`], for backends that can't paint [`SdfRect`]s.
+    pub fn to_mesh(self, pixels_per_point: f32) -> Mesh {
+        let mut mesh = Mesh::default();
+        let options = TessellationOptions {
+            sdf_rects: false,
+            ..Default::default()
+        };
+        Tessellator::new(pixels_per_point, options, [1, 1], vec![])
+            .tessellate_rect(&self.to_rect_shape(), &mut mesh);
+        mesh
+    }
+
+    /// The corners of the quad that covers [`Self::visual_bounding_rect`],
+    /// expanded by one physical pixel for the anti-aliasing.
+    pub fn quad(&self, pixels_per_point: f32) -> [Pos2; 4] {
+        let rect = self
+            .visual_bounding_rect()
+            .expand2(vec2(1.0, 1.0) / pixels_per_point);
+        [
+            rect.left_top(),
+            rect.right_top(),
+            rect.left_bottom(),
+            rect.right_bottom(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn test_sdf_rect() {
+        let sdf = SdfRect {
+            rect: Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0)),
+            corner_radius: CornerRadiusF32::same(10.0),
+            fill: Color32::RED,
+            stroke: Stroke::new(4.0, Color32::BLUE),
+            blur_width: 0.0,
+        };
+
+        assert_eq!(sdf.distance(pos2(50.0, 0.0)), 0.0);
+        assert_eq!(sdf.distance(pos2(50.0, 25.0)), -25.0);
+        assert_eq!(sdf.distance(pos2(110.0, 25.0)), 10.0);
+        assert!(
+            0.0 < sdf.distance(pos2(1.0, 1.0)),
+            "The rounded corner is outside"
+        );
+
+        assert_eq!(sdf.color_at(pos2(50.0, 25.0), 1.0), Color32::RED);
+        assert_eq!(sdf.color_at(pos2(50.0, 2.0), 1.0), Color32::BLUE);
+        assert_eq!(sdf.color_at(pos2(1.0, 1.0), 1.0), Color32::TRANSPARENT);
+        assert_eq!(sdf.color_at(pos2(120.0, 25.0), 1.0), Color32::TRANSPARENT);
+
+        let mesh = sdf.to_mesh(1.0);
+        assert!(mesh.is_valid() && !mesh.is_empty());
+        assert!(
+            sdf.visual_bounding_rect()
+                .expand(1.0)
+                .contains_rect(mesh.calc_bounds())
+        );
+    }
+}
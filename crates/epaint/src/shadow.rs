@@ -1,4 +1,7 @@
-use crate::{Color32, CornerRadius, MarginF32, Rect, RectShape, Vec2};
+use crate::{
+    Color32, CornerRadius, EllipseShape, Instance, InstancedShape, MarginF32, PathShape,
+    PathStroke, Rect, RectShape, Shape, Stroke, StrokeKind, Vec2,
+};
 
 /// The color and fuzziness of a fuzzy shape.
 ///
@@ -35,6 +38,35 @@ fn shadow_size() {
     );
 }
 
+#[test]
+fn shadow_cast_by_any_shape() {
+    use crate::pos2;
+
+    let shadow = Shadow {
+        offset: [2, 4],
+        blur: 8,
+        spread: 3,
+        color: Color32::from_black_alpha(100),
+    };
+    let triangle = Shape::convex_polygon(
+        vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(5.0, 10.0)],
+        Color32::RED,
+        Stroke::NONE,
+    );
+    let caster = triangle
+        .visual_bounding_rect()
+        .translate(Vec2::new(2.0, 4.0));
+
+    let cast = shadow.cast_by(&triangle).visual_bounding_rect();
+    assert!(
+        !caster.expand(3.0).contains_rect(cast),
+        "Should blur beyond the spread"
+    );
+    assert!(caster.expand(3.0 + 4.0).contains_rect(cast));
+
+    assert_eq!(Shadow::NONE.cast_by(&triangle), Shape::Noop);
+}
+
 impl Shadow {
     /// No shadow at all.
     pub const NONE: Self = Self {
@@ -64,6 +96,61 @@ impl Shadow {
         RectShape::filled(rect, corner_radius, color).with_blur_width(blur as _)
     }
 
+    /// The shadow cast by any [`Shape`], not just rectangles.
+    ///
+    /// The shadow is the silhouette of the shape, moved by [`Self::offset`],
+    /// grown by [`Self::spread`], and with its edges blurred by [`Self::blur`].
+    /// Paint it before (below) the shape itself.
+    ///
+    /// Rectangles and circles get the same smooth shadow as [`Self::as_shape`].
+    /// Other shapes are blurred by painting their silhouette a few times, growing it each time.
+    /// Text and meshes are not grown nor blurred, only moved.
+    pub fn cast_by(&self, shape: &Shape) -> Shape {
+        if self.color == Color32::TRANSPARENT {
+            return Shape::Noop;
+        }
+
+        match shape {
+            Shape::Rect(rect_shape) => {
+                let outer_rect = rect_shape
+                    .rect
+                    .expand(rect_outset(rect_shape.stroke, rect_shape.stroke_kind));
+                return self.as_shape(outer_rect, rect_shape.corner_radius).into();
+            }
+            Shape::Circle(circle) => {
+                let radius = circle.radius + stroke_outset(circle.stroke);
+                if radius + self.spread as f32 <= u8::MAX as f32 {
+                    let rect = Rect::from_center_size(circle.center, Vec2::splat(2.0 * radius));
+                    return self.as_shape(rect, radius).into();
+                }
+            }
+            _ => {}
+        }
+
+        let blur = self.blur as f32;
+        let spread = self.spread as f32;
+        let num_layers = ((blur / 2.0).ceil() as usize).clamp(1, 8);
+
+        // The layers are painted on top of each other, so choose their opacity
+        // so that they add up to `color` where they all overlap:
+        let alpha = self.color.a() as f32 / 255.0;
+        let layer_alpha = 1.0 - (1.0 - alpha).powf(1.0 / num_layers as f32);
+        let layer_color = self.color.gamma_multiply(layer_alpha / alpha);
+
+        let layers = (0..num_layers)
+            .map(|i| {
+                let t = (i as f32 + 0.5) / num_layers as f32;
+                let grow = (spread + blur * (t - 0.5)).max(0.0);
+                silhouette(shape, grow, layer_color)
+            })
+            .collect();
+
+        let [offset_x, offset_y] = self.offset;
+        let mut shadow = Shape::Vec(layers);
+        shadow.translate(Vec2::new(offset_x as _, offset_y as _));
+        shadow
+    }
+
     /// How much larger than the parent rect are we in each direction?
     pub fn margin(&self) -> MarginF32 {
         let Self {
@@ -83,3 +170,127 @@ impl Shadow {
         }
     }
 }
+
+/// How far a stroke centered on the outline reaches outside of it.
+fn stroke_outset(stroke: Stroke) -> f32 {
+    if stroke.is_empty() {
+        0.0
+    } else {
+        0.5 * stroke.width
+    }
+}
+
+fn rect_outset(stroke: Stroke, stroke_kind: StrokeKind) -> f32 {
+    if stroke.is_empty() {
+        return 0.0;
+    }
+    match stroke_kind {
+        StrokeKind::Inside => 0.0,
+        StrokeKind::Middle => 0.5 * stroke.width,
+        StrokeKind::Outside => stroke.width,
+    }
+}
+
+/// The silhouette of `shape`, grown by `grow` points in every direction, filled with `color`.
+fn silhouette(shape: &Shape, grow: f32, color: Color32) -> Shape {
+    // A closed outline is grown by stroking it with the silhouette color.
+    let path_stroke = |stroke: &PathStroke| {
+        let width = if stroke.is_empty() { 0.0 } else { stroke.width };
+        PathStroke::new(width + 2.0 * grow, color)
+            .with_cap(stroke.cap)
+            .with_join(stroke.join)
+    };
+    let fill = |fill: Color32| {
+        if fill == Color32::TRANSPARENT {
+            Color32::TRANSPARENT
+        } else {
+            color
+        }
+    };
+
+    match shape {
+        Shape::Noop | Shape::BackdropBlur(_) | Shape::Callback(_) => Shape::Noop,
+        Shape::Vec(shapes) => Shape::Vec(
+            shapes
+                .iter()
+                .map(|shape| silhouette(shape, grow, color))
+                .collect(),
+        ),
+        Shape::Circle(circle) => Shape::circle_filled(
+            circle.center,
+            circle.radius + stroke_outset(circle.stroke) + grow,
+            color,
+        ),
+        Shape::Ellipse(ellipse) => EllipseShape::filled(
+            ellipse.center,
+            ellipse.radius + Vec2::splat(stroke_outset(ellipse.stroke) + grow),
+            color,
+        )
+        .into(),
+        Shape::LineSegment { points, stroke } => {
+            if stroke.is_empty() {
+                Shape::Noop
+            } else {
+                Shape::line_segment(*points, Stroke::new(stroke.width + 2.0 * grow, color))
+            }
+        }
+        Shape::Path(path) => PathShape {
+            points: path.points.clone(),
+            closed: path.closed,
+            fill: fill(path.fill),
+            stroke: path_stroke(&path.stroke),
+        }
+        .into(),
+        Shape::Rect(rect_shape) => {
+            let outset = rect_outset(rect_shape.stroke, rect_shape.stroke_kind) + grow;
+            RectShape::filled(
+                rect_shape.rect.expand(outset),
+                rect_shape.corner_radius + CornerRadius::from(outset),
+                color,
+            )
+            .into()
+        }
+        Shape::QuadraticBezier(bezier) => {
+            let mut bezier = bezier.clone();
+            bezier.fill = fill(bezier.fill);
+            bezier.stroke = path_stroke(&bezier.stroke);
+            bezier.into()
+        }
+        Shape::CubicBezier(bezier) => {
+            let mut bezier = bezier.clone();
+            bezier.fill = fill(bezier.fill);
+            bezier.stroke = path_stroke(&bezier.stroke);
+            bezier.into()
+        }
+        Shape::Text(text) => {
+            let mut text = text.clone();
+            text.override_text_color = Some(color);
+            if !text.underline.is_empty() {
+                text.underline.color = color;
+            }
+            text.into()
+        }
+        Shape::Mesh(mesh) => {
+            let mut mesh = (**mesh).clone();
+            for vertex in &mut mesh.vertices {
+                vertex.color = color.gamma_multiply(vertex.color.a() as f32 / 255.0);
+            }
+            mesh.into()
+        }
+        Shape::Instanced(instanced) => {
+            // Note: `grow` is applied before the instances are scaled.
+            InstancedShape::new(
+                silhouette(&instanced.shape, grow, color),
+                instanced
+                    .instances
+                    .iter()
+                    .map(|instance| Instance::new(instance.transform)),
+            )
+            .into()
+        }
+        Shape::Masked(masked) => Shape::masked(
+            silhouette(&masked.shape, grow, color),
+            silhouette(&masked.mask, grow, Color32::WHITE),
+        ),
+    }
+}
@@ -110,6 +110,20 @@ pub fn adjust_colors(
             }
         }
 
+        Shape::Instanced(instanced) => {
+            // The tints of the instances are multiplied on top, so leave them be.
+            adjust_colors(Arc::make_mut(&mut instanced.shape), adjust_color);
+        }
+
+        Shape::BackdropBlur(blur) => {
+            adjust_color(&mut blur.fallback_fill);
+        }
+
+        Shape::Masked(masked) => {
+            // Only the alpha of the mask is used, so leave it be.
+            adjust_colors(Arc::make_mut(&mut masked.shape), adjust_color);
+        }
+
         Shape::Callback(_) => {
             // Can't tint user callback code
         }
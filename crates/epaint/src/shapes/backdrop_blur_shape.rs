@@ -0,0 +1,80 @@
+use emath::Rect;
+
+use crate::{Color32, CornerRadius, Mesh, RectShape, Shape, TessellationOptions, Tessellator};
+
+/// Blur whatever has already been painted behind a rounded rectangle,
+/// for a frosted-glass look.
+///
+/// This is only painted as a blur if the backend supports it,
+/// see [`crate::TessellationOptions::backdrop_blur`] and [`crate::Primitive::BackdropBlur`].
+/// Otherwise [`Self::fallback_fill`] is painted instead.
+///
+/// The blur should be followed by a translucent fill, e.g. the background of a window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BackdropBlurShape {
+    /// The area to blur, in points.
+    pub rect: Rect,
+
+    /// How rounded the corners of [`Self::rect`] are, in points.
+    pub corner_radius: CornerRadius,
+
+    /// How far each pixel is spread out, in points.
+    ///
+    /// This is about twice the standard deviation of a gaussian blur.
+    pub blur_radius: f32,
+
+    /// Painted in [`Self::rect`] when the backend can't blur.
+    ///
+    /// Usually a translucent version of the background that follows the blur,
+    /// so that what is behind is still muted.
+    pub fallback_fill: Color32,
+}
+
+impl BackdropBlurShape {
+    pub fn new(
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        blur_radius: f32,
+        fallback_fill: Color32,
+    ) -> Self {
+        Self {
+            rect,
+            corner_radius: corner_radius.into(),
+            blur_radius,
+            fallback_fill,
+        }
+    }
+
+    /// The visual bounding rectangle.
+    #[inline]
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// What to paint when the backend can't blur.
+    pub fn to_fallback_shape(self) -> RectShape {
+        RectShape::filled(self.rect, self.corner_radius, self.fallback_fill)
+    }
+
+    /// Tessellate [`Self::to_fallback_shape`] into a [`Mesh`],
+    /// for backends that can't paint [`crate::Primitive::BackdropBlur`].
+    pub fn to_fallback_mesh(self, pixels_per_point: f32) -> Mesh {
+        let mut mesh = Mesh::default();
+        Tessellator::new(
+            pixels_per_point,
+            TessellationOptions::default(),
+            [1, 1],
+            vec![],
+        )
+        .tessellate_rect(&self.to_fallback_shape(), &mut mesh);
+        mesh
+    }
+}
+
+impl From<BackdropBlurShape> for Shape {
+    #[inline(always)]
+    fn from(shape: BackdropBlurShape) -> Self {
+        Self::BackdropBlur(shape)
+    }
+}
@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use emath::{Rect, TSTransform};
+
+use crate::{Color32, Mesh, Shape};
+
+/// Where and how to paint one copy of an [`InstancedShape`] or [`InstancedMesh`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Instance {
+    /// Maps the coordinates of the instanced shape to screen space (points).
+    pub transform: TSTransform,
+
+    /// Multiplied with the vertex colors.
+    ///
+    /// Use [`Color32::WHITE`] to paint the shape as is.
+    pub tint: Color32,
+}
+
+impl Instance {
+    /// Paint the shape moved and scaled by `transform`.
+    #[inline]
+    pub fn new(transform: TSTransform) -> Self {
+        Self {
+            transform,
+            tint: Color32::WHITE,
+        }
+    }
+
+    /// Multiply the colors of this instance with `tint`.
+    #[inline]
+    pub fn with_tint(mut self, tint: Color32) -> Self {
+        self.tint = tint;
+        self
+    }
+}
+
+impl From<TSTransform> for Instance {
+    #[inline]
+    fn from(transform: TSTransform) -> Self {
+        Self::new(transform)
+    }
+}
+
+/// The same [`Shape`] painted many times, e.g. the markers of a scatter plot.
+///
+/// The shape is only tessellated once, and then copied to each instance.
+/// If [`crate::TessellationOptions::instanced_primitives`] is set,
+/// the copying is left to the backend via [`crate::Primitive::Instanced`].
+///
+/// Note that the shape is tessellated before the instance transforms are applied,
+/// so the anti-aliasing is tuned for a scale of `1.0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstancedShape {
+    /// The shape to repeat, in its own coordinate system.
+    ///
+    /// This must not contain any [`Shape::Callback`].
+    pub shape: Arc<Shape>,
+
+    /// Where to paint the copies of [`Self::shape`].
+    pub instances: Vec<Instance>,
+}
+
+impl InstancedShape {
+    pub fn new(shape: impl Into<Shape>, instances: impl IntoIterator<Item = Instance>) -> Self {
+        Self {
+            shape: Arc::new(shape.into()),
+            instances: instances.into_iter().collect(),
+        }
+    }
+
+    /// The visual bounding rectangle of all the instances.
+    pub fn visual_bounding_rect(&self) -> Rect {
+        let bounds = self.shape.visual_bounding_rect();
+        if !bounds.is_positive() {
+            return Rect::NOTHING;
+        }
+        self.instances.iter().fold(Rect::NOTHING, |rect, instance| {
+            rect | (instance.transform * bounds)
+        })
+    }
+}
+
+impl From<InstancedShape> for Shape {
+    #[inline(always)]
+    fn from(shape: InstancedShape) -> Self {
+        Self::Instanced(shape)
+    }
+}
+
+/// The same [`Mesh`] painted many times, produced by tessellating an [`InstancedShape`].
+///
+/// A backend can paint this with a single instanced draw call,
+/// or turn it into a plain [`Mesh`] with [`Self::to_mesh`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InstancedMesh {
+    /// The mesh to repeat, in its own coordinate system.
+    pub mesh: Mesh,
+
+    /// Where to paint the copies of [`Self::mesh`].
+    pub instances: Vec<Instance>,
+}
+
+impl InstancedMesh {
+    /// Number of vertices after all instances have been copied.
+    pub fn num_vertices(&self) -> usize {
+        self.mesh.vertices.len() * self.instances.len()
+    }
+
+    /// Number of indices after all instances have been copied.
+    pub fn num_indices(&self) -> usize {
+        self.mesh.indices.len() * self.instances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mesh.is_empty() || self.instances.is_empty()
+    }
+
+    /// Copy the mesh to each instance.
+    pub fn to_mesh(&self) -> Mesh {
+        profiling::function_scope!();
+
+        let mut out = Mesh::with_texture(self.mesh.texture_id);
+        out.reserve_vertices(self.num_vertices());
+        out.indices.reserve(self.num_indices());
+
+        for instance in &self.instances {
+            let index_offset = out.vertices.len() as u32;
+            out.indices
+                .extend(self.mesh.indices.iter().map(|index| index + index_offset));
+            out.vertices.extend(self.mesh.vertices.iter().map(|vertex| {
+                let mut vertex = *vertex;
+                vertex.pos = instance.transform * vertex.pos;
+                if instance.tint != Color32::WHITE {
+                    vertex.color = vertex.color * instance.tint;
+                }
+                vertex
+            }));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::{pos2, vec2};
+
+    use super::*;
+
+    #[test]
+    fn test_instanced_mesh_to_mesh() {
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_min_size(pos2(0.0, 0.0), vec2(1.0, 1.0)),
+            Color32::WHITE,
+        );
+        let instanced = InstancedMesh {
+            mesh,
+            instances: vec![
+                Instance::new(TSTransform::from_translation(vec2(10.0, 0.0))),
+                Instance::new(TSTransform::new(vec2(0.0, 10.0), 2.0)).with_tint(Color32::RED),
+            ],
+        };
+
+        let mesh = instanced.to_mesh();
+        assert!(mesh.is_valid());
+        assert_eq!(mesh.vertices.len(), instanced.num_vertices());
+        assert_eq!(mesh.indices.len(), instanced.num_indices());
+        assert_eq!(
+            mesh.calc_bounds(),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(11.0, 12.0))
+        );
+        assert_eq!(mesh.vertices[0].color, Color32::WHITE);
+        assert_eq!(mesh.vertices[4].color, Color32::RED);
+    }
+}
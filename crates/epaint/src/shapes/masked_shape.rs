@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use emath::Rect;
+
+use crate::Shape;
+
+/// A [`Shape`] that is only visible where another shape, the mask, is painted.
+///
+/// The colors of [`Self::shape`] are multiplied with the alpha (coverage) of [`Self::mask`].
+/// The colors and textures of the mask are otherwise ignored.
+/// This means the mask can be:
+/// * A filled circle or rounded rectangle, to clip an image to the shape of an avatar
+/// * A thick stroke along an arc, for a progress ring
+/// * A circle that grows over time, for a reveal animation
+/// * A semi-transparent shape, to fade out parts of the shape
+///
+/// The edges of the mask are anti-aliased like any other shape.
+///
+/// The masking is done during tessellation, by cutting the triangles of the shape along the
+/// triangles of the mask, so it works with all backends.
+/// This is fine for small masks like the ones above,
+/// but costs `O(n·m)` for a shape of `n` and a mask of `m` triangles.
+/// Where the triangles of the mask overlap (e.g. if it is a [`Shape::Vec`] of overlapping shapes),
+/// the shape is painted more than once.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaskedShape {
+    /// What to paint.
+    ///
+    /// This must not contain any [`Shape::Callback`].
+    pub shape: Arc<Shape>,
+
+    /// Where to paint it.
+    ///
+    /// This must not contain any [`Shape::Callback`].
+    pub mask: Arc<Shape>,
+}
+
+impl MaskedShape {
+    pub fn new(shape: impl Into<Shape>, mask: impl Into<Shape>) -> Self {
+        Self {
+            shape: Arc::new(shape.into()),
+            mask: Arc::new(mask.into()),
+        }
+    }
+
+    /// The visual bounding rectangle (the overlap of the shape and the mask).
+    pub fn visual_bounding_rect(&self) -> Rect {
+        let rect = self
+            .shape
+            .visual_bounding_rect()
+            .intersect(self.mask.visual_bounding_rect());
+        if rect.is_positive() {
+            rect
+        } else {
+            Rect::NOTHING
+        }
+    }
+}
+
+impl From<MaskedShape> for Shape {
+    #[inline(always)]
+    fn from(shape: MaskedShape) -> Self {
+        Self::Masked(shape)
+    }
+}
@@ -1,6 +1,9 @@
+mod backdrop_blur_shape;
 mod bezier_shape;
 mod circle_shape;
 mod ellipse_shape;
+mod instanced_shape;
+mod masked_shape;
 mod paint_callback;
 mod path_shape;
 mod rect_shape;
@@ -8,9 +11,12 @@ mod shape;
 mod text_shape;
 
 pub use self::{
+    backdrop_blur_shape::BackdropBlurShape,
     bezier_shape::{CubicBezierShape, QuadraticBezierShape},
     circle_shape::CircleShape,
     ellipse_shape::EllipseShape,
+    instanced_shape::{Instance, InstancedMesh, InstancedShape},
+    masked_shape::MaskedShape,
     paint_callback::{PaintCallback, PaintCallbackInfo},
     path_shape::PathShape,
     rect_shape::RectShape,
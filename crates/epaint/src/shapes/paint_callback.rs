@@ -1,5 +1,7 @@
 use std::{any::Any, sync::Arc};
 
+use emath::TSTransform;
+
 use crate::*;
 
 /// Information passed along with [`PaintCallback`] ([`Shape::Callback`]).
@@ -22,6 +24,27 @@ pub struct PaintCallbackInfo {
 
     /// Full size of the screen, in pixels.
     pub screen_size_px: [u32; 2],
+
+    /// The transform of the layer the callback was painted on.
+    ///
+    /// This maps from the coordinates the [`PaintCallback`] was created in (layer space, in points)
+    /// to screen coordinates (in points), and has already been applied to [`Self::viewport`].
+    /// It is [`TSTransform::IDENTITY`] unless the callback was painted on a transformed layer,
+    /// e.g. inside an `egui::Scene`.
+    ///
+    /// This comes from [`PaintCallback::transform`].
+    pub transform: TSTransform,
+
+    /// Does the render target have a depth buffer that the callback can use?
+    ///
+    /// See [`PaintCallback::depth_buffer`].
+    pub has_depth_buffer: bool,
+
+    /// The number of samples per pixel of the render target.
+    ///
+    /// Use this to create render pipelines compatible with the render target.
+    /// `1` means no multisampling.
+    pub msaa_samples: u32,
 }
 
 #[test]
@@ -50,6 +73,15 @@ impl PaintCallbackInfo {
     pub fn clip_rect_in_pixels(&self) -> ViewportInPixels {
         ViewportInPixels::from_points(&self.clip_rect, self.pixels_per_point, self.screen_size_px)
     }
+
+    /// The zoom factor of the layer the callback was painted on.
+    ///
+    /// Multiply with [`Self::pixels_per_point`] to get the number of physical pixels
+    /// per point in the coordinate system the callback was created in.
+    #[inline]
+    pub fn scaling(&self) -> f32 {
+        self.transform.scaling
+    }
 }
 
 /// If you want to paint some 3D shapes inside an egui region, you can use this.
@@ -62,6 +94,20 @@ pub struct PaintCallback {
     /// This will become [`PaintCallbackInfo::viewport`].
     pub rect: Rect,
 
+    /// The transform that has been applied to [`Self::rect`].
+    ///
+    /// This is set by egui when the callback is painted on a transformed layer,
+    /// and will become [`PaintCallbackInfo::transform`].
+    pub transform: TSTransform,
+
+    /// Does the callback need a depth buffer?
+    ///
+    /// If `true`, the `glow` and `wgpu` backends clear the depth buffer inside the clip rectangle
+    /// before calling the callback.
+    /// Whether or not a depth buffer is actually available is reported in
+    /// [`PaintCallbackInfo::has_depth_buffer`].
+    pub depth_buffer: bool,
+
     /// Paint something custom (e.g. 3D stuff).
     ///
     /// The concrete value of `callback` depends on the rendering backend used. For instance, the
@@ -81,17 +127,43 @@ pub struct PaintCallback {
     pub callback: Arc<dyn Any + Send + Sync>,
 }
 
+impl PaintCallback {
+    /// Paint with the given backend-specific callback in the given rectangle.
+    pub fn new(rect: Rect, callback: Arc<dyn Any + Send + Sync>) -> Self {
+        Self {
+            rect,
+            transform: TSTransform::IDENTITY,
+            depth_buffer: false,
+            callback,
+        }
+    }
+
+    /// Request a depth buffer for the callback.
+    ///
+    /// See [`Self::depth_buffer`].
+    #[inline]
+    pub fn with_depth_buffer(mut self, depth_buffer: bool) -> Self {
+        self.depth_buffer = depth_buffer;
+        self
+    }
+}
+
 impl std::fmt::Debug for PaintCallback {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CustomShape")
             .field("rect", &self.rect)
+            .field("transform", &self.transform)
+            .field("depth_buffer", &self.depth_buffer)
             .finish_non_exhaustive()
     }
 }
 
 impl std::cmp::PartialEq for PaintCallback {
     fn eq(&self, other: &Self) -> bool {
-        self.rect.eq(&other.rect) && Arc::ptr_eq(&self.callback, &other.callback)
+        self.rect.eq(&other.rect)
+            && self.transform == other.transform
+            && self.depth_buffer == other.depth_buffer
+            && Arc::ptr_eq(&self.callback, &other.callback)
     }
 }
 
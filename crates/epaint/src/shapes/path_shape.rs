@@ -62,13 +62,48 @@ impl PathShape {
         }
     }
 
+    /// Split the stroke into dashes, e.g. for a selection rectangle or a diagram edge.
+    ///
+    /// Returns the fill (if any) followed by one open path per dash,
+    /// which gets the [`PathStroke::cap`] at both ends.
+    ///
+    /// ```
+    /// # use epaint::*;
+    /// # let rect = Rect::from_min_size(pos2(10.0, 10.0), vec2(100.0, 50.0));
+    /// # let time = 0.0;
+    /// // Marching ants:
+    /// let points = vec![rect.left_top(), rect.right_top(), rect.right_bottom(), rect.left_bottom()];
+    /// let dash = DashPattern::new(4.0, 4.0).with_offset(-10.0 * time);
+    /// let shapes = PathShape::closed_line(points, (1.0, Color32::WHITE)).dashed(&dash);
+    /// ```
+    pub fn dashed(&self, dash: &DashPattern) -> Vec<Shape> {
+        let mut shapes = Vec::new();
+        if self.closed && self.fill != Color32::TRANSPARENT {
+            shapes.push(
+                Self::convex_polygon(self.points.clone(), self.fill, PathStroke::NONE).into(),
+            );
+        }
+        if !self.stroke.is_empty() {
+            shapes.extend(
+                dash.split(&self.points, self.closed)
+                    .into_iter()
+                    .map(|points| Self::line(points, self.stroke.clone()).into()),
+            );
+        }
+        shapes
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
         if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
             Rect::NOTHING
         } else {
-            Rect::from_points(&self.points).expand(self.stroke.width / 2.0)
+            let mut half_width = self.stroke.width / 2.0;
+            if self.stroke.cap == LineCap::Square {
+                half_width *= std::f32::consts::SQRT_2;
+            }
+            Rect::from_points(&self.points).expand(half_width)
         }
     }
 }
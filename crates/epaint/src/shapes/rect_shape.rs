@@ -150,13 +150,22 @@ impl RectShape {
     /// Set the texture to use when painting this rectangle, if any.
     #[inline]
     pub fn with_texture(mut self, fill_texture_id: TextureId, uv: Rect) -> Self {
-        self.brush = Some(Arc::new(Brush {
+        self.brush = Some(Arc::new(Brush::Texture {
             fill_texture_id,
             uv,
         }));
         self
     }
 
+    /// Fill this rectangle with a texture or gradient.
+    ///
+    /// The brush is multiplied with [`Self::fill`], so usually you want that to be [`Color32::WHITE`].
+    #[inline]
+    pub fn with_brush(mut self, brush: impl Into<Arc<Brush>>) -> Self {
+        self.brush = Some(brush.into());
+        self
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
@@ -178,7 +187,7 @@ impl RectShape {
     pub fn fill_texture_id(&self) -> TextureId {
         self.brush
             .as_ref()
-            .map_or_else(TextureId::default, |brush| brush.fill_texture_id)
+            .map_or_else(TextureId::default, |brush| brush.texture_id())
     }
 }
 
@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape, QuadraticBezierShape,
-    RectShape, TextShape,
+    BackdropBlurShape, CircleShape, CubicBezierShape, EllipseShape, InstancedShape, MaskedShape,
+    PaintCallback, PathShape, QuadraticBezierShape, RectShape, TextShape,
 };
 
 /// A paint primitive such as a circle or a piece of text.
@@ -66,6 +66,15 @@ pub enum Shape {
     /// A cubic [Bézier Curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve).
     CubicBezier(CubicBezierShape),
 
+    /// The same shape painted many times.
+    Instanced(InstancedShape),
+
+    /// A shape that is only visible where another shape is painted.
+    Masked(MaskedShape),
+
+    /// Blur what has been painted behind it.
+    BackdropBlur(BackdropBlurShape),
+
     /// Backend-specific painting.
     Callback(PaintCallback),
 }
@@ -256,6 +265,51 @@ impl Shape {
         Self::Path(PathShape::convex_polygon(points, fill, stroke))
     }
 
+    /// A slice of a ring around `center`, e.g. for a pie menu or a pie chart.
+    ///
+    /// `radius` is the inner and outer radius (use `0.0` as the inner radius for a pie slice),
+    /// and `angle` the start and end of the slice, in radians.
+    /// An angle of zero points to the right, and angles grow clockwise, since y points down.
+    pub fn ring_sector(
+        center: Pos2,
+        radius: impl Into<Rangef>,
+        angle: impl Into<Rangef>,
+        fill: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        let (radius, angle, fill, stroke) =
+            (radius.into(), angle.into(), fill.into(), stroke.into());
+
+        // Enough segments for the arcs to look smooth:
+        let num_segments = ((angle.span().abs() * radius.max.sqrt()).ceil() as usize).max(1);
+        let point = |radius: f32, i: usize| {
+            let angle = emath::lerp(angle, i as f32 / num_segments as f32);
+            center + radius * Vec2::angled(angle)
+        };
+        let outer: Vec<Pos2> = (0..=num_segments).map(|i| point(radius.max, i)).collect();
+        let inner: Vec<Pos2> = (0..=num_segments).map(|i| point(radius.min, i)).collect();
+
+        let mut shapes = Vec::with_capacity(2);
+        if fill != Color32::TRANSPARENT {
+            // Not convex, so we can't use a `PathShape` for the fill:
+            let mut mesh = Mesh::default();
+            for (&inner, &outer) in inner.iter().zip(&outer) {
+                mesh.colored_vertex(inner, fill);
+                mesh.colored_vertex(outer, fill);
+            }
+            for i in 0..num_segments as u32 {
+                mesh.add_triangle(2 * i, 2 * i + 1, 2 * i + 2);
+                mesh.add_triangle(2 * i + 1, 2 * i + 3, 2 * i + 2);
+            }
+            shapes.push(Self::mesh(mesh));
+        }
+        if !stroke.is_empty() {
+            let outline = outer.into_iter().chain(inner.into_iter().rev()).collect();
+            shapes.push(Self::closed_line(outline, stroke));
+        }
+        Self::Vec(shapes)
+    }
+
     #[inline]
     pub fn circle_filled(center: Pos2, radius: f32, fill_color: impl Into<Color32>) -> Self {
         Self::Circle(CircleShape::filled(center, radius, fill_color))
@@ -311,6 +365,81 @@ impl Shape {
         Self::galley(rect.min, galley, color)
     }
 
+    /// Lay out a single line of text along `path`, starting `start_distance` points from its start.
+    ///
+    /// Each glyph is rotated to follow the path, with the middle of its baseline on the path.
+    /// Glyphs that don't fit on the path are left out.
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn text_along_path(
+        fonts: &Fonts,
+        path: &[Pos2],
+        start_distance: f32,
+        text: impl ToString,
+        font_id: FontId,
+        color: Color32,
+    ) -> Self {
+        if path.len() < 2 {
+            return Self::Noop;
+        }
+
+        let mut distances = Vec::with_capacity(path.len());
+        let mut total = 0.0;
+        for (i, &point) in path.iter().enumerate() {
+            if 0 < i {
+                total += path[i - 1].distance(point);
+            }
+            distances.push(total);
+        }
+
+        // The point at `distance` along the path, and the angle of the path there.
+        let point_along = |distance: f32| {
+            if distance < 0.0 || total < distance {
+                return None;
+            }
+            let i = distances
+                .partition_point(|&d| d <= distance)
+                .clamp(1, path.len() - 1);
+            let (a, b) = (path[i - 1], path[i]);
+            let length = distances[i] - distances[i - 1];
+            let t = if length > 0.0 {
+                (distance - distances[i - 1]) / length
+            } else {
+                0.0
+            };
+            Some((a.lerp(b, t), (b - a).angle()))
+        };
+
+        let galley = fonts.layout_no_wrap(text.to_string(), font_id.clone(), color);
+        let mut shapes = vec![];
+        let Some(row) = galley.rows.first() else {
+            return Self::Vec(shapes);
+        };
+        for glyph in &row.glyphs {
+            if glyph.chr.is_whitespace() {
+                continue;
+            }
+            let distance = start_distance + row.pos.x + glyph.pos.x + 0.5 * glyph.advance_width;
+            let Some((point, angle)) = point_along(distance) else {
+                continue;
+            };
+
+            let glyph_galley = fonts.layout_no_wrap(glyph.chr.to_string(), font_id.clone(), color);
+            let baseline = glyph_galley
+                .rows
+                .first()
+                .and_then(|row| Some(row.pos.y + row.glyphs.first()?.pos.y))
+                .unwrap_or_default();
+            let anchor = Vec2::new(0.5 * glyph_galley.size().x, baseline);
+            let pos = point - emath::Rot2::from_angle(angle) * anchor;
+            shapes.push(
+                TextShape::new(pos, glyph_galley, color)
+                    .with_angle(angle)
+                    .into(),
+            );
+        }
+        Self::Vec(shapes)
+    }
+
     /// Any uncolored parts of the [`Galley`] (using [`Color32::PLACEHOLDER`]) will be replaced with the given color.
     ///
     /// Any non-placeholder color in the galley takes precedence over this fallback color.
@@ -344,6 +473,13 @@ impl Shape {
         Self::Mesh(mesh)
     }
 
+    /// Only paint `shape` where `mask` is painted, see [`MaskedShape`].
+    ///
+    /// For instance, use a filled circle as the mask to clip an image to a circle.
+    pub fn masked(shape: impl Into<Self>, mask: impl Into<Self>) -> Self {
+        Self::Masked(MaskedShape::new(shape, mask))
+    }
+
     /// An image at the given position.
     ///
     /// `uv` should normally be `Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0))`
@@ -382,6 +518,9 @@ impl Shape {
             Self::Mesh(mesh) => mesh.calc_bounds(),
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
+            Self::Instanced(instanced) => instanced.visual_bounding_rect(),
+            Self::Masked(masked) => masked.visual_bounding_rect(),
+            Self::BackdropBlur(blur) => blur.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
         }
     }
@@ -395,6 +534,10 @@ impl Shape {
             mesh.texture_id
         } else if let Self::Rect(rect_shape) = self {
             rect_shape.fill_texture_id()
+        } else if let Self::Instanced(instanced) = self {
+            instanced.shape.texture_id()
+        } else if let Self::Masked(masked) = self {
+            masked.shape.texture_id()
         } else {
             crate::TextureId::default()
         }
@@ -474,8 +617,23 @@ impl Shape {
                 }
                 bezier.stroke.width *= transform.scaling;
             }
+            Self::Instanced(instanced) => {
+                for instance in &mut instanced.instances {
+                    instance.transform = transform * instance.transform;
+                }
+            }
+            Self::Masked(masked) => {
+                Arc::make_mut(&mut masked.shape).transform(transform);
+                Arc::make_mut(&mut masked.mask).transform(transform);
+            }
+            Self::BackdropBlur(blur) => {
+                blur.rect = transform * blur.rect;
+                blur.corner_radius *= transform.scaling;
+                blur.blur_radius *= transform.scaling;
+            }
             Self::Callback(shape) => {
                 shape.rect = transform * shape.rect;
+                shape.transform = transform * shape.transform;
             }
         }
     }
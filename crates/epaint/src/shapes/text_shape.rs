@@ -211,4 +211,46 @@ mod tests {
         assert!(almost_equal(size_orig.x, size_rot.y, 1e-4));
         assert!(almost_equal(size_orig.y, size_rot.x, 1e-4));
     }
+
+    #[test]
+    fn text_along_path() {
+        let fonts = Fonts::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let font = FontId::monospace(12.0);
+
+        // Down along a vertical line:
+        let path = [Pos2::new(0.0, 0.0), Pos2::new(0.0, 100.0)];
+        let shape = Shape::text_along_path(&fonts, &path, 10.0, "a b", font, Color32::BLACK);
+        let Shape::Vec(glyphs) = shape else {
+            panic!("Expected a Shape::Vec");
+        };
+        assert_eq!(glyphs.len(), 2, "One shape per visible glyph");
+        for glyph in &glyphs {
+            let Shape::Text(text) = glyph else {
+                panic!("Expected a Shape::Text");
+            };
+            assert!(almost_equal(text.angle, std::f32::consts::PI / 2.0, 1e-4));
+            let center = text.visual_bounding_rect().center();
+            assert!(center.x.abs() < 12.0 && 10.0 < center.y, "{center:?}");
+        }
+
+        // Text beyond the end of the path is left out:
+        let short_path = [Pos2::new(0.0, 0.0), Pos2::new(10.0, 0.0)];
+        let shape = Shape::text_along_path(
+            &fonts,
+            &short_path,
+            0.0,
+            "long text",
+            FontId::monospace(12.0),
+            Color32::BLACK,
+        );
+        let Shape::Vec(glyphs) = shape else {
+            panic!("Expected a Shape::Vec");
+        };
+        assert_eq!(glyphs.len(), 1);
+    }
 }
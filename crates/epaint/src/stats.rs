@@ -1,6 +1,6 @@
 //! Collect statistics about what is being painted.
 
-use crate::{ClippedShape, Galley, Mesh, Primitive, Shape};
+use crate::{ClippedShape, Galley, InstancedMesh, Mesh, Primitive, Shape};
 
 /// Size of the elements in a vector/array.
 #[derive(Clone, Copy, PartialEq)]
@@ -208,7 +208,8 @@ impl PaintStats {
             | Shape::LineSegment { .. }
             | Shape::Rect { .. }
             | Shape::CubicBezier(_)
-            | Shape::QuadraticBezier(_) => {}
+            | Shape::QuadraticBezier(_)
+            | Shape::BackdropBlur(_) => {}
             Shape::Path(path_shape) => {
                 self.shape_path += AllocInfo::from_slice(&path_shape.points);
             }
@@ -223,6 +224,13 @@ impl PaintStats {
             Shape::Mesh(mesh) => {
                 self.shape_mesh += AllocInfo::from_mesh(mesh);
             }
+            Shape::Instanced(instanced) => {
+                self.add(&instanced.shape);
+            }
+            Shape::Masked(masked) => {
+                self.add(&masked.shape);
+                self.add(&masked.mask);
+            }
             Shape::Callback(_) => {
                 self.num_callbacks += 1;
             }
@@ -235,9 +243,12 @@ impl PaintStats {
     ) -> Self {
         self.clipped_primitives += AllocInfo::from_slice(clipped_primitives);
         for clipped_primitive in clipped_primitives {
-            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
-                self.vertices += AllocInfo::from_slice(&mesh.vertices);
-                self.indices += AllocInfo::from_slice(&mesh.indices);
+            match &clipped_primitive.primitive {
+                Primitive::Mesh(mesh) | Primitive::Instanced(InstancedMesh { mesh, .. }) => {
+                    self.vertices += AllocInfo::from_slice(&mesh.vertices);
+                    self.indices += AllocInfo::from_slice(&mesh.indices);
+                }
+                Primitive::SdfRect(_) | Primitive::BackdropBlur(_) | Primitive::Callback(_) => {}
             }
         }
         self
@@ -111,6 +111,42 @@ pub enum StrokeKind {
     Outside,
 }
 
+/// How the ends of an open path (or of a dash) are painted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineCap {
+    /// The stroke ends exactly at the end point.
+    #[default]
+    Butt,
+
+    /// The stroke ends in a half circle around the end point.
+    ///
+    /// Only supported for solid colors, otherwise [`Self::Butt`] is used.
+    Round,
+
+    /// The stroke continues half its width past the end point.
+    Square,
+}
+
+/// How the corners of a path are painted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet.
+    ///
+    /// Corners sharper than a right angle are cut off, so that they don't grow too long.
+    #[default]
+    Miter,
+
+    /// The corner is rounded.
+    ///
+    /// Only supported for solid colors with [`StrokeKind::Middle`], otherwise [`Self::Bevel`] is used.
+    Round,
+
+    /// The corner is cut off.
+    Bevel,
+}
+
 /// Describes the width and color of paths. The color can either be solid or provided by a callback. For more information, see [`ColorMode`]
 ///
 /// The default stroke is the same as [`Stroke::NONE`].
@@ -120,6 +156,14 @@ pub struct PathStroke {
     pub width: f32,
     pub color: ColorMode,
     pub kind: StrokeKind,
+
+    /// How the ends of open paths are painted.
+    pub cap: LineCap,
+
+    /// How the corners of paths are painted.
+    ///
+    /// Filled paths always use [`LineJoin::Miter`].
+    pub join: LineJoin,
 }
 
 impl Default for PathStroke {
@@ -135,6 +179,8 @@ impl PathStroke {
         width: 0.0,
         color: ColorMode::TRANSPARENT,
         kind: StrokeKind::Middle,
+        cap: LineCap::Butt,
+        join: LineJoin::Miter,
     };
 
     #[inline]
@@ -143,6 +189,8 @@ impl PathStroke {
             width: width.into(),
             color: ColorMode::Solid(color.into()),
             kind: StrokeKind::Middle,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
         }
     }
 
@@ -158,6 +206,8 @@ impl PathStroke {
             width: width.into(),
             color: ColorMode::UV(Arc::new(callback)),
             kind: StrokeKind::Middle,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
         }
     }
 
@@ -193,6 +243,16 @@ impl PathStroke {
         }
     }
 
+    #[inline]
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
+    #[inline]
+    pub fn with_join(self, join: LineJoin) -> Self {
+        Self { join, ..self }
+    }
+
     /// True if width is zero or color is solid and transparent
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -220,8 +280,146 @@ impl From<Stroke> for PathStroke {
                 width: value.width,
                 color: ColorMode::Solid(value.color),
                 kind: StrokeKind::Middle,
+                cap: LineCap::Butt,
+                join: LineJoin::Miter,
+            }
+        }
+    }
+}
+
+/// Splits a stroked path into dashes.
+///
+/// Use with [`crate::PathShape::dashed`].
+///
+/// To get "marching ants" (e.g. around a selection rectangle),
+/// increase the [`Self::offset`] every frame.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DashPattern {
+    /// Alternating lengths of dashes and gaps, starting with a dash, in points.
+    ///
+    /// If the number of lengths is odd, the pattern is repeated twice,
+    /// so that the dashes and gaps swap places on each repetition.
+    pub lengths: Vec<f32>,
+
+    /// How far into the pattern the path starts, in points.
+    pub offset: f32,
+}
+
+impl DashPattern {
+    /// Dashes and gaps of constant length.
+    pub fn new(dash_length: f32, gap_length: f32) -> Self {
+        Self {
+            lengths: vec![dash_length, gap_length],
+            offset: 0.0,
+        }
+    }
+
+    /// Alternating dash and gap lengths.
+    pub fn from_lengths(lengths: impl Into<Vec<f32>>) -> Self {
+        Self {
+            lengths: lengths.into(),
+            offset: 0.0,
+        }
+    }
+
+    /// Zero-length dashes, `spacing` apart.
+    ///
+    /// Use together with [`LineCap::Round`] to get a dotted line.
+    pub fn dotted(spacing: f32) -> Self {
+        Self::new(0.0, spacing)
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// The length of one repetition of the pattern.
+    pub fn period(&self) -> f32 {
+        let sum: f32 = self.lengths.iter().sum();
+        if self.lengths.len() % 2 == 1 {
+            2.0 * sum
+        } else {
+            sum
+        }
+    }
+
+    /// Split a path into its dashes.
+    ///
+    /// Each dash keeps the corners of the path it follows.
+    /// Zero-length dashes are returned as a single point.
+    ///
+    /// Returns the whole path if the pattern is invalid,
+    /// e.g. empty or with negative lengths.
+    pub fn split(&self, points: &[Pos2], closed: bool) -> Vec<Vec<Pos2>> {
+        let mut path = points.to_vec();
+        if closed {
+            path.extend(points.first());
+        }
+
+        let period = self.period();
+        if self
+            .lengths
+            .iter()
+            .any(|&length| length < 0.0 || !length.is_finite())
+            || period <= 0.0
+        {
+            return vec![path];
+        }
+
+        let lengths = if self.lengths.len() % 2 == 1 {
+            self.lengths.repeat(2)
+        } else {
+            self.lengths.clone()
+        };
+
+        // Find where in the pattern the path starts:
+        let mut index = 0;
+        let mut remaining = lengths[0];
+        let mut skip = self.offset.rem_euclid(period);
+        while remaining < skip {
+            skip -= remaining;
+            index = (index + 1) % lengths.len();
+            remaining = lengths[index];
+        }
+        remaining -= skip;
+
+        let mut dashes = Vec::new();
+        let mut dash: Vec<Pos2> = Vec::new();
+        if index % 2 == 0 {
+            dash.extend(path.first());
+        }
+
+        for segment in path.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let segment_length = start.distance(end);
+            let mut position = 0.0;
+            while position + remaining <= segment_length {
+                position += remaining;
+                let point = start.lerp(end, position / segment_length);
+                dash.push(point);
+                if index % 2 == 0 {
+                    // End of a dash:
+                    dash.dedup();
+                    dashes.push(std::mem::take(&mut dash));
+                }
+                index = (index + 1) % lengths.len();
+                remaining = lengths[index];
             }
+            remaining -= segment_length - position;
+            if index % 2 == 0 {
+                dash.push(end);
+            }
+        }
+
+        dash.dedup();
+        let cut_short = dash.len() == 1 && 0.0 < lengths[index];
+        if !dash.is_empty() && !cut_short {
+            dashes.push(dash);
         }
+        dashes
     }
 }
 
@@ -242,3 +440,46 @@ fn test_is_nearest_integer_odd() {
     assert!(is_nearest_integer_odd(3.0));
     assert!(is_nearest_integer_odd(3.4));
 }
+
+#[test]
+fn test_dash_pattern() {
+    use emath::pos2;
+
+    let line = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)];
+
+    let dashes = DashPattern::new(4.0, 2.0).split(&line, false);
+    assert_eq!(
+        dashes,
+        vec![
+            vec![pos2(0.0, 0.0), pos2(4.0, 0.0)],
+            vec![pos2(6.0, 0.0), pos2(10.0, 0.0)],
+            vec![pos2(10.0, 2.0), pos2(10.0, 6.0)],
+            vec![pos2(10.0, 8.0), pos2(10.0, 10.0)],
+        ]
+    );
+
+    let dashes = DashPattern::new(4.0, 2.0)
+        .with_offset(3.0)
+        .split(&line, false);
+    assert_eq!(dashes[0], vec![pos2(0.0, 0.0), pos2(1.0, 0.0)]);
+    assert_eq!(
+        dashes[1],
+        vec![pos2(3.0, 0.0), pos2(7.0, 0.0)],
+        "Marching ants"
+    );
+    assert_eq!(
+        dashes[2],
+        vec![pos2(9.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 3.0)],
+        "Dashes keep the corners"
+    );
+
+    let dots = DashPattern::dotted(5.0).split(&line, true);
+    assert!(dots.iter().all(|dot| dot.len() == 1));
+    assert_eq!(dots.len(), 7, "20 + 14.1 points long");
+
+    assert_eq!(
+        DashPattern::from_lengths([]).split(&line, false),
+        vec![line.to_vec()],
+        "Invalid pattern"
+    );
+}
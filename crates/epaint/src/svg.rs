@@ -0,0 +1,269 @@
+//! Importing SVG files as vector shapes.
+//!
+//! Enable the `svg` feature to use this.
+
+use emath::{Rect, TSTransform, Vec2, pos2};
+use usvg::tiny_skia_path::{PathSegment, Point};
+
+use crate::{
+    Color32, DashPattern, FillRule, LineCap, LineJoin, PathBuilder, PathShape, PathStroke, Rgba,
+    Shape, shape_transform::adjust_colors,
+};
+
+/// The vector shapes of an SVG file.
+///
+/// Unlike loading an SVG as an image (e.g. with `egui_extras`), this keeps the paths as
+/// [`Shape`]s, so they stay crisp when zoomed (e.g. with a layer transform),
+/// and they can be tinted when painted.
+///
+/// Paths are imported with their fill, stroke, dashes, and opacity.
+/// Gradients are replaced by the average color of their stops.
+/// Text, images, patterns, clip paths, masks, and filters are ignored.
+///
+/// ```
+/// # use epaint::{svg::SvgShapes, pos2, Color32, Rect};
+/// let svg = SvgShapes::from_svg_str(
+///     r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">
+///         <circle cx="12" cy="12" r="10" fill="#fff"/>
+///     </svg>"##,
+/// )
+/// .unwrap();
+/// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(48.0, 48.0));
+/// let shape = svg.to_shape(rect, Color32::RED);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgShapes {
+    /// The size of the SVG, in its own units (usually pixels).
+    pub size: Vec2,
+
+    /// The shapes, in the same units as [`Self::size`], with the origin at the left top.
+    pub shapes: Vec<Shape>,
+}
+
+impl SvgShapes {
+    /// Curves are flattened to within this fraction of the size of the SVG.
+    ///
+    /// This keeps an SVG crisp until it is painted about 2000 pixels large.
+    pub const TOLERANCE: f32 = 1.0 / 2000.0;
+
+    /// Parse an SVG file.
+    ///
+    /// # Errors
+    /// If the SVG could not be parsed.
+    pub fn from_svg_bytes(svg_bytes: &[u8]) -> Result<Self, String> {
+        profiling::function_scope!();
+        let tree = usvg::Tree::from_data(svg_bytes, &usvg::Options::default())
+            .map_err(|err| err.to_string())?;
+
+        let size = Vec2::new(tree.size().width(), tree.size().height());
+        let tolerance = Self::TOLERANCE * size.max_elem();
+
+        let mut shapes = vec![];
+        add_group(&mut shapes, tree.root(), 1.0, tolerance);
+        Ok(Self { size, shapes })
+    }
+
+    /// Parse an SVG file.
+    ///
+    /// # Errors
+    /// If the SVG could not be parsed.
+    pub fn from_svg_str(svg: &str) -> Result<Self, String> {
+        Self::from_svg_bytes(svg.as_bytes())
+    }
+
+    /// Scale the shapes to fit inside `rect`, keeping the aspect ratio, and multiply their colors by `tint`.
+    ///
+    /// Use [`Color32::WHITE`] to keep the original colors,
+    /// or use a white icon with the text color of the current style.
+    pub fn to_shape(&self, rect: Rect, tint: Color32) -> Shape {
+        let mut shape = Shape::Vec(self.shapes.clone());
+
+        if 0.0 < self.size.x && 0.0 < self.size.y {
+            let scaling = (rect.size() / self.size).min_elem();
+            let translation = rect.center() - scaling * self.size / 2.0;
+            shape.transform(TSTransform::new(translation.to_vec2(), scaling));
+        }
+
+        if tint != Color32::WHITE {
+            adjust_colors(&mut shape, move |color| *color = *color * tint);
+        }
+
+        shape
+    }
+}
+
+fn add_group(shapes: &mut Vec<Shape>, group: &usvg::Group, opacity: f32, tolerance: f32) {
+    let opacity = opacity * group.opacity().get();
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => add_group(shapes, group, opacity, tolerance),
+            usvg::Node::Path(path) => {
+                if path.is_visible() {
+                    add_path(shapes, path, opacity, tolerance);
+                }
+            }
+            usvg::Node::Image(_) | usvg::Node::Text(_) => {}
+        }
+    }
+}
+
+fn add_path(shapes: &mut Vec<Shape>, path: &usvg::Path, opacity: f32, tolerance: f32) {
+    let transform = path.abs_transform();
+    let (scale_x, scale_y) = transform.get_scale();
+    let scale = (scale_x * scale_y).sqrt();
+
+    let to_pos = |mut point: Point| {
+        transform.map_point(&mut point);
+        pos2(point.x, point.y)
+    };
+
+    let mut builder = PathBuilder::new().with_tolerance(tolerance);
+    if let Some(fill) = path.fill() {
+        builder = builder.with_fill_rule(match fill.rule() {
+            usvg::FillRule::NonZero => FillRule::NonZero,
+            usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+        });
+    }
+    for segment in path.data().segments() {
+        builder = match segment {
+            PathSegment::MoveTo(to) => builder.move_to(to_pos(to)),
+            PathSegment::LineTo(to) => builder.line_to(to_pos(to)),
+            PathSegment::QuadTo(control, to) => builder.quadratic_to(to_pos(control), to_pos(to)),
+            PathSegment::CubicTo(control1, control2, to) => {
+                builder.cubic_to(to_pos(control1), to_pos(control2), to_pos(to))
+            }
+            PathSegment::Close => builder.close(),
+        };
+    }
+
+    let fill = path
+        .fill()
+        .and_then(|fill| paint_color(fill.paint(), opacity * fill.opacity().get()))
+        .map(|color| builder.to_shape(color, PathStroke::NONE));
+
+    let stroke = path.stroke().and_then(|stroke| {
+        let color = paint_color(stroke.paint(), opacity * stroke.opacity().get())?;
+        let path_stroke = PathStroke::new(stroke.width().get() * scale, color)
+            .with_cap(match stroke.linecap() {
+                usvg::LineCap::Butt => LineCap::Butt,
+                usvg::LineCap::Round => LineCap::Round,
+                usvg::LineCap::Square => LineCap::Square,
+            })
+            .with_join(match stroke.linejoin() {
+                usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => LineJoin::Miter,
+                usvg::LineJoin::Round => LineJoin::Round,
+                usvg::LineJoin::Bevel => LineJoin::Bevel,
+            });
+        let dash = stroke.dasharray().map(|lengths| {
+            DashPattern::from_lengths(
+                lengths
+                    .iter()
+                    .map(|length| length * scale)
+                    .collect::<Vec<_>>(),
+            )
+            .with_offset(stroke.dashoffset() * scale)
+        });
+
+        let mut stroke_shapes = vec![];
+        for subpath in builder.subpaths() {
+            let path_shape = PathShape {
+                points: subpath.points.clone(),
+                closed: subpath.closed,
+                fill: Color32::TRANSPARENT,
+                stroke: path_stroke.clone(),
+            };
+            if let Some(dash) = &dash {
+                stroke_shapes.extend(path_shape.dashed(dash));
+            } else {
+                stroke_shapes.push(path_shape.into());
+            }
+        }
+        Some(Shape::Vec(stroke_shapes))
+    });
+
+    let (first, second) = match path.paint_order() {
+        usvg::PaintOrder::FillAndStroke => (fill, stroke),
+        usvg::PaintOrder::StrokeAndFill => (stroke, fill),
+    };
+    shapes.extend(first);
+    shapes.extend(second);
+}
+
+/// The color of a fill or stroke, or `None` if it is not supported.
+fn paint_color(paint: &usvg::Paint, opacity: f32) -> Option<Color32> {
+    let to_rgba = |color: usvg::Color, alpha: f32| {
+        Rgba::from_srgba_unmultiplied(color.red, color.green, color.blue, 255).multiply(alpha)
+    };
+
+    let rgba = match paint {
+        usvg::Paint::Color(color) => to_rgba(*color, opacity),
+        usvg::Paint::LinearGradient(gradient) => average_stop_color(gradient.stops(), opacity)?,
+        usvg::Paint::RadialGradient(gradient) => average_stop_color(gradient.stops(), opacity)?,
+        usvg::Paint::Pattern(_) => return None,
+    };
+    Some(rgba.into())
+}
+
+fn average_stop_color(stops: &[usvg::Stop], opacity: f32) -> Option<Rgba> {
+    if stops.is_empty() {
+        return None;
+    }
+    let sum = stops.iter().fold(Rgba::TRANSPARENT, |sum, stop| {
+        let color = stop.color();
+        sum + Rgba::from_srgba_unmultiplied(color.red, color.green, color.blue, 255)
+            .multiply(stop.opacity().get())
+    });
+    Some(sum * (opacity / stops.len() as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_shapes() {
+        let svg = SvgShapes::from_svg_str(
+            r##"<svg width="20" height="10" viewBox="0 0 2 1" xmlns="http://www.w3.org/2000/svg">
+                <rect width="1" height="1" fill="#ff0000"/>
+                <circle cx="1.5" cy="0.5" r="0.4" fill="none" stroke="#0000ff" stroke-width="0.2"/>
+            </svg>"##,
+        )
+        .unwrap();
+        assert_eq!(svg.size, Vec2::new(20.0, 10.0));
+        assert_eq!(svg.shapes.len(), 2, "One fill and one stroke");
+
+        let bounds = Shape::Vec(svg.shapes.clone()).visual_bounding_rect();
+        assert!(
+            (bounds.min - pos2(0.0, 0.0)).length() < 0.1,
+            "Viewbox is applied: {bounds:?}"
+        );
+        assert!((bounds.max - pos2(20.0, 10.0)).length() < 0.1, "{bounds:?}");
+
+        // Fit into a square, tinted:
+        let rect = Rect::from_min_max(pos2(100.0, 100.0), pos2(140.0, 140.0));
+        let shape = svg.to_shape(rect, Color32::from_gray(128));
+        let bounds = shape.visual_bounding_rect();
+        assert!(
+            (bounds.center() - rect.center()).length() < 0.1,
+            "{bounds:?}"
+        );
+        assert!((bounds.width() - 40.0).abs() < 0.1, "{bounds:?}");
+        assert!((bounds.height() - 20.0).abs() < 0.1, "{bounds:?}");
+
+        let Shape::Vec(shapes) = &shape else {
+            panic!("Expected a Vec: {shape:?}");
+        };
+        let Shape::Vec(fill) = &shapes[0] else {
+            panic!("Expected a Vec: {:?}", shapes[0]);
+        };
+        let Shape::Path(fill) = &fill[0] else {
+            panic!("The square is convex: {:?}", fill[0]);
+        };
+        assert_eq!(fill.fill, Color32::RED * Color32::from_gray(128));
+    }
+
+    #[test]
+    fn test_svg_error() {
+        assert!(SvgShapes::from_svg_str("not an svg").is_err());
+    }
+}
@@ -8,8 +8,9 @@
 use emath::{GuiRounding as _, NumExt as _, Pos2, Rect, Rot2, Vec2, pos2, remap, vec2};
 
 use crate::{
-    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32, CubicBezierShape,
-    EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke,
+    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadius, CornerRadiusF32,
+    CubicBezierShape, EllipseShape, InstancedMesh, InstancedShape, LineCap, LineJoin, MaskedShape,
+    Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, SdfRect, Shape, Stroke,
     StrokeKind, TextShape, TextureId, Vertex, WHITE_UV, color::ColorMode, emath,
     stroke::PathStroke, texture_atlas::PreparedDisc,
 };
@@ -381,6 +382,12 @@ impl Path {
     }
 
     pub fn add_open_points(&mut self, points: &[Pos2]) {
+        self.add_open_points_with_join(points, LineJoin::Miter);
+    }
+
+    /// Like [`Self::add_open_points`], but [`LineJoin::Round`] and [`LineJoin::Bevel`]
+    /// cut off every corner, not just the sharp ones.
+    pub fn add_open_points_with_join(&mut self, points: &[Pos2], join: LineJoin) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
 
@@ -403,9 +410,7 @@ impl Path {
 
                 let normal = (n0 + n1) / 2.0;
                 let length_sq = normal.length_sq();
-                let right_angle_length_sq = 0.5;
-                let sharper_than_a_right_angle = length_sq < right_angle_length_sq;
-                if sharper_than_a_right_angle {
+                if should_cut_off_corner(join, length_sq) {
                     // cut off the sharp corner
                     let center_normal = normal.normalized();
                     let n0c = (n0 + center_normal) / 2.0;
@@ -427,6 +432,14 @@ impl Path {
     }
 
     pub fn add_line_loop(&mut self, points: &[Pos2]) {
+        self.add_line_loop_with_join(points, LineJoin::Miter);
+    }
+
+    /// Like [`Self::add_line_loop`], but [`LineJoin::Round`] and [`LineJoin::Bevel`]
+    /// cut off every corner.
+    ///
+    /// Only use this for paths that won't be filled.
+    pub fn add_line_loop_with_join(&mut self, points: &[Pos2], join: LineJoin) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
         self.reserve(n);
@@ -455,11 +468,8 @@ impl Path {
             // and then only expand during feathering.
             //
             // See https://github.com/emilk/egui/issues/1226
-            const CUT_OFF_SHARP_CORNERS: bool = false;
-
-            let right_angle_length_sq = 0.5;
-            let sharper_than_a_right_angle = length_sq < right_angle_length_sq;
-            if CUT_OFF_SHARP_CORNERS && sharper_than_a_right_angle {
+            let cut_off_corner = join != LineJoin::Miter && should_cut_off_corner(join, length_sq);
+            if cut_off_corner {
                 // cut off the sharp corner
                 let center_normal = normal.normalized();
                 let n0c = (n0 + center_normal) / 2.0;
@@ -532,6 +542,20 @@ impl Path {
     }
 }
 
+/// `length_sq` is the squared length of the average of the normals on each side of the corner.
+fn should_cut_off_corner(join: LineJoin, length_sq: f32) -> bool {
+    match join {
+        LineJoin::Miter => {
+            let right_angle_length_sq = 0.5;
+            length_sq < right_angle_length_sq // sharper than a right angle
+        }
+        LineJoin::Round | LineJoin::Bevel => {
+            let straight_length_sq = 0.999;
+            length_sq < straight_length_sq
+        }
+    }
+}
+
 pub mod path {
     //! Helpers for constructing paths
     use crate::CornerRadiusF32;
@@ -720,6 +744,30 @@ pub struct TessellationOptions {
     ///
     /// The default is `false` to save performance.
     pub validate_meshes: bool,
+
+    /// Output [`Shape::Instanced`] as [`Primitive::Instanced`],
+    /// for backends that can paint all the instances with a single draw call.
+    ///
+    /// If `false`, the instances are copied into a plain [`Mesh`].
+    ///
+    /// The default is `false`.
+    pub instanced_primitives: bool,
+
+    /// Output rounded or blurred [`RectShape`]s (including shadows) as [`Primitive::SdfRect`],
+    /// for backends that can paint them with a signed distance field shader.
+    ///
+    /// If `false`, they are tessellated into feathered triangles.
+    ///
+    /// The default is `false`.
+    pub sdf_rects: bool,
+
+    /// Output [`Shape::BackdropBlur`] as [`Primitive::BackdropBlur`],
+    /// for backends that can blur what has already been painted.
+    ///
+    /// If `false`, [`crate::BackdropBlurShape::fallback_fill`] is painted instead.
+    ///
+    /// The default is `false`.
+    pub backdrop_blur: bool,
 }
 
 impl Default for TessellationOptions {
@@ -739,6 +787,9 @@ impl Default for TessellationOptions {
             epsilon: 1.0e-5,
             parallel_tessellation: true,
             validate_meshes: false,
+            instanced_primitives: false,
+            sdf_rects: false,
+            backdrop_blur: false,
         }
     }
 }
@@ -1289,6 +1340,213 @@ fn stroke_and_fill_path(
     }
 }
 
+/// Copy the triangles of `mesh` to `out`, multiplying the vertex colors with `color_at`.
+///
+/// The triangles are subdivided where needed, so that the gradient is smooth.
+fn add_with_gradient(
+    mesh: &Mesh,
+    color_at: impl Fn(Pos2) -> Color32,
+    pixels_per_point: f32,
+    out: &mut Mesh,
+) {
+    /// Stop subdividing when a triangle is this small (in pixels)…
+    const MIN_EDGE_LENGTH: f32 = 2.0;
+
+    /// …or when the gradient is this close to linear across it (in `u8` units)…
+    const TOLERANCE: i32 = 2;
+
+    /// …but never leave an edge longer than this (in pixels), so we don't miss a thin stripe.
+    const MAX_EDGE_LENGTH: f32 = 64.0;
+
+    fn lerp_vertex(a: &Vertex, b: &Vertex) -> Vertex {
+        Vertex {
+            pos: a.pos.lerp(b.pos, 0.5),
+            uv: a.uv.lerp(b.uv, 0.5),
+            color: a.color.lerp_to_gamma(b.color, 0.5),
+        }
+    }
+
+    fn is_close(a: Color32, b: Color32) -> bool {
+        (0..4).all(|i| (i32::from(a[i]) - i32::from(b[i])).abs() <= TOLERANCE)
+    }
+
+    struct Subdivider<'a, F> {
+        color_at: F,
+        min_edge_length: f32,
+        max_edge_length: f32,
+        out: &'a mut Mesh,
+    }
+
+    impl<F: Fn(Pos2) -> Color32> Subdivider<'_, F> {
+        fn add(&mut self, triangle: [(Vertex, Color32); 3]) {
+            let edge_length = |i: usize| triangle[i].0.pos.distance(triangle[(i + 1) % 3].0.pos);
+            let longest = (0..3)
+                .max_by(|&a, &b| edge_length(a).total_cmp(&edge_length(b)))
+                .unwrap_or_default();
+            let longest_length = edge_length(longest);
+
+            let needs_split = if longest_length <= self.min_edge_length {
+                false
+            } else if self.max_edge_length < longest_length {
+                true
+            } else {
+                // Would linear interpolation of the corner colors be a good enough approximation?
+                let [(a, ca), (b, cb), (c, cc)] = triangle;
+                let centroid = pos2(
+                    (a.pos.x + b.pos.x + c.pos.x) / 3.0,
+                    (a.pos.y + b.pos.y + c.pos.y) / 3.0,
+                );
+                let centroid_color = Color32::from_rgba_premultiplied(
+                    ((u32::from(ca.r()) + u32::from(cb.r()) + u32::from(cc.r())) / 3) as u8,
+                    ((u32::from(ca.g()) + u32::from(cb.g()) + u32::from(cc.g())) / 3) as u8,
+                    ((u32::from(ca.b()) + u32::from(cb.b()) + u32::from(cc.b())) / 3) as u8,
+                    ((u32::from(ca.a()) + u32::from(cb.a()) + u32::from(cc.a())) / 3) as u8,
+                );
+                let samples = [
+                    (a.pos.lerp(b.pos, 0.5), ca.lerp_to_gamma(cb, 0.5)),
+                    (b.pos.lerp(c.pos, 0.5), cb.lerp_to_gamma(cc, 0.5)),
+                    (c.pos.lerp(a.pos, 0.5), cc.lerp_to_gamma(ca, 0.5)),
+                    (centroid, centroid_color),
+                ];
+                samples
+                    .iter()
+                    .any(|&(pos, interpolated)| !is_close(interpolated, (self.color_at)(pos)))
+            };
+
+            if needs_split {
+                let [a, b, c] = [longest, (longest + 1) % 3, (longest + 2) % 3];
+                let mid = lerp_vertex(&triangle[a].0, &triangle[b].0);
+                let mid = (mid, (self.color_at)(mid.pos));
+                self.add([triangle[a], mid, triangle[c]]);
+                self.add([mid, triangle[b], triangle[c]]);
+            } else {
+                let idx = self.out.vertices.len() as u32;
+                self.out.add_triangle(idx, idx + 1, idx + 2);
+                for (mut vertex, gradient) in triangle {
+                    vertex.color = vertex.color * gradient;
+                    self.out.vertices.push(vertex);
+                }
+            }
+        }
+    }
+
+    let mut subdivider = Subdivider {
+        min_edge_length: MIN_EDGE_LENGTH / pixels_per_point,
+        max_edge_length: MAX_EDGE_LENGTH / pixels_per_point,
+        color_at: &color_at,
+        out,
+    };
+    for triangle in mesh.indices.chunks_exact(3) {
+        let vertex = |i: usize| {
+            let vertex = mesh.vertices[triangle[i] as usize];
+            (vertex, color_at(vertex.pos))
+        };
+        subdivider.add([vertex(0), vertex(1), vertex(2)]);
+    }
+}
+
+/// Copy the triangles of `content` to `out` where they overlap the triangles of `mask`,
+/// multiplying the vertex colors with the alpha of the mask.
+fn add_masked(content: &Mesh, mask: &Mesh, out: &mut Mesh) {
+    fn triangle(mesh: &Mesh, indices: &[u32]) -> [Vertex; 3] {
+        [0, 1, 2].map(|i| mesh.vertices[indices[i] as usize])
+    }
+
+    /// Interpolate the vertex attributes of `triangle` at `pos`.
+    fn interpolate(triangle: &[Vertex; 3], pos: Pos2) -> Vertex {
+        let [a, b, c] = triangle;
+        let (ab, ac, ap) = (b.pos - a.pos, c.pos - a.pos, pos - a.pos);
+        let area = ab.x * ac.y - ab.y * ac.x;
+        if area == 0.0 {
+            return Vertex { pos, ..*a };
+        }
+        let wb = (ap.x * ac.y - ap.y * ac.x) / area;
+        let wc = (ab.x * ap.y - ab.y * ap.x) / area;
+        let wa = 1.0 - wb - wc;
+        let channel = |i: usize| {
+            (wa * f32::from(a.color[i]) + wb * f32::from(b.color[i]) + wc * f32::from(c.color[i]))
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Vertex {
+            pos,
+            uv: (wa * a.uv.to_vec2() + wb * b.uv.to_vec2() + wc * c.uv.to_vec2()).to_pos2(),
+            color: Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3)),
+        }
+    }
+
+    /// Cut away the parts of the convex `polygon` outside of `triangle` (Sutherland–Hodgman).
+    fn clip_to_triangle(polygon: &mut Vec<Pos2>, scratch: &mut Vec<Pos2>, triangle: [Pos2; 3]) {
+        let [a, b, c] = triangle;
+        let area = (b - a).x * (c - a).y - (b - a).y * (c - a).x;
+        if area == 0.0 {
+            polygon.clear();
+            return;
+        }
+        let winding = area.signum();
+        for i in 0..3 {
+            let (start, end) = (triangle[i], triangle[(i + 1) % 3]);
+            let edge = end - start;
+            let side = |p: Pos2| winding * (edge.x * (p - start).y - edge.y * (p - start).x);
+
+            std::mem::swap(polygon, scratch);
+            polygon.clear();
+            for j in 0..scratch.len() {
+                let (p, q) = (scratch[j], scratch[(j + 1) % scratch.len()]);
+                let (sp, sq) = (side(p), side(q));
+                if 0.0 <= sp {
+                    polygon.push(p);
+                }
+                if (0.0 <= sp) != (0.0 <= sq) {
+                    polygon.push(p.lerp(q, sp / (sp - sq)));
+                }
+            }
+            if polygon.len() < 3 {
+                return;
+            }
+        }
+    }
+
+    let mask_triangles: Vec<([Vertex; 3], Rect)> = mask
+        .indices
+        .chunks_exact(3)
+        .map(|indices| triangle(mask, indices))
+        .filter(|triangle| triangle.iter().any(|v| 0 < v.color.a()))
+        .map(|triangle| (triangle, Rect::from_points(&triangle.map(|v| v.pos))))
+        .collect();
+
+    let mut polygon = vec![];
+    let mut scratch = vec![];
+    for indices in content.indices.chunks_exact(3) {
+        let content_triangle = triangle(content, indices);
+        let bounds = Rect::from_points(&content_triangle.map(|v| v.pos));
+
+        for (mask_triangle, mask_bounds) in &mask_triangles {
+            if !bounds.intersects(*mask_bounds) {
+                continue;
+            }
+
+            polygon.clear();
+            polygon.extend(content_triangle.map(|v| v.pos));
+            clip_to_triangle(&mut polygon, &mut scratch, mask_triangle.map(|v| v.pos));
+            if polygon.len() < 3 {
+                continue;
+            }
+
+            let idx = out.vertices.len() as u32;
+            for &pos in &polygon {
+                let mut vertex = interpolate(&content_triangle, pos);
+                let coverage = interpolate(mask_triangle, pos).color.a();
+                vertex.color = vertex.color.gamma_multiply_u8(coverage);
+                out.vertices.push(vertex);
+            }
+            for i in 2..polygon.len() as u32 {
+                out.add_triangle(idx, idx + i - 1, idx + i);
+            }
+        }
+    }
+}
+
 fn mul_color(color: Color32, factor: f32) -> Color32 {
     // The fast gamma-space multiply also happens to be perceptually better.
     // Win-win!
@@ -1383,6 +1641,64 @@ impl Tessellator {
             return;
         }
 
+        if let Shape::BackdropBlur(blur) = shape {
+            if !self.options.backdrop_blur {
+                self.tessellate_clipped_shape(
+                    ClippedShape {
+                        clip_rect,
+                        shape: blur.to_fallback_shape().into(),
+                    },
+                    out_primitives,
+                );
+            } else if !self.options.coarse_tessellation_culling
+                || blur.visual_bounding_rect().intersects(clip_rect)
+            {
+                out_primitives.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::BackdropBlur(blur),
+                });
+            }
+            return;
+        }
+
+        if self.options.sdf_rects {
+            if let Shape::Rect(rect_shape) = &shape {
+                if let Some(sdf_rect) = self.rect_to_sdf(rect_shape) {
+                    if !self.options.coarse_tessellation_culling
+                        || sdf_rect.visual_bounding_rect().intersects(clip_rect)
+                    {
+                        out_primitives.push(ClippedPrimitive {
+                            clip_rect,
+                            primitive: Primitive::SdfRect(sdf_rect),
+                        });
+                    }
+                    return;
+                }
+            }
+        }
+
+        if let Shape::Instanced(instanced) = shape {
+            let instanced = self.tessellate_instanced(instanced, clip_rect);
+            if instanced.is_empty() {
+                // Nothing to paint
+            } else if self.options.instanced_primitives {
+                out_primitives.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::Instanced(instanced),
+                });
+            } else {
+                let mesh = instanced.to_mesh();
+                self.tessellate_clipped_shape(
+                    ClippedShape {
+                        clip_rect,
+                        shape: mesh.into(),
+                    },
+                    out_primitives,
+                );
+            }
+            return;
+        }
+
         let start_new_mesh = match out_primitives.last() {
             None => true,
             Some(output_clipped_primitive) => {
@@ -1391,7 +1707,10 @@ impl Tessellator {
                         Primitive::Mesh(output_mesh) => {
                             output_mesh.texture_id != shape.texture_id()
                         }
-                        Primitive::Callback(_) => true,
+                        Primitive::Instanced(_)
+                        | Primitive::SdfRect(_)
+                        | Primitive::BackdropBlur(_)
+                        | Primitive::Callback(_) => true,
                     }
             }
         };
@@ -1473,12 +1792,93 @@ impl Tessellator {
                 self.tessellate_quadratic_bezier(&quadratic_shape, out);
             }
             Shape::CubicBezier(cubic_shape) => self.tessellate_cubic_bezier(&cubic_shape, out),
+            Shape::Instanced(instanced) => {
+                let clip_rect = self.clip_rect;
+                out.append(self.tessellate_instanced(instanced, clip_rect).to_mesh());
+            }
+            Shape::Masked(masked) => {
+                self.tessellate_masked(&masked, out);
+            }
+            Shape::BackdropBlur(blur) => {
+                // Blurring needs to be done by the backend, see `tessellate_clipped_shape`.
+                self.tessellate_rect(&blur.to_fallback_shape(), out);
+            }
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
         }
     }
 
+    /// Tessellate the shape of an [`InstancedShape`] once,
+    /// dropping the instances that fall outside of `clip_rect`.
+    pub fn tessellate_instanced(
+        &mut self,
+        instanced: InstancedShape,
+        clip_rect: Rect,
+    ) -> InstancedMesh {
+        profiling::function_scope!();
+
+        fn tessellate_instanced_shape(tessellator: &mut Tessellator, shape: Shape, out: &mut Mesh) {
+            match shape {
+                Shape::Vec(shapes) => {
+                    for shape in shapes {
+                        tessellate_instanced_shape(tessellator, shape, out);
+                    }
+                }
+                Shape::Callback(_) => {
+                    debug_assert!(false, "Shape::Callback can't be instanced");
+                }
+                shape => tessellator.tessellate_shape(shape, out),
+            }
+        }
+
+        let InstancedShape {
+            shape,
+            mut instances,
+        } = instanced;
+
+        let mut mesh = Mesh::with_texture(shape.texture_id());
+
+        // The shape is in its own coordinate system, so we can't cull against the clip rect:
+        let culling = std::mem::replace(&mut self.options.coarse_tessellation_culling, false);
+        self.clip_rect = Rect::EVERYTHING;
+        tessellate_instanced_shape(self, std::sync::Arc::unwrap_or_clone(shape), &mut mesh);
+        self.options.coarse_tessellation_culling = culling;
+        self.clip_rect = clip_rect;
+
+        if culling {
+            let bounds = mesh.calc_bounds();
+            instances.retain(|instance| clip_rect.intersects(instance.transform * bounds));
+        }
+
+        InstancedMesh { mesh, instances }
+    }
+
+    /// Tessellate a [`MaskedShape`] into a [`Mesh`].
+    ///
+    /// * `masked`: the shape and mask to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_masked(&mut self, masked: &MaskedShape, out: &mut Mesh) {
+        profiling::function_scope!();
+
+        if self.options.coarse_tessellation_culling
+            && !self.clip_rect.intersects(masked.visual_bounding_rect())
+        {
+            return;
+        }
+
+        let mut mask = Mesh::default();
+        self.tessellate_shape((*masked.mask).clone(), &mut mask);
+        if mask.is_empty() {
+            return;
+        }
+
+        let mut content = Mesh::with_texture(out.texture_id);
+        self.tessellate_shape((*masked.shape).clone(), &mut content);
+
+        add_masked(&content, &mask, out);
+    }
+
     /// Tessellate a single [`CircleShape`] into a [`Mesh`].
     ///
     /// * `shape`: the circle to tessellate.
@@ -1711,7 +2111,7 @@ impl Tessellator {
     /// * `path_shape`: the path to tessellate.
     /// * `out`: triangles are appended to this.
     pub fn tessellate_path(&mut self, path_shape: &PathShape, out: &mut Mesh) {
-        if path_shape.points.len() < 2 {
+        if path_shape.points.len() < 2 && (path_shape.closed || path_shape.points.is_empty()) {
             return;
         }
 
@@ -1730,27 +2130,176 @@ impl Tessellator {
             stroke,
         } = path_shape;
 
-        self.scratchpad_path.clear();
-
-        if *closed {
-            self.scratchpad_path.add_line_loop(points);
-
-            self.scratchpad_path
-                .fill_and_stroke(self.feathering, *fill, stroke, out);
-        } else {
+        if !*closed {
             debug_assert_eq!(
                 *fill,
                 Color32::TRANSPARENT,
                 "You asked to fill a path that is not closed. That makes no sense."
             );
+        }
 
-            self.scratchpad_path.add_open_points(points);
+        self.tessellate_points(points, *closed, *fill, stroke, out);
+    }
 
+    /// Fill and/or stroke a polyline, e.g. a [`PathShape`] or a flattened Bézier curve.
+    fn tessellate_points(
+        &mut self,
+        points: &[Pos2],
+        closed: bool,
+        fill: Color32,
+        stroke: &PathStroke,
+        out: &mut Mesh,
+    ) {
+        if closed {
+            // Only unfilled paths can cut off corners, see `Path::add_line_loop_with_join`:
+            let join = if fill == Color32::TRANSPARENT {
+                stroke.join
+            } else {
+                LineJoin::Miter
+            };
+
+            self.scratchpad_path.clear();
+            self.scratchpad_path.add_line_loop_with_join(points, join);
             self.scratchpad_path
-                .stroke(self.feathering, PathType::Open, stroke, out);
+                .fill_and_stroke(self.feathering, fill, stroke, out);
+
+            if join == LineJoin::Round {
+                self.add_round_points(points, stroke, out);
+            }
+        } else {
+            self.stroke_open_points(points, stroke, out);
         }
     }
 
+    /// Stroke an open polyline, with [`PathStroke::cap`] at the ends.
+    ///
+    /// A single point only paints the caps.
+    fn stroke_open_points(&mut self, points: &[Pos2], stroke: &PathStroke, out: &mut Mesh) {
+        let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+            return;
+        };
+
+        if points.len() == 1 {
+            match stroke.cap {
+                LineCap::Butt => {}
+                LineCap::Round => self.add_round_points(&[first], stroke, out),
+                LineCap::Square => {
+                    if let ColorMode::Solid(color) = stroke.color {
+                        let rect = Rect::from_center_size(first, Vec2::splat(stroke.width));
+                        self.tessellate_rect(&RectShape::filled(rect, 0.0, color), out);
+                    }
+                }
+            }
+            return;
+        }
+
+        self.scratchpad_path.clear();
+        if stroke.cap == LineCap::Square {
+            let n = points.len();
+            let half_width = stroke.width / 2.0;
+            let start_dir = (points[1] - first).normalized();
+            let end_dir = (last - points[n - 2]).normalized();
+            let mut points = points.to_vec();
+            points[0] -= half_width * start_dir;
+            points[n - 1] += half_width * end_dir;
+            self.scratchpad_path
+                .add_open_points_with_join(&points, stroke.join);
+        } else {
+            self.scratchpad_path
+                .add_open_points_with_join(points, stroke.join);
+        }
+        self.scratchpad_path
+            .stroke(self.feathering, PathType::Open, stroke, out);
+
+        if stroke.cap == LineCap::Round {
+            self.add_round_points(&[first, last], stroke, out);
+        }
+        if stroke.join == LineJoin::Round {
+            self.add_round_points(&points[1..points.len() - 1], stroke, out);
+        }
+    }
+
+    /// Paint a disc the width of the stroke at each point, for round caps and joins.
+    fn add_round_points(&self, points: &[Pos2], stroke: &PathStroke, out: &mut Mesh) {
+        let ColorMode::Solid(color) = stroke.color else {
+            return; // not supported
+        };
+        if stroke.kind != StrokeKind::Middle || stroke.width <= self.feathering {
+            return; // not supported, or too thin to be visible
+        }
+
+        let mut path = Path::default();
+        for &point in points {
+            path.clear();
+            path.add_circle(point, stroke.width / 2.0);
+            path.fill(self.feathering, color, out);
+        }
+    }
+
+    /// Turn a [`RectShape`] into a [`SdfRect`], if that is worth it.
+    ///
+    /// Sharp and crisp rectangles are cheap to tessellate, so they return `None`,
+    /// as do rectangles painted with a [`crate::Brush`].
+    fn rect_to_sdf(&self, rect_shape: &RectShape) -> Option<SdfRect> {
+        let RectShape {
+            mut rect,
+            corner_radius,
+            fill,
+            mut stroke,
+            stroke_kind,
+            round_to_pixels,
+            blur_width,
+            brush: _,
+        } = *rect_shape;
+
+        if rect_shape.brush.is_some() || (corner_radius == CornerRadius::ZERO && blur_width <= 0.0)
+        {
+            return None;
+        }
+
+        let mut corner_radius = CornerRadiusF32::from(corner_radius);
+
+        if stroke.width == 0.0 {
+            stroke = Stroke::NONE;
+        }
+
+        // Same as in `tessellate_rect`:
+        rect.min = rect.min.at_least(pos2(-1e7, -1e7));
+        rect.max = rect.max.at_most(pos2(1e7, 1e7));
+
+        if round_to_pixels.unwrap_or(self.options.round_rects_to_pixels) {
+            match stroke_kind {
+                StrokeKind::Inside | StrokeKind::Outside => {
+                    rect = rect.round_to_pixels(self.pixels_per_point);
+                }
+                StrokeKind::Middle => {
+                    stroke.round_rect_to_pixel(self.pixels_per_point, &mut rect);
+                }
+            }
+        }
+
+        // Make `rect` the outer edge of the stroke:
+        match stroke_kind {
+            StrokeKind::Inside => {}
+            StrokeKind::Middle => {
+                rect = rect.expand(stroke.width / 2.0);
+                corner_radius += stroke.width / 2.0;
+            }
+            StrokeKind::Outside => {
+                rect = rect.expand(stroke.width);
+                corner_radius += stroke.width;
+            }
+        }
+
+        Some(SdfRect {
+            rect,
+            corner_radius,
+            fill,
+            stroke,
+            blur_width: blur_width.at_least(0.0),
+        })
+    }
+
     /// Tessellate a single [`Rect`] into a [`Mesh`].
     ///
     /// * `rect`: the rectangle to tessellate.
@@ -1953,17 +2502,25 @@ impl Tessellator {
             };
 
             if fill_rect.is_positive() {
-                let crate::Brush {
+                if let crate::Brush::Texture {
                     fill_texture_id,
                     uv,
-                } = **brush;
-                let uv_from_pos = |p: Pos2| {
-                    pos2(
-                        remap(p.x, rect.x_range(), uv.x_range()),
-                        remap(p.y, rect.y_range(), uv.y_range()),
-                    )
-                };
-                path.fill_with_uv(self.feathering, fill, fill_texture_id, uv_from_pos, out);
+                } = **brush
+                {
+                    let uv_from_pos = |p: Pos2| {
+                        pos2(
+                            remap(p.x, rect.x_range(), uv.x_range()),
+                            remap(p.y, rect.y_range(), uv.y_range()),
+                        )
+                    };
+                    path.fill_with_uv(self.feathering, fill, fill_texture_id, uv_from_pos, out);
+                } else {
+                    // Gradient: fill with `fill`, then multiply with the gradient
+                    let mut fill_mesh = Mesh::default();
+                    path.fill(self.feathering, fill, &mut fill_mesh);
+                    let color_at = |pos| brush.gradient_color_at(fill_rect, pos).unwrap_or(fill);
+                    add_with_gradient(&fill_mesh, color_at, self.pixels_per_point, out);
+                }
             }
 
             if !stroke.is_empty() {
@@ -2171,24 +2728,15 @@ impl Tessellator {
             return;
         }
 
-        self.scratchpad_path.clear();
-        if closed {
-            self.scratchpad_path.add_line_loop(points);
-
-            self.scratchpad_path
-                .fill_and_stroke(self.feathering, fill, stroke, out);
-        } else {
+        if !closed {
             debug_assert_eq!(
                 fill,
                 Color32::TRANSPARENT,
                 "You asked to fill a bezier path that is not closed. That makes no sense."
             );
-
-            self.scratchpad_path.add_open_points(points);
-
-            self.scratchpad_path
-                .stroke(self.feathering, PathType::Open, stroke, out);
         }
+
+        self.tessellate_points(points, closed, fill, stroke, out);
     }
 }
 
@@ -2240,13 +2788,27 @@ impl Tessellator {
             p.clip_rect.is_positive()
                 && match &p.primitive {
                     Primitive::Mesh(mesh) => !mesh.is_empty(),
+                    Primitive::Instanced(instanced) => !instanced.is_empty(),
+                    Primitive::SdfRect(sdf_rect) => sdf_rect.rect.is_positive(),
+                    Primitive::BackdropBlur(blur) => {
+                        blur.rect.is_positive() && 0.0 < blur.blur_radius
+                    }
                     Primitive::Callback(_) => true,
                 }
         });
 
         for clipped_primitive in &clipped_primitives {
-            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
-                debug_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
+            match &clipped_primitive.primitive {
+                Primitive::Mesh(mesh) => {
+                    debug_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
+                }
+                Primitive::Instanced(instanced) => {
+                    debug_assert!(
+                        instanced.mesh.is_valid(),
+                        "Tessellator generated invalid Mesh"
+                    );
+                }
+                Primitive::SdfRect(_) | Primitive::BackdropBlur(_) | Primitive::Callback(_) => {}
             }
         }
 
@@ -2270,7 +2832,10 @@ impl Tessellator {
 
                 Shape::Path(path_shape) => 32 < path_shape.points.len(),
 
-                Shape::QuadraticBezier(_) | Shape::CubicBezier(_) | Shape::Ellipse(_) => true,
+                Shape::QuadraticBezier(_)
+                | Shape::CubicBezier(_)
+                | Shape::Ellipse(_)
+                | Shape::Masked(_) => true,
 
                 Shape::Noop
                 | Shape::Text(_)
@@ -2278,6 +2843,8 @@ impl Tessellator {
                 | Shape::Mesh(_)
                 | Shape::LineSegment { .. }
                 | Shape::Rect(_)
+                | Shape::Instanced(_) // may become a `Primitive::Instanced`
+                | Shape::BackdropBlur(_)
                 | Shape::Callback(_) => false,
             }
         }
@@ -2367,6 +2934,56 @@ fn test_tessellator() {
     assert_eq!(primitives.len(), 2);
 }
 
+#[test]
+fn test_tessellate_instanced() {
+    use crate::*;
+
+    let screen = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let marker = Shape::circle_filled(Pos2::ZERO, 2.0, Color32::WHITE);
+    let instances = (0..20).map(|i| {
+        Instance::new(emath::TSTransform::from_translation(vec2(
+            10.0 * i as f32,
+            50.0,
+        )))
+    });
+    let clipped_shapes = vec![ClippedShape {
+        clip_rect: screen,
+        shape: InstancedShape::new(marker.clone(), instances).into(),
+    }];
+
+    let tessellate = |instanced_primitives| {
+        let options = TessellationOptions {
+            instanced_primitives,
+            ..Default::default()
+        };
+        Tessellator::new(1.0, options, [1024, 1024], vec![])
+            .tessellate_shapes(clipped_shapes.clone())
+    };
+
+    let mut marker_mesh = Mesh::default();
+    Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+        .tessellate_shape(marker, &mut marker_mesh);
+
+    let primitives = tessellate(true);
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Instanced(instanced) = &primitives[0].primitive else {
+        panic!("Expected an instanced primitive");
+    };
+    assert_eq!(instanced.mesh, marker_mesh);
+    assert_eq!(
+        instanced.instances.len(),
+        11,
+        "Instances outside the clip rect are culled"
+    );
+
+    let primitives = tessellate(false);
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    assert_eq!(mesh, &instanced.to_mesh());
+}
+
 #[test]
 fn path_bounding_box() {
     use crate::*;
@@ -2411,3 +3028,197 @@ fn path_bounding_box() {
         );
     }
 }
+
+#[test]
+fn test_tessellate_sdf_rects() {
+    use crate::*;
+
+    let screen = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let rect = Rect::from_min_max(pos2(10.0, 10.0), pos2(50.0, 30.0));
+    let clipped_shapes = vec![
+        ClippedShape {
+            clip_rect: screen,
+            shape: Shape::Rect(RectShape::filled(rect, 4.0, Color32::BLACK).with_blur_width(8.0)),
+        },
+        ClippedShape {
+            clip_rect: screen,
+            shape: Shape::rect_filled(rect, 0.0, Color32::RED),
+        },
+    ];
+
+    let tessellate = |sdf_rects| {
+        let options = TessellationOptions {
+            sdf_rects,
+            ..Default::default()
+        };
+        Tessellator::new(1.0, options, [1024, 1024], vec![])
+            .tessellate_shapes(clipped_shapes.clone())
+    };
+
+    let primitives = tessellate(true);
+    assert_eq!(primitives.len(), 2);
+    let Primitive::SdfRect(sdf_rect) = &primitives[0].primitive else {
+        panic!("Expected a SDF rect");
+    };
+    assert_eq!(sdf_rect.rect, rect);
+    assert_eq!(sdf_rect.blur_width, 8.0);
+    assert!(
+        matches!(primitives[1].primitive, Primitive::Mesh(_)),
+        "Sharp rectangles are still tessellated"
+    );
+
+    let primitives = tessellate(false);
+    assert_eq!(primitives.len(), 1);
+    assert!(matches!(primitives[0].primitive, Primitive::Mesh(_)));
+}
+
+#[test]
+fn test_tessellate_line_caps() {
+    use crate::*;
+
+    let tessellate = |shape: PathShape| {
+        let mut mesh = Mesh::default();
+        Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+            .tessellate_path(&shape, &mut mesh);
+        assert!(mesh.is_valid());
+        mesh
+    };
+
+    let points = vec![pos2(10.0, 10.0), pos2(50.0, 10.0)];
+    let stroke = PathStroke::new(4.0, Color32::WHITE);
+
+    let with_cap = |cap| {
+        tessellate(PathShape::line(
+            points.clone(),
+            stroke.clone().with_cap(cap),
+        ))
+        .calc_bounds()
+    };
+    let butt = with_cap(LineCap::Butt);
+    assert_eq!(
+        with_cap(LineCap::Square),
+        butt.expand2(vec2(stroke.width / 2.0, 0.0)),
+        "Square caps extend the line by half the width"
+    );
+    assert_eq!(
+        with_cap(LineCap::Round),
+        Rect::from_two_pos(points[0], points[1]).expand(stroke.width / 2.0 + 0.5),
+        "Round caps are half discs, plus feathering"
+    );
+
+    let dashes =
+        PathShape::line(points.clone(), stroke.clone()).dashed(&DashPattern::new(4.0, 4.0));
+    assert_eq!(dashes.len(), 5);
+
+    let dot = PathShape::line(vec![points[0]], stroke.with_cap(LineCap::Round));
+    assert_eq!(
+        tessellate(dot).calc_bounds(),
+        Rect::from_center_size(points[0], Vec2::splat(5.0)),
+        "A single point is painted as a dot"
+    );
+}
+
+#[test]
+fn test_tessellate_gradient() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 20.0));
+    let brush = Brush::horizontal_gradient(Color32::BLACK, Color32::WHITE);
+    let shape = RectShape::filled(rect, 0.0, Color32::WHITE).with_brush(brush);
+
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_rect(&shape, &mut mesh);
+    assert!(mesh.is_valid());
+
+    for vertex in &mesh.vertices {
+        if vertex.color.a() == 255 {
+            // Not part of the feathering
+            let expected = (255.0 * vertex.pos.x / rect.width()).round() as i32;
+            let actual = vertex.color.r() as i32;
+            assert!(
+                (actual - expected).abs() <= 2,
+                "{vertex:?} should be {expected}"
+            );
+        }
+    }
+    assert!(
+        mesh.vertices.len() > 8,
+        "The rectangle is subdivided along the gradient"
+    );
+}
+
+#[test]
+fn test_tessellate_masked() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let (center, radius) = (pos2(50.0, 50.0), 30.0);
+    let shape = Shape::masked(
+        Shape::rect_filled(rect, 0.0, Color32::RED),
+        Shape::circle_filled(center, radius, Color32::WHITE),
+    );
+    assert_eq!(
+        shape.visual_bounding_rect(),
+        Rect::from_center_size(center, Vec2::splat(2.0 * radius))
+    );
+
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_shape(shape, &mut mesh);
+    assert!(mesh.is_valid());
+
+    let mut coverage = 0.0;
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+        for vertex in [a, b, c] {
+            assert!(vertex.pos.distance(center) <= radius + 1.0, "{vertex:?}");
+            assert_eq!(vertex.color.r(), vertex.color.a(), "Still red");
+        }
+        let area = 0.5
+            * ((b.pos - a.pos).x * (c.pos - a.pos).y - (b.pos - a.pos).y * (c.pos - a.pos).x).abs();
+        let alpha =
+            (f32::from(a.color.a()) + f32::from(b.color.a()) + f32::from(c.color.a())) / 3.0;
+        coverage += area * alpha / 255.0;
+    }
+    let circle_area = std::f32::consts::PI * radius * radius;
+    assert!(
+        (coverage - circle_area).abs() < 0.01 * circle_area,
+        "{coverage} vs {circle_area}"
+    );
+}
+
+#[test]
+fn test_tessellate_backdrop_blur() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(10.0, 10.0), pos2(50.0, 30.0));
+    let blur = BackdropBlurShape::new(rect, 4, 8.0, Color32::from_black_alpha(64));
+    let clipped_shapes = vec![ClippedShape {
+        clip_rect: Rect::EVERYTHING,
+        shape: blur.into(),
+    }];
+
+    let tessellate = |backdrop_blur| {
+        let options = TessellationOptions {
+            backdrop_blur,
+            ..Default::default()
+        };
+        Tessellator::new(1.0, options, [1024, 1024], vec![])
+            .tessellate_shapes(clipped_shapes.clone())
+    };
+
+    let primitives = tessellate(true);
+    assert_eq!(primitives.len(), 1);
+    let Primitive::BackdropBlur(output) = &primitives[0].primitive else {
+        panic!("Expected a backdrop blur");
+    };
+    assert_eq!(*output, blur);
+
+    let primitives = tessellate(false);
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected the fallback fill");
+    };
+    assert_eq!(mesh.vertices[0].color, Color32::from_black_alpha(64));
+}
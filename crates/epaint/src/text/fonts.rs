@@ -422,6 +422,9 @@ impl FontDefinitions {
 pub struct Fonts(Arc<Mutex<FontsAndCache>>);
 
 impl Fonts {
+    /// The default for [`Self::set_galley_cache_budget`].
+    pub const DEFAULT_GALLEY_CACHE_BUDGET: usize = 4 * 1024 * 1024;
+
     /// Create a new [`Fonts`] for text layout.
     /// This call is expensive, so only create one [`Fonts`] and then reuse it.
     ///
@@ -472,6 +475,7 @@ impl Fonts {
 
         if needs_recreate {
             let definitions = fonts_and_cache.fonts.definitions.clone();
+            let budget = fonts_and_cache.galley_cache.budget;
 
             *fonts_and_cache = FontsAndCache {
                 fonts: FontsImpl::new(
@@ -480,7 +484,10 @@ impl Fonts {
                     text_alpha_from_coverage,
                     definitions,
                 ),
-                galley_cache: Default::default(),
+                galley_cache: GalleyCache {
+                    budget,
+                    ..Default::default()
+                },
             };
         }
 
@@ -579,6 +586,24 @@ impl Fonts {
         self.lock().galley_cache.num_galleys_in_cache()
     }
 
+    /// Statistics about the text layout cache.
+    pub fn galley_cache_stats(&self) -> GalleyCacheStats {
+        self.lock().galley_cache.stats()
+    }
+
+    /// How many bytes of laid out text to keep around for reuse in later frames.
+    ///
+    /// Text laid out in the current frame is always kept until the next frame.
+    /// On top of that, the most recently used text is kept
+    /// until the cache uses more than this much memory.
+    ///
+    /// Set to zero to only keep the text used in the previous frame.
+    ///
+    /// Default is [`Self::DEFAULT_GALLEY_CACHE_BUDGET`].
+    pub fn set_galley_cache_budget(&self, num_bytes: usize) {
+        self.lock().galley_cache.budget = num_bytes;
+    }
+
     /// How full is the font atlas?
     ///
     /// This increases as new fonts and/or glyphs are used,
@@ -756,13 +781,76 @@ struct CachedGalley {
     children: Option<Arc<[u64]>>,
 
     galley: Arc<Galley>,
+
+    /// Approximate size of [`Self::galley`].
+    num_bytes: usize,
+}
+
+/// Statistics about the text layout cache, see [`Fonts::galley_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GalleyCacheStats {
+    /// Number of laid out texts in the cache.
+    pub num_galleys: usize,
+
+    /// Approximate memory used by the cached galleys, in bytes.
+    pub num_bytes: usize,
+
+    /// See [`Fonts::set_galley_cache_budget`].
+    pub budget: usize,
+
+    /// How many layouts were found in the cache.
+    ///
+    /// This and the other counters start over when the [`Fonts`] are recreated,
+    /// e.g. when `pixels_per_point` changes.
+    pub hits: u64,
+
+    /// How many layouts were not found in the cache, and had to be computed.
+    pub misses: u64,
+
+    /// How many galleys were removed from the cache to stay within the budget.
+    pub evictions: u64,
+}
+
+impl GalleyCacheStats {
+    /// The fraction of layouts that were found in the cache, in `0..=1`.
+    pub fn hit_ratio(&self) -> f32 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f32 / total as f32
+        }
+    }
 }
 
-#[derive(Default)]
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    /// Sum of [`CachedGalley::num_bytes`].
+    num_bytes: usize,
+
+    /// See [`Fonts::set_galley_cache_budget`].
+    budget: usize,
+
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl Default for GalleyCache {
+    fn default() -> Self {
+        Self {
+            generation: 0,
+            cache: Default::default(),
+            num_bytes: 0,
+            budget: Fonts::DEFAULT_GALLEY_CACHE_BUDGET,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
 }
 
 impl GalleyCache {
@@ -802,6 +890,7 @@ impl GalleyCache {
         let galley = match self.cache.entry(hash) {
             std::collections::hash_map::Entry::Occupied(entry) => {
                 // The job was found in cache - no need to re-layout.
+                self.hits += 1;
                 let cached = entry.into_mut();
                 cached.last_used = self.generation;
 
@@ -822,6 +911,7 @@ impl GalleyCache {
                 galley
             }
             std::collections::hash_map::Entry::Vacant(entry) => {
+                self.misses += 1;
                 let job = Arc::new(job);
                 if allow_split_paragraphs && should_cache_each_paragraph_individually(&job) {
                     let (child_galleys, child_hashes) =
@@ -834,22 +924,28 @@ impl GalleyCache {
                     let galley =
                         Arc::new(Galley::concat(job, &child_galleys, fonts.pixels_per_point));
 
+                    let num_bytes = galley_num_bytes(&galley);
+                    self.num_bytes += num_bytes;
                     self.cache.insert(
                         hash,
                         CachedGalley {
                             last_used: self.generation,
                             children: Some(child_hashes.into()),
                             galley: galley.clone(),
+                            num_bytes,
                         },
                     );
                     galley
                 } else {
                     let galley = super::layout(fonts, job);
                     let galley = Arc::new(galley);
+                    let num_bytes = galley_num_bytes(&galley);
+                    self.num_bytes += num_bytes;
                     entry.insert(CachedGalley {
                         last_used: self.generation,
                         children: None,
                         galley: galley.clone(),
+                        num_bytes,
                     });
                     galley
                 }
@@ -976,16 +1072,71 @@ impl GalleyCache {
         self.cache.len()
     }
 
-    /// Must be called once per frame to clear the [`Galley`] cache.
+    pub fn stats(&self) -> GalleyCacheStats {
+        GalleyCacheStats {
+            num_galleys: self.cache.len(),
+            num_bytes: self.num_bytes,
+            budget: self.budget,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+
+    /// Must be called once per frame to evict old galleys from the cache.
+    ///
+    /// Galleys used this frame are always kept.
+    /// Older galleys are kept, most recently used first, as long as we stay within the budget.
     pub fn flush_cache(&mut self) {
         let current_generation = self.generation;
-        self.cache.retain(|_key, cached| {
-            cached.last_used == current_generation // only keep those that were used this frame
-        });
+
+        if self.budget < self.num_bytes {
+            let mut unused: Vec<(u32, u64)> = self
+                .cache
+                .iter()
+                .filter(|(_, cached)| cached.last_used != current_generation)
+                .map(|(hash, cached)| (current_generation.wrapping_sub(cached.last_used), *hash))
+                .collect();
+
+            // Oldest first:
+            unused.sort_unstable_by_key(|&(age, _)| std::cmp::Reverse(age));
+
+            for (_, hash) in unused {
+                if self.num_bytes <= self.budget {
+                    break;
+                }
+                if let Some(cached) = self.cache.remove(&hash) {
+                    self.num_bytes -= cached.num_bytes;
+                    self.evictions += 1;
+                }
+            }
+        }
+
         self.generation = self.generation.wrapping_add(1);
     }
 }
 
+/// Approximately how much memory does this [`Galley`] use?
+fn galley_num_bytes(galley: &Galley) -> usize {
+    let rows: usize = galley
+        .rows
+        .iter()
+        .map(|placed_row| {
+            let row = &placed_row.row;
+            size_of::<crate::text::PlacedRow>()
+                + size_of::<crate::text::Row>()
+                + row.glyphs.len() * size_of::<crate::text::Glyph>()
+                + row.visuals.mesh.vertices.len() * size_of::<crate::Vertex>()
+                + row.visuals.mesh.indices.len() * size_of::<u32>()
+        })
+        .sum();
+
+    size_of::<Galley>()
+        + galley.job.text.len()
+        + galley.job.sections.len() * size_of::<LayoutSection>()
+        + rows
+}
+
 /// If true, lay out and cache each paragraph (sections separated by newlines) individually.
 ///
 /// This makes it much faster to re-layout the full text when only a portion of it has changed since last frame, i.e. when editing somewhere in a file with thousands of lines/paragraphs.
@@ -1271,4 +1422,62 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_galley_cache_budget() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let job = |text: &str| {
+            LayoutJob::simple(
+                text.to_owned(),
+                FontId::default(),
+                Color32::WHITE,
+                f32::INFINITY,
+            )
+        };
+
+        let mut cache = GalleyCache {
+            budget: 0,
+            ..Default::default()
+        };
+        cache.layout(&mut fonts, job("first"), true);
+        cache.flush_cache();
+        cache.layout(&mut fonts, job("second"), true);
+        cache.flush_cache();
+        assert_eq!(
+            cache.stats().num_galleys,
+            1,
+            "Without a budget, only the last frame is kept"
+        );
+        assert_eq!(cache.stats().evictions, 1);
+
+        let mut cache = GalleyCache::default();
+        let first = cache.layout(&mut fonts, job("first"), true);
+        cache.flush_cache();
+        cache.layout(&mut fonts, job("second"), true);
+        cache.flush_cache();
+        assert!(
+            Arc::ptr_eq(&first, &cache.layout(&mut fonts, job("first"), true)),
+            "Unused galleys are reused in later frames"
+        );
+
+        let stats = cache.stats();
+        assert_eq!(stats.num_galleys, 2);
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 2, 0));
+        assert!(0 < stats.num_bytes && stats.num_bytes <= stats.budget);
+
+        // Shrinking the budget evicts the least recently used galley, but never one used this frame:
+        cache.budget = 1;
+        cache.flush_cache();
+        let stats = cache.stats();
+        assert_eq!((stats.num_galleys, stats.evictions), (1, 1));
+        assert!(Arc::ptr_eq(
+            &first,
+            &cache.layout(&mut fonts, job("first"), true)
+        ));
+    }
 }
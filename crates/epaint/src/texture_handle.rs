@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use crate::{
-    ImageData, ImageDelta, TextureId, TextureManager, emath::NumExt as _, mutex::RwLock,
-    textures::TextureOptions,
+    ColorImage, ImageData, ImageDelta, TextureId, TextureManager, emath::NumExt as _,
+    mutex::RwLock, textures::TextureOptions,
 };
 
 /// Used to paint images.
@@ -86,6 +86,25 @@ impl TextureHandle {
             .set(self.id, ImageDelta::partial(pos, image.into(), options));
     }
 
+    /// Copy a region of `source` to the same place in the texture.
+    ///
+    /// `source` should be the size of the whole texture, e.g. a buffer you draw into on the CPU.
+    /// Use this to stream changes to a large texture every frame,
+    /// by only uploading the parts that changed.
+    ///
+    /// The texture keeps its [`TextureOptions`].
+    pub fn set_region_from(&mut self, source: &ColorImage, pos: [usize; 2], size: [usize; 2]) {
+        self.set_partial(pos, source.region_by_pixels(pos, size), self.options());
+    }
+
+    /// The options the texture was last set with.
+    pub fn options(&self) -> TextureOptions {
+        self.tex_mngr
+            .read()
+            .meta(self.id)
+            .map_or_else(TextureOptions::default, |tex| tex.options)
+    }
+
     /// width x height
     pub fn size(&self) -> [usize; 2] {
         self.tex_mngr
@@ -54,6 +54,19 @@ impl TextureManager {
                         && pos[1] + delta.image.height() <= meta.size[1],
                     "Partial texture update is outside the bounds of texture {id:?}",
                 );
+                // Discard enqueued partial updates that this one overwrites,
+                // so streaming updates don't pile up if the painter falls behind:
+                let covers = |old: &ImageDelta| {
+                    old.pos.is_some_and(|old_pos| {
+                        pos[0] <= old_pos[0]
+                            && pos[1] <= old_pos[1]
+                            && old_pos[0] + old.image.width() <= pos[0] + delta.image.width()
+                            && old_pos[1] + old.image.height() <= pos[1] + delta.image.height()
+                    })
+                };
+                self.delta
+                    .set
+                    .retain(|(x, old)| x != &id || old.options != delta.options || !covers(old));
             } else {
                 // whole update
                 meta.size = delta.image.size();
@@ -61,6 +74,7 @@ impl TextureManager {
                 // since we update the whole image, we can discard all old enqueued deltas
                 self.delta.set.retain(|(x, _)| x != &id);
             }
+            meta.options = delta.options;
             self.delta.set.push((id, delta));
         } else {
             debug_assert!(false, "Tried setting texture {id:?} which is not allocated");
@@ -165,9 +179,14 @@ pub struct TextureOptions {
     /// Mipmaps ensures textures look smooth even when the texture is very small and pixels are much
     /// larger than individual texels.
     ///
+    /// The mipmaps are generated by the backend when the texture is set,
+    /// and regenerated for the updated region on partial updates.
+    /// Enable this for images that are shown much smaller than their size,
+    /// e.g. thumbnails of photos, to avoid aliasing.
+    ///
     /// # Notes
     ///
-    /// - This may not be available on all backends (currently only `egui_glow`).
+    /// - This is supported by `egui_glow` and `egui-wgpu`.
     pub mipmap_mode: Option<TextureFilter>,
 }
 
@@ -220,6 +239,10 @@ impl TextureOptions {
         mipmap_mode: None,
     };
 
+    /// Linear magnification, minification and mipmap filtering,
+    /// for images that are shown much smaller than their size.
+    pub const LINEAR_MIPMAPPED: Self = Self::LINEAR.with_mipmap_mode(Some(TextureFilter::Linear));
+
     pub const fn with_mipmap_mode(self, mipmap_mode: Option<TextureFilter>) -> Self {
         Self {
             mipmap_mode,
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use crate::{
+    Color32, ColorImage, Rect, RectShape, Shape, TextureHandle, TextureManager, Vec2,
+    emath::{Pos2, pos2},
+    mutex::RwLock,
+    textures::TextureOptions,
+};
+
+/// An image that is too large for a single texture, split into tiles.
+///
+/// Each tile is a texture of at most `max_texture_side` pixels to a side, managed by the
+/// [`TextureManager`] like any other texture, and freed when the [`TiledTexture`] is dropped.
+/// Each tile also contains the pixels next to it, so that linear filtering has no seams.
+///
+/// If you are using egui, use `egui::Context::load_tiled_texture`.
+pub struct TiledTexture {
+    size: [usize; 2],
+    options: TextureOptions,
+    tiles: Vec<Tile>,
+}
+
+struct Tile {
+    handle: TextureHandle,
+
+    /// The part of the image shown by this tile.
+    min: [usize; 2],
+    max: [usize; 2],
+
+    /// The part of the image in the texture of this tile,
+    /// which also includes the pixels next to it.
+    texture_min: [usize; 2],
+    texture_max: [usize; 2],
+}
+
+impl TiledTexture {
+    /// How many pixels of its neighbors each tile includes on each side.
+    const GUTTER: usize = 1;
+
+    /// Split `image` into tiles of at most `max_texture_side` pixels to a side, and allocate them.
+    pub fn new(
+        tex_mngr: &Arc<RwLock<TextureManager>>,
+        name: &str,
+        image: &ColorImage,
+        options: TextureOptions,
+        max_texture_side: usize,
+    ) -> Self {
+        let [width, height] = image.size;
+        let stride = max_texture_side.saturating_sub(2 * Self::GUTTER).max(1);
+
+        let mut tiles = vec![];
+        for y in (0..height.max(1)).step_by(stride) {
+            for x in (0..width.max(1)).step_by(stride) {
+                let min = [x, y];
+                let max = [(x + stride).min(width), (y + stride).min(height)];
+                let texture_min = [
+                    x.saturating_sub(Self::GUTTER),
+                    y.saturating_sub(Self::GUTTER),
+                ];
+                let texture_max = [
+                    (max[0] + Self::GUTTER).min(width),
+                    (max[1] + Self::GUTTER).min(height),
+                ];
+                let tile_image = image.region_by_pixels(
+                    texture_min,
+                    [
+                        texture_max[0] - texture_min[0],
+                        texture_max[1] - texture_min[1],
+                    ],
+                );
+                let id = tex_mngr.write().alloc(
+                    format!("{name} [{x}, {y}]"),
+                    tile_image.into(),
+                    options,
+                );
+                tiles.push(Tile {
+                    handle: TextureHandle::new(tex_mngr.clone(), id),
+                    min,
+                    max,
+                    texture_min,
+                    texture_max,
+                });
+            }
+        }
+
+        Self {
+            size: image.size,
+            options,
+            tiles,
+        }
+    }
+
+    /// width x height of the whole image.
+    pub fn size(&self) -> [usize; 2] {
+        self.size
+    }
+
+    /// width x height of the whole image.
+    pub fn size_vec2(&self) -> Vec2 {
+        Vec2::new(self.size[0] as f32, self.size[1] as f32)
+    }
+
+    /// The textures of the tiles.
+    pub fn tiles(&self) -> impl ExactSizeIterator<Item = &TextureHandle> {
+        self.tiles.iter().map(|tile| &tile.handle)
+    }
+
+    /// `width x height x bytes_per_pixel` of all the tiles.
+    pub fn byte_size(&self) -> usize {
+        self.tiles.iter().map(|tile| tile.handle.byte_size()).sum()
+    }
+
+    /// Update the part of the image at `pos` to `image`.
+    ///
+    /// Only the tiles that overlap this region are updated.
+    pub fn set_partial(&mut self, pos: [usize; 2], image: &ColorImage) {
+        let end = [pos[0] + image.width(), pos[1] + image.height()];
+        debug_assert!(
+            end[0] <= self.size[0] && end[1] <= self.size[1],
+            "Partial texture update is outside the bounds of the tiled texture"
+        );
+        for tile in &mut self.tiles {
+            let min = [
+                pos[0].max(tile.texture_min[0]),
+                pos[1].max(tile.texture_min[1]),
+            ];
+            let max = [
+                end[0].min(tile.texture_max[0]),
+                end[1].min(tile.texture_max[1]),
+            ];
+            if max[0] <= min[0] || max[1] <= min[1] {
+                continue;
+            }
+            let patch = image.region_by_pixels(
+                [min[0] - pos[0], min[1] - pos[1]],
+                [max[0] - min[0], max[1] - min[1]],
+            );
+            tile.handle.set_partial(
+                [min[0] - tile.texture_min[0], min[1] - tile.texture_min[1]],
+                patch,
+                self.options,
+            );
+        }
+    }
+
+    /// Paint the whole image in `rect`, with its colors multiplied by `tint`.
+    pub fn to_shape(&self, rect: Rect, tint: Color32) -> Shape {
+        let size = self.size_vec2().max(Vec2::splat(1.0));
+        let to_screen =
+            |[x, y]: [usize; 2]| rect.min + rect.size() * Vec2::new(x as f32, y as f32) / size;
+        Shape::Vec(
+            self.tiles
+                .iter()
+                .map(|tile| {
+                    let texture_size = Vec2::new(
+                        (tile.texture_max[0] - tile.texture_min[0]) as f32,
+                        (tile.texture_max[1] - tile.texture_min[1]) as f32,
+                    );
+                    let to_uv = |[x, y]: [usize; 2]| -> Pos2 {
+                        pos2(
+                            (x - tile.texture_min[0]) as f32 / texture_size.x,
+                            (y - tile.texture_min[1]) as f32 / texture_size.y,
+                        )
+                    };
+                    RectShape::filled(
+                        Rect::from_min_max(to_screen(tile.min), to_screen(tile.max)),
+                        0,
+                        tint,
+                    )
+                    .with_texture(
+                        tile.handle.id(),
+                        Rect::from_min_max(to_uv(tile.min), to_uv(tile.max)),
+                    )
+                    .into()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl std::fmt::Debug for TiledTexture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TiledTexture")
+            .field("size", &self.size)
+            .field("options", &self.options)
+            .field("tiles", &self.tiles.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiled_texture() {
+        let tex_mngr = Arc::new(RwLock::new(TextureManager::default()));
+        let image = ColorImage::new(
+            [10, 5],
+            (0..50).map(|i| Color32::from_gray(i as u8)).collect(),
+        );
+        let mut tiled = TiledTexture::new(&tex_mngr, "test", &image, TextureOptions::LINEAR, 6);
+        assert_eq!(
+            tiled.tiles().len(),
+            6,
+            "3x2 tiles of 4x4 pixels, plus gutters"
+        );
+        assert_eq!(tiled.tiles().next().unwrap().size(), [5, 5]);
+        assert_eq!(tiled.tiles().nth(1).unwrap().size(), [6, 5]);
+
+        let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+        let Shape::Vec(shapes) = tiled.to_shape(rect, Color32::WHITE) else {
+            panic!("Expected a Vec");
+        };
+        let bounds = shapes.iter().fold(Rect::NOTHING, |bounds, shape| {
+            bounds.union(shape.visual_bounding_rect())
+        });
+        assert_eq!(bounds, rect);
+        let Shape::Rect(first) = &shapes[0] else {
+            panic!("Expected a Rect");
+        };
+        assert_eq!(
+            first.rect,
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(40.0, 40.0))
+        );
+        let Some(crate::Brush::Texture { uv, .. }) = first.brush.as_deref() else {
+            panic!("Expected a texture");
+        };
+        assert_eq!(uv.max, pos2(0.8, 0.8));
+
+        // Updating the middle pixel updates the two tiles that contain it:
+        let _ = tex_mngr.write().take_delta();
+        tiled.set_partial([4, 2], &ColorImage::filled([1, 1], Color32::RED));
+        tiled.set_partial([4, 2], &ColorImage::filled([1, 1], Color32::BLUE));
+        let delta = tex_mngr.write().take_delta();
+        assert_eq!(delta.set.len(), 2, "The first update is overwritten");
+    }
+}
@@ -68,22 +68,23 @@ impl eframe::App for MyApp {
 
 impl MyApp {
     fn custom_painting(&mut self, ui: &mut egui::Ui) {
-        let (rect, response) =
-            ui.allocate_exact_size(egui::Vec2::splat(300.0), egui::Sense::drag());
-
-        self.angle += response.drag_motion().x * 0.01;
+        egui::View3D::new()
+            .desired_size(egui::Vec2::splat(300.0))
+            .show(ui, |input| {
+                for event in &input.camera_events {
+                    if let egui::CameraEvent::Orbit(delta) = event {
+                        self.angle += delta.x;
+                    }
+                }
 
-        // Clone locals so we can move them into the paint callback:
-        let angle = self.angle;
-        let rotating_triangle = self.rotating_triangle.clone();
+                // Clone locals so we can move them into the paint callback:
+                let angle = self.angle;
+                let rotating_triangle = self.rotating_triangle.clone();
 
-        let callback = egui::PaintCallback {
-            rect,
-            callback: std::sync::Arc::new(egui_glow::CallbackFn::new(move |_info, painter| {
-                rotating_triangle.lock().paint(painter.gl(), angle);
-            })),
-        };
-        ui.painter().add(callback);
+                Arc::new(egui_glow::CallbackFn::new(move |_info, painter| {
+                    rotating_triangle.lock().paint(painter.gl(), angle);
+                }))
+            });
     }
 }
 
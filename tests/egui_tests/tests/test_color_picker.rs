@@ -0,0 +1,78 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_color_picker_inline() {
+    let mut harness = Harness::new_ui_state(
+        |ui, color| {
+            ui.color_picker_inline(color);
+        },
+        egui::Color32::RED,
+    );
+    harness.run();
+
+    // The picker is always open, without having to click a button:
+    let green = harness.get_by_value("G 0").rect();
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(green.center()));
+    harness.run();
+    harness.input_mut().events.push(egui::Event::PointerButton {
+        pos: green.center(),
+        button: egui::PointerButton::Primary,
+        pressed: true,
+        modifiers: Modifiers::NONE,
+    });
+    harness.run();
+    for x in 1..=5 {
+        let pos = green.center() + Vec2::new(10.0 * x as f32, 0.0);
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.run();
+    }
+    assert_ne!(*harness.state(), egui::Color32::RED);
+    let recent = egui::color_picker::ColorPickerMemory::load(&harness.ctx).recent;
+    assert!(recent.is_empty(), "Only added once the user is done");
+
+    harness.input_mut().events.push(egui::Event::PointerButton {
+        pos: green.center() + Vec2::new(50.0, 0.0),
+        button: egui::PointerButton::Primary,
+        pressed: false,
+        modifiers: Modifiers::NONE,
+    });
+    harness.run();
+    let recent = egui::color_picker::ColorPickerMemory::load(&harness.ctx).recent;
+    assert_eq!(recent, vec![*harness.state()]);
+}
+
+#[test]
+fn test_color_picker_extended_controls() {
+    let mut harness = Harness::new_ui_state(
+        |ui, color| {
+            ui.color_picker_inline(color);
+        },
+        egui::Color32::RED,
+    );
+    harness.run();
+
+    // The hex field, color models and palette are opt-in:
+    assert!(harness.query_by_label("Hex:").is_none());
+    assert!(harness.query_by_label("Oklab").is_none());
+    assert!(harness.query_by_label("Saved:").is_none());
+
+    egui::color_picker::set_extended_controls(&harness.ctx, true);
+    harness.run();
+    harness.get_by_label("Hex:");
+    harness.get_by_label("Saved:");
+
+    harness.get_by_label("Oklab").click();
+    harness.run();
+    assert_eq!(
+        egui::color_picker::ColorPickerMemory::load(&harness.ctx).model,
+        egui::color_picker::ColorModel::Oklab
+    );
+}
@@ -0,0 +1,321 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_read_only_rejects_edits() {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, (checked, text): &mut (bool, String)| {
+            ui.read_only(|ui| {
+                ui.checkbox(checked, "Check");
+                ui.text_edit_singleline(text);
+            });
+        },
+        (false, String::from("Hello")),
+    );
+
+    harness.get_by_label("Check").click();
+    harness.run();
+    assert!(!harness.state().0, "Checkbox was toggled");
+
+    let text_input = egui::accesskit::Role::TextInput;
+    harness.get_by_role(text_input).click();
+    harness.run();
+    assert!(
+        harness.get_by_role(text_input).is_focused(),
+        "Text should still be selectable"
+    );
+    harness.get_by_role(text_input).type_text(" world");
+    harness.run();
+    assert_eq!(harness.state().1, "Hello");
+}
+
+#[test]
+fn test_accordion_opens_one_section_at_a_time() {
+    let mut harness = Harness::new_ui(|ui| {
+        egui::Accordion::new("settings")
+            .default_open(Some(0))
+            .show(ui, |accordion| {
+                for heading in ["General", "Appearance", "Advanced"] {
+                    accordion.section(heading, |ui| {
+                        ui.label(format!("{heading} settings"));
+                    });
+                }
+            });
+    });
+    harness.run();
+    let open_sections = |harness: &Harness<'_>| {
+        ["General", "Appearance", "Advanced"]
+            .into_iter()
+            .filter(|heading| {
+                harness
+                    .query_by_label(&format!("{heading} settings"))
+                    .is_some()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(open_sections(&harness), ["General"]);
+
+    harness.get_by_label("Advanced").click();
+    harness.run();
+    assert_eq!(open_sections(&harness), ["Advanced"]);
+
+    // Opening a section above the open one:
+    harness.get_by_label("Appearance").click();
+    harness.run();
+    assert_eq!(open_sections(&harness), ["Appearance"]);
+
+    // Clicking the open section closes it:
+    harness.get_by_label("Appearance").click();
+    harness.run();
+    assert!(open_sections(&harness).is_empty());
+}
+
+#[test]
+fn test_wizard_steps() {
+    #[derive(Default)]
+    struct State {
+        name: String,
+        finished: bool,
+    }
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = egui::Wizard::new("setup", ["Welcome", "Account", "Done"])
+                .animated(false)
+                .show(ui, |wizard| match wizard.step() {
+                    0 => {
+                        wizard.ui.label("Hello");
+                    }
+                    1 => {
+                        wizard.ui.text_edit_singleline(&mut state.name);
+                        if state.name.is_empty() {
+                            wizard.disable_next("Please enter a name");
+                        }
+                    }
+                    _ => {
+                        wizard.ui.label(format!("Bye {}", state.name));
+                    }
+                });
+            state.finished |= response.finished;
+        },
+        State::default(),
+    );
+    harness.run();
+    harness.get_by_label("Hello");
+
+    harness.get_by_label("Next").click();
+    harness.run();
+    harness.get_by_label("Please enter a name");
+
+    // Next is disabled until the step is valid:
+    harness.get_by_label("Next").click();
+    harness.run();
+    assert!(harness.query_by_label("Bye").is_none());
+
+    harness.state_mut().name = "Ada".to_owned();
+    harness.run();
+    harness.get_by_label("Next").click();
+    harness.run();
+    harness.get_by_label("Bye Ada");
+
+    // Jump back to a step that has been reached from the header:
+    harness.get_by_label("Welcome").click();
+    harness.run();
+    harness.get_by_label("Hello");
+    harness.get_by_label("Done").click();
+    harness.run();
+    harness.get_by_label("Bye Ada");
+
+    harness.get_by_label("Finish").click();
+    harness.run();
+    assert!(harness.state().finished);
+}
+
+#[test]
+fn test_expand_and_collapse_all() {
+    let mut harness = Harness::new_ui_state(
+        |ui, header_id: &mut Option<egui::Id>| {
+            if ui.button("Collapse everything").clicked() {
+                ui.ctx().set_all_collapsing_open(false);
+            }
+            ui.push_id("inspector", |ui| {
+                let expand = ui.button("Expand all").clicked();
+                ui.collapsing("Transform", |ui| ui.label("Position"));
+                let response = ui.collapsing("Material", |ui| {
+                    ui.collapsing("Texture", |ui| ui.label("Size"));
+                });
+                *header_id = Some(response.header_response.id);
+                if expand {
+                    ui.set_all_collapsing_open(true);
+                }
+            });
+            ui.collapsing("Elsewhere", |ui| ui.label("Unaffected"));
+        },
+        None,
+    );
+    harness.run();
+    let header_id = harness.state().unwrap();
+    assert_eq!(
+        egui::collapsing_header::CollapsingState::is_open_by_id(&harness.ctx, header_id),
+        Some(false)
+    );
+
+    harness.get_by_label("Expand all").click();
+    harness.run();
+    harness.get_by_label("Position");
+    harness.get_by_label("Size");
+    assert!(harness.query_by_label("Unaffected").is_none());
+    assert_eq!(
+        egui::collapsing_header::CollapsingState::is_open_by_id(&harness.ctx, header_id),
+        Some(true)
+    );
+
+    harness.get_by_label("Elsewhere").click();
+    harness.run();
+    harness.get_by_label("Collapse everything").click();
+    harness.run();
+    for label in ["Position", "Size", "Unaffected"] {
+        assert!(harness.query_by_label(label).is_none(), "{label} is shown");
+    }
+}
+
+#[test]
+fn test_tab_bar() {
+    struct Documents {
+        names: Vec<&'static str>,
+        active: usize,
+        closed: Option<&'static str>,
+    }
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 100.0))
+        .build_ui_state(
+            |ui, docs: &mut Documents| {
+                let response =
+                    egui::TabBar::new("tabs").show(ui, &mut docs.names, &mut docs.active, |name| {
+                        egui::Tab::new(*name).unsaved(*name == "Two")
+                    });
+                if let Some(index) = response.close_requested {
+                    docs.closed = Some(docs.names.remove(index));
+                }
+            },
+            Documents {
+                names: vec!["One", "Two", "Three"],
+                active: 0,
+                closed: None,
+            },
+        );
+    harness.run();
+
+    harness.get_by_label("Three").click();
+    harness.run();
+    assert_eq!(harness.state().active, 2);
+
+    harness.key_press_modifiers(Modifiers::CTRL, egui::Key::Tab);
+    harness.run();
+    assert_eq!(harness.state().active, 0);
+    harness.key_press_modifiers(Modifiers::CTRL | Modifiers::SHIFT, egui::Key::Tab);
+    harness.run();
+    assert_eq!(harness.state().active, 2);
+
+    // Drag "Three" to the front:
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    let start = harness.get_by_label("Three").rect().center();
+    let end = egui::pos2(2.0, start.y);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(start));
+    harness.step();
+    harness.input_mut().events.push(button(start, true));
+    harness.step();
+    for pos in [start - Vec2::new(20.0, 0.0), end] {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.step();
+    }
+    harness.input_mut().events.push(button(end, false));
+    harness.run();
+    assert_eq!(harness.state().names, ["Three", "One", "Two"]);
+    assert_eq!(harness.state().active, 0);
+
+    // Close the active tab:
+    harness
+        .get_all_by_label("Close tab")
+        .next()
+        .unwrap()
+        .click();
+    harness.run();
+    assert_eq!(harness.state().closed, Some("Three"));
+    assert_eq!(harness.state().names, ["One", "Two"]);
+}
+
+#[test]
+fn test_tab_bar_overflow() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 300.0))
+        .build_ui_state(
+            |ui, active: &mut usize| {
+                let mut tabs: Vec<String> = (0..10).map(|i| format!("Document {i}")).collect();
+                _ = egui::TabBar::new("tabs")
+                    .show(ui, &mut tabs, active, |name| egui::Tab::new(name.as_str()));
+            },
+            0,
+        );
+    harness.run();
+    harness.get_by_label("Document 0");
+    assert!(harness.query_by_label("Document 9").is_none());
+
+    harness.get_by_label("⏷").click();
+    harness.run();
+    harness.get_by_label("Document 9").click();
+    harness.run();
+    assert_eq!(*harness.state(), 9);
+    // The active tab is scrolled into view:
+    assert!(harness.query_by_label("Document 0").is_none());
+    harness.get_by_label("Document 9");
+}
+
+#[test]
+fn test_group_response() {
+    #[derive(Default)]
+    struct State {
+        enabled: bool,
+        clicked: usize,
+        changed: usize,
+    }
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = ui
+                .group_response(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut state.enabled, "Enabled");
+                        ui.label("Some description");
+                    });
+                })
+                .response;
+            state.clicked += usize::from(response.clicked());
+            state.changed += usize::from(response.changed());
+        },
+        State::default(),
+    );
+    harness.run();
+
+    harness.get_by_label("Enabled").click();
+    harness.run();
+    assert!(harness.state().enabled);
+    assert_eq!(harness.state().clicked, 1);
+    assert_eq!(harness.state().changed, 1);
+
+    // Clicking the non-interactive part of the group:
+    harness.get_by_label("Some description").click();
+    harness.run();
+    assert_eq!(harness.state().clicked, 2);
+    assert_eq!(harness.state().changed, 1);
+}
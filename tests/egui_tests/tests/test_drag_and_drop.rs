@@ -0,0 +1,96 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+
+#[test]
+fn test_drop_target_accepted_types() {
+    use egui::{CursorIcon, DragAndDrop, DropTarget, Event, PointerButton, Sense, pos2};
+
+    #[derive(Default)]
+    struct State {
+        drag_text: bool,
+        hovered: bool,
+        dropped: Option<usize>,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 100.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                ui.horizontal(|ui| {
+                    let source = ui.allocate_response(Vec2::new(50.0, 50.0), Sense::drag());
+                    if state.drag_text {
+                        source.dnd_set_drag_payload("text".to_owned());
+                    } else {
+                        source.dnd_set_drag_payload(42_usize);
+                    }
+                    ui.add_space(50.0);
+                    let target =
+                        DropTarget::new()
+                            .accept::<usize>()
+                            .accept::<u32>()
+                            .show(ui, |ui| {
+                                ui.allocate_space(Vec2::new(100.0, 50.0));
+                            });
+                    state.hovered = target.hovered;
+                    if let Some(dropped) = target.dropped::<usize>() {
+                        state.dropped = Some(*dropped);
+                    }
+                });
+            },
+            State::default(),
+        );
+    harness.run();
+
+    let drag_to_target = |harness: &mut Harness<'_, State>| {
+        let start = pos2(20.0, 20.0);
+        harness.input_mut().events.push(Event::PointerMoved(start));
+        harness.step();
+        harness.input_mut().events.push(Event::PointerButton {
+            pos: start,
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        });
+        harness.step();
+        for x in [60.0, 160.0] {
+            harness
+                .input_mut()
+                .events
+                .push(Event::PointerMoved(pos2(x, 20.0)));
+            harness.step();
+        }
+    };
+    let release = |harness: &mut Harness<'_, State>| {
+        harness.input_mut().events.push(Event::PointerButton {
+            pos: pos2(160.0, 20.0),
+            button: PointerButton::Primary,
+            pressed: false,
+            modifiers: Modifiers::NONE,
+        });
+        harness.step();
+        harness.step();
+    };
+
+    // A payload of another type is not allowed:
+    harness.state_mut().drag_text = true;
+    drag_to_target(&mut harness);
+    assert!(!harness.state().hovered);
+    assert_eq!(
+        harness.output().platform_output.cursor_icon,
+        CursorIcon::NotAllowed
+    );
+    release(&mut harness);
+    assert_eq!(harness.state().dropped, None);
+    assert!(!DragAndDrop::has_any_payload(&harness.ctx));
+
+    // An accepted payload is highlighted and dropped:
+    harness.state_mut().drag_text = false;
+    drag_to_target(&mut harness);
+    assert!(harness.state().hovered);
+    assert_ne!(
+        harness.output().platform_output.cursor_icon,
+        CursorIcon::NotAllowed
+    );
+    release(&mut harness);
+    assert_eq!(harness.state().dropped, Some(42));
+}
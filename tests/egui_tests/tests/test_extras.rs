@@ -0,0 +1,91 @@
+use egui::Vec2;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_table_footer_stays_visible() {
+    use egui_extras::{Column, TableBuilder};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, (footer_rect, body_x): &mut (egui::Rect, f32)| {
+                TableBuilder::new(ui)
+                    .columns(Column::auto(), 2)
+                    .footer(20.0, |mut footer| {
+                        footer.col(|ui| {
+                            ui.label("Total");
+                        });
+                        *footer_rect = footer
+                            .col(|ui| {
+                                ui.label("1225");
+                            })
+                            .0;
+                    })
+                    .body(|body| {
+                        body.rows(20.0, 50, |mut row| {
+                            let index = row.index();
+                            row.col(|ui| {
+                                ui.label(format!("Item {index}"));
+                            });
+                            *body_x = row
+                                .col(|ui| {
+                                    ui.label(index.to_string());
+                                })
+                                .0
+                                .left();
+                        });
+                    });
+            },
+            (egui::Rect::NOTHING, 0.0),
+        );
+    harness.run();
+
+    let (footer_rect, body_x) = *harness.state();
+    harness.get_by_label("Total");
+    assert!(footer_rect.bottom() <= 200.0, "{footer_rect:?}");
+    assert_eq!(footer_rect.left(), body_x);
+}
+
+#[test]
+fn test_about_window() {
+    let info = egui_extras::AppInfo {
+        name: "Sketchpad".to_owned(),
+        version: "1.2.3".to_owned(),
+        renderer: Some("wgpu (Vulkan)".to_owned()),
+        credits: vec![egui_extras::Credit::new("egui", "MIT OR Apache-2.0")],
+        ..Default::default()
+    };
+    let mut harness = Harness::new_state(
+        |ctx, open: &mut bool| {
+            egui_extras::AboutWindow::new(&info).show(ctx, open);
+        },
+        true,
+    );
+    harness.run();
+    harness.get_by_label("About Sketchpad");
+    harness.get_by_label("Renderer: wgpu (Vulkan)");
+
+    harness.get_by_label("📋 Copy version info").click();
+    harness.run_steps(1);
+    let copied =
+        harness
+            .output()
+            .platform_output
+            .commands
+            .iter()
+            .find_map(|command| match command {
+                egui::OutputCommand::CopyText(text) => Some(text.clone()),
+                _ => None,
+            });
+    assert_eq!(
+        copied.as_deref(),
+        Some(
+            format!(
+                "Sketchpad 1.2.3\negui {}\nRenderer: wgpu (Vulkan)",
+                env!("CARGO_PKG_VERSION")
+            )
+            .as_str()
+        )
+    );
+}
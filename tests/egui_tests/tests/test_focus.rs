@@ -0,0 +1,185 @@
+use egui::{Modifiers, ScrollArea, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_focus_scope_and_tab_index() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, scope_id: &mut Option<egui::Id>| {
+                _ = ui.button("Outside");
+                let scope = ui.focus_scope(|ui| {
+                    _ = ui.button("A");
+                    _ = ui.button("B").tab_index(-1);
+                    _ = ui.button("C");
+                    _ = ui.button("D").tab_index(1);
+                });
+                *scope_id = Some(scope.response.id);
+
+                let response = ui.button("Menu");
+                egui::Popup::menu(&response).show(|ui| {
+                    _ = ui.button("Item");
+                });
+            },
+            None,
+        );
+    harness.run();
+
+    // Moving the focus into the scope starts at the lowest positive tab index:
+    let scope = harness.state().expect("shown");
+    harness.ctx.focus_next_in(scope);
+    harness.run();
+    assert!(harness.get_by_label("D").is_focused());
+
+    // Tab cycles through the scope, skipping the negative tab index:
+    for expected in ["A", "C", "D", "A"] {
+        harness.key_press(egui::Key::Tab);
+        harness.run();
+        assert!(harness.get_by_label(expected).is_focused(), "{expected}");
+    }
+    harness.key_press_modifiers(Modifiers::SHIFT, egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("D").is_focused());
+
+    // Closing a popup gives the focus back to where it was:
+    harness.get_by_label("Menu").focus();
+    harness.run();
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    harness.get_by_label("Item").focus();
+    harness.run();
+    assert!(harness.get_by_label("Item").is_focused());
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Item").is_none());
+    assert!(harness.get_by_label("Menu").is_focused());
+}
+
+#[test]
+fn test_keyboard_navigation() {
+    #[derive(Default)]
+    struct State {
+        choice: usize,
+        volume: f32,
+        scroll_offset: f32,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 400.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                for (i, name) in ["A", "B", "C"].into_iter().enumerate() {
+                    ui.radio_value(&mut state.choice, i, name);
+                }
+                ui.add(
+                    egui::Slider::new(&mut state.volume, 0.0..=100.0)
+                        .text("Volume")
+                        .show_value(false),
+                );
+                state.scroll_offset = ScrollArea::vertical()
+                    .max_height(100.0)
+                    .show(ui, |ui| {
+                        for i in 0..50 {
+                            ui.label(format!("Line {i}"));
+                        }
+                    })
+                    .state
+                    .offset
+                    .y;
+            },
+            State::default(),
+        );
+    harness.run();
+
+    // The radio group is a single tab stop, and the arrow keys move the selection:
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("A").is_focused());
+    harness.key_press(egui::Key::ArrowDown);
+    harness.run();
+    assert!(harness.get_by_label("B").is_focused());
+    assert_eq!(harness.state().choice, 1);
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("Volume").is_focused());
+
+    // Home/End/PageUp/PageDown on a slider:
+    harness.key_press(egui::Key::End);
+    harness.run();
+    assert_eq!(harness.state().volume, 100.0);
+    harness.key_press(egui::Key::PageDown);
+    harness.run();
+    assert_eq!(harness.state().volume, 90.0);
+    harness.key_press(egui::Key::Home);
+    harness.run();
+    assert_eq!(harness.state().volume, 0.0);
+
+    // The slider isn't in the scroll area, so the scroll area doesn't react to them:
+    assert_eq!(harness.state().scroll_offset, 0.0);
+
+    // …until the focus is gone, and the scroll area is hovered:
+    harness.key_press(egui::Key::Escape);
+    harness.get_by_label("Line 0").hover();
+    harness.run();
+    harness.key_press(egui::Key::PageDown);
+    harness.run();
+    let page = harness.state().scroll_offset;
+    assert!(page > 50.0, "{page}");
+    harness.key_press(egui::Key::End);
+    harness.run();
+    assert!(harness.state().scroll_offset > page);
+    harness.key_press(egui::Key::Home);
+    harness.run();
+    assert_eq!(harness.state().scroll_offset, 0.0);
+}
+
+#[test]
+fn test_gamepad_navigation() {
+    use egui::{Event, GamepadNav};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, clicks: &mut Vec<&'static str>| {
+                if ui.button("A").clicked() {
+                    clicks.push("A");
+                }
+                _ = ui.button("Skipped").gamepad_navigable(false);
+                let response = ui.button("Menu");
+                egui::Popup::menu(&response).show(|ui| {
+                    _ = ui.button("Item");
+                });
+            },
+            Vec::new(),
+        );
+    harness.run();
+
+    let nav = |harness: &mut Harness<'_, Vec<&'static str>>, nav| {
+        harness.input_mut().events.push(Event::GamepadNav(nav));
+        harness.run();
+    };
+
+    // With nothing focused, the first widget gets the focus:
+    nav(&mut harness, GamepadNav::Down);
+    assert!(harness.get_by_label("A").is_focused());
+    assert!(harness.ctx.memory(|mem| mem.is_navigating_with_gamepad()));
+
+    nav(&mut harness, GamepadNav::Activate);
+    assert_eq!(harness.state(), &["A"]);
+
+    // Skips the widget that opted out:
+    nav(&mut harness, GamepadNav::Down);
+    assert!(harness.get_by_label("Menu").is_focused());
+
+    // Back works like escape:
+    nav(&mut harness, GamepadNav::Activate);
+    assert!(harness.query_by_label("Item").is_some());
+    nav(&mut harness, GamepadNav::Back);
+    assert!(harness.query_by_label("Item").is_none());
+
+    // Using the pointer ends the gamepad navigation:
+    harness.get_by_label("A").hover();
+    harness.run();
+    assert!(!harness.ctx.memory(|mem| mem.is_navigating_with_gamepad()));
+}
@@ -0,0 +1,308 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_custom_cursor_on_hover() {
+    let mut harness = Harness::new_ui(|ui| {
+        let image = egui::ColorImage::filled([8, 8], egui::Color32::RED);
+        let cursor = ui.ctx().register_cursor("red", image, [4, 4]);
+        ui.button("Custom").on_hover_cursor(cursor);
+        ui.button("Icon")
+            .on_hover_cursor(egui::CursorIcon::PointingHand);
+    });
+
+    harness.get_by_label("Custom").hover();
+    harness.run();
+    assert_eq!(
+        harness.output().platform_output.cursor_icon,
+        egui::CursorIcon::None,
+        "egui paints custom cursors itself, so the platform cursor should be hidden"
+    );
+
+    harness.get_by_label("Icon").hover();
+    harness.run();
+    assert_eq!(
+        harness.output().platform_output.cursor_icon,
+        egui::CursorIcon::PointingHand
+    );
+}
+
+#[test]
+fn test_widget_interaction_callback() {
+    let interactions = std::sync::Arc::new(egui::mutex::Mutex::new(Vec::new()));
+    let mut harness = Harness::new_ui(|ui| {
+        let _ = ui.button("Save");
+        ui.label("Not interactive");
+    });
+    let sink = interactions.clone();
+    harness.ctx.on_widget_interaction(
+        "test",
+        std::sync::Arc::new(move |interaction| sink.lock().push(interaction.clone())),
+    );
+    harness.run();
+    assert!(interactions.lock().is_empty());
+
+    harness.get_by_label("Save").click();
+    harness.run();
+    let interactions = interactions.lock();
+    assert_eq!(interactions.len(), 1);
+    let egui::output::OutputEvent::Clicked(info) = &interactions[0].event else {
+        panic!("Expected a click, got {:?}", interactions[0].event);
+    };
+    assert_eq!(info.typ, egui::WidgetType::Button);
+    assert_eq!(info.label.as_deref(), Some("Save"));
+}
+
+#[test]
+fn test_gestures() {
+    use egui::{Event, Gesture, Pos2, TouchDeviceId, TouchId, TouchPhase, pos2};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, gestures: &mut Vec<Gesture>| {
+                let response = ui.allocate_response(Vec2::splat(200.0), egui::Sense::click());
+                gestures.extend(response.gesture());
+            },
+            Vec::new(),
+        );
+    harness.run();
+
+    let touch =
+        |harness: &mut Harness<'_, Vec<Gesture>>, time: f64, touches: &[(TouchPhase, Pos2)]| {
+            for &(phase, pos) in touches {
+                harness.input_mut().events.push(Event::Touch {
+                    device_id: TouchDeviceId(0),
+                    id: TouchId(0),
+                    phase,
+                    pos,
+                    force: None,
+                });
+            }
+            harness.input_mut().time = Some(time);
+            harness.step();
+        };
+    let take = |harness: &mut Harness<'_, Vec<Gesture>>| std::mem::take(harness.state_mut());
+
+    // A tap, followed by a double tap:
+    let pos = pos2(50.0, 50.0);
+    touch(&mut harness, 1.0, &[(TouchPhase::Start, pos)]);
+    touch(&mut harness, 1.1, &[(TouchPhase::End, pos)]);
+    touch(&mut harness, 1.2, &[(TouchPhase::Start, pos)]);
+    touch(&mut harness, 1.3, &[(TouchPhase::End, pos)]);
+    assert_eq!(
+        take(&mut harness),
+        [Gesture::Tap { pos }, Gesture::DoubleTap { pos }]
+    );
+
+    // A long press, which isn't a tap when letting go:
+    touch(&mut harness, 3.0, &[(TouchPhase::Start, pos)]);
+    touch(&mut harness, 3.6, &[]);
+    touch(&mut harness, 3.7, &[(TouchPhase::End, pos)]);
+    assert_eq!(take(&mut harness), [Gesture::LongPress { pos }]);
+
+    // A swipe to the right:
+    let start = pos2(20.0, 100.0);
+    touch(&mut harness, 5.0, &[(TouchPhase::Start, start)]);
+    touch(&mut harness, 5.02, &[(TouchPhase::Move, pos2(60.0, 100.0))]);
+    touch(
+        &mut harness,
+        5.04,
+        &[(TouchPhase::Move, pos2(100.0, 100.0))],
+    );
+    touch(&mut harness, 5.05, &[(TouchPhase::End, pos2(120.0, 100.0))]);
+    let gestures = take(&mut harness);
+    assert!(
+        matches!(gestures[..], [Gesture::Swipe { start: s, velocity }] if s == start && 1000.0 < velocity.x),
+        "{gestures:?}"
+    );
+
+    // Moving slowly is no swipe:
+    touch(&mut harness, 7.0, &[(TouchPhase::Start, start)]);
+    touch(&mut harness, 7.5, &[(TouchPhase::Move, pos2(60.0, 100.0))]);
+    touch(&mut harness, 8.0, &[(TouchPhase::End, pos2(60.0, 100.0))]);
+    assert_eq!(take(&mut harness), []);
+
+    // Outside of the widget, so only in the input:
+    let outside = pos2(250.0, 250.0);
+    touch(&mut harness, 10.0, &[(TouchPhase::Start, outside)]);
+    touch(&mut harness, 10.1, &[(TouchPhase::End, outside)]);
+    assert_eq!(take(&mut harness), []);
+    assert_eq!(
+        harness.ctx.input(|i| i.gestures().to_vec()),
+        [Gesture::Tap { pos: outside }]
+    );
+}
+
+#[test]
+fn test_pointer_capture() {
+    use egui::{Event, PointerButton, Pos2, Response, RoutedPointerEvent, Sense, pos2};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 100.0))
+        .build_ui_state(
+            |ui, responses: &mut Vec<Response>| {
+                ui.horizontal(|ui| {
+                    let timeline = ui.allocate_response(Vec2::new(100.0, 50.0), Sense::click());
+                    if timeline.is_pointer_button_down_on() {
+                        timeline.capture_pointer();
+                    }
+                    let other = ui.allocate_response(Vec2::new(100.0, 50.0), Sense::click());
+                    *responses = vec![timeline, other];
+                });
+            },
+            Vec::new(),
+        );
+    harness.run();
+    let timeline_id = harness.state()[0].id;
+
+    let button = |harness: &mut Harness<'_, Vec<Response>>, pos: Pos2, pressed: bool| {
+        harness.input_mut().events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+        harness.step();
+    };
+    let move_to = |harness: &mut Harness<'_, Vec<Response>>, pos: Pos2| {
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+    };
+
+    let start = pos2(50.0, 20.0);
+    move_to(&mut harness, start);
+    button(&mut harness, start, true);
+    assert!(harness.state()[0].has_pointer_capture());
+
+    // Moving onto the other widget keeps sending the pointer to the captured one:
+    let outside = pos2(160.0, 20.0);
+    move_to(&mut harness, outside);
+    move_to(&mut harness, outside);
+    let [timeline, other] = &harness.state()[..] else {
+        panic!()
+    };
+    assert!(timeline.hovered() && timeline.contains_pointer());
+    assert!(!other.hovered());
+    assert!(
+        timeline
+            .interact_pointer_pos()
+            .is_some_and(|pos| 150.0 < pos.x)
+    );
+    harness.ctx.interaction_snapshot(|i| {
+        assert_eq!(
+            i.routed_pointer_events,
+            [RoutedPointerEvent::Moved {
+                pos: outside,
+                widget: Some(timeline_id)
+            }]
+        );
+    });
+
+    // Letting go releases the capture:
+    button(&mut harness, outside, false);
+    harness.ctx.interaction_snapshot(|i| {
+        assert_eq!(
+            i.routed_pointer_events,
+            [RoutedPointerEvent::Released {
+                button: PointerButton::Primary,
+                widget: Some(timeline_id)
+            }]
+        );
+    });
+    harness.step();
+    assert_eq!(harness.ctx.pointer_capture_id(), None);
+    assert!(harness.state()[1].hovered());
+}
+
+#[test]
+fn test_pointer_capture_needs_a_button_down() {
+    use egui::{Pos2, Response, Sense, pos2};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 100.0))
+        .build_ui_state(
+            |ui, responses: &mut Vec<Response>| {
+                ui.horizontal(|ui| {
+                    let timeline = ui.allocate_response(Vec2::new(100.0, 50.0), Sense::click());
+                    if timeline.hovered() {
+                        timeline.capture_pointer(); // Without any button down
+                    }
+                    let other = ui.allocate_response(Vec2::new(100.0, 50.0), Sense::click());
+                    *responses = vec![timeline, other];
+                });
+            },
+            Vec::new(),
+        );
+    let move_to = |harness: &mut Harness<'_, Vec<Response>>, pos: Pos2| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.run();
+    };
+
+    move_to(&mut harness, pos2(50.0, 20.0));
+    assert!(harness.state()[0].hovered());
+    assert_eq!(harness.ctx.pointer_capture_id(), None);
+
+    move_to(&mut harness, pos2(160.0, 20.0));
+    assert!(!harness.state()[0].hovered());
+    assert!(harness.state()[1].hovered());
+}
+
+#[test]
+fn test_multi_click_count() {
+    use egui::{Event, PointerButton, Pos2, Sense, pos2};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, counts: &mut Vec<u32>| {
+                let response = ui.allocate_response(Vec2::splat(100.0), Sense::click());
+                counts.extend(response.click_count());
+                if response.clicked_n(4) {
+                    counts.push(u32::MAX);
+                }
+            },
+            Vec::new(),
+        );
+    harness.run();
+
+    let click = |harness: &mut Harness<'_, Vec<u32>>, time: f64, pos: Pos2| {
+        harness.input_mut().time = Some(time);
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        for pressed in [true, false] {
+            harness.input_mut().events.push(Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed,
+                modifiers: Modifiers::NONE,
+            });
+        }
+        harness.step();
+    };
+
+    // Clicks close together in time and space keep counting:
+    let pos = pos2(50.0, 50.0);
+    for i in 0..4 {
+        click(&mut harness, 1.0 + 0.1 * i as f64, pos);
+    }
+    assert_eq!(*harness.state(), [1, 2, 3, 4, u32::MAX]);
+    harness.state_mut().clear();
+
+    // Too far from the previous click, or too late after it, starts over:
+    click(&mut harness, 1.5, pos2(80.0, 50.0));
+    click(&mut harness, 3.0, pos2(80.0, 50.0));
+    assert_eq!(*harness.state(), [1, 1]);
+    harness.state_mut().clear();
+
+    // Unless the thresholds allow it:
+    harness.ctx.options_mut(|o| {
+        o.input_options.max_double_click_delay = 1.0;
+        o.input_options.max_double_click_dist = 50.0;
+    });
+    click(&mut harness, 3.8, pos2(50.0, 50.0));
+    assert_eq!(*harness.state(), [2]);
+}
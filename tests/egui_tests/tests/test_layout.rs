@@ -0,0 +1,305 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_split_collapse_on_double_click() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .build_ui_state(
+            |ui, left_width: &mut f32| {
+                ui.split_horizontal(
+                    0.25,
+                    |left| {
+                        *left_width = left.max_rect().width();
+                        left.label("Left");
+                    },
+                    |right| {
+                        right.label("Right");
+                    },
+                );
+            },
+            0.0,
+        );
+    let initial_width = *harness.state();
+    assert!(
+        (initial_width - 100.0).abs() < 10.0,
+        "The left pane should get a quarter of the width, got {initial_width}"
+    );
+
+    // Double-click just left of the right pane, where the divider is:
+    let double_click = |harness: &mut Harness<'_, f32>| {
+        let divider = harness.get_by_label("Right").rect().min - Vec2::new(4.0, 0.0);
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(divider));
+        harness.step();
+        for _ in 0..2 {
+            for pressed in [true, false] {
+                harness.input_mut().events.push(egui::Event::PointerButton {
+                    pos: divider,
+                    button: egui::PointerButton::Primary,
+                    pressed,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            harness.step();
+        }
+        harness.run();
+    };
+
+    double_click(&mut harness);
+    assert!(
+        *harness.state() < 1.0,
+        "Double-clicking the divider should collapse the left pane"
+    );
+
+    double_click(&mut harness);
+    assert_eq!(
+        *harness.state(),
+        initial_width,
+        "Double-clicking the divider again should restore the left pane"
+    );
+}
+
+#[test]
+fn test_track_grid_sizes_and_spans() {
+    use egui::{GridCell, Rect, Track, TrackGrid};
+
+    struct State {
+        available_width: f32,
+        col_widths: Vec<f32>,
+        span_rect: Rect,
+        auto_rect: Rect,
+        centered_rect: Rect,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(500.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.available_width = ui.available_width();
+                TrackGrid::new("grid")
+                    .column(Track::Fixed(100.0))
+                    .named_column("narrow", Track::Weight(1.0))
+                    .named_column("wide", Track::Weight(3.0))
+                    .column(Track::Auto)
+                    .rows([Track::Auto, Track::Fixed(80.0)])
+                    .spacing([0.0, 0.0])
+                    .show(ui, |grid| {
+                        state.col_widths = grid.col_widths().to_vec();
+                        state.span_rect = grid.cell(GridCell::new(0, 0).span(3, 1), |ui| {
+                            ui.label("Spanning");
+                            ui.max_rect()
+                        });
+                        state.auto_rect =
+                            grid.cell(GridCell::new(3, 0), |ui| ui.label("Auto-sized column").rect);
+                        state.centered_rect = grid.cell(
+                            GridCell::new("narrow", 1).align(egui::Align2::CENTER_CENTER),
+                            |ui| ui.label("Centered").rect,
+                        );
+                    });
+            },
+            State {
+                available_width: 0.0,
+                col_widths: vec![],
+                span_rect: Rect::NOTHING,
+                auto_rect: Rect::NOTHING,
+                centered_rect: Rect::NOTHING,
+            },
+        );
+    harness.run();
+
+    let state = harness.state();
+    let auto_width = state.col_widths[3];
+    assert!(
+        (auto_width - state.auto_rect.width()).abs() < 1.0,
+        "The auto column should fit its contents"
+    );
+    let remaining = state.available_width - 100.0 - auto_width;
+    let expected = [100.0, 0.25 * remaining, 0.75 * remaining];
+    for (width, expected) in state.col_widths.iter().zip(expected) {
+        assert!((width - expected).abs() < 0.5, "{width} != {expected}");
+    }
+    assert!((state.span_rect.width() - expected.iter().sum::<f32>()).abs() < 1.0);
+
+    let narrow_center_x = state.span_rect.left() + 100.0 + 0.5 * expected[1];
+    assert!(
+        (state.centered_rect.center().x - narrow_center_x).abs() < 1.0,
+        "The cell should be centered in its column"
+    );
+    assert!(
+        (state.centered_rect.center().y - (state.span_rect.bottom() + 40.0)).abs() < 1.0,
+        "The cell should be centered in its row"
+    );
+}
+
+#[test]
+fn test_masonry_balances_columns() {
+    let heights = [100.0, 20.0, 20.0, 50.0, 30.0];
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_ui_state(
+            |ui, rects: &mut Vec<egui::Rect>| {
+                rects.clear();
+                egui::Masonry::new("masonry")
+                    .columns(2)
+                    .spacing([10.0, 10.0])
+                    .show(ui, |masonry| {
+                        for height in heights {
+                            let rect = masonry.card(|ui| {
+                                ui.allocate_space(Vec2::new(ui.available_width(), height));
+                                ui.min_rect()
+                            });
+                            rects.push(rect);
+                        }
+                    });
+            },
+            vec![],
+        );
+    harness.run();
+
+    let rects = harness.state();
+    let left = rects[0].left();
+    let is_left = |rect: &egui::Rect| rect.left() == left;
+    let columns: Vec<bool> = rects.iter().map(is_left).collect();
+    // Each card goes into the shortest column:
+    assert_eq!(columns, [true, false, false, false, true]);
+    assert_eq!(rects[1].width(), rects[0].width());
+    assert_eq!(rects[2].top(), rects[1].bottom() + 10.0);
+}
+
+#[test]
+fn test_animated_layout() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, names: &mut Vec<&'static str>| {
+                egui::AnimatedLayout::new("names").show(ui, |layout| {
+                    for name in names.iter() {
+                        layout.item(name, |ui| {
+                            ui.label(*name);
+                        });
+                    }
+                });
+            },
+            vec!["B", "A"],
+        );
+    harness.run();
+    assert!(harness.get_by_label("B").rect().min.y < harness.get_by_label("A").rect().min.y);
+
+    harness.state_mut().sort();
+    harness.step();
+    // The items are laid out at their new place right away, but are painted moving there:
+    assert!(harness.get_by_label("A").rect().min.y < harness.get_by_label("B").rect().min.y);
+    assert!(harness.ctx.has_requested_repaint());
+
+    harness.run();
+    assert!(!harness.ctx.has_requested_repaint());
+}
+
+#[test]
+fn test_layout_transition() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, expanded: &mut bool| {
+                ui.scope_builder(
+                    egui::UiBuilder::new().layout_transition(egui::Tween::new(0.5)),
+                    |ui| {
+                        ui.label("Top");
+                        if *expanded {
+                            ui.add_space(100.0);
+                        }
+                    },
+                );
+                ui.label("After");
+            },
+            false,
+        );
+    harness.run();
+    let start = harness.get_by_label("After").rect().min.y;
+
+    *harness.state_mut() = true;
+    harness.step();
+    harness.step();
+    // What comes after moves smoothly:
+    let during = harness.get_by_label("After").rect().min.y;
+    assert!(start < during && during < start + 100.0, "{start} {during}");
+
+    harness.run();
+    let end = harness.get_by_label("After").rect().min.y;
+    assert!(start + 100.0 <= end, "{start} {end}");
+}
+
+#[test]
+fn test_show_animated_if() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, shown: &mut bool| {
+                ui.show_animated_if(*shown, egui::Transition::FadeSlide, |ui| {
+                    ui.label("Row");
+                });
+            },
+            true,
+        );
+    harness
+        .ctx
+        .all_styles_mut(|style| style.animation_time = 0.5);
+    harness.run();
+    assert!(harness.query_by_label("Row").is_some());
+
+    *harness.state_mut() = false;
+    harness.step();
+    assert!(
+        harness.query_by_label("Row").is_some(),
+        "Still shown while animating out"
+    );
+    assert!(harness.ctx.has_requested_repaint());
+
+    harness.run();
+    assert!(harness.query_by_label("Row").is_none());
+}
+
+#[test]
+fn test_right_to_left_layout_is_mirrored() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .build(|ctx| {
+            ctx.style_mut(|style| style.layout_direction = egui::LayoutDirection::Rtl);
+            egui::SidePanel::left("sidebar")
+                .resizable(false)
+                .exact_width(100.0)
+                .show(ctx, |ui| {
+                    ui.label("Sidebar");
+                });
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label("Title");
+                ui.horizontal(|ui| {
+                    ui.label("First");
+                    ui.label("Second");
+                });
+            });
+        });
+    harness.run();
+
+    let sidebar = harness.get_by_label("Sidebar").rect();
+    let title = harness.get_by_label("Title").rect();
+    let first = harness.get_by_label("First").rect();
+    let second = harness.get_by_label("Second").rect();
+
+    assert!(
+        sidebar.min.x > 250.0,
+        "A left panel should be on the right in RTL"
+    );
+    assert!(
+        title.max.x > 250.0 && title.max.x < sidebar.min.x,
+        "Text should be aligned to the right edge of the central panel"
+    );
+    assert!(
+        second.max.x < first.min.x,
+        "Horizontal layouts should run from right to left"
+    );
+}
@@ -0,0 +1,125 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_pie_menu() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_ui_state(
+            |ui, picked: &mut Vec<usize>| {
+                let response = ui.add(egui::Button::new("Canvas").min_size(Vec2::splat(300.0)));
+                if let Some(i) = egui::PieMenu::new("tools")
+                    .item("A", "Pen")
+                    .item("B", "Rectangle")
+                    .item("C", "Circle")
+                    .item("D", "Erase")
+                    .show(&response)
+                {
+                    picked.push(i);
+                }
+            },
+            Vec::new(),
+        );
+    harness.run();
+
+    // Press, move towards the right slice, and release:
+    let center = harness.get_by_label("Canvas").rect().center();
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(center));
+    harness.step();
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Secondary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    harness.input_mut().events.push(button(center, true));
+    harness.step();
+    let right = center + Vec2::new(50.0, 5.0);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(right));
+    harness.step();
+    harness.input_mut().events.push(button(right, false));
+    harness.run();
+    assert_eq!(harness.state(), &[1]);
+
+    // With the keyboard:
+    harness.get_by_label("Canvas").focus();
+    harness.run();
+    harness.key_press_modifiers(Modifiers::SHIFT, egui::Key::F10);
+    harness.run();
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.run();
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    assert_eq!(harness.state(), &[1, 3]);
+}
+
+#[test]
+fn test_context_menu_builder() {
+    #[derive(Default)]
+    struct State {
+        show_grid: bool,
+        cut_clicked: bool,
+        zoom_menu_built: bool,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                ui.button("Target").context_menu_builder(|menu| {
+                    menu.section("Edit");
+                    if menu
+                        .item(egui::MenuItem::new("Cut").icon("✂").shortcut_text("Ctrl+X"))
+                        .clicked()
+                    {
+                        state.cut_clicked = true;
+                    }
+                    menu.item(egui::MenuItem::new("Paste").disabled("The clipboard is empty"));
+                    menu.section("View");
+                    menu.checkable(&mut state.show_grid, egui::MenuItem::new("Show grid"));
+                    menu.submenu(egui::MenuItem::new("Zoom"), |menu| {
+                        state.zoom_menu_built = true;
+                        menu.item(egui::MenuItem::new("200%"));
+                    });
+                });
+            },
+            State::default(),
+        );
+    harness.run();
+
+    harness.get_by_label("Target").click_secondary();
+    harness.run();
+    harness.get_by_label("Edit");
+    harness.get_by_label("View");
+    harness.get_by_label_contains("Paste");
+    assert!(
+        !harness.state().zoom_menu_built,
+        "Submenus are only built when open"
+    );
+
+    harness.get_by_label_contains("Zoom").hover();
+    harness.run();
+    assert!(harness.state().zoom_menu_built);
+    harness.get_by_label_contains("200%");
+
+    harness.get_by_label_contains("Show grid").click();
+    harness.run();
+    assert!(harness.state().show_grid);
+    assert!(
+        harness.query_by_label_contains("Show grid").is_none(),
+        "Clicking an item closes the menu"
+    );
+
+    harness.get_by_label("Target").click_secondary();
+    harness.run();
+    harness.get_by_label_contains("Cut").click();
+    harness.run();
+    assert!(harness.state().cut_clicked);
+}
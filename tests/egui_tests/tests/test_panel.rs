@@ -0,0 +1,91 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_overlay_panel_blocks_content_below() {
+    let mut clicks = 0;
+    let mut central_width = 0.0;
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .build(|ctx| {
+            egui::SidePanel::left("drawer")
+                .overlay(true)
+                .show_animated(ctx, true, |ui| {
+                    ui.label("Drawer");
+                });
+            egui::CentralPanel::default().show(ctx, |ui| {
+                central_width = ui.max_rect().width();
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                    if ui.button("Behind").clicked() {
+                        clicks += 1;
+                    }
+                });
+            });
+        });
+
+    harness.get_by_label("Drawer");
+    harness.get_by_label("Behind").click();
+    harness.run();
+    drop(harness);
+
+    assert!(
+        central_width > 350.0,
+        "An overlay panel should not take space from the central panel"
+    );
+    assert_eq!(clicks, 0, "The scrim should block clicks on content below");
+}
+
+#[test]
+fn test_side_panel_resize_preview() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 300.0))
+        .build_state(
+            |ctx, width: &mut f32| {
+                *width = egui::SidePanel::left("panel")
+                    .resize_preview(true)
+                    .show(ctx, |ui| {
+                        ui.label("Expensive contents");
+                        ui.separator();
+                    })
+                    .response
+                    .rect
+                    .width();
+                egui::CentralPanel::default().show(ctx, |_ui| {});
+            },
+            0.0,
+        );
+    harness.run();
+    let width = *harness.state();
+
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    let start = egui::pos2(width, 150.0);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(start));
+    harness.step();
+    harness.input_mut().events.push(button(start, true));
+    harness.step();
+    for dx in [20.0, 60.0, 100.0] {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(start + Vec2::new(dx, 0.0)));
+        harness.step();
+        // Only the preview line moves while dragging:
+        assert_eq!(*harness.state(), width);
+    }
+
+    harness
+        .input_mut()
+        .events
+        .push(button(start + Vec2::new(100.0, 0.0), false));
+    harness.run();
+    assert_eq!(*harness.state(), width + 100.0);
+}
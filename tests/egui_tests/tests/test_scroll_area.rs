@@ -0,0 +1,416 @@
+use egui::{Modifiers, ScrollArea, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_tab_scrolls_focused_widget_into_view() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(100.0, 200.0))
+        .build_ui_state(
+            |ui, [outer_id, inner_id]: &mut [egui::Id; 2]| {
+                *outer_id = ScrollArea::vertical()
+                    .id_salt("outer")
+                    .show(ui, |ui| {
+                        for i in 0..20 {
+                            ui.label(format!("Item {i}"));
+                        }
+                        *inner_id = ScrollArea::vertical()
+                            .id_salt("inner")
+                            .max_height(100.0)
+                            .show(ui, |ui| {
+                                for i in 0..20 {
+                                    ui.label(format!("Inner item {i}"));
+                                }
+                                _ = ui.button("Hidden Button");
+                            })
+                            .id;
+                    })
+                    .id;
+            },
+            [egui::Id::NULL; 2],
+        );
+
+    let screen_rect = harness.ctx.screen_rect();
+    assert!(
+        !screen_rect.contains_rect(harness.get_by_label("Hidden Button").rect()),
+        "The button should start out of view"
+    );
+
+    // Each ScrollArea senses drags on its content (for drag-to-scroll), which makes it a tab stop:
+    for area_id in *harness.state() {
+        harness.key_press(egui::Key::Tab);
+        harness.run();
+        assert_eq!(
+            harness.ctx.memory(|mem| mem.focused()),
+            Some(area_id.with("area"))
+        );
+    }
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("Hidden Button").is_focused());
+
+    assert!(
+        screen_rect.contains_rect(harness.get_by_label("Hidden Button").rect()),
+        "Focusing the button with Tab should scroll both ScrollAreas to reveal it"
+    );
+}
+
+#[test]
+fn test_scroll_area_varying_rows_are_measured() {
+    let row_height = |row: usize| 10.0 + 20.0 * (row % 3) as f32;
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, (offset, content_height, shown): &mut (f32, f32, Vec<usize>)| {
+                ui.spacing_mut().item_spacing.y = 0.0;
+                let output = ScrollArea::vertical()
+                    .vertical_scroll_offset(*offset)
+                    .show_varying_rows(
+                        ui,
+                        100,
+                        |_row| 10.0,
+                        |ui, row| {
+                            ui.allocate_space(Vec2::new(10.0, row_height(row)));
+                        },
+                    );
+                *content_height = output.content_size.y;
+                *shown = output.inner.collect();
+            },
+            (0.0, 0.0, vec![]),
+        );
+    harness.run();
+    // Rows 0, 1, 2, … are 10, 30, 50, … high, so the first 7 rows fill the viewport:
+    assert_eq!(harness.state().2, (0..7).collect::<Vec<_>>());
+
+    // Scroll through everything, so all rows are measured:
+    for offset in (0..3000).step_by(100) {
+        harness.state_mut().0 = offset as f32;
+        harness.run();
+    }
+    let total_height: f32 = (0..100).map(row_height).sum();
+    assert_eq!(harness.state().1, total_height);
+    assert_eq!(harness.state().2.last(), Some(&99));
+}
+
+#[test]
+fn test_sticky_section_headers() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, (offset, header_tops): &mut (f32, Vec<f32>)| {
+                header_tops.clear();
+                ui.visuals_mut().clip_rect_margin = 0.0;
+                let output =
+                    ScrollArea::vertical()
+                        .vertical_scroll_offset(*offset)
+                        .show(ui, |ui| {
+                            for section in 0..3 {
+                                egui::StickySection::new(section)
+                                    .header(|ui| {
+                                        let header = ui.label(format!("Section {section}"));
+                                        header_tops.push(header.rect.top());
+                                    })
+                                    .show(ui, |ui| {
+                                        ui.allocate_space(Vec2::new(100.0, 300.0));
+                                    });
+                            }
+                        });
+                for top in header_tops.iter_mut() {
+                    *top -= output.inner_rect.top();
+                }
+            },
+            (0.0, vec![]),
+        );
+    harness.run();
+    assert_eq!(harness.state().1[0], 0.0);
+    let section_height = harness.state().1[1];
+
+    // The first header sticks to the top while its section scrolls:
+    harness.state_mut().0 = 100.0;
+    harness.run();
+    assert_eq!(harness.state().1[0], 0.0);
+    assert_eq!(harness.state().1[1], section_height - 100.0);
+
+    // …until it is pushed out by the next section:
+    harness.state_mut().0 = section_height - 5.0;
+    harness.run();
+    let [first, second, _] = harness.state().1[..] else {
+        panic!("Expected three headers");
+    };
+    assert!(first < 0.0, "{first}");
+    assert_eq!(second, 5.0);
+}
+
+#[test]
+fn test_scroll_area_reach_start_and_end() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    struct State {
+        items: std::ops::Range<i32>,
+        scroll_to: Option<f32>,
+        offset: f32,
+        reached_start: Arc<AtomicBool>,
+        reached_end: Arc<AtomicBool>,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                ui.spacing_mut().item_spacing.y = 0.0;
+                let mut scroll_area = ScrollArea::vertical()
+                    .on_reach_start(50.0, {
+                        let reached_start = state.reached_start.clone();
+                        move || reached_start.store(true, Ordering::Relaxed)
+                    })
+                    .on_reach_end(50.0, {
+                        let reached_end = state.reached_end.clone();
+                        move || reached_end.store(true, Ordering::Relaxed)
+                    });
+                if let Some(offset) = state.scroll_to.take() {
+                    scroll_area = scroll_area.vertical_scroll_offset(offset);
+                }
+                let output = scroll_area.show(ui, |ui| {
+                    for _ in state.items.clone() {
+                        ui.allocate_space(Vec2::new(10.0, 20.0));
+                    }
+                });
+                state.offset = output.state.offset.y;
+            },
+            State {
+                items: 0..20,
+                scroll_to: None,
+                offset: 0.0,
+                reached_start: Arc::default(),
+                reached_end: Arc::default(),
+            },
+        );
+    harness.run();
+    assert!(harness.state().reached_start.swap(false, Ordering::Relaxed));
+    assert!(!harness.state().reached_end.load(Ordering::Relaxed));
+
+    // Prepending ten items keeps the first item in view:
+    harness.state_mut().items = -10..20;
+    harness.run();
+    assert_eq!(harness.state().offset, 200.0);
+    assert!(!harness.state().reached_start.load(Ordering::Relaxed));
+
+    // Nearing the end:
+    harness.state_mut().scroll_to = Some(400.0);
+    harness.run();
+    assert!(harness.state().reached_end.swap(false, Ordering::Relaxed));
+
+    // Only called again once the content has changed:
+    harness.run();
+    assert!(!harness.state().reached_end.load(Ordering::Relaxed));
+    harness.state_mut().items = -10..21;
+    harness.run();
+    assert!(harness.state().reached_end.load(Ordering::Relaxed));
+    assert_eq!(harness.state().offset, 400.0);
+}
+
+#[test]
+fn test_scroll_area_anchor_id() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, (items, scroll_to, anchor_top): &mut (Vec<u32>, Option<f32>, f32)| {
+                ui.spacing_mut().item_spacing.y = 0.0;
+                let mut scroll_area = ScrollArea::vertical().anchor_id(egui::Id::new(("item", 10)));
+                if let Some(offset) = scroll_to.take() {
+                    scroll_area = scroll_area.vertical_scroll_offset(offset);
+                }
+                scroll_area.show(ui, |ui| {
+                    for &item in items.iter() {
+                        let response = ui
+                            .scope_builder(egui::UiBuilder::new().id(("item", item)), |ui| {
+                                ui.allocate_space(Vec2::new(10.0, 20.0));
+                            })
+                            .response;
+                        if item == 10 {
+                            *anchor_top = response.rect.top();
+                        }
+                    }
+                });
+            },
+            ((0..50).collect(), Some(100.0), 0.0),
+        );
+    harness.run();
+    let anchor_top = harness.state().2;
+
+    // Inserting items above the anchor doesn't move it:
+    harness.state_mut().0.splice(0..0, 100..103);
+    harness.run();
+    assert_eq!(harness.state().2, anchor_top);
+
+    // Neither does removing them:
+    harness.state_mut().0.drain(0..5);
+    harness.run();
+    assert_eq!(harness.state().2, anchor_top);
+}
+
+#[test]
+fn test_scroll_progress() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, progress: &mut f32| {
+                let scroll_area = egui::ScrollArea::vertical().id_salt("feed");
+                *progress = scroll_area.scroll_progress(ui, 100.0).y;
+                scroll_area.show(ui, |ui| {
+                    for i in 0..100 {
+                        ui.label(format!("Post {i}"));
+                    }
+                });
+            },
+            0.0,
+        );
+    harness.run();
+    assert_eq!(*harness.state(), 0.0);
+
+    harness.get_by_label("Post 0").hover();
+    harness.run();
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -50.0),
+        modifiers: Modifiers::NONE,
+    });
+    harness.run();
+    assert!(
+        0.1 < *harness.state() && *harness.state() < 0.9,
+        "Scrolled half the distance, got {}",
+        harness.state()
+    );
+
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -500.0),
+        modifiers: Modifiers::NONE,
+    });
+    harness.run();
+    assert_eq!(*harness.state(), 1.0);
+}
+
+#[test]
+fn test_scroll_area_overscroll_is_opt_in() {
+    use egui::{Event, PointerButton, pos2};
+
+    for overscroll in [false, true] {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(200.0, 200.0))
+            .build_ui_state(
+                |ui, label_top: &mut f32| {
+                    let mut scroll_area = ScrollArea::vertical().auto_shrink(false);
+                    if overscroll {
+                        scroll_area = scroll_area.overscroll(true);
+                    }
+                    scroll_area.show(ui, |ui| {
+                        *label_top = ui.label("Item 0").rect.top();
+                        for i in 1..50 {
+                            ui.label(format!("Item {i}"));
+                        }
+                    });
+                },
+                0.0,
+            );
+        harness.run();
+        let start_top = *harness.state();
+
+        // Drag the content down, past its start:
+        let mut pos = pos2(100.0, 20.0);
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+        harness.input_mut().events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        });
+        harness.step();
+        for _ in 0..10 {
+            pos.y += 10.0;
+            harness.input_mut().events.push(Event::PointerMoved(pos));
+            harness.step();
+        }
+
+        if overscroll {
+            assert!(start_top < *harness.state(), "The content follows the drag");
+        } else {
+            assert_eq!(*harness.state(), start_top, "The content stays put");
+        }
+    }
+}
+
+#[test]
+fn test_scroll_area_pull_to_refresh() {
+    use egui::{Event, PointerButton, pos2};
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    let refresh_requested = Arc::new(AtomicBool::new(false));
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, (refreshing, offset): &mut (bool, Vec2)| {
+                let refresh_requested = refresh_requested.clone();
+                *offset = ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .pull_to_refresh(*refreshing, move || {
+                        refresh_requested.store(true, Ordering::Relaxed);
+                    })
+                    .show(ui, |ui| {
+                        ui.label("Latest news");
+                    })
+                    .state
+                    .offset;
+            },
+            (false, Vec2::ZERO),
+        );
+    harness.run();
+
+    let drag = |harness: &mut Harness<'_, (bool, Vec2)>, distance: f32| {
+        let mut pos = pos2(100.0, 20.0);
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+        harness.input_mut().events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        });
+        harness.step();
+        for _ in 0..10 {
+            pos.y += distance / 10.0;
+            harness.input_mut().events.push(Event::PointerMoved(pos));
+            harness.step();
+        }
+        harness.input_mut().events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed: false,
+            modifiers: Modifiers::NONE,
+        });
+        // Let it spring back:
+        harness.run_steps(60);
+    };
+    // Pulling down a little is not enough:
+    drag(&mut harness, 30.0);
+    assert!(!refresh_requested.load(Ordering::Relaxed));
+    assert_eq!(harness.state().1, Vec2::ZERO, "springs back to the start");
+
+    drag(&mut harness, 180.0);
+    assert!(refresh_requested.load(Ordering::Relaxed));
+    assert_eq!(harness.state().1, Vec2::ZERO, "springs back to the start");
+
+    // While refreshing, pulling down again doesn't ask for another refresh:
+    refresh_requested.store(false, Ordering::Relaxed);
+    harness.state_mut().0 = true;
+    drag(&mut harness, 180.0);
+    assert!(!refresh_requested.load(Ordering::Relaxed));
+}
@@ -0,0 +1,188 @@
+use egui::{Modifiers, ScrollArea, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_focused_text_edit_scrolls_above_keyboard() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 400.0))
+        .build_ui_state(
+            |ui, rect: &mut egui::Rect| {
+                ScrollArea::vertical().show(ui, |ui| {
+                    ui.add_space(300.0);
+                    let response = ui.text_edit_singleline(&mut String::from("Hello"));
+                    if ui.ctx().cumulative_pass_nr() == 0 {
+                        response.request_focus();
+                    }
+                    *rect = response.rect;
+                    ui.add_space(500.0);
+                });
+            },
+            egui::Rect::NOTHING,
+        );
+    harness.run();
+    let unscrolled = *harness.state();
+    assert!(unscrolled.bottom() < 400.0, "Visible without a keyboard");
+
+    let keyboard_rect = egui::Rect::from_min_max(egui::pos2(0.0, 250.0), egui::pos2(300.0, 400.0));
+    harness.input_mut().virtual_keyboard_rect = Some(keyboard_rect);
+    harness.run();
+
+    let rect = *harness.state();
+    assert!(
+        rect.bottom() <= keyboard_rect.top(),
+        "{rect:?} is behind the keyboard"
+    );
+    assert!(0.0 <= rect.top());
+}
+
+#[test]
+fn test_text_edit_paste_menu() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, text: &mut String| {
+                ui.add(egui::TextEdit::singleline(text).paste_menu(true));
+            },
+            String::new(),
+        );
+
+    harness.ctx.copy_text("first".to_owned());
+    harness.ctx.copy_text("second".to_owned());
+    harness.ctx.copy_text("first".to_owned());
+    assert_eq!(
+        egui::ClipboardHistory::load(&harness.ctx).entries,
+        ["first", "second"]
+    );
+
+    // Open the menu with the arrow and pick an entry:
+    harness.get_by_label("Paste from history").click();
+    harness.run();
+    harness.get_by_label("second").click();
+    harness.run();
+    assert_eq!(harness.state(), "second");
+    assert!(
+        harness.query_by_label("first").is_none(),
+        "The menu should close"
+    );
+
+    // Open it with the keyboard, the first entry is focused:
+    harness.key_press_modifiers(Modifiers::COMMAND | Modifiers::SHIFT, egui::Key::V);
+    harness.run();
+    assert!(harness.get_by_label("first").is_focused());
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    assert_eq!(harness.state(), "secondfirst");
+    assert!(
+        harness.query_by_label("first").is_none(),
+        "The menu should close"
+    );
+}
+
+#[test]
+fn test_text_edit_paste_content() {
+    use egui::{ClipboardContent, Event};
+    use std::path::PathBuf;
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, text: &mut String| {
+                ui.text_edit_multiline(text);
+            },
+            String::new(),
+        );
+
+    let text_input = egui::accesskit::Role::MultilineTextInput;
+    harness.get_by_role(text_input).click();
+    harness.run();
+
+    // Pasted files without a text version are inserted as paths:
+    let files = ClipboardContent::Files(vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+    harness
+        .input_mut()
+        .events
+        .push(Event::PasteContent(files.clone()));
+    harness.run();
+    assert_eq!(harness.state(), "a.txt\nb.txt");
+
+    // Rich content next to plain text is ignored, and the text is pasted once:
+    harness.state_mut().clear();
+    harness.input_mut().events.extend([
+        Event::Paste("hello".to_owned()),
+        Event::PasteContent(ClipboardContent::Html {
+            html: "<b>hello</b>".to_owned(),
+            alt_text: "hello".to_owned(),
+        }),
+        Event::PasteContent(files),
+    ]);
+    harness.step();
+    assert_eq!(harness.state(), "hello");
+    assert_eq!(
+        harness.ctx.input(|i| i.pasted_content()),
+        [
+            ClipboardContent::Text("hello".to_owned()),
+            ClipboardContent::Html {
+                html: "<b>hello</b>".to_owned(),
+                alt_text: "hello".to_owned(),
+            },
+            ClipboardContent::Files(vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]),
+        ]
+    );
+}
+
+#[test]
+fn test_text_edit_ime_composition() {
+    use egui::{Event, ImeEvent};
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 100.0))
+        .build_ui_state(
+            |ui, text: &mut String| {
+                ui.text_edit_singleline(text);
+            },
+            "ab".to_owned(),
+        );
+
+    let text_input = egui::accesskit::Role::TextInput;
+    harness.get_by_role(text_input).click();
+    harness.run();
+    harness.key_press(egui::Key::End);
+    harness.run();
+
+    let ime = |harness: &mut Harness<'_, String>, event: ImeEvent| {
+        harness.input_mut().events.push(Event::Ime(event));
+        harness.run();
+    };
+
+    // The composition is shown inline, and each update replaces the previous one:
+    ime(&mut harness, ImeEvent::Enabled);
+    ime(&mut harness, ImeEvent::Preedit("n".to_owned()));
+    assert_eq!(harness.state(), "abn");
+    ime(&mut harness, ImeEvent::Preedit("ni".to_owned()));
+    assert_eq!(harness.state(), "abni");
+    assert!(harness.output().platform_output.ime.is_some());
+
+    // Committing replaces the composition:
+    ime(&mut harness, ImeEvent::Commit("你".to_owned()));
+    ime(&mut harness, ImeEvent::Disabled);
+    assert_eq!(harness.state(), "ab你");
+
+    // Disabling the IME before committing cancels the composition:
+    ime(&mut harness, ImeEvent::Enabled);
+    ime(&mut harness, ImeEvent::Preedit("hao".to_owned()));
+    assert_eq!(harness.state(), "ab你hao");
+    ime(&mut harness, ImeEvent::Disabled);
+    assert_eq!(harness.state(), "ab你");
+
+    // And so does an empty commit:
+    ime(&mut harness, ImeEvent::Enabled);
+    ime(&mut harness, ImeEvent::Preedit("hao".to_owned()));
+    ime(&mut harness, ImeEvent::Commit(String::new()));
+    assert_eq!(harness.state(), "ab你");
+
+    // Typing continues after the committed text:
+    harness.get_by_role(text_input).type_text("!");
+    harness.run();
+    assert_eq!(harness.state(), "ab你!");
+}
@@ -0,0 +1,186 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_window_minimize_and_restore() {
+    let mut harness = Harness::new(|ctx| {
+        egui::Window::new("My Window")
+            .minimizable(true)
+            .show(ctx, |ui| {
+                ui.label("Window contents");
+            });
+    });
+
+    assert!(harness.query_by_label("Window contents").is_some());
+
+    harness.get_by_label("Minimize window").click();
+    harness.run();
+
+    assert!(egui::Window::is_minimized(
+        &harness.ctx,
+        egui::Id::new("My Window")
+    ));
+    assert!(
+        harness.query_by_label("Window contents").is_none(),
+        "A minimized window should only show its title"
+    );
+
+    harness.get_by_label("My Window").click();
+    harness.run();
+
+    assert!(!egui::Window::is_minimized(
+        &harness.ctx,
+        egui::Id::new("My Window")
+    ));
+    assert!(harness.query_by_label("Window contents").is_some());
+}
+
+#[test]
+fn test_window_layout_save_and_restore() {
+    let mut harness = Harness::new(|ctx| {
+        for title in ["Window A", "Window B"] {
+            egui::Window::new(title).show(ctx, |ui| {
+                ui.label(title);
+            });
+        }
+    });
+
+    let id_a = egui::Id::new("Window A");
+    let layer_a = egui::LayerId::new(egui::Order::Middle, id_a);
+    let saved = harness.ctx.save_window_layout();
+    assert!(saved.areas.iter().any(|area| area.layer_id == layer_a));
+    assert_ne!(harness.ctx.top_layer_id(), Some(layer_a));
+
+    // Move "Window A" and put it on top:
+    let mut layout = saved.clone();
+    layout.areas.sort_by_key(|area| area.layer_id == layer_a);
+    for area in &mut layout.areas {
+        if area.layer_id == layer_a {
+            area.pivot_pos = egui::pos2(200.0, 150.0);
+            area.collapsed = Some(true);
+        }
+    }
+    harness.ctx.restore_window_layout(&layout);
+    harness.run();
+
+    let rect_a = harness.ctx.memory(|mem| mem.area_rect(id_a)).unwrap();
+    assert_eq!(rect_a.min, egui::pos2(200.0, 150.0));
+    assert_eq!(harness.ctx.top_layer_id(), Some(layer_a));
+    assert!(egui::Window::is_collapsed(&harness.ctx, id_a));
+
+    harness.ctx.restore_window_layout(&saved);
+    harness.run();
+    assert_eq!(harness.ctx.save_window_layout(), saved);
+}
+
+#[test]
+fn test_window_snaps_to_layout_grid() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build_state(
+            |ctx, rect: &mut egui::Rect| {
+                ctx.style_mut(|style| style.interaction.layout_grid = Some(20.0));
+                *rect = egui::Window::new("Tool")
+                    .show(ctx, |ui| {
+                        ui.label("Contents");
+                    })
+                    .unwrap()
+                    .response
+                    .rect;
+            },
+            egui::Rect::NOTHING,
+        );
+    harness.run();
+
+    let drag = |harness: &mut Harness<'_, egui::Rect>, delta: Vec2, modifiers: Modifiers| {
+        let start = harness.get_by_label("Tool").rect().center();
+        harness.input_mut().modifiers = modifiers;
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(start));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::PointerButton {
+            pos: start,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers,
+        });
+        harness.step();
+        for i in 1..=10 {
+            harness
+                .input_mut()
+                .events
+                .push(egui::Event::PointerMoved(start + delta * i as f32 / 10.0));
+            harness.step();
+        }
+        harness.input_mut().events.push(egui::Event::PointerButton {
+            pos: start + delta,
+            button: egui::PointerButton::Primary,
+            pressed: false,
+            modifiers,
+        });
+        harness.run();
+    };
+
+    drag(&mut harness, Vec2::new(53.0, 27.0), Modifiers::NONE);
+    let rect = *harness.state();
+    assert_eq!(
+        (rect.min.x % 20.0, rect.min.y % 20.0),
+        (0.0, 0.0),
+        "{rect:?}"
+    );
+
+    // Holding alt places the window freely:
+    drag(&mut harness, Vec2::new(3.0, 3.0), Modifiers::ALT);
+    let moved = harness.state().min - rect.min;
+    assert!((moved - Vec2::new(3.0, 3.0)).length() < 0.5, "{moved:?}");
+}
+
+#[test]
+fn test_window_shortcuts() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 400.0))
+        .build_state(
+            |ctx, open: &mut [bool; 2]| {
+                for (i, open) in open.iter_mut().enumerate() {
+                    egui::Window::new(format!("Window {i}"))
+                        .default_pos(egui::pos2(50.0 + 200.0 * i as f32, 50.0))
+                        .open(open)
+                        .show(ctx, |ui| {
+                            ui.label("Contents");
+                        });
+                }
+            },
+            [true; 2],
+        );
+    harness.run();
+    let window_id = |i: usize| egui::Id::new(format!("Window {i}"));
+    let top = |harness: &Harness<'_, [bool; 2]>| harness.ctx.top_layer_id().map(|layer| layer.id);
+
+    let first_top = top(&harness);
+    harness.key_press_modifiers(Modifiers::CTRL, egui::Key::F6);
+    harness.run();
+    let second_top = top(&harness);
+    assert_ne!(first_top, second_top);
+    harness.key_press_modifiers(Modifiers::CTRL, egui::Key::F6);
+    harness.run();
+    assert_eq!(top(&harness), first_top);
+    harness.key_press_modifiers(Modifiers::CTRL | Modifiers::SHIFT, egui::Key::F6);
+    harness.run();
+    assert_eq!(top(&harness), second_top);
+
+    let second_top = second_top.unwrap();
+    let rect_before = harness.ctx.memory(|mem| mem.area_rect(second_top)).unwrap();
+    harness.key_press_modifiers(Modifiers::CTRL | Modifiers::ALT, egui::Key::ArrowRight);
+    harness.run();
+    let rect_after = harness.ctx.memory(|mem| mem.area_rect(second_top)).unwrap();
+    assert_eq!(rect_after.min, rect_before.min + Vec2::new(10.0, 0.0));
+
+    harness.key_press_modifiers(Modifiers::CTRL, egui::Key::F4);
+    harness.run();
+    let closed = usize::from(second_top != window_id(0));
+    assert!(!harness.state()[closed]);
+    assert!(harness.state()[1 - closed]);
+}
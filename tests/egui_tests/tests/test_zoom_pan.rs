@@ -0,0 +1,188 @@
+use egui::{Modifiers, Vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn test_magnifier_shows_lens_on_hover() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build_ui(|ui| {
+            egui::Magnifier::new().zoom(8.0).show(ui, |ui| {
+                ui.label("Magnified");
+            });
+        });
+
+    let num_shapes = |harness: &Harness<'_>| harness.output().shapes.len();
+    let shapes_without_lens = num_shapes(&harness);
+
+    harness.get_by_label("Magnified").hover();
+    harness.run();
+    assert!(
+        num_shapes(&harness) > shapes_without_lens,
+        "Hovering the contents should paint them again in the lens"
+    );
+}
+
+#[test]
+fn test_zoom_pan_area() {
+    #[derive(Default)]
+    struct State {
+        zoom: f32,
+        node_on_screen: egui::Pos2,
+        node_clicks: usize,
+    }
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let response = egui::ZoomPanArea::new("canvas").show(ui, |ui| {
+                    ui.put(
+                        egui::Rect::from_min_size(egui::Pos2::ZERO, Vec2::new(100.0, 50.0)),
+                        egui::Button::new("Node"),
+                    )
+                });
+                if response.inner.clicked() {
+                    state.node_clicks += 1;
+                }
+                state.zoom = response.zoom();
+                state.node_on_screen = response.screen_from_scene(egui::pos2(50.0, 25.0));
+            },
+            State::default(),
+        );
+    harness.run();
+
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    let click = |harness: &mut Harness<'_, State>, pos| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.step();
+        harness.input_mut().events.push(button(pos, true));
+        harness.input_mut().events.push(button(pos, false));
+        harness.run();
+    };
+
+    // The contents fit, without zooming in past 1:1:
+    assert_eq!(harness.state().zoom, 1.0);
+    let node = harness.state().node_on_screen;
+    assert_eq!(node, egui::pos2(200.0, 150.0));
+    click(&mut harness, node);
+    assert_eq!(harness.state().node_clicks, 1);
+
+    // Drag the background to pan:
+    let start = node + Vec2::new(0.0, 80.0);
+    let delta = Vec2::new(30.0, 20.0);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(start));
+    harness.step();
+    harness.input_mut().events.push(button(start, true));
+    harness.step();
+    for i in 1..=5 {
+        let pos = start + delta * i as f32 / 5.0;
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.step();
+    }
+    harness
+        .input_mut()
+        .events
+        .push(button(start + delta, false));
+    harness.run();
+    let moved = harness.state().node_on_screen;
+    assert!((moved - node - delta).length() < 0.5);
+    click(&mut harness, moved);
+    assert_eq!(harness.state().node_clicks, 2);
+
+    // Zoom out around the pointer, which stays over the same point of the contents:
+    harness.input_mut().events.push(egui::Event::Zoom(0.5));
+    harness.run();
+    assert_eq!(harness.state().zoom, 0.5);
+    assert!((harness.state().node_on_screen - moved).length() < 0.5);
+
+    // Double-click the background to fit the contents again:
+    // (long enough after the last click for this not to count as a triple-click)
+    let background = egui::pos2(10.0, 290.0);
+    harness.input_mut().time = Some(100.0);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(background));
+    harness.step();
+    for _ in 0..2 {
+        harness.input_mut().events.push(button(background, true));
+        harness.step();
+        harness.input_mut().events.push(button(background, false));
+        harness.step();
+    }
+    harness.run();
+    assert_eq!(harness.state().zoom, 1.0);
+    assert_eq!(harness.state().node_on_screen, node);
+}
+
+#[test]
+fn test_image_viewer() {
+    #[derive(Default)]
+    struct State {
+        texture: Option<egui::TextureHandle>,
+        response: Option<egui::ImageViewerResponse>,
+    }
+    let pixels = egui::ColorImage::new(
+        [4, 2],
+        (0..8).map(|i| egui::Color32::from_gray(i * 30)).collect(),
+    );
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                ui.spacing_mut().item_spacing = Vec2::ZERO;
+                let texture = state.texture.get_or_insert_with(|| {
+                    ui.ctx()
+                        .load_texture("pixels", pixels.clone(), Default::default())
+                });
+                let image = egui::Image::from_texture(&*texture);
+                state.response = Some(
+                    egui::ImageViewer::new("viewer", image)
+                        .pixels(&pixels)
+                        .show(ui),
+                );
+            },
+            State::default(),
+        );
+    harness.run();
+
+    // The 4x2 image fits the 400x200 view:
+    let response = harness.state().response.clone().unwrap();
+    assert_eq!(response.state.zoom, None);
+    let pos = response.response.rect.min + Vec2::new(250.0, 150.0);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(pos));
+    harness.run();
+    let response = harness.state().response.clone().unwrap();
+    assert_eq!(response.hovered_pixel, Some([2, 1]));
+    assert_eq!(
+        response.hovered_color,
+        Some(egui::Color32::from_gray(6 * 30))
+    );
+
+    harness.get_by_label("1:1").click();
+    harness.run();
+    let response = harness.state().response.clone().unwrap();
+    assert_eq!(response.state.zoom, Some(1.0));
+    assert_eq!(response.hovered_pixel, None, "The image is tiny at 1:1");
+
+    harness.get_by_label("Fit").click();
+    harness.run();
+    assert_eq!(harness.state().response.as_ref().unwrap().state.zoom, None);
+}